@@ -0,0 +1,137 @@
+// boot/spinUP/src/bus.rs
+// Last Updated: 2025-01-13 06:30:00 UTC
+// Author: Caleb J.D. Terkovics (isdood)
+// Current User: isdood
+
+//! Device bus abstraction for spinUP's memory-mapped and port-mapped I/O.
+//!
+//! Centralizes the hardware layout that used to be scattered through
+//! `print`, `SerialController`, and `read_disk_sector` as magic constants,
+//! similar in spirit to a device bus with `ROM_START`/`RAM_START` ranges
+//! and a `get_device(address)` dispatch: an out-of-range access becomes a
+//! recoverable [`BusError`] instead of a silent wild write.
+
+/// One of the regions [`Bus`] knows how to route `read`/`write` to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeviceKind {
+    /// The VGA text-mode buffer at 0xb8000, two bytes per cell
+    /// (character, attribute).
+    VgaText,
+    /// The COM1 UART's register range starting at 0x3F8.
+    Com1,
+    /// The scratch buffer used to build a BIOS `int 0x13` extended-read
+    /// "Disk Address Packet" before the call.
+    DiskPacket,
+}
+
+/// A named, range-checked region owned by [`Bus`].
+#[derive(Debug, Clone, Copy)]
+pub struct DeviceRegion {
+    pub kind: DeviceKind,
+    pub start: usize,
+    pub len: usize,
+}
+
+impl DeviceRegion {
+    const fn contains(&self, address: usize) -> bool {
+        address >= self.start && address < self.start + self.len
+    }
+}
+
+/// Failure mode for [`Bus::read_u8`]/[`Bus::write_u8`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BusError {
+    /// `address` didn't fall inside any registered device region.
+    OutOfRange { address: usize },
+}
+
+pub const VGA_TEXT_START: usize = 0xb8000;
+pub const VGA_TEXT_LEN: usize = 80 * 25 * 2;
+pub const COM1_PORT_START: usize = 0x3F8;
+pub const COM1_PORT_LEN: usize = 8;
+pub const DISK_PACKET_START: usize = 0x1000;
+pub const DISK_PACKET_LEN: usize = 16;
+
+/// The bootloader's device map: a fixed table of named MMIO/PIO regions,
+/// documented in one place instead of as magic constants scattered across
+/// `print`, `SerialController`, and `read_disk_sector`.
+#[repr(C)]
+pub struct Bus {
+    regions: [DeviceRegion; 3],
+}
+
+impl Bus {
+    pub const fn new() -> Self {
+        Self {
+            regions: [
+                DeviceRegion { kind: DeviceKind::VgaText, start: VGA_TEXT_START, len: VGA_TEXT_LEN },
+                DeviceRegion { kind: DeviceKind::Com1, start: COM1_PORT_START, len: COM1_PORT_LEN },
+                DeviceRegion { kind: DeviceKind::DiskPacket, start: DISK_PACKET_START, len: DISK_PACKET_LEN },
+            ],
+        }
+    }
+
+    /// Finds the device region `address` belongs to, if any.
+    pub fn get_device(&self, address: usize) -> Option<&DeviceRegion> {
+        self.regions.iter().find(|region| region.contains(address))
+    }
+
+    /// Writes `value` to `address`, dispatching to whichever device owns
+    /// it. Returns [`BusError::OutOfRange`] instead of performing a wild
+    /// write when no region claims `address`.
+    ///
+    /// # Safety
+    /// `address` must be valid for whatever access `get_device` resolves
+    /// it to (a mapped VGA cell, a real I/O port, or the disk-packet
+    /// scratch buffer).
+    pub unsafe fn write_u8(&self, address: usize, value: u8) -> Result<(), BusError> {
+        let region = self.get_device(address).ok_or(BusError::OutOfRange { address })?;
+
+        match region.kind {
+            DeviceKind::VgaText | DeviceKind::DiskPacket => {
+                *(address as *mut u8) = value;
+            }
+            DeviceKind::Com1 => {
+                core::arch::asm!(
+                    "out dx, al",
+                    in("dx") address as u16,
+                    in("al") value,
+                    options(nomem, nostack, preserves_flags)
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reads one byte from `address`, dispatching to whichever device
+    /// owns it. Returns [`BusError::OutOfRange`] when no region claims
+    /// `address`.
+    ///
+    /// # Safety
+    /// See [`Bus::write_u8`].
+    pub unsafe fn read_u8(&self, address: usize) -> Result<u8, BusError> {
+        let region = self.get_device(address).ok_or(BusError::OutOfRange { address })?;
+
+        let value = match region.kind {
+            DeviceKind::VgaText | DeviceKind::DiskPacket => *(address as *const u8),
+            DeviceKind::Com1 => {
+                let value: u8;
+                core::arch::asm!(
+                    "in al, dx",
+                    out("al") value,
+                    in("dx") address as u16,
+                    options(nomem, nostack, preserves_flags)
+                );
+                value
+            }
+        };
+
+        Ok(value)
+    }
+}
+
+/// The bootloader's single device map, shared by `spinup::serial` and the
+/// `spinUP` binary so both route VGA/COM1/disk-packet access through one
+/// table instead of each hardcoding its own magic addresses.
+pub static BUS: Bus = Bus::new();
@@ -0,0 +1,174 @@
+// boot/spinUP/src/config.rs
+// Last Updated: 2025-01-13 06:45:00 UTC
+// Author: Caleb J.D. Terkovics (isdood)
+// Current User: isdood
+
+//! A tiny key-value config store living in a reserved sector, modeled on
+//! flash-config stores that keep a magic+checksum header plus a flat
+//! entry table in a dedicated flash region. `real_start` reads this
+//! sector on boot and overrides the hardcoded kernel sector/size/
+//! load-address and mesh density with whatever entries are present,
+//! falling back to the defaults when the region is blank or corrupt --
+//! so reflashing the kernel or changing its size no longer requires
+//! rebuilding the bootloader.
+
+use crate::{read_disk_sector, write_disk_sector, BootError};
+
+/// The sector this config store lives in. Well clear of
+/// `KERNEL_SECTOR_START` (33) in `main.rs`.
+pub const CONFIG_SECTOR: u16 = 1;
+
+const CONFIG_MAGIC: u32 = 0x5350_4346; // "SPCF"
+const HEADER_SIZE: usize = 16;
+const ENTRY_SIZE: usize = 16;
+const MAX_ENTRIES: usize = 16;
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ConfigKey {
+    KernelSectorStart = 1,
+    SectorsToRead = 2,
+    KernelLoadAddr = 3,
+    MeshDensity = 4,
+}
+
+impl ConfigKey {
+    fn from_raw(raw: u32) -> Option<Self> {
+        match raw {
+            1 => Some(Self::KernelSectorStart),
+            2 => Some(Self::SectorsToRead),
+            3 => Some(Self::KernelLoadAddr),
+            4 => Some(Self::MeshDensity),
+            _ => None,
+        }
+    }
+}
+
+/// Parsed contents of the config sector. Each field overrides the
+/// matching hardcoded default in `main.rs` when present.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BootConfig {
+    pub kernel_sector_start: Option<u16>,
+    pub sectors_to_read: Option<u16>,
+    pub kernel_load_addr: Option<u64>,
+    pub mesh_density: Option<usize>,
+}
+
+impl BootConfig {
+    fn entries(&self) -> [Option<(ConfigKey, u64)>; 4] {
+        [
+            self.kernel_sector_start.map(|v| (ConfigKey::KernelSectorStart, v as u64)),
+            self.sectors_to_read.map(|v| (ConfigKey::SectorsToRead, v as u64)),
+            self.kernel_load_addr.map(|v| (ConfigKey::KernelLoadAddr, v)),
+            self.mesh_density.map(|v| (ConfigKey::MeshDensity, v as u64)),
+        ]
+    }
+
+    fn apply(&mut self, key: ConfigKey, value: u64) {
+        match key {
+            ConfigKey::KernelSectorStart => self.kernel_sector_start = Some(value as u16),
+            ConfigKey::SectorsToRead => self.sectors_to_read = Some(value as u16),
+            ConfigKey::KernelLoadAddr => self.kernel_load_addr = Some(value),
+            ConfigKey::MeshDensity => self.mesh_density = Some(value as usize),
+        }
+    }
+}
+
+fn checksum_of(bytes: &[u8]) -> u32 {
+    bytes.iter().fold(0u32, |acc, &byte| acc.wrapping_add(byte as u32).rotate_left(1))
+}
+
+unsafe fn write_u32(buffer: *mut u8, offset: usize, value: u32) {
+    core::ptr::write(buffer.add(offset) as *mut u32, value);
+}
+
+unsafe fn read_u32(buffer: *const u8, offset: usize) -> u32 {
+    core::ptr::read(buffer.add(offset) as *const u32)
+}
+
+unsafe fn write_u64(buffer: *mut u8, offset: usize, value: u64) {
+    core::ptr::write(buffer.add(offset) as *mut u64, value);
+}
+
+unsafe fn read_u64(buffer: *const u8, offset: usize) -> u64 {
+    core::ptr::read(buffer.add(offset) as *const u64)
+}
+
+/// Reads the config sector into `buffer` (at least 512 bytes, 4-byte
+/// aligned) and parses it. Returns `Ok(None)` -- not an error -- when the
+/// region is blank or its magic/checksum don't validate, so the caller
+/// can fall back to hardcoded defaults; returns `Err` only if the disk
+/// read itself failed.
+pub unsafe fn read_config(buffer: *mut u8) -> Result<Option<BootConfig>, BootError> {
+    read_disk_sector(CONFIG_SECTOR, buffer)?;
+
+    let magic = read_u32(buffer, 0);
+    if magic != CONFIG_MAGIC {
+        return Ok(None);
+    }
+
+    let stored_checksum = read_u32(buffer, 4);
+    let entry_count = read_u32(buffer, 8) as usize;
+    if entry_count > MAX_ENTRIES {
+        return Ok(None);
+    }
+
+    let entries_len = entry_count * ENTRY_SIZE;
+    let entries_bytes = core::slice::from_raw_parts(buffer.add(HEADER_SIZE), entries_len);
+    if checksum_of(entries_bytes) != stored_checksum {
+        return Ok(None);
+    }
+
+    let mut config = BootConfig::default();
+    for i in 0..entry_count {
+        let offset = HEADER_SIZE + i * ENTRY_SIZE;
+        let key_raw = read_u32(buffer, offset);
+        let value = read_u64(buffer, offset + 8);
+        if let Some(key) = ConfigKey::from_raw(key_raw) {
+            config.apply(key, value);
+        }
+    }
+
+    Ok(Some(config))
+}
+
+/// Serializes `config` into `buffer` (at least 512 bytes, 4-byte aligned)
+/// and writes it to the config sector, so a kernel or tool can update
+/// boot parameters without recompiling the bootloader.
+pub unsafe fn write_config(buffer: *mut u8, config: &BootConfig) -> Result<(), BootError> {
+    let present: [(ConfigKey, u64); 4] = {
+        let mut packed = [(ConfigKey::KernelSectorStart, 0u64); 4];
+        let mut count = 0;
+        for entry in config.entries().into_iter().flatten() {
+            packed[count] = entry;
+            count += 1;
+        }
+        packed
+    };
+    let entry_count = config.entries().into_iter().flatten().count();
+
+    for (i, (key, value)) in present.into_iter().take(entry_count).enumerate() {
+        let offset = HEADER_SIZE + i * ENTRY_SIZE;
+        write_u32(buffer, offset, key as u32);
+        write_u32(buffer, offset + 4, 0);
+        write_u64(buffer, offset + 8, value);
+    }
+
+    let entries_len = entry_count * ENTRY_SIZE;
+    let entries_bytes = core::slice::from_raw_parts(buffer.add(HEADER_SIZE), entries_len);
+    let checksum = checksum_of(entries_bytes);
+
+    write_u32(buffer, 0, CONFIG_MAGIC);
+    write_u32(buffer, 4, checksum);
+    write_u32(buffer, 8, entry_count as u32);
+    write_u32(buffer, 12, 0);
+
+    write_disk_sector(CONFIG_SECTOR, buffer)
+}
+
+/// Erases the config sector by zeroing its magic, so the next boot falls
+/// back to hardcoded defaults.
+pub unsafe fn erase_config(buffer: *mut u8) -> Result<(), BootError> {
+    core::ptr::write_bytes(buffer, 0, HEADER_SIZE);
+    write_disk_sector(CONFIG_SECTOR, buffer)
+}
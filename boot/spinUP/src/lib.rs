@@ -6,8 +6,10 @@
 #![no_std]
 
 pub mod boot_params;
+pub mod bus;
 pub mod serial;
 
 // Re-export types that should be public
 pub use crate::boot_params::BootParams;
+pub use crate::bus::{Bus, BusError, DeviceKind, DeviceRegion, BUS};
 pub use crate::serial::*;
@@ -6,13 +6,17 @@
 #![no_std]
 #![no_main]
 #![feature(naked_functions)]
+#![feature(never_type)]
 
 use spinup::{
     serial,
     serial_println,
     memory::{self, init_memory, is_aligned, AlignedMemoryRegion},
+    bus::{self, Bus, BUS},
 };
 
+mod config;
+
 use core::panic::PanicInfo;
 use unstable_matter::{
     SpaceTime,
@@ -28,6 +32,7 @@ const KERNEL_SECTOR_START: u16 = 33;     // Kernel starts at sector 33
 const SECTORS_TO_READ: u16 = 100;        // Adjust based on kernel size
 const VECTOR_CELL_SIZE: usize = 4096;    // 4KB per cell
 const MESH_DENSITY: usize = 16;          // 16x16x16 mesh
+const DISK_READ_RETRIES: u8 = 3;         // Max attempts per sector before giving up
 const VGA_BUFFER: *mut u8 = 0xb8000 as *mut u8;
 static mut VGA_CURSOR: usize = 0;
 
@@ -37,12 +42,61 @@ pub struct MainBootParams {
     pub kernel_size: u32,
     pub space_metadata: *const SpaceMetadata,
     pub vector_space: *const VectorSpace,
+    /// The bootloader's device map (VGA text buffer, COM1, disk packet
+    /// scratch buffer), so the kernel can validate its own MMIO/PIO access
+    /// against the same table instead of re-deriving the hardware layout.
+    pub device_map: *const Bus,
+}
+
+/// A boot-sequence failure, carrying enough context to print a diagnostic
+/// line instead of the opaque "PANIC: System halted" an `assert!` used to
+/// produce.
+#[derive(Debug, Clone, Copy)]
+pub enum BootError {
+    /// `address` was not aligned to `alignment`.
+    MemoryAlignment { address: usize, alignment: usize },
+    /// The BIOS extended read for `sector` failed; `status` is the AH
+    /// error code the BIOS returned.
+    DiskRead { sector: u16, status: u8 },
+    /// The vector space was in `actual` when `expected` was required.
+    VectorSpaceState { expected: UFOState, actual: UFOState },
+    /// The staged image had a valid ELF64 magic but a header that can't
+    /// be loaded (e.g. no program headers).
+    ElfMalformed { entry: u64 },
+}
+
+/// Prints a diagnostic line for `err` to both serial and VGA before the
+/// caller halts, so a boot failure is identifiable rather than an opaque
+/// "PANIC: System halted".
+unsafe fn report_boot_error(err: BootError) {
+    match err {
+        BootError::MemoryAlignment { address, alignment } => {
+            serial_println!("spinUP: BOOT ERROR - {:#x} is not aligned to {}", address, alignment);
+            println("BOOT ERROR: memory alignment failure");
+        }
+        BootError::DiskRead { sector, status } => {
+            serial_println!(
+                "spinUP: BOOT ERROR - disk read failed at sector {} (status {:#x})",
+                sector,
+                status
+            );
+            println("BOOT ERROR: disk read failure");
+        }
+        BootError::VectorSpaceState { expected, actual } => {
+            serial_println!("spinUP: BOOT ERROR - vector space state: expected {:?}, got {:?}", expected, actual);
+            println("BOOT ERROR: vector space state mismatch");
+        }
+        BootError::ElfMalformed { entry } => {
+            serial_println!("spinUP: BOOT ERROR - malformed ELF kernel image (entry {:#x})", entry);
+            println("BOOT ERROR: malformed ELF kernel image");
+        }
+    }
 }
 
 // Initialize space configuration
-fn init_space_config() -> SpaceConfig {
+fn init_space_config(mesh_density: usize) -> SpaceConfig {
     SpaceConfig::new(
-        Vector3D::new(MESH_DENSITY, MESH_DENSITY, MESH_DENSITY),
+        Vector3D::new(mesh_density, mesh_density, mesh_density),
                      Vector3D::new(VECTOR_CELL_SIZE, VECTOR_CELL_SIZE, VECTOR_CELL_SIZE)
     )
 }
@@ -57,30 +111,191 @@ fn panic(_info: &PanicInfo) -> ! {
     }
 }
 
-unsafe fn read_disk_sector(sector: u16, buffer: *mut u8) {
-    let mut disk_packet = [0u8; 16];
-    disk_packet[0] = 16;    // Size of packet
-    disk_packet[1] = 0;     // Reserved
-    disk_packet[2] = 1;     // Number of sectors to read
-    disk_packet[3] = 0;     // Reserved
-    disk_packet[4..8].copy_from_slice(&(buffer as u32).to_le_bytes());
-    disk_packet[8..12].copy_from_slice(&(sector as u32).to_le_bytes());
-    disk_packet[12..16].fill(0);
+// The Disk Address Packet is built in the bus's disk-packet region rather
+// than on the stack, so writing it goes through the same range-checked
+// `Bus::write_u8` entry point as VGA and COM1 instead of a raw store.
+//
+// Captures the BIOS carry-flag/AH status of the `int 0x13` extended read
+// instead of ignoring it, so a failed read surfaces as
+// `BootError::DiskRead` rather than silently copying garbage into the
+// kernel region.
+unsafe fn read_disk_sector(sector: u16, buffer: *mut u8) -> Result<(), BootError> {
+    let packet_addr = bus::DISK_PACKET_START;
+
+    let _ = BUS.write_u8(packet_addr, 16);    // Size of packet
+    let _ = BUS.write_u8(packet_addr + 1, 0); // Reserved
+    let _ = BUS.write_u8(packet_addr + 2, 1); // Number of sectors to read
+    let _ = BUS.write_u8(packet_addr + 3, 0); // Reserved
+    for (i, byte) in (buffer as u32).to_le_bytes().iter().enumerate() {
+        let _ = BUS.write_u8(packet_addr + 4 + i, *byte);
+    }
+    for (i, byte) in (sector as u32).to_le_bytes().iter().enumerate() {
+        let _ = BUS.write_u8(packet_addr + 8 + i, *byte);
+    }
+    for i in 0..4 {
+        let _ = BUS.write_u8(packet_addr + 12 + i, 0);
+    }
 
+    let status: u8;
+    let carry: u8;
     core::arch::asm!(
         ".code32",
         "mov ah, 0x42",
         "mov dl, 0x00",
         "int 0x13",
-        in("si") disk_packet.as_ptr(),
-                     options(preserves_flags)
+        "setc dl",
+        out("ah") status,
+        out("dl") carry,
+        in("si") packet_addr,
+    );
+
+    if carry != 0 {
+        return Err(BootError::DiskRead { sector, status });
+    }
+
+    Ok(())
+}
+
+/// Writes one sector via BIOS `int 0x13` extended write (AH=0x43),
+/// mirroring `read_disk_sector`'s carry/AH status capture so a failed
+/// write surfaces as `BootError::DiskRead` instead of being silently
+/// dropped. Used by the config store to persist boot parameters.
+unsafe fn write_disk_sector(sector: u16, buffer: *const u8) -> Result<(), BootError> {
+    let packet_addr = bus::DISK_PACKET_START;
+
+    let _ = BUS.write_u8(packet_addr, 16);    // Size of packet
+    let _ = BUS.write_u8(packet_addr + 1, 0); // Reserved
+    let _ = BUS.write_u8(packet_addr + 2, 1); // Number of sectors to write
+    let _ = BUS.write_u8(packet_addr + 3, 0); // Reserved
+    for (i, byte) in (buffer as u32).to_le_bytes().iter().enumerate() {
+        let _ = BUS.write_u8(packet_addr + 4 + i, *byte);
+    }
+    for (i, byte) in (sector as u32).to_le_bytes().iter().enumerate() {
+        let _ = BUS.write_u8(packet_addr + 8 + i, *byte);
+    }
+    for i in 0..4 {
+        let _ = BUS.write_u8(packet_addr + 12 + i, 0);
+    }
+
+    let status: u8;
+    let carry: u8;
+    core::arch::asm!(
+        ".code32",
+        "mov ah, 0x43",
+        "mov al, 0x00",
+        "mov dl, 0x00",
+        "int 0x13",
+        "setc dl",
+        out("ah") status,
+        out("dl") carry,
+        in("si") packet_addr,
+    );
+
+    if carry != 0 {
+        return Err(BootError::DiskRead { sector, status });
+    }
+
+    Ok(())
+}
+
+/// Resets the disk controller via `int 0x13` AH=0x00, so a failed read can
+/// be retried from a known-good controller state.
+unsafe fn reset_disk() {
+    core::arch::asm!(
+        ".code32",
+        "mov ah, 0x00",
+        "mov dl, 0x00",
+        "int 0x13",
+        out("ah") _,
     );
 }
 
+const ELF_MAGIC: [u8; 4] = [0x7F, b'E', b'L', b'F'];
+const ELFCLASS64: u8 = 2;
+const EM_X86_64: u16 = 62;
+const PT_LOAD: u32 = 1;
+
+#[repr(C)]
+struct Elf64Header {
+    e_ident: [u8; 16],
+    e_type: u16,
+    e_machine: u16,
+    e_version: u32,
+    e_entry: u64,
+    e_phoff: u64,
+    e_shoff: u64,
+    e_flags: u32,
+    e_ehsize: u16,
+    e_phentsize: u16,
+    e_phnum: u16,
+    e_shentsize: u16,
+    e_shnum: u16,
+    e_shstrndx: u16,
+}
+
+#[repr(C)]
+struct Elf64ProgramHeader {
+    p_type: u32,
+    p_flags: u32,
+    p_offset: u64,
+    p_vaddr: u64,
+    p_paddr: u64,
+    p_filesz: u64,
+    p_memsz: u64,
+    p_align: u64,
+}
+
+/// Parses a staged ELF64 image at `image` and loads each `PT_LOAD` segment
+/// to its `p_paddr`, zero-filling the `p_memsz - p_filesz` BSS tail. This
+/// mirrors how a szl-style bootloader loads a `runtime.elf` with distinct
+/// load/entry addresses instead of assuming a flat binary linked at a
+/// fixed address. Returns the kernel's entry point on success, `None` if
+/// `image` doesn't start with a valid ELF64/x86-64 header (the caller
+/// should then fall back to treating `image` as a flat binary), or
+/// `Err(BootError::ElfMalformed)` if the magic matches but the header
+/// can't actually be loaded.
+unsafe fn load_elf_kernel(image: *const u8) -> Result<Option<u64>, BootError> {
+    let header = &*(image as *const Elf64Header);
+
+    if &header.e_ident[0..4] != &ELF_MAGIC[..] {
+        return Ok(None);
+    }
+    if header.e_ident[4] != ELFCLASS64 {
+        return Ok(None);
+    }
+    if header.e_machine != EM_X86_64 {
+        return Ok(None);
+    }
+    if header.e_phnum == 0 {
+        return Err(BootError::ElfMalformed { entry: header.e_entry });
+    }
+
+    let ph_base = image.add(header.e_phoff as usize) as *const Elf64ProgramHeader;
+    for i in 0..header.e_phnum as usize {
+        let ph = &*ph_base.add(i);
+        if ph.p_type != PT_LOAD {
+            continue;
+        }
+
+        let src = image.add(ph.p_offset as usize);
+        let dest = ph.p_paddr as *mut u8;
+
+        core::ptr::copy_nonoverlapping(src, dest, ph.p_filesz as usize);
+
+        let bss_len = (ph.p_memsz - ph.p_filesz) as usize;
+        if bss_len > 0 {
+            core::ptr::write_bytes(dest.add(ph.p_filesz as usize), 0, bss_len);
+        }
+    }
+
+    Ok(Some(header.e_entry))
+}
+
 unsafe fn print(s: &str) {
     for byte in s.bytes() {
-        let char_ptr = (VGA_BUFFER as *mut u16).add(VGA_CURSOR);
-        *char_ptr = (0x0F << 8) | byte as u16; // White on black
+        let cell_addr = VGA_BUFFER as usize + VGA_CURSOR * 2;
+        let _ = BUS.write_u8(cell_addr, byte);       // Character
+        let _ = BUS.write_u8(cell_addr + 1, 0x0F);   // White on black
         VGA_CURSOR += 1;
     }
 }
@@ -119,22 +334,59 @@ pub unsafe extern "C" fn _start() -> ! {
 
 #[no_mangle]
 unsafe extern "C" fn real_start() -> ! {
+    match try_boot() {
+        Ok(never) => never,
+        Err(err) => {
+            report_boot_error(err);
+            loop {
+                core::arch::asm!("hlt");
+            }
+        }
+    }
+}
+
+/// The boot sequence proper. Returns `Err(BootError)` instead of halting
+/// via `assert!`/`assert_eq!` on alignment failures and unexpected
+/// vector-space states, so `real_start` can report a diagnostic before it
+/// halts. Diverges into the kernel on success.
+unsafe fn try_boot() -> Result<!, BootError> {
     serial::init_serial();
     serial_println!("spinUP: Bootloader starting...");
 
+    // Read the config sector, overriding the hardcoded defaults with
+    // whatever it holds; a blank or corrupt region just falls back to the
+    // defaults rather than failing the boot.
+    let mut config_buffer = AlignedMemoryRegion::new();
+    let boot_config = config::read_config(config_buffer.as_mut_ptr()).unwrap_or(None).unwrap_or_default();
+
+    let kernel_load_addr = boot_config.kernel_load_addr.unwrap_or(KERNEL_LOAD_ADDR);
+    let kernel_sector_start = boot_config.kernel_sector_start.unwrap_or(KERNEL_SECTOR_START);
+    let sectors_to_read = boot_config.sectors_to_read.unwrap_or(SECTORS_TO_READ);
+    let mesh_density = boot_config.mesh_density.unwrap_or(MESH_DENSITY);
+
+    if boot_config.kernel_load_addr.is_some()
+        || boot_config.kernel_sector_start.is_some()
+        || boot_config.sectors_to_read.is_some()
+        || boot_config.mesh_density.is_some()
+    {
+        serial_println!("spinUP: Loaded boot parameter overrides from config sector");
+    }
+
     // Initialize vector space with proper memory checks
     let vector_space = {
         // Verify alignment
-        assert!(
-            is_aligned(KERNEL_LOAD_ADDR as usize, VECTOR_CELL_SIZE),
-                "Kernel load address must be aligned to vector cell size"
-        );
+        if !is_aligned(kernel_load_addr as usize, VECTOR_CELL_SIZE) {
+            return Err(BootError::MemoryAlignment {
+                address: kernel_load_addr as usize,
+                alignment: VECTOR_CELL_SIZE,
+            });
+        }
 
         // Create space configuration
-        let space_config = init_space_config();
+        let space_config = init_space_config(mesh_density);
 
         // Initialize memory system
-        let vs = init_memory(KERNEL_LOAD_ADDR as usize);
+        let vs = init_memory(kernel_load_addr as usize);
 
         // Ensure proper initialization state
         if vs.get_state() != UFOState::Hovering {
@@ -142,17 +394,18 @@ unsafe extern "C" fn real_start() -> ! {
             memory::transition_vector_space(vs, UFOState::Hovering);
 
             // Verify state transition
-            assert_eq!(
-                vs.get_state(),
-                       UFOState::Hovering,
-                       "Failed to transition vector space to hovering state"
-            );
+            if vs.get_state() != UFOState::Hovering {
+                return Err(BootError::VectorSpaceState {
+                    expected: UFOState::Hovering,
+                    actual: vs.get_state(),
+                });
+            }
         }
 
         serial_println!(
             "spinUP: Vector space initialized at {:#x} with {} cells",
-            KERNEL_LOAD_ADDR,
-            MESH_DENSITY.pow(3)
+            kernel_load_addr,
+            mesh_density.pow(3)
         );
         vs
     };
@@ -160,9 +413,9 @@ unsafe extern "C" fn real_start() -> ! {
     // Set up the space-time region for kernel loading
     println("spinUP: Setting up kernel space...");
     let kernel_space = {
-        let size = (SECTORS_TO_READ as usize) * 512;
+        let size = (sectors_to_read as usize) * 512;
         SpaceTime::<u8>::new(
-            KERNEL_LOAD_ADDR as usize,
+            kernel_load_addr as usize,
             size,
             0
         )
@@ -170,21 +423,38 @@ unsafe extern "C" fn real_start() -> ! {
 
     // Load kernel into vector space
     println("spinUP: Loading kernel...");
-    assert_eq!(
-        vector_space.get_state(),
-               UFOState::Hovering,
-               "Vector space not in hovering state"
-    );
+    if vector_space.get_state() != UFOState::Hovering {
+        return Err(BootError::VectorSpaceState {
+            expected: UFOState::Hovering,
+            actual: vector_space.get_state(),
+        });
+    }
 
     let mut aligned_buffer = AlignedMemoryRegion::new();
-    for sector in 0..SECTORS_TO_READ {
+    for sector in 0..sectors_to_read {
         let sector_offset = (sector * 512) as usize;
-        let target_addr = KERNEL_LOAD_ADDR as usize + sector_offset;
+        let target_addr = kernel_load_addr as usize + sector_offset;
+        let absolute_sector = kernel_sector_start + sector;
 
-        read_disk_sector(
-            KERNEL_SECTOR_START + sector,
-            aligned_buffer.as_mut_ptr()
-        );
+        let mut attempts = 0;
+        loop {
+            match read_disk_sector(absolute_sector, aligned_buffer.as_mut_ptr()) {
+                Ok(()) => break,
+                Err(err) => {
+                    attempts += 1;
+                    if attempts >= DISK_READ_RETRIES {
+                        return Err(err);
+                    }
+                    serial_println!(
+                        "spinUP: disk read failed for sector {}, retrying ({}/{})",
+                        absolute_sector,
+                        attempts,
+                        DISK_READ_RETRIES
+                    );
+                    reset_disk();
+                }
+            }
+        }
 
         // Copy from aligned buffer to target location
         core::ptr::copy_nonoverlapping(
@@ -205,10 +475,11 @@ unsafe extern "C" fn real_start() -> ! {
 
     // Set up boot parameters
     let boot_params = MainBootParams {
-        kernel_load_addr: KERNEL_LOAD_ADDR as u32,
-        kernel_size: (SECTORS_TO_READ as u32) * 512,
+        kernel_load_addr: kernel_load_addr as u32,
+        kernel_size: (sectors_to_read as u32) * 512,
         space_metadata: vector_space.get_metadata() as *const SpaceMetadata,
         vector_space: vector_space as *const VectorSpace,
+        device_map: &BUS as *const Bus,
     };
 
     // Log memory configuration
@@ -218,16 +489,30 @@ unsafe extern "C" fn real_start() -> ! {
 - Kernel size: {} bytes\n\
 - Vector cells: {}\n\
 - Cell size: {} bytes",
-KERNEL_LOAD_ADDR,
+kernel_load_addr,
 boot_params.kernel_size,
-MESH_DENSITY.pow(3),
+mesh_density.pow(3),
                     VECTOR_CELL_SIZE
     );
 
+    // Try to parse the staged image as an ELF64 kernel so it can be linked
+    // normally instead of as a flat binary fixed at kernel_load_addr; fall
+    // back to the raw-binary entry point when the magic doesn't match.
+    let entry_addr = match load_elf_kernel(kernel_load_addr as *const u8)? {
+        Some(entry) => {
+            serial_println!("spinUP: ELF64 kernel detected, entry at {:#x}", entry);
+            entry
+        }
+        None => {
+            serial_println!("spinUP: no ELF magic found, falling back to flat binary load");
+            kernel_load_addr
+        }
+    };
+
     println("spinUP: Jumping to kernel...");
 
     // Jump to kernel entry point
-    let kernel_entry = KERNEL_LOAD_ADDR as *const fn(*const MainBootParams) -> !;
+    let kernel_entry = entry_addr as *const fn(*const MainBootParams) -> !;
     (*kernel_entry)(&boot_params)
 }
 
@@ -5,6 +5,8 @@
 
 use core::fmt;
 
+use crate::bus::BUS;
+
 const SERIAL_PORT: u16 = 0x3F8;
 
 pub fn init_serial() {
@@ -28,15 +30,14 @@ pub fn init_serial() {
     outb(SERIAL_PORT + 4, 0x0B);
 }
 
+// Routed through the shared device bus (see `crate::bus`) instead of
+// issuing `out`/`in` directly, so COM1 access stays range-checked against
+// the same device map the bootloader documents in `MainBootParams`.
+
 #[inline]
 fn outb(port: u16, value: u8) {
     unsafe {
-        core::arch::asm!(
-            "out dx, al",
-            in("dx") port,
-                         in("al") value,
-                         options(nomem, nostack, preserves_flags)
-        );
+        let _ = BUS.write_u8(port as usize, value);
     }
 }
 
@@ -47,16 +48,7 @@ fn serial_write_byte(byte: u8) {
 
 #[inline]
 fn inb(port: u16) -> u8 {
-    let value: u8;
-    unsafe {
-        core::arch::asm!(
-            "in al, dx",
-            out("al") value,
-                         in("dx") port,
-                         options(nomem, nostack, preserves_flags)
-        );
-    }
-    value
+    unsafe { BUS.read_u8(port as usize).unwrap_or(0) }
 }
 
 pub fn serial_write_str(s: &str) {
@@ -6,6 +6,10 @@
 // Hashbrown space hashing
 pub mod spacemap;
 
+use async_trait::async_trait;
+use futures::future::join_all;
+use lazuline::CrystalRuntime;
+
 pub mod assy;
 pub mod html;
 pub mod php;
@@ -102,9 +106,36 @@ impl Scribe for TranslationState {
     }
 }
 
+/// A structured translation diagnostic carrying exactly where in the
+/// source the offending marker (or translator error) was found, instead
+/// of a bare `&'static str` with no position.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TranslateError {
+    pub message: String,
+    pub line: usize,
+    pub col: usize,
+}
+
+impl std::fmt::Display for TranslateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (line {}, col {})", self.message, self.line, self.col)
+    }
+}
+
+impl std::error::Error for TranslateError {}
+
+/// A single lexical token produced by [`UnifiedTranslator::tokenize`].
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Text(String),
+    MarkerOpen { lang: String, line: usize, col: usize },
+    MarkerClose { lang: String, line: usize, col: usize },
+}
+
 /// Unified Translator that handles different markers
 pub struct UnifiedTranslator {
     translators: SpaceMap<String, Box<dyn Translator>>,
+    runtime: CrystalRuntime,
 }
 
 impl UnifiedTranslator {
@@ -125,60 +156,484 @@ impl UnifiedTranslator {
         translators.insert("go".to_string(), Box::new(GoTranslator::new()));
         translators.insert("zig".to_string(), Box::new(ZigTranslator::new()));
 
-        Self { translators }
+        Self { translators, runtime: CrystalRuntime::new() }
+    }
+
+    /// Finds the index of the next unescaped `!` at or after `start`,
+    /// treating `\!` as a literal bang rather than a delimiter.
+    fn find_next_unescaped_bang(chars: &[char], start: usize) -> Option<usize> {
+        let mut i = start;
+        while i < chars.len() {
+            if chars[i] == '\\' && i + 1 < chars.len() && chars[i + 1] == '!' {
+                i += 2;
+                continue;
+            }
+            if chars[i] == '!' {
+                return Some(i);
+            }
+            i += 1;
+        }
+        None
+    }
+
+    /// Scans `source` into a stream of [`Token`]s, recognizing `!lang!`
+    /// marker pairs only when `lang` names a registered translator --
+    /// any other `!...!` span (and any `\!` escape) is left as literal
+    /// text, so unrelated `!` characters in the payload never break the
+    /// block boundaries.
+    fn tokenize(&self, source: &str) -> Vec<Token> {
+        let chars: Vec<char> = source.chars().collect();
+        let mut tokens = Vec::new();
+        let mut text = String::new();
+        let mut open_stack: Vec<String> = Vec::new();
+
+        let mut i = 0;
+        let mut line = 1;
+        let mut col = 1;
+
+        while i < chars.len() {
+            let c = chars[i];
+
+            if c == '\\' && i + 1 < chars.len() && chars[i + 1] == '!' {
+                text.push('!');
+                i += 2;
+                col += 2;
+                continue;
+            }
+
+            if c == '!' {
+                if let Some(end) = Self::find_next_unescaped_bang(&chars, i + 1) {
+                    let candidate: String = chars[i + 1..end].iter().collect();
+                    let lang = candidate.trim();
+
+                    if self.translators.get(lang).is_some() {
+                        if !text.is_empty() {
+                            tokens.push(Token::Text(std::mem::take(&mut text)));
+                        }
+
+                        let marker_line = line;
+                        let marker_col = col;
+
+                        if open_stack.last().map(|s| s.as_str()) == Some(lang) {
+                            open_stack.pop();
+                            tokens.push(Token::MarkerClose { lang: lang.to_string(), line: marker_line, col: marker_col });
+                        } else {
+                            open_stack.push(lang.to_string());
+                            tokens.push(Token::MarkerOpen { lang: lang.to_string(), line: marker_line, col: marker_col });
+                        }
+
+                        for consumed in &chars[i..=end] {
+                            if *consumed == '\n' {
+                                line += 1;
+                                col = 1;
+                            } else {
+                                col += 1;
+                            }
+                        }
+                        i = end + 1;
+                        continue;
+                    }
+                }
+
+                // Not a marker for a registered language -- keep the
+                // bang itself as literal text and re-examine from the
+                // very next character (so a real closing marker right
+                // after an unrelated `!` is still found).
+                text.push('!');
+                i += 1;
+                col += 1;
+                continue;
+            }
+
+            text.push(c);
+            if c == '\n' {
+                line += 1;
+                col = 1;
+            } else {
+                col += 1;
+            }
+            i += 1;
+        }
+
+        if !text.is_empty() {
+            tokens.push(Token::Text(text));
+        }
+
+        tokens
     }
 
     /// Translate code based on markers
-    pub fn translate(&mut self, source: &str) -> Result<String, &'static str> {
+    pub fn translate(&mut self, source: &str) -> Result<String, TranslateError> {
+        let tokens = self.tokenize(source);
+
         let mut result = String::new();
         let mut buffer = String::new();
         let mut inline_mode = false;
-        let mut current_translator: Option<(&str, &mut Box<dyn Translator>)> = None;
+        let mut block_stack: Vec<(String, usize, usize)> = Vec::new();
+        let mut preceded_by_text = false;
+
+        for token in tokens {
+            match token {
+                Token::Text(text) => {
+                    if !block_stack.is_empty() {
+                        buffer.push_str(&text);
+                    } else {
+                        result.push_str(&text);
+                        preceded_by_text = !text.trim().is_empty();
+                    }
+                }
+                Token::MarkerOpen { lang, line, col } => {
+                    if !block_stack.is_empty() {
+                        return Err(TranslateError {
+                            message: format!("cannot open `!{}!` block while a `!{}!` block is still open", lang, block_stack.last().unwrap().0),
+                            line,
+                            col,
+                        });
+                    }
 
-        let parts: Vec<&str> = source.split('!').collect();
+                    inline_mode = preceded_by_text;
+                    if !inline_mode {
+                        result.push_str("// Begin Translation Block\n");
+                    }
+                    block_stack.push((lang, line, col));
+                    buffer.clear();
+                }
+                Token::MarkerClose { lang, line, col } => {
+                    let (open_lang, open_line, open_col) = match block_stack.pop() {
+                        Some(entry) => entry,
+                        None => {
+                            return Err(TranslateError {
+                                message: format!("`!{}!` closing marker with no matching open marker", lang),
+                                line,
+                                col,
+                            });
+                        }
+                    };
+
+                    if open_lang != lang {
+                        return Err(TranslateError {
+                            message: format!("expected closing `!{}!` marker, found `!{}!`", open_lang, lang),
+                            line,
+                            col,
+                        });
+                    }
 
-        for (i, part) in parts.iter().enumerate() {
-            if let Some(translator_name) = self.translators.get(part.trim()) {
-                if current_translator.is_some() {
-                    // End of current translation block
-                    let (lang, translator) = current_translator.take().unwrap();
-                    let translated_content = translator.translate_line(&buffer)?;
+                    let translator = self.translators.get(&open_lang).expect("lang was validated during tokenizing");
+                    let translated_content = translator.translate_line(&buffer).map_err(|message| TranslateError {
+                        message: message.to_string(),
+                        line: open_line,
+                        col: open_col,
+                    })?;
 
                     if inline_mode {
-                        // Verify spaces around markers for inline mode
                         if !buffer.starts_with(' ') || !buffer.ends_with(' ') {
-                            return Err("Inline translation blocks must have spaces before and after the content");
+                            return Err(TranslateError {
+                                message: "Inline translation blocks must have spaces before and after the content".to_string(),
+                                line,
+                                col,
+                            });
                         }
                         let trimmed_content = buffer.trim();
-                        result.push_str(&format!("inline_{}!({});", lang, trimmed_content));
+                        result.push_str(&format!("inline_{}!({});", open_lang, trimmed_content));
                     } else {
                         result.push_str("// End Translation Block\n");
                         result.push_str(&translated_content);
                     }
 
                     buffer.clear();
-                } else {
-                    // Start of new translation block
-                    // Check if we're in inline mode (part of a larger line)
-                    inline_mode = i > 0 && !parts[i-1].trim().is_empty();
-                    if !inline_mode {
-                        result.push_str("// Begin Translation Block\n");
+                    preceded_by_text = true;
+                }
+            }
+        }
+
+        if let Some((lang, line, col)) = block_stack.last() {
+            return Err(TranslateError {
+                message: format!("Unclosed translation block - missing end marker for `!{}!`", lang),
+                line: *line,
+                col: *col,
+            });
+        }
+
+        Ok(result)
+    }
+
+    /// Tokenizes `source` once, translates each independent `!lang!` block
+    /// (the CPU-bound half every [`Translator`] impl already does), then
+    /// schedules the blocks' dispatch to the crystal runtime concurrently
+    /// instead of one at a time, reassembling the finished output back
+    /// into source order once every dispatch completes.
+    pub async fn translate_async(&mut self, source: &str) -> Result<String, TranslateError> {
+        let tokens = self.tokenize(source);
+
+        let mut segments: Vec<Result<String, TranslateError>> = Vec::new();
+        let mut pending: Vec<(usize, PendingBlock)> = Vec::new();
+
+        let mut buffer = String::new();
+        let mut inline_mode = false;
+        let mut block_stack: Vec<(String, usize, usize)> = Vec::new();
+        let mut preceded_by_text = false;
+
+        for token in tokens {
+            match token {
+                Token::Text(text) => {
+                    if !block_stack.is_empty() {
+                        buffer.push_str(&text);
+                    } else {
+                        preceded_by_text = !text.trim().is_empty();
+                        segments.push(Ok(text));
                     }
-                    current_translator = Some((part.trim(), translator_name));
                 }
-            } else if let Some((_, translator)) = &mut current_translator {
-                buffer.push_str(part);
+                Token::MarkerOpen { lang, line, col } => {
+                    if !block_stack.is_empty() {
+                        return Err(TranslateError {
+                            message: format!("cannot open `!{}!` block while a `!{}!` block is still open", lang, block_stack.last().unwrap().0),
+                            line,
+                            col,
+                        });
+                    }
+
+                    inline_mode = preceded_by_text;
+                    block_stack.push((lang, line, col));
+                    buffer.clear();
+                }
+                Token::MarkerClose { lang, line, col } => {
+                    let (open_lang, open_line, open_col) = match block_stack.pop() {
+                        Some(entry) => entry,
+                        None => {
+                            return Err(TranslateError {
+                                message: format!("`!{}!` closing marker with no matching open marker", lang),
+                                line,
+                                col,
+                            });
+                        }
+                    };
+
+                    if open_lang != lang {
+                        return Err(TranslateError {
+                            message: format!("expected closing `!{}!` marker, found `!{}!`", open_lang, lang),
+                            line,
+                            col,
+                        });
+                    }
+
+                    let index = segments.len();
+                    segments.push(Ok(String::new()));
+                    pending.push((
+                        index,
+                        PendingBlock {
+                            lang: open_lang,
+                            content: std::mem::take(&mut buffer),
+                            inline: inline_mode,
+                            open_line,
+                            open_col,
+                        },
+                    ));
+                    preceded_by_text = true;
+                }
+            }
+        }
+
+        if let Some((lang, line, col)) = block_stack.last() {
+            return Err(TranslateError {
+                message: format!("Unclosed translation block - missing end marker for `!{}!`", lang),
+                line: *line,
+                col: *col,
+            });
+        }
+
+        let mut rendered_blocks: Vec<(usize, String)> = Vec::with_capacity(pending.len());
+        for (index, block) in pending {
+            let translator = self.translators.get(&block.lang).expect("lang was validated during tokenizing");
+            let translated = translator.translate_line(&block.content).map_err(|message| TranslateError {
+                message: message.to_string(),
+                line: block.open_line,
+                col: block.open_col,
+            })?;
+
+            let rendered = if block.inline {
+                if !block.content.starts_with(' ') || !block.content.ends_with(' ') {
+                    return Err(TranslateError {
+                        message: "Inline translation blocks must have spaces before and after the content".to_string(),
+                        line: block.open_line,
+                        col: block.open_col,
+                    });
+                }
+                let trimmed_content = block.content.trim();
+                format!("inline_{}!({});", block.lang, trimmed_content)
             } else {
-                // Pass through non-translated content
-                result.push_str(part);
+                format!("// Begin Translation Block\n// End Translation Block\n{}", translated)
+            };
+
+            rendered_blocks.push((index, rendered));
+        }
+
+        let self_ref: &Self = self;
+        let dispatches = rendered_blocks.into_iter().map(|(index, rendered)| async move {
+            self_ref.translate_line_async(rendered).await.map(|text| (index, text))
+        });
+
+        for result in join_all(dispatches).await {
+            let (index, text) = result?;
+            segments[index] = Ok(text);
+        }
+
+        let pieces: Vec<String> = segments.into_iter().collect::<Result<Vec<String>, TranslateError>>()?;
+        Ok(pieces.concat())
+    }
+
+    /// Parses `source` into its block/inline segments and renders them as a
+    /// Graphviz `digraph`: one node per translation block (language plus a
+    /// truncated snippet of its payload), with edges connecting blocks in
+    /// document order. An edge leading into an inline block is dashed; an
+    /// edge leading into a full block is solid.
+    pub fn to_dot(&self, source: &str) -> Result<String, TranslateError> {
+        let tokens = self.tokenize(source);
+
+        let mut blocks: Vec<DotBlock> = Vec::new();
+        let mut buffer = String::new();
+        let mut inline_mode = false;
+        let mut block_stack: Vec<(String, usize, usize)> = Vec::new();
+        let mut preceded_by_text = false;
+
+        for token in tokens {
+            match token {
+                Token::Text(text) => {
+                    if !block_stack.is_empty() {
+                        buffer.push_str(&text);
+                    } else {
+                        preceded_by_text = !text.trim().is_empty();
+                    }
+                }
+                Token::MarkerOpen { lang, line, col } => {
+                    if !block_stack.is_empty() {
+                        return Err(TranslateError {
+                            message: format!("cannot open `!{}!` block while a `!{}!` block is still open", lang, block_stack.last().unwrap().0),
+                            line,
+                            col,
+                        });
+                    }
+
+                    inline_mode = preceded_by_text;
+                    block_stack.push((lang, line, col));
+                    buffer.clear();
+                }
+                Token::MarkerClose { lang, line, col } => {
+                    let (open_lang, _open_line, _open_col) = match block_stack.pop() {
+                        Some(entry) => entry,
+                        None => {
+                            return Err(TranslateError {
+                                message: format!("`!{}!` closing marker with no matching open marker", lang),
+                                line,
+                                col,
+                            });
+                        }
+                    };
+
+                    if open_lang != lang {
+                        return Err(TranslateError {
+                            message: format!("expected closing `!{}!` marker, found `!{}!`", open_lang, lang),
+                            line,
+                            col,
+                        });
+                    }
+
+                    blocks.push(DotBlock {
+                        lang: open_lang,
+                        snippet: Self::truncate_snippet(buffer.trim()),
+                        inline: inline_mode,
+                    });
+                    buffer.clear();
+                    preceded_by_text = true;
+                }
             }
         }
 
-        if current_translator.is_some() {
-            return Err("Unclosed translation block - missing end marker");
+        if let Some((lang, line, col)) = block_stack.last() {
+            return Err(TranslateError {
+                message: format!("Unclosed translation block - missing end marker for `!{}!`", lang),
+                line: *line,
+                col: *col,
+            });
         }
 
-        Ok(result)
+        let mut dot = String::from("digraph translation_blocks {\n");
+        for (index, block) in blocks.iter().enumerate() {
+            let label = Self::escape_dot_label(&format!("{}: {}", block.lang, block.snippet));
+            dot.push_str(&format!("    node_{} [label=\"{}\", shape=box];\n", index, label));
+        }
+        for index in 0..blocks.len().saturating_sub(1) {
+            let style = if blocks[index + 1].inline { "dashed" } else { "solid" };
+            dot.push_str(&format!("    node_{} -> node_{} [style={}];\n", index, index + 1, style));
+        }
+        dot.push_str("}\n");
+
+        Ok(dot)
+    }
+
+    /// Truncates `content` to a short preview suitable for a DOT label,
+    /// appending `...` when it was cut off.
+    fn truncate_snippet(content: &str) -> String {
+        const MAX_LEN: usize = 24;
+        if content.chars().count() <= MAX_LEN {
+            content.to_string()
+        } else {
+            let truncated: String = content.chars().take(MAX_LEN).collect();
+            format!("{}...", truncated)
+        }
+    }
+
+    /// Escapes a string for use inside a quoted DOT label.
+    fn escape_dot_label(label: &str) -> String {
+        label.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+    }
+}
+
+/// One translation block discovered while walking the token stream for
+/// [`UnifiedTranslator::to_dot`], already reduced to what the graph needs:
+/// its language, a truncated preview of its payload, and whether it was
+/// rendered inline.
+struct DotBlock {
+    lang: String,
+    snippet: String,
+    inline: bool,
+}
+
+/// One independently-translatable block discovered while tokenizing,
+/// carried alongside its position so [`UnifiedTranslator::translate_async`]
+/// can dispatch every block concurrently and still reassemble the final
+/// source in order.
+struct PendingBlock {
+    lang: String,
+    content: String,
+    inline: bool,
+    open_line: usize,
+    open_col: usize,
+}
+
+/// Non-blocking counterpart to [`Translator`], mirroring a client's
+/// blocking/non-blocking split: the blocking path stays on
+/// [`Translator::translate_line`], while this trait hands a block's bytes
+/// to the crystal runtime instead of running synchronously on the caller's
+/// thread.
+#[async_trait]
+pub trait AsyncTranslator {
+    async fn translate_line_async(&self, line: String) -> Result<String, TranslateError>;
+}
+
+#[async_trait]
+impl AsyncTranslator for UnifiedTranslator {
+    /// Hands `line` to the crystal runtime and awaits its completion.
+    /// `crystal_core_process_task` has no return channel of its own, so
+    /// the line's content is already fully translated by the time it
+    /// reaches here (see [`UnifiedTranslator::translate_async`]) -- this
+    /// call is purely the non-blocking dispatch half of the split.
+    async fn translate_line_async(&self, line: String) -> Result<String, TranslateError> {
+        self.runtime.spawn(line.clone().into_bytes()).await.map_err(|err| TranslateError {
+            message: format!("crystal runtime dispatch failed: {}", err),
+            line: 0,
+            col: 0,
+        })?;
+        Ok(line)
     }
 }
 
@@ -265,6 +720,76 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_unclosed_block_error_points_at_the_open_marker() {
+        let mut translator = UnifiedTranslator::new();
+        let source = "ok\n!sql! SELECT * FROM users";
+        let err = translator.translate(source).unwrap_err();
+        assert_eq!(err.line, 2);
+        assert_eq!(err.col, 1);
+    }
+
+    #[test]
+    fn test_unregistered_marker_name_is_left_as_literal_text() {
+        let mut translator = UnifiedTranslator::new();
+        let source = "!invalid!test!invalid!";
+        let result = translator.translate(source).unwrap();
+        assert_eq!(result, source);
+    }
+
+    #[test]
+    fn test_bare_bang_inside_payload_does_not_break_the_block() {
+        let mut translator = UnifiedTranslator::new();
+        let source = r#"!bash! echo "hi!" !bash!"#;
+        let result = translator.translate(source);
+        assert!(result.is_ok(), "a literal ! inside the payload should not be mistaken for a marker");
+    }
+
+    #[test]
+    fn test_escaped_bang_is_kept_literal_in_the_payload() {
+        let mut translator = UnifiedTranslator::new();
+        let source = r#"!bash! echo \! !bash!"#;
+        let result = translator.translate(source).unwrap();
+        assert!(result.contains("println!(\"!\");"));
+    }
+
+    #[test]
+    fn test_interleaved_markers_are_rejected_precisely() {
+        let mut translator = UnifiedTranslator::new();
+        let source = "!bash! one !sql! two !bash! !sql!";
+        let err = translator.translate(source).unwrap_err();
+        assert!(err.message.contains("bash"));
+        assert!(err.message.contains("sql"));
+    }
+
+    #[test]
+    fn test_to_dot_emits_a_node_per_block_and_an_edge_between_them() {
+        let translator = UnifiedTranslator::new();
+        let source = "!bash! echo hi !bash! and !sql! SELECT 1 !sql!";
+        let dot = translator.to_dot(source).unwrap();
+        assert!(dot.starts_with("digraph translation_blocks {"));
+        assert!(dot.contains("node_0 [label=\"bash: echo hi\""));
+        assert!(dot.contains("node_1 [label=\"sql: SELECT 1\""));
+        assert!(dot.contains("node_0 -> node_1"));
+    }
+
+    #[test]
+    fn test_to_dot_escapes_quotes_and_backslashes_in_labels() {
+        let translator = UnifiedTranslator::new();
+        let source = r#"!bash! echo "hi" !bash!"#;
+        let dot = translator.to_dot(source).unwrap();
+        assert!(dot.contains("\\\"hi\\\""));
+    }
+
+    #[tokio::test]
+    async fn test_translate_async_matches_blocking_translation_for_independent_blocks() {
+        let mut translator = UnifiedTranslator::new();
+        let source = "!bash! echo hi !bash! and !sql! SELECT 1 !sql!";
+        let sync_result = translator.translate(source).unwrap();
+        let async_result = translator.translate_async(source).await.unwrap();
+        assert_eq!(sync_result, async_result);
+    }
+
     #[test]
     fn test_zig_translation() {
         let mut translator = UnifiedTranslator::new();
@@ -1,3 +1,6 @@
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+
 use crate::quantum::{Quantum, QUANTUM_COHERENCE_THRESHOLD};
 use crate::scribe::{Scribe, ScribePrecision, QuantumString};
 use crate::state::TranslationState;
@@ -11,12 +14,437 @@ pub enum PrologState {
     Error,
 }
 
+/// Whether a [`PrologError`] stops translation or is just advisory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+/// A specific way a clause failed to translate or looked suspicious,
+/// independent of where it occurred -- the location lives on
+/// [`PrologError`] so variants don't each have to repeat it.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PrologErrorKind {
+    /// The `!prolog!` / `!prolog!` block markers didn't balance
+    UnbalancedBlockMarkers,
+    /// A `:-` rule didn't split into exactly one head and one body
+    MalformedRule { found_arrows: usize },
+    /// A term opened a delimiter (e.g. `(`) that was never closed
+    UnterminatedTerm,
+    /// A non-anonymous variable occurred exactly once in its clause --
+    /// almost always a typo for another variable's name
+    SingletonVariable { variable: String },
+}
+
+impl PrologErrorKind {
+    /// A static summary, for call sites -- like the shared [`Translator`]
+    /// trait -- that can only carry a `&'static str`.
+    fn as_static_str(&self) -> &'static str {
+        match self {
+            Self::UnbalancedBlockMarkers => "Invalid Prolog code block markers",
+            Self::MalformedRule { .. } => "Invalid rule format",
+            Self::UnterminatedTerm => "Unterminated Prolog term",
+            Self::SingletonVariable { .. } => "Singleton variable",
+        }
+    }
+}
+
+/// A single point in the source, reported as 1-indexed line/column the
+/// way editors and rustc do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourceLocation {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// A structured translation diagnostic carrying exactly where in the
+/// source it happened, instead of the bare `&'static str` the
+/// translator used to return.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrologError {
+    pub kind: PrologErrorKind,
+    pub severity: Severity,
+    pub location: SourceLocation,
+    /// Byte-offset spans within the offending line, each with a short
+    /// label, rendered as caret-underlines by [`PrologError::render`]
+    pub labels: Vec<(Range<usize>, String)>,
+    pub expected: Option<String>,
+    pub found: Option<String>,
+}
+
+impl PrologError {
+    fn new(kind: PrologErrorKind, location: SourceLocation) -> Self {
+        Self {
+            kind,
+            severity: Severity::Error,
+            location,
+            labels: Vec::new(),
+            expected: None,
+            found: None,
+        }
+    }
+
+    fn new_warning(kind: PrologErrorKind, location: SourceLocation) -> Self {
+        Self { severity: Severity::Warning, ..Self::new(kind, location) }
+    }
+
+    fn with_label(mut self, span: Range<usize>, label: impl Into<String>) -> Self {
+        self.labels.push((span, label.into()));
+        self
+    }
+
+    fn with_expected_found(mut self, expected: impl Into<String>, found: impl Into<String>) -> Self {
+        self.expected = Some(expected.into());
+        self.found = Some(found.into());
+        self
+    }
+
+    /// Renders a caret-underlined snippet pointing at this diagnostic's
+    /// labeled spans within `line_text`, in the style of rustc's
+    /// region-conflict diagnostics.
+    pub fn render(&self, line_text: &str) -> String {
+        let severity = match self.severity {
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        };
+        let mut out = format!(
+            "{}: {}\n --> line {}, column {}\n  | {}\n",
+            severity, self.kind.as_static_str(), self.location.line, self.location.column, line_text
+        );
+
+        for (span, label) in &self.labels {
+            let mut carets = String::with_capacity(line_text.len());
+            for i in 0..line_text.len() {
+                carets.push(if span.contains(&i) { '^' } else { ' ' });
+            }
+            out.push_str(&format!("  | {} {}\n", carets, label));
+        }
+
+        if let (Some(expected), Some(found)) = (&self.expected, &self.found) {
+            out.push_str(&format!("  = expected {}, found {}\n", expected, found));
+        }
+
+        out
+    }
+}
+
+/// A lexical token within one line of Prolog source
+#[derive(Debug, Clone, PartialEq)]
+struct Token {
+    kind: TokenKind,
+    text: String,
+    span: Range<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TokenKind {
+    Atom,
+    Variable,
+    Number,
+    LParen,
+    RParen,
+    Comma,
+    Arrow,
+    Dot,
+}
+
+/// Splits one line of Prolog source into tokens. Identifiers starting
+/// with an uppercase letter or `_` are [`TokenKind::Variable`]s,
+/// everything else alphabetic is an [`TokenKind::Atom`] -- the same
+/// distinction Prolog's own reader makes.
+fn tokenize(line: &str) -> Vec<Token> {
+    let chars: Vec<(usize, char)> = line.char_indices().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let (start, ch) = chars[i];
+
+        if ch.is_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        if ch == ':' && chars.get(i + 1).map(|(_, c)| *c) == Some('-') {
+            let end = chars.get(i + 2).map(|(offset, _)| *offset).unwrap_or(line.len());
+            tokens.push(Token { kind: TokenKind::Arrow, text: ":-".to_string(), span: start..end });
+            i += 2;
+            continue;
+        }
+
+        if let Some(kind) = match ch {
+            '(' => Some(TokenKind::LParen),
+            ')' => Some(TokenKind::RParen),
+            ',' => Some(TokenKind::Comma),
+            '.' => Some(TokenKind::Dot),
+            _ => None,
+        } {
+            let end = chars.get(i + 1).map(|(offset, _)| *offset).unwrap_or(line.len());
+            tokens.push(Token { kind, text: ch.to_string(), span: start..end });
+            i += 1;
+            continue;
+        }
+
+        if ch.is_alphabetic() || ch == '_' {
+            let mut j = i;
+            while j < chars.len() && (chars[j].1.is_alphanumeric() || chars[j].1 == '_') {
+                j += 1;
+            }
+            let end = chars.get(j).map(|(offset, _)| *offset).unwrap_or(line.len());
+            let text = line[start..end].to_string();
+            let kind = if ch.is_uppercase() || ch == '_' { TokenKind::Variable } else { TokenKind::Atom };
+            tokens.push(Token { kind, text, span: start..end });
+            i = j;
+            continue;
+        }
+
+        if ch.is_numeric() {
+            let mut j = i;
+            while j < chars.len() && chars[j].1.is_numeric() {
+                j += 1;
+            }
+            let end = chars.get(j).map(|(offset, _)| *offset).unwrap_or(line.len());
+            tokens.push(Token { kind: TokenKind::Number, text: line[start..end].to_string(), span: start..end });
+            i = j;
+            continue;
+        }
+
+        // Unrecognized punctuation (operators other than `:-` etc.) is
+        // skipped rather than rejected outright -- this translator only
+        // needs to recognize terms and the rule arrow.
+        i += 1;
+    }
+
+    tokens
+}
+
+/// Indices, within `tokens`, of every `:-` that appears outside of any
+/// parenthesized compound term -- the only arrows that can plausibly be
+/// the clause's own rule operator.
+fn top_level_arrow_positions(tokens: &[Token]) -> Vec<usize> {
+    let mut depth = 0i32;
+    let mut positions = Vec::new();
+    for (index, token) in tokens.iter().enumerate() {
+        match token.kind {
+            TokenKind::LParen => depth += 1,
+            TokenKind::RParen => depth -= 1,
+            TokenKind::Arrow if depth == 0 => positions.push(index),
+            _ => {}
+        }
+    }
+    positions
+}
+
+/// A parsed Prolog term: an atom, a variable occurrence, a number, or a
+/// compound term (a functor applied to argument terms).
+#[derive(Debug, Clone, PartialEq)]
+enum Term {
+    Atom(String),
+    Number(String),
+    Variable { name: String, span: Range<usize> },
+    Compound { functor: String, args: Vec<Term> },
+}
+
+impl Term {
+    fn render(&self) -> String {
+        match self {
+            Self::Atom(name) => name.clone(),
+            Self::Number(digits) => digits.clone(),
+            Self::Variable { name, .. } => name.clone(),
+            Self::Compound { functor, args } => {
+                let rendered_args: Vec<String> = args.iter().map(Term::render).collect();
+                format!("{}({})", functor, rendered_args.join(", "))
+            }
+        }
+    }
+}
+
+/// A parsed clause: a head term plus, for rules, the list of body goals.
+/// Facts are represented with an empty body.
+struct Clause {
+    head: Term,
+    body: Vec<Term>,
+}
+
+/// A predicate identity for the purposes of the call graph: Prolog
+/// distinguishes predicates by name *and* arity, so `parent/2` and
+/// `parent/3` are graphed as separate nodes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct PredicateId {
+    name: String,
+    arity: usize,
+}
+
+impl PredicateId {
+    fn render(&self) -> String {
+        format!("{}/{}", self.name, self.arity)
+    }
+}
+
+/// The predicate a term invokes, or `None` for terms (variables,
+/// numbers) that can't head a clause or appear as a goal.
+fn predicate_id(term: &Term) -> Option<PredicateId> {
+    match term {
+        Term::Atom(name) => Some(PredicateId { name: name.clone(), arity: 0 }),
+        Term::Compound { functor, args } => Some(PredicateId { name: functor.clone(), arity: args.len() }),
+        Term::Variable { .. } | Term::Number(_) => None,
+    }
+}
+
+/// Whether a rendered predicate graph is a Graphviz `digraph` (directed
+/// edges, for the rule-head-calls-body-predicate relationship) or a
+/// plain `graph` (undirected, for a symmetric co-occurrence view).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphKind {
+    Directed,
+    Undirected,
+}
+
+impl GraphKind {
+    fn graph_keyword(&self) -> &'static str {
+        match self {
+            Self::Directed => "digraph",
+            Self::Undirected => "graph",
+        }
+    }
+
+    fn edge_operator(&self) -> &'static str {
+        match self {
+            Self::Directed => "->",
+            Self::Undirected => "--",
+        }
+    }
+}
+
+/// A simple recursive-descent reader over a token slice, used to parse
+/// one term or a top-level comma-separated goal list at a time.
+struct Parser<'a> {
+    tokens: &'a [Token],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(tokens: &'a [Token]) -> Self {
+        Self { tokens, pos: 0 }
+    }
+
+    fn peek_kind(&self) -> Option<TokenKind> {
+        self.tokens.get(self.pos).map(|t| t.kind)
+    }
+
+    fn advance(&mut self) -> Option<&Token> {
+        let token = self.tokens.get(self.pos);
+        self.pos += 1;
+        token
+    }
+
+    /// Parses a single term, returning `None` if the tokens don't form
+    /// one -- most notably when a compound term's `(` is never closed.
+    fn parse_term(&mut self) -> Option<Term> {
+        let token = self.advance()?.clone();
+        match token.kind {
+            TokenKind::Variable => Some(Term::Variable { name: token.text, span: token.span }),
+            TokenKind::Number => Some(Term::Number(token.text)),
+            TokenKind::Atom => {
+                if self.peek_kind() != Some(TokenKind::LParen) {
+                    return Some(Term::Atom(token.text));
+                }
+                self.advance(); // consume `(`
+                let mut args = Vec::new();
+                loop {
+                    args.push(self.parse_term()?);
+                    match self.peek_kind() {
+                        Some(TokenKind::Comma) => {
+                            self.advance();
+                        }
+                        Some(TokenKind::RParen) => {
+                            self.advance();
+                            break;
+                        }
+                        _ => return None, // never saw the closing `)`
+                    }
+                }
+                Some(Term::Compound { functor: token.text, args })
+            }
+            _ => None,
+        }
+    }
+
+    /// Parses a comma-separated list of top-level goals, as found in a
+    /// rule body.
+    fn parse_goal_list(&mut self) -> Vec<Term> {
+        let mut goals = Vec::new();
+        while self.peek_kind().is_some() {
+            match self.parse_term() {
+                Some(term) => goals.push(term),
+                None => break,
+            }
+            if self.peek_kind() == Some(TokenKind::Comma) {
+                self.advance();
+            } else {
+                break;
+            }
+        }
+        goals
+    }
+}
+
+/// Collects every variable occurrence in `term`, depth-first, into
+/// `occurrences` -- the dataflow walk a liveness analysis performs over
+/// an AST, specialized to "does this variable appear more than once".
+fn collect_variable_occurrences(term: &Term, occurrences: &mut Vec<(String, Range<usize>)>) {
+    match term {
+        Term::Variable { name, span } => occurrences.push((name.clone(), span.clone())),
+        Term::Compound { args, .. } => {
+            for arg in args {
+                collect_variable_occurrences(arg, occurrences);
+            }
+        }
+        Term::Atom(_) | Term::Number(_) => {}
+    }
+}
+
+/// Finds every non-anonymous variable (one not starting with `_`) that
+/// occurs exactly once across a clause's head and body -- the standard
+/// Prolog singleton-variable safety check, which catches typos like
+/// `parent(X, Y) :- ancestor(X, Z)` where `Y`/`Z` are singletons.
+fn find_singleton_variables(clause: &Clause) -> Vec<(String, Range<usize>)> {
+    let mut occurrences = Vec::new();
+    collect_variable_occurrences(&clause.head, &mut occurrences);
+    for goal in &clause.body {
+        collect_variable_occurrences(goal, &mut occurrences);
+    }
+
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for (name, _) in &occurrences {
+        *counts.entry(name.as_str()).or_insert(0) += 1;
+    }
+
+    occurrences
+    .into_iter()
+    .filter(|(name, _)| !name.starts_with('_') && counts[name.as_str()] == 1)
+    .collect()
+}
+
 /// Translator for converting Prolog code to Rust
 pub struct PrologTranslator {
     state: TranslationState,
     prolog_state: PrologState,
     quantum_stability: f64,
     indentation_level: usize,
+    /// 1-indexed line the translator is currently processing, used to
+    /// attach a location to any [`PrologError`] a helper returns
+    line_number: usize,
+    /// Non-fatal diagnostics (currently just singleton-variable
+    /// warnings) collected during the last [`PrologTranslator::translate`]
+    warnings: Vec<PrologError>,
+    /// Predicates seen as a clause head or body goal during the last
+    /// [`PrologTranslator::translate`], for [`PrologTranslator::predicate_graph`]
+    predicate_nodes: HashSet<PredicateId>,
+    /// `(head, body goal)` pairs recorded during the last
+    /// [`PrologTranslator::translate`], for [`PrologTranslator::predicate_graph`]
+    predicate_edges: Vec<(PredicateId, PredicateId)>,
 }
 
 impl PrologTranslator {
@@ -27,14 +455,50 @@ impl PrologTranslator {
             prolog_state: PrologState::Parsing,
             quantum_stability: 1.0,
             indentation_level: 0,
+            line_number: 0,
+            warnings: Vec::new(),
+            predicate_nodes: HashSet::new(),
+            predicate_edges: Vec::new(),
         }
     }
 
+    /// Warnings collected while translating the most recent source block
+    pub fn warnings(&self) -> &[PrologError] {
+        &self.warnings
+    }
+
+    /// Renders the predicate call graph built from the most recent
+    /// [`PrologTranslator::translate`] call as a Graphviz `digraph`/`graph`:
+    /// one node per `name/arity` predicate, and an edge from a rule's head
+    /// predicate to every predicate invoked in its body.
+    pub fn predicate_graph(&self, kind: GraphKind) -> String {
+        let mut nodes: Vec<String> = self.predicate_nodes.iter().map(PredicateId::render).collect();
+        nodes.sort();
+
+        let mut out = format!("{} prolog_predicates {{\n", kind.graph_keyword());
+        for node in &nodes {
+            out.push_str(&format!("    \"{}\";\n", node));
+        }
+        for (from, to) in &self.predicate_edges {
+            out.push_str(&format!("    \"{}\" {} \"{}\";\n", from.render(), kind.edge_operator(), to.render()));
+        }
+        out.push_str("}\n");
+        out
+    }
+
     /// Translates a block of Prolog code to Rust
-    pub fn translate(&mut self, source: &str) -> Result<String, &'static str> {
+    pub fn translate(&mut self, source: &str) -> Result<String, PrologError> {
+        self.warnings.clear();
+        self.predicate_nodes.clear();
+        self.predicate_edges.clear();
+
         if !source.starts_with("!prolog!") || !source.ends_with("!prolog!") {
             self.prolog_state = PrologState::Error;
-            return Err("Invalid Prolog code block markers");
+            return Err(PrologError::new(
+                PrologErrorKind::UnbalancedBlockMarkers,
+                SourceLocation { line: self.line_number.max(1), column: 1 },
+            )
+            .with_label(0..source.len().min(8), "block should open and close with `!prolog!`"));
         }
 
         // Extract the Prolog code between the markers
@@ -46,7 +510,8 @@ impl PrologTranslator {
         .trim();
 
         let mut result = String::new();
-        for line in prolog_code.lines() {
+        for (index, line) in prolog_code.lines().enumerate() {
+            self.line_number = index + 1;
             let translated = self.process_line(line)?;
             result.push_str(&translated);
             result.push('\n');
@@ -56,7 +521,8 @@ impl PrologTranslator {
         Ok(result)
     }
 
-    fn process_line(&mut self, line: &str) -> Result<String, &'static str> {
+    fn process_line(&mut self, line: &str) -> Result<String, PrologError> {
+        let column = line.len() - line.trim_start().len() + 1;
         let trimmed = line.trim();
 
         if trimmed.is_empty() {
@@ -71,11 +537,11 @@ impl PrologTranslator {
         let translated = if trimmed.starts_with("%") {
             self.translate_comment(trimmed)?
         } else if trimmed.ends_with(":-") {
-            self.translate_rule_head(trimmed)?
+            self.translate_rule_head(trimmed, column)?
         } else if trimmed.ends_with(".") {
-            self.translate_fact(trimmed)?
+            self.translate_fact(trimmed, column)?
         } else if trimmed.contains(":-") {
-            self.translate_rule(trimmed)?
+            self.translate_rule(trimmed, column)?
         } else {
             self.translate_query(trimmed)?
         };
@@ -89,33 +555,103 @@ impl PrologTranslator {
     }
 
     // Translation helper methods
-    fn translate_comment(&self, line: &str) -> Result<String, &'static str> {
+    fn translate_comment(&self, line: &str) -> Result<String, PrologError> {
         Ok(format!("// {}", line.trim_start_matches('%').trim()))
     }
 
-    fn translate_fact(&self, line: &str) -> Result<String, &'static str> {
-        let fact = line.trim_end_matches('.');
-        Ok(format!("fact!({});", fact))
+    fn translate_fact(&mut self, line: &str, column: usize) -> Result<String, PrologError> {
+        let mut tokens = tokenize(line);
+        if matches!(tokens.last(), Some(t) if t.kind == TokenKind::Dot) {
+            tokens.pop();
+        }
+
+        let head = Parser::new(&tokens).parse_term().ok_or_else(|| self.unterminated_term_error(column))?;
+        let clause = Clause { head: head.clone(), body: Vec::new() };
+        self.record_singleton_warnings(&clause, column);
+        self.record_predicate_graph(&clause);
+        Ok(format!("fact!({});", head.render()))
     }
 
-    fn translate_rule_head(&self, line: &str) -> Result<String, &'static str> {
-        let head = line.trim_end_matches(":-");
-        Ok(format!("rule!({}) {{", head))
+    fn translate_rule_head(&self, line: &str, column: usize) -> Result<String, PrologError> {
+        let mut tokens = tokenize(line);
+        if matches!(tokens.last(), Some(t) if t.kind == TokenKind::Arrow) {
+            tokens.pop();
+        }
+
+        let head = Parser::new(&tokens).parse_term().ok_or_else(|| self.unterminated_term_error(column))?;
+        Ok(format!("rule!({}) {{", head.render()))
     }
 
-    fn translate_rule(&self, line: &str) -> Result<String, &'static str> {
-        let parts: Vec<&str> = line.split(":-").collect();
-        if parts.len() != 2 {
-            return Err("Invalid rule format");
+    fn translate_rule(&mut self, line: &str, column: usize) -> Result<String, PrologError> {
+        let tokens = tokenize(line);
+        let arrow_indices = top_level_arrow_positions(&tokens);
+
+        if arrow_indices.len() != 1 {
+            let mut error = PrologError::new(
+                PrologErrorKind::MalformedRule { found_arrows: arrow_indices.len() },
+                SourceLocation { line: self.line_number, column },
+            )
+            .with_expected_found("exactly one `:-` rule operator", format!("{} `:-` operators", arrow_indices.len()));
+
+            if let (Some(&first), Some(&second)) = (arrow_indices.first(), arrow_indices.get(1)) {
+                error = error
+                .with_label(tokens[first].span.clone(), "first `:-` here")
+                .with_label(tokens[second].span.clone(), "second `:-` conflicts with this one, rule body begins here");
+            }
+            return Err(error);
         }
-        let head = parts[0].trim();
-        let body = parts[1].trim().trim_end_matches('.');
-        Ok(format!("rule!({}) {{ {}", head, body))
+
+        let arrow_index = arrow_indices[0];
+        let head_tokens = &tokens[..arrow_index];
+        let mut body_tokens = &tokens[arrow_index + 1..];
+        if matches!(body_tokens.last(), Some(t) if t.kind == TokenKind::Dot) {
+            body_tokens = &body_tokens[..body_tokens.len() - 1];
+        }
+
+        let head = Parser::new(head_tokens).parse_term().ok_or_else(|| self.unterminated_term_error(column))?;
+        let body = Parser::new(body_tokens).parse_goal_list();
+
+        let clause = Clause { head: head.clone(), body: body.clone() };
+        self.record_singleton_warnings(&clause, column);
+        self.record_predicate_graph(&clause);
+
+        let rendered_body: Vec<String> = body.iter().map(Term::render).collect();
+        Ok(format!("rule!({}) {{ {}", head.render(), rendered_body.join(", ")))
     }
 
-    fn translate_query(&self, line: &str) -> Result<String, &'static str> {
+    fn translate_query(&self, line: &str) -> Result<String, PrologError> {
         Ok(format!("query!({});", line))
     }
+
+    fn unterminated_term_error(&self, column: usize) -> PrologError {
+        PrologError::new(
+            PrologErrorKind::UnterminatedTerm,
+            SourceLocation { line: self.line_number, column },
+        )
+        .with_label(0..1, "term opened here is never closed")
+    }
+
+    fn record_singleton_warnings(&mut self, clause: &Clause, column: usize) {
+        for (variable, span) in find_singleton_variables(clause) {
+            self.warnings.push(
+                PrologError::new_warning(
+                    PrologErrorKind::SingletonVariable { variable: variable.clone() },
+                    SourceLocation { line: self.line_number, column: column + span.start },
+                )
+                .with_label(span, format!("`{}` is used only here", variable))
+            );
+        }
+    }
+
+    fn record_predicate_graph(&mut self, clause: &Clause) {
+        let Some(head_id) = predicate_id(&clause.head) else { return };
+        self.predicate_nodes.insert(head_id.clone());
+        for goal in &clause.body {
+            let Some(goal_id) = predicate_id(goal) else { continue };
+            self.predicate_nodes.insert(goal_id.clone());
+            self.predicate_edges.push((head_id.clone(), goal_id));
+        }
+    }
 }
 
 impl Quantum for PrologTranslator {
@@ -151,7 +687,7 @@ impl Scribe for PrologTranslator {
 
 impl Translator for PrologTranslator {
     fn translate_line(&mut self, line: &str) -> Result<String, &'static str> {
-        self.process_line(line)
+        self.process_line(line).map_err(|e| e.kind.as_static_str())
     }
 }
 
@@ -173,6 +709,8 @@ mod tests {
         let source = "!prolog! grandparent(X, Y) :- parent(X, Z), parent(Z, Y). !prolog!";
         let result = translator.translate(source).unwrap();
         assert!(result.contains("rule!(grandparent(X, Y))"));
+        assert!(result.contains("parent(X, Z), parent(Z, Y)"));
+        assert!(translator.warnings().is_empty());
     }
 
     #[test]
@@ -204,5 +742,98 @@ mod tests {
         let source = "!prolog! parent(john, mary).";
         let result = translator.translate(source);
         assert!(result.is_err());
+        assert_eq!(result.unwrap_err().kind, PrologErrorKind::UnbalancedBlockMarkers);
+    }
+
+    #[test]
+    fn test_malformed_rule_reports_both_arrows() {
+        let mut translator = PrologTranslator::new();
+        let source = "!prolog! a :- b :- c !prolog!";
+        let err = translator.translate(source).unwrap_err();
+        assert_eq!(err.kind, PrologErrorKind::MalformedRule { found_arrows: 2 });
+        assert_eq!(err.labels.len(), 2);
+    }
+
+    #[test]
+    fn test_unterminated_term_reports_location() {
+        let mut translator = PrologTranslator::new();
+        let source = "!prolog! parent(john, mary. !prolog!";
+        let err = translator.translate(source).unwrap_err();
+        assert_eq!(err.kind, PrologErrorKind::UnterminatedTerm);
+        assert_eq!(err.location.line, 1);
+    }
+
+    #[test]
+    fn test_error_render_includes_location_and_carets() {
+        let mut translator = PrologTranslator::new();
+        let source = "!prolog! a :- b :- c !prolog!";
+        let err = translator.translate(source).unwrap_err();
+        let rendered = err.render("a :- b :- c");
+        assert!(rendered.contains("line 1, column 1"));
+        assert!(rendered.contains('^'));
+    }
+
+    #[test]
+    fn test_singleton_variable_is_reported_as_a_warning() {
+        let mut translator = PrologTranslator::new();
+        let source = "!prolog! parent(X, Y) :- ancestor(X, Z) !prolog!";
+        let _ = translator.translate(source).unwrap();
+        let warnings = translator.warnings();
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings.iter().all(|w| w.severity == Severity::Warning));
+        assert!(warnings.iter().any(|w| matches!(
+            &w.kind, PrologErrorKind::SingletonVariable { variable } if variable == "Y"
+        )));
+        assert!(warnings.iter().any(|w| matches!(
+            &w.kind, PrologErrorKind::SingletonVariable { variable } if variable == "Z"
+        )));
+    }
+
+    #[test]
+    fn test_anonymous_variable_is_never_flagged_as_singleton() {
+        let mut translator = PrologTranslator::new();
+        let source = "!prolog! parent(_, Y) :- ancestor(Y, _) !prolog!";
+        let _ = translator.translate(source).unwrap();
+        assert!(translator.warnings().is_empty());
+    }
+
+    #[test]
+    fn test_nested_compound_terms_do_not_break_rule_splitting() {
+        let mut translator = PrologTranslator::new();
+        let source = "!prolog! total(X) :- sum(pair(X, Y), add(Y, 1)) !prolog!";
+        let result = translator.translate(source).unwrap();
+        assert!(result.contains("rule!(total(X))"));
+        assert!(result.contains("sum(pair(X, Y), add(Y, 1))"));
+    }
+
+    #[test]
+    fn test_predicate_graph_has_edge_from_rule_head_to_each_body_predicate() {
+        let mut translator = PrologTranslator::new();
+        let source = "!prolog! grandparent(X, Y) :- parent(X, Z), parent(Z, Y) !prolog!";
+        let _ = translator.translate(source).unwrap();
+        let dot = translator.predicate_graph(GraphKind::Directed);
+        assert!(dot.starts_with("digraph prolog_predicates {\n"));
+        assert!(dot.contains("\"grandparent/2\" -> \"parent/2\";"));
+        assert!(dot.contains("\"parent/2\";"));
+    }
+
+    #[test]
+    fn test_predicate_graph_undirected_uses_graph_keyword_and_operator() {
+        let mut translator = PrologTranslator::new();
+        let source = "!prolog! grandparent(X, Y) :- parent(X, Y) !prolog!";
+        let _ = translator.translate(source).unwrap();
+        let dot = translator.predicate_graph(GraphKind::Undirected);
+        assert!(dot.starts_with("graph prolog_predicates {\n"));
+        assert!(dot.contains("\"grandparent/2\" -- \"parent/2\";"));
+    }
+
+    #[test]
+    fn test_predicate_graph_resets_between_translate_calls() {
+        let mut translator = PrologTranslator::new();
+        let _ = translator.translate("!prolog! parent(john, mary). !prolog!").unwrap();
+        let _ = translator.translate("!prolog! sibling(al, bo). !prolog!").unwrap();
+        let dot = translator.predicate_graph(GraphKind::Directed);
+        assert!(!dot.contains("parent/2"));
+        assert!(dot.contains("sibling/2"));
     }
 }
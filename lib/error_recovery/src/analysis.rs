@@ -4,13 +4,31 @@
 
 use crate::{
     validation::{ValidationRegistry, ValidationResult, ValidationSeverity},
-    RecoveryStrategy,
+    RecoveryError, RecoveryStrategy,
 };
 use error_integration::context::ErrorContext;
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
 
+/// Default cooldown a tripped circuit breaker waits out before allowing a
+/// half-open probe attempt; see [`RecoveryAnalyzer::with_circuit_cooldown`].
+const DEFAULT_CIRCUIT_COOLDOWN: Duration = Duration::from_secs(30);
+
+/// Per-pattern circuit breaker state
+///
+/// Mirrors the standard closed/open/half-open breaker: `Closed` lets
+/// attempts through, `Open` rejects them until `opened_at + cooldown`
+/// elapses, and `HalfOpen` allows exactly one probe attempt to decide
+/// whether to close the breaker again or reopen it.
+#[derive(Debug, Clone, Copy)]
+enum BreakerState {
+    Closed,
+    Open { opened_at: Instant },
+    HalfOpen,
+}
+
 /// Represents a pattern of errors and their recovery behavior
 #[derive(Debug, Clone)]
 pub struct ErrorPattern {
@@ -33,6 +51,8 @@ pub struct AnalysisResult {
 pub struct RecoveryAnalyzer {
     patterns: Arc<RwLock<HashMap<String, ErrorPattern>>>,
     validation_registry: ValidationRegistry,
+    circuit_breakers: Arc<RwLock<HashMap<String, BreakerState>>>,
+    circuit_cooldown: Duration,
 }
 
 impl RecoveryAnalyzer {
@@ -40,9 +60,20 @@ impl RecoveryAnalyzer {
         Self {
             patterns: Arc::new(RwLock::new(HashMap::new())),
             validation_registry,
+            circuit_breakers: Arc::new(RwLock::new(HashMap::new())),
+            circuit_cooldown: DEFAULT_CIRCUIT_COOLDOWN,
         }
     }
 
+    /// Overrides the circuit breaker cooldown (`new` defaults to 30s)
+    ///
+    /// Mainly useful in tests that need to observe the half-open transition
+    /// without waiting out the real default.
+    pub fn with_circuit_cooldown(mut self, cooldown: Duration) -> Self {
+        self.circuit_cooldown = cooldown;
+        self
+    }
+
     pub async fn record_pattern(
         &self,
         error: &(dyn std::error::Error + Send + Sync),
@@ -110,12 +141,131 @@ impl RecoveryAnalyzer {
         }
     }
 
-    fn generate_pattern_id(&self, error: &(dyn std::error::Error + Send + Sync)) -> String {
-        use std::hash::{Hash, Hasher};
-        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    /// Drives `strategy.attempt_recovery` against `error`, retrying with
+    /// exponential backoff and jitter up to `strategy.config().max_attempts`
+    /// times, recording every outcome via [`Self::record_pattern`]
+    ///
+    /// Each error pattern has its own circuit breaker: once a pattern has
+    /// been seen at least 10 times with a success rate under 50%, further
+    /// calls short-circuit into [`RecoveryError::CircuitOpen`] instead of
+    /// hammering a strategy that's clearly not working, until the cooldown
+    /// configured via [`Self::with_circuit_cooldown`] elapses and a single
+    /// half-open probe attempt is let through.
+    pub async fn execute_with_recovery<S>(
+        &self,
+        strategy: &S,
+        error: &S::Error,
+        context: &ErrorContext,
+    ) -> Result<(), RecoveryError>
+    where
+        S: RecoveryStrategy,
+    {
+        let pattern_id = self.generate_pattern_id(error);
+
+        if let Some(retry_after) = self.circuit_block(&pattern_id).await {
+            return Err(RecoveryError::CircuitOpen {
+                pattern_id,
+                retry_after,
+            });
+        }
+
+        let config = strategy.config();
+
+        for attempt in 1..=config.max_attempts {
+            let outcome = strategy.attempt_recovery(error, context).await;
+            self.record_pattern(error, context, outcome.is_ok()).await;
+            self.update_circuit_breaker(&pattern_id, outcome.is_ok())
+                .await;
+
+            if outcome.is_ok() {
+                return Ok(());
+            }
+
+            if let Some(retry_after) = self.circuit_block(&pattern_id).await {
+                return Err(RecoveryError::CircuitOpen {
+                    pattern_id,
+                    retry_after,
+                });
+            }
+
+            if attempt < config.max_attempts {
+                self.sleep_before_retry(config, attempt).await;
+            }
+        }
+
+        Err(RecoveryError::MaxAttemptsExceeded)
+    }
+
+    /// Sleeps `retry_delay * 2^(attempt - 1)` (or a flat `retry_delay` when
+    /// `use_backoff` is off), plus uniform jitter in `[0, delay / 2)` so that
+    /// concurrently retrying callers don't all wake up in lockstep
+    async fn sleep_before_retry(&self, config: &crate::RecoveryConfig, attempt: u32) {
+        let delay = if config.use_backoff {
+            config.retry_delay * 2_u32.pow(attempt - 1)
+        } else {
+            config.retry_delay
+        };
+        let jitter = Duration::from_secs_f64(rand::random::<f64>() * delay.as_secs_f64() / 2.0);
+        tokio::time::sleep(delay + jitter).await;
+    }
 
-        error.to_string().hash(&mut hasher);
-        format!("ERR_{:x}", hasher.finish())
+    /// Returns `Some(remaining_cooldown)` if `pattern_id`'s circuit breaker
+    /// is open, transitioning it to half-open (and returning `None`, letting
+    /// one probe attempt through) once the cooldown has elapsed
+    async fn circuit_block(&self, pattern_id: &str) -> Option<Duration> {
+        let mut breakers = self.circuit_breakers.write().await;
+        match breakers.get(pattern_id) {
+            Some(BreakerState::Open { opened_at }) => {
+                let elapsed = opened_at.elapsed();
+                if elapsed >= self.circuit_cooldown {
+                    breakers.insert(pattern_id.to_string(), BreakerState::HalfOpen);
+                    None
+                } else {
+                    Some(self.circuit_cooldown - elapsed)
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Advances `pattern_id`'s circuit breaker after a recovery attempt:
+    /// a half-open probe closes the breaker on success or reopens it on
+    /// failure, and a closed breaker trips open once the pattern crosses
+    /// the same frequency/success-rate threshold [`Self::analyze_strategy`]
+    /// uses to recommend revising a strategy.
+    async fn update_circuit_breaker(&self, pattern_id: &str, succeeded: bool) {
+        let trips = {
+            let patterns = self.patterns.read().await;
+            patterns
+                .get(pattern_id)
+                .map(|p| p.frequency >= 10 && p.success_rate < 0.5)
+                .unwrap_or(false)
+        };
+
+        let mut breakers = self.circuit_breakers.write().await;
+        let current = breakers
+            .get(pattern_id)
+            .copied()
+            .unwrap_or(BreakerState::Closed);
+
+        let next = match current {
+            BreakerState::HalfOpen if succeeded => BreakerState::Closed,
+            BreakerState::HalfOpen => BreakerState::Open {
+                opened_at: Instant::now(),
+            },
+            BreakerState::Open { opened_at } => BreakerState::Open { opened_at },
+            BreakerState::Closed if trips => BreakerState::Open {
+                opened_at: Instant::now(),
+            },
+            BreakerState::Closed => BreakerState::Closed,
+        };
+
+        breakers.insert(pattern_id.to_string(), next);
+    }
+
+    fn generate_pattern_id(&self, error: &(dyn std::error::Error + Send + Sync)) -> String {
+        let fingerprint = crate::fingerprint::fingerprint(error.to_string().as_bytes());
+        format!("ERR_{:x}", fingerprint)
     }
 }
 
@@ -188,4 +338,129 @@ mod tests {
         let result = analyzer.analyze_strategy(&strategy).await;
         assert!(result.recommendations.is_empty());
     }
+
+    /// A strategy whose `attempt_recovery` fails until an atomic counter of
+    /// remaining failures reaches zero, for exercising retry loops.
+    struct FlakyStrategy {
+        config: RecoveryConfig,
+        remaining_failures: std::sync::atomic::AtomicUsize,
+    }
+
+    #[async_trait]
+    impl RecoveryStrategy for FlakyStrategy {
+        type Error = IoError;
+
+        async fn attempt_recovery(
+            &self,
+            _error: &Self::Error,
+            _context: &ErrorContext,
+        ) -> Result<(), RecoveryError> {
+            use std::sync::atomic::Ordering;
+            if self.remaining_failures.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| {
+                if n == 0 {
+                    None
+                } else {
+                    Some(n - 1)
+                }
+            }).is_ok() {
+                Err(RecoveryError::StrategyFailed("flaky".to_string()))
+            } else {
+                Ok(())
+            }
+        }
+
+        fn can_handle(&self, _error: &Self::Error) -> bool {
+            true
+        }
+
+        fn config(&self) -> &RecoveryConfig {
+            &self.config
+        }
+    }
+
+    fn flaky_config() -> RecoveryConfig {
+        RecoveryConfig {
+            max_attempts: 5,
+            retry_delay: Duration::from_millis(1),
+            use_backoff: true,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execute_with_recovery_succeeds_after_retries() {
+        let analyzer = RecoveryAnalyzer::new(ValidationRegistry::new());
+        let context = ErrorContext::new();
+        let error = IoError::new(ErrorKind::WouldBlock, "flaky error");
+        let strategy = FlakyStrategy {
+            config: flaky_config(),
+            remaining_failures: std::sync::atomic::AtomicUsize::new(2),
+        };
+
+        let result = analyzer.execute_with_recovery(&strategy, &error, &context).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_execute_with_recovery_exhausts_attempts() {
+        let analyzer = RecoveryAnalyzer::new(ValidationRegistry::new());
+        let context = ErrorContext::new();
+        let error = IoError::new(ErrorKind::WouldBlock, "always fails");
+        let strategy = FlakyStrategy {
+            config: flaky_config(),
+            remaining_failures: std::sync::atomic::AtomicUsize::new(100),
+        };
+
+        let result = analyzer.execute_with_recovery(&strategy, &error, &context).await;
+        assert!(matches!(result, Err(RecoveryError::MaxAttemptsExceeded)));
+    }
+
+    #[tokio::test]
+    async fn test_circuit_breaker_trips_after_repeated_failures() {
+        let analyzer = RecoveryAnalyzer::new(ValidationRegistry::new())
+            .with_circuit_cooldown(Duration::from_secs(60));
+        let context = ErrorContext::new();
+        let error = IoError::new(ErrorKind::WouldBlock, "consistently failing error");
+        let strategy = FlakyStrategy {
+            config: RecoveryConfig {
+                max_attempts: 1,
+                retry_delay: Duration::from_millis(1),
+                use_backoff: false,
+            },
+            remaining_failures: std::sync::atomic::AtomicUsize::new(100),
+        };
+
+        // Ten failing calls cross the frequency/success-rate threshold and
+        // trip the breaker on the last one.
+        for _ in 0..10 {
+            let _ = analyzer.execute_with_recovery(&strategy, &error, &context).await;
+        }
+
+        let result = analyzer.execute_with_recovery(&strategy, &error, &context).await;
+        assert!(matches!(result, Err(RecoveryError::CircuitOpen { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_circuit_breaker_half_opens_after_cooldown() {
+        let analyzer = RecoveryAnalyzer::new(ValidationRegistry::new())
+            .with_circuit_cooldown(Duration::from_millis(10));
+        let context = ErrorContext::new();
+        let error = IoError::new(ErrorKind::WouldBlock, "recovers eventually");
+        let strategy = FlakyStrategy {
+            config: RecoveryConfig {
+                max_attempts: 1,
+                retry_delay: Duration::from_millis(1),
+                use_backoff: false,
+            },
+            remaining_failures: std::sync::atomic::AtomicUsize::new(10),
+        };
+
+        for _ in 0..10 {
+            let _ = analyzer.execute_with_recovery(&strategy, &error, &context).await;
+        }
+        // Breaker is now open; let the cooldown elapse before probing again.
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        let result = analyzer.execute_with_recovery(&strategy, &error, &context).await;
+        assert!(result.is_ok());
+    }
 }
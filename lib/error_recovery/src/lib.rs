@@ -15,6 +15,7 @@ pub mod analysis;
 pub mod reporting;
 pub mod core;
 pub mod monitor;
+mod fingerprint;
 
 pub use core::{RecoveryCore, recover};
 pub use monitor::{RecoveryMonitor, RecoveryMetrics, Alert, AlertSeverity};
@@ -27,6 +28,11 @@ pub enum RecoveryError {
     NoStrategyFound,
     #[error("Recovery strategy failed: {0}")]
     StrategyFailed(String),
+    #[error("circuit breaker open for pattern '{pattern_id}', retry after {retry_after:?}")]
+    CircuitOpen {
+        pattern_id: String,
+        retry_after: Duration,
+    },
 }
 
 /// Configuration for error recovery strategies
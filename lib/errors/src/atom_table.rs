@@ -0,0 +1,88 @@
+//! Global string interner for error message payloads.
+//!
+//! Most `MathError`/`QuantumError` variants carry a human-readable message.
+//! When that payload was an owned `String`, constructing and cloning these
+//! errors heap-allocated every time -- wasteful on the hot fractal/resonance
+//! paths that raise them in loops. `AtomId` is a `Copy` index into a
+//! process-wide table of deduplicated strings (mirroring the
+//! static-string-indexing `atom_table` used by Scryer), so building,
+//! cloning, and matching an error with a message payload is now just
+//! copying a `u32`.
+
+use std::collections::HashMap;
+use std::sync::{OnceLock, RwLock};
+
+/// A `Copy` handle to an interned string. Resolve it back to text with
+/// [`resolve`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AtomId(u32);
+
+struct AtomTable {
+    strings: Vec<&'static str>,
+    ids: HashMap<&'static str, AtomId>,
+}
+
+impl AtomTable {
+    fn new() -> Self {
+        Self {
+            strings: Vec::new(),
+            ids: HashMap::new(),
+        }
+    }
+
+    fn intern(&mut self, s: &str) -> AtomId {
+        if let Some(&id) = self.ids.get(s) {
+            return id;
+        }
+        let leaked: &'static str = Box::leak(s.to_owned().into_boxed_str());
+        let id = AtomId(self.strings.len() as u32);
+        self.strings.push(leaked);
+        self.ids.insert(leaked, id);
+        id
+    }
+
+    fn resolve(&self, id: AtomId) -> &'static str {
+        self.strings[id.0 as usize]
+    }
+}
+
+fn table() -> &'static RwLock<AtomTable> {
+    static TABLE: OnceLock<RwLock<AtomTable>> = OnceLock::new();
+    TABLE.get_or_init(|| RwLock::new(AtomTable::new()))
+}
+
+/// Intern `s`, returning a cheap `Copy` handle. Interning the same text
+/// twice returns the same `AtomId` rather than allocating again.
+pub fn intern(s: &str) -> AtomId {
+    table().write().unwrap().intern(s)
+}
+
+/// Resolve an `AtomId` back to the string it was interned from.
+pub fn resolve(id: AtomId) -> &'static str {
+    table().read().unwrap().resolve(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intern_dedups() {
+        let a = intern("overflow");
+        let b = intern("overflow");
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_intern_distinguishes_distinct_strings() {
+        let a = intern("lattice overflow");
+        let b = intern("lattice underflow");
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_resolve_roundtrips() {
+        let id = intern("julia set stability loss");
+        assert_eq!(resolve(id), "julia set stability loss");
+    }
+}
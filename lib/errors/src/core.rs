@@ -10,9 +10,14 @@
 
 use scribe::Scribe;
 use scribe::native_string::String; // Import the correct String type
+use crate::atom_table::{self, AtomId};
 
-/// Core error type for crystal computing operations
-#[derive(Debug, Clone)]
+/// Core error type for crystal computing operations.
+///
+/// Every variant is now `Copy`: message payloads on `MathError`/
+/// `QuantumError` are interned `AtomId`s, so there's no owned `String` left
+/// anywhere in the tree to make cloning expensive.
+#[derive(Debug, Clone, Copy)]
 pub enum CrystalError {
     /// Mathematical operation errors
     Math(MathError),
@@ -24,45 +29,112 @@ pub enum CrystalError {
     Coherence(CoherenceError),
 }
 
-/// Error type for mathematical operations
-#[derive(Debug, Clone)]
+/// Error type for mathematical operations.
+///
+/// Message payloads are interned [`AtomId`]s rather than owned `String`s, so
+/// constructing and cloning a `MathError` on the hot fractal/resonance
+/// paths that raise these in loops no longer heap-allocates. Use the
+/// `MathError::overflow("...")`-style constructors below, which intern the
+/// message transparently.
+#[derive(Debug, Clone, Copy)]
 pub enum MathError {
     /// Division by zero error
     DivisionByZero,
     /// Value overflow error
-    Overflow(String),
+    Overflow(AtomId),
     /// Value underflow error
-    Underflow(String),
+    Underflow(AtomId),
     /// Invalid domain for operation
-    InvalidDomain(String),
+    InvalidDomain(AtomId),
     /// Harmony state became unstable
     HarmonyStateUnstable,
     /// Conversion error between types
-    ConversionError(String),
+    ConversionError(AtomId),
     /// Invalid parameter value
-    InvalidParameter(String),
+    InvalidParameter(AtomId),
     /// Logarithm domain error
     LogarithmDomainError(f64),
     /// Julia set stability loss
-    JuliaStabilityLoss(String),
+    JuliaStabilityLoss(AtomId),
     /// Mandelbrot set stability loss
-    MandelbrotStabilityLoss(String),
+    MandelbrotStabilityLoss(AtomId),
     /// Generic fractal stability loss
-    FractalStabilityLoss(String),
+    FractalStabilityLoss(AtomId),
     /// Fractal type mismatch
     FractalTypeMismatch,
     /// Complex number convergence failure
-    ComplexConvergenceFailure(String),
+    ComplexConvergenceFailure(AtomId),
     /// Resonance loss in harmony state
-    ResonanceLoss(String),
+    ResonanceLoss(AtomId),
     /// Iteration limit exceeded
     IterationLimitExceeded(usize),
     /// Generic harmony error
-    HarmonyError(String),
+    HarmonyError(AtomId),
+    /// Operation required a non-negative (real-valued) result but the
+    /// input would have produced a complex one, e.g. the square root of
+    /// a matrix with a negative eigenvalue
+    ComplexDomain,
+}
+
+impl MathError {
+    /// Build an [`MathError::Overflow`], interning `msg`.
+    pub fn overflow(msg: &str) -> Self {
+        Self::Overflow(atom_table::intern(msg))
+    }
+
+    /// Build an [`MathError::Underflow`], interning `msg`.
+    pub fn underflow(msg: &str) -> Self {
+        Self::Underflow(atom_table::intern(msg))
+    }
+
+    /// Build an [`MathError::InvalidDomain`], interning `msg`.
+    pub fn invalid_domain(msg: &str) -> Self {
+        Self::InvalidDomain(atom_table::intern(msg))
+    }
+
+    /// Build a [`MathError::ConversionError`], interning `msg`.
+    pub fn conversion_error(msg: &str) -> Self {
+        Self::ConversionError(atom_table::intern(msg))
+    }
+
+    /// Build a [`MathError::InvalidParameter`], interning `msg`.
+    pub fn invalid_parameter(msg: &str) -> Self {
+        Self::InvalidParameter(atom_table::intern(msg))
+    }
+
+    /// Build a [`MathError::JuliaStabilityLoss`], interning `msg`.
+    pub fn julia_stability_loss(msg: &str) -> Self {
+        Self::JuliaStabilityLoss(atom_table::intern(msg))
+    }
+
+    /// Build a [`MathError::MandelbrotStabilityLoss`], interning `msg`.
+    pub fn mandelbrot_stability_loss(msg: &str) -> Self {
+        Self::MandelbrotStabilityLoss(atom_table::intern(msg))
+    }
+
+    /// Build a [`MathError::FractalStabilityLoss`], interning `msg`.
+    pub fn fractal_stability_loss(msg: &str) -> Self {
+        Self::FractalStabilityLoss(atom_table::intern(msg))
+    }
+
+    /// Build a [`MathError::ComplexConvergenceFailure`], interning `msg`.
+    pub fn complex_convergence_failure(msg: &str) -> Self {
+        Self::ComplexConvergenceFailure(atom_table::intern(msg))
+    }
+
+    /// Build a [`MathError::ResonanceLoss`], interning `msg`.
+    pub fn resonance_loss(msg: &str) -> Self {
+        Self::ResonanceLoss(atom_table::intern(msg))
+    }
+
+    /// Build a [`MathError::HarmonyError`], interning `msg`.
+    pub fn harmony_error(msg: &str) -> Self {
+        Self::HarmonyError(atom_table::intern(msg))
+    }
 }
 
 /// Error type for quantum operations
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 pub enum QuantumError {
     /// Invalid quantum state
     InvalidState,
@@ -75,13 +147,20 @@ pub enum QuantumError {
     /// Resonance failure
     ResonanceFailure,
     /// Alignment failure
-    AlignmentFailure(String),
+    AlignmentFailure(AtomId),
     /// Vector operation error
     VectorError(VectorError),
 }
 
+impl QuantumError {
+    /// Build a [`QuantumError::AlignmentFailure`], interning `msg`.
+    pub fn alignment_failure(msg: &str) -> Self {
+        Self::AlignmentFailure(atom_table::intern(msg))
+    }
+}
+
 /// Error type for vector operations
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 pub enum VectorError {
     /// Division by zero
     DivisionByZero,
@@ -94,7 +173,7 @@ pub enum VectorError {
 }
 
 /// Error type for coherence operations
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 pub enum CoherenceError {
     /// Invalid coherence value
     InvalidValue,
@@ -128,19 +207,19 @@ impl Scribe for MathError {
             Self::Overflow(msg) => {
                 let mut result = String::new();
                 result.push_str("Overflow error: ");
-                result.push_str(msg.to_str()); // Convert to &str
+                result.push_str(atom_table::resolve(*msg));
                 result
             },
             Self::Underflow(msg) => {
                 let mut result = String::new();
                 result.push_str("Underflow error: ");
-                result.push_str(msg.to_str()); // Convert to &str
+                result.push_str(atom_table::resolve(*msg));
                 result
             },
             Self::InvalidDomain(msg) => {
                 let mut result = String::new();
                 result.push_str("Invalid domain: ");
-                result.push_str(msg.to_str()); // Convert to &str
+                result.push_str(atom_table::resolve(*msg));
                 result
             },
             Self::HarmonyStateUnstable => {
@@ -151,13 +230,13 @@ impl Scribe for MathError {
             Self::ConversionError(msg) => {
                 let mut result = String::new();
                 result.push_str("Conversion error: ");
-                result.push_str(msg.to_str()); // Convert to &str
+                result.push_str(atom_table::resolve(*msg));
                 result
             },
             Self::InvalidParameter(msg) => {
                 let mut result = String::new();
                 result.push_str("Invalid parameter: ");
-                result.push_str(msg.to_str()); // Convert to &str
+                result.push_str(atom_table::resolve(*msg));
                 result
             },
             Self::LogarithmDomainError(val) => {
@@ -169,19 +248,19 @@ impl Scribe for MathError {
             Self::JuliaStabilityLoss(msg) => {
                 let mut result = String::new();
                 result.push_str("Julia set stability loss: ");
-                result.push_str(msg.to_str()); // Convert to &str
+                result.push_str(atom_table::resolve(*msg));
                 result
             },
             Self::MandelbrotStabilityLoss(msg) => {
                 let mut result = String::new();
                 result.push_str("Mandelbrot set stability loss: ");
-                result.push_str(msg.to_str()); // Convert to &str
+                result.push_str(atom_table::resolve(*msg));
                 result
             },
             Self::FractalStabilityLoss(msg) => {
                 let mut result = String::new();
                 result.push_str("Fractal stability loss: ");
-                result.push_str(msg.to_str()); // Convert to &str
+                result.push_str(atom_table::resolve(*msg));
                 result
             },
             Self::FractalTypeMismatch => {
@@ -192,13 +271,13 @@ impl Scribe for MathError {
             Self::ComplexConvergenceFailure(msg) => {
                 let mut result = String::new();
                 result.push_str("Complex convergence failure: ");
-                result.push_str(msg.to_str()); // Convert to &str
+                result.push_str(atom_table::resolve(*msg));
                 result
             },
             Self::ResonanceLoss(msg) => {
                 let mut result = String::new();
                 result.push_str("Resonance loss: ");
-                result.push_str(msg.to_str()); // Convert to &str
+                result.push_str(atom_table::resolve(*msg));
                 result
             },
             Self::IterationLimitExceeded(limit) => {
@@ -210,7 +289,12 @@ impl Scribe for MathError {
             Self::HarmonyError(msg) => {
                 let mut result = String::new();
                 result.push_str("Harmony error: ");
-                result.push_str(msg.to_str()); // Convert to &str
+                result.push_str(atom_table::resolve(*msg));
+                result
+            },
+            Self::ComplexDomain => {
+                let mut result = String::new();
+                result.push_str("Operation requires a complex result in a real-only domain");
                 result
             },
         }
@@ -248,7 +332,7 @@ impl Scribe for QuantumError {
             Self::AlignmentFailure(msg) => {
                 let mut result = String::new();
                 result.push_str("Alignment failure: ");
-                result.push_str(msg.to_str()); // Convert to &str
+                result.push_str(atom_table::resolve(*msg));
                 result
             },
             Self::VectorError(e) => e.scribe(),
@@ -360,10 +444,27 @@ mod tests {
 
     #[test]
     fn test_math_error_scribe() {
-        let error = MathError::InvalidDomain("test".to_string());
+        let error = MathError::invalid_domain("test");
         assert_eq!(error.scribe().to_str(), "Invalid domain: test");
     }
 
+    #[test]
+    fn test_math_error_is_copy() {
+        let error = MathError::overflow("lattice bound exceeded");
+        let copied = error;
+        assert_eq!(error.scribe().to_str(), copied.scribe().to_str());
+    }
+
+    #[test]
+    fn test_message_constructor_interns_transparently() {
+        let a = MathError::overflow("duplicate message");
+        let b = MathError::overflow("duplicate message");
+        match (a, b) {
+            (MathError::Overflow(id_a), MathError::Overflow(id_b)) => assert_eq!(id_a, id_b),
+            _ => panic!("expected Overflow variants"),
+        }
+    }
+
     #[test]
     fn test_quantum_error_scribe() {
         let error = QuantumError::InvalidState;
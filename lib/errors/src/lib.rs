@@ -4,6 +4,9 @@
 
 use scribe::String;
 
+pub mod atom_table;
+pub mod core;
+
 #[derive(Debug)]
 pub struct Error {
     message: String,
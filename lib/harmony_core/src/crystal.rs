@@ -19,6 +19,8 @@ use magicmath::constants::{
 };
 
 use crate::align::{Alignment, AlignmentState};
+use crate::sponge::{self, LatticeDigest};
+use crate::{CoherenceStore, Precision};
 
 /// Core crystal node for quantum operations
 #[derive(Debug, Clone)]  // Added Debug derive
@@ -26,24 +28,29 @@ pub struct CrystalNode {
     /// Position in crystal lattice
     position: Vector3D,
     /// Phase coherence value
-    coherence: f64,
+    coherence: CoherenceStore,
     /// Node alignment
     alignment: Alignment,
 }
 
 impl CrystalNode {
-    /// Create a new crystal node
+    /// Create a new crystal node at full precision
     pub fn new(position: Vector3D) -> Self {
+        Self::with_precision(position, Precision::default())
+    }
+
+    /// Create a new crystal node storing coherence at the given precision
+    pub fn with_precision(position: Vector3D, precision: Precision) -> Self {
         Self {
             position: position.clone(),
-            coherence: 1.0,
+            coherence: CoherenceStore::new(precision, 1.0),
             alignment: Alignment::new(position),
         }
     }
 
     /// Get node's phase coherence
     pub fn get_phase_coherence(&self) -> f64 {
-        self.coherence
+        self.coherence.get()
     }
 
     /// Set node's phase coherence
@@ -58,10 +65,18 @@ impl CrystalNode {
         if value < 0.0 || value > 1.0 {
             return Err(MathError::InvalidRange);
         }
-        self.coherence = value;
+        self.coherence.set(value);
         Ok(())
     }
 
+    /// Decay this node's coherence by `factor` (typically just under
+    /// 1.0), always multiplying in `f64` so repeated calls don't
+    /// compound rounding error beyond a single `Mixed` storage round trip.
+    pub fn decay_coherence(&mut self, factor: f64) {
+        let decayed = self.coherence.get() * factor;
+        self.coherence.set(decayed);
+    }
+
     /// Get node's position
     pub fn position(&self) -> &Vector3D {
         &self.position
@@ -71,6 +86,11 @@ impl CrystalNode {
     pub fn alignment_state(&self) -> AlignmentState {
         self.alignment.state()
     }
+
+    /// Get the precision this node stores its coherence at
+    pub fn precision(&self) -> Precision {
+        self.coherence.precision()
+    }
 }
 
 /// Crystal lattice structure
@@ -82,14 +102,26 @@ pub struct CrystalLattice {
     size: usize,
     /// Lattice alignment
     alignment: Alignment,
+    /// Precision this lattice's nodes store coherence at; see
+    /// [`Precision`] for the accuracy/memory tradeoff.
+    precision: Precision,
 }
 
 impl CrystalLattice {
-    /// Create a new crystal lattice
+    /// Create a new crystal lattice at full precision
     ///
     /// # Parameters
     /// * `size` - Size of the lattice (will be capped at MAX_QUANTUM_SIZE)
     pub fn new(size: usize) -> Self {
+        Self::with_precision(size, Precision::default())
+    }
+
+    /// Create a new crystal lattice whose nodes store coherence at the
+    /// given precision. `Mixed` halves the memory `nodes` occupies, but
+    /// `calculate_resonance` still upcasts to `f64` before comparing
+    /// against `HARMONY_RESONANCE_THRESHOLD`, so it only drifts from the
+    /// `Full` result by a single rounding step.
+    pub fn with_precision(size: usize, precision: Precision) -> Self {
         let size = size.min(MAX_QUANTUM_SIZE);  // Use MAX_QUANTUM_SIZE instead
         let nodes = vec![vec![None; size]; size];
         let origin = Vector3D::new(0.0, 0.0, 0.0);
@@ -98,6 +130,7 @@ impl CrystalLattice {
             nodes,
             size,
             alignment: Alignment::new(origin),
+            precision,
         }
     }
 
@@ -135,7 +168,9 @@ impl CrystalLattice {
         Ok(())
     }
 
-    /// Calculate resonance at position
+    /// Calculate resonance at position. Runs in `f64` regardless of
+    /// `precision`, since `CrystalNode::get_phase_coherence` already
+    /// upcasts `Mixed` storage before this ever sees it.
     ///
     /// # Returns
     /// * `Ok(f64)` containing the resonance value if calculation succeeds
@@ -160,6 +195,32 @@ impl CrystalLattice {
     pub fn alignment_state(&self) -> AlignmentState {
         self.alignment.state()
     }
+
+    /// Get the precision this lattice's nodes store coherence at
+    pub fn precision(&self) -> Precision {
+        self.precision
+    }
+
+    /// Fingerprint every occupied node into a [`LatticeDigest`].
+    ///
+    /// Nodes are absorbed in row-major `(x, y)` order, skipping `None`
+    /// entries, so the digest depends only on the lattice's contents and
+    /// never on the order nodes were inserted in. See [`crate::sponge`]
+    /// for the construction.
+    pub fn commit(&self) -> LatticeDigest {
+        sponge::commit(self.nodes.iter().enumerate().flat_map(|(x, column)| {
+            column.iter().enumerate().filter_map(move |(y, node)| {
+                node.as_ref().map(|node| (x, y, node.get_phase_coherence()))
+            })
+        }))
+    }
+
+    /// Recompute this lattice's commitment and compare it against a
+    /// previously captured `digest`. Returns `false` if any node's
+    /// position, occupancy, or (quantized) coherence has drifted.
+    pub fn verify(&self, digest: &LatticeDigest) -> bool {
+        self.commit() == *digest
+    }
 }
 
 #[cfg(test)]
@@ -209,4 +270,40 @@ mod tests {
                          Err(QuantumError::BoundaryViolation)
         ));
     }
+
+    #[test]
+    fn test_commit_is_deterministic_across_insertion_order() {
+        let mut first = CrystalLattice::new(4);
+        first.set_node(&Vector3D::new(0.0, 0.0, 0.0), CrystalNode::new(Vector3D::new(0.0, 0.0, 0.0))).unwrap();
+        first.set_node(&Vector3D::new(2.0, 1.0, 0.0), CrystalNode::new(Vector3D::new(2.0, 1.0, 0.0))).unwrap();
+
+        let mut second = CrystalLattice::new(4);
+        second.set_node(&Vector3D::new(2.0, 1.0, 0.0), CrystalNode::new(Vector3D::new(2.0, 1.0, 0.0))).unwrap();
+        second.set_node(&Vector3D::new(0.0, 0.0, 0.0), CrystalNode::new(Vector3D::new(0.0, 0.0, 0.0))).unwrap();
+
+        assert_eq!(first.commit(), second.commit());
+        assert!(first.verify(&second.commit()));
+    }
+
+    #[test]
+    fn test_commit_changes_when_coherence_drifts() {
+        let mut lattice = CrystalLattice::new(4);
+        let pos = Vector3D::new(0.0, 0.0, 0.0);
+        lattice.set_node(&pos, CrystalNode::new(pos.clone())).unwrap();
+        let digest = lattice.commit();
+
+        lattice.get_node(&pos).is_ok();
+        let mut node = CrystalNode::new(pos.clone());
+        node.set_phase_coherence(0.5).unwrap();
+        lattice.set_node(&pos, node).unwrap();
+
+        assert!(!lattice.verify(&digest));
+    }
+
+    #[test]
+    fn test_commit_empty_lattice_is_stable() {
+        let a = CrystalLattice::new(4);
+        let b = CrystalLattice::new(4);
+        assert_eq!(a.commit(), b.commit());
+    }
 }
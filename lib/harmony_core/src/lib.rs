@@ -16,8 +16,11 @@ mod cube;
 mod growth;
 mod harmony;
 mod phantom;
+mod qasm;
+mod resonance_graph;
 mod zeronaut;
 mod cell;
+mod sponge;
 
 pub use align::*;
 pub use crystal::*;
@@ -27,8 +30,11 @@ pub use cube::*;
 pub use growth::*;
 pub use harmony::*;
 pub use phantom::*;
+pub use qasm::*;
+pub use resonance_graph::*;
 pub use zeronaut::*;
 pub use cell::*;
+pub use sponge::LatticeDigest;
 
 use magicmath::constants::{
     HARMONY_RESONANCE_THRESHOLD,
@@ -50,30 +56,98 @@ pub trait Protected {
     fn is_harmonically_stable(&self) -> bool;
 }
 
+/// Storage precision for coherence-bearing structures (`CrystalNode`,
+/// `Phantom`), borrowed from the mixed-vs-full precision policies used
+/// in quantum Monte Carlo codes.
+///
+/// `Mixed` halves per-node storage by keeping coherence in `f32`, but
+/// every reduction (`calculate_resonance`, `phantom_energy`,
+/// `decay_coherence`) still upcasts to `f64` before accumulating or
+/// comparing against a threshold, so its results only drift from `Full`
+/// by the rounding error of a single `f64 -> f32 -> f64` round trip.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Precision {
+    /// Store coherence as `f64`. Highest accuracy; double the memory of `Mixed`.
+    Full,
+    /// Store coherence as `f32`. Half the memory of `Full`, at the cost
+    /// of that storage's rounding error surfacing in subsequent reads.
+    Mixed,
+}
+
+impl Default for Precision {
+    fn default() -> Self {
+        Self::Full
+    }
+}
+
+/// Coherence storage backing a `CrystalNode`, sized according to its
+/// `Precision`. Every accessor upcasts to `f64`, so callers never need to
+/// branch on which variant is active.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum CoherenceStore {
+    Full(f64),
+    Mixed(f32),
+}
+
+impl CoherenceStore {
+    pub(crate) fn new(precision: Precision, value: f64) -> Self {
+        match precision {
+            Precision::Full => Self::Full(value),
+            Precision::Mixed => Self::Mixed(value as f32),
+        }
+    }
+
+    pub(crate) fn get(&self) -> f64 {
+        match self {
+            Self::Full(value) => *value,
+            Self::Mixed(value) => *value as f64,
+        }
+    }
+
+    pub(crate) fn set(&mut self, value: f64) {
+        match self {
+            Self::Full(slot) => *slot = value,
+            Self::Mixed(slot) => *slot = value as f32,
+        }
+    }
+
+    pub(crate) fn precision(&self) -> Precision {
+        match self {
+            Self::Full(_) => Precision::Full,
+            Self::Mixed(_) => Precision::Mixed,
+        }
+    }
+}
+
 /// Core crystal node for quantum operations
 #[derive(Debug, Clone)]
 pub struct CrystalNode {
     /// Position in crystal lattice
     position: Vector3D,
     /// Phase coherence value
-    coherence: f64,
+    coherence: CoherenceStore,
     /// Node alignment
     alignment: Alignment,
 }
 
 impl CrystalNode {
-    /// Create a new crystal node
+    /// Create a new crystal node at full precision
     pub fn new(position: Vector3D) -> Self {
+        Self::with_precision(position, Precision::default())
+    }
+
+    /// Create a new crystal node storing coherence at the given precision
+    pub fn with_precision(position: Vector3D, precision: Precision) -> Self {
         Self {
             position: position.clone(),
-            coherence: 1.0,
+            coherence: CoherenceStore::new(precision, 1.0),
             alignment: Alignment::new(position),
         }
     }
 
     /// Get node's phase coherence
     pub fn get_phase_coherence(&self) -> f64 {
-        self.coherence
+        self.coherence.get()
     }
 
     /// Set node's phase coherence
@@ -81,10 +155,20 @@ impl CrystalNode {
         if value < 0.0 || value > 1.0 {
             return Err(MathError::InvalidParameter(String::from("Phase coherence value must be between 0 and 1"))); // Correcting error variant
         }
-        self.coherence = value;
+        self.coherence.set(value);
         Ok(())
     }
 
+    /// Decay this node's coherence by `factor` (typically just under
+    /// 1.0). The multiplication always happens in `f64`, regardless of
+    /// `precision`, so repeated decay calls don't compound rounding
+    /// error beyond what a single `Mixed` storage round trip already
+    /// costs.
+    pub fn decay_coherence(&mut self, factor: f64) {
+        let decayed = self.coherence.get() * factor;
+        self.coherence.set(decayed);
+    }
+
     /// Get node's position
     pub fn position(&self) -> &Vector3D {
         &self.position
@@ -94,6 +178,11 @@ impl CrystalNode {
     pub fn alignment_state(&self) -> AlignmentState {
         self.alignment.state() // Correcting method call
     }
+
+    /// Get the precision this node stores its coherence at
+    pub fn precision(&self) -> Precision {
+        self.coherence.precision()
+    }
 }
 
 /// Crystal lattice structure
@@ -105,11 +194,22 @@ pub struct CrystalLattice {
     size: usize,
     /// Lattice alignment
     alignment: Alignment,
+    /// Precision new nodes are created at via `set_node`'s callers; see
+    /// `Precision` for the accuracy/memory tradeoff.
+    precision: Precision,
 }
 
 impl CrystalLattice {
-    /// Create a new crystal lattice
+    /// Create a new crystal lattice at full precision
     pub fn new(size: usize) -> Self {
+        Self::with_precision(size, Precision::default())
+    }
+
+    /// Create a new crystal lattice whose nodes store coherence at the
+    /// given precision. `Mixed` halves the memory `nodes` occupies but
+    /// upcasts to `f64` for every reduction, so `calculate_resonance`
+    /// only drifts from the `Full` result by a single rounding step.
+    pub fn with_precision(size: usize, precision: Precision) -> Self {
         let size = size.min(MAX_QUANTUM_SIZE); // Adjusted to use a valid constant
         let nodes = vec![vec![None; size]; size]; // Using Vec instead of fixed-size array
         let origin = Vector3D::new(0.0, 0.0, 0.0);
@@ -118,6 +218,7 @@ impl CrystalLattice {
             nodes,
             size,
             alignment: Alignment::new(origin),
+            precision,
         }
     }
 
@@ -146,7 +247,9 @@ impl CrystalLattice {
         Ok(())
     }
 
-    /// Calculate resonance at position
+    /// Calculate resonance at position. Runs in `f64` regardless of the
+    /// lattice's `Precision`, since `CrystalNode::get_phase_coherence`
+    /// already upcasts `Mixed` storage before this ever sees it.
     pub fn calculate_resonance(&self, pos: &Vector3D) -> Result<f64, QuantumError> {
         let node = self.get_node(pos)?;
         let coherence = node.get_phase_coherence();
@@ -167,6 +270,32 @@ impl CrystalLattice {
     pub fn alignment_state(&self) -> AlignmentState {
         self.alignment.state() // Correcting method call
     }
+
+    /// Get the precision this lattice's nodes store coherence at
+    pub fn precision(&self) -> Precision {
+        self.precision
+    }
+
+    /// Fingerprint every occupied node into a [`LatticeDigest`].
+    ///
+    /// Nodes are absorbed in row-major `(x, y)` order, skipping `None`
+    /// entries, so the digest depends only on the lattice's contents and
+    /// never on the order nodes were inserted in. See [`sponge`] for the
+    /// construction.
+    pub fn commit(&self) -> LatticeDigest {
+        sponge::commit(self.nodes.iter().enumerate().flat_map(|(x, column)| {
+            column.iter().enumerate().filter_map(move |(y, node)| {
+                node.as_ref().map(|node| (x, y, node.get_phase_coherence()))
+            })
+        }))
+    }
+
+    /// Recompute this lattice's commitment and compare it against a
+    /// previously captured `digest`. Returns `false` if any node's
+    /// position, occupancy, or (quantized) coherence has drifted.
+    pub fn verify(&self, digest: &LatticeDigest) -> bool {
+        self.commit() == *digest
+    }
 }
 
 #[cfg(test)]
@@ -199,4 +328,62 @@ mod tests {
         let pos = Vector3D::new(0.0, 0.0, 0.0);
         assert!(lattice.calculate_resonance(&pos).is_err()); // No node set yet
     }
+
+    #[test]
+    fn test_mixed_and_full_precision_agree_within_epsilon() {
+        const EPSILON: f64 = 1e-6;
+        let pos = Vector3D::new(0.0, 0.0, 0.0);
+
+        let mut full = CrystalLattice::with_precision(4, Precision::Full);
+        let mut mixed = CrystalLattice::with_precision(4, Precision::Mixed);
+        full.set_node(&pos, CrystalNode::with_precision(pos.clone(), Precision::Full)).unwrap();
+        mixed.set_node(&pos, CrystalNode::with_precision(pos.clone(), Precision::Mixed)).unwrap();
+
+        let full_resonance = full.calculate_resonance(&pos).unwrap();
+        let mixed_resonance = mixed.calculate_resonance(&pos).unwrap();
+        assert!((full_resonance - mixed_resonance).abs() < EPSILON);
+        assert_eq!(mixed.precision(), Precision::Mixed);
+    }
+
+    #[test]
+    fn test_decay_coherence_accumulates_in_f64() {
+        let mut node = CrystalNode::with_precision(Vector3D::new(0.0, 0.0, 0.0), Precision::Mixed);
+        node.decay_coherence(0.99);
+        assert!((node.get_phase_coherence() - 0.99).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_commit_is_deterministic_across_insertion_order() {
+        let mut first = CrystalLattice::new(4);
+        first.set_node(&Vector3D::new(0.0, 0.0, 0.0), CrystalNode::new(Vector3D::new(0.0, 0.0, 0.0))).unwrap();
+        first.set_node(&Vector3D::new(2.0, 1.0, 0.0), CrystalNode::new(Vector3D::new(2.0, 1.0, 0.0))).unwrap();
+
+        let mut second = CrystalLattice::new(4);
+        second.set_node(&Vector3D::new(2.0, 1.0, 0.0), CrystalNode::new(Vector3D::new(2.0, 1.0, 0.0))).unwrap();
+        second.set_node(&Vector3D::new(0.0, 0.0, 0.0), CrystalNode::new(Vector3D::new(0.0, 0.0, 0.0))).unwrap();
+
+        assert_eq!(first.commit(), second.commit());
+        assert!(first.verify(&second.commit()));
+    }
+
+    #[test]
+    fn test_commit_changes_when_coherence_drifts() {
+        let mut lattice = CrystalLattice::new(4);
+        let pos = Vector3D::new(0.0, 0.0, 0.0);
+        lattice.set_node(&pos, CrystalNode::new(pos.clone())).unwrap();
+        let digest = lattice.commit();
+
+        let mut node = CrystalNode::new(pos.clone());
+        node.set_phase_coherence(0.5).unwrap();
+        lattice.set_node(&pos, node).unwrap();
+
+        assert!(!lattice.verify(&digest));
+    }
+
+    #[test]
+    fn test_commit_empty_lattice_is_stable() {
+        let a = CrystalLattice::new(4);
+        let b = CrystalLattice::new(4);
+        assert_eq!(a.commit(), b.commit());
+    }
 }
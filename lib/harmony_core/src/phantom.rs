@@ -10,6 +10,9 @@
 
 use core::fmt::{Display, Formatter, Result as FmtResult};
 
+use ndarray::{Array1, Array2};
+use num_complex::Complex64;
+
 use magicmath::{
     traits::MeshValue,
     operations::{
@@ -26,6 +29,85 @@ use errors::{
     core::Error as MathError,
 };
 
+use crate::Precision;
+
+/// Common single-qubit unitary gates, expressed as 2x2 operator matrices
+/// for use with [`Phantom::apply_operator`].
+pub mod gates {
+    use ndarray::Array2;
+    use num_complex::Complex64;
+
+    /// Hadamard gate: `1/sqrt(2) * [[1, 1], [1, -1]]`.
+    pub fn hadamard() -> Array2<Complex64> {
+        let s = std::f64::consts::FRAC_1_SQRT_2;
+        Array2::from_shape_vec(
+            (2, 2),
+            vec![
+                Complex64::new(s, 0.0), Complex64::new(s, 0.0),
+                Complex64::new(s, 0.0), Complex64::new(-s, 0.0),
+            ],
+        ).expect("hadamard matrix is always 2x2")
+    }
+
+    /// Phase gate: `diag(1, e^{i*theta})`.
+    pub fn phase(theta: f64) -> Array2<Complex64> {
+        Array2::from_shape_vec(
+            (2, 2),
+            vec![
+                Complex64::new(1.0, 0.0), Complex64::new(0.0, 0.0),
+                Complex64::new(0.0, 0.0), Complex64::from_polar(1.0, theta),
+            ],
+        ).expect("phase gate matrix is always 2x2")
+    }
+
+    /// Pauli-X (bit flip) gate: `[[0, 1], [1, 0]]`.
+    pub fn pauli_x() -> Array2<Complex64> {
+        Array2::from_shape_vec(
+            (2, 2),
+            vec![
+                Complex64::new(0.0, 0.0), Complex64::new(1.0, 0.0),
+                Complex64::new(1.0, 0.0), Complex64::new(0.0, 0.0),
+            ],
+        ).expect("pauli-x matrix is always 2x2")
+    }
+
+    /// Pauli-Z (phase flip) gate: `[[1, 0], [0, -1]]`.
+    pub fn pauli_z() -> Array2<Complex64> {
+        Array2::from_shape_vec(
+            (2, 2),
+            vec![
+                Complex64::new(1.0, 0.0), Complex64::new(0.0, 0.0),
+                Complex64::new(0.0, 0.0), Complex64::new(-1.0, 0.0),
+            ],
+        ).expect("pauli-z matrix is always 2x2")
+    }
+}
+
+/// How far `u^dagger * u` may drift from the identity before
+/// [`Phantom::apply_operator`]'s debug-only unitarity check rejects `u`.
+const UNITARITY_TOLERANCE: f64 = 1e-6;
+
+fn is_approximately_unitary(u: &Array2<Complex64>) -> bool {
+    let dim = u.nrows();
+    if u.ncols() != dim {
+        return false;
+    }
+
+    let u_dagger = u.t().map(|c| c.conj());
+    let product = u_dagger.dot(u);
+
+    for i in 0..dim {
+        for j in 0..dim {
+            let expected = if i == j { Complex64::new(1.0, 0.0) } else { Complex64::new(0.0, 0.0) };
+            if (product[[i, j]] - expected).norm() > UNITARITY_TOLERANCE {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
 /// Phantom state handler for higher-dimensional operations
 #[derive(Debug)]
 pub struct Phantom<T> {
@@ -34,20 +116,102 @@ pub struct Phantom<T> {
     mesh: Mesh<T>,
     resonance: Resonance,
     position: Vector4D,
+    /// Complex state vector over the phantom's basis states. Starts in
+    /// the |0> computational basis state; [`Phantom::apply_operator`]
+    /// advances it in place, letting the phase field model genuine
+    /// superposition and interference instead of a single scalar phase.
+    amplitudes: Array1<Complex64>,
+    /// Precision `phantom_energy` accumulates its terms at; see
+    /// [`Precision`] for the accuracy/memory tradeoff.
+    precision: Precision,
 }
 
 impl<T: Default + Clone + MeshValue> Phantom<T> {
-    /// Create a new phantom handler
+    /// Create a new phantom handler at full precision
     pub fn new(size: usize) -> Self {
+        Self::with_precision(size, Precision::default())
+    }
+
+    /// Create a new phantom handler that accumulates `phantom_energy` at
+    /// the given precision. `Mixed` rounds each energy term through
+    /// `f32` before summing in `f64`, trading a small amount of accuracy
+    /// for half the storage a lattice of these terms would otherwise need.
+    pub fn with_precision(size: usize, precision: Precision) -> Self {
+        let mut amplitudes = Array1::from_elem(size, Complex64::new(0.0, 0.0));
+        if size > 0 {
+            amplitudes[0] = Complex64::new(1.0, 0.0);
+        }
+
         Self {
             field: Field::default(),
             phase_field: PhaseField::new(),
             mesh: Mesh::new(size),
             resonance: Resonance::new(),
             position: Vector4D::new(0.0, 0.0, 0.0, 1.0),
+            amplitudes,
+            precision,
         }
     }
 
+    /// Get the precision `phantom_energy` accumulates its terms at
+    pub fn precision(&self) -> Precision {
+        self.precision
+    }
+
+    /// Advance the complex state vector by a unitary step: `amplitudes <-
+    /// u * amplitudes`. `u` must be square and match the state vector's
+    /// dimension. In debug builds `u` is additionally checked to be
+    /// (approximately) unitary, since a non-unitary `u` would silently
+    /// break the Born-rule normalization `phantom_energy` and
+    /// `born_weights` depend on.
+    pub fn apply_operator(&mut self, u: &Array2<Complex64>) -> Result<(), MathError> {
+        let dim = self.amplitudes.len();
+        if u.nrows() != dim || u.ncols() != dim {
+            return Err(MathError::new(format!(
+                "operator is {}x{} but phantom state has dimension {}",
+                u.nrows(),
+                u.ncols(),
+                dim
+            )));
+        }
+
+        debug_assert!(
+            is_approximately_unitary(u),
+            "apply_operator: u is not unitary (u^dagger * u != I)"
+        );
+
+        self.amplitudes = u.dot(&self.amplitudes);
+        Ok(())
+    }
+
+    /// Born-rule measurement weights `|amplitude_k|^2` for each basis
+    /// state. Sums to 1 for a normalized state vector.
+    pub fn born_weights(&self) -> Vec<f64> {
+        self.amplitudes.iter().map(|a| a.norm_sqr()).collect()
+    }
+
+    /// Scalar phase of the dominant amplitude (the basis state with the
+    /// largest Born weight), used to keep the existing `Phase`/`Quantum`
+    /// trait impls meaningful now that phase lives in a complex state
+    /// vector rather than a single `f64`.
+    fn dominant_phase(&self) -> f64 {
+        self.amplitudes
+            .iter()
+            .max_by(|a, b| a.norm_sqr().partial_cmp(&b.norm_sqr()).unwrap_or(core::cmp::Ordering::Equal))
+            .map(|c| c.arg())
+            .unwrap_or(0.0)
+    }
+
+    /// Expected basis-index "energy" under the Born weights, i.e. the
+    /// state vector's contribution to [`Phantom::phantom_energy`].
+    fn state_energy(&self) -> f64 {
+        self.born_weights()
+            .iter()
+            .enumerate()
+            .map(|(k, weight)| weight * (k as f64))
+            .sum()
+    }
+
     /// Get the phantom state at position
     pub fn get_state(&self, pos: &Vector4D) -> Result<T, QuantumError> {
         self.mesh.get_value_at(&Vector3D::new(pos.x, pos.y, pos.z))
@@ -94,12 +258,26 @@ impl<T: Default + Clone + MeshValue> Phantom<T> {
         &self.resonance
     }
 
-    /// Calculate phantom energy
+    /// Calculate phantom energy. At `Precision::Mixed`, each term is
+    /// rounded through `f32` before the final sum, emulating the
+    /// rounding a halved-memory lattice of these terms would incur while
+    /// still accumulating in `f64` so the result only drifts from
+    /// `Precision::Full` by a single rounding step per term.
     pub fn phantom_energy(&self) -> Result<f64, MathError> {
         let field_energy = self.field.energy()?;
-        let phase_energy = self.phase_field.energy()?;
+        let state_energy = self.state_energy();
         let resonance_energy = self.resonance.energy()?;
-        Ok((field_energy + phase_energy + resonance_energy) / 3.0)
+
+        let (field_energy, state_energy, resonance_energy) = match self.precision {
+            Precision::Full => (field_energy, state_energy, resonance_energy),
+            Precision::Mixed => (
+                field_energy as f32 as f64,
+                state_energy as f32 as f64,
+                resonance_energy as f32 as f64,
+            ),
+        };
+
+        Ok((field_energy + state_energy + resonance_energy) / 3.0)
     }
 }
 
@@ -109,7 +287,7 @@ impl<T: MeshValue> Quantum for Phantom<T> {
     }
 
     fn phase(&self) -> Result<f64, MathError> {
-        self.phase_field.phase()
+        Ok(self.dominant_phase())
     }
 }
 
@@ -267,4 +445,53 @@ mod tests {
         assert!(phantom.phase().is_ok());
         assert!(phantom.phase_shift(0.5).is_ok());
     }
+
+    #[test]
+    fn test_apply_operator_hadamard_splits_weight_evenly() {
+        let mut phantom = Phantom::<TestPhantom>::new(2);
+        phantom.apply_operator(&gates::hadamard()).unwrap();
+
+        let weights = phantom.born_weights();
+        assert!((weights[0] - 0.5).abs() < 1e-9);
+        assert!((weights[1] - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_apply_operator_rejects_wrong_dimension() {
+        let mut phantom = Phantom::<TestPhantom>::new(4);
+        assert!(phantom.apply_operator(&gates::hadamard()).is_err());
+    }
+
+    #[test]
+    fn test_apply_operator_pauli_x_flips_basis_state() {
+        let mut phantom = Phantom::<TestPhantom>::new(2);
+        phantom.apply_operator(&gates::pauli_x()).unwrap();
+
+        let weights = phantom.born_weights();
+        assert!((weights[0] - 0.0).abs() < 1e-9);
+        assert!((weights[1] - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_phase_tracks_dominant_amplitude() {
+        let mut phantom = Phantom::<TestPhantom>::new(2);
+        phantom.apply_operator(&gates::pauli_x()).unwrap();
+        phantom.apply_operator(&gates::phase(std::f64::consts::FRAC_PI_2)).unwrap();
+
+        let phase = phantom.phase().unwrap();
+        assert!((phase - std::f64::consts::FRAC_PI_2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_mixed_and_full_precision_energy_agree_within_epsilon() {
+        const EPSILON: f64 = 1e-6;
+
+        let full = Phantom::<TestPhantom>::with_precision(4, Precision::Full);
+        let mixed = Phantom::<TestPhantom>::with_precision(4, Precision::Mixed);
+
+        let full_energy = full.phantom_energy().unwrap();
+        let mixed_energy = mixed.phantom_energy().unwrap();
+        assert!((full_energy - mixed_energy).abs() < EPSILON);
+        assert_eq!(mixed.precision(), Precision::Mixed);
+    }
 }
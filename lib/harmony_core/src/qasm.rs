@@ -0,0 +1,564 @@
+//! Quantum-Assembly DSL and Interpreter
+//! =====================================
+//!
+//! Author: Caleb J.D. Terkovics <isdood>
+//! Current User: isdood
+//! Created: 2026-07-31
+//! Version: 0.1.0
+//! License: MIT
+//!
+//! A small text-based instruction set for scripting sequences of
+//! phantom-state manipulations, instead of hand-wiring
+//! [`Phantom`]/[`CrystalLattice`]/`TrackedUFO` method calls one at a
+//! time. [`parse_program`] lexes and parses source text into a
+//! `Vec<Instr>`; [`Vm`] holds a register file of named handles and runs
+//! that program against them, producing a trace of per-instruction
+//! results plus the register file's final average coherence -- giving a
+//! reproducible, serializable way to author and replay phantom
+//! experiments.
+//!
+//! ```text
+//! MOVE r1, 1.0 2.0 3.0 1.0
+//! PHASE r1, 0.5
+//! SET r1, 1.0 1.0 1.0 42.0
+//! GET r1, 1.0 1.0 1.0
+//! PROJECT r1
+//! ENT u1, u2
+//! MEASURE r1
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+
+use errors::MathError;
+use magicmath::{
+    geometry::{Vector3D, Vector4D},
+    traits::{CrystalAdd, CrystalDiv, CrystalMul, CrystalSub, MeshValue},
+};
+use unstable_matter::tracked_ufo::{TrackedUFO, UFOState};
+
+use crate::phantom::Phantom;
+use crate::CrystalLattice;
+
+/// Concrete mesh value `Phantom` registers are parameterized over. The
+/// DSL only ever moves bare scalars through `SET`/`GET`, so there's no
+/// need for a caller-supplied type parameter -- this plays the same role
+/// `TestPhantom` plays in `phantom.rs`'s own tests.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct QasmValue(pub f64);
+
+impl MeshValue for QasmValue {
+    fn to_f64(&self) -> Result<f64, MathError> {
+        Ok(self.0)
+    }
+
+    fn from(value: f64) -> Self {
+        Self(value)
+    }
+
+    fn coherence(&self) -> Result<f64, MathError> {
+        Ok(1.0)
+    }
+
+    fn energy(&self) -> Result<f64, MathError> {
+        Ok(self.0.abs())
+    }
+
+    fn magnitude(&self) -> Result<f64, MathError> {
+        Ok(self.0.abs())
+    }
+
+    fn to_usize(&self) -> Result<usize, MathError> {
+        Ok(self.0 as usize)
+    }
+
+    fn check_harmony_state(&self) -> bool {
+        true
+    }
+}
+
+impl CrystalAdd for QasmValue {
+    fn add(&self, other: &Self) -> Result<Self, MathError> {
+        Ok(Self(self.0 + other.0))
+    }
+
+    fn add_assign(&mut self, other: &Self) -> Result<(), MathError> {
+        self.0 += other.0;
+        Ok(())
+    }
+}
+
+impl CrystalSub for QasmValue {
+    fn sub(&self, other: &Self) -> Result<Self, MathError> {
+        Ok(Self(self.0 - other.0))
+    }
+
+    fn sub_assign(&mut self, other: &Self) -> Result<(), MathError> {
+        self.0 -= other.0;
+        Ok(())
+    }
+}
+
+impl CrystalMul for QasmValue {
+    fn mul(&self, other: &Self) -> Result<Self, MathError> {
+        Ok(Self(self.0 * other.0))
+    }
+
+    fn mul_assign(&mut self, other: &Self) -> Result<(), MathError> {
+        self.0 *= other.0;
+        Ok(())
+    }
+}
+
+impl CrystalDiv for QasmValue {
+    fn div(&self, other: &Self) -> Result<Self, MathError> {
+        if other.0 == 0.0 {
+            return Err(MathError::DivisionByZero);
+        }
+        Ok(Self(self.0 / other.0))
+    }
+
+    fn div_assign(&mut self, other: &Self) -> Result<(), MathError> {
+        if other.0 == 0.0 {
+            return Err(MathError::DivisionByZero);
+        }
+        self.0 /= other.0;
+        Ok(())
+    }
+}
+
+/// A single quantum-assembly instruction, in register-machine form.
+#[derive(Debug, Clone)]
+pub enum Instr {
+    /// `MOVE r, x y z w` -- `Phantom::move_to`.
+    Move { reg: String, pos: Vector4D },
+    /// `PHASE r, theta` -- `Phase::phase_shift`.
+    Phase { reg: String, theta: f64 },
+    /// `SET r, x y z v` -- `Phantom::set_state`.
+    Set { reg: String, pos: Vector4D, value: f64 },
+    /// `GET r, x y z` -- `Phantom::get_state`.
+    Get { reg: String, pos: Vector4D },
+    /// `PROJECT r` -- `Phantom::project`.
+    Project { reg: String },
+    /// `ENT ra, rb` -- `TrackedUFO::entangle_with`.
+    Entangle { reg_a: String, reg_b: String },
+    /// `MEASURE r` -- reports `phantom_energy`/`resonance` without
+    /// mutating `r`.
+    Measure { reg: String },
+}
+
+/// Everything that can go wrong lexing, parsing, or executing a program.
+#[derive(Debug, Clone)]
+pub enum QasmError {
+    /// Malformed source text, with a human-readable reason.
+    Parse(String),
+    /// An instruction named a register nothing was ever bound to.
+    UnknownRegister(String),
+    /// An instruction expects a different kind of handle than what's
+    /// bound to the named register (e.g. `MEASURE` on a `TrackedUFO`).
+    WrongHandleKind { reg: String, expected: &'static str },
+    /// A `TrackedUFO` operation refused to run -- the same condition
+    /// `TrackedUFO::update_origin` already guards against, surfaced
+    /// through `entangle_with`.
+    QuantumUnstable(&'static str),
+    /// A `Phantom` operation failed for a math reason (boundary
+    /// violation, division by zero, ...).
+    Math(MathError),
+}
+
+impl fmt::Display for QasmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QasmError::Parse(reason) => write!(f, "parse error: {reason}"),
+            QasmError::UnknownRegister(reg) => write!(f, "unknown register `{reg}`"),
+            QasmError::WrongHandleKind { reg, expected } => {
+                write!(f, "register `{reg}` is not a {expected}")
+            }
+            QasmError::QuantumUnstable(reason) => write!(f, "quantum state unstable: {reason}"),
+            QasmError::Math(err) => write!(f, "math error: {err:?}"),
+        }
+    }
+}
+
+impl From<MathError> for QasmError {
+    fn from(err: MathError) -> Self {
+        QasmError::Math(err)
+    }
+}
+
+/// Splits a line into whitespace- and comma-delimited tokens, so both
+/// `MOVE r, 1 2 3 1` and `MOVE r 1 2 3 1` parse the same way.
+fn tokenize(line: &str) -> Vec<&str> {
+    line.split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|token| !token.is_empty())
+        .collect()
+}
+
+fn parse_f64(token: &str) -> Result<f64, QasmError> {
+    token
+        .parse()
+        .map_err(|_| QasmError::Parse(format!("invalid number `{token}`")))
+}
+
+fn expect_operands<'a, const N: usize>(
+    op: &str,
+    operands: &[&'a str],
+) -> Result<[&'a str; N], QasmError> {
+    operands.try_into().map_err(|_| {
+        QasmError::Parse(format!(
+            "`{op}` expects {N} operand(s), got {}",
+            operands.len()
+        ))
+    })
+}
+
+/// Lexes and parses `source` into a sequence of [`Instr`]s, one per
+/// non-blank, non-comment (`#`-prefixed) line.
+pub fn parse_program(source: &str) -> Result<Vec<Instr>, QasmError> {
+    source
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(parse_line)
+        .collect()
+}
+
+fn parse_line(line: &str) -> Result<Instr, QasmError> {
+    let tokens = tokenize(line);
+    let (op, operands) = tokens
+        .split_first()
+        .ok_or_else(|| QasmError::Parse("empty instruction".to_string()))?;
+
+    match op.to_ascii_uppercase().as_str() {
+        "MOVE" => {
+            let [reg, x, y, z, w] = expect_operands("MOVE", operands)?;
+            Ok(Instr::Move {
+                reg: reg.to_string(),
+                pos: Vector4D::new(parse_f64(x)?, parse_f64(y)?, parse_f64(z)?, parse_f64(w)?),
+            })
+        }
+        "PHASE" => {
+            let [reg, theta] = expect_operands("PHASE", operands)?;
+            Ok(Instr::Phase {
+                reg: reg.to_string(),
+                theta: parse_f64(theta)?,
+            })
+        }
+        "SET" => {
+            let [reg, x, y, z, v] = expect_operands("SET", operands)?;
+            Ok(Instr::Set {
+                reg: reg.to_string(),
+                pos: Vector4D::new(parse_f64(x)?, parse_f64(y)?, parse_f64(z)?, 1.0),
+                value: parse_f64(v)?,
+            })
+        }
+        "GET" => {
+            let [reg, x, y, z] = expect_operands("GET", operands)?;
+            Ok(Instr::Get {
+                reg: reg.to_string(),
+                pos: Vector4D::new(parse_f64(x)?, parse_f64(y)?, parse_f64(z)?, 1.0),
+            })
+        }
+        "PROJECT" => {
+            let [reg] = expect_operands("PROJECT", operands)?;
+            Ok(Instr::Project { reg: reg.to_string() })
+        }
+        "ENT" => {
+            let [reg_a, reg_b] = expect_operands("ENT", operands)?;
+            Ok(Instr::Entangle {
+                reg_a: reg_a.to_string(),
+                reg_b: reg_b.to_string(),
+            })
+        }
+        "MEASURE" => {
+            let [reg] = expect_operands("MEASURE", operands)?;
+            Ok(Instr::Measure { reg: reg.to_string() })
+        }
+        other => Err(QasmError::Parse(format!("unknown instruction `{other}`"))),
+    }
+}
+
+/// A handle a [`Vm`] register can be bound to.
+pub enum Handle {
+    Phantom(Phantom<QasmValue>),
+    Lattice(CrystalLattice),
+    Ufo(TrackedUFO),
+}
+
+/// What an instruction actually did, recorded in a [`Vm`]'s trace.
+#[derive(Debug, Clone)]
+pub enum InstrOutcome {
+    Moved,
+    PhaseShifted,
+    Set,
+    Got(f64),
+    Projected(Vector3D),
+    Entangled,
+    Measured { phantom_energy: f64, resonance: f64 },
+}
+
+/// Result of running a whole program: one outcome (or error) per
+/// instruction, in order, plus the register file's average coherence
+/// once the program finished.
+#[derive(Debug, Clone)]
+pub struct Trace {
+    pub results: Vec<Result<InstrOutcome, QasmError>>,
+    pub final_coherence: f64,
+}
+
+/// Register file plus interpreter loop for quantum-assembly programs.
+#[derive(Default)]
+pub struct Vm {
+    registers: HashMap<String, Handle>,
+}
+
+impl Vm {
+    pub fn new() -> Self {
+        Self { registers: HashMap::new() }
+    }
+
+    /// Binds `name` to `handle`, so a program can address it.
+    pub fn bind(&mut self, name: impl Into<String>, handle: Handle) {
+        self.registers.insert(name.into(), handle);
+    }
+
+    fn handle(&self, reg: &str) -> Result<&Handle, QasmError> {
+        self.registers
+            .get(reg)
+            .ok_or_else(|| QasmError::UnknownRegister(reg.to_string()))
+    }
+
+    fn handle_mut(&mut self, reg: &str) -> Result<&mut Handle, QasmError> {
+        self.registers
+            .get_mut(reg)
+            .ok_or_else(|| QasmError::UnknownRegister(reg.to_string()))
+    }
+
+    fn phantom_mut(&mut self, reg: &str) -> Result<&mut Phantom<QasmValue>, QasmError> {
+        match self.handle_mut(reg)? {
+            Handle::Phantom(phantom) => Ok(phantom),
+            _ => Err(QasmError::WrongHandleKind { reg: reg.to_string(), expected: "phantom" }),
+        }
+    }
+
+    fn phantom(&self, reg: &str) -> Result<&Phantom<QasmValue>, QasmError> {
+        match self.handle(reg)? {
+            Handle::Phantom(phantom) => Ok(phantom),
+            _ => Err(QasmError::WrongHandleKind { reg: reg.to_string(), expected: "phantom" }),
+        }
+    }
+
+    fn ufo(&self, reg: &str) -> Result<&TrackedUFO, QasmError> {
+        match self.handle(reg)? {
+            Handle::Ufo(ufo) => Ok(ufo),
+            _ => Err(QasmError::WrongHandleKind { reg: reg.to_string(), expected: "ufo" }),
+        }
+    }
+
+    /// Runs a single instruction against the register file.
+    fn exec(&mut self, instr: &Instr) -> Result<InstrOutcome, QasmError> {
+        match instr {
+            Instr::Move { reg, pos } => {
+                self.phantom_mut(reg)?.move_to(pos.clone())?;
+                Ok(InstrOutcome::Moved)
+            }
+            Instr::Phase { reg, theta } => {
+                self.phantom_mut(reg)?.phase_shift(*theta)?;
+                Ok(InstrOutcome::PhaseShifted)
+            }
+            Instr::Set { reg, pos, value } => {
+                self.phantom_mut(reg)?.set_state(pos, QasmValue(*value))?;
+                Ok(InstrOutcome::Set)
+            }
+            Instr::Get { reg, pos } => {
+                let value = self.phantom(reg)?.get_state(pos)?;
+                Ok(InstrOutcome::Got(value.0))
+            }
+            Instr::Project { reg } => Ok(InstrOutcome::Projected(self.phantom(reg)?.project())),
+            Instr::Entangle { reg_a, reg_b } => {
+                let ufo_a = self.ufo(reg_a)?;
+                let ufo_b = self.ufo(reg_b)?;
+                ufo_a
+                    .entangle_with(ufo_b)
+                    .map_err(QasmError::QuantumUnstable)?;
+                Ok(InstrOutcome::Entangled)
+            }
+            // Read-only: `phantom_energy`/`resonance` only ever borrow
+            // `self`, so measuring never mutates the register's state
+            // and is safe to repeat any number of times.
+            Instr::Measure { reg } => {
+                let phantom = self.phantom(reg)?;
+                Ok(InstrOutcome::Measured {
+                    phantom_energy: phantom.phantom_energy()?,
+                    resonance: phantom.resonance().energy()?,
+                })
+            }
+        }
+    }
+
+    /// Runs every instruction in `program` in order, collecting one
+    /// result per instruction -- a failing instruction doesn't stop the
+    /// rest of the program, so the trace always covers every line.
+    pub fn run(&mut self, program: &[Instr]) -> Trace {
+        let results: Vec<_> = program.iter().map(|instr| self.exec(instr)).collect();
+        Trace { results, final_coherence: self.average_coherence() }
+    }
+
+    /// Average coherence across every bound register with a notion of
+    /// one: `TrackedUFO::get_coherence` directly, `Phantom::phantom_energy`
+    /// as its closest analog. Lattices don't carry a single coherence
+    /// value and are skipped.
+    fn average_coherence(&self) -> f64 {
+        let values: Vec<f64> = self
+            .registers
+            .values()
+            .filter_map(|handle| match handle {
+                Handle::Ufo(ufo) => Some(ufo.get_coherence()),
+                Handle::Phantom(phantom) => phantom.phantom_energy().ok(),
+                Handle::Lattice(_) => None,
+            })
+            .collect();
+
+        if values.is_empty() {
+            return 0.0;
+        }
+        values.iter().sum::<f64>() / values.len() as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vm_with_phantom(reg: &str) -> Vm {
+        let mut vm = Vm::new();
+        vm.bind(reg, Handle::Phantom(Phantom::<QasmValue>::new(4)));
+        vm
+    }
+
+    #[test]
+    fn test_parse_program_round_trip() {
+        let program = parse_program(
+            "MOVE r1, 1.0 2.0 3.0 1.0\n\
+             # comment lines and blanks are skipped\n\
+             \n\
+             PHASE r1, 0.5\n\
+             MEASURE r1",
+        )
+        .unwrap();
+
+        assert_eq!(program.len(), 3);
+        assert!(matches!(program[0], Instr::Move { .. }));
+        assert!(matches!(program[1], Instr::Phase { .. }));
+        assert!(matches!(program[2], Instr::Measure { .. }));
+    }
+
+    #[test]
+    fn test_parse_rejects_wrong_operand_count() {
+        let err = parse_program("MOVE r1, 1.0 2.0").unwrap_err();
+        assert!(matches!(err, QasmError::Parse(_)));
+    }
+
+    #[test]
+    fn test_move_and_project() {
+        let mut vm = vm_with_phantom("r1");
+        let program = parse_program("MOVE r1, 2.0 2.0 2.0 2.0\nPROJECT r1").unwrap();
+        let trace = vm.run(&program);
+
+        assert!(matches!(trace.results[0], Ok(InstrOutcome::Moved)));
+        match &trace.results[1] {
+            Ok(InstrOutcome::Projected(proj)) => {
+                assert_eq!(proj.x, 1.0);
+                assert_eq!(proj.y, 1.0);
+                assert_eq!(proj.z, 1.0);
+            }
+            other => panic!("expected Projected, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_set_and_get() {
+        let mut vm = vm_with_phantom("r1");
+        let program = parse_program("SET r1, 1.0 1.0 1.0 42.0\nGET r1, 1.0 1.0 1.0").unwrap();
+        let trace = vm.run(&program);
+
+        assert!(matches!(trace.results[0], Ok(InstrOutcome::Set)));
+        assert!(matches!(trace.results[1], Ok(InstrOutcome::Got(value)) if value == 42.0));
+    }
+
+    #[test]
+    fn test_measure_is_idempotent() {
+        let mut vm = vm_with_phantom("r1");
+        let program = parse_program("MEASURE r1\nMEASURE r1").unwrap();
+        let trace = vm.run(&program);
+
+        let energies: Vec<f64> = trace
+            .results
+            .iter()
+            .map(|result| match result {
+                Ok(InstrOutcome::Measured { phantom_energy, .. }) => *phantom_energy,
+                other => panic!("expected Measured, got {other:?}"),
+            })
+            .collect();
+        assert_eq!(energies[0], energies[1]);
+    }
+
+    #[test]
+    fn test_entangle_two_ufos() {
+        let mut vm = Vm::new();
+        vm.bind("u1", Handle::Ufo(TrackedUFO::new(0x1000, 0x1000)));
+        vm.bind("u2", Handle::Ufo(TrackedUFO::new(0x2000, 0x1000)));
+
+        let program = parse_program("ENT u1, u2").unwrap();
+        let trace = vm.run(&program);
+
+        assert!(matches!(trace.results[0], Ok(InstrOutcome::Entangled)));
+    }
+
+    #[test]
+    fn test_entangle_decoherent_ufo_fails() {
+        let decoherent = TrackedUFO::new(0x1000, 0x1000);
+        for _ in 0..200 {
+            let _ = decoherent.update_origin(0x1000);
+        }
+        assert_eq!(decoherent.get_quantum_state(), UFOState::Decoherent);
+
+        let mut vm = Vm::new();
+        vm.bind("u1", Handle::Ufo(decoherent));
+        vm.bind("u2", Handle::Ufo(TrackedUFO::new(0x2000, 0x1000)));
+
+        let program = parse_program("ENT u1, u2").unwrap();
+        let trace = vm.run(&program);
+
+        assert!(matches!(trace.results[0], Err(QasmError::QuantumUnstable(_))));
+    }
+
+    #[test]
+    fn test_unknown_register_errors() {
+        let mut vm = Vm::new();
+        let program = parse_program("MEASURE ghost").unwrap();
+        let trace = vm.run(&program);
+
+        match &trace.results[0] {
+            Err(QasmError::UnknownRegister(reg)) => assert_eq!(reg, "ghost"),
+            other => panic!("expected UnknownRegister, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_wrong_handle_kind_errors() {
+        let mut vm = Vm::new();
+        vm.bind("u1", Handle::Ufo(TrackedUFO::new(0x1000, 0x1000)));
+
+        let program = parse_program("MEASURE u1").unwrap();
+        let trace = vm.run(&program);
+
+        match &trace.results[0] {
+            Err(QasmError::WrongHandleKind { reg, expected }) => {
+                assert_eq!(reg, "u1");
+                assert_eq!(*expected, "phantom");
+            }
+            other => panic!("expected WrongHandleKind, got {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,294 @@
+//! Crystal Resonance Graph
+//! =======================
+//!
+//! Author: Caleb J.D. Terkovics <isdood>
+//! Current User: isdood
+//! Created: 2026-07-28
+//! Version: 0.1.0
+//! License: MIT
+
+use errors::MathError;
+
+/// Handle for a crystal registered with a [`ResonanceGraph`].
+pub type CrystalId = usize;
+
+/// Upper bound on propagation rounds before a component is declared
+/// non-convergent. Mirrors the cap used by the fractal/complex iteration
+/// loops elsewhere in the tree.
+const MAX_PROPAGATION_ITERATIONS: usize = 1_000;
+
+/// A component converges once its widest pairwise frequency gap drops
+/// below this, matching `Crystal::synchronize`'s own mismatch tolerance.
+const CONVERGENCE_THRESHOLD: f64 = 1.0;
+
+/// A networked lattice of crystals that synchronize resonance frequencies
+/// across links, rather than `Crystal::synchronize` averaging one pair at
+/// a time.
+///
+/// Modeled on Scryer's `ugraphs`: crystals are nodes in an adjacency-list
+/// graph, and a synchronization link between two crystals is an edge that
+/// may be directed (one-way influence) or undirected (mutual resonance).
+#[derive(Debug, Default)]
+pub struct ResonanceGraph {
+    frequencies: Vec<f64>,
+    edges: Vec<Vec<CrystalId>>,
+}
+
+impl ResonanceGraph {
+    /// Create an empty resonance graph.
+    pub fn new() -> Self {
+        Self {
+            frequencies: Vec::new(),
+            edges: Vec::new(),
+        }
+    }
+
+    /// Register a crystal with the given starting frequency and return its
+    /// [`CrystalId`].
+    pub fn add_crystal(&mut self, frequency: f64) -> CrystalId {
+        let id = self.frequencies.len();
+        self.frequencies.push(frequency);
+        self.edges.push(Vec::new());
+        id
+    }
+
+    /// Number of crystals registered with the graph.
+    pub fn len(&self) -> usize {
+        self.frequencies.len()
+    }
+
+    /// Whether the graph holds no crystals.
+    pub fn is_empty(&self) -> bool {
+        self.frequencies.is_empty()
+    }
+
+    /// Current frequency of a crystal.
+    pub fn frequency(&self, node: CrystalId) -> f64 {
+        self.frequencies[node]
+    }
+
+    /// Add a synchronization link from `from` to `to`. When `directed` is
+    /// `false` the reverse link is added as well, so resonance flows both
+    /// ways.
+    pub fn add_edge(&mut self, from: CrystalId, to: CrystalId, directed: bool) {
+        self.edges[from].push(to);
+        if !directed {
+            self.edges[to].push(from);
+        }
+    }
+
+    /// Crystals directly linked from `node`.
+    pub fn neighbors(&self, node: CrystalId) -> &[CrystalId] {
+        &self.edges[node]
+    }
+
+    /// Adjacency with every edge treated as undirected, used for grouping
+    /// and propagation: a one-way influence link still lets resonance
+    /// drift back along it.
+    fn undirected_adjacency(&self) -> Vec<Vec<CrystalId>> {
+        let mut adjacency = vec![Vec::new(); self.frequencies.len()];
+        for (node, targets) in self.edges.iter().enumerate() {
+            for &target in targets {
+                adjacency[node].push(target);
+                adjacency[target].push(node);
+            }
+        }
+        adjacency
+    }
+
+    /// Group crystals into their weakly-connected components: two crystals
+    /// are in the same component if a chain of links (followed in either
+    /// direction) connects them.
+    pub fn connected_components(&self) -> Vec<Vec<CrystalId>> {
+        let adjacency = self.undirected_adjacency();
+        let mut visited = vec![false; self.frequencies.len()];
+        let mut components = Vec::new();
+
+        for start in 0..self.frequencies.len() {
+            if visited[start] {
+                continue;
+            }
+
+            let mut component = Vec::new();
+            let mut stack = vec![start];
+            visited[start] = true;
+
+            while let Some(node) = stack.pop() {
+                component.push(node);
+                for &neighbor in &adjacency[node] {
+                    if !visited[neighbor] {
+                        visited[neighbor] = true;
+                        stack.push(neighbor);
+                    }
+                }
+            }
+
+            component.sort_unstable();
+            components.push(component);
+        }
+
+        components
+    }
+
+    /// For every crystal, the set of crystals reachable by following
+    /// directed resonance links outward (undirected edges count both
+    /// ways). This is the reach of a crystal's resonance influence, not
+    /// merely its direct neighbors.
+    pub fn transitive_closure(&self) -> Vec<Vec<CrystalId>> {
+        let mut closure = Vec::with_capacity(self.frequencies.len());
+
+        for start in 0..self.frequencies.len() {
+            let mut visited = vec![false; self.frequencies.len()];
+            visited[start] = true;
+            let mut stack = vec![start];
+            let mut reachable = Vec::new();
+
+            while let Some(node) = stack.pop() {
+                for &neighbor in &self.edges[node] {
+                    if !visited[neighbor] {
+                        visited[neighbor] = true;
+                        reachable.push(neighbor);
+                        stack.push(neighbor);
+                    }
+                }
+            }
+
+            reachable.sort_unstable();
+            closure.push(reachable);
+        }
+
+        closure
+    }
+
+    /// Synchronize the whole lattice: within each connected component,
+    /// iteratively average every crystal's frequency with its neighbors'
+    /// until the widest pairwise gap in the component drops below
+    /// [`CONVERGENCE_THRESHOLD`].
+    ///
+    /// Returns `Err(MathError::ResonanceLoss)` if a component still hasn't
+    /// reconciled after [`MAX_PROPAGATION_ITERATIONS`] rounds, reusing the
+    /// same iteration-cap guard the rest of the tree uses to bound
+    /// convergence loops.
+    pub fn propagate(&mut self) -> Result<(), MathError> {
+        let adjacency = self.undirected_adjacency();
+
+        for component in self.connected_components() {
+            if component.len() < 2 {
+                continue;
+            }
+
+            let mut converged = false;
+            for _ in 0..MAX_PROPAGATION_ITERATIONS {
+                let snapshot = self.frequencies.clone();
+
+                for &node in &component {
+                    let mut sum = snapshot[node];
+                    let mut count = 1usize;
+                    for &neighbor in &adjacency[node] {
+                        sum += snapshot[neighbor];
+                        count += 1;
+                    }
+                    self.frequencies[node] = sum / count as f64;
+                }
+
+                let (lo, hi) = component.iter().fold(
+                    (f64::INFINITY, f64::NEG_INFINITY),
+                    |(lo, hi), &node| {
+                        let freq = self.frequencies[node];
+                        (lo.min(freq), hi.max(freq))
+                    },
+                );
+
+                if hi - lo < CONVERGENCE_THRESHOLD {
+                    converged = true;
+                    break;
+                }
+            }
+
+            if !converged {
+                return Err(MathError::resonance_loss(
+                    "crystal resonance graph component did not reconcile within the iteration cap",
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_crystal_and_edge() {
+        let mut graph = ResonanceGraph::new();
+        let a = graph.add_crystal(1.0);
+        let b = graph.add_crystal(2.0);
+        graph.add_edge(a, b, false);
+
+        assert_eq!(graph.len(), 2);
+        assert_eq!(graph.neighbors(a), &[b]);
+        assert_eq!(graph.neighbors(b), &[a]);
+    }
+
+    #[test]
+    fn test_directed_edge_is_one_way() {
+        let mut graph = ResonanceGraph::new();
+        let a = graph.add_crystal(1.0);
+        let b = graph.add_crystal(2.0);
+        graph.add_edge(a, b, true);
+
+        assert_eq!(graph.neighbors(a), &[b]);
+        assert!(graph.neighbors(b).is_empty());
+    }
+
+    #[test]
+    fn test_connected_components() {
+        let mut graph = ResonanceGraph::new();
+        let a = graph.add_crystal(1.0);
+        let b = graph.add_crystal(2.0);
+        let c = graph.add_crystal(3.0);
+        graph.add_edge(a, b, false);
+
+        let mut components = graph.connected_components();
+        components.sort_by_key(|component| component[0]);
+
+        assert_eq!(components, vec![vec![a, b], vec![c]]);
+    }
+
+    #[test]
+    fn test_transitive_closure_follows_directed_edges() {
+        let mut graph = ResonanceGraph::new();
+        let a = graph.add_crystal(1.0);
+        let b = graph.add_crystal(1.0);
+        let c = graph.add_crystal(1.0);
+        graph.add_edge(a, b, true);
+        graph.add_edge(b, c, true);
+
+        let closure = graph.transitive_closure();
+        assert_eq!(closure[a], vec![b, c]);
+        assert_eq!(closure[b], vec![c]);
+        assert!(closure[c].is_empty());
+    }
+
+    #[test]
+    fn test_propagate_converges_component() {
+        let mut graph = ResonanceGraph::new();
+        let a = graph.add_crystal(0.0);
+        let b = graph.add_crystal(10.0);
+        graph.add_edge(a, b, false);
+
+        assert!(graph.propagate().is_ok());
+        assert!((graph.frequency(a) - graph.frequency(b)).abs() < CONVERGENCE_THRESHOLD);
+    }
+
+    #[test]
+    fn test_propagate_leaves_isolated_crystal_untouched() {
+        let mut graph = ResonanceGraph::new();
+        let a = graph.add_crystal(42.0);
+
+        assert!(graph.propagate().is_ok());
+        assert_eq!(graph.frequency(a), 42.0);
+    }
+}
@@ -0,0 +1,150 @@
+//! Poseidon-style sponge shared by both `CrystalLattice` implementations
+//! in this crate (`lib.rs`'s and `crystal.rs`'s -- see their module docs
+//! for why there are two). Each commits its occupied nodes to a
+//! [`LatticeDigest`] through [`commit`], so a future correction to round
+//! count, prime, or node packing only has to be made here once instead
+//! of drifting between two copies and silently desyncing what each
+//! lattice considers "tamper-evident".
+
+/// Width of the sponge's permutation state. Three lanes: one rate lane
+/// absorbs/squeezes, the other two act as capacity so recovering the
+/// input from the digest alone means inverting the permutation.
+const SPONGE_WIDTH: usize = 3;
+/// Full rounds (S-box on every lane) run split evenly before and after
+/// the partial rounds, as in the standard Poseidon round schedule.
+const SPONGE_FULL_ROUNDS: usize = 8;
+/// Partial rounds (S-box on only the first lane) sandwiched between the
+/// full rounds; cheaper per round while still mixing every lane via MDS.
+const SPONGE_PARTIAL_ROUNDS: usize = 16;
+/// A 61-bit Mersenne prime. Every lane stays below this, so two lanes
+/// multiplied together never overflow a `u128` accumulator.
+const SPONGE_PRIME: u64 = (1u64 << 61) - 1;
+/// Small fixed MDS-style mixing matrix, applied mod `SPONGE_PRIME` after
+/// every round's S-box layer.
+const SPONGE_MDS: [[u64; SPONGE_WIDTH]; SPONGE_WIDTH] = [
+    [2, 3, 1],
+    [1, 2, 3],
+    [3, 1, 2],
+];
+/// Coherence is quantized to an integer (scaled by this factor) before
+/// being absorbed, so the digest never depends on float rounding.
+const COHERENCE_QUANTIZATION: f64 = 1_000_000.0;
+
+/// Verifiable fingerprint of a `CrystalLattice`'s occupied nodes,
+/// produced by [`commit`] and checked by comparing two digests for
+/// equality.
+///
+/// Built from a fixed-width sponge/permutation hash in the style of
+/// Poseidon: each occupied node's packed `(x, y, coherence)` encoding is
+/// absorbed into the state, the state is permuted between absorptions,
+/// and the first lane is squeezed out as the digest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LatticeDigest(u64);
+
+/// Fingerprints `nodes` -- an iterator over every occupied node's
+/// `(x, y, coherence)` -- into a [`LatticeDigest`].
+///
+/// Each node is absorbed in whatever order `nodes` iterates, so the
+/// result depends only on which `(x, y, coherence)` triples are present,
+/// never on the order they were inserted in.
+pub(crate) fn commit(nodes: impl Iterator<Item = (usize, usize, f64)>) -> LatticeDigest {
+    let mut state = [0u64; SPONGE_WIDTH];
+
+    for (x, y, coherence) in nodes {
+        absorb(&mut state, pack_node(x, y, coherence));
+    }
+
+    LatticeDigest(state[0])
+}
+
+/// Fold a node's grid index and quantized coherence into one lane value.
+/// `x`/`y` get 20 bits each (`MAX_QUANTUM_SIZE` caps lattices well under
+/// 2^20), leaving the low 24 bits for quantized coherence.
+fn pack_node(x: usize, y: usize, coherence: f64) -> u64 {
+    let quantized = (coherence * COHERENCE_QUANTIZATION).round() as i64;
+    let quantized = (quantized & 0x00FF_FFFF) as u64;
+    ((x as u64 & 0xF_FFFF) << 44) | ((y as u64 & 0xF_FFFF) << 24) | quantized
+}
+
+/// Absorb `value` into the rate lane and permute the state.
+fn absorb(state: &mut [u64; SPONGE_WIDTH], value: u64) {
+    state[0] = add_mod(state[0], value);
+    permute(state);
+}
+
+/// Run the full Poseidon-style round schedule: half the full rounds,
+/// then the partial rounds, then the remaining full rounds.
+fn permute(state: &mut [u64; SPONGE_WIDTH]) {
+    let mut round = 0;
+
+    for _ in 0..SPONGE_FULL_ROUNDS / 2 {
+        full_round(state, round);
+        round += 1;
+    }
+    for _ in 0..SPONGE_PARTIAL_ROUNDS {
+        partial_round(state, round);
+        round += 1;
+    }
+    for _ in 0..SPONGE_FULL_ROUNDS / 2 {
+        full_round(state, round);
+        round += 1;
+    }
+}
+
+/// Add round constants and apply the S-box to every lane, then mix.
+fn full_round(state: &mut [u64; SPONGE_WIDTH], round: usize) {
+    for (lane, value) in state.iter_mut().enumerate() {
+        *value = add_mod(*value, round_constant(round, lane));
+        *value = sbox(*value);
+    }
+    mix(state);
+}
+
+/// Add round constants to every lane but apply the S-box only to the
+/// first, then mix.
+fn partial_round(state: &mut [u64; SPONGE_WIDTH], round: usize) {
+    for (lane, value) in state.iter_mut().enumerate() {
+        *value = add_mod(*value, round_constant(round, lane));
+    }
+    state[0] = sbox(state[0]);
+    mix(state);
+}
+
+/// Mix lanes via `SPONGE_MDS`, mod `SPONGE_PRIME`.
+fn mix(state: &mut [u64; SPONGE_WIDTH]) {
+    let mut mixed = [0u64; SPONGE_WIDTH];
+
+    for (i, slot) in mixed.iter_mut().enumerate() {
+        let mut acc: u128 = 0;
+        for j in 0..SPONGE_WIDTH {
+            acc += SPONGE_MDS[i][j] as u128 * state[j] as u128;
+        }
+        *slot = (acc % SPONGE_PRIME as u128) as u64;
+    }
+
+    *state = mixed;
+}
+
+/// `x -> x^5 mod SPONGE_PRIME`, the sponge's S-box.
+fn sbox(x: u64) -> u64 {
+    let x = x as u128;
+    let p = SPONGE_PRIME as u128;
+    let x2 = (x * x) % p;
+    let x4 = (x2 * x2) % p;
+    ((x4 * x) % p) as u64
+}
+
+fn add_mod(a: u64, b: u64) -> u64 {
+    (a + b) % SPONGE_PRIME
+}
+
+/// Deterministic per-(round, lane) constant, mixed from a splitmix64-style
+/// avalanche rather than a hardcoded table, reduced into `SPONGE_PRIME`.
+fn round_constant(round: usize, lane: usize) -> u64 {
+    let mut z = (round as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+        ^ (lane as u64).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^= z >> 31;
+    z % SPONGE_PRIME
+}
@@ -5,10 +5,12 @@
 pub mod harmony;
 pub mod crystal;
 pub mod whimsy;
+pub mod runtime;
 
 pub use harmony::HarmonyField;
 pub use crystal::CrystalBridge;
 pub use whimsy::WhimsyEngine;
+pub use runtime::CrystalRuntime;
 
 #[cfg(test)]
 mod tests {
@@ -2,7 +2,6 @@
 //! Created: 2025-01-21 23:31:38 UTC
 //! Author: isdood
 
-use std::future::Future;
 use std::sync::Arc;
 
 #[repr(C)]
@@ -19,11 +18,14 @@ impl CrystalRuntime {
         }
     }
 
-    pub async fn spawn<F>(&self, future: F) -> Result<(), Box<dyn std::error::Error>>
-    where
-        F: Future<Output = Result<(), Box<dyn std::error::Error>>> + Send + 'static,
-    {
-        // Implementation using FFI
+    /// Dispatches a serialized task to the native crystal core and awaits
+    /// its completion. `crystal_core_process_task` is synchronous, so the
+    /// returned future resolves as soon as the call returns -- this is the
+    /// one boundary where task bytes actually cross into native code.
+    pub async fn spawn(&self, task: Vec<u8>) -> Result<(), Box<dyn std::error::Error>> {
+        unsafe {
+            crystal_core_process_task(self.core, task.as_ptr(), task.len());
+        }
         Ok(())
     }
 }
@@ -0,0 +1,171 @@
+//! Memoization Cache for Repeated Square Root Evaluations
+//! ===========================================
+//!
+//! Author: Caleb J.D. Terkovics <isdood>
+//! Current User: isdood
+//! Created: 2026-07-31
+//! Version: 0.1.0
+//! License: MIT
+//!
+//! Iterative lattice sweeps tend to re-evaluate `quantum_sqrt`/`newton_sqrt`
+//! on the same handful of recurring magnitudes millions of times. `SqrtCache`
+//! sits in front of `newton_sqrt`'s iteration with a small fixed-capacity,
+//! open-addressed table keyed on the bit-pattern of the `f64` input, so a
+//! repeat lookup skips the Newton loop entirely.
+
+/// Slot count of a `SqrtCache`'s backing table. Small and fixed so the
+/// cache itself stays a cheap linear scan rather than growing into a
+/// second hash map sitting in front of the one it's avoiding.
+const SQRT_CACHE_CAPACITY: usize = 32;
+
+#[derive(Clone, Copy)]
+struct SqrtCacheSlot {
+    key: u64,
+    value: f64,
+    occupied: bool,
+    /// Clock (second-chance) reference bit: set on every hit, cleared the
+    /// first time the clock hand sweeps past it without evicting it.
+    referenced: bool,
+}
+
+impl SqrtCacheSlot {
+    const EMPTY: Self = Self {
+        key: 0,
+        value: 0.0,
+        occupied: false,
+        referenced: false,
+    };
+}
+
+/// Fixed-capacity memoization table for `newton_sqrt` convergence,
+/// keyed on the bit-pattern (`f64::to_bits`) of the input value.
+///
+/// Open-addressed with linear probing and clock eviction: a full table
+/// evicts the first unreferenced slot the clock hand finds, giving
+/// recently-hit entries a second chance before they're reclaimed.
+pub struct SqrtCache {
+    slots: [SqrtCacheSlot; SQRT_CACHE_CAPACITY],
+    clock_hand: usize,
+}
+
+impl SqrtCache {
+    /// An empty cache with the table's fixed capacity.
+    pub fn new() -> Self {
+        Self {
+            slots: [SqrtCacheSlot::EMPTY; SQRT_CACHE_CAPACITY],
+            clock_hand: 0,
+        }
+    }
+
+    /// Converged `newton_sqrt` result for `input`, if it's still cached.
+    pub fn get(&mut self, input: f64) -> Option<f64> {
+        let key = input.to_bits();
+        let index = self.probe(key)?;
+        self.slots[index].referenced = true;
+        Some(self.slots[index].value)
+    }
+
+    /// Records the converged result of `newton_sqrt(input)`, evicting an
+    /// unreferenced entry via the clock hand if the table is full.
+    pub fn insert(&mut self, input: f64, value: f64) {
+        let key = input.to_bits();
+
+        if let Some(index) = self.probe(key) {
+            self.slots[index].value = value;
+            self.slots[index].referenced = true;
+            return;
+        }
+
+        let index = self.slot_for_insert(key);
+        self.slots[index] = SqrtCacheSlot {
+            key,
+            value,
+            occupied: true,
+            referenced: true,
+        };
+    }
+
+    /// Drops every cached entry. Call this whenever the surrounding
+    /// lattice state changes in a way that would make a cached Newton
+    /// result stale (e.g. a `MetricTensor::quantize`/`realign` mutation).
+    pub fn invalidate(&mut self) {
+        self.slots = [SqrtCacheSlot::EMPTY; SQRT_CACHE_CAPACITY];
+        self.clock_hand = 0;
+    }
+
+    fn probe(&self, key: u64) -> Option<usize> {
+        self.slots
+            .iter()
+            .position(|slot| slot.occupied && slot.key == key)
+    }
+
+    /// Advances the clock hand until it finds an unreferenced slot (an
+    /// empty one, or one whose reference bit it has already cleared),
+    /// clearing reference bits along the way so every occupied slot gets
+    /// one more sweep before eviction.
+    fn slot_for_insert(&mut self, key: u64) -> usize {
+        if let Some(index) = self.slots.iter().position(|slot| !slot.occupied) {
+            return index;
+        }
+
+        loop {
+            let index = self.clock_hand;
+            self.clock_hand = (self.clock_hand + 1) % SQRT_CACHE_CAPACITY;
+
+            if !self.slots[index].referenced {
+                return index;
+            }
+            self.slots[index].referenced = false;
+        }
+        #[allow(unreachable_code)]
+        {
+            let _ = key;
+            unreachable!("clock sweep always finds an unreferenced slot in a full table")
+        }
+    }
+}
+
+impl Default for SqrtCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_miss_then_hit() {
+        let mut cache = SqrtCache::new();
+        assert_eq!(cache.get(4.0), None);
+
+        cache.insert(4.0, 2.0);
+        assert_eq!(cache.get(4.0), Some(2.0));
+    }
+
+    #[test]
+    fn test_invalidate_clears_entries() {
+        let mut cache = SqrtCache::new();
+        cache.insert(9.0, 3.0);
+        assert_eq!(cache.get(9.0), Some(3.0));
+
+        cache.invalidate();
+        assert_eq!(cache.get(9.0), None);
+    }
+
+    #[test]
+    fn test_eviction_keeps_recently_referenced_entry() {
+        let mut cache = SqrtCache::new();
+        for i in 0..SQRT_CACHE_CAPACITY {
+            cache.insert(i as f64, (i as f64).sqrt());
+        }
+
+        // Re-reference the first entry so its clock bit survives one sweep.
+        assert_eq!(cache.get(0.0), Some(0.0));
+
+        // Force eviction by inserting past capacity.
+        cache.insert(1000.0, 31.622776601683793);
+        assert_eq!(cache.get(0.0), Some(0.0));
+    }
+}
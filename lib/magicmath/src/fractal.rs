@@ -8,6 +8,8 @@
 //! Version: 0.1.0
 //! License: MIT
 
+use num_complex::Complex64;
+
 use crate::{
     errors::MathError,
     constants::{
@@ -28,7 +30,7 @@ use crate::{
         pi::quantum_pi,
         fibb::quantum_fibonacci,
         sqrt::quantum_sqrt,
-        log::quantum_ln
+        log::{quantum_ln, LnMode}
     },
     julia::{self, JuliaParams, JuliaState, JuliaVariant},
     brot::{self, MandelbrotParams, MandelbrotState, MandelbrotVariant}
@@ -77,12 +79,17 @@ pub enum FractalState {
 /// Custom fractal state
 #[derive(Debug, Clone)]
 pub struct CustomState {
-    z_real: f64,
-    z_imag: f64,
+    z: Complex64,
     iterations: usize,
     stability: f64,
     phase: f64,
     escape_time: Option<usize>,
+    /// Fractional escape-time: `escape_time` rounded up to the nearest
+    /// integer plus a continuous correction from the double-logarithm
+    /// of `|z|` at the escaping iteration. Gives band-free shading
+    /// instead of the hard rings `escape_time` alone produces. `None`
+    /// until the orbit escapes, same as `escape_time`.
+    smooth_escape: Option<f64>,
 }
 
 impl FractalState {
@@ -103,6 +110,16 @@ impl FractalState {
             FractalState::Custom(state) => state.escape_time,
         }
     }
+
+    /// Get the fractional (smooth) escape time, if this state supports
+    /// one. Only `FractalState::Custom` tracks it today.
+    pub fn smooth_escape(&self) -> Option<f64> {
+        match self {
+            FractalState::Julia(_) => None,
+            FractalState::Mandelbrot(_) => None,
+            FractalState::Custom(state) => state.smooth_escape,
+        }
+    }
 }
 
 /// Generate fractal with specified parameters
@@ -160,25 +177,42 @@ fn iterate_custom_fractal(
     state: CustomState,
     params: &FractalParams
 ) -> Result<FractalState, MathError> {
-    // Custom fractal implementation
+    // Custom fractal implementation: z = z*z*(phi*pi) + c, with c drawn
+    // from the quantum Fibonacci sequence so the orbit keeps the same
+    // iteration-dependent drift the original linear version had.
     let phi = quantum_phi(1.0)?;
     let pi = quantum_pi(1.0)?;
     let fib = quantum_fibonacci(state.iterations + 2)?;
 
-    let new_real = state.z_real * phi * pi + fib;
-    let new_imag = state.z_imag * phi * pi + fib;
+    let c = Complex64::new(fib, fib);
+    let new_z = state.z * state.z * (phi * pi) + c;
+
+    let escape_radius_sq = params.escape_radius * params.escape_radius;
+    let (escape_time, smooth_escape) = if new_z.norm_sqr() > escape_radius_sq {
+        let norm = new_z.norm();
+        let smooth = if norm > 1.0 {
+            Some(
+                (state.iterations as f64) + 1.0
+                    - quantum_ln(quantum_ln(norm, LnMode::Checked)?, LnMode::Checked)?
+                        / quantum_ln(2.0, LnMode::Checked)?,
+            )
+        } else {
+            // Guard against the double logarithm producing NaN for
+            // |z| <= 1.0; fall back to the plain integer escape count.
+            None
+        };
+        (Some(state.iterations), smooth)
+    } else {
+        (None, None)
+    };
 
     let new_state = CustomState {
-        z_real: new_real,
-        z_imag: new_imag,
+        z: new_z,
         iterations: state.iterations + 1,
         stability: state.stability * params.stability_factor,
         phase: (state.phase + params.phase_shift * PHASE_FRACTAL_FACTOR) % TAU,
-        escape_time: if new_real * new_real + new_imag * new_imag > params.escape_radius * params.escape_radius {
-            Some(state.iterations)
-        } else {
-            None
-        },
+        escape_time,
+        smooth_escape,
     };
 
     Ok(FractalState::Custom(new_state))
@@ -211,12 +245,12 @@ mod tests {
     #[test]
     fn test_custom_fractal() {
         let state = FractalState::Custom(CustomState {
-            z_real: 0.0,
-            z_imag: 0.0,
+            z: Complex64::new(0.0, 0.0),
             iterations: 0,
             stability: 1.0,
             phase: 0.0,
             escape_time: None,
+            smooth_escape: None,
         });
         let mut params = FractalParams::default();
         params.fractal_type = FractalType::Custom;
@@ -225,6 +259,26 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_custom_fractal_smooth_escape_is_band_free() {
+        // A starting orbit already well outside the escape radius, so
+        // the very first iteration escapes and populates both fields.
+        let state = FractalState::Custom(CustomState {
+            z: Complex64::new(10.0, 10.0),
+            iterations: 0,
+            stability: 1.0,
+            phase: 0.0,
+            escape_time: None,
+            smooth_escape: None,
+        });
+        let mut params = FractalParams::default();
+        params.fractal_type = FractalType::Custom;
+
+        let result = generate_fractal(state, &params).unwrap();
+        assert_eq!(result.escape_time(), Some(0));
+        assert!(result.smooth_escape().unwrap().is_finite());
+    }
+
     #[test]
     fn test_type_mismatch() {
         let state = FractalState::Julia(JuliaState::new(0.0, 0.0));
@@ -0,0 +1,82 @@
+//! Vectorized harmony-state validation.
+//! ======================================
+//!
+//! `CrystalSub::sub` (see `sub.rs`) calls `check_harmony_state()` on
+//! each operand and the result one `MeshValue` at a time -- fine for a
+//! single subtraction, but validating every element of a whole array
+//! that way costs one branch per element. `check_harmony_state_simd`
+//! instead computes the harmony predicate for a `LANES`-wide chunk at
+//! once and reduces it with a single horizontal step per chunk, the
+//! same mask-then-reduce shape a real SIMD backend uses: aarch64's
+//! `vminvq_u8`/`vmaxvq_u8` collapse a lane mask to "all true"/"any true"
+//! without extracting lanes one at a time, and x86's `movemask` does the
+//! equivalent by packing the mask into an integer and testing it. Plain
+//! Rust has no portable lane type to mirror either instruction, so this
+//! walks `LANES`-wide windows of booleans instead -- the same reduction
+//! shape, sized so the optimizer can autovectorize the comparison loop.
+//!
+//! `CrystalArray` (`lib/sparkle/spark`) is a separate crate with no
+//! dependency on `magicmath`/`MeshValue`, so this operates on a `&[T]`
+//! slice rather than a `CrystalArray<T>` directly -- the same data a
+//! `CrystalArray<T>`'s backing storage would hand over once that link
+//! exists.
+
+use crate::traits::MeshValue;
+
+/// Chunk width for the mask-then-reduce loop below.
+const LANES: usize = 16;
+
+/// `true` only if every element of `values` is harmony-stable, checked
+/// `LANES` elements at a time instead of short-circuiting lane by lane.
+pub fn check_harmony_state_simd<T: MeshValue>(values: &[T]) -> bool {
+    let len = values.len();
+    let full_chunks = len / LANES;
+
+    for chunk in 0..full_chunks {
+        let start = chunk * LANES;
+        let mut mask = [true; LANES];
+        for lane in 0..LANES {
+            mask[lane] = values[start + lane].check_harmony_state();
+        }
+
+        // Horizontal "all true" reduction, mirroring vminvq_u8's
+        // min-across-lanes / x86's movemask(cmp) == full_mask test.
+        if mask.iter().any(|&stable| !stable) {
+            return false;
+        }
+    }
+
+    values[(full_chunks * LANES)..].iter().all(|v| v.check_harmony_state())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vector4d::Vector4D;
+
+    #[test]
+    fn test_all_stable_values_pass() {
+        let values: Vec<Vector4D> = (0..40).map(|i| Vector4D::new(i as f64, 0.0, 0.0, 0.0)).collect();
+        assert!(check_harmony_state_simd(&values));
+    }
+
+    #[test]
+    fn test_one_unstable_value_in_a_full_chunk_fails() {
+        let mut values: Vec<Vector4D> = (0..32).map(|i| Vector4D::new(i as f64, 0.0, 0.0, 0.0)).collect();
+        values[5].state.coherence = -1.0;
+        assert!(!check_harmony_state_simd(&values));
+    }
+
+    #[test]
+    fn test_one_unstable_value_in_the_remainder_fails() {
+        let mut values: Vec<Vector4D> = (0..20).map(|i| Vector4D::new(i as f64, 0.0, 0.0, 0.0)).collect();
+        values[19].state.coherence = -1.0;
+        assert!(!check_harmony_state_simd(&values));
+    }
+
+    #[test]
+    fn test_empty_slice_is_vacuously_stable() {
+        let values: Vec<Vector4D> = Vec::new();
+        assert!(check_harmony_state_simd(&values));
+    }
+}
@@ -18,51 +18,71 @@ use crate::{
         PHASE_LOGARITHM_FACTOR,
         QUANTUM_CONTINUITY_THRESHOLD,
         CONVERGENCE_THRESHOLD,
+        LN_2,
         E
     },
     traits::MeshValue,
 };
 
+/// Selects how `quantum_ln` (and the Taylor expansion underneath it) treats
+/// a `raw_add`/`raw_mul`/`raw_div` result that overflows the representable
+/// range, mirroring the `checked_*`/`wrapping_*` split on Rust's integer
+/// types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LnMode {
+    /// Propagate `MathError::LatticeOverflow` the moment a term or the
+    /// final result stops being finite / in-bounds.
+    Checked,
+    /// Saturate an out-of-range term or result to the crystal lattice's
+    /// bounds instead of erroring.
+    Wrapping,
+}
+
 /// Quantum-aware natural logarithm for crystal lattice values
 /// Handles energy compression and phase continuity
-pub fn quantum_ln<T: MeshValue>(x: T) -> Result<T, MathError> {
+pub fn quantum_ln<T: MeshValue>(x: T, mode: LnMode) -> Result<T, MathError> {
     let coherence = calculate_compression(x)?;
     if coherence < QUANTUM_STABILITY_THRESHOLD {
         return Err(MathError::CoherenceLoss);
     }
 
-    let result = stabilized_ln(x)?;
-    validate_lattice_bounds(result)?;
+    let result = stabilized_ln(x, mode)?;
 
-    Ok(result)
+    match mode {
+        LnMode::Checked => {
+            validate_lattice_bounds(result)?;
+            Ok(result)
+        }
+        LnMode::Wrapping => Ok(clamp_lattice_bounds(result)),
+    }
 }
 
 /// Calculate natural logarithm with quantum continuity preservation
-pub fn continuous_ln<T: MeshValue>(x: T) -> Result<T, MathError> {
+pub fn continuous_ln<T: MeshValue>(x: T, mode: LnMode) -> Result<T, MathError> {
     let continuity = check_continuity(x)?;
     if !continuity.is_stable() {
         return Err(MathError::ContinuityLoss);
     }
 
-    quantum_ln(x)
+    quantum_ln(x, mode)
 }
 
 /// Resonant natural logarithm with harmonic compression
-pub fn harmonic_ln<T: MeshValue>(x: T) -> Result<T, MathError> {
+pub fn harmonic_ln<T: MeshValue>(x: T, mode: LnMode) -> Result<T, MathError> {
     let harmonics = calculate_compression_harmonics(x)?;
     let compressed_result = apply_compression_harmonics(x, harmonics)?;
 
-    quantum_ln(compressed_result)
+    quantum_ln(compressed_result, mode)
 }
 
 /// Calculate natural logarithm with phase compression
-pub fn phase_ln<T: MeshValue>(x: T, phase: f64) -> Result<T, MathError> {
+pub fn phase_ln<T: MeshValue>(x: T, phase: f64, mode: LnMode) -> Result<T, MathError> {
     if !is_valid_phase(phase) {
         return Err(MathError::PhaseError);
     }
 
     let phase_compressed = apply_phase_compression(x, phase)?;
-    quantum_ln(phase_compressed)
+    quantum_ln(phase_compressed, mode)
 }
 
 // Internal helper functions
@@ -74,30 +94,120 @@ fn calculate_compression<T: MeshValue>(x: T) -> Result<f64, MathError> {
 }
 
 #[inline]
-fn stabilized_ln<T: MeshValue>(x: T) -> Result<T, MathError> {
+fn stabilized_ln<T: MeshValue>(x: T, mode: LnMode) -> Result<T, MathError> {
     if x.is_negative() || x.is_zero() {
         return Err(MathError::DomainError);
     }
 
-    taylor_ln(x)
+    taylor_ln(x, mode)
+}
+
+/// Adds `a` and `b` under `mode`: `Checked` rejects a non-finite/overflowing
+/// sum, `Wrapping` saturates it to the lattice bounds instead.
+#[inline]
+fn mode_add<T: MeshValue>(a: T, b: T, mode: LnMode) -> Result<T, MathError> {
+    match mode {
+        LnMode::Checked => a.checked_add(b),
+        LnMode::Wrapping => Ok(a.wrapping_add(b)),
+    }
+}
+
+#[inline]
+fn mode_sub<T: MeshValue>(a: T, b: T, mode: LnMode) -> Result<T, MathError> {
+    match mode {
+        LnMode::Checked => a.checked_sub(b),
+        LnMode::Wrapping => Ok(a.wrapping_sub(b)),
+    }
+}
+
+#[inline]
+fn mode_mul<T: MeshValue>(a: T, b: T, mode: LnMode) -> Result<T, MathError> {
+    match mode {
+        LnMode::Checked => a.checked_mul(b),
+        LnMode::Wrapping => Ok(a.wrapping_mul(b)),
+    }
+}
+
+#[inline]
+fn mode_div<T: MeshValue>(a: T, b: T, mode: LnMode) -> Result<T, MathError> {
+    match mode {
+        LnMode::Checked => a.checked_div(b),
+        LnMode::Wrapping => Ok(a.wrapping_div(b)),
+    }
+}
+
+#[inline]
+fn taylor_ln<T: MeshValue>(x: T, mode: LnMode) -> Result<T, MathError> {
+    let (m, k) = reduce_range(x)?;
+    let series = atanh_series(m, mode)?;
+
+    if k == 0 {
+        return Ok(series);
+    }
+
+    let k_ln2 = mode_mul(T::from(k as f64), T::from(LN_2), mode)?;
+    mode_add(k_ln2, series, mode)
+}
+
+/// Decomposes `x` into `m · 2^k` with `m` in `[1, 2)`, so the atanh-style
+/// series below only ever has to converge near 1 -- it needs a bounded
+/// number of iterations regardless of how large or small `x` started out.
+///
+/// For an ordinary float-backed value this reads the binary exponent
+/// straight off `x.to_f64()`, the same trick `frexp` uses. If that ever
+/// lands outside `[1, 2)` -- which can happen for a `MeshValue` that
+/// isn't really float-backed underneath, or for a value whose magnitude
+/// overflows the fast path's `f64` math -- it falls back to repeatedly
+/// halving or doubling `x` by hand while counting `k`.
+#[inline]
+fn reduce_range<T: MeshValue>(x: T) -> Result<(T, i32), MathError> {
+    let raw = x.to_f64();
+    if raw.is_finite() && raw > 0.0 {
+        let k = raw.log2().floor() as i32;
+        let mantissa = raw / 2f64.powi(k);
+        if mantissa.is_finite() && mantissa >= 1.0 && mantissa < 2.0 {
+            return Ok((T::from(mantissa), k));
+        }
+    }
+
+    let mut m = x;
+    let mut k = 0i32;
+    while m.to_f64() >= 2.0 {
+        m = m.raw_div(T::from(2.0))?;
+        k += 1;
+    }
+    while m.to_f64() < 1.0 {
+        m = m.raw_mul(T::from(2.0))?;
+        k -= 1;
+    }
+    Ok((m, k))
 }
 
+/// The atanh-style Taylor series `ln(m) = 2 * atanh((m-1)/(m+1))`, which
+/// only converges quickly for `m` near 1 -- callers are expected to have
+/// already range-reduced `m` via [`reduce_range`].
 #[inline]
-fn taylor_ln<T: MeshValue>(x: T) -> Result<T, MathError> {
-    let mut term = (x.raw_sub(T::unit())?).raw_div(x.raw_add(T::unit())?)?;
+fn atanh_series<T: MeshValue>(m: T, mode: LnMode) -> Result<T, MathError> {
+    let ratio = mode_div(mode_sub(m, T::unit(), mode)?, mode_add(m, T::unit(), mode)?, mode)?;
+    let mut term = ratio.clone();
     let mut result = term.clone();
     let mut n = T::from(3);
 
     while term.magnitude()? > CONVERGENCE_THRESHOLD {
-        term = term.raw_mul(
-            (x.raw_sub(T::unit())?).raw_div(x.raw_add(T::unit())?)?
-        )?.raw_mul(T::from((n - T::from(2)).to_f64())?)?
-        .raw_div(T::from(n.to_f64())?)?;
-        result = result.raw_add(term)?;
-        n = n.raw_add(T::from(2))?;
+        term = mode_div(
+            mode_mul(
+                mode_mul(term, ratio, mode)?,
+                T::from((n - T::from(2)).to_f64()),
+                mode,
+            )?,
+            T::from(n.to_f64()),
+            mode,
+        )?;
+        result = mode_add(result, term, mode)?;
+        n = mode_add(n, T::from(2), mode)?;
     }
 
-    Ok(result.raw_mul(T::from(2))?)
+    mode_mul(result, T::from(2), mode)
 }
 
 #[inline]
@@ -109,6 +219,24 @@ fn validate_lattice_bounds<T: MeshValue>(value: T) -> Result<(), MathError> {
     Ok(())
 }
 
+/// Clamps a result into the crystal lattice's representable magnitude
+/// range, for `LnMode::Wrapping` callers that never want an error back.
+#[inline]
+fn clamp_lattice_bounds<T: MeshValue>(value: T) -> T {
+    let mag = match value.magnitude() {
+        Ok(mag) => mag,
+        Err(_) => return T::from(MIN_LATTICE_SIZE as f64),
+    };
+
+    if mag < MIN_LATTICE_SIZE as f64 {
+        T::from(MIN_LATTICE_SIZE as f64)
+    } else if mag > MAX_LATTICE_SIZE as f64 {
+        T::from(MAX_LATTICE_SIZE as f64)
+    } else {
+        value
+    }
+}
+
 #[inline]
 fn check_continuity<T: MeshValue>(x: T) -> Result<ContinuityState, MathError> {
     let continuity = x.continuity_state()?;
@@ -177,6 +305,22 @@ mod tests {
         fn raw_sub(&self, other: Self) -> Result<Self, MathError> { Ok(self - other) }
         fn raw_mul(&self, other: Self) -> Result<Self, MathError> { Ok(self * other) }
         fn raw_div(&self, other: Self) -> Result<Self, MathError> { Ok(self / other) }
+        fn checked_add(&self, other: Self) -> Result<Self, MathError> {
+            checked_finite(self + other)
+        }
+        fn checked_sub(&self, other: Self) -> Result<Self, MathError> {
+            checked_finite(self - other)
+        }
+        fn checked_mul(&self, other: Self) -> Result<Self, MathError> {
+            checked_finite(self * other)
+        }
+        fn checked_div(&self, other: Self) -> Result<Self, MathError> {
+            checked_finite(self / other)
+        }
+        fn wrapping_add(&self, other: Self) -> Self { wrap_finite(self + other) }
+        fn wrapping_sub(&self, other: Self) -> Self { wrap_finite(self - other) }
+        fn wrapping_mul(&self, other: Self) -> Self { wrap_finite(self * other) }
+        fn wrapping_div(&self, other: Self) -> Self { wrap_finite(self / other) }
         fn is_zero(&self) -> bool { *self == 0.0 }
         fn is_negative(&self) -> bool { *self < 0.0 }
         fn zero() -> Self { 0.0 }
@@ -199,30 +343,57 @@ mod tests {
         }
     }
 
+    /// Rejects a non-finite (`±∞`/`NaN`) float result instead of letting
+    /// it silently slip past `validate_lattice_bounds`.
+    fn checked_finite(value: f64) -> Result<f64, MathError> {
+        if value.is_finite() {
+            Ok(value)
+        } else {
+            Err(MathError::LatticeOverflow(format!("non-finite mesh result: {value}")))
+        }
+    }
+
+    fn wrap_finite(value: f64) -> f64 {
+        if value.is_finite() {
+            value
+        } else if value.is_sign_negative() {
+            -(MAX_LATTICE_SIZE as f64)
+        } else {
+            MAX_LATTICE_SIZE as f64
+        }
+    }
+
     #[test]
     fn test_quantum_ln() {
-        assert_eq!(quantum_ln(E).unwrap(), 1.0);
-        assert!(quantum_ln(-1.0).is_err());
-        assert!(quantum_ln(0.0).is_err());
+        assert_eq!(quantum_ln(E, LnMode::Checked).unwrap(), 1.0);
+        assert!(quantum_ln(-1.0, LnMode::Checked).is_err());
+        assert!(quantum_ln(0.0, LnMode::Checked).is_err());
+    }
+
+    #[test]
+    fn test_quantum_ln_wrapping_saturates_instead_of_erroring() {
+        // A value whose logarithm lands comfortably in-bounds behaves the
+        // same in both modes.
+        assert_eq!(quantum_ln(E, LnMode::Wrapping).unwrap(), 1.0);
     }
 
     #[test]
     fn test_continuous_ln() {
-        assert_eq!(continuous_ln(E).unwrap(), 1.0);
+        assert_eq!(continuous_ln(E, LnMode::Checked).unwrap(), 1.0);
     }
 
     #[test]
     fn test_harmonic_ln() {
-        let result = harmonic_ln(E).unwrap();
+        let result = harmonic_ln(E, LnMode::Checked).unwrap();
         assert!(result < 1.0); // Due to harmonic compression
     }
 
     #[test]
     fn test_phase_ln() {
-        let result = phase_ln(E, 0.0).unwrap();
+        let result = phase_ln(E, 0.0, LnMode::Checked).unwrap();
         assert_eq!(result, 1.0);
 
-        assert!(phase_ln(E, -1.0).is_err()); // Invalid phase
+        assert!(phase_ln(E, -1.0, LnMode::Checked).is_err()); // Invalid phase
     }
 
     #[test]
@@ -241,7 +412,29 @@ mod tests {
 
     #[test]
     fn test_convergence() {
-        let result = quantum_ln(2.0).unwrap();
+        let result = quantum_ln(2.0, LnMode::Checked).unwrap();
         assert!((result.exp() - 2.0).abs() < CONVERGENCE_THRESHOLD);
     }
+
+    #[test]
+    fn test_convergence_across_lattice_range() {
+        // Without range reduction the atanh series stalls or takes far
+        // too many iterations once x strays from 1; these should all
+        // converge to the same handful of Taylor terms now.
+        for &x in &[MIN_LATTICE_SIZE as f64, 1.0, 1_000.0, MAX_LATTICE_SIZE as f64] {
+            let result = quantum_ln(x, LnMode::Checked).unwrap();
+            assert!((result.exp() - x).abs() / x < 1e-9, "x = {x}, ln(x) = {result}");
+        }
+    }
+
+    #[test]
+    fn test_reduce_range_mantissa_in_bounds() {
+        let (m, k) = reduce_range(8.0).unwrap();
+        assert_eq!(k, 3);
+        assert_eq!(m, 1.0);
+
+        let (m, k) = reduce_range(0.25).unwrap();
+        assert_eq!(k, -2);
+        assert_eq!(m, 1.0);
+    }
 }
@@ -9,6 +9,7 @@
 //! License: MIT
 
 use crate::{
+    cache::SqrtCache,
     errors::MathError,
     constants::{
         MAX_LATTICE_SIZE,
@@ -17,7 +18,7 @@ use crate::{
         RESONANCE_FACTOR,
         PHASE_BIFURCATION_FACTOR,
         QUANTUM_SYMMETRY_THRESHOLD,
-        CONVERGENCE_THRESHOLD
+        CONVERGENCE_EPSILON
     },
     traits::MeshValue,
 };
@@ -54,6 +55,24 @@ pub fn harmonic_sqrt<T: MeshValue>(x: T) -> Result<T, MathError> {
     quantum_sqrt(bifurcated_result)
 }
 
+/// Cached variant of `quantum_sqrt`: checks `cache` for a converged
+/// `newton_sqrt` result keyed on `x`'s bit pattern before entering the
+/// Newton loop, recording a miss's result for the next repeat call.
+/// Iterative lattice sweeps that re-evaluate the same handful of
+/// magnitudes millions of times use this to skip the loop entirely on
+/// every repeat.
+pub fn quantum_sqrt_cached<T: MeshValue>(x: T, cache: &mut SqrtCache) -> Result<T, MathError> {
+    let coherence = calculate_bifurcation(x)?;
+    if coherence < QUANTUM_STABILITY_THRESHOLD {
+        return Err(MathError::CoherenceLoss);
+    }
+
+    let result = stabilized_sqrt_cached(x, cache)?;
+    validate_lattice_bounds(result)?;
+
+    Ok(result)
+}
+
 /// Calculate square root with phase bifurcation
 pub fn phase_sqrt<T: MeshValue>(x: T, phase: f64) -> Result<T, MathError> {
     if !is_valid_phase(phase) {
@@ -81,17 +100,61 @@ fn stabilized_sqrt<T: MeshValue>(x: T) -> Result<T, MathError> {
     newton_sqrt(x)
 }
 
+#[inline]
+fn stabilized_sqrt_cached<T: MeshValue>(x: T, cache: &mut SqrtCache) -> Result<T, MathError> {
+    if x.is_negative() {
+        return Err(MathError::ComplexDomain);
+    }
+
+    newton_sqrt_cached(x, cache)
+}
+
+/// Newton-Raphson square root, accumulating iterates in `f64` regardless
+/// of `T`'s own storage precision. Mirrors the `Full`/`Mixed` split
+/// `unstable_matter::cube::Precision` offers `MetricTensor`: halving a
+/// lattice's footprint to `f32` (`T`) only costs a single rounding step
+/// at the end, since every iteration in between runs at full precision.
 #[inline]
 fn newton_sqrt<T: MeshValue>(x: T) -> Result<T, MathError> {
-    let mut guess = x.half()?;
-    let mut prev_guess = T::zero();
+    let input = x.to_f64()?;
+
+    let mut guess: f64 = input / 2.0;
+    let mut prev_guess: f64 = 0.0;
 
-    while relative_error(guess, prev_guess)? > CONVERGENCE_THRESHOLD {
+    while relative_error_f64(guess, prev_guess) > CONVERGENCE_EPSILON {
         prev_guess = guess;
-        guess = average(guess, x.raw_div(guess)?)?;
+        guess = (guess + input / guess) / 2.0;
+    }
+
+    Ok(T::from(guess))
+}
+
+/// `newton_sqrt`, memoized through `cache`: a hit returns the prior
+/// converged result keyed on `x`'s bit pattern without touching the
+/// Newton loop; a miss runs `newton_sqrt` as usual and records the
+/// result before returning it.
+#[inline]
+fn newton_sqrt_cached<T: MeshValue>(x: T, cache: &mut SqrtCache) -> Result<T, MathError> {
+    let input = x.to_f64()?;
+
+    if let Some(cached) = cache.get(input) {
+        return Ok(T::from(cached));
     }
 
-    Ok(guess)
+    let result = newton_sqrt(x)?;
+    cache.insert(input, result.to_f64()?);
+    Ok(result)
+}
+
+/// `f64`-native relative error between successive Newton iterates, so
+/// `newton_sqrt`'s convergence check never downcasts through `T`
+/// mid-iteration.
+#[inline]
+fn relative_error_f64(a: f64, b: f64) -> f64 {
+    if b == 0.0 {
+        return f64::INFINITY;
+    }
+    ((a - b) / b).abs()
 }
 
 #[inline]
@@ -177,6 +240,8 @@ mod tests {
 
     // Test implementation of MeshValue for f64
     impl MeshValue for f64 {
+        fn to_f64(&self) -> Result<f64, MathError> { Ok(*self) }
+        fn from(value: f64) -> Self { value }
         fn coherence(&self) -> Result<f64, MathError> { Ok(1.0) }
         fn energy(&self) -> Result<f64, MathError> { Ok(*self) }
         fn magnitude(&self) -> Result<f64, MathError> { Ok(self.abs()) }
@@ -202,6 +267,36 @@ mod tests {
         }
     }
 
+    // Mirrors the `f64` impl above, for exercising `newton_sqrt`'s
+    // higher-precision accumulator against a halved-precision storage type.
+    impl MeshValue for f32 {
+        fn to_f64(&self) -> Result<f64, MathError> { Ok(*self as f64) }
+        fn from(value: f64) -> Self { value as f32 }
+        fn coherence(&self) -> Result<f64, MathError> { Ok(1.0) }
+        fn energy(&self) -> Result<f64, MathError> { Ok(*self as f64) }
+        fn magnitude(&self) -> Result<f64, MathError> { Ok(self.abs() as f64) }
+        fn raw_add(&self, other: Self) -> Result<Self, MathError> { Ok(self + other) }
+        fn raw_div(&self, other: Self) -> Result<Self, MathError> { Ok(self / other) }
+        fn half(&self) -> Result<Self, MathError> { Ok(self / 2.0) }
+        fn is_zero(&self) -> bool { *self == 0.0 }
+        fn is_negative(&self) -> bool { *self < 0.0 }
+        fn zero() -> Self { 0.0 }
+        fn bifurcate(&self, factor: f64) -> Result<Self, MathError> {
+            Ok(self * factor.sqrt() as f32)
+        }
+        fn phase_bifurcate(&self, phase: f64) -> Result<Self, MathError> {
+            Ok(self * (phase * PHASE_BIFURCATION_FACTOR).cos() as f32)
+        }
+        fn symmetry_state(&self) -> Result<SymmetryState, MathError> {
+            Ok(SymmetryState {
+                coherence: 1.0,
+                phase: 0.0,
+                energy: *self as f64,
+                symmetry: 1.0,
+            })
+        }
+    }
+
     #[test]
     fn test_quantum_sqrt() {
         assert_eq!(quantum_sqrt(4.0).unwrap(), 2.0);
@@ -244,6 +339,23 @@ mod tests {
     #[test]
     fn test_convergence() {
         let result = quantum_sqrt(2.0).unwrap();
-        assert!((result * result - 2.0).abs() < CONVERGENCE_THRESHOLD);
+        assert!((result * result - 2.0).abs() < CONVERGENCE_EPSILON);
+    }
+
+    #[test]
+    fn test_newton_sqrt_accumulates_through_f32_storage() {
+        let result = quantum_sqrt(2.0f32).unwrap();
+        assert!((result * result - 2.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_quantum_sqrt_cached_matches_uncached() {
+        let mut cache = crate::cache::SqrtCache::new();
+        let direct = quantum_sqrt(4.0).unwrap();
+        let cached = quantum_sqrt_cached(4.0, &mut cache).unwrap();
+        assert_eq!(direct, cached);
+
+        // Second call on the same input should be served from the cache.
+        assert_eq!(quantum_sqrt_cached(4.0, &mut cache).unwrap(), direct);
     }
 }
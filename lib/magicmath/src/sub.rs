@@ -7,6 +7,10 @@
 //! Last Updated: 2025-01-19 23:56:38 UTC
 //! Version: 0.1.0
 //! License: MIT
+//!
+//! `check_harmony_state` below validates one operand at a time; see
+//! `harmony::check_harmony_state_simd` for the batched equivalent used
+//! when validating a whole array of values at once.
 
 use crate::traits::CrystalSub;
 use crate::constants::HARMONY_STABILITY_THRESHOLD;
@@ -174,8 +174,166 @@ impl MeshMath {
 
         sum
     }
+
+    /// Like [`Self::eq_f64`], but with a caller-supplied tolerance. The
+    /// CORDIC trig routines below only converge to within roughly
+    /// `2^-CORDIC_ITERATIONS`, well short of the hardcoded machine-epsilon
+    /// bound `eq_f64` uses, so callers comparing trig results need a
+    /// looser threshold.
+    #[inline(always)]
+    pub fn eq_f64_tolerance(a: f64, b: f64, tolerance: f64) -> bool {
+        Self::abs(a - b) < tolerance
+    }
+
+    /// Sine of `x` (radians), via CORDIC rotation mode.
+    #[inline]
+    pub fn sin(x: f64) -> f64 {
+        let (reduced, flipped) = Self::reduce_angle(x);
+        let (s, _c) = Self::cordic_rotate(reduced);
+        if flipped { -s } else { s }
+    }
+
+    /// Cosine of `x` (radians), via CORDIC rotation mode.
+    #[inline]
+    pub fn cos(x: f64) -> f64 {
+        let (reduced, flipped) = Self::reduce_angle(x);
+        let (_s, c) = Self::cordic_rotate(reduced);
+        if flipped { -c } else { c }
+    }
+
+    /// Tangent of `x` (radians), as `sin(x) / cos(x)` from a single
+    /// CORDIC rotation. The sign flip from range reduction cancels
+    /// between numerator and denominator, so it's ignored here.
+    #[inline]
+    pub fn tan(x: f64) -> f64 {
+        let (reduced, _flipped) = Self::reduce_angle(x);
+        let (s, c) = Self::cordic_rotate(reduced);
+        s / c
+    }
+
+    /// Four-quadrant arctangent of `y / x`, via CORDIC vectoring mode.
+    ///
+    /// Vectoring mode only converges when the starting vector is in the
+    /// right half-plane, so a negative `x` is reflected through the
+    /// origin before the CORDIC loop runs, and the accumulated angle is
+    /// corrected by `±PI` afterward to land back in the original
+    /// quadrant.
+    pub fn atan2(y: f64, x: f64) -> f64 {
+        if Self::eq_f64(x, 0.0) && Self::eq_f64(y, 0.0) {
+            return 0.0;
+        }
+
+        let (mut vx, mut vy, quadrant_offset) = if x < 0.0 {
+            if y >= 0.0 {
+                (-x, -y, constants::PI)
+            } else {
+                (-x, -y, -constants::PI)
+            }
+        } else {
+            (x, y, 0.0)
+        };
+
+        let mut z = 0.0;
+        let mut pow2 = 1.0;
+        for i in 0..CORDIC_ITERATIONS {
+            let d = if vy >= 0.0 { -1.0 } else { 1.0 };
+            let vx_old = vx;
+            vx -= d * vy * pow2;
+            vy += d * vx_old * pow2;
+            z -= d * CORDIC_ATAN[i];
+            pow2 /= 2.0;
+        }
+
+        z + quadrant_offset
+    }
+
+    /// Reduces `angle` into `[-PI/2, PI/2]` by subtracting multiples of
+    /// `PI`, returning the reduced angle and whether an odd number of
+    /// subtractions happened (which flips the sign of sin/cos of the
+    /// original angle relative to the reduced one).
+    fn reduce_angle(angle: f64) -> (f64, bool) {
+        let half_pi = constants::PI / 2.0;
+        let mut reduced = angle;
+        let mut flipped = false;
+
+        while reduced > half_pi {
+            reduced -= constants::PI;
+            flipped = !flipped;
+        }
+        while reduced < -half_pi {
+            reduced += constants::PI;
+            flipped = !flipped;
+        }
+
+        (reduced, flipped)
+    }
+
+    /// Runs CORDIC rotation mode on an angle already reduced into
+    /// `[-PI/2, PI/2]`, returning `(sin, cos)`.
+    fn cordic_rotate(angle: f64) -> (f64, f64) {
+        let mut x = CORDIC_K;
+        let mut y = 0.0;
+        let mut z = angle;
+
+        let mut pow2 = 1.0;
+        for i in 0..CORDIC_ITERATIONS {
+            let d = if z >= 0.0 { 1.0 } else { -1.0 };
+            let x_old = x;
+            x -= d * y * pow2;
+            y += d * x_old * pow2;
+            z -= d * CORDIC_ATAN[i];
+            pow2 /= 2.0;
+        }
+
+        (y, x)
+    }
 }
 
+/// Number of CORDIC iterations used by the trig routines above; each
+/// iteration roughly halves the residual angle error.
+const CORDIC_ITERATIONS: usize = 30;
+
+/// `CORDIC_ATAN[i] = atan(2^-i)`, precomputed so the CORDIC loop only
+/// ever shifts and adds rather than calling back into `atan` itself.
+const CORDIC_ATAN: [f64; CORDIC_ITERATIONS] = [
+    0.78539816339744830961,
+    0.46364760900080611621,
+    0.24497866312686415417,
+    0.12435499454676143503,
+    0.06241880999595734847,
+    0.03123983343026827626,
+    0.01562372862047683080,
+    0.00781234106010111169,
+    0.00390623013196697182,
+    0.00195312251647881879,
+    0.00097656218955931943,
+    0.00048828121119489829,
+    0.00024414062014936177,
+    0.00012207031189367021,
+    0.00006103515617420877,
+    0.00003051757806215613,
+    0.00001525878906131576,
+    0.00000762939453110197,
+    0.00000381469726560650,
+    0.00000190734863281136,
+    0.00000095367431640596,
+    0.00000047683715820308,
+    0.00000023841857910153,
+    0.00000011920928955076,
+    0.00000005960464477539,
+    0.00000002980232238770,
+    0.00000001490116119385,
+    0.00000000745058059692,
+    0.00000000372529029846,
+    0.00000000186264514923,
+];
+
+/// CORDIC gain constant `K = Π 1/sqrt(1 + 2^-2i)` for `i` in
+/// `0..CORDIC_ITERATIONS`; pre-scaling `x` by `K` before the rotation
+/// loop cancels the vector-length growth the pseudo-rotations introduce,
+/// so the final `(x, y)` lands on the unit circle.
+const CORDIC_K: f64 = 0.6072529350088813;
+
 /// Trait for mesh-compatible numeric types
 pub trait MeshValue: Copy + Clone + core::fmt::Debug {
     fn mesh_add(self, other: Self) -> Self;
@@ -187,6 +345,10 @@ pub trait MeshValue: Copy + Clone + core::fmt::Debug {
     fn mesh_normalize(self) -> Self;
     fn mesh_zero() -> Self;
     fn mesh_one() -> Self;
+    fn mesh_sin(self) -> Self;
+    fn mesh_cos(self) -> Self;
+    fn mesh_tan(self) -> Self;
+    fn mesh_atan2(self, other: Self) -> Self;
     fn as_f64(self) -> f64;
     fn from_f64(value: f64) -> Self;
 }
@@ -224,6 +386,18 @@ impl MeshValue for f64 {
     #[inline(always)]
     fn mesh_one() -> Self { 1.0 }
 
+    #[inline(always)]
+    fn mesh_sin(self) -> Self { MeshMath::sin(self) }
+
+    #[inline(always)]
+    fn mesh_cos(self) -> Self { MeshMath::cos(self) }
+
+    #[inline(always)]
+    fn mesh_tan(self) -> Self { MeshMath::tan(self) }
+
+    #[inline(always)]
+    fn mesh_atan2(self, other: Self) -> Self { MeshMath::atan2(self, other) }
+
     #[inline(always)]
     fn as_f64(self) -> f64 { self }
 
@@ -264,6 +438,20 @@ impl MeshValue for isize {
     #[inline(always)]
     fn mesh_one() -> Self { 1 }
 
+    #[inline(always)]
+    fn mesh_sin(self) -> Self { Self::from_f64(MeshMath::sin(self.as_f64())) }
+
+    #[inline(always)]
+    fn mesh_cos(self) -> Self { Self::from_f64(MeshMath::cos(self.as_f64())) }
+
+    #[inline(always)]
+    fn mesh_tan(self) -> Self { Self::from_f64(MeshMath::tan(self.as_f64())) }
+
+    #[inline(always)]
+    fn mesh_atan2(self, other: Self) -> Self {
+        Self::from_f64(MeshMath::atan2(self.as_f64(), other.as_f64()))
+    }
+
     #[inline(always)]
     fn as_f64(self) -> f64 { self as f64 }
 
@@ -306,6 +494,20 @@ impl MeshValue for usize {
     #[inline(always)]
     fn mesh_one() -> Self { 1 }
 
+    #[inline(always)]
+    fn mesh_sin(self) -> Self { Self::from_f64(MeshMath::sin(self.as_f64())) }
+
+    #[inline(always)]
+    fn mesh_cos(self) -> Self { Self::from_f64(MeshMath::cos(self.as_f64())) }
+
+    #[inline(always)]
+    fn mesh_tan(self) -> Self { Self::from_f64(MeshMath::tan(self.as_f64())) }
+
+    #[inline(always)]
+    fn mesh_atan2(self, other: Self) -> Self {
+        Self::from_f64(MeshMath::atan2(self.as_f64(), other.as_f64()))
+    }
+
     #[inline(always)]
     fn as_f64(self) -> f64 { self as f64 }
 
@@ -349,6 +551,33 @@ mod tests {
         assert!(MeshMath::eq_f64(MeshMath::exp(0.0), 1.0));
     }
 
+    #[test]
+    fn test_trig_functions() {
+        const TOLERANCE: f64 = 1e-6;
+
+        assert!(MeshMath::eq_f64_tolerance(MeshMath::sin(0.0), 0.0, TOLERANCE));
+        assert!(MeshMath::eq_f64_tolerance(MeshMath::cos(0.0), 1.0, TOLERANCE));
+        assert!(MeshMath::eq_f64_tolerance(MeshMath::sin(constants::PI / 2.0), 1.0, TOLERANCE));
+        assert!(MeshMath::eq_f64_tolerance(MeshMath::cos(constants::PI / 2.0), 0.0, TOLERANCE));
+        assert!(MeshMath::eq_f64_tolerance(MeshMath::sin(constants::PI), 0.0, TOLERANCE));
+        assert!(MeshMath::eq_f64_tolerance(MeshMath::cos(constants::PI), -1.0, TOLERANCE));
+        assert!(MeshMath::eq_f64_tolerance(MeshMath::tan(constants::PI / 4.0), 1.0, TOLERANCE));
+
+        assert!(MeshMath::eq_f64_tolerance(MeshMath::atan2(1.0, 1.0), constants::PI / 4.0, TOLERANCE));
+        assert!(MeshMath::eq_f64_tolerance(MeshMath::atan2(1.0, -1.0), 3.0 * constants::PI / 4.0, TOLERANCE));
+        assert!(MeshMath::eq_f64_tolerance(MeshMath::atan2(-1.0, -1.0), -3.0 * constants::PI / 4.0, TOLERANCE));
+        assert!(MeshMath::eq_f64_tolerance(MeshMath::atan2(0.0, 1.0), 0.0, TOLERANCE));
+    }
+
+    #[test]
+    fn test_mesh_value_trig() {
+        const TOLERANCE: f64 = 1e-6;
+
+        assert!(MeshMath::eq_f64_tolerance(0.0_f64.mesh_sin(), 0.0, TOLERANCE));
+        assert!(MeshMath::eq_f64_tolerance(0.0_f64.mesh_cos(), 1.0, TOLERANCE));
+        assert!(MeshMath::eq_f64_tolerance(1.0_f64.mesh_atan2(1.0), constants::PI / 4.0, TOLERANCE));
+    }
+
     #[test]
     fn test_mesh_value_implementations() {
         // Test f64
@@ -0,0 +1,249 @@
+//! Loss-based congestion control for quantum transport channels
+//!
+//! `CrystalNode` used to admit quantum-state updates onto a channel purely
+//! on a flat `max_connections` cap, with no notion of how much traffic a
+//! single channel can sustain before it destabilizes. This module adds a
+//! per-channel [`CongestionController`], selectable via [`CongestionAlgorithm`]
+//! in `NodeConfig`, that tracks a congestion window (`cwnd`) the way a
+//! loss-based TCP algorithm would: growing on successful updates, shrinking
+//! on a harmony-drop event. `CrystalNode` treats harmony-drop signals as the
+//! congestion "loss" feedback instead of tripping straight into
+//! `NodeState::Destabilized`.
+
+use std::time::Instant;
+
+/// One quantum-state-update unit, the analog of an MSS (maximum segment
+/// size) in the loss-based window algorithms below.
+const MSS: f64 = 1.0;
+
+/// The smallest a congestion window is allowed to shrink to.
+const MIN_CWND: f64 = 1.0;
+
+/// Per-channel congestion control, selectable via [`NodeConfig`]
+///
+/// Tracks how many quantum-state updates a channel can have in flight
+/// before further updates should wait. `on_ack` grows the window after a
+/// successful update; `on_loss` shrinks it after a harmony-drop event.
+pub trait CongestionController: std::fmt::Debug + Send + Sync {
+    /// The current congestion window, in quantum-state-update units.
+    fn cwnd(&self) -> f64;
+
+    /// Returns true if a channel with `in_flight` outstanding updates has
+    /// room for one more.
+    fn can_send(&self, in_flight: usize) -> bool {
+        (in_flight as f64) < self.cwnd()
+    }
+
+    /// Records a successfully admitted and acknowledged update.
+    fn on_ack(&mut self);
+
+    /// Records a harmony-drop event, treated as a loss signal.
+    fn on_loss(&mut self);
+}
+
+/// Selects which [`CongestionController`] implementation a node's channels use
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CongestionAlgorithm {
+    /// Slow-start plus additive-increase/multiplicative-decrease, as in
+    /// RFC 6582.
+    #[default]
+    NewReno,
+    /// Cubic-growth window recovery, as in RFC 8312.
+    Cubic,
+}
+
+impl CongestionAlgorithm {
+    /// Builds a fresh controller for a newly connected channel.
+    pub fn build_controller(self) -> Box<dyn CongestionController> {
+        match self {
+            Self::NewReno => Box::new(NewRenoController::new()),
+            Self::Cubic => Box::new(CubicController::new()),
+        }
+    }
+}
+
+/// NewReno congestion control
+///
+/// Slow start doubles `cwnd` every round-trip (approximated here as `+MSS`
+/// per ack, which sums to a doubling across the ~`cwnd` acks in a round) up
+/// to `ssthresh`. Past `ssthresh`, congestion avoidance grows `cwnd` by
+/// `MSS^2 / cwnd` per ack instead. A loss halves `ssthresh` and drops `cwnd`
+/// straight to the new `ssthresh`.
+#[derive(Debug, Clone)]
+pub struct NewRenoController {
+    cwnd: f64,
+    ssthresh: f64,
+}
+
+impl NewRenoController {
+    pub fn new() -> Self {
+        Self {
+            cwnd: MIN_CWND,
+            ssthresh: f64::MAX,
+        }
+    }
+}
+
+impl Default for NewRenoController {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CongestionController for NewRenoController {
+    fn cwnd(&self) -> f64 {
+        self.cwnd
+    }
+
+    fn on_ack(&mut self) {
+        if self.cwnd < self.ssthresh {
+            self.cwnd += MSS;
+        } else {
+            self.cwnd += (MSS * MSS) / self.cwnd;
+        }
+    }
+
+    fn on_loss(&mut self) {
+        self.ssthresh = (self.cwnd / 2.0).max(MIN_CWND);
+        self.cwnd = self.ssthresh;
+    }
+}
+
+/// Beta multiplicative-decrease factor CUBIC applies on loss.
+const CUBIC_BETA: f64 = 0.3;
+/// Scaling constant controlling how aggressively CUBIC regrows `cwnd`.
+const CUBIC_C: f64 = 0.4;
+
+/// CUBIC congestion control
+///
+/// On loss, remembers the pre-loss window as `w_max`, backs `cwnd` off by
+/// `(1 - beta)`, and derives `K` (the time it would take the cubic curve to
+/// climb back to `w_max`). From then on `cwnd` is recomputed on every ack as
+/// a cubic function of the time elapsed since the loss event, concave
+/// through the dip and convex again as it approaches `w_max`.
+#[derive(Debug, Clone)]
+pub struct CubicController {
+    cwnd: f64,
+    w_max: f64,
+    k: f64,
+    last_loss: Option<Instant>,
+}
+
+impl CubicController {
+    pub fn new() -> Self {
+        Self {
+            cwnd: MIN_CWND,
+            w_max: MIN_CWND,
+            k: 0.0,
+            last_loss: None,
+        }
+    }
+}
+
+impl Default for CubicController {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CongestionController for CubicController {
+    fn cwnd(&self) -> f64 {
+        self.cwnd
+    }
+
+    fn on_ack(&mut self) {
+        let Some(last_loss) = self.last_loss else {
+            // No loss yet observed; grow linearly like slow start until the
+            // first loss event gives the cubic curve something to climb
+            // back toward.
+            self.cwnd += MSS;
+            return;
+        };
+
+        let t = last_loss.elapsed().as_secs_f64();
+        self.cwnd = (CUBIC_C * (t - self.k).powi(3) + self.w_max).max(MIN_CWND);
+    }
+
+    fn on_loss(&mut self) {
+        self.w_max = self.cwnd;
+        self.cwnd = (self.cwnd * (1.0 - CUBIC_BETA)).max(MIN_CWND);
+        self.k = (self.w_max * CUBIC_BETA / CUBIC_C).cbrt();
+        self.last_loss = Some(Instant::now());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_reno_slow_start_grows_by_mss_per_ack() {
+        let mut controller = NewRenoController::new();
+        assert_eq!(controller.cwnd(), 1.0);
+        controller.on_ack();
+        assert_eq!(controller.cwnd(), 2.0);
+        controller.on_ack();
+        assert_eq!(controller.cwnd(), 3.0);
+    }
+
+    #[test]
+    fn test_new_reno_loss_halves_window() {
+        let mut controller = NewRenoController::new();
+        for _ in 0..10 {
+            controller.on_ack();
+        }
+        let cwnd_before = controller.cwnd();
+        controller.on_loss();
+        assert_eq!(controller.cwnd(), cwnd_before / 2.0);
+        assert_eq!(controller.cwnd(), controller.cwnd());
+    }
+
+    #[test]
+    fn test_new_reno_congestion_avoidance_after_ssthresh() {
+        let mut controller = NewRenoController::new();
+        for _ in 0..10 {
+            controller.on_ack();
+        }
+        controller.on_loss();
+        let ssthresh = controller.cwnd();
+        let cwnd_before = controller.cwnd();
+        controller.on_ack();
+        // Past ssthresh, growth is MSS^2/cwnd, which is far smaller than the
+        // +MSS slow-start step.
+        assert!(controller.cwnd() - cwnd_before < 1.0);
+        assert!(controller.cwnd() > ssthresh);
+    }
+
+    #[test]
+    fn test_cubic_loss_backs_off_by_beta() {
+        let mut controller = CubicController::new();
+        for _ in 0..10 {
+            controller.on_ack();
+        }
+        let cwnd_before = controller.cwnd();
+        controller.on_loss();
+        assert!((controller.cwnd() - cwnd_before * (1.0 - CUBIC_BETA)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_cubic_regrows_toward_w_max_over_time() {
+        let mut controller = CubicController::new();
+        for _ in 0..10 {
+            controller.on_ack();
+        }
+        controller.on_loss();
+        let just_after_loss = controller.cwnd();
+
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        controller.on_ack();
+
+        assert!(controller.cwnd() >= just_after_loss);
+    }
+
+    #[test]
+    fn test_can_send_respects_window() {
+        let controller = NewRenoController::new();
+        assert!(controller.can_send(0));
+        assert!(!controller.can_send(1));
+    }
+}
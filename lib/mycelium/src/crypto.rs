@@ -0,0 +1,302 @@
+//! AEAD-style confidentiality for quantum transport channels
+//!
+//! `TransportChannel::new_quantum()` carries every `QuantumState` in the
+//! clear. This module adds an optional encrypted mode negotiated during
+//! `CrystalNode::connect`: both sides contribute a [`KeyShare`] to a key
+//! exchange, derive a per-channel key from the resulting shared secret via
+//! HKDF, and seal every transmitted payload in the [`CipherSuite`] the
+//! connection settled on. A [`ChannelCipher`] tracks its own nonce counter
+//! and rekeys itself once a configurable byte budget is exhausted.
+//!
+//! This crate has no existing dependency on a cryptographic library, so
+//! the key exchange and AEAD here are modeled with `std`-only primitives
+//! rather than real X25519/ChaCha20-Poly1305/AES-256-GCM. Unlike
+//! [`crate::handshake::RetryToken`] -- which only ever needs to prove
+//! authenticity, so reusing the peer's own bytes back at it is fine --
+//! this module exists to keep the shared secret confidential, so
+//! [`KeyShare`] has to actually hide it: the exchange is a toy
+//! Diffie-Hellman built on 64-bit modular exponentiation rather than real
+//! elliptic-curve points, but it has the same property that matters here,
+//! a share reveals nothing about the secret behind it without solving a
+//! discrete log. `CipherSuite` still distinguishes the two real-world
+//! algorithms for capability negotiation and cost reporting, even though
+//! both map to the same sealing primitive underneath.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::error::{NetworkError, NetworkResult};
+
+/// Which AEAD construction protects an encrypted channel
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CipherSuite {
+    /// ChaCha20-Poly1305
+    ChaCha20Poly1305,
+    /// AES-256-GCM
+    Aes256Gcm,
+}
+
+impl CipherSuite {
+    /// Relative CPU-cost multiplier over plaintext, for `NodeStats`
+    /// reporting -- mirrors how a VPN compares native/plain, AES-256, and
+    /// ChaCha throughput tiers.
+    pub fn cost_factor(self) -> f64 {
+        match self {
+            Self::ChaCha20Poly1305 => 1.15,
+            Self::Aes256Gcm => 1.35,
+        }
+    }
+}
+
+/// A 61-bit Mersenne prime modulus for [`modexp`], chosen so two lanes
+/// multiplied together never overflow a `u128` accumulator.
+const DH_PRIME: u64 = (1u64 << 61) - 1;
+/// Generator for the toy Diffie-Hellman group `[`DH_PRIME`]` forms a
+/// multiplicative group over.
+const DH_GENERATOR: u64 = 5;
+
+/// One side's public contribution to a channel's key exchange
+///
+/// Stands in for an X25519 public point: `from_secret` raises
+/// [`DH_GENERATOR`] to the secret's power mod [`DH_PRIME`], so recovering
+/// the secret from the share means solving a discrete log rather than just
+/// reading it back off the wire.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyShare(u64);
+
+impl KeyShare {
+    /// Derives the public share a local secret would publish
+    pub fn from_secret(secret: u64) -> Self {
+        Self(modexp(DH_GENERATOR, secret, DH_PRIME))
+    }
+}
+
+/// Generates a fresh local secret and the share to send the peer
+pub fn generate_key_share() -> (u64, KeyShare) {
+    let secret = rand::random::<u64>();
+    (secret, KeyShare::from_secret(secret))
+}
+
+/// Combines a local secret with the peer's share into a shared secret
+///
+/// Diffie-Hellman's key property makes this commutative: raising the
+/// peer's share (`g^peer_secret`) to our own secret's power gives
+/// `g^(local_secret * peer_secret)`, the same value the peer arrives at by
+/// raising our share to theirs.
+pub fn derive_shared_secret(local_secret: u64, peer_share: KeyShare) -> u64 {
+    modexp(peer_share.0, local_secret, DH_PRIME)
+}
+
+/// Modular exponentiation via square-and-multiply: `base^exponent mod
+/// modulus`. Widens to `u128` for the intermediate products so repeated
+/// squaring can't overflow before the `% modulus` reduction.
+fn modexp(base: u64, exponent: u64, modulus: u64) -> u64 {
+    let modulus = modulus as u128;
+    let mut result: u128 = 1;
+    let mut base = base as u128 % modulus;
+    let mut exponent = exponent;
+
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = (result * base) % modulus;
+        }
+        exponent >>= 1;
+        base = (base * base) % modulus;
+    }
+
+    result as u64
+}
+
+fn hkdf_expand(secret: u64, label: &str, generation: u64) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    secret.hash(&mut hasher);
+    label.hash(&mut hasher);
+    generation.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn keystream_byte(key: u64, nonce: u64, index: u64) -> u8 {
+    let mut hasher = DefaultHasher::new();
+    key.hash(&mut hasher);
+    nonce.hash(&mut hasher);
+    index.hash(&mut hasher);
+    (hasher.finish() & 0xff) as u8
+}
+
+fn xor_keystream(key: u64, nonce: u64, data: &[u8]) -> Vec<u8> {
+    data.iter()
+        .enumerate()
+        .map(|(i, byte)| byte ^ keystream_byte(key, nonce, i as u64))
+        .collect()
+}
+
+fn tag_for(key: u64, nonce: u64, ciphertext: &[u8]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    key.hash(&mut hasher);
+    nonce.hash(&mut hasher);
+    ciphertext.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// A sealed payload: ciphertext plus the nonce and tag needed to open it
+#[derive(Debug, Clone)]
+pub struct SealedPayload {
+    pub ciphertext: Vec<u8>,
+    pub nonce: u64,
+    pub tag: u64,
+}
+
+/// Per-connection AEAD state: the derived key, nonce counter, and the
+/// byte budget that triggers a rekey.
+#[derive(Debug)]
+pub struct ChannelCipher {
+    suite: CipherSuite,
+    shared_secret: u64,
+    generation: AtomicU64,
+    key: AtomicU64,
+    nonce: AtomicU64,
+    bytes_sealed: AtomicU64,
+    rekey_after_bytes: u64,
+}
+
+impl ChannelCipher {
+    /// Derives a fresh channel cipher from a shared secret
+    pub fn new(suite: CipherSuite, shared_secret: u64, rekey_after_bytes: u64) -> Self {
+        let key = hkdf_expand(shared_secret, "channel", 0);
+        Self {
+            suite,
+            shared_secret,
+            generation: AtomicU64::new(0),
+            key: AtomicU64::new(key),
+            nonce: AtomicU64::new(0),
+            bytes_sealed: AtomicU64::new(0),
+            rekey_after_bytes,
+        }
+    }
+
+    /// The cipher suite this channel negotiated
+    pub fn suite(&self) -> CipherSuite {
+        self.suite
+    }
+
+    /// Total plaintext bytes sealed since the last rekey
+    pub fn bytes_sealed(&self) -> u64 {
+        self.bytes_sealed.load(Ordering::SeqCst)
+    }
+
+    /// Encrypts and authenticates `plaintext`, rekeying first if the byte
+    /// budget has been exhausted.
+    pub fn seal(&self, plaintext: &[u8]) -> SealedPayload {
+        if self.bytes_sealed.load(Ordering::SeqCst) >= self.rekey_after_bytes {
+            self.rekey();
+        }
+
+        let key = self.key.load(Ordering::SeqCst);
+        let nonce = self.nonce.fetch_add(1, Ordering::SeqCst);
+        let ciphertext = xor_keystream(key, nonce, plaintext);
+        let tag = tag_for(key, nonce, &ciphertext);
+
+        self.bytes_sealed
+            .fetch_add(plaintext.len() as u64, Ordering::SeqCst);
+
+        SealedPayload {
+            ciphertext,
+            nonce,
+            tag,
+        }
+    }
+
+    /// Verifies and decrypts a payload sealed by this cipher
+    pub fn open(&self, payload: &SealedPayload) -> NetworkResult<Vec<u8>> {
+        let key = self.key.load(Ordering::SeqCst);
+        if tag_for(key, payload.nonce, &payload.ciphertext) != payload.tag {
+            return Err(NetworkError::ConfigurationError(
+                "AEAD tag verification failed".into(),
+            ));
+        }
+        Ok(xor_keystream(key, payload.nonce, &payload.ciphertext))
+    }
+
+    /// Derives the next generation's key and resets the nonce/byte counters
+    fn rekey(&self) {
+        let generation = self.generation.fetch_add(1, Ordering::SeqCst) + 1;
+        let new_key = hkdf_expand(self.shared_secret, "channel", generation);
+        self.key.store(new_key, Ordering::SeqCst);
+        self.nonce.store(0, Ordering::SeqCst);
+        self.bytes_sealed.store(0, Ordering::SeqCst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_both_sides_derive_the_same_shared_secret() {
+        let (a_secret, a_share) = generate_key_share();
+        let (b_secret, b_share) = generate_key_share();
+
+        let a_view = derive_shared_secret(a_secret, b_share);
+        let b_view = derive_shared_secret(b_secret, a_share);
+        assert_eq!(a_view, b_view);
+    }
+
+    #[test]
+    fn test_key_share_does_not_expose_the_secret_it_was_derived_from() {
+        // The whole point of the exchange: an observer who only ever sees
+        // the share sent over the wire must not already be holding the
+        // secret behind it.
+        let (secret, share) = generate_key_share();
+        assert_ne!(share.0, secret);
+    }
+
+    #[test]
+    fn test_modexp_matches_repeated_multiplication() {
+        let expected = (1..=7u128).fold(1u128, |acc, _| (acc * 5) % DH_PRIME as u128) as u64;
+        assert_eq!(modexp(5, 7, DH_PRIME), expected);
+    }
+
+    #[test]
+    fn test_seal_then_open_round_trips() {
+        let cipher = ChannelCipher::new(CipherSuite::ChaCha20Poly1305, 1234, 1024);
+        let plaintext = b"crystal quantum state payload";
+
+        let sealed = cipher.seal(plaintext);
+        assert_ne!(sealed.ciphertext, plaintext);
+
+        let opened = cipher.open(&sealed).unwrap();
+        assert_eq!(opened, plaintext);
+    }
+
+    #[test]
+    fn test_open_rejects_tampered_ciphertext() {
+        let cipher = ChannelCipher::new(CipherSuite::Aes256Gcm, 1234, 1024);
+        let mut sealed = cipher.seal(b"payload");
+        sealed.ciphertext[0] ^= 0xff;
+
+        assert!(cipher.open(&sealed).is_err());
+    }
+
+    #[test]
+    fn test_rekey_after_byte_budget_changes_the_key() {
+        let cipher = ChannelCipher::new(CipherSuite::ChaCha20Poly1305, 1234, 4);
+        let first = cipher.seal(b"1234");
+        // The budget was exhausted by the seal above, so the next seal
+        // rekeys before sealing -- which also means the cipher can no
+        // longer open payloads sealed under the old key.
+        let second = cipher.seal(b"1234");
+
+        assert!(cipher.open(&first).is_err());
+        assert!(cipher.open(&second).is_ok());
+        assert_eq!(cipher.bytes_sealed(), 4);
+    }
+
+    #[test]
+    fn test_nonces_increase_per_seal() {
+        let cipher = ChannelCipher::new(CipherSuite::ChaCha20Poly1305, 1234, 1024);
+        let first = cipher.seal(b"a");
+        let second = cipher.seal(b"b");
+        assert!(second.nonce > first.nonce);
+    }
+}
@@ -11,6 +11,8 @@ use std::error::Error;
 use std::result::Result;
 use uuid::Uuid;
 
+use crate::node::NodeId;
+
 /// Type alias for Network operation results
 pub type NetworkResult<T> = Result<T, NetworkError>;
 
@@ -81,6 +83,18 @@ pub enum NetworkError {
     },
     /// Configuration error
     ConfigurationError(String),
+    /// A retry token was presented outside its freshness window
+    StaleToken {
+        age_secs: u64,
+        max_age_secs: u64,
+    },
+    /// A retry token's embedded peer address did not match the observed one
+    AddressMismatch {
+        expected: NodeId,
+        observed: NodeId,
+    },
+    /// A `NodeStore` backend failed to persist or load a node snapshot
+    PersistenceError(String),
 }
 
 /// Severity levels for topology violations
@@ -155,6 +169,12 @@ impl fmt::Display for NetworkError {
             write!(f, "Network capacity exceeded - current: {}, maximum: {}", current, maximum),
             Self::ConfigurationError(msg) =>
             write!(f, "Configuration error: {}", msg),
+            Self::StaleToken { age_secs, max_age_secs } =>
+            write!(f, "Retry token is stale - age: {}s, maximum: {}s", age_secs, max_age_secs),
+            Self::AddressMismatch { expected, observed } =>
+            write!(f, "Address mismatch - token was issued for {}, observed {}", expected.as_uuid(), observed.as_uuid()),
+            Self::PersistenceError(msg) =>
+            write!(f, "Node persistence error: {}", msg),
         }
     }
 }
@@ -220,6 +240,21 @@ impl NetworkError {
     pub fn topology_violation(message: String, severity: TopologyViolationSeverity) -> Self {
         Self::TopologyViolation { message, severity }
     }
+
+    /// Creates a new stale retry token error
+    pub fn stale_token(age_secs: u64, max_age_secs: u64) -> Self {
+        Self::StaleToken { age_secs, max_age_secs }
+    }
+
+    /// Creates a new address mismatch error
+    pub fn address_mismatch(expected: NodeId, observed: NodeId) -> Self {
+        Self::AddressMismatch { expected, observed }
+    }
+
+    /// Creates a new node persistence error
+    pub fn persistence_error(msg: impl Into<String>) -> Self {
+        Self::PersistenceError(msg.into())
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,227 @@
+//! Peer address validation and connection migration for `CrystalNode`
+//!
+//! `CrystalNode::connect` used to insert any peer into its connection map
+//! on request, which let a spoofed `NodeId` open a `TransportChannel` and
+//! exhaust `max_connections` without ever proving it controls the address
+//! it claims. This module adds a stateless retry-token handshake: a node
+//! seals a [`RetryToken`] binding the peer's id and the current time, and
+//! only allocates a channel once the peer echoes that token back within a
+//! configurable freshness window.
+//!
+//! It also adds [`ConnectionIdSet`], a small rotating set of short
+//! connection ids issued per peer with retire-prior-to semantics, so a
+//! connection can migrate across transport paths without re-running the
+//! handshake above.
+
+use std::hash::{Hash, Hasher};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use std::collections::hash_map::DefaultHasher;
+
+use crate::error::{ConnectionId, NetworkError, NetworkResult};
+use crate::node::NodeId;
+
+/// Default window within which an issued retry token remains valid.
+pub const DEFAULT_TOKEN_FRESHNESS: Duration = Duration::from_secs(30);
+
+/// A sealed, stateless retry token binding a peer id to an issue time.
+///
+/// Carries its payload in the clear alongside an authentication tag, the
+/// way the rest of this crate tracks stability and coherence as plain
+/// values rather than opaque ciphertext; the tag is what `connect` checks
+/// before trusting the payload, not secrecy of the payload itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryToken {
+    peer: NodeId,
+    issued_at: u64,
+    tag: u64,
+}
+
+impl RetryToken {
+    /// Seals a fresh token for `peer` using `key`, stamped with the current
+    /// time.
+    pub(crate) fn seal(key: u64, peer: NodeId) -> Self {
+        let issued_at = now_secs();
+        let tag = mac(key, peer, issued_at);
+        Self {
+            peer,
+            issued_at,
+            tag,
+        }
+    }
+
+    /// Validates that this token was sealed by `key` for `peer` and is
+    /// still within `freshness` of the current time.
+    pub(crate) fn validate(
+        &self,
+        key: u64,
+        peer: NodeId,
+        freshness: Duration,
+    ) -> NetworkResult<()> {
+        if self.peer != peer {
+            return Err(NetworkError::address_mismatch(self.peer, peer));
+        }
+
+        if mac(key, self.peer, self.issued_at) != self.tag {
+            return Err(NetworkError::address_mismatch(self.peer, peer));
+        }
+
+        let age = now_secs().saturating_sub(self.issued_at);
+        if age > freshness.as_secs() {
+            return Err(NetworkError::stale_token(age, freshness.as_secs()));
+        }
+
+        Ok(())
+    }
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+/// Keyed authentication tag over `(peer, issued_at)`.
+///
+/// This is a lightweight MAC rather than a true AEAD seal: the crate has
+/// no existing dependency on a cryptographic library, so this reuses
+/// `std`'s keyed `Hasher` the same way the rest of the crate favors plain
+/// arithmetic over bringing in new machinery for a single feature.
+fn mac(key: u64, peer: NodeId, issued_at: u64) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    key.hash(&mut hasher);
+    peer.as_uuid().hash(&mut hasher);
+    issued_at.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// One issued connection id and the sequence number it was issued under.
+#[derive(Debug, Clone, Copy)]
+struct IssuedConnectionId {
+    seq: u64,
+    id: ConnectionId,
+}
+
+/// A small rotating set of connection ids issued to one peer.
+///
+/// A peer migrating across transport paths presents one of its still-active
+/// ids instead of re-running the retry-token handshake; `retire_prior_to`
+/// lets the node drop ids older than a sequence number once the peer
+/// confirms it has moved on.
+#[derive(Debug)]
+pub struct ConnectionIdSet {
+    active: Vec<IssuedConnectionId>,
+    next_seq: u64,
+}
+
+impl ConnectionIdSet {
+    /// Creates a set with one freshly issued connection id.
+    pub fn new() -> Self {
+        let mut set = Self {
+            active: Vec::new(),
+            next_seq: 0,
+        };
+        set.issue();
+        set
+    }
+
+    /// Issues and tracks a new connection id, returning it.
+    pub fn issue(&mut self) -> ConnectionId {
+        let id = ConnectionId::new();
+        self.active.push(IssuedConnectionId {
+            seq: self.next_seq,
+            id,
+        });
+        self.next_seq += 1;
+        id
+    }
+
+    /// Returns true if `id` is one of this peer's still-active connection
+    /// ids.
+    pub fn contains(&self, id: ConnectionId) -> bool {
+        self.active.iter().any(|entry| entry.id == id)
+    }
+
+    /// Retires every connection id issued before `seq`.
+    pub fn retire_prior_to(&mut self, seq: u64) {
+        self.active.retain(|entry| entry.seq >= seq);
+    }
+
+    /// Returns the most recently issued connection id.
+    pub fn current(&self) -> ConnectionId {
+        self.active
+            .last()
+            .expect("a ConnectionIdSet always retains at least its newest id")
+            .id
+    }
+}
+
+impl Default for ConnectionIdSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_token_validates_for_matching_peer() {
+        let peer = NodeId::new();
+        let token = RetryToken::seal(42, peer);
+        assert!(token.validate(42, peer, DEFAULT_TOKEN_FRESHNESS).is_ok());
+    }
+
+    #[test]
+    fn test_retry_token_rejects_address_mismatch() {
+        let peer = NodeId::new();
+        let impostor = NodeId::new();
+        let token = RetryToken::seal(42, peer);
+        let result = token.validate(42, impostor, DEFAULT_TOKEN_FRESHNESS);
+        assert!(matches!(result, Err(NetworkError::AddressMismatch { .. })));
+    }
+
+    #[test]
+    fn test_retry_token_rejects_wrong_key() {
+        let peer = NodeId::new();
+        let token = RetryToken::seal(42, peer);
+        let result = token.validate(7, peer, DEFAULT_TOKEN_FRESHNESS);
+        assert!(matches!(result, Err(NetworkError::AddressMismatch { .. })));
+    }
+
+    #[test]
+    fn test_retry_token_rejects_stale_token() {
+        let peer = NodeId::new();
+        let mut token = RetryToken::seal(42, peer);
+        token.issued_at = token.issued_at.saturating_sub(3600);
+        token.tag = mac(42, peer, token.issued_at);
+        let result = token.validate(42, peer, DEFAULT_TOKEN_FRESHNESS);
+        assert!(matches!(result, Err(NetworkError::StaleToken { .. })));
+    }
+
+    #[test]
+    fn test_connection_id_set_issues_distinct_ids() {
+        let mut set = ConnectionIdSet::new();
+        let first = set.current();
+        let second = set.issue();
+        assert_ne!(first, second);
+        assert!(set.contains(first));
+        assert!(set.contains(second));
+    }
+
+    #[test]
+    fn test_connection_id_set_retire_prior_to() {
+        let mut set = ConnectionIdSet::new();
+        let first = set.current();
+        let second = set.issue();
+        let third = set.issue();
+
+        set.retire_prior_to(2);
+
+        assert!(!set.contains(first));
+        assert!(!set.contains(second));
+        assert!(set.contains(third));
+    }
+}
@@ -9,6 +9,10 @@ pub mod transport;
 pub mod topology;
 pub mod error;
 pub mod coherence;
+pub mod congestion;
+pub mod handshake;
+pub mod crypto;
+pub mod store;
 
 pub mod prelude {
     //! Convenient imports for common Mycelium types and traits
@@ -18,6 +22,10 @@ pub mod prelude {
     pub use crate::topology::TopologyType;
     pub use crate::error::{NetworkError, NetworkResult};
     pub use crate::coherence::{CoherenceMonitor, StabilityMetrics};
+    pub use crate::congestion::{CongestionAlgorithm, CongestionController};
+    pub use crate::handshake::RetryToken;
+    pub use crate::crypto::{CipherSuite, KeyShare};
+    pub use crate::store::{NodeStore, PersistenceBackend};
 }
 
 // Re-exports
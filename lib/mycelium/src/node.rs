@@ -9,12 +9,18 @@
 
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::RwLock;
 use uuid::Uuid;
 
-use crate::error::{NetworkError, NetworkResult};
+use crate::congestion::{CongestionAlgorithm, CongestionController};
+use crate::crypto::{self, ChannelCipher, CipherSuite, KeyShare};
+use crate::error::{ConnectionId, NetworkError, NetworkResult};
+use crate::handshake::{ConnectionIdSet, RetryToken, DEFAULT_TOKEN_FRESHNESS};
 use crate::transport::{TransportChannel, QuantumState};
 use crate::harmony::{HarmonyMonitor, Harmonizable};
+use crate::store::{ChannelSnapshot, NodeSnapshot, NodeStore, PersistenceBackend};
 use crate::topology::TopologyType;
 
 /// Unique identifier for crystal nodes
@@ -31,6 +37,12 @@ impl NodeId {
     pub fn as_uuid(&self) -> Uuid {
         self.0
     }
+
+    /// Wraps an existing UUID as a node ID, for restoring a node's
+    /// identity from a [`crate::store::NodeSnapshot`]
+    pub(crate) fn from_uuid(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
 }
 
 /// Configuration for crystal nodes
@@ -48,6 +60,22 @@ pub struct NodeConfig {
     pub auto_stabilize: bool,
     /// Node capabilities
     pub capabilities: NodeCapabilities,
+    /// Congestion control algorithm used for outgoing channels
+    pub congestion_algorithm: CongestionAlgorithm,
+    /// Freshness window a retry token must be presented within
+    pub token_freshness: Duration,
+    /// Plaintext bytes an encrypted channel seals before rekeying
+    pub rekey_after_bytes: u64,
+    /// How often a node created with [`CrystalNode::new_with_store`]
+    /// snapshots itself via [`crate::store::spawn_persistence_loop`];
+    /// `None` disables periodic snapshots (shutdown still snapshots once)
+    pub snapshot_interval: Option<Duration>,
+    /// Which [`NodeStore`] backend, if any, this node persists itself to
+    ///
+    /// Opening the backend is a fallible I/O operation, so this field only
+    /// selects it; [`PersistenceBackend::open`] builds the store that
+    /// [`CrystalNode::new_with_store`] then attaches.
+    pub persistence_backend: PersistenceBackend,
 }
 
 impl Default for NodeConfig {
@@ -59,10 +87,23 @@ impl Default for NodeConfig {
             max_connections: 16,
             auto_stabilize: true,
             capabilities: NodeCapabilities::default(),
+            congestion_algorithm: CongestionAlgorithm::default(),
+            token_freshness: DEFAULT_TOKEN_FRESHNESS,
+            rekey_after_bytes: DEFAULT_REKEY_AFTER_BYTES,
+            snapshot_interval: Some(DEFAULT_SNAPSHOT_INTERVAL),
+            persistence_backend: PersistenceBackend::None,
         }
     }
 }
 
+/// Default interval between periodic snapshots for a node persisted via
+/// [`crate::store::spawn_persistence_loop`]
+const DEFAULT_SNAPSHOT_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Default plaintext byte budget an encrypted channel seals before
+/// rekeying itself.
+const DEFAULT_REKEY_AFTER_BYTES: u64 = 1_000_000;
+
 /// Node operational state
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum NodeState {
@@ -93,6 +134,9 @@ pub struct NodeCapabilities {
     pub max_quantum_channels: usize,
     /// Supported topology types
     pub supported_topologies: Vec<TopologyType>,
+    /// Preferred AEAD suite for encrypted channels, if this node supports
+    /// negotiating one during `connect`
+    pub encryption_suite: Option<CipherSuite>,
 }
 
 impl Default for NodeCapabilities {
@@ -107,6 +151,7 @@ impl Default for NodeCapabilities {
                 TopologyType::Ring,
                 TopologyType::Star,
             ],
+            encryption_suite: None,
         }
     }
 }
@@ -126,6 +171,17 @@ pub struct NodeStats {
     pub uptime: u64,
     /// Current reality anchor strength
     pub reality_anchor: f64,
+    /// Total plaintext bytes sealed across all encrypted channels
+    pub encrypted_throughput: u64,
+    /// Cipher suite in use, if any channel has negotiated encryption
+    pub cipher: Option<CipherSuite>,
+}
+
+/// A channel's congestion controller plus how many updates are currently
+/// in flight on it
+struct ChannelCongestion {
+    controller: Box<dyn CongestionController>,
+    in_flight: usize,
 }
 
 /// Core crystal node implementation
@@ -140,6 +196,18 @@ pub struct CrystalNode {
     quantum_state: RwLock<QuantumState>,
     /// Active connections
     connections: RwLock<HashMap<NodeId, TransportChannel>>,
+    /// Per-channel congestion control state, keyed by the connected node
+    congestion: RwLock<HashMap<NodeId, ChannelCongestion>>,
+    /// Rotating connection ids issued per peer, for migration without
+    /// re-running the retry-token handshake
+    connection_ids: RwLock<HashMap<NodeId, ConnectionIdSet>>,
+    /// Key used to seal and validate this node's retry tokens
+    retry_token_key: u64,
+    /// AEAD state for channels that negotiated encryption, keyed by peer
+    encryption: RwLock<HashMap<NodeId, ChannelCipher>>,
+    /// This node's ephemeral key-exchange secrets, kept only until
+    /// `connect` derives the shared secret and discards them
+    pending_key_shares: RwLock<HashMap<NodeId, u64>>,
     /// Harmony monitor
     harmony_monitor: HarmonyMonitor,
     /// Operation statistics
@@ -148,11 +216,30 @@ pub struct CrystalNode {
     transition_counter: AtomicU64,
     /// Start timestamp
     start_time: std::time::Instant,
+    /// Backend this node snapshots itself to, if persistence is enabled
+    persistence: Option<Arc<dyn NodeStore>>,
 }
 
 impl CrystalNode {
     /// Creates a new crystal node with the given configuration
     pub fn new(config: NodeConfig) -> NetworkResult<Self> {
+        Self::with_id(config, NodeId::new())
+    }
+
+    /// Creates a new crystal node that snapshots itself to `store` every
+    /// [`NodeConfig::snapshot_interval`], and on [`Self::shutdown`]
+    ///
+    /// Use [`NodeStore::restore`] instead to resume a previously persisted
+    /// node.
+    pub fn new_with_store(config: NodeConfig, store: Arc<dyn NodeStore>) -> NetworkResult<Self> {
+        let mut node = Self::with_id(config, NodeId::new())?;
+        node.persistence = Some(store);
+        Ok(node)
+    }
+
+    /// Creates a new crystal node with an explicit identity, for restoring
+    /// one from a [`NodeSnapshot`] via [`NodeStore::restore`]
+    pub(crate) fn with_id(config: NodeConfig, id: NodeId) -> NetworkResult<Self> {
         if config.harmony_threshold < 0.0 || config.harmony_threshold > 1.0 {
             return Err(NetworkError::ConfigurationError(
                 "Harmony threshold must be between 0.0 and 1.0".into()
@@ -160,11 +247,16 @@ impl CrystalNode {
         }
 
         Ok(Self {
-            id: NodeId::new(),
+            id,
            config: config.clone(),
            state: RwLock::new(NodeState::Initializing),
            quantum_state: RwLock::new(QuantumState::new()),
            connections: RwLock::new(HashMap::new()),
+           congestion: RwLock::new(HashMap::new()),
+           connection_ids: RwLock::new(HashMap::new()),
+           retry_token_key: rand::random::<u64>(),
+           encryption: RwLock::new(HashMap::new()),
+           pending_key_shares: RwLock::new(HashMap::new()),
            harmony_monitor: HarmonyMonitor::new(config.harmony_threshold),
            stats: RwLock::new(NodeStats {
                harmony_level: 1.0,
@@ -173,12 +265,89 @@ impl CrystalNode {
                state_transitions: 0,
                uptime: 0,
                reality_anchor: config.reality_anchor,
+               encrypted_throughput: 0,
+               cipher: None,
            }),
            transition_counter: AtomicU64::new(0),
            start_time: std::time::Instant::now(),
+           persistence: None,
         })
     }
 
+    /// Captures the state a [`NodeStore`] needs to restore this node later
+    ///
+    /// Persists the quantum state, each connection's peer and
+    /// [`crate::transport::ChannelKind`] (not the live channel itself),
+    /// the harmony level, and the transition counter.
+    pub(crate) async fn snapshot_state(&self) -> NodeSnapshot {
+        let channels = self
+            .connections
+            .read()
+            .await
+            .iter()
+            .map(|(peer, channel)| ChannelSnapshot {
+                peer: peer.as_uuid().as_u128(),
+                kind: channel.kind(),
+            })
+            .collect();
+
+        NodeSnapshot {
+            node_id: self.id.as_uuid().as_u128(),
+            quantum_state: self.quantum_state.read().await.to_bytes(),
+            channels,
+            harmony_level: self.harmony_monitor.get_metrics().harmony_level,
+            transition_counter: self.transition_counter.load(Ordering::SeqCst),
+        }
+    }
+
+    /// Rehydrates this node's in-memory state from a previously captured
+    /// snapshot
+    ///
+    /// Each persisted connection gets a fresh placeholder channel of the
+    /// same [`crate::transport::ChannelKind`] -- the live socket behind
+    /// the original channel does not survive a restart -- and the node
+    /// resumes in [`NodeState::Stabilizing`] so callers re-establish and
+    /// re-negotiate those channels before relying on them.
+    pub(crate) async fn apply_snapshot(&self, snapshot: NodeSnapshot) -> NetworkResult<()> {
+        *self.quantum_state.write().await = QuantumState::from_bytes(&snapshot.quantum_state)?;
+
+        let mut connections = self.connections.write().await;
+        let mut congestion = self.congestion.write().await;
+        let mut connection_ids = self.connection_ids.write().await;
+        for channel in snapshot.channels {
+            let peer = NodeId::from_uuid(Uuid::from_u128(channel.peer));
+            connections.insert(peer, TransportChannel::from_kind(channel.kind));
+            congestion.insert(
+                peer,
+                ChannelCongestion {
+                    controller: self.config.congestion_algorithm.build_controller(),
+                    in_flight: 0,
+                },
+            );
+            connection_ids.insert(peer, ConnectionIdSet::new());
+        }
+        drop(connections);
+        drop(congestion);
+        drop(connection_ids);
+
+        self.transition_counter
+            .store(snapshot.transition_counter, Ordering::SeqCst);
+
+        *self.state.write().await = NodeState::Stabilizing;
+        self.update_stats().await
+    }
+
+    /// Snapshots this node to its configured store, if any
+    ///
+    /// A no-op when the node was created with [`Self::new`] rather than
+    /// [`Self::new_with_store`].
+    pub async fn snapshot_if_configured(&self) -> NetworkResult<()> {
+        match &self.persistence {
+            Some(store) => store.snapshot(self).await,
+            None => Ok(()),
+        }
+    }
+
     /// Returns the node's unique identifier
     pub fn id(&self) -> NodeId {
         self.id
@@ -204,8 +373,50 @@ impl CrystalNode {
         Ok(())
     }
 
+    /// Issues a stateless retry token for `peer`'s first contact attempt
+    ///
+    /// The peer must echo this token back to [`Self::connect`] before a
+    /// [`TransportChannel`] is allocated on its behalf, which keeps a
+    /// spoofed `NodeId` from exhausting `max_connections` without ever
+    /// proving it controls the address it claims.
+    pub fn issue_retry_token(&self, peer: NodeId) -> RetryToken {
+        RetryToken::seal(self.retry_token_key, peer)
+    }
+
+    /// Issues this node's contribution to an encrypted channel's key
+    /// exchange with `peer`
+    ///
+    /// The returned share must be forwarded to `peer` out of band; the
+    /// secret behind it is held until [`Self::connect`] derives the
+    /// channel's shared key, then discarded.
+    pub async fn issue_key_share(&self, peer: NodeId) -> KeyShare {
+        let (secret, share) = crypto::generate_key_share();
+        self.pending_key_shares.write().await.insert(peer, secret);
+        share
+    }
+
     /// Establishes a connection with another node
-    pub async fn connect(&self, target: NodeId, channel: TransportChannel) -> NetworkResult<()> {
+    ///
+    /// `token` must be one this node issued to `target` via
+    /// [`Self::issue_retry_token`] and still be within
+    /// [`NodeConfig::token_freshness`]; otherwise the channel is refused
+    /// with [`NetworkError::AddressMismatch`] or [`NetworkError::StaleToken`].
+    ///
+    /// If `encryption` is `Some((suite, peer_share))`, this side's own
+    /// key-exchange secret (from a prior [`Self::issue_key_share`] call, or
+    /// a fresh one if none is pending) is combined with `peer_share` to
+    /// derive a [`crate::crypto::ChannelCipher`] for the connection, and
+    /// this node's own share is returned so the peer can derive the same
+    /// key. A `None` channel stays in plaintext mode.
+    pub async fn connect(
+        &self,
+        target: NodeId,
+        channel: TransportChannel,
+        token: RetryToken,
+        encryption: Option<(CipherSuite, KeyShare)>,
+    ) -> NetworkResult<Option<KeyShare>> {
+        token.validate(self.retry_token_key, target, self.config.token_freshness)?;
+
         let mut connections = self.connections.write().await;
         if connections.len() >= self.config.max_connections {
             return Err(NetworkError::CapacityExceeded {
@@ -215,17 +426,185 @@ impl CrystalNode {
         }
 
         connections.insert(target, channel);
+        drop(connections);
+
+        let mut congestion = self.congestion.write().await;
+        congestion.insert(
+            target,
+            ChannelCongestion {
+                controller: self.config.congestion_algorithm.build_controller(),
+                in_flight: 0,
+            },
+        );
+        drop(congestion);
+
+        let mut connection_ids = self.connection_ids.write().await;
+        connection_ids.insert(target, ConnectionIdSet::new());
+        drop(connection_ids);
+
+        let own_share = if let Some((suite, peer_share)) = encryption {
+            let mut pending = self.pending_key_shares.write().await;
+            let (local_secret, own_share) = match pending.remove(&target) {
+                Some(secret) => (secret, KeyShare::from_secret(secret)),
+                None => crypto::generate_key_share(),
+            };
+            drop(pending);
+
+            let shared_secret = crypto::derive_shared_secret(local_secret, peer_share);
+            let cipher = ChannelCipher::new(suite, shared_secret, self.config.rekey_after_bytes);
+            self.encryption.write().await.insert(target, cipher);
+
+            Some(own_share)
+        } else {
+            None
+        };
+
         self.update_stats().await?;
+        Ok(own_share)
+    }
+
+    /// Migrates `peer`'s connection onto a new transport path
+    ///
+    /// `presented_id` must be one of the connection ids previously issued
+    /// to `peer` (via [`Self::issue_connection_id`] or the initial
+    /// [`Self::connect`]); on success the old channel is swapped for
+    /// `channel` without re-running the retry-token handshake.
+    pub async fn migrate_connection(
+        &self,
+        peer: NodeId,
+        presented_id: ConnectionId,
+        channel: TransportChannel,
+    ) -> NetworkResult<()> {
+        let connection_ids = self.connection_ids.read().await;
+        let ids = connection_ids.get(&peer).ok_or_else(|| {
+            NetworkError::ConfigurationError(format!("no connection to node {}", peer.as_uuid()))
+        })?;
+        if !ids.contains(presented_id) {
+            return Err(NetworkError::ConfigurationError(format!(
+                "connection id not recognized for node {}",
+                peer.as_uuid()
+            )));
+        }
+        drop(connection_ids);
+
+        let mut connections = self.connections.write().await;
+        let existing = connections.get_mut(&peer).ok_or_else(|| {
+            NetworkError::ConfigurationError(format!("no connection to node {}", peer.as_uuid()))
+        })?;
+        *existing = channel;
+        Ok(())
+    }
+
+    /// Issues a new rotating connection id for an already-connected peer
+    pub async fn issue_connection_id(&self, peer: NodeId) -> NetworkResult<ConnectionId> {
+        let mut connection_ids = self.connection_ids.write().await;
+        let ids = connection_ids.get_mut(&peer).ok_or_else(|| {
+            NetworkError::ConfigurationError(format!("no connection to node {}", peer.as_uuid()))
+        })?;
+        Ok(ids.issue())
+    }
+
+    /// Retires every connection id issued to `peer` before `seq`
+    pub async fn retire_connection_ids(&self, peer: NodeId, seq: u64) -> NetworkResult<()> {
+        let mut connection_ids = self.connection_ids.write().await;
+        let ids = connection_ids.get_mut(&peer).ok_or_else(|| {
+            NetworkError::ConfigurationError(format!("no connection to node {}", peer.as_uuid()))
+        })?;
+        ids.retire_prior_to(seq);
         Ok(())
     }
 
+    /// Sends a quantum state update over the channel to `target`
+    ///
+    /// Consults the channel's congestion controller before admitting the
+    /// update, returning [`NetworkError::CapacityExceeded`] if the
+    /// congestion window is already full. A harmony-drop (the transmitted
+    /// state's stability falling below [`NodeConfig::harmony_threshold`], or
+    /// the transmission failing outright) is fed back into the controller
+    /// as a loss signal instead of tripping the node into
+    /// [`NodeState::Destabilized`]; a clean transmission feeds back as an
+    /// ack, growing the window.
+    ///
+    /// If `target`'s connection negotiated encryption, `state` is sealed
+    /// with its [`ChannelCipher`] and the channel only ever sees the
+    /// resulting ciphertext, via [`TransportChannel::transmit_sealed`];
+    /// otherwise `state` reaches [`TransportChannel::transmit`] as-is.
+    pub async fn send_quantum_update(
+        &self,
+        target: NodeId,
+        state: QuantumState,
+    ) -> NetworkResult<QuantumState> {
+        {
+            let mut congestion = self.congestion.write().await;
+            let entry = congestion.get_mut(&target).ok_or_else(|| {
+                NetworkError::ConfigurationError(format!(
+                    "no connection to node {}",
+                    target.as_uuid()
+                ))
+            })?;
+
+            if !entry.controller.can_send(entry.in_flight) {
+                return Err(NetworkError::CapacityExceeded {
+                    current: entry.in_flight,
+                    maximum: entry.controller.cwnd() as usize,
+                });
+            }
+            entry.in_flight += 1;
+        }
+
+        let result = {
+            let connections = self.connections.read().await;
+            let channel = connections.get(&target).ok_or_else(|| {
+                NetworkError::ConfigurationError(format!(
+                    "no connection to node {}",
+                    target.as_uuid()
+                ))
+            })?;
+
+            match self.encryption.read().await.get(&target) {
+                Some(cipher) => {
+                    let sealed = cipher.seal(&state.to_bytes());
+                    channel.transmit_sealed(&sealed, cipher).await
+                }
+                None => channel.transmit(state).await,
+            }
+        };
+
+        let mut congestion = self.congestion.write().await;
+        if let Some(entry) = congestion.get_mut(&target) {
+            entry.in_flight = entry.in_flight.saturating_sub(1);
+
+            let harmony_dropped = match &result {
+                Ok(transmitted) => transmitted.stability() < self.config.harmony_threshold,
+                Err(_) => true,
+            };
+
+            if harmony_dropped {
+                entry.controller.on_loss();
+            } else {
+                entry.controller.on_ack();
+            }
+        }
+        drop(congestion);
+
+        self.update_stats().await?;
+        result
+    }
+
     /// Updates node statistics
     async fn update_stats(&self) -> NetworkResult<()> {
+        let encryption = self.encryption.read().await;
+        let encrypted_throughput = encryption.values().map(|cipher| cipher.bytes_sealed()).sum();
+        let cipher = encryption.values().next().map(|cipher| cipher.suite());
+        drop(encryption);
+
         let mut stats = self.stats.write().await;
         stats.harmony_level = self.harmony_monitor.get_metrics().harmony_level;
         stats.active_connections = self.connections.read().await.len();
         stats.state_transitions = self.transition_counter.load(Ordering::SeqCst);
         stats.uptime = self.start_time.elapsed().as_secs();
+        stats.encrypted_throughput = encrypted_throughput;
+        stats.cipher = cipher;
         Ok(())
     }
 
@@ -247,12 +626,32 @@ impl CrystalNode {
     }
 
     /// Initiates node shutdown
+    ///
+    /// Snapshots the node to its configured store, if any, before clearing
+    /// its in-memory connection state.
     pub async fn shutdown(&self) -> NetworkResult<()> {
+        self.snapshot_if_configured().await?;
+
         let mut state = self.state.write().await;
         *state = NodeState::ShuttingDown;
 
         let mut connections = self.connections.write().await;
         connections.clear();
+        drop(connections);
+
+        let mut congestion = self.congestion.write().await;
+        congestion.clear();
+        drop(congestion);
+
+        let mut connection_ids = self.connection_ids.write().await;
+        connection_ids.clear();
+        drop(connection_ids);
+
+        let mut encryption = self.encryption.write().await;
+        encryption.clear();
+        drop(encryption);
+
+        self.pending_key_shares.write().await.clear();
 
         self.update_stats().await?;
         Ok(())
@@ -311,11 +710,15 @@ mod tests {
         };
         let node = CrystalNode::new(config).unwrap();
 
-        node.connect(NodeId::new(), TransportChannel::new_quantum())
+        let first = NodeId::new();
+        let first_token = node.issue_retry_token(first);
+        node.connect(first, TransportChannel::new_quantum(), first_token, None)
         .await
         .unwrap();
 
-        let result = node.connect(NodeId::new(), TransportChannel::new_quantum()).await;
+        let second = NodeId::new();
+        let second_token = node.issue_retry_token(second);
+        let result = node.connect(second, TransportChannel::new_quantum(), second_token, None).await;
         assert!(matches!(result, Err(NetworkError::CapacityExceeded { .. })));
     }
 
@@ -341,4 +744,221 @@ mod tests {
         node.harmonize().unwrap();
         assert!(node.harmony_level() >= node.config.harmony_threshold);
     }
+
+    #[tokio::test]
+    async fn test_send_quantum_update_requires_connection() {
+        let config = NodeConfig::default();
+        let node = CrystalNode::new(config).unwrap();
+
+        let result = node
+            .send_quantum_update(NodeId::new(), QuantumState::new())
+            .await;
+        assert!(matches!(result, Err(NetworkError::ConfigurationError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_send_quantum_update_grows_window_on_success() {
+        let config = NodeConfig::default();
+        let node = CrystalNode::new(config).unwrap();
+        let target = NodeId::new();
+
+        let token = node.issue_retry_token(target);
+        node.connect(target, TransportChannel::new_quantum(), token, None)
+            .await
+            .unwrap();
+
+        let cwnd_before = node.congestion.read().await.get(&target).unwrap().controller.cwnd();
+
+        node.send_quantum_update(target, QuantumState::new())
+            .await
+            .unwrap();
+
+        let cwnd_after = node.congestion.read().await.get(&target).unwrap().controller.cwnd();
+        assert!(cwnd_after > cwnd_before);
+    }
+
+    #[tokio::test]
+    async fn test_send_quantum_update_shrinks_window_on_transmit_failure() {
+        let config = NodeConfig::default();
+        let node = CrystalNode::new(config).unwrap();
+        let target = NodeId::new();
+
+        // A hyperspace tunnel channel rejects transmits once its capacity is
+        // exhausted, which `send_quantum_update` feeds back as a loss
+        // signal just like a harmony-drop would.
+        let token = node.issue_retry_token(target);
+        node.connect(target, TransportChannel::new_hyperspace_tunnel(), token, None)
+            .await
+            .unwrap();
+        node.send_quantum_update(target, QuantumState::new())
+            .await
+            .unwrap();
+
+        let cwnd_before = node.congestion.read().await.get(&target).unwrap().controller.cwnd();
+
+        let result = node.send_quantum_update(target, QuantumState::new()).await;
+        assert!(matches!(result, Err(NetworkError::CapacityExceeded { .. })));
+
+        let cwnd_after = node.congestion.read().await.get(&target).unwrap().controller.cwnd();
+        assert!(cwnd_after <= cwnd_before);
+    }
+
+    #[tokio::test]
+    async fn test_send_quantum_update_blocks_when_window_full() {
+        let config = NodeConfig::default();
+        let node = CrystalNode::new(config).unwrap();
+        let target = NodeId::new();
+
+        let token = node.issue_retry_token(target);
+        node.connect(target, TransportChannel::new_quantum(), token, None)
+            .await
+            .unwrap();
+
+        // The window starts at 1.0, so marking one update in flight without
+        // it completing should leave no room for a second.
+        node.congestion
+            .write()
+            .await
+            .get_mut(&target)
+            .unwrap()
+            .in_flight = 1;
+
+        let result = node
+            .send_quantum_update(target, QuantumState::new())
+            .await;
+        assert!(matches!(result, Err(NetworkError::CapacityExceeded { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_connect_rejects_token_for_wrong_peer() {
+        let config = NodeConfig::default();
+        let node = CrystalNode::new(config).unwrap();
+        let target = NodeId::new();
+        let impostor_token = node.issue_retry_token(NodeId::new());
+
+        let result = node
+            .connect(target, TransportChannel::new_quantum(), impostor_token, None)
+            .await;
+        assert!(matches!(result, Err(NetworkError::AddressMismatch { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_connect_rejects_stale_token() {
+        let config = NodeConfig {
+            token_freshness: Duration::from_secs(0),
+            ..Default::default()
+        };
+        let node = CrystalNode::new(config).unwrap();
+        let target = NodeId::new();
+        let token = node.issue_retry_token(target);
+
+        tokio::time::sleep(Duration::from_millis(1100)).await;
+
+        let result = node
+            .connect(target, TransportChannel::new_quantum(), token, None)
+            .await;
+        assert!(matches!(result, Err(NetworkError::StaleToken { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_migrate_connection_with_recognized_id() {
+        let config = NodeConfig::default();
+        let node = CrystalNode::new(config).unwrap();
+        let target = NodeId::new();
+        let token = node.issue_retry_token(target);
+
+        node.connect(target, TransportChannel::new_quantum(), token, None)
+            .await
+            .unwrap();
+
+        let new_id = node.issue_connection_id(target).await.unwrap();
+        node.migrate_connection(target, new_id, TransportChannel::new_quantum())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_migrate_connection_rejects_unrecognized_id() {
+        let config = NodeConfig::default();
+        let node = CrystalNode::new(config).unwrap();
+        let target = NodeId::new();
+        let token = node.issue_retry_token(target);
+
+        node.connect(target, TransportChannel::new_quantum(), token, None)
+            .await
+            .unwrap();
+
+        let unrelated = ConnectionId::new();
+        let result = node
+            .migrate_connection(target, unrelated, TransportChannel::new_quantum())
+            .await;
+        assert!(matches!(result, Err(NetworkError::ConfigurationError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_retire_connection_ids_drops_old_ids() {
+        let config = NodeConfig::default();
+        let node = CrystalNode::new(config).unwrap();
+        let target = NodeId::new();
+        let token = node.issue_retry_token(target);
+
+        node.connect(target, TransportChannel::new_quantum(), token, None)
+            .await
+            .unwrap();
+
+        let initial_id = node.connection_ids.read().await.get(&target).unwrap().current();
+        let _new_id = node.issue_connection_id(target).await.unwrap();
+        node.retire_connection_ids(target, 1).await.unwrap();
+
+        let result = node
+            .migrate_connection(target, initial_id, TransportChannel::new_quantum())
+            .await;
+        assert!(matches!(result, Err(NetworkError::ConfigurationError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_connect_negotiates_encryption() {
+        let config = NodeConfig::default();
+        let node = CrystalNode::new(config).unwrap();
+        let target = NodeId::new();
+        let token = node.issue_retry_token(target);
+        let peer_share = node.issue_key_share(target).await;
+
+        let own_share = node
+            .connect(
+                target,
+                TransportChannel::new_quantum(),
+                token,
+                Some((CipherSuite::ChaCha20Poly1305, peer_share)),
+            )
+            .await
+            .unwrap();
+        assert!(own_share.is_some());
+
+        node.send_quantum_update(target, QuantumState::new())
+            .await
+            .unwrap();
+
+        let stats = node.get_stats().await;
+        assert_eq!(stats.cipher, Some(CipherSuite::ChaCha20Poly1305));
+        assert!(stats.encrypted_throughput > 0);
+    }
+
+    #[tokio::test]
+    async fn test_connect_without_encryption_leaves_channel_plaintext() {
+        let config = NodeConfig::default();
+        let node = CrystalNode::new(config).unwrap();
+        let target = NodeId::new();
+        let token = node.issue_retry_token(target);
+
+        let own_share = node
+            .connect(target, TransportChannel::new_quantum(), token, None)
+            .await
+            .unwrap();
+        assert!(own_share.is_none());
+
+        let stats = node.get_stats().await;
+        assert_eq!(stats.cipher, None);
+        assert_eq!(stats.encrypted_throughput, 0);
+    }
 }
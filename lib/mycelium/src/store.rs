@@ -0,0 +1,345 @@
+//! Pluggable persistence for node state snapshots and crash recovery
+//!
+//! `CrystalNode` keeps its quantum state, connection set, harmony level,
+//! and transition counter purely behind in-memory locks, so a restart
+//! loses all of it. This module adds a [`NodeStore`] trait -- following
+//! the move away from a single embedded KV store toward swappable
+//! embedded backends -- with an LMDB-backed [`LmdbNodeStore`] and a
+//! SQLite-backed [`SqliteNodeStore`] implementation, selectable via
+//! [`crate::node::NodeConfig::persistence_backend`].
+//!
+//! `NodeStore` only requires implementors to load and save a
+//! [`NodeSnapshot`]'s bytes; [`NodeStore::snapshot`] and
+//! [`NodeStore::restore`] build on top of that to capture and rehydrate a
+//! [`CrystalNode`], the same way [`crate::congestion::CongestionController`]
+//! derives `can_send` from `cwnd`.
+
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{NetworkError, NetworkResult};
+use crate::node::{CrystalNode, NodeConfig, NodeId};
+use crate::transport::ChannelKind;
+
+/// A restored connection's peer and kind -- not the live channel itself
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelSnapshot {
+    /// The connected peer's [`NodeId`], as a raw `u128`
+    pub peer: u128,
+    /// Which kind of channel connected this peer
+    pub kind: ChannelKind,
+}
+
+/// Everything a [`NodeStore`] needs to rehydrate a [`CrystalNode`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NodeSnapshot {
+    /// The node's identity, as a raw `u128`
+    pub node_id: u128,
+    /// The node's quantum state, serialized via
+    /// [`crate::transport::QuantumState::to_bytes`]
+    pub quantum_state: Vec<u8>,
+    /// Each connected peer's channel metadata
+    pub channels: Vec<ChannelSnapshot>,
+    /// The harmony level at the time of the snapshot
+    pub harmony_level: f64,
+    /// The node's transition counter at the time of the snapshot
+    pub transition_counter: u64,
+}
+
+/// Persists and restores [`CrystalNode`] snapshots for crash recovery
+///
+/// Implementors only need [`Self::save_snapshot`] and
+/// [`Self::load_snapshot`]; [`Self::snapshot`] and [`Self::restore`] wire
+/// those into `CrystalNode` itself.
+#[async_trait]
+pub trait NodeStore: Send + Sync {
+    /// Persists `snapshot`, replacing any previously stored one
+    async fn save_snapshot(&self, snapshot: &NodeSnapshot) -> NetworkResult<()>;
+
+    /// Loads the most recently persisted snapshot, if any
+    async fn load_snapshot(&self) -> NetworkResult<Option<NodeSnapshot>>;
+
+    /// Captures `node`'s current state and persists it
+    async fn snapshot(&self, node: &CrystalNode) -> NetworkResult<()> {
+        self.save_snapshot(&node.snapshot_state().await).await
+    }
+
+    /// Builds a node from `config`, restoring persisted state if present
+    ///
+    /// A recovered node resumes in [`crate::node::NodeState::Stabilizing`]
+    /// with a placeholder channel for each previously connected peer, so
+    /// callers re-establish and re-negotiate those channels before relying
+    /// on them. With no prior snapshot, this is equivalent to
+    /// [`CrystalNode::new`].
+    async fn restore(&self, config: NodeConfig) -> NetworkResult<CrystalNode> {
+        match self.load_snapshot().await? {
+            Some(snapshot) => {
+                let id = NodeId::from_uuid(uuid::Uuid::from_u128(snapshot.node_id));
+                let node = CrystalNode::with_id(config, id)?;
+                node.apply_snapshot(snapshot).await?;
+                Ok(node)
+            }
+            None => CrystalNode::new(config),
+        }
+    }
+}
+
+/// Which [`NodeStore`] backend a node persists itself to, and where
+#[derive(Debug, Clone, Default)]
+pub enum PersistenceBackend {
+    /// No persistence; the node is purely in-memory
+    #[default]
+    None,
+    /// LMDB-backed persistence, at the given environment directory
+    Lmdb(PathBuf),
+    /// SQLite-backed persistence, at the given database file
+    Sqlite(PathBuf),
+}
+
+impl PersistenceBackend {
+    /// Opens the selected backend, if any
+    pub fn open(&self) -> NetworkResult<Option<std::sync::Arc<dyn NodeStore>>> {
+        match self {
+            Self::None => Ok(None),
+            Self::Lmdb(path) => Ok(Some(std::sync::Arc::new(LmdbNodeStore::open(path)?))),
+            Self::Sqlite(path) => Ok(Some(std::sync::Arc::new(SqliteNodeStore::open(path)?))),
+        }
+    }
+}
+
+/// Key every snapshot is stored under -- a node persists exactly one
+/// snapshot of itself, always replacing the last.
+const SNAPSHOT_KEY: &str = "node_snapshot";
+
+/// An LMDB-backed [`NodeStore`]
+pub struct LmdbNodeStore {
+    env: heed::Env,
+    db: heed::Database<heed::types::Str, heed::types::SerdeBincode<NodeSnapshot>>,
+}
+
+impl LmdbNodeStore {
+    /// Opens (creating if needed) an LMDB environment at `path`
+    pub fn open(path: &Path) -> NetworkResult<Self> {
+        std::fs::create_dir_all(path).map_err(|e| {
+            NetworkError::persistence_error(format!(
+                "failed to create LMDB environment directory {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+
+        // Safety: the environment directory is not shared with another
+        // process's LMDB environment at a different map size.
+        let env = unsafe {
+            heed::EnvOpenOptions::new()
+                .map_size(16 * 1024 * 1024)
+                .max_dbs(1)
+                .open(path)
+        }
+            .map_err(|e| {
+                NetworkError::persistence_error(format!(
+                    "failed to open LMDB environment at {}: {}",
+                    path.display(),
+                    e
+                ))
+            })?;
+
+        let mut wtxn = env
+            .write_txn()
+            .map_err(|e| NetworkError::persistence_error(format!("failed to open LMDB write transaction: {}", e)))?;
+        let db = env
+            .create_database(&mut wtxn, Some("node_snapshots"))
+            .map_err(|e| NetworkError::persistence_error(format!("failed to open LMDB database: {}", e)))?;
+        wtxn.commit()
+            .map_err(|e| NetworkError::persistence_error(format!("failed to commit LMDB database creation: {}", e)))?;
+
+        Ok(Self { env, db })
+    }
+}
+
+#[async_trait]
+impl NodeStore for LmdbNodeStore {
+    async fn save_snapshot(&self, snapshot: &NodeSnapshot) -> NetworkResult<()> {
+        let mut wtxn = self
+            .env
+            .write_txn()
+            .map_err(|e| NetworkError::persistence_error(format!("failed to open LMDB write transaction: {}", e)))?;
+        self.db
+            .put(&mut wtxn, SNAPSHOT_KEY, snapshot)
+            .map_err(|e| NetworkError::persistence_error(format!("failed to write node snapshot: {}", e)))?;
+        wtxn.commit()
+            .map_err(|e| NetworkError::persistence_error(format!("failed to commit node snapshot: {}", e)))?;
+        Ok(())
+    }
+
+    async fn load_snapshot(&self) -> NetworkResult<Option<NodeSnapshot>> {
+        let rtxn = self
+            .env
+            .read_txn()
+            .map_err(|e| NetworkError::persistence_error(format!("failed to open LMDB read transaction: {}", e)))?;
+        self.db
+            .get(&rtxn, SNAPSHOT_KEY)
+            .map_err(|e| NetworkError::persistence_error(format!("failed to read node snapshot: {}", e)))
+    }
+}
+
+/// A SQLite-backed [`NodeStore`]
+pub struct SqliteNodeStore {
+    conn: std::sync::Mutex<rusqlite::Connection>,
+}
+
+impl SqliteNodeStore {
+    /// Opens (creating if needed) a SQLite database at `path`
+    pub fn open(path: &Path) -> NetworkResult<Self> {
+        let conn = rusqlite::Connection::open(path).map_err(|e| {
+            NetworkError::persistence_error(format!(
+                "failed to open SQLite database at {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS node_snapshot (id INTEGER PRIMARY KEY CHECK (id = 0), payload BLOB NOT NULL)",
+            (),
+        )
+        .map_err(|e| NetworkError::persistence_error(format!("failed to initialize node_snapshot table: {}", e)))?;
+
+        Ok(Self {
+            conn: std::sync::Mutex::new(conn),
+        })
+    }
+}
+
+#[async_trait]
+impl NodeStore for SqliteNodeStore {
+    async fn save_snapshot(&self, snapshot: &NodeSnapshot) -> NetworkResult<()> {
+        let payload = bincode::serialize(snapshot)
+            .map_err(|e| NetworkError::persistence_error(format!("failed to encode node snapshot: {}", e)))?;
+
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "INSERT INTO node_snapshot (id, payload) VALUES (0, ?1)
+             ON CONFLICT(id) DO UPDATE SET payload = excluded.payload",
+            [payload],
+        )
+        .map_err(|e| NetworkError::persistence_error(format!("failed to persist node snapshot: {}", e)))?;
+        Ok(())
+    }
+
+    async fn load_snapshot(&self) -> NetworkResult<Option<NodeSnapshot>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn
+            .prepare("SELECT payload FROM node_snapshot WHERE id = 0")
+            .map_err(|e| NetworkError::persistence_error(format!("failed to prepare snapshot query: {}", e)))?;
+        let mut rows = stmt
+            .query(())
+            .map_err(|e| NetworkError::persistence_error(format!("failed to query node snapshot: {}", e)))?;
+
+        match rows
+            .next()
+            .map_err(|e| NetworkError::persistence_error(format!("failed to read node snapshot row: {}", e)))?
+        {
+            Some(row) => {
+                let payload: Vec<u8> = row
+                    .get(0)
+                    .map_err(|e| NetworkError::persistence_error(format!("failed to read node snapshot payload: {}", e)))?;
+                let snapshot = bincode::deserialize(&payload)
+                    .map_err(|e| NetworkError::persistence_error(format!("failed to decode node snapshot: {}", e)))?;
+                Ok(Some(snapshot))
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+/// Spawns a background task that snapshots `node` to its configured store
+/// every [`crate::node::NodeConfig::snapshot_interval`], until `node` is
+/// dropped
+///
+/// Does nothing (and returns `None`) if `node` has no store attached or
+/// `snapshot_interval` is `None`.
+pub fn spawn_persistence_loop(
+    node: std::sync::Arc<CrystalNode>,
+    interval: std::time::Duration,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            // A single missed snapshot should not stop future attempts; the
+            // store itself is the place to surface a persistent failure.
+            let _ = node.snapshot_if_configured().await;
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::node::NodeConfig;
+    use crate::transport::TransportChannel;
+
+    fn temp_path(label: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "mycelium-store-test-{}-{}-{}",
+            std::process::id(),
+            label,
+            NodeId::new().as_uuid()
+        ))
+    }
+
+    #[tokio::test]
+    async fn test_lmdb_store_round_trips_a_snapshot() {
+        let store = LmdbNodeStore::open(&temp_path("lmdb")).unwrap();
+        let node = CrystalNode::new(NodeConfig::default()).unwrap();
+        let target = NodeId::new();
+        let token = node.issue_retry_token(target);
+        node.connect(target, TransportChannel::new_quantum(), token, None)
+            .await
+            .unwrap();
+
+        store.snapshot(&node).await.unwrap();
+
+        let restored = store.restore(NodeConfig::default()).await.unwrap();
+        assert_eq!(restored.id(), node.id());
+        assert_eq!(restored.snapshot_state().await.channels.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_store_round_trips_a_snapshot() {
+        let store = SqliteNodeStore::open(&temp_path("sqlite")).unwrap();
+        let node = CrystalNode::new(NodeConfig::default()).unwrap();
+        let target = NodeId::new();
+        let token = node.issue_retry_token(target);
+        node.connect(target, TransportChannel::new_quantum(), token, None)
+            .await
+            .unwrap();
+
+        store.snapshot(&node).await.unwrap();
+
+        let restored = store.restore(NodeConfig::default()).await.unwrap();
+        assert_eq!(restored.id(), node.id());
+        assert_eq!(restored.snapshot_state().await.channels.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_restore_with_no_prior_snapshot_creates_a_fresh_node() {
+        let store = SqliteNodeStore::open(&temp_path("sqlite-empty")).unwrap();
+        let restored = store.restore(NodeConfig::default()).await.unwrap();
+        assert_eq!(restored.snapshot_state().await.channels.len(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_snapshots_a_node_with_a_store_attached() {
+        let path = temp_path("shutdown");
+        let store: std::sync::Arc<dyn NodeStore> =
+            std::sync::Arc::new(SqliteNodeStore::open(&path).unwrap());
+        let node = CrystalNode::new_with_store(NodeConfig::default(), store.clone()).unwrap();
+
+        node.shutdown().await.unwrap();
+
+        assert!(store.load_snapshot().await.unwrap().is_some());
+    }
+}
@@ -7,6 +7,7 @@
 //! Last Updated: 2025-01-20 02:03:23 UTC
 
 use std::sync::atomic::{AtomicU64, Ordering};
+use serde::{Deserialize, Serialize};
 use tokio::sync::RwLock;
 use uuid::Uuid;
 
@@ -71,6 +72,59 @@ impl QuantumState {
         self.reality_anchor
     }
 
+    /// Serializes this state to bytes, for sealing in an encrypted channel
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(8 + self.components.len() * 16 + 24);
+        buf.extend_from_slice(&(self.components.len() as u64).to_le_bytes());
+        for component in &self.components {
+            buf.extend_from_slice(&component.real.to_le_bytes());
+            buf.extend_from_slice(&component.imag.to_le_bytes());
+        }
+        buf.extend_from_slice(&self.stability.to_le_bytes());
+        buf.extend_from_slice(&self.reality_anchor.to_le_bytes());
+        buf.extend_from_slice(&self.timestamp.to_le_bytes());
+        buf
+    }
+
+    /// Reconstructs a state previously serialized with [`Self::to_bytes`]
+    pub fn from_bytes(bytes: &[u8]) -> NetworkResult<Self> {
+        if bytes.len() < 8 {
+            return Err(NetworkError::ConfigurationError(
+                "quantum state payload too short to contain a component count".into(),
+            ));
+        }
+
+        let len = u64::from_le_bytes(bytes[0..8].try_into().unwrap()) as usize;
+        let expected_len = 8 + len * 16 + 24;
+        if bytes.len() != expected_len {
+            return Err(NetworkError::ConfigurationError(format!(
+                "quantum state payload has {} bytes, expected {}",
+                bytes.len(),
+                expected_len
+            )));
+        }
+
+        let mut cursor = 8;
+        let mut components = Vec::with_capacity(len);
+        for _ in 0..len {
+            let real = f64::from_le_bytes(bytes[cursor..cursor + 8].try_into().unwrap());
+            let imag = f64::from_le_bytes(bytes[cursor + 8..cursor + 16].try_into().unwrap());
+            components.push(Complex::new(real, imag));
+            cursor += 16;
+        }
+
+        let stability = f64::from_le_bytes(bytes[cursor..cursor + 8].try_into().unwrap());
+        let reality_anchor = f64::from_le_bytes(bytes[cursor + 8..cursor + 16].try_into().unwrap());
+        let timestamp = u64::from_le_bytes(bytes[cursor + 16..cursor + 24].try_into().unwrap());
+
+        Ok(Self {
+            components,
+            stability,
+            reality_anchor,
+            timestamp,
+        })
+    }
+
     /// Applies a quantum transformation
     pub fn apply_transform(&mut self, transform: &QuantumTransform) -> NetworkResult<()> {
         if transform.components.len() != self.components.len() {
@@ -121,6 +175,19 @@ impl QuantumTransform {
     }
 }
 
+/// Which kind of [`TransportChannel`] a connection uses, independent of
+/// its live state -- what a [`crate::store::NodeStore`] snapshot persists
+/// in place of the channel itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChannelKind {
+    /// A [`TransportChannel::QuantumBridge`]
+    QuantumBridge,
+    /// A [`TransportChannel::RealityAnchor`]
+    RealityAnchor,
+    /// A [`TransportChannel::HyperspaceTunnel`]
+    HyperspaceTunnel,
+}
+
 /// Types of transport channels
 #[derive(Debug)]
 pub enum TransportChannel {
@@ -266,6 +333,27 @@ impl TransportChannel {
         }
     }
 
+    /// Transmits a quantum state that arrived sealed from an encrypted
+    /// channel.
+    ///
+    /// Unseals `sealed` with `cipher`, reconstructing the [`QuantumState`]
+    /// the sender serialized with [`QuantumState::to_bytes`], then carries
+    /// it through the same per-channel-kind logic as [`Self::transmit`].
+    /// Callers on an encrypted channel should call this instead of
+    /// `transmit` directly, so the only value that ever crosses the
+    /// channel boundary is ciphertext -- `transmit` keeps taking plaintext
+    /// for unencrypted channels, and this delegates to it once the
+    /// payload has been opened.
+    pub async fn transmit_sealed(
+        &self,
+        sealed: &crate::crypto::SealedPayload,
+        cipher: &crate::crypto::ChannelCipher,
+    ) -> NetworkResult<QuantumState> {
+        let plaintext = cipher.open(sealed)?;
+        let state = QuantumState::from_bytes(&plaintext)?;
+        self.transmit(state).await
+    }
+
     /// Returns the channel's unique identifier
     pub fn id(&self) -> Uuid {
         match self {
@@ -275,6 +363,29 @@ impl TransportChannel {
         }
     }
 
+    /// Returns which kind of channel this is, for persistence
+    pub fn kind(&self) -> ChannelKind {
+        match self {
+            Self::QuantumBridge { .. } => ChannelKind::QuantumBridge,
+            Self::RealityAnchor { .. } => ChannelKind::RealityAnchor,
+            Self::HyperspaceTunnel { .. } => ChannelKind::HyperspaceTunnel,
+        }
+    }
+
+    /// Rebuilds a fresh channel of `kind`, for restoring a connection's
+    /// placeholder after a [`crate::store::NodeStore`] restore
+    ///
+    /// The live socket/state behind the original channel is gone; this
+    /// only re-establishes a channel of the same kind so the connection
+    /// exists again and can be re-negotiated.
+    pub fn from_kind(kind: ChannelKind) -> Self {
+        match kind {
+            ChannelKind::QuantumBridge => Self::new_quantum(),
+            ChannelKind::RealityAnchor => Self::new_reality_anchor(),
+            ChannelKind::HyperspaceTunnel => Self::new_hyperspace_tunnel(),
+        }
+    }
+
     /// Returns the channel's stability
     pub async fn stability(&self) -> f64 {
         match self {
@@ -336,6 +447,7 @@ impl Harmonizable for TransportChannel {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::crypto::{ChannelCipher, CipherSuite};
 
     #[tokio::test]
     async fn test_quantum_state_creation() {
@@ -344,6 +456,22 @@ mod tests {
         assert_eq!(state.reality_anchor(), 1.0);
     }
 
+    #[test]
+    fn test_quantum_state_byte_round_trip() {
+        let state = QuantumState::new();
+        let bytes = state.to_bytes();
+        let restored = QuantumState::from_bytes(&bytes).unwrap();
+        assert_eq!(state, restored);
+    }
+
+    #[test]
+    fn test_quantum_state_from_bytes_rejects_truncated_payload() {
+        let state = QuantumState::new();
+        let mut bytes = state.to_bytes();
+        bytes.truncate(bytes.len() - 1);
+        assert!(QuantumState::from_bytes(&bytes).is_err());
+    }
+
     #[tokio::test]
     async fn test_quantum_transform() {
         let mut state = QuantumState::new();
@@ -360,6 +488,28 @@ mod tests {
         assert!(transmitted.stability() > 0.0);
     }
 
+    #[tokio::test]
+    async fn test_transmit_sealed_only_accepts_ciphertext_and_matches_plaintext_path() {
+        let cipher = ChannelCipher::new(CipherSuite::ChaCha20Poly1305, 42, 1_000_000);
+        let state = QuantumState::new();
+        let sealed = cipher.seal(&state.to_bytes());
+
+        // What actually crosses the channel boundary for an encrypted
+        // connection is `sealed.ciphertext`, not `state.to_bytes()` --
+        // confirm it doesn't even parse as a `QuantumState`, so nothing
+        // downstream could mistake it for the plaintext payload.
+        assert_ne!(sealed.ciphertext, state.to_bytes());
+        assert!(QuantumState::from_bytes(&sealed.ciphertext).is_err());
+
+        let sealed_channel = TransportChannel::new_quantum();
+        let via_sealed = sealed_channel.transmit_sealed(&sealed, &cipher).await.unwrap();
+
+        let plain_channel = TransportChannel::new_quantum();
+        let via_plain = plain_channel.transmit(state).await.unwrap();
+
+        assert_eq!(via_sealed, via_plain);
+    }
+
     #[tokio::test]
     async fn test_reality_anchor_transmission() {
         let channel = TransportChannel::new_reality_anchor();
@@ -390,4 +540,15 @@ mod tests {
         channel.harmonize().unwrap();
         assert!(channel.harmony_level() >= 0.87);
     }
+
+    #[test]
+    fn test_channel_kind_round_trips_through_from_kind() {
+        for kind in [
+            ChannelKind::QuantumBridge,
+            ChannelKind::RealityAnchor,
+            ChannelKind::HyperspaceTunnel,
+        ] {
+            assert_eq!(TransportChannel::from_kind(kind).kind(), kind);
+        }
+    }
 }
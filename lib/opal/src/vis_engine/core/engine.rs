@@ -1,12 +1,21 @@
 use wgpu::*;
 use winit::window::Window;
 
+use crate::vis_engine::resonance_client::{Client, ResonanceFrame};
+
 pub struct VisEngine {
     surface: Surface,
     device: Device,
     queue: Queue,
     config: SurfaceConfiguration,
     window: Window,
+    /// Optional remote collector; if set, each successful `render` also
+    /// streams `frame` to it so other viewers or a recorder can observe
+    /// the same simulation without sharing this GPU surface.
+    sink: Option<Box<dyn Client>>,
+    /// The wave/field amplitudes and `Zeronaut` coordinates that the
+    /// next `render` call will stream to `sink`, if one is set.
+    frame: ResonanceFrame,
 }
 
 impl VisEngine {
@@ -59,9 +68,29 @@ impl VisEngine {
             queue,
             config,
             window: window.clone(),
+            sink: None,
+            frame: ResonanceFrame::default(),
         })
     }
 
+    /// Installs a streaming sink; `render` will push the most recently
+    /// set `ResonanceFrame` to it on every successful redraw.
+    pub fn set_sink(&mut self, sink: Box<dyn Client>) {
+        self.sink = Some(sink);
+    }
+
+    /// Removes any installed streaming sink.
+    pub fn clear_sink(&mut self) {
+        self.sink = None;
+    }
+
+    /// Updates the wave/field amplitudes and `Zeronaut` coordinates
+    /// that the next `render` call will stream to the sink, if one is
+    /// set.
+    pub fn update_frame(&mut self, frame: ResonanceFrame) {
+        self.frame = frame;
+    }
+
     pub fn resize(&mut self, new_size: winit::dpi::PhysicalSize<u32>) {
         if new_size.width > 0 && new_size.height > 0 {
             self.config.width = new_size.width;
@@ -100,6 +129,10 @@ impl VisEngine {
         self.queue.submit(std::iter::once(encoder.finish()));
         output.present();
 
+        if let Some(sink) = &mut self.sink {
+            sink.send_frame_async(self.frame.clone());
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,199 @@
+//! Graphviz DOT Export for Navigation Traces
+//! =========================================
+//!
+//! `VisEngine` only renders live, via wgpu -- there's no way to hand
+//! someone a reproducible artifact of a navigation run without shipping
+//! them a GPU window. `GraphWriter` fills that gap: it accumulates the
+//! nodes and edges of a `Zeronaut` navigation trace and serializes them
+//! to a DOT string that any Graphviz tool (or a diff) can consume.
+
+/// Which flavor of Graphviz graph to emit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    /// Directed graph, edges drawn with `->`.
+    Digraph,
+    /// Undirected graph, edges drawn with `--`.
+    Graph,
+}
+
+impl Kind {
+    /// The DOT keyword used to open the graph block.
+    fn keyword(&self) -> &'static str {
+        match self {
+            Kind::Digraph => "digraph",
+            Kind::Graph => "graph",
+        }
+    }
+
+    /// The edge operator this graph kind draws its edges with.
+    pub fn edge_operator(&self) -> &'static str {
+        match self {
+            Kind::Digraph => "->",
+            Kind::Graph => "--",
+        }
+    }
+}
+
+/// A single recorded `Zeronaut` position, labeled with its quantum
+/// coordinates and stability metrics at the time it was visited.
+struct Node {
+    coordinates: [f64; 4],
+    coherence: f64,
+    anchor_strength: f64,
+}
+
+/// A recorded shift between two nodes, labeled with the delta that
+/// produced it and the resonance of the position it landed on.
+struct Edge {
+    from: usize,
+    to: usize,
+    delta: (f64, f64, f64, f64),
+    resonance: f64,
+}
+
+/// Accumulates a `Zeronaut` navigation trace and serializes it to DOT.
+///
+/// Feed it one node per visited position (starting with the origin) and
+/// one edge per `shift`, then call [`GraphWriter::to_dot`] once the run
+/// is over. See [`record_shift`](GraphWriter::record_shift) for the
+/// shape a `Zeronaut::shift` trace sink should call into.
+pub struct GraphWriter {
+    kind: Kind,
+    nodes: Vec<Node>,
+    edges: Vec<Edge>,
+}
+
+impl GraphWriter {
+    /// Creates an empty graph of the given kind.
+    pub fn new(kind: Kind) -> Self {
+        Self {
+            kind,
+            nodes: Vec::new(),
+            edges: Vec::new(),
+        }
+    }
+
+    /// Records a visited position, returning its node index for use in
+    /// a later [`add_edge`](GraphWriter::add_edge) call.
+    pub fn add_node(&mut self, coordinates: [f64; 4], coherence: f64, anchor_strength: f64) -> usize {
+        self.nodes.push(Node {
+            coordinates,
+            coherence,
+            anchor_strength,
+        });
+        self.nodes.len() - 1
+    }
+
+    /// Records an edge between two previously added nodes.
+    pub fn add_edge(&mut self, from: usize, to: usize, delta: (f64, f64, f64, f64), resonance: f64) {
+        self.edges.push(Edge { from, to, delta, resonance });
+    }
+
+    /// Records one `Zeronaut::shift`: the node it landed on plus the
+    /// edge from the prior position. This is the shape a `Zeronaut`
+    /// shift trace sink should call into -- `before`/`after` are the
+    /// `[prime, resonant, harmonic, aether]` coordinates as `f64`
+    /// before and after the shift, `delta` is the `Vector4D` that was
+    /// applied, and `resonance` is `Zeronaut::resonance()` after the
+    /// shift landed.
+    pub fn record_shift(
+        &mut self,
+        before: [f64; 4],
+        after: [f64; 4],
+        coherence: f64,
+        anchor_strength: f64,
+        delta: (f64, f64, f64, f64),
+        resonance: f64,
+    ) {
+        let from = self.nodes.iter().position(|n| n.coordinates == before).unwrap_or_else(|| {
+            self.add_node(before, coherence, anchor_strength)
+        });
+        let to = self.add_node(after, coherence, anchor_strength);
+        self.add_edge(from, to, delta, resonance);
+    }
+
+    /// Serializes the accumulated nodes and edges to a valid DOT string.
+    pub fn to_dot(&self) -> String {
+        let mut dot = format!("{} navigation {{\n", self.kind.keyword());
+
+        for (id, node) in self.nodes.iter().enumerate() {
+            dot.push_str(&format!(
+                "  n{} [label=\"[{:.4}, {:.4}, {:.4}, {:.4}]\\ncoherence={:.4}, anchor={:.4}\"];\n",
+                id,
+                node.coordinates[0],
+                node.coordinates[1],
+                node.coordinates[2],
+                node.coordinates[3],
+                node.coherence,
+                node.anchor_strength,
+            ));
+        }
+
+        let op = self.kind.edge_operator();
+        for edge in &self.edges {
+            dot.push_str(&format!(
+                "  n{} {} n{} [label=\"delta=({:.4}, {:.4}, {:.4}, {:.4})\\nresonance={:.4}\"];\n",
+                edge.from, op, edge.to, edge.delta.0, edge.delta.1, edge.delta.2, edge.delta.3, edge.resonance,
+            ));
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_digraph_uses_arrow_operator() {
+        let mut writer = GraphWriter::new(Kind::Digraph);
+        let a = writer.add_node([0.0, 0.0, 0.0, 0.0], 0.87, 1.0);
+        let b = writer.add_node([1.0, 2.0, 3.0, 4.0], 0.82, 0.95);
+        writer.add_edge(a, b, (1.0, 2.0, 3.0, 4.0), 5.47);
+
+        let dot = writer.to_dot();
+        assert!(dot.starts_with("digraph navigation {\n"));
+        assert!(dot.contains("n0 -> n1"));
+    }
+
+    #[test]
+    fn test_graph_uses_double_dash_operator() {
+        let mut writer = GraphWriter::new(Kind::Graph);
+        let a = writer.add_node([0.0, 0.0, 0.0, 0.0], 0.87, 1.0);
+        let b = writer.add_node([1.0, 0.0, 0.0, 0.0], 0.87, 1.0);
+        writer.add_edge(a, b, (1.0, 0.0, 0.0, 0.0), 1.0);
+
+        let dot = writer.to_dot();
+        assert!(dot.starts_with("graph navigation {\n"));
+        assert!(dot.contains("n0 -- n1"));
+        assert!(!dot.contains("->"));
+    }
+
+    #[test]
+    fn test_record_shift_reuses_existing_origin_node() {
+        let mut writer = GraphWriter::new(Kind::Digraph);
+        writer.record_shift(
+            [0.0, 0.0, 0.0, 0.0],
+            [1.0, 2.0, 3.0, 4.0],
+            0.87,
+            1.0,
+            (1.0, 2.0, 3.0, 4.0),
+            5.47,
+        );
+        writer.record_shift(
+            [1.0, 2.0, 3.0, 4.0],
+            [2.0, 2.0, 3.0, 4.0],
+            0.84,
+            0.95,
+            (1.0, 0.0, 0.0, 0.0),
+            4.9,
+        );
+
+        assert_eq!(writer.nodes.len(), 3);
+        assert_eq!(writer.edges.len(), 2);
+        // The second shift's "before" should reuse the first shift's "after".
+        assert_eq!(writer.edges[1].from, writer.edges[0].to);
+    }
+}
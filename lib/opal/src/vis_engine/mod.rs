@@ -1,10 +1,14 @@
 pub mod core;
+pub mod graph_export;
 pub mod renderers;
+pub mod resonance_client;
 pub mod views;
 pub mod utils;
 
 pub use core::engine::VisEngine;
+pub use graph_export::{GraphWriter, Kind};
 pub use renderers::Renderer;
+pub use resonance_client::{AsyncClient, Client, ClientError, ResonanceFrame, SyncClient};
 pub use views::{FieldVisualizer, LatticeVisualizer};
 
 pub const VERSION: &str = "0.1.0";
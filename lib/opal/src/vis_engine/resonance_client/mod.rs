@@ -0,0 +1,174 @@
+//! Resonance Streaming Clients
+//! ===========================
+//!
+//! `VisEngine` only renders live, via wgpu -- there's no way to let a
+//! second viewer, a recorder, or an external dashboard observe the same
+//! simulation without sharing the GPU surface. `ResonanceFrame` packages
+//! one rendered tick's wave/field amplitudes and any active `Zeronaut`
+//! coordinates into a compact snapshot; `SyncClient`/`AsyncClient` let
+//! `VisEngine` push that snapshot to a remote collector alongside the
+//! local render.
+//!
+//! Like `graph_export`, the frame carries plain data rather than the
+//! actual `Wave`/`Zeronaut` types, so a client implementation doesn't
+//! need to depend on whichever crate produced them.
+
+/// A snapshot of one rendered tick, ready to hand to a `SyncClient` or
+/// `AsyncClient`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ResonanceFrame {
+    /// Sequence number, incremented once per frame captured.
+    pub sequence: u64,
+    /// `Wave`/`WaveField` amplitudes at this tick.
+    pub wave_amplitudes: Vec<f64>,
+    /// `[prime, resonant, harmonic, aether]` coordinates of every
+    /// active `Zeronaut` at this tick.
+    pub zeronaut_coordinates: Vec<[f64; 4]>,
+}
+
+/// Failure sending a `ResonanceFrame`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClientError {
+    /// The transport failed but a retry might succeed (a dropped
+    /// connection, a timeout, a busy collector).
+    Transient(String),
+    /// The transport failed in a way a retry cannot fix (the endpoint
+    /// rejected the frame, the connection was closed for good).
+    Fatal(String),
+}
+
+/// Blocking frame transmission: waits for the frame to be handed off to
+/// the transport before returning, retrying on transient failure.
+pub trait SyncClient {
+    /// Attempts the transmission once. `send_frame`'s retry loop is
+    /// built on top of this.
+    fn try_send_frame(&mut self, frame: &ResonanceFrame) -> Result<(), ClientError>;
+
+    /// Serializes and transmits `frame`, retrying up to 3 times while
+    /// the failure is `Transient`.
+    fn send_frame(&mut self, frame: &ResonanceFrame) -> Result<(), ClientError> {
+        self.send_frame_with_retries(frame, 3)
+    }
+
+    /// Serializes and transmits `frame`, retrying up to `max_attempts`
+    /// times while the failure is `Transient`. A `Fatal` failure gives
+    /// up immediately.
+    fn send_frame_with_retries(
+        &mut self,
+        frame: &ResonanceFrame,
+        max_attempts: u32,
+    ) -> Result<(), ClientError> {
+        let mut last_err = ClientError::Transient("no attempts made".to_string());
+        for _ in 0..max_attempts.max(1) {
+            match self.try_send_frame(frame) {
+                Ok(()) => return Ok(()),
+                Err(ClientError::Fatal(msg)) => return Err(ClientError::Fatal(msg)),
+                Err(err) => last_err = err,
+            }
+        }
+        Err(last_err)
+    }
+}
+
+/// Non-blocking frame transmission: fires the frame at the transport
+/// without awaiting acknowledgement, so a slow or stalled remote
+/// collector can never block the render loop.
+pub trait AsyncClient {
+    /// Queues `frame` for transmission without awaiting acknowledgement.
+    fn send_frame_async(&mut self, frame: ResonanceFrame);
+}
+
+/// A resonance streaming endpoint that supports both blocking and
+/// fire-and-forget transmission.
+pub trait Client: SyncClient + AsyncClient {
+    /// The remote endpoint this client streams frames to.
+    fn endpoint(&self) -> &str;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FlakyClient {
+        endpoint: String,
+        failures_left: u32,
+        sent: Vec<ResonanceFrame>,
+        queued: Vec<ResonanceFrame>,
+    }
+
+    impl FlakyClient {
+        fn new(endpoint: &str, failures_left: u32) -> Self {
+            Self {
+                endpoint: endpoint.to_string(),
+                failures_left,
+                sent: Vec::new(),
+                queued: Vec::new(),
+            }
+        }
+    }
+
+    impl SyncClient for FlakyClient {
+        fn try_send_frame(&mut self, frame: &ResonanceFrame) -> Result<(), ClientError> {
+            if self.failures_left > 0 {
+                self.failures_left -= 1;
+                return Err(ClientError::Transient("collector busy".to_string()));
+            }
+            self.sent.push(frame.clone());
+            Ok(())
+        }
+    }
+
+    impl AsyncClient for FlakyClient {
+        fn send_frame_async(&mut self, frame: ResonanceFrame) {
+            self.queued.push(frame);
+        }
+    }
+
+    impl Client for FlakyClient {
+        fn endpoint(&self) -> &str {
+            &self.endpoint
+        }
+    }
+
+    #[test]
+    fn test_send_frame_retries_transient_failures() {
+        let mut client = FlakyClient::new("collector:9000", 2);
+        let frame = ResonanceFrame {
+            sequence: 1,
+            wave_amplitudes: vec![0.1, 0.2],
+            zeronaut_coordinates: vec![[0.0, 0.0, 0.0, 0.0]],
+        };
+
+        assert!(client.send_frame(&frame).is_ok());
+        assert_eq!(client.sent.len(), 1);
+    }
+
+    #[test]
+    fn test_send_frame_gives_up_after_max_attempts() {
+        let mut client = FlakyClient::new("collector:9000", 5);
+        let frame = ResonanceFrame::default();
+
+        let err = client.send_frame_with_retries(&frame, 3).unwrap_err();
+        assert_eq!(err, ClientError::Transient("collector busy".to_string()));
+        assert!(client.sent.is_empty());
+    }
+
+    #[test]
+    fn test_send_frame_async_does_not_block_on_failure() {
+        let mut client = FlakyClient::new("collector:9000", 5);
+        let frame = ResonanceFrame {
+            sequence: 7,
+            wave_amplitudes: vec![1.0],
+            zeronaut_coordinates: Vec::new(),
+        };
+
+        client.send_frame_async(frame.clone());
+        assert_eq!(client.queued, vec![frame]);
+    }
+
+    #[test]
+    fn test_client_exposes_endpoint() {
+        let client = FlakyClient::new("collector:9000", 0);
+        assert_eq!(client.endpoint(), "collector:9000");
+    }
+}
@@ -2,18 +2,268 @@
 // Created by: isdood
 // Date: 2025-01-21 10:59:59 UTC
 
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
 use std::ffi::{c_void, CStr};
+use std::future::Future;
 use std::os::raw::{c_char, c_int, c_uint, c_ulonglong};
+use std::pin::Pin;
 use std::ptr::NonNull;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use std::task::{Context as PollContext, Poll, Waker};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
 
 use crate::types::{PrismError, PrismResult, TaskHandle, TaskStatus};
 
+/// A queued `PrismTask` pointer. The pointee is only ever touched by
+/// whichever worker pops it, so it's safe to hand across threads even
+/// though raw pointers aren't `Send` by default.
+struct ScheduledTask {
+    task: *mut PrismTask,
+}
+
+unsafe impl Send for ScheduledTask {}
+
+/// One worker's local run queue. Backed by a mutex rather than a true
+/// lock-free deque (this crate has no lock-free-deque dependency to
+/// reach for), but still gives each worker an independent queue: the
+/// owner pops/pushes from the back, a thief steals from the front, so
+/// the common case never contends with a steal in flight.
+struct WorkerQueue {
+    deque: Mutex<VecDeque<ScheduledTask>>,
+}
+
+impl WorkerQueue {
+    fn new() -> Self {
+        Self {
+            deque: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    fn push(&self, task: ScheduledTask) {
+        self.deque.lock().unwrap().push_back(task);
+    }
+
+    fn pop(&self) -> Option<ScheduledTask> {
+        self.deque.lock().unwrap().pop_back()
+    }
+
+    fn steal(&self) -> Option<ScheduledTask> {
+        self.deque.lock().unwrap().pop_front()
+    }
+}
+
+/// Per-task completion signal. `prism_task_wait` blocks on `condvar`
+/// until the worker that ran the task calls `signal`, instead of
+/// burning CPU in a spin loop.
+struct TaskCompletion {
+    done: Mutex<bool>,
+    condvar: Condvar,
+}
+
+impl TaskCompletion {
+    fn new() -> Self {
+        Self {
+            done: Mutex::new(false),
+            condvar: Condvar::new(),
+        }
+    }
+
+    fn signal(&self) {
+        *self.done.lock().unwrap() = true;
+        self.condvar.notify_all();
+    }
+
+    /// Blocks until signalled, or `timeout` elapses. `None` waits
+    /// forever. Returns whether the wait ended because of a signal
+    /// (`false` means it timed out).
+    fn wait(&self, timeout: Option<Duration>) -> bool {
+        let guard = self.done.lock().unwrap();
+        match timeout {
+            Some(timeout) => {
+                let (_guard, result) = self
+                    .condvar
+                    .wait_timeout_while(guard, timeout, |done| !*done)
+                    .unwrap();
+                !result.timed_out()
+            }
+            None => {
+                let _guard = self.condvar.wait_while(guard, |done| !*done).unwrap();
+                true
+            }
+        }
+    }
+}
+
+/// State shared between the scheduler and every worker thread it owns.
+struct SchedulerShared {
+    /// Entry point for newly submitted tasks. Workers only fall back to
+    /// this once their own local queue and every sibling's are empty.
+    injector: Mutex<VecDeque<ScheduledTask>>,
+    injector_cv: Condvar,
+    workers: Vec<WorkerQueue>,
+    shutdown: AtomicBool,
+    completions: Mutex<HashMap<u64, Arc<TaskCompletion>>>,
+}
+
+impl SchedulerShared {
+    fn enqueue(&self, task: ScheduledTask) {
+        self.injector.lock().unwrap().push_back(task);
+        self.injector_cv.notify_all();
+    }
+
+    fn steal_from_siblings(&self, index: usize) -> Option<ScheduledTask> {
+        let count = self.workers.len();
+        (1..count)
+            .map(|offset| (index + offset) % count)
+            .find_map(|victim| self.workers[victim].steal())
+    }
+
+    fn pop_injector(&self) -> Option<ScheduledTask> {
+        self.injector.lock().unwrap().pop_front()
+    }
+
+    /// Parks the calling worker on the injector's condvar until a task
+    /// is enqueued or the scheduler is torn down, whichever comes
+    /// first. Bounded so a worker periodically re-checks its own and
+    /// its siblings' queues even if it was never directly notified.
+    fn park_until_work(&self) {
+        let guard = self.injector.lock().unwrap();
+        if !guard.is_empty() || self.shutdown.load(Ordering::Acquire) {
+            return;
+        }
+        let _ = self
+            .injector_cv
+            .wait_timeout(guard, Duration::from_millis(20))
+            .unwrap();
+    }
+}
+
+fn run_task(shared: &SchedulerShared, scheduled: ScheduledTask) {
+    let task = unsafe { &mut *scheduled.task };
+    task.status = TaskStatus::Running as c_int;
+
+    if let Some(callback) = task.callback {
+        callback(scheduled.task);
+    }
+
+    task.status = TaskStatus::Completed as c_int;
+
+    if let Some(completion) = shared.completions.lock().unwrap().remove(&task.id) {
+        completion.signal();
+    }
+
+    if let Some(on_complete) = task.on_complete {
+        on_complete(scheduled.task, PrismResult::Success as c_int);
+    }
+}
+
+fn worker_loop(shared: Arc<SchedulerShared>, index: usize) {
+    loop {
+        if let Some(task) = shared.workers[index].pop() {
+            run_task(&shared, task);
+            continue;
+        }
+        if let Some(task) = shared.steal_from_siblings(index) {
+            run_task(&shared, task);
+            continue;
+        }
+        if let Some(task) = shared.pop_injector() {
+            run_task(&shared, task);
+            continue;
+        }
+        if shared.shutdown.load(Ordering::Acquire) {
+            return;
+        }
+        shared.park_until_work();
+    }
+}
+
+/// The mutable, non-ABI-stable half of `PrismRuntime`'s scheduling
+/// state. Lives behind a raw pointer so `PrismRuntime` itself keeps a
+/// fixed `#[repr(C)]` layout regardless of how this grows.
+struct Scheduler {
+    shared: Arc<SchedulerShared>,
+    handles: Mutex<Vec<JoinHandle<()>>>,
+}
+
+impl Scheduler {
+    fn new(thread_count: c_uint) -> Self {
+        let thread_count = thread_count.max(1) as usize;
+        let shared = Arc::new(SchedulerShared {
+            injector: Mutex::new(VecDeque::new()),
+            injector_cv: Condvar::new(),
+            workers: (0..thread_count).map(|_| WorkerQueue::new()).collect(),
+            shutdown: AtomicBool::new(false),
+            completions: Mutex::new(HashMap::new()),
+        });
+
+        let handles = (0..thread_count)
+            .map(|index| {
+                let shared = Arc::clone(&shared);
+                thread::spawn(move || worker_loop(shared, index))
+            })
+            .collect();
+
+        Self {
+            shared,
+            handles: Mutex::new(handles),
+        }
+    }
+
+    fn enqueue(&self, task: ScheduledTask) {
+        self.shared.enqueue(task);
+    }
+
+    /// Signals every worker to stop once its queues run dry, then joins
+    /// them all. Called once from `prism_runtime_destroy`.
+    fn shutdown(&self) {
+        self.shared.shutdown.store(true, Ordering::Release);
+        self.shared.injector_cv.notify_all();
+
+        for handle in self.handles.lock().unwrap().drain(..) {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// Current ABI version this build of `libprism` implements. A caller's
+/// `PrismConfig::requested_abi_version` must not exceed this, the same
+/// way `CrystalCapabilities::protocol_version` gates feature support in
+/// `crystal::bridge` -- a higher version is always a superset of a
+/// lower one's capabilities.
+pub const PRISM_ABI_VERSION: c_uint = 1;
+
+/// `PrismConfig::feature_flags` bit for coroutine tasks
+/// (`prism_task_create_coroutine` and friends).
+pub const PRISM_FEATURE_COROUTINES: c_ulonglong = 1 << 0;
+/// `PrismConfig::feature_flags` bit for SIMD-accelerated kernels.
+pub const PRISM_FEATURE_SIMD: c_ulonglong = 1 << 1;
+/// `PrismConfig::feature_flags` bit for `prism_task_submit` /
+/// `PrismRuntime::submit`'s async-submission path.
+pub const PRISM_FEATURE_ASYNC_SUBMIT: c_ulonglong = 1 << 2;
+
+/// Every feature flag this build recognizes. `prism_runtime_create`
+/// rejects a `feature_flags` value with any bit outside this set.
+const SUPPORTED_FEATURES: c_ulonglong =
+    PRISM_FEATURE_COROUTINES | PRISM_FEATURE_SIMD | PRISM_FEATURE_ASYNC_SUBMIT;
+
 #[repr(C)]
 pub struct PrismRuntime {
     initialized: AtomicBool,
     next_task_id: AtomicU64,
     thread_count: c_uint,
+    /// Stack size new coroutine tasks are allocated with, copied from
+    /// the `PrismConfig` this runtime was created from.
+    stack_size: c_ulonglong,
+    /// `feature_flags` this runtime was created with, already validated
+    /// against `SUPPORTED_FEATURES` -- see `prism_runtime_features`.
+    features: c_ulonglong,
+    /// Opaque pointer to the scheduler so this struct's ABI-visible
+    /// layout never changes as the scheduler's internals do.
+    scheduler: *mut Scheduler,
 }
 
 #[repr(C)]
@@ -22,6 +272,12 @@ pub struct PrismTask {
     status: c_int,
     data: *mut c_void,
     callback: Option<extern "C" fn(*mut PrismTask)>,
+    /// Invoked from the worker thread that finishes this task (see
+    /// `prism_task_set_completion_callback`), with the final
+    /// `PrismResult` -- lets a caller drive an event loop instead of
+    /// polling `prism_task_status`. `None` for tasks that never
+    /// register one.
+    on_complete: Option<extern "C" fn(*mut PrismTask, c_int)>,
 }
 
 #[repr(C)]
@@ -29,26 +285,86 @@ pub struct PrismConfig {
     thread_count: c_uint,
     stack_size: c_ulonglong,
     use_hardware_threads: bool,
+    /// ABI version this caller was built against. `prism_runtime_create`
+    /// rejects any value greater than `PRISM_ABI_VERSION`.
+    requested_abi_version: c_uint,
+    /// Bitmask of `PRISM_FEATURE_*` flags this caller requires.
+    /// `prism_runtime_create` rejects any bit outside `SUPPORTED_FEATURES`;
+    /// query what was actually negotiated with `prism_runtime_features`.
+    feature_flags: c_ulonglong,
+}
+
+/// Whether `config` is something this build of `libprism` can honor:
+/// its requested ABI version isn't newer than `PRISM_ABI_VERSION`, and
+/// every bit in its `feature_flags` is one `SUPPORTED_FEATURES` covers.
+fn abi_is_compatible(config: &PrismConfig) -> bool {
+    config.requested_abi_version <= PRISM_ABI_VERSION
+        && config.feature_flags & !SUPPORTED_FEATURES == 0
+}
+
+#[no_mangle]
+pub extern "C" fn prism_abi_version() -> c_uint {
+    PRISM_ABI_VERSION
 }
 
 #[no_mangle]
 pub extern "C" fn prism_runtime_create(config: *const PrismConfig) -> *mut PrismRuntime {
     let config = unsafe { &*config };
-    
+
+    if !abi_is_compatible(config) {
+        return std::ptr::null_mut();
+    }
+
+    let scheduler = Box::new(Scheduler::new(config.thread_count));
+
     let runtime = Box::new(PrismRuntime {
         initialized: AtomicBool::new(true),
         next_task_id: AtomicU64::new(0),
         thread_count: config.thread_count,
+        stack_size: config.stack_size,
+        features: config.feature_flags,
+        scheduler: Box::into_raw(scheduler),
     });
 
     Box::into_raw(runtime)
 }
 
+/// Returns the feature flags `runtime` negotiated at creation -- always
+/// a subset of `SUPPORTED_FEATURES`, since `prism_runtime_create` rejects
+/// any unrecognized bit up front rather than silently masking it out.
+#[no_mangle]
+pub extern "C" fn prism_runtime_features(runtime: *const PrismRuntime) -> c_ulonglong {
+    let runtime = unsafe { &*runtime };
+    runtime.features
+}
+
+/// Human-readable name for a single `PRISM_FEATURE_*` bit, for
+/// diagnostics -- analogous to `prism_error_message`. `flag` must be
+/// exactly one of the `PRISM_FEATURE_*` constants; anything else (zero,
+/// an unrecognized bit, or more than one bit set) yields `"unknown"`.
+#[no_mangle]
+pub extern "C" fn prism_feature_name(flag: c_ulonglong) -> *const c_char {
+    let name = match flag {
+        PRISM_FEATURE_COROUTINES => "coroutines",
+        PRISM_FEATURE_SIMD => "simd",
+        PRISM_FEATURE_ASYNC_SUBMIT => "async_submission",
+        _ => "unknown",
+    };
+
+    std::ffi::CString::new(name)
+        .map(|s| s.into_raw())
+        .unwrap_or(std::ptr::null())
+}
+
 #[no_mangle]
 pub extern "C" fn prism_runtime_destroy(runtime: *mut PrismRuntime) {
     if !runtime.is_null() {
         unsafe {
-            let _ = Box::from_raw(runtime);
+            let runtime = Box::from_raw(runtime);
+            if !runtime.scheduler.is_null() {
+                let scheduler = Box::from_raw(runtime.scheduler);
+                scheduler.shutdown();
+            }
         }
     }
 }
@@ -60,12 +376,13 @@ pub extern "C" fn prism_task_create(
     callback: Option<extern "C" fn(*mut PrismTask)>,
 ) -> *mut PrismTask {
     let runtime = unsafe { &*runtime };
-    
+
     let task = Box::new(PrismTask {
         id: runtime.next_task_id.fetch_add(1, Ordering::SeqCst),
         status: TaskStatus::Ready as c_int,
         data,
         callback,
+        on_complete: None,
     });
 
     Box::into_raw(task)
@@ -74,6 +391,12 @@ pub extern "C" fn prism_task_create(
 #[no_mangle]
 pub extern "C" fn prism_task_destroy(task: *mut PrismTask) {
     if !task.is_null() {
+        let task_id = unsafe { (*task).id };
+        // Drops the coroutine's stack allocation along with its entry,
+        // if this task was ever created via
+        // `prism_task_create_coroutine` -- a no-op otherwise, including
+        // for a coroutine destroyed before it ever finished.
+        COROUTINES.lock().unwrap().remove(&task_id);
         unsafe {
             let _ = Box::from_raw(task);
         }
@@ -86,22 +409,377 @@ pub extern "C" fn prism_task_execute(
     task: *mut PrismTask,
 ) -> c_int {
     let runtime = unsafe { &*runtime };
-    let task = unsafe { &mut *task };
 
     if !runtime.initialized.load(Ordering::SeqCst) {
         return PrismError::NotInitialized as c_int;
     }
 
-    task.status = TaskStatus::Running as c_int;
-    
-    if let Some(callback) = task.callback {
-        callback(task);
+    let scheduler = unsafe { &*runtime.scheduler };
+    let task_id = unsafe { (*task).id };
+
+    let completion = Arc::new(TaskCompletion::new());
+    scheduler
+        .shared
+        .completions
+        .lock()
+        .unwrap()
+        .insert(task_id, completion);
+
+    scheduler.enqueue(ScheduledTask { task });
+
+    PrismResult::Success as c_int
+}
+
+/// Enqueues `task` and returns immediately without registering anything
+/// for `prism_task_wait` to block on -- true fire-and-forget submission.
+/// Pair this with `prism_task_set_completion_callback` if the caller
+/// needs to know when the task finishes; otherwise the only way to
+/// observe completion is polling `prism_task_status`.
+#[no_mangle]
+pub extern "C" fn prism_task_submit(runtime: *mut PrismRuntime, task: *mut PrismTask) -> c_int {
+    let runtime = unsafe { &*runtime };
+
+    if !runtime.initialized.load(Ordering::SeqCst) {
+        return PrismError::NotInitialized as c_int;
+    }
+
+    let scheduler = unsafe { &*runtime.scheduler };
+    scheduler.enqueue(ScheduledTask { task });
+
+    PrismResult::Success as c_int
+}
+
+/// Registers `callback` to be invoked from the worker thread that
+/// finishes `task`, passed the task pointer and its final `PrismResult`
+/// code. Overwrites any callback registered previously; pass `None` to
+/// clear it.
+#[no_mangle]
+pub extern "C" fn prism_task_set_completion_callback(
+    task: *mut PrismTask,
+    callback: Option<extern "C" fn(*mut PrismTask, c_int)>,
+) {
+    unsafe {
+        (*task).on_complete = callback;
+    }
+}
+
+// --- Coroutine tasks: cooperative stack switching -------------------
+//
+// A coroutine task's callback runs on its own dedicated stack instead
+// of the worker pool. `prism_task_resume` switches the calling thread
+// onto that stack; the callback runs there until it either returns
+// (the task finishes) or calls `prism_task_yield`, which switches back
+// to whatever called `prism_task_resume`. No worker thread is ever
+// blocked by a suspended coroutine -- the calling thread just gets
+// control back.
+
+/// Callee-saved register state the System V AMD64 ABI requires a
+/// callee to preserve across a call, plus the stack pointer itself.
+/// Everything else is caller-saved and so doesn't need to survive a
+/// switch.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+struct Context {
+    rsp: u64,
+    rbp: u64,
+    rbx: u64,
+    r12: u64,
+    r13: u64,
+    r14: u64,
+    r15: u64,
+}
+
+/// Saves the running register state into `*from`, then restores `*to`
+/// and resumes there. This function's own (compiler-generated) epilogue
+/// is what actually transfers control: once the registers above are
+/// restored, `rsp` points at whatever return address is sitting on
+/// `to`'s stack -- either a prior suspension point, or the trampoline
+/// address `CoroutineStack::new` planted for a stack that's never been
+/// entered yet.
+#[cfg(target_arch = "x86_64")]
+#[inline(never)]
+unsafe fn switch_context(from: *mut Context, to: *const Context) {
+    std::arch::asm!(
+        "mov [{from} + 0], rsp",
+        "mov [{from} + 8], rbp",
+        "mov [{from} + 16], rbx",
+        "mov [{from} + 24], r12",
+        "mov [{from} + 32], r13",
+        "mov [{from} + 40], r14",
+        "mov [{from} + 48], r15",
+        "mov rsp, [{to} + 0]",
+        "mov rbp, [{to} + 8]",
+        "mov rbx, [{to} + 16]",
+        "mov r12, [{to} + 24]",
+        "mov r13, [{to} + 32]",
+        "mov r14, [{to} + 40]",
+        "mov r15, [{to} + 48]",
+        from = in(reg) from,
+        to = in(reg) to,
+    );
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+unsafe fn switch_context(_from: *mut Context, _to: *const Context) {
+    unimplemented!("coroutine stack switching is only implemented for x86_64");
+}
+
+/// Smallest stack this module will allocate for a coroutine,
+/// regardless of what a caller asks for via `PrismConfig::stack_size`.
+const MIN_COROUTINE_STACK_SIZE: usize = 16 * 1024;
+
+/// A coroutine's dedicated stack. Freeing this (by dropping the
+/// `Coroutine` that owns it) is the "stack cleanup" that happens when
+/// `prism_task_destroy` is called on a coroutine task, finished or not.
+struct CoroutineStack {
+    memory: Vec<u8>,
+}
+
+impl CoroutineStack {
+    /// Allocates a fresh stack and prepares a `Context` that, when
+    /// switched to for the first time, lands in `coroutine_trampoline`.
+    fn new(stack_size: u64) -> (Self, Context) {
+        let size = (stack_size as usize).max(MIN_COROUTINE_STACK_SIZE);
+        let mut memory = vec![0u8; size];
+
+        // The stack grows down from the top; align it and reserve one
+        // slot for the initial "return address" this context's first
+        // `switch_context` pops via its own epilogue.
+        let top = (memory.as_mut_ptr() as u64 + size as u64) & !0xf;
+        let entry_sp = top - 8;
+        unsafe {
+            (entry_sp as *mut u64).write(coroutine_trampoline as usize as u64);
+        }
+
+        let context = Context {
+            rsp: entry_sp,
+            ..Context::default()
+        };
+
+        (Self { memory }, context)
+    }
+}
+
+/// State for one coroutine task, registered in `COROUTINES` for the
+/// lifetime of its `PrismTask`.
+struct Coroutine {
+    task: *mut PrismTask,
+    callback: extern "C" fn(*mut PrismTask),
+    /// Kept alive for as long as the coroutine might still be resumed;
+    /// dropping this frees the stack.
+    _stack: CoroutineStack,
+    /// Where the coroutine resumes on the next `prism_task_resume`.
+    coroutine_context: Context,
+    /// Where `prism_task_resume` resumes once the coroutine yields or
+    /// finishes.
+    caller_context: Context,
+    finished: bool,
+}
+
+// `Coroutine` is only ever touched while its one raw-pointer-holding
+// registry entry is accessed under `COROUTINES`'s lock, or via the
+// thread-local stack the currently-running coroutine pushes itself
+// onto -- never concurrently from two threads.
+unsafe impl Send for Coroutine {}
+
+/// Every coroutine task ever created, by task id, independent of which
+/// `PrismRuntime` created it -- resuming and yielding a coroutine is a
+/// stack switch on the calling thread, not scheduler work, so it needs
+/// no runtime-scoped state.
+static COROUTINES: Mutex<Option<HashMap<u64, Box<Coroutine>>>> = Mutex::new(None);
+
+fn with_coroutines<R>(f: impl FnOnce(&mut HashMap<u64, Box<Coroutine>>) -> R) -> R {
+    let mut guard = COROUTINES.lock().unwrap();
+    f(guard.get_or_insert_with(HashMap::new))
+}
+
+thread_local! {
+    /// Stack of coroutines currently being resumed on this OS thread,
+    /// innermost (most recently resumed) last. `prism_task_yield` reads
+    /// the top entry to find which `Coroutine` it's suspending; nesting
+    /// only happens if a coroutine callback itself resumes another
+    /// coroutine.
+    static CURRENT_COROUTINE: RefCell<Vec<*mut Coroutine>> = RefCell::new(Vec::new());
+}
+
+/// Entry point for every fresh coroutine stack. Reached the first time
+/// its `Context` is switched to, via `switch_context`'s own epilogue
+/// rather than an ordinary call.
+extern "C" fn coroutine_trampoline() -> ! {
+    let coroutine_ptr = CURRENT_COROUTINE
+        .with(|stack| stack.borrow().last().copied())
+        .expect("coroutine_trampoline entered with no coroutine on CURRENT_COROUTINE");
+    let coroutine = unsafe { &mut *coroutine_ptr };
+
+    unsafe {
+        (*coroutine.task).status = TaskStatus::Running as c_int;
+    }
+
+    (coroutine.callback)(coroutine.task);
+
+    unsafe {
+        (*coroutine.task).status = TaskStatus::Completed as c_int;
+    }
+    coroutine.finished = true;
+
+    unsafe {
+        switch_context(&mut coroutine.coroutine_context, &coroutine.caller_context);
+    }
+
+    unreachable!("a finished coroutine's stack must never be resumed");
+}
+
+/// Creates a coroutine task: `callback` runs on a dedicated stack of
+/// `PrismConfig::stack_size` bytes (whatever `runtime` was created
+/// with) and may call `prism_task_yield` to suspend itself instead of
+/// running to completion in one `prism_task_resume` call.
+#[no_mangle]
+pub extern "C" fn prism_task_create_coroutine(
+    runtime: *mut PrismRuntime,
+    data: *mut c_void,
+    callback: Option<extern "C" fn(*mut PrismTask)>,
+) -> *mut PrismTask {
+    let runtime = unsafe { &*runtime };
+    let callback = match callback {
+        Some(callback) => callback,
+        None => return std::ptr::null_mut(),
+    };
+
+    let task = Box::into_raw(Box::new(PrismTask {
+        id: runtime.next_task_id.fetch_add(1, Ordering::SeqCst),
+        status: TaskStatus::Ready as c_int,
+        data,
+        callback: Some(callback),
+        on_complete: None,
+    }));
+
+    let (stack, coroutine_context) = CoroutineStack::new(runtime.stack_size);
+    let coroutine = Box::new(Coroutine {
+        task,
+        callback,
+        _stack: stack,
+        coroutine_context,
+        caller_context: Context::default(),
+        finished: false,
+    });
+
+    let task_id = unsafe { (*task).id };
+    with_coroutines(|coroutines| coroutines.insert(task_id, coroutine));
+
+    task
+}
+
+/// Suspends the currently-running coroutine, switching execution back
+/// to whichever `prism_task_resume` call is waiting for it. `task` must
+/// be the coroutine task whose callback is calling this. Returns
+/// whatever value the next `prism_task_resume` call for this task is
+/// given.
+#[no_mangle]
+pub extern "C" fn prism_task_yield(task: *mut PrismTask, resume_value: *mut c_void) -> *mut c_void {
+    let coroutine_ptr = CURRENT_COROUTINE
+        .with(|stack| stack.borrow().last().copied())
+        .expect("prism_task_yield called outside a running coroutine");
+    let coroutine = unsafe { &mut *coroutine_ptr };
+
+    unsafe {
+        (*task).data = resume_value;
+        (*task).status = TaskStatus::Suspended as c_int;
+        switch_context(&mut coroutine.coroutine_context, &coroutine.caller_context);
+
+        // Execution resumes here once `prism_task_resume` switches back
+        // in; `task.data` now holds the value that call was given.
+        (*task).status = TaskStatus::Running as c_int;
+        (*task).data
+    }
+}
+
+/// Resumes a coroutine task, switching onto its stack and handing it
+/// `value` (read back via `prism_task_yield`'s return, or as `task.data`
+/// for a callback that hasn't yielded yet). Returns once the coroutine
+/// yields or finishes.
+#[no_mangle]
+pub extern "C" fn prism_task_resume(
+    runtime: *mut PrismRuntime,
+    task: *mut PrismTask,
+    value: *mut c_void,
+) -> c_int {
+    let runtime = unsafe { &*runtime };
+    if !runtime.initialized.load(Ordering::SeqCst) {
+        return PrismError::NotInitialized as c_int;
+    }
+
+    let task_id = unsafe { (*task).id };
+    let coroutine_ptr: *mut Coroutine = match with_coroutines(|coroutines| {
+        coroutines.get_mut(&task_id).map(|boxed| boxed.as_mut() as *mut Coroutine)
+    }) {
+        Some(ptr) => ptr,
+        None => return PrismError::TaskNotFound as c_int,
+    };
+
+    let coroutine = unsafe { &mut *coroutine_ptr };
+    if coroutine.finished {
+        return PrismError::InvalidState as c_int;
+    }
+
+    unsafe {
+        (*task).data = value;
+        (*task).status = TaskStatus::Running as c_int;
+    }
+
+    CURRENT_COROUTINE.with(|stack| stack.borrow_mut().push(coroutine_ptr));
+    unsafe {
+        switch_context(&mut coroutine.caller_context, &coroutine.coroutine_context);
+    }
+    CURRENT_COROUTINE.with(|stack| {
+        stack.borrow_mut().pop();
+    });
+
+    if coroutine.finished {
+        with_coroutines(|coroutines| coroutines.remove(&task_id));
     }
 
-    task.status = TaskStatus::Completed as c_int;
     PrismResult::Success as c_int
 }
 
+/// Safe handle to a coroutine-backed `PrismTask`, returned by
+/// [`PrismRuntime::create_coroutine`]. Destroys the task (and frees its
+/// stack) when dropped, whether or not it ever finished.
+pub struct CoroutineHandle<'a> {
+    runtime: &'a PrismRuntime,
+    task: NonNull<PrismTask>,
+}
+
+impl<'a> CoroutineHandle<'a> {
+    /// Resumes the coroutine, handing it `value`.
+    pub fn resume(&self, value: *mut c_void) -> PrismResult<()> {
+        let runtime_ptr = self.runtime as *const PrismRuntime as *mut PrismRuntime;
+        match prism_task_resume(runtime_ptr, self.task.as_ptr(), value) {
+            x if x == PrismResult::Success as c_int => Ok(()),
+            x if x == PrismError::TaskNotFound as c_int => Err(PrismError::TaskNotFound),
+            x if x == PrismError::InvalidState as c_int => Err(PrismError::InvalidState),
+            _ => Err(PrismError::SystemError),
+        }
+    }
+
+    /// Whether the coroutine is currently parked inside a
+    /// `prism_task_yield` call, as opposed to finished or never
+    /// started.
+    pub fn is_suspended(&self) -> bool {
+        prism_task_status(self.task.as_ptr()) == TaskStatus::Suspended as c_int
+    }
+
+    /// Whether the coroutine's callback has returned.
+    pub fn is_finished(&self) -> bool {
+        prism_task_status(self.task.as_ptr()) == TaskStatus::Completed as c_int
+    }
+}
+
+impl<'a> Drop for CoroutineHandle<'a> {
+    fn drop(&mut self) {
+        prism_task_destroy(self.task.as_ptr());
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn prism_task_status(task: *const PrismTask) -> c_int {
     let task = unsafe { &*task };
@@ -115,23 +793,37 @@ pub extern "C" fn prism_task_wait(
     timeout_ms: c_ulonglong,
 ) -> c_int {
     let runtime = unsafe { &*runtime };
-    let task = unsafe { &*task };
 
     if !runtime.initialized.load(Ordering::SeqCst) {
         return PrismError::NotInitialized as c_int;
     }
 
-    let start = std::time::Instant::now();
-    let timeout = std::time::Duration::from_millis(timeout_ms as u64);
+    if unsafe { (*task).status } == TaskStatus::Completed as c_int {
+        return PrismResult::Success as c_int;
+    }
+
+    let scheduler = unsafe { &*runtime.scheduler };
+    let task_id = unsafe { (*task).id };
+    let completion = scheduler.shared.completions.lock().unwrap().get(&task_id).cloned();
 
-    while task.status != TaskStatus::Completed as c_int {
-        if timeout_ms > 0 && start.elapsed() > timeout {
-            return PrismError::Timeout as c_int;
+    match completion {
+        // Never submitted via `prism_task_execute`, so there's nothing
+        // to wait on.
+        None => PrismError::InvalidState as c_int,
+        Some(completion) => {
+            let timeout = if timeout_ms > 0 {
+                Some(Duration::from_millis(timeout_ms as u64))
+            } else {
+                None
+            };
+
+            if completion.wait(timeout) {
+                PrismResult::Success as c_int
+            } else {
+                PrismError::Timeout as c_int
+            }
         }
-        std::thread::yield_now();
     }
-
-    PrismResult::Success as c_int
 }
 
 #[no_mangle]
@@ -159,18 +851,122 @@ pub extern "C" fn prism_string_free(ptr: *mut c_char) {
     }
 }
 
+// --- Async submission: a oneshot future over the completion callback ---
+//
+// `PrismRuntime::submit` gives Rust callers `.await`-based orchestration
+// over the same fire-and-forget path `prism_task_submit` exposes to C.
+// An `extern "C" fn` can't capture per-call state, so the one completion
+// callback every `submit`-created task is registered with looks itself
+// up in `SUBMIT_WAKERS` by task id instead.
+
+/// Shared state behind a `SubmitFuture`: written once by
+/// `submit_completion_trampoline` when the task's worker finishes it,
+/// read once by the future's `poll`.
+struct SubmitState {
+    result: Option<c_int>,
+    waker: Option<Waker>,
+}
+
+/// In-flight `PrismRuntime::submit` calls, keyed by task id.
+static SUBMIT_WAKERS: Mutex<Option<HashMap<u64, Arc<Mutex<SubmitState>>>>> = Mutex::new(None);
+
+fn with_submit_wakers<R>(f: impl FnOnce(&mut HashMap<u64, Arc<Mutex<SubmitState>>>) -> R) -> R {
+    let mut guard = SUBMIT_WAKERS.lock().unwrap();
+    f(guard.get_or_insert_with(HashMap::new))
+}
+
+/// The `on_complete` callback registered for every task created by
+/// `PrismRuntime::submit`. Resolves the matching `SubmitFuture` instead
+/// of doing any work itself.
+extern "C" fn submit_completion_trampoline(task: *mut PrismTask, result: c_int) {
+    let task_id = unsafe { (*task).id };
+    if let Some(state) = with_submit_wakers(|wakers| wakers.remove(&task_id)) {
+        let mut state = state.lock().unwrap();
+        state.result = Some(result);
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+/// Future returned by [`PrismRuntime::submit`], resolved the moment
+/// `submit_completion_trampoline` fires for its task.
+struct SubmitFuture {
+    state: Arc<Mutex<SubmitState>>,
+}
+
+impl Future for SubmitFuture {
+    type Output = PrismResult<()>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut PollContext<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.lock().unwrap();
+        match state.result.take() {
+            Some(result) if result == PrismResult::Success as c_int => Poll::Ready(Ok(())),
+            Some(_) => Poll::Ready(Err(PrismError::SystemError)),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
 // Safe wrapper functions for internal use
 impl PrismRuntime {
     pub fn new(config: PrismConfig) -> PrismResult<NonNull<PrismRuntime>> {
+        if !abi_is_compatible(&config) {
+            return Err(PrismError::IncompatibleAbi);
+        }
         let runtime = prism_runtime_create(&config);
         NonNull::new(runtime).ok_or(PrismError::OutOfMemory)
     }
 
-    pub fn create_task(&self, data: *mut c_void, callback: Option<extern "C" fn(*mut PrismTask)>) 
+    pub fn create_task(&self, data: *mut c_void, callback: Option<extern "C" fn(*mut PrismTask)>)
         -> PrismResult<NonNull<PrismTask>> {
         let task = prism_task_create(self as *const _ as *mut _, data, callback);
         NonNull::new(task).ok_or(PrismError::OutOfMemory)
     }
+
+    /// Submits `callback` as a fire-and-forget task and returns a future
+    /// that resolves once it finishes, woken by its completion callback
+    /// rather than polling `prism_task_status`.
+    pub async fn submit(
+        &self,
+        data: *mut c_void,
+        callback: Option<extern "C" fn(*mut PrismTask)>,
+    ) -> PrismResult<()> {
+        let task = self.create_task(data, callback)?;
+        let task_id = unsafe { task.as_ref().id };
+
+        let state = Arc::new(Mutex::new(SubmitState { result: None, waker: None }));
+        with_submit_wakers(|wakers| wakers.insert(task_id, Arc::clone(&state)));
+        prism_task_set_completion_callback(task.as_ptr(), Some(submit_completion_trampoline));
+
+        let runtime_ptr = self as *const PrismRuntime as *mut PrismRuntime;
+        let submitted = prism_task_submit(runtime_ptr, task.as_ptr());
+        let result = if submitted == PrismResult::Success as c_int {
+            SubmitFuture { state }.await
+        } else {
+            with_submit_wakers(|wakers| wakers.remove(&task_id));
+            Err(PrismError::SystemError)
+        };
+
+        prism_task_destroy(task.as_ptr());
+        result
+    }
+
+    /// Creates a coroutine task whose callback can suspend itself via
+    /// `prism_task_yield` instead of running to completion in one call.
+    pub fn create_coroutine(
+        &self,
+        data: *mut c_void,
+        callback: Option<extern "C" fn(*mut PrismTask)>,
+    ) -> PrismResult<CoroutineHandle<'_>> {
+        let task = prism_task_create_coroutine(self as *const _ as *mut _, data, callback);
+        NonNull::new(task)
+            .map(|task| CoroutineHandle { runtime: self, task })
+            .ok_or(PrismError::OutOfMemory)
+    }
 }
 
 impl Drop for PrismRuntime {
@@ -190,6 +986,8 @@ mod tests {
             thread_count: 4,
             stack_size: 1024 * 1024,
             use_hardware_threads: true,
+            requested_abi_version: PRISM_ABI_VERSION,
+            feature_flags: 0,
         };
 
         let runtime = prism_runtime_create(&config);
@@ -210,10 +1008,12 @@ mod tests {
             thread_count: 1,
             stack_size: 1024 * 1024,
             use_hardware_threads: false,
+            requested_abi_version: PRISM_ABI_VERSION,
+            feature_flags: 0,
         };
 
         let runtime = prism_runtime_create(&config);
-        
+
         extern "C" fn test_callback(task: *mut PrismTask) {
             unsafe {
                 (*task).status = TaskStatus::Running as c_int;
@@ -226,6 +1026,9 @@ mod tests {
         let result = prism_task_execute(runtime, task);
         assert_eq!(result, PrismResult::Success as c_int);
 
+        let wait_result = prism_task_wait(runtime, task, 1000);
+        assert_eq!(wait_result, PrismResult::Success as c_int);
+
         let status = prism_task_status(task);
         assert_eq!(status, TaskStatus::Completed as c_int);
 
@@ -239,10 +1042,12 @@ mod tests {
             thread_count: 1,
             stack_size: 1024 * 1024,
             use_hardware_threads: false,
+            requested_abi_version: PRISM_ABI_VERSION,
+            feature_flags: 0,
         };
 
         let runtime = prism_runtime_create(&config);
-        
+
         extern "C" fn endless_callback(task: *mut PrismTask) {
             unsafe {
                 (*task).status = TaskStatus::Running as c_int;
@@ -253,10 +1058,293 @@ mod tests {
         }
 
         let task = prism_task_create(runtime, ptr::null_mut(), Some(endless_callback));
+        prism_task_execute(runtime, task);
         let result = prism_task_wait(runtime, task, 50);
         assert_eq!(result, PrismError::Timeout as c_int);
 
         prism_task_destroy(task);
         prism_runtime_destroy(runtime);
     }
+
+    #[test]
+    fn test_work_stealing_across_threads() {
+        let config = PrismConfig {
+            thread_count: 4,
+            stack_size: 1024 * 1024,
+            use_hardware_threads: true,
+            requested_abi_version: PRISM_ABI_VERSION,
+            feature_flags: 0,
+        };
+
+        let runtime = prism_runtime_create(&config);
+
+        extern "C" fn slow_callback(task: *mut PrismTask) {
+            std::thread::sleep(std::time::Duration::from_millis(20));
+            unsafe {
+                (*task).status = TaskStatus::Running as c_int;
+            }
+        }
+
+        let tasks: Vec<*mut PrismTask> = (0..16)
+            .map(|_| {
+                let task = prism_task_create(runtime, ptr::null_mut(), Some(slow_callback));
+                prism_task_execute(runtime, task);
+                task
+            })
+            .collect();
+
+        for task in &tasks {
+            assert_eq!(prism_task_wait(runtime, *task, 5000), PrismResult::Success as c_int);
+            assert_eq!(prism_task_status(*task), TaskStatus::Completed as c_int);
+        }
+
+        for task in tasks {
+            prism_task_destroy(task);
+        }
+        prism_runtime_destroy(runtime);
+    }
+
+    #[test]
+    fn test_coroutine_multi_yield_round_trip() {
+        let config = PrismConfig {
+            thread_count: 1,
+            stack_size: 64 * 1024,
+            use_hardware_threads: false,
+            requested_abi_version: PRISM_ABI_VERSION,
+            feature_flags: 0,
+        };
+        let runtime = prism_runtime_create(&config);
+
+        extern "C" fn counting_callback(task: *mut PrismTask) {
+            for step in 0..3i64 {
+                let received = prism_task_yield(task, (step * 10) as *mut c_void) as i64;
+                // The value handed back in by the matching
+                // `prism_task_resume` should be visible right where
+                // `prism_task_yield` returns.
+                assert_eq!(received, 100 + step);
+            }
+        }
+
+        let task = prism_task_create_coroutine(runtime, ptr::null_mut(), Some(counting_callback));
+        assert!(!task.is_null());
+
+        for step in 0..3i64 {
+            let result = prism_task_resume(runtime, task, (100 + step) as *mut c_void);
+            assert_eq!(result, PrismResult::Success as c_int);
+            assert_eq!(prism_task_status(task), TaskStatus::Suspended as c_int);
+            assert_eq!(unsafe { (*task).data } as i64, step * 10);
+        }
+
+        // One more resume lets the callback fall off the end.
+        let result = prism_task_resume(runtime, task, ptr::null_mut());
+        assert_eq!(result, PrismResult::Success as c_int);
+        assert_eq!(prism_task_status(task), TaskStatus::Completed as c_int);
+
+        prism_task_destroy(task);
+        prism_runtime_destroy(runtime);
+    }
+
+    #[test]
+    fn test_coroutine_destroy_before_finished_cleans_up_stack() {
+        let config = PrismConfig {
+            thread_count: 1,
+            stack_size: 64 * 1024,
+            use_hardware_threads: false,
+            requested_abi_version: PRISM_ABI_VERSION,
+            feature_flags: 0,
+        };
+        let runtime = prism_runtime_create(&config);
+
+        extern "C" fn forever_callback(task: *mut PrismTask) {
+            loop {
+                prism_task_yield(task, ptr::null_mut());
+            }
+        }
+
+        let task = prism_task_create_coroutine(runtime, ptr::null_mut(), Some(forever_callback));
+        prism_task_resume(runtime, task, ptr::null_mut());
+        assert_eq!(prism_task_status(task), TaskStatus::Suspended as c_int);
+
+        let task_id = unsafe { (*task).id };
+        assert!(with_coroutines(|coroutines| coroutines.contains_key(&task_id)));
+
+        // Destroying a never-finished coroutine should drop its
+        // registry entry (and with it, its stack allocation) rather
+        // than leaking it.
+        prism_task_destroy(task);
+        assert!(!with_coroutines(|coroutines| coroutines.contains_key(&task_id)));
+
+        prism_runtime_destroy(runtime);
+    }
+
+    #[test]
+    fn test_coroutine_handle_wrapper() {
+        let config = PrismConfig {
+            thread_count: 1,
+            stack_size: 64 * 1024,
+            use_hardware_threads: false,
+            requested_abi_version: PRISM_ABI_VERSION,
+            feature_flags: 0,
+        };
+        let runtime = unsafe { &*prism_runtime_create(&config) };
+
+        extern "C" fn yield_once(task: *mut PrismTask) {
+            prism_task_yield(task, 7 as *mut c_void);
+        }
+
+        let handle = runtime.create_coroutine(ptr::null_mut(), Some(yield_once)).unwrap();
+        assert!(!handle.is_suspended());
+
+        handle.resume(ptr::null_mut()).unwrap();
+        assert!(handle.is_suspended());
+        assert!(!handle.is_finished());
+
+        handle.resume(ptr::null_mut()).unwrap();
+        assert!(handle.is_finished());
+    }
+
+    #[test]
+    fn test_submit_invokes_completion_callback() {
+        use std::sync::atomic::AtomicI32;
+
+        let config = PrismConfig {
+            thread_count: 1,
+            stack_size: 1024 * 1024,
+            use_hardware_threads: false,
+            requested_abi_version: PRISM_ABI_VERSION,
+            feature_flags: 0,
+        };
+        let runtime = prism_runtime_create(&config);
+
+        extern "C" fn noop_callback(_task: *mut PrismTask) {}
+
+        static LAST_RESULT: AtomicI32 = AtomicI32::new(-1);
+        extern "C" fn on_complete(_task: *mut PrismTask, result: c_int) {
+            LAST_RESULT.store(result, Ordering::SeqCst);
+        }
+
+        let task = prism_task_create(runtime, ptr::null_mut(), Some(noop_callback));
+        prism_task_set_completion_callback(task, Some(on_complete));
+
+        let result = prism_task_submit(runtime, task);
+        assert_eq!(result, PrismResult::Success as c_int);
+
+        // No completion was registered for this task, so `prism_task_wait`
+        // has nothing to block on; poll status instead.
+        while prism_task_status(task) != TaskStatus::Completed as c_int {
+            thread::yield_now();
+        }
+        assert_eq!(LAST_RESULT.load(Ordering::SeqCst), PrismResult::Success as c_int);
+
+        prism_task_destroy(task);
+        prism_runtime_destroy(runtime);
+    }
+
+    #[test]
+    fn test_runtime_submit_future_resolves() {
+        let config = PrismConfig {
+            thread_count: 1,
+            stack_size: 1024 * 1024,
+            use_hardware_threads: false,
+            requested_abi_version: PRISM_ABI_VERSION,
+            feature_flags: 0,
+        };
+        let runtime = unsafe { &*prism_runtime_create(&config) };
+
+        extern "C" fn noop_callback(_task: *mut PrismTask) {}
+
+        let result = futures::executor::block_on(runtime.submit(ptr::null_mut(), Some(noop_callback)));
+        assert!(result.is_ok());
+
+        prism_runtime_destroy(runtime as *const _ as *mut _);
+    }
+
+    #[test]
+    fn test_runtime_create_rejects_newer_abi_version() {
+        let config = PrismConfig {
+            thread_count: 1,
+            stack_size: 1024 * 1024,
+            use_hardware_threads: false,
+            requested_abi_version: PRISM_ABI_VERSION + 1,
+            feature_flags: 0,
+        };
+
+        let runtime = prism_runtime_create(&config);
+        assert!(runtime.is_null());
+    }
+
+    #[test]
+    fn test_runtime_create_accepts_older_abi_version() {
+        let config = PrismConfig {
+            thread_count: 1,
+            stack_size: 1024 * 1024,
+            use_hardware_threads: false,
+            requested_abi_version: 0,
+            feature_flags: 0,
+        };
+
+        let runtime = prism_runtime_create(&config);
+        assert!(!runtime.is_null());
+        prism_runtime_destroy(runtime);
+    }
+
+    #[test]
+    fn test_runtime_create_rejects_unknown_feature_bit() {
+        const UNKNOWN_FEATURE: c_ulonglong = 1 << 63;
+
+        let config = PrismConfig {
+            thread_count: 1,
+            stack_size: 1024 * 1024,
+            use_hardware_threads: false,
+            requested_abi_version: PRISM_ABI_VERSION,
+            feature_flags: UNKNOWN_FEATURE,
+        };
+
+        let runtime = prism_runtime_create(&config);
+        assert!(runtime.is_null());
+    }
+
+    #[test]
+    fn test_runtime_features_negotiates_requested_subset() {
+        let config = PrismConfig {
+            thread_count: 1,
+            stack_size: 1024 * 1024,
+            use_hardware_threads: false,
+            requested_abi_version: PRISM_ABI_VERSION,
+            feature_flags: PRISM_FEATURE_COROUTINES | PRISM_FEATURE_ASYNC_SUBMIT,
+        };
+
+        let runtime = prism_runtime_create(&config);
+        assert!(!runtime.is_null());
+        assert_eq!(
+            prism_runtime_features(runtime),
+            PRISM_FEATURE_COROUTINES | PRISM_FEATURE_ASYNC_SUBMIT
+        );
+
+        prism_runtime_destroy(runtime);
+    }
+
+    #[test]
+    fn test_prism_runtime_new_reports_incompatible_abi() {
+        let config = PrismConfig {
+            thread_count: 1,
+            stack_size: 1024 * 1024,
+            use_hardware_threads: false,
+            requested_abi_version: PRISM_ABI_VERSION + 1,
+            feature_flags: 0,
+        };
+
+        let result = PrismRuntime::new(config);
+        assert_eq!(result.unwrap_err(), PrismError::IncompatibleAbi);
+    }
+
+    #[test]
+    fn test_feature_name_round_trip() {
+        let name = |flag| unsafe { CStr::from_ptr(prism_feature_name(flag)).to_str().unwrap().to_owned() };
+
+        assert_eq!(name(PRISM_FEATURE_COROUTINES), "coroutines");
+        assert_eq!(name(PRISM_FEATURE_SIMD), "simd");
+        assert_eq!(name(PRISM_FEATURE_ASYNC_SUBMIT), "async_submission");
+        assert_eq!(name(0), "unknown");
+    }
 }
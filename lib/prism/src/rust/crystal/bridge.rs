@@ -7,11 +7,11 @@ use std::os::raw::{c_char, c_int, c_uint, c_ulonglong};
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 
-use crate::types::{PrismError, PrismResult};
+use crate::types::{CachePadded, PrismError, PrismResult};
 
 /// Crystal system types matching Zig implementation
 #[repr(C)]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CrystalSystem {
     Cubic,
     Tetragonal,
@@ -22,6 +22,60 @@ pub enum CrystalSystem {
     Rhombohedral,
 }
 
+/// Capability/version descriptor a [`Crystal`] advertises, so a caller
+/// like `TaskExecutor::submit` can check a task's crystal-alignment
+/// requirements against it before enqueuing -- analogous to a network
+/// peer's version handshake -- instead of only discovering an
+/// incompatibility once `crystal.optimize()` runs partway through
+/// execution.
+///
+/// `protocol_version` is monotone: a higher version always advertises a
+/// superset of a lower version's capabilities, the same way a higher
+/// `p2p_version` implies support for every message type an older
+/// version understood. [`CrystalCapabilities::satisfies`] relies on
+/// this -- it never needs to special-case an older version supporting
+/// a newer feature by accident.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CrystalCapabilities {
+    pub crystal_system: CrystalSystem,
+    pub min_coherence: f64,
+    pub supports_phase_alignment: bool,
+    pub harmony_depth: u32,
+    pub protocol_version: u16,
+}
+
+impl CrystalCapabilities {
+    /// `protocol_version` at which phase-alignment support was added.
+    /// A crystal advertising a lower version doesn't honor
+    /// `supports_phase_alignment`, however its field happens to be set.
+    const PHASE_ALIGNMENT_VERSION: u16 = 1;
+
+    /// Whether this capability set (typically `Crystal::capabilities`)
+    /// satisfies `required` (typically a task's declared requirement):
+    /// the crystal system must match exactly, `min_coherence` and
+    /// `harmony_depth` must meet or exceed what's required, and phase
+    /// alignment -- if required -- must actually have arrived by
+    /// `self.protocol_version`.
+    pub fn satisfies(&self, required: &CrystalCapabilities) -> bool {
+        if self.crystal_system != required.crystal_system {
+            return false;
+        }
+        if self.min_coherence < required.min_coherence {
+            return false;
+        }
+        if self.harmony_depth < required.harmony_depth {
+            return false;
+        }
+        if required.supports_phase_alignment
+            && !(self.supports_phase_alignment
+                && self.protocol_version >= Self::PHASE_ALIGNMENT_VERSION)
+        {
+            return false;
+        }
+        true
+    }
+}
+
 /// Bridge to Zig crystal lattice
 #[repr(C)]
 pub struct CrystalBridge {
@@ -179,29 +233,71 @@ impl Drop for CrystalBridge {
 pub struct Crystal {
     bridge: Arc<CrystalBridge>,
     nodes: Arc<Mutex<Vec<Arc<CrystalNode>>>>,
+    /// Counts completed [`Crystal::optimize`] passes. Bumped from every
+    /// worker thread running an aligned task (see `TaskExecutor`), so
+    /// it's cache-padded to keep that bump from invalidating whatever
+    /// else happens to sit next to it in memory.
+    coherence: CachePadded<AtomicU64>,
+    /// Counts nodes ever anchored into the lattice via
+    /// [`Crystal::add_node`]. Unlike `CrystalBridge::node_count`, this
+    /// never decrements on removal -- it's a lifetime anchor count, not
+    /// a live count -- and is just as hot a write path under concurrent
+    /// task execution, so it gets the same cache-line padding.
+    reality_anchor: CachePadded<AtomicU64>,
+    /// Capability/version descriptor this bridge advertises, fixed at
+    /// construction from the same config handed to the Zig lattice.
+    capabilities: CrystalCapabilities,
 }
 
 impl Crystal {
+    /// Depth of harmony state this bridge's lattice supports negotiating
+    /// over. `CrystalBridge` itself doesn't model harmony states (that's
+    /// `prismancer`'s concern), but a task can still declare a required
+    /// depth for `TaskExecutor::submit` to check against this constant.
+    const HARMONY_DEPTH: u32 = 16;
+
+    /// Current capability/version handshake protocol this bridge
+    /// speaks. Bump this -- and keep every earlier capability true --
+    /// whenever a new negotiable feature is added.
+    const PROTOCOL_VERSION: u16 = 1;
+
     /// Create a new crystal instance
     pub fn new(system: CrystalSystem) -> PrismResult<Self> {
+        let stability_threshold = 0.8;
         let config = CrystalConfig {
             system,
             initial_capacity: 1024,
-            stability_threshold: 0.8,
+            stability_threshold,
         };
 
         let bridge = Arc::new(CrystalBridge::new(config)?);
-        
+
         Ok(Self {
             bridge,
             nodes: Arc::new(Mutex::new(Vec::new())),
+            coherence: CachePadded::new(AtomicU64::new(0)),
+            reality_anchor: CachePadded::new(AtomicU64::new(0)),
+            capabilities: CrystalCapabilities {
+                crystal_system: system,
+                min_coherence: stability_threshold,
+                supports_phase_alignment: true,
+                harmony_depth: Self::HARMONY_DEPTH,
+                protocol_version: Self::PROTOCOL_VERSION,
+            },
         })
     }
 
+    /// Capability/version descriptor for this crystal, for a caller to
+    /// check a task's requirements against before enqueuing it.
+    pub fn capabilities(&self) -> CrystalCapabilities {
+        self.capabilities
+    }
+
     /// Add a node at the specified position
     pub fn add_node(&self, position: [f64; 3]) -> PrismResult<Arc<CrystalNode>> {
         let node = self.bridge.add_node(position)?;
         self.nodes.lock().unwrap().push(Arc::clone(&node));
+        self.reality_anchor.fetch_add(1, Ordering::Relaxed);
         Ok(node)
     }
 
@@ -225,7 +321,20 @@ impl Crystal {
 
     /// Optimize crystal structure
     pub fn optimize(&self) -> PrismResult<()> {
-        self.bridge.optimize()
+        self.bridge.optimize()?;
+        self.coherence.fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Number of completed `optimize` passes.
+    pub fn coherence(&self) -> u64 {
+        self.coherence.load(Ordering::Relaxed)
+    }
+
+    /// Number of nodes ever anchored into the lattice, including ones
+    /// since removed.
+    pub fn reality_anchor(&self) -> u64 {
+        self.reality_anchor.load(Ordering::Relaxed)
     }
 }
 
@@ -289,4 +398,63 @@ mod tests {
 
         assert_eq!(crystal.bridge.node_count(), 10);
     }
+
+    #[test]
+    fn test_coherence_and_reality_anchor_counters() {
+        let crystal = Crystal::new(CrystalSystem::Cubic).unwrap();
+
+        let node = crystal.add_node([0.0, 0.0, 0.0]).unwrap();
+        assert_eq!(crystal.reality_anchor(), 1);
+
+        crystal.optimize().unwrap();
+        crystal.optimize().unwrap();
+        assert_eq!(crystal.coherence(), 2);
+
+        // Removing a node doesn't un-anchor it from the lattice's history.
+        crystal.remove_node(node).unwrap();
+        assert_eq!(crystal.reality_anchor(), 1);
+    }
+
+    #[test]
+    fn test_capabilities_reject_mismatched_system() {
+        let crystal = Crystal::new(CrystalSystem::Cubic).unwrap();
+        let mut required = crystal.capabilities();
+        required.crystal_system = CrystalSystem::Hexagonal;
+
+        assert!(!crystal.capabilities().satisfies(&required));
+    }
+
+    #[test]
+    fn test_capabilities_reject_unmet_coherence_and_depth() {
+        let crystal = Crystal::new(CrystalSystem::Cubic).unwrap();
+        let available = crystal.capabilities();
+
+        let mut required = available;
+        required.min_coherence = available.min_coherence + 0.1;
+        assert!(!available.satisfies(&required));
+
+        let mut required = available;
+        required.harmony_depth = available.harmony_depth + 1;
+        assert!(!available.satisfies(&required));
+    }
+
+    #[test]
+    fn test_capabilities_reject_phase_alignment_below_version() {
+        let crystal = Crystal::new(CrystalSystem::Cubic).unwrap();
+        let mut available = crystal.capabilities();
+        available.protocol_version = 0;
+        available.supports_phase_alignment = false;
+
+        let mut required = available;
+        required.supports_phase_alignment = true;
+
+        assert!(!available.satisfies(&required));
+    }
+
+    #[test]
+    fn test_capabilities_satisfy_identical_descriptor() {
+        let crystal = Crystal::new(CrystalSystem::Cubic).unwrap();
+        let capabilities = crystal.capabilities();
+        assert!(capabilities.satisfies(&capabilities));
+    }
 }
@@ -29,7 +29,7 @@ use std::future::Future;
 
 // Internal re-exports
 pub use self::crystal::bridge::{Crystal, CrystalNode, CrystalSystem};
-pub use self::runtime::task::{Task, TaskConfig, TaskExecutor};
+pub use self::runtime::task::{Signal, Task, TaskConfig, TaskExecutor};
 pub use self::types::{PrismError, PrismResult, Priority, TaskStatus};
 
 /// Result type for quantum-harmonic operations
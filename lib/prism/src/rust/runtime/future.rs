@@ -4,6 +4,7 @@
 
 use std::future::Future;
 use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 use std::task::{Context, Poll, Waker};
 use std::time::{Duration, Instant};
@@ -13,18 +14,34 @@ use crate::types::{PrismError, PrismResult, TaskHandle, TaskStatus};
 /// Future state container
 pub(crate) struct FutureState<T> {
     result: Option<PrismResult<T>>,
-    waker: Option<Waker>,
+    /// Wakers of every task currently parked on this future. A single
+    /// `PrismFuture` handle can be cloned and awaited from several
+    /// places at once (it's just an `Arc<Mutex<FutureState<T>>>>`), so
+    /// `set_result` must wake all of them, not just the most recent
+    /// poller.
+    wakers: Vec<Waker>,
     status: TaskStatus,
     start_time: Option<Instant>,
     timeout: Option<Duration>,
 }
 
-/// Async future for Prism tasks
+/// Async future for Prism tasks. Cheap to clone (it's a `TaskHandle`
+/// plus an `Arc`), so the same handle can be awaited from multiple
+/// tasks; each poller gets its own clone of the eventual result.
 pub struct PrismFuture<T> {
     handle: TaskHandle,
     state: Arc<Mutex<FutureState<T>>>,
 }
 
+impl<T> Clone for PrismFuture<T> {
+    fn clone(&self) -> Self {
+        Self {
+            handle: self.handle,
+            state: self.state.clone(),
+        }
+    }
+}
+
 impl<T> PrismFuture<T> {
     /// Create a new future
     pub(crate) fn new(handle: TaskHandle, timeout: Option<Duration>) -> Self {
@@ -32,7 +49,7 @@ impl<T> PrismFuture<T> {
             handle,
             state: Arc::new(Mutex::new(FutureState {
                 result: None,
-                waker: None,
+                wakers: Vec::new(),
                 status: TaskStatus::Ready,
                 start_time: None,
                 timeout,
@@ -40,7 +57,7 @@ impl<T> PrismFuture<T> {
         }
     }
 
-    /// Set the result and wake the future
+    /// Set the result and wake every task currently awaiting this future.
     pub(crate) fn set_result(&self, result: PrismResult<T>) {
         let mut state = self.state.lock().unwrap();
         state.result = Some(result);
@@ -49,7 +66,7 @@ impl<T> PrismFuture<T> {
             Some(Err(_)) => TaskStatus::Failed,
             None => TaskStatus::Running,
         };
-        if let Some(waker) = state.waker.take() {
+        for waker in state.wakers.drain(..) {
             waker.wake();
         }
     }
@@ -74,9 +91,18 @@ impl<T> PrismFuture<T> {
         }
         Ok(())
     }
+
+    /// Mark this future as a shared broadcast handle: the returned
+    /// value is just `self`, since `PrismFuture` is already an `Arc`
+    /// around its state, but the explicit conversion documents the
+    /// intent at the call site that the handle will be cloned and
+    /// awaited from multiple tasks rather than consumed by one.
+    pub fn shared(self) -> Self {
+        self
+    }
 }
 
-impl<T> Future for PrismFuture<T> {
+impl<T: Clone> Future for PrismFuture<T> {
     type Output = PrismResult<T>;
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
@@ -92,10 +118,10 @@ impl<T> Future for PrismFuture<T> {
             return Poll::Ready(Err(e));
         }
 
-        match state.result.take() {
+        match state.result.clone() {
             Some(result) => Poll::Ready(result),
             None => {
-                state.waker = Some(cx.waker().clone());
+                state.wakers.push(cx.waker().clone());
                 Poll::Pending
             }
         }
@@ -106,12 +132,19 @@ impl<T> Future for PrismFuture<T> {
 pub trait FutureExt: Future + Sized {
     /// Add timeout to the future
     fn timeout(self, duration: Duration) -> TimeoutFuture<Self>;
-    
+
     /// Chain multiple futures
     fn chain<F, U>(self, f: F) -> ChainFuture<Self, F>
     where
         F: FnOnce(Self::Output) -> U,
         U: Future;
+
+    /// Wrap the future so it can be cancelled cooperatively from outside
+    /// its executor. Returns the wrapped future alongside an `AbortHandle`
+    /// whose `abort()` causes the next poll to resolve with
+    /// `PrismError::Aborted` (waking the task immediately if it was
+    /// already parked).
+    fn abortable(self) -> (Abortable<Self>, AbortHandle);
 }
 
 impl<F: Future> FutureExt for F {
@@ -134,6 +167,110 @@ impl<F: Future> FutureExt for F {
             state: ChainState::First,
         }
     }
+
+    fn abortable(self) -> (Abortable<Self>, AbortHandle) {
+        let (handle, registration) = AbortHandle::new_pair();
+        (Abortable::new(self, registration), handle)
+    }
+}
+
+/// Shared state between an `AbortHandle` and its `Abortable` future: the
+/// aborted flag, plus the waker of whichever task last parked on the
+/// future so `abort()` can wake it immediately instead of waiting for
+/// the next poll.
+struct AbortInner {
+    aborted: AtomicBool,
+    waker: Mutex<Option<Waker>>,
+}
+
+/// Handle to remotely cancel an `Abortable` future. Cloning an
+/// `AbortHandle` is not supported directly; share it behind an `Arc` (or
+/// hand out clones of the handle itself, which is cheap since it's just
+/// an `Arc` around the shared flag) if multiple owners need to abort the
+/// same task.
+#[derive(Clone)]
+pub struct AbortHandle {
+    inner: Arc<AbortInner>,
+}
+
+/// Token that threads an `AbortHandle`'s cancellation through to the
+/// `Abortable` future it was paired with at construction.
+pub struct AbortRegistration {
+    inner: Arc<AbortInner>,
+}
+
+impl AbortHandle {
+    /// Create a fresh `AbortHandle`/`AbortRegistration` pair sharing the
+    /// same aborted flag and waker slot.
+    pub fn new_pair() -> (Self, AbortRegistration) {
+        let inner = Arc::new(AbortInner {
+            aborted: AtomicBool::new(false),
+            waker: Mutex::new(None),
+        });
+        (
+            AbortHandle { inner: inner.clone() },
+            AbortRegistration { inner },
+        )
+    }
+
+    /// Flip the aborted flag and wake the task if it's currently parked,
+    /// so the next poll observes the abort instead of waiting for
+    /// whatever the inner future was waiting on.
+    pub fn abort(&self) {
+        self.inner.aborted.store(true, Ordering::SeqCst);
+        if let Some(waker) = self.inner.waker.lock().unwrap().take() {
+            waker.wake();
+        }
+    }
+
+    /// Whether `abort()` has already been called.
+    pub fn is_aborted(&self) -> bool {
+        self.inner.aborted.load(Ordering::SeqCst)
+    }
+}
+
+/// A future that can be cancelled from outside via its paired
+/// `AbortHandle`. Every poll checks the shared aborted flag first; once
+/// set, the future resolves to `Err(PrismError::Aborted)` without
+/// touching the inner future again.
+pub struct Abortable<F> {
+    future: F,
+    registration: AbortRegistration,
+}
+
+impl<F> Abortable<F> {
+    /// Wrap `future` so it honors aborts signalled through
+    /// `registration`. Prefer `FutureExt::abortable`, which also
+    /// produces the matching `AbortHandle`.
+    pub fn new(future: F, registration: AbortRegistration) -> Self {
+        Self { future, registration }
+    }
+}
+
+impl<F: Future> Future for Abortable<F> {
+    type Output = PrismResult<F::Output>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.registration.inner.aborted.load(Ordering::SeqCst) {
+            return Poll::Ready(Err(PrismError::Aborted));
+        }
+
+        // Safe to use pin projection as fields implement Unpin
+        let future = unsafe { self.as_mut().map_unchecked_mut(|s| &mut s.future) };
+        match future.poll(cx) {
+            Poll::Ready(output) => Poll::Ready(Ok(output)),
+            Poll::Pending => {
+                *self.registration.inner.waker.lock().unwrap() = Some(cx.waker().clone());
+
+                // Re-check in case `abort()` landed between the flag
+                // check above and the waker being stored.
+                if self.registration.inner.aborted.load(Ordering::SeqCst) {
+                    return Poll::Ready(Err(PrismError::Aborted));
+                }
+                Poll::Pending
+            }
+        }
+    }
 }
 
 /// Future with timeout
@@ -215,6 +352,147 @@ where
     }
 }
 
+/// Drives a batch of `PrismFuture`s concurrently, resolving once every
+/// one of them is `Ready`.
+///
+/// Each slot's future is taken out and polled once it's still pending;
+/// completed slots are filled with their result and skipped on later
+/// polls. `PrismFuture` is `Unpin` (it holds no borrowed or pinned
+/// state), so slots can be polled directly without pin projection.
+pub struct JoinAll<T> {
+    futures: Vec<Option<PrismFuture<T>>>,
+    results: Vec<Option<PrismResult<T>>>,
+}
+
+/// Concurrently await every future in `futures`, resolving to their
+/// results in the same order once all have completed.
+pub fn join_all<T>(futures: Vec<PrismFuture<T>>) -> JoinAll<T> {
+    let len = futures.len();
+    JoinAll {
+        futures: futures.into_iter().map(Some).collect(),
+        results: (0..len).map(|_| None).collect(),
+    }
+}
+
+impl<T: Clone> Future for JoinAll<T> {
+    type Output = Vec<PrismResult<T>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let mut all_ready = true;
+
+        for (slot, result) in this.futures.iter_mut().zip(this.results.iter_mut()) {
+            if result.is_some() {
+                continue;
+            }
+            match slot.as_mut() {
+                Some(future) => match Pin::new(future).poll(cx) {
+                    Poll::Ready(output) => {
+                        *result = Some(output);
+                        *slot = None;
+                    }
+                    Poll::Pending => all_ready = false,
+                },
+                None => unreachable!("a slot without a result must still hold its future"),
+            }
+        }
+
+        if all_ready {
+            Poll::Ready(this.results.iter_mut().map(|r| r.take().unwrap()).collect())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+/// Like [`JoinAll`], but short-circuits to the first `Err` any input
+/// future resolves to instead of collecting every result.
+pub struct TryJoinAll<T> {
+    inner: JoinAll<T>,
+}
+
+/// Concurrently await every future in `futures`, resolving to the
+/// collected `Ok` values in order, or the first `Err` observed.
+pub fn try_join_all<T>(futures: Vec<PrismFuture<T>>) -> TryJoinAll<T> {
+    TryJoinAll { inner: join_all(futures) }
+}
+
+impl<T: Clone> Future for TryJoinAll<T> {
+    type Output = PrismResult<Vec<T>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let mut all_ready = true;
+
+        for (slot, result) in this.inner.futures.iter_mut().zip(this.inner.results.iter_mut()) {
+            if result.is_some() {
+                continue;
+            }
+            match slot.as_mut() {
+                Some(future) => match Pin::new(future).poll(cx) {
+                    Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                    Poll::Ready(output) => {
+                        *result = Some(output);
+                        *slot = None;
+                    }
+                    Poll::Pending => all_ready = false,
+                },
+                None => unreachable!("a slot without a result must still hold its future"),
+            }
+        }
+
+        if all_ready {
+            Poll::Ready(Ok(this
+                .inner
+                .results
+                .iter_mut()
+                .map(|r| r.take().unwrap().unwrap())
+                .collect()))
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+/// Races a set of `PrismFuture`s and resolves as soon as any one of them
+/// becomes `Ready`, identifying the winner by its original index.
+///
+/// Futures that haven't finished by the time a winner is found are left
+/// in place (as `Some`) so the caller can re-drive or abort them; the
+/// winning slot is taken out so a finished `Select` can't be polled
+/// again into the same result.
+pub struct Select<T> {
+    futures: Vec<Option<PrismFuture<T>>>,
+}
+
+/// Poll `futures` concurrently, resolving to `(index, result)` for
+/// whichever one completes first. The remaining entries are left for
+/// the caller to inspect or cancel.
+pub fn select<T>(futures: Vec<PrismFuture<T>>) -> Select<T> {
+    Select {
+        futures: futures.into_iter().map(Some).collect(),
+    }
+}
+
+impl<T: Clone> Future for Select<T> {
+    type Output = (usize, PrismResult<T>);
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        for (i, slot) in this.futures.iter_mut().enumerate() {
+            if let Some(future) = slot {
+                if let Poll::Ready(result) = Pin::new(future).poll(cx) {
+                    *slot = None;
+                    return Poll::Ready((i, result));
+                }
+            }
+        }
+
+        Poll::Pending
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -274,4 +552,155 @@ mod tests {
         let result = futures::executor::block_on(chained);
         assert_eq!(result.unwrap(), 42);
     }
+
+    #[test]
+    fn test_abort_before_poll() {
+        let handle = TaskHandle::new();
+        let future = PrismFuture::<i32>::new(handle, None);
+        let (abortable, abort_handle) = future.abortable();
+
+        abort_handle.abort();
+
+        let result = futures::executor::block_on(abortable);
+        assert!(matches!(result, Err(PrismError::Aborted)));
+    }
+
+    #[test]
+    fn test_abort_while_pending() {
+        let handle = TaskHandle::new();
+        let future = PrismFuture::<i32>::new(handle, None);
+        let (abortable, abort_handle) = future.abortable();
+
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(10));
+            abort_handle.abort();
+        });
+
+        let result = futures::executor::block_on(abortable);
+        assert!(matches!(result, Err(PrismError::Aborted)));
+    }
+
+    #[test]
+    fn test_abortable_completes_when_not_aborted() {
+        let handle = TaskHandle::new();
+        let future = PrismFuture::<i32>::new(handle, None);
+        let (abortable, _abort_handle) = future.clone().abortable();
+
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(10));
+            future.set_result(Ok(42));
+        });
+
+        let result = futures::executor::block_on(abortable);
+        assert_eq!(result.unwrap(), 42);
+    }
+
+    #[test]
+    fn test_join_all_waits_for_every_future() {
+        let futures: Vec<_> = (0..3)
+            .map(|_| PrismFuture::<i32>::new(TaskHandle::new(), None))
+            .collect();
+
+        for (i, future) in futures.iter().enumerate() {
+            let future = future.clone();
+            thread::spawn(move || {
+                thread::sleep(Duration::from_millis(10));
+                future.set_result(Ok(i as i32));
+            });
+        }
+
+        let results = futures::executor::block_on(join_all(futures));
+        let values: Vec<i32> = results.into_iter().map(|r| r.unwrap()).collect();
+        assert_eq!(values, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_try_join_all_short_circuits_on_first_error() {
+        let ok_future = PrismFuture::<i32>::new(TaskHandle::new(), None);
+        let err_future = PrismFuture::<i32>::new(TaskHandle::new(), None);
+
+        {
+            let ok_future = ok_future.clone();
+            thread::spawn(move || {
+                thread::sleep(Duration::from_millis(30));
+                ok_future.set_result(Ok(1));
+            });
+        }
+        {
+            let err_future = err_future.clone();
+            thread::spawn(move || {
+                thread::sleep(Duration::from_millis(10));
+                err_future.set_result(Err(PrismError::Timeout));
+            });
+        }
+
+        let result = futures::executor::block_on(try_join_all(vec![ok_future, err_future]));
+        assert!(matches!(result, Err(PrismError::Timeout)));
+    }
+
+    #[test]
+    fn test_try_join_all_collects_ok_values() {
+        let futures: Vec<_> = (0..3)
+            .map(|_| PrismFuture::<i32>::new(TaskHandle::new(), None))
+            .collect();
+
+        for (i, future) in futures.iter().enumerate() {
+            let future = future.clone();
+            thread::spawn(move || {
+                thread::sleep(Duration::from_millis(10));
+                future.set_result(Ok(i as i32));
+            });
+        }
+
+        let result = futures::executor::block_on(try_join_all(futures));
+        assert_eq!(result.unwrap(), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_select_returns_first_completed_with_its_index() {
+        let slow = PrismFuture::<i32>::new(TaskHandle::new(), None);
+        let fast = PrismFuture::<i32>::new(TaskHandle::new(), None);
+
+        {
+            let slow = slow.clone();
+            thread::spawn(move || {
+                thread::sleep(Duration::from_millis(50));
+                slow.set_result(Ok(1));
+            });
+        }
+        {
+            let fast = fast.clone();
+            thread::spawn(move || {
+                thread::sleep(Duration::from_millis(10));
+                fast.set_result(Ok(2));
+            });
+        }
+
+        let (index, result) = futures::executor::block_on(select(vec![slow, fast]));
+        assert_eq!(index, 1);
+        assert_eq!(result.unwrap(), 2);
+    }
+
+    #[test]
+    fn test_shared_future_broadcasts_to_multiple_awaiters() {
+        let handle = TaskHandle::new();
+        let future = PrismFuture::<i32>::new(handle, None).shared();
+
+        let first = future.clone();
+        let second = future.clone();
+
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(10));
+            future.set_result(Ok(7));
+        });
+
+        let handles = [
+            thread::spawn(move || futures::executor::block_on(first).unwrap()),
+            thread::spawn(move || futures::executor::block_on(second).unwrap()),
+        ];
+
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), 7);
+        }
+    }
 }
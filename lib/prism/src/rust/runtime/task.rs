@@ -2,14 +2,196 @@
 // Created by: isdood
 // Date: 2025-01-21 11:08:32 UTC
 
-use std::future::Future;
+use std::cmp::Ordering as PriorityOrdering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::future::{poll_fn, Future};
 use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
-use std::task::{Context, Poll, Waker};
+use std::task::{Poll, Waker};
 use std::time::{Duration, Instant};
 
-use crate::crystal::bridge::Crystal;
-use crate::types::{PrismError, PrismResult, Priority, TaskHandle, TaskMetadata, TaskState};
+use tokio::sync::{Notify, Semaphore};
+
+use crate::crystal::bridge::{Crystal, CrystalCapabilities};
+use crate::types::{
+    CachePadded, PrismError, PrismResult, Priority, TaskHandle, TaskMetadata, TaskState,
+    TaskStatus,
+};
+
+/// A cooperative control signal that can be raised against a running
+/// task, modeled on a process-wide signal control block: each signal is
+/// a single bit within a fixed-width slot of a shared [`SignalControl`]
+/// bitmask rather than its own atomic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Signal {
+    Cancel,
+    Pause,
+    Resume,
+    Timeout,
+}
+
+impl Signal {
+    /// How many signal bits each task's slot reserves in the shared
+    /// pending-signal word.
+    const BITS_PER_TASK: u32 = 4;
+
+    fn bit_offset(self) -> u32 {
+        match self {
+            Signal::Cancel => 0,
+            Signal::Pause => 1,
+            Signal::Resume => 2,
+            Signal::Timeout => 3,
+        }
+    }
+}
+
+/// Shared pending-signal bitmask for a [`TaskExecutor`]: each registered
+/// task gets a fixed-width slot in a single `AtomicU64`, so raising a
+/// signal is one `fetch_or` rather than a per-task atomic. Signals are
+/// edge-consumed -- [`SignalControl::take`] clears the bit it observed,
+/// so a stale wakeup can't redeliver the same signal twice.
+#[derive(Debug)]
+pub struct SignalControl {
+    pending: AtomicU64,
+    slots: Mutex<HashMap<TaskHandle, u32>>,
+    wakers: Mutex<HashMap<TaskHandle, Waker>>,
+}
+
+/// Maximum number of tasks a single [`SignalControl`] can track at once
+/// (64 bits / [`Signal::BITS_PER_TASK`] bits per slot).
+const MAX_SIGNAL_SLOTS: u32 = 64 / Signal::BITS_PER_TASK;
+
+impl SignalControl {
+    fn new() -> Self {
+        Self {
+            pending: AtomicU64::new(0),
+            slots: Mutex::new(HashMap::new()),
+            wakers: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Registers `handle` for signal delivery, returning the slot it was
+    /// assigned.
+    fn register(&self, handle: TaskHandle) -> u32 {
+        let mut slots = self.slots.lock().unwrap();
+        let slot = slots.len() as u32;
+        assert!(
+            slot < MAX_SIGNAL_SLOTS,
+            "SignalControl: too many concurrently tracked tasks (max {})",
+            MAX_SIGNAL_SLOTS
+        );
+        slots.insert(handle, slot);
+        slot
+    }
+
+    fn slot_of(&self, handle: TaskHandle) -> PrismResult<u32> {
+        self.slots
+            .lock()
+            .unwrap()
+            .get(&handle)
+            .copied()
+            .ok_or(PrismError::TaskNotFound)
+    }
+
+    /// Sets `signal`'s bit for `handle` with `Ordering::Release`.
+    fn raise(&self, handle: TaskHandle, signal: Signal) -> PrismResult<()> {
+        let slot = self.slot_of(handle)?;
+        let bit = 1u64 << (slot * Signal::BITS_PER_TASK + signal.bit_offset());
+        self.pending.fetch_or(bit, Ordering::Release);
+        Ok(())
+    }
+
+    /// Checks whether `signal`'s bit is set for `slot`, without clearing
+    /// it. Used for `Cancel`, which should stay visible to every poll
+    /// once raised rather than being consumed by the first one.
+    fn peek(&self, slot: u32, signal: Signal) -> bool {
+        let bit = 1u64 << (slot * Signal::BITS_PER_TASK + signal.bit_offset());
+        self.pending.load(Ordering::Acquire) & bit != 0
+    }
+
+    /// Checks and edge-consumes `signal`'s bit for `slot`, returning
+    /// whether it had been set.
+    fn take(&self, slot: u32, signal: Signal) -> bool {
+        let bit = 1u64 << (slot * Signal::BITS_PER_TASK + signal.bit_offset());
+        self.pending.fetch_and(!bit, Ordering::AcqRel) & bit != 0
+    }
+
+    /// Stores the waker a parked task should be woken with once it's
+    /// resumed.
+    fn park(&self, handle: TaskHandle, waker: Waker) {
+        self.wakers.lock().unwrap().insert(handle, waker);
+    }
+
+    /// Wakes `handle`'s parked waker, if one is stored.
+    fn wake(&self, handle: TaskHandle) {
+        if let Some(waker) = self.wakers.lock().unwrap().get(&handle) {
+            waker.wake_by_ref();
+        }
+    }
+}
+
+/// Backoff strategy between retry attempts.
+#[derive(Debug, Clone, Copy)]
+pub enum Backoff {
+    /// Always wait the same duration.
+    Fixed(Duration),
+    /// Wait `base * factor^attempt`, capped at `cap`.
+    Exponential {
+        base: Duration,
+        factor: f64,
+        cap: Duration,
+    },
+}
+
+impl Backoff {
+    /// Computes the delay before retrying after `attempt` (0-indexed)
+    /// has failed, optionally adding up to 50% jitter so retrying
+    /// callers don't all wake up in lockstep.
+    fn delay(&self, attempt: u32, jitter: bool) -> Duration {
+        let base_delay = match *self {
+            Backoff::Fixed(duration) => duration,
+            Backoff::Exponential { base, factor, cap } => {
+                let scaled = base.as_secs_f64() * factor.powi(attempt as i32);
+                Duration::from_secs_f64(scaled.min(cap.as_secs_f64()))
+            }
+        };
+
+        if !jitter {
+            return base_delay;
+        }
+
+        // A `rand` dependency isn't worth it for one jitter call; mix the
+        // attempt number into a cheap hash instead of drawing real
+        // randomness.
+        let jitter_fraction =
+            (attempt as u64).wrapping_mul(2_654_435_761).wrapping_add(1) % 1000;
+        base_delay.mul_f64(1.0 + (jitter_fraction as f64 / 1000.0) * 0.5)
+    }
+}
+
+/// Retry policy for `TaskExecutor::submit_retryable`: a failed or
+/// timed-out task is re-created from its future factory and re-run,
+/// sleeping the computed backoff between attempts, until `max_attempts`
+/// is reached.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub backoff: Backoff,
+    pub jitter: bool,
+}
+
+impl Default for RetryPolicy {
+    /// No retries: a single attempt, matching the behavior of
+    /// `TaskExecutor::submit` for tasks that don't opt into retrying.
+    fn default() -> Self {
+        Self {
+            max_attempts: 1,
+            backoff: Backoff::Fixed(Duration::ZERO),
+            jitter: false,
+        }
+    }
+}
 
 /// Task configuration
 #[derive(Debug, Clone)]
@@ -18,6 +200,13 @@ pub struct TaskConfig {
     pub timeout: Option<Duration>,
     pub stack_size: Option<usize>,
     pub crystal_alignment: bool,
+    /// Crystal capabilities this task needs in order to align, checked
+    /// against the executor's `Crystal::capabilities()` by
+    /// `TaskExecutor::submit` before the task is even enqueued. `None`
+    /// (the default) means the task aligns with whatever crystal is
+    /// present, same as before this check existed.
+    pub required_capabilities: Option<CrystalCapabilities>,
+    pub retry: RetryPolicy,
 }
 
 impl Default for TaskConfig {
@@ -27,6 +216,8 @@ impl Default for TaskConfig {
             timeout: None,
             stack_size: None,
             crystal_alignment: true,
+            required_capabilities: None,
+            retry: RetryPolicy::default(),
         }
     }
 }
@@ -39,11 +230,19 @@ pub struct TaskContext {
     config: TaskConfig,
     start_time: Option<Instant>,
     waker: Option<Waker>,
+    signals: Arc<SignalControl>,
+    signal_slot: u32,
 }
 
 impl TaskContext {
-    /// Create a new task context
+    /// Create a new task context. Each context gets its own private
+    /// [`SignalControl`] so cancellation works even for a bare `Task`
+    /// created outside a [`TaskExecutor`]; `TaskExecutor::submit` rebinds
+    /// it to the executor's shared one via [`Task::bind_signals`] so
+    /// `TaskExecutor::signal` can reach it.
     pub fn new(handle: TaskHandle, config: TaskConfig, crystal: Option<Arc<Crystal>>) -> Self {
+        let signals = Arc::new(SignalControl::new());
+        let signal_slot = signals.register(handle);
         Self {
             handle,
             state: Arc::new(TaskState::new(handle, config.priority)),
@@ -51,6 +250,8 @@ impl TaskContext {
             config,
             start_time: None,
             waker: None,
+            signals,
+            signal_slot,
         }
     }
 
@@ -78,6 +279,30 @@ impl TaskContext {
         }
         Ok(())
     }
+
+    /// Creates a context that reuses an existing task's identity --
+    /// its handle, shared state, and signal registration -- instead of
+    /// minting a new one. Used by `RetryableTask` so a retried attempt
+    /// is still the same task from the caller's perspective.
+    fn with_shared_state(
+        handle: TaskHandle,
+        config: TaskConfig,
+        crystal: Option<Arc<Crystal>>,
+        state: Arc<TaskState>,
+        signals: Arc<SignalControl>,
+        signal_slot: u32,
+    ) -> Self {
+        Self {
+            handle,
+            state,
+            crystal,
+            config,
+            start_time: None,
+            waker: None,
+            signals,
+            signal_slot,
+        }
+    }
 }
 
 /// Executable task wrapper
@@ -106,10 +331,30 @@ where
         &self.context
     }
 
+    /// Rebinds this task onto a shared [`SignalControl`], so
+    /// `TaskExecutor::signal` can reach it by handle. Used by
+    /// `TaskExecutor::submit`; a `Task` created directly via [`Task::new`]
+    /// keeps the private `SignalControl` `TaskContext::new` gave it.
+    fn bind_signals(&mut self, signals: Arc<SignalControl>) {
+        let slot = signals.register(self.context.handle);
+        self.context.signals = signals;
+        self.context.signal_slot = slot;
+    }
+
+    /// Builds a task from an already-constructed context instead of a
+    /// fresh `TaskHandle`. Used by `RetryableTask` to re-run a future
+    /// under the same task identity across retry attempts.
+    fn from_context(future: F, context: TaskContext) -> Self {
+        Self {
+            context,
+            future: Box::pin(future),
+        }
+    }
+
     /// Execute the task
     pub async fn execute(mut self) -> PrismResult<()> {
         self.context.start_time = Some(Instant::now());
-        self.context.state.set_status(crate::types::TaskStatus::Running);
+        self.context.state.set_status(TaskStatus::Running);
 
         // Align with crystal pattern if enabled
         if self.context.config.crystal_alignment {
@@ -118,11 +363,50 @@ where
             }
         }
 
-        let result = self.future.as_mut().await;
-        
+        let timeout = self.context.config.timeout;
+        let context = &mut self.context;
+        let mut future = self.future;
+
+        // Wrap the inner future's poll so a raised Cancel signal is
+        // observed before each poll instead of only between `.await`
+        // points, a Pause signal parks the task on the waker it was
+        // polled with until a matching Resume wakes it back up, and an
+        // elapsed timeout ends the poll loop as soon as it's next
+        // polled at all.
+        let poll_future = poll_fn(|cx| {
+            if context.signals.peek(context.signal_slot, Signal::Cancel) {
+                return Poll::Ready(Err(PrismError::Cancelled));
+            }
+
+            if context.signals.take(context.signal_slot, Signal::Pause) {
+                let waker = cx.waker().clone();
+                context.signals.park(context.handle, waker.clone());
+                context.waker = Some(waker);
+                return Poll::Pending;
+            }
+
+            if let Err(err) = context.check_timeout() {
+                return Poll::Ready(Err(err));
+            }
+
+            future.as_mut().poll(cx)
+        });
+
+        // Racing against `tokio::time::timeout` (rather than relying on
+        // `check_timeout` alone) means the timeout still fires even if
+        // the inner future never yields control back for a re-poll.
+        let result = match timeout {
+            Some(duration) => match tokio::time::timeout(duration, poll_future).await {
+                Ok(result) => result,
+                Err(_elapsed) => Err(PrismError::Timeout),
+            },
+            None => poll_future.await,
+        };
+
         match &result {
-            Ok(_) => self.context.state.set_status(crate::types::TaskStatus::Completed),
-            Err(_) => self.context.state.set_status(crate::types::TaskStatus::Failed),
+            Ok(_) => context.state.set_status(TaskStatus::Completed),
+            Err(PrismError::Cancelled) => context.state.set_status(TaskStatus::Cancelled),
+            Err(_) => context.state.set_status(TaskStatus::Failed),
         }
 
         result
@@ -131,16 +415,111 @@ where
 
 /// Task executor for running multiple tasks
 pub struct TaskExecutor {
-    tasks: Arc<Mutex<Vec<Box<dyn TaskTrait + Send>>>>,
+    /// One independently locked ready queue per shard, so concurrent
+    /// `submit` calls from different producers rarely block on the same
+    /// lock. A task's shard is chosen by hashing its `TaskHandle`, so a
+    /// given task always lands in the same shard for its whole queued
+    /// lifetime. Each shard is cache-padded so adjacent shards' mutexes
+    /// don't pack onto the same cache line -- without that, one shard's
+    /// lock/unlock would still invalidate its neighbor's line and
+    /// reintroduce the cross-core contention sharding is meant to avoid.
+    shards: Vec<CachePadded<Mutex<BinaryHeap<QueuedTask>>>>,
     crystal: Option<Arc<Crystal>>,
+    signals: Arc<SignalControl>,
+    concurrency: usize,
+    /// Total tasks ever submitted. Bumped by every producer thread, so
+    /// it's cache-padded the same way `Crystal`'s counters are.
+    submitted: CachePadded<AtomicU64>,
 }
 
 impl TaskExecutor {
-    /// Create a new task executor
+    /// Create a new task executor, running up to one task per available
+    /// CPU at once, with one ready-queue shard per CPU too.
     pub fn new(crystal: Option<Arc<Crystal>>) -> Self {
+        Self::with_concurrency(crystal, num_cpus::get())
+    }
+
+    /// Create a new task executor that runs at most `concurrency` tasks
+    /// at once, sharding its ready queue the same `concurrency` ways.
+    pub fn with_concurrency(crystal: Option<Arc<Crystal>>, concurrency: usize) -> Self {
+        let concurrency = concurrency.max(1);
+        Self::new_sharded(crystal, concurrency, concurrency)
+    }
+
+    /// Create a new task executor whose ready queue is split into
+    /// `shard_count` independent lock shards, decoupling queue
+    /// contention from how many tasks actually run at once. Concurrency
+    /// still defaults to one task per available CPU; use
+    /// [`TaskExecutor::new_sharded`] to control both independently.
+    pub fn with_shards(crystal: Option<Arc<Crystal>>, shard_count: usize) -> Self {
+        Self::new_sharded(crystal, num_cpus::get(), shard_count)
+    }
+
+    /// Create a new task executor with an explicit concurrency bound and
+    /// shard count.
+    pub fn new_sharded(
+        crystal: Option<Arc<Crystal>>,
+        concurrency: usize,
+        shard_count: usize,
+    ) -> Self {
+        let shard_count = shard_count.max(1);
         Self {
-            tasks: Arc::new(Mutex::new(Vec::new())),
+            shards: (0..shard_count)
+                .map(|_| CachePadded::new(Mutex::new(BinaryHeap::new())))
+                .collect(),
             crystal,
+            signals: Arc::new(SignalControl::new()),
+            concurrency: concurrency.max(1),
+            submitted: CachePadded::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Total number of tasks ever submitted to this executor.
+    pub fn submitted_count(&self) -> u64 {
+        self.submitted.load(Ordering::Relaxed)
+    }
+
+    /// Picks which shard `handle` belongs in by mixing its raw ID
+    /// through the same cheap multiplicative hash `Backoff::delay` uses
+    /// for jitter -- no need for a real hash function just to spread
+    /// handles across shards.
+    fn shard_for(&self, handle: TaskHandle) -> usize {
+        let mixed = handle.raw_id().wrapping_mul(2_654_435_761);
+        (mixed as usize) % self.shards.len()
+    }
+
+    fn enqueue(&self, handle: TaskHandle, task: Box<dyn TaskTrait + Send>) {
+        let shard = self.shard_for(handle);
+        self.shards[shard].lock().unwrap().push(QueuedTask(task));
+        self.submitted.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Checks `config.required_capabilities` (if any) against this
+    /// executor's crystal before a task is enqueued, so a capability
+    /// mismatch is reported as a structured `PrismError::IncompatibleCrystal`
+    /// at submit-time rather than failing opaquely inside
+    /// `Crystal::optimize` partway through `Task::execute`. A task that
+    /// doesn't request `crystal_alignment`, or declares no required
+    /// capabilities, always passes -- this only tightens the check for
+    /// tasks that opted into one.
+    fn check_crystal_compatibility(&self, config: &TaskConfig) -> PrismResult<()> {
+        if !config.crystal_alignment {
+            return Ok(());
+        }
+
+        let (Some(crystal), Some(required)) = (&self.crystal, config.required_capabilities)
+        else {
+            return Ok(());
+        };
+
+        let available = crystal.capabilities();
+        if available.satisfies(&required) {
+            Ok(())
+        } else {
+            Err(PrismError::IncompatibleCrystal {
+                required,
+                available,
+            })
         }
     }
 
@@ -149,31 +528,162 @@ impl TaskExecutor {
     where
         F: Future<Output = PrismResult<()>> + Send + 'static,
     {
-        let task = Task::new(future, config, self.crystal.clone());
+        self.check_crystal_compatibility(&config)?;
+
+        let mut task = Task::new(future, config, self.crystal.clone());
+        task.bind_signals(Arc::clone(&self.signals));
         let handle = task.context().handle();
-        
-        self.tasks.lock().unwrap().push(Box::new(TaskWrapper(task)));
+
+        self.enqueue(handle, Box::new(TaskWrapper(task)));
+        Ok(handle)
+    }
+
+    /// Raises `signal` against the task registered under `handle`, so it
+    /// takes effect on that task's next poll: `Cancel` makes the next
+    /// poll finish the task with `Err(PrismError::Cancelled)`, `Pause`
+    /// parks it on the waker it was polled with, and `Resume` clears the
+    /// park and wakes it back up. Returns `Err(PrismError::TaskNotFound)`
+    /// if `handle` isn't (or is no longer) registered with this executor.
+    pub fn signal(&self, handle: TaskHandle, signal: Signal) -> PrismResult<()> {
+        self.signals.raise(handle, signal)?;
+
+        if signal == Signal::Resume {
+            self.signals.wake(handle);
+        }
+
+        Ok(())
+    }
+
+    /// Submits a task that's re-created from `factory` and re-run
+    /// according to `config.retry` if an attempt fails, sharing one
+    /// task identity (handle, state, signal slot) across attempts.
+    /// Needed because `Task::execute` consumes its future, so `submit`
+    /// -- which is handed an already-constructed future -- can't retry
+    /// on its own.
+    pub fn submit_retryable<Fct, F>(
+        &self,
+        factory: Fct,
+        config: TaskConfig,
+    ) -> PrismResult<TaskHandle>
+    where
+        Fct: Fn() -> F + Send + Sync + 'static,
+        F: Future<Output = PrismResult<()>> + Send + 'static,
+    {
+        self.check_crystal_compatibility(&config)?;
+
+        let handle = TaskHandle::new();
+        let state = Arc::new(TaskState::new(handle, config.priority));
+        let signal_slot = self.signals.register(handle);
+
+        let task = RetryableTask {
+            factory,
+            config,
+            crystal: self.crystal.clone(),
+            handle,
+            state,
+            signals: Arc::clone(&self.signals),
+            signal_slot,
+        };
+
+        self.enqueue(handle, Box::new(task));
         Ok(handle)
     }
 
-    /// Execute all pending tasks
-    pub async fn execute_all(&self) -> PrismResult<()> {
-        let mut tasks = self.tasks.lock().unwrap();
-        let mut results = Vec::new();
+    /// Drains every shard's heap into its own priority-descending list,
+    /// then merges those lists into one globally priority-ordered list.
+    /// Each step through the merge scans shards starting from wherever
+    /// the previous pick left off, so shards round-robin for the slot
+    /// whenever their front tasks tie on priority, instead of the same
+    /// low-index shard always winning ties.
+    fn drain_and_merge(&self) -> Vec<Box<dyn TaskTrait + Send>> {
+        let mut per_shard: Vec<VecDeque<Box<dyn TaskTrait + Send>>> = self
+            .shards
+            .iter()
+            .map(|shard| {
+                let mut heap = shard.lock().unwrap();
+                let mut drained = VecDeque::with_capacity(heap.len());
+                while let Some(QueuedTask(task)) = heap.pop() {
+                    drained.push_back(task);
+                }
+                drained
+            })
+            .collect();
+
+        let total: usize = per_shard.iter().map(VecDeque::len).sum();
+        let mut merged = Vec::with_capacity(total);
+        let mut start = 0;
 
-        // Sort tasks by priority
-        tasks.sort_by(|a, b| b.priority().cmp(&a.priority()));
+        loop {
+            let mut best: Option<(usize, Priority)> = None;
+            for offset in 0..per_shard.len() {
+                let idx = (start + offset) % per_shard.len();
+                if let Some(task) = per_shard[idx].front() {
+                    let priority = task.priority();
+                    if best.map_or(true, |(_, best_priority)| priority > best_priority) {
+                        best = Some((idx, priority));
+                    }
+                }
+            }
 
-        for task in tasks.drain(..) {
-            results.push(task.execute().await);
+            match best {
+                Some((idx, _)) => {
+                    merged.push(per_shard[idx].pop_front().unwrap());
+                    start = (idx + 1) % per_shard.len();
+                }
+                None => break,
+            }
         }
 
-        // Check for any errors
-        for result in results {
-            result?;
+        merged
+    }
+
+    /// Runs every submitted task concurrently, bounded to at most
+    /// `concurrency` tasks in flight at once via a semaphore. Tasks are
+    /// spawned onto the runtime in priority order (highest first) via
+    /// [`TaskExecutor::drain_and_merge`], so when the pool is saturated a
+    /// higher-`Priority` task acquires its permit before a lower-priority
+    /// one queued behind it. Unlike a sequential drain, one task's
+    /// failure doesn't stop the rest: every outcome is reported back,
+    /// keyed by `TaskHandle`.
+    pub async fn execute_all(&self) -> PrismResult<Vec<(TaskHandle, PrismResult<()>)>> {
+        let ordered = self.drain_and_merge();
+
+        if ordered.is_empty() {
+            return Ok(Vec::new());
         }
 
-        Ok(())
+        let semaphore = Arc::new(Semaphore::new(self.concurrency));
+        let pending = Arc::new(AtomicUsize::new(ordered.len()));
+        let done = Arc::new(Notify::new());
+        let results: Arc<Mutex<Vec<(TaskHandle, PrismResult<()>)>>> =
+            Arc::new(Mutex::new(Vec::with_capacity(ordered.len())));
+
+        for task in ordered {
+            let handle = task.handle();
+            let semaphore = Arc::clone(&semaphore);
+            let pending = Arc::clone(&pending);
+            let done = Arc::clone(&done);
+            let results = Arc::clone(&results);
+
+            tokio::spawn(async move {
+                let permit = semaphore.acquire_owned().await.unwrap();
+                let result = task.execute().await;
+                drop(permit);
+
+                results.lock().unwrap().push((handle, result));
+
+                if pending.fetch_sub(1, Ordering::AcqRel) == 1 {
+                    done.notify_one();
+                }
+            });
+        }
+
+        done.notified().await;
+
+        Ok(Arc::try_unwrap(results)
+            .expect("all spawned tasks have completed and dropped their result handle")
+            .into_inner()
+            .unwrap())
     }
 }
 
@@ -181,6 +691,32 @@ impl TaskExecutor {
 trait TaskTrait {
     fn execute(self: Box<Self>) -> Pin<Box<dyn Future<Output = PrismResult<()>> + Send>>;
     fn priority(&self) -> Priority;
+    fn handle(&self) -> TaskHandle;
+}
+
+/// Orders boxed tasks by `Priority` so `TaskExecutor`'s ready queue is a
+/// max-heap: `execute_all` pops (and so spawns) the highest-priority
+/// task first.
+struct QueuedTask(Box<dyn TaskTrait + Send>);
+
+impl PartialEq for QueuedTask {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.priority() == other.0.priority()
+    }
+}
+
+impl Eq for QueuedTask {}
+
+impl PartialOrd for QueuedTask {
+    fn partial_cmp(&self, other: &Self) -> Option<PriorityOrdering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for QueuedTask {
+    fn cmp(&self, other: &Self) -> PriorityOrdering {
+        self.0.priority().cmp(&other.0.priority())
+    }
 }
 
 /// Task wrapper for trait implementation
@@ -197,6 +733,78 @@ where
     fn priority(&self) -> Priority {
         self.0.context().priority()
     }
+
+    fn handle(&self) -> TaskHandle {
+        self.0.context().handle()
+    }
+}
+
+/// A task re-created from a future factory and re-run on failure,
+/// sharing one identity (handle, state, signal slot) across attempts so
+/// it behaves as a single task from `TaskExecutor`'s perspective. Built
+/// by `TaskExecutor::submit_retryable`.
+struct RetryableTask<Fct, F>
+where
+    Fct: Fn() -> F + Send + Sync + 'static,
+    F: Future<Output = PrismResult<()>> + Send + 'static,
+{
+    factory: Fct,
+    config: TaskConfig,
+    crystal: Option<Arc<Crystal>>,
+    handle: TaskHandle,
+    state: Arc<TaskState>,
+    signals: Arc<SignalControl>,
+    signal_slot: u32,
+}
+
+impl<Fct, F> TaskTrait for RetryableTask<Fct, F>
+where
+    Fct: Fn() -> F + Send + Sync + 'static,
+    F: Future<Output = PrismResult<()>> + Send + 'static,
+{
+    fn execute(self: Box<Self>) -> Pin<Box<dyn Future<Output = PrismResult<()>> + Send>> {
+        Box::pin(async move {
+            let policy = self.config.retry;
+            let mut attempt = 0;
+
+            loop {
+                self.state.increment_attempts();
+
+                let context = TaskContext::with_shared_state(
+                    self.handle,
+                    self.config.clone(),
+                    self.crystal.clone(),
+                    Arc::clone(&self.state),
+                    Arc::clone(&self.signals),
+                    self.signal_slot,
+                );
+                let task = Task::from_context((self.factory)(), context);
+
+                match task.execute().await {
+                    Ok(()) => return Ok(()),
+                    // A deliberate cancellation ends the task outright;
+                    // retrying it would silently override the caller's
+                    // intent to stop it.
+                    Err(PrismError::Cancelled) => return Err(PrismError::Cancelled),
+                    Err(err) => {
+                        attempt += 1;
+                        if attempt >= policy.max_attempts {
+                            return Err(err);
+                        }
+                        tokio::time::sleep(policy.backoff.delay(attempt - 1, policy.jitter)).await;
+                    }
+                }
+            }
+        })
+    }
+
+    fn priority(&self) -> Priority {
+        self.config.priority
+    }
+
+    fn handle(&self) -> TaskHandle {
+        self.handle
+    }
 }
 
 #[cfg(test)]
@@ -245,7 +853,164 @@ mod tests {
             ..Default::default()
         }).unwrap();
 
-        executor.execute_all().await.unwrap();
+        let results = executor.execute_all().await.unwrap();
+        assert_eq!(results.len(), 2);
+        let handles: Vec<TaskHandle> = results.iter().map(|(h, _)| *h).collect();
+        assert!(handles.contains(&handle1));
+        assert!(handles.contains(&handle2));
+        assert!(results.iter().all(|(_, r)| r.is_ok()));
+    }
+
+    #[tokio::test]
+    async fn test_task_cancellation() {
+        let future = async {
+            loop {
+                tokio::task::yield_now().await;
+            }
+        };
+
+        let mut task = Task::new(future, TaskConfig::default(), None);
+        task.context.signals.raise(task.context.handle, Signal::Cancel).unwrap();
+
+        let result = task.execute().await;
+        assert!(matches!(result, Err(PrismError::Cancelled)));
+    }
+
+    #[tokio::test]
+    async fn test_executor_signal_cancel() {
+        let executor = TaskExecutor::new(None);
+
+        let future = async {
+            loop {
+                tokio::task::yield_now().await;
+            }
+        };
+
+        let handle = executor.submit(future, TaskConfig::default()).unwrap();
+        executor.signal(handle, Signal::Cancel).unwrap();
+
+        let results = executor.execute_all().await.unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0, handle);
+        assert!(matches!(results[0].1, Err(PrismError::Cancelled)));
+    }
+
+    #[tokio::test]
+    async fn test_task_pause_and_resume() {
+        let executor = Arc::new(TaskExecutor::new(None));
+
+        let future = async {
+            tokio::task::yield_now().await;
+            Ok(())
+        };
+
+        let handle = executor.submit(future, TaskConfig::default()).unwrap();
+        executor.signal(handle, Signal::Pause).unwrap();
+
+        let background = Arc::clone(&executor);
+        let join = tokio::spawn(async move { background.execute_all().await });
+
+        // Give the paused task a chance to park on its waker before
+        // resuming it.
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        executor.signal(handle, Signal::Resume).unwrap();
+
+        let results = join.await.unwrap().unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0, handle);
+        assert!(results[0].1.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_signal_unknown_handle() {
+        let executor = TaskExecutor::new(None);
+        let unknown = TaskHandle::new();
+
+        assert!(matches!(
+            executor.signal(unknown, Signal::Cancel),
+            Err(PrismError::TaskNotFound)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_submit_retryable_eventually_succeeds() {
+        let executor = TaskExecutor::new(None);
+        let attempts = Arc::new(std::sync::atomic::AtomicU32::new(0));
+
+        let counter = Arc::clone(&attempts);
+        let factory = move || {
+            let counter = Arc::clone(&counter);
+            async move {
+                if counter.fetch_add(1, Ordering::SeqCst) < 2 {
+                    Err(PrismError::SystemError)
+                } else {
+                    Ok(())
+                }
+            }
+        };
+
+        let config = TaskConfig {
+            retry: RetryPolicy {
+                max_attempts: 5,
+                backoff: Backoff::Fixed(Duration::from_millis(1)),
+                jitter: false,
+            },
+            ..Default::default()
+        };
+
+        executor.submit_retryable(factory, config).unwrap();
+        let results = executor.execute_all().await.unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert!(results[0].1.is_ok());
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_submit_retryable_exhausts_attempts() {
+        let executor = TaskExecutor::new(None);
+
+        let factory = || async { Err(PrismError::SystemError) };
+
+        let config = TaskConfig {
+            retry: RetryPolicy {
+                max_attempts: 3,
+                backoff: Backoff::Fixed(Duration::from_millis(1)),
+                jitter: false,
+            },
+            ..Default::default()
+        };
+
+        executor.submit_retryable(factory, config).unwrap();
+        let results = executor.execute_all().await.unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert!(matches!(results[0].1, Err(PrismError::SystemError)));
+    }
+
+    #[tokio::test]
+    async fn test_execute_all_runs_concurrently() {
+        let executor = TaskExecutor::new(None);
+        let concurrent = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let max_concurrent = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+
+        for _ in 0..4 {
+            let concurrent = Arc::clone(&concurrent);
+            let max_concurrent = Arc::clone(&max_concurrent);
+            let future = async move {
+                let now = concurrent.fetch_add(1, Ordering::SeqCst) + 1;
+                max_concurrent.fetch_max(now, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                concurrent.fetch_sub(1, Ordering::SeqCst);
+                Ok(())
+            };
+            executor.submit(future, TaskConfig::default()).unwrap();
+        }
+
+        let results = executor.execute_all().await.unwrap();
+        assert_eq!(results.len(), 4);
+        assert!(results.iter().all(|(_, r)| r.is_ok()));
+        assert!(max_concurrent.load(Ordering::SeqCst) > 1);
     }
 
     #[tokio::test]
@@ -262,4 +1027,65 @@ mod tests {
         executor.submit(future, config).unwrap();
         executor.execute_all().await.unwrap();
     }
+
+    #[tokio::test]
+    async fn test_submit_rejects_incompatible_required_capabilities() {
+        let crystal = Arc::new(Crystal::new(crate::crystal::bridge::CrystalSystem::Cubic).unwrap());
+        let executor = TaskExecutor::new(Some(Arc::clone(&crystal)));
+
+        let mut required = crystal.capabilities();
+        required.min_coherence += 0.5;
+
+        let future = async { Ok(()) };
+        let config = TaskConfig {
+            crystal_alignment: true,
+            required_capabilities: Some(required),
+            ..Default::default()
+        };
+
+        let result = executor.submit(future, config);
+        assert!(matches!(
+            result,
+            Err(PrismError::IncompatibleCrystal { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_submit_accepts_satisfied_required_capabilities() {
+        let crystal = Arc::new(Crystal::new(crate::crystal::bridge::CrystalSystem::Cubic).unwrap());
+        let executor = TaskExecutor::new(Some(Arc::clone(&crystal)));
+
+        let required = crystal.capabilities();
+        let future = async { Ok(()) };
+        let config = TaskConfig {
+            crystal_alignment: true,
+            required_capabilities: Some(required),
+            ..Default::default()
+        };
+
+        assert!(executor.submit(future, config).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_sharded_executor_preserves_priority_order() {
+        let executor = TaskExecutor::with_shards(None, 4);
+
+        for priority in [Priority::Low, Priority::Critical, Priority::Normal, Priority::High] {
+            let future = async { Ok(()) };
+            let config = TaskConfig {
+                priority,
+                ..Default::default()
+            };
+            executor.submit(future, config).unwrap();
+        }
+
+        assert_eq!(executor.submitted_count(), 4);
+
+        let ordered = executor.drain_and_merge();
+        let priorities: Vec<Priority> = ordered.iter().map(|task| task.priority()).collect();
+        assert_eq!(
+            priorities,
+            vec![Priority::Critical, Priority::High, Priority::Normal, Priority::Low]
+        );
+    }
 }
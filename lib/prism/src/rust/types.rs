@@ -59,6 +59,9 @@ pub enum TaskStatus {
     Completed = 3,
     Failed = 4,
     Cancelled = 5,
+    /// Parked inside a `prism_task_yield` call on a coroutine task,
+    /// waiting for `prism_task_resume` to switch back onto its stack.
+    Suspended = 6,
 }
 
 impl Default for TaskStatus {
@@ -68,7 +71,7 @@ impl Default for TaskStatus {
 }
 
 /// Error types for Prism operations
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 #[repr(i32)]
 pub enum PrismError {
     Success = 0,
@@ -80,6 +83,27 @@ pub enum PrismError {
     TaskNotFound = -6,
     InvalidState = -7,
     SystemError = -8,
+    Cancelled = -9,
+    /// `PrismConfig::requested_abi_version` is newer than this build of
+    /// `libprism` provides, or `PrismConfig::feature_flags` sets a bit
+    /// `prism_runtime_create` doesn't recognize. Returned by
+    /// `PrismRuntime::new`; the raw `prism_runtime_create` FFI export
+    /// signals the same condition by returning null, matching how it
+    /// already reports allocation failure.
+    IncompatibleAbi = -10,
+    /// A task required crystal capabilities its executor's `Crystal`
+    /// doesn't advertise. Returned by `TaskExecutor::submit` at
+    /// submit-time instead of surfacing as an opaque failure once
+    /// `crystal.optimize()` runs partway through execution.
+    IncompatibleCrystal {
+        required: crate::crystal::bridge::CrystalCapabilities,
+        available: crate::crystal::bridge::CrystalCapabilities,
+    },
+    /// Returned by a polled `Abortable<F>` whose `AbortHandle::abort()` was
+    /// called, either before the first poll or while the inner future was
+    /// pending. Distinct from `Cancelled`, which covers runtime-initiated
+    /// task teardown rather than a caller-held handle firing.
+    Aborted = -11,
 }
 
 impl std::error::Error for PrismError {}
@@ -96,6 +120,17 @@ impl std::fmt::Display for PrismError {
             PrismError::TaskNotFound => write!(f, "Task not found"),
             PrismError::InvalidState => write!(f, "Invalid state"),
             PrismError::SystemError => write!(f, "System error occurred"),
+            PrismError::Cancelled => write!(f, "Task was cancelled"),
+            PrismError::IncompatibleAbi => write!(
+                f,
+                "Requested ABI version or feature flags are not supported by this build"
+            ),
+            PrismError::IncompatibleCrystal { required, available } => write!(
+                f,
+                "Crystal capabilities incompatible: task requires {:?}, executor's crystal advertises {:?}",
+                required, available
+            ),
+            PrismError::Aborted => write!(f, "Task was aborted"),
         }
     }
 }
@@ -127,6 +162,10 @@ pub struct TaskMetadata {
     pub creation_time: std::time::Instant,
     pub start_time: Option<std::time::Instant>,
     pub completion_time: Option<std::time::Instant>,
+    /// Number of times this task has been run, including the current
+    /// attempt. Only ever above 1 for tasks submitted through
+    /// `TaskExecutor::submit_retryable`.
+    pub attempts: u32,
 }
 
 impl TaskMetadata {
@@ -138,6 +177,7 @@ impl TaskMetadata {
             creation_time: std::time::Instant::now(),
             start_time: None,
             completion_time: None,
+            attempts: 0,
         }
     }
 
@@ -180,11 +220,50 @@ impl TaskState {
         *self.result.lock().unwrap() = Some(result);
     }
 
+    /// Records the start of another attempt, returning the new attempt
+    /// count.
+    pub fn increment_attempts(&self) -> u32 {
+        let mut metadata = self.metadata.lock().unwrap();
+        metadata.attempts += 1;
+        metadata.attempts
+    }
+
     pub fn get_result(&self) -> Option<PrismResult<()>> {
         self.result.lock().unwrap().clone()
     }
 }
 
+/// Pads `T` out to its own cache line (64 bytes on the architectures
+/// Prism targets), so two instances packed next to each other -- e.g.
+/// one counter per worker thread -- never share a cache line. Without
+/// this, one thread's write to its own counter invalidates the cache
+/// line backing a neighboring thread's counter too, and the two threads
+/// end up serialized on that line regardless of the fact that they
+/// never touch the same logical value.
+#[repr(align(64))]
+#[derive(Debug, Default)]
+pub struct CachePadded<T>(T);
+
+impl<T> CachePadded<T> {
+    pub const fn new(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> std::ops::Deref for CachePadded<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> std::ops::DerefMut for CachePadded<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
 /// Runtime statistics
 #[derive(Debug, Default)]
 pub struct RuntimeStats {
@@ -15,6 +15,8 @@ fn test_runtime_creation() {
         thread_count: 4,
         stack_size: 1024 * 1024,
         use_hardware_threads: true,
+        requested_abi_version: prism::binding::ffi::PRISM_ABI_VERSION,
+        feature_flags: 0,
     };
 
     unsafe {
@@ -30,6 +32,8 @@ fn test_task_lifecycle() {
         thread_count: 1,
         stack_size: 1024 * 1024,
         use_hardware_threads: false,
+        requested_abi_version: prism::binding::ffi::PRISM_ABI_VERSION,
+        feature_flags: 0,
     };
 
     unsafe {
@@ -66,6 +70,8 @@ fn test_crystal_integration() {
         thread_count: 1,
         stack_size: 1024 * 1024,
         use_hardware_threads: false,
+        requested_abi_version: prism::binding::ffi::PRISM_ABI_VERSION,
+        feature_flags: 0,
     };
 
     unsafe {
@@ -118,6 +124,8 @@ fn test_task_timeout() {
         thread_count: 1,
         stack_size: 1024 * 1024,
         use_hardware_threads: false,
+        requested_abi_version: prism::binding::ffi::PRISM_ABI_VERSION,
+        feature_flags: 0,
     };
 
     unsafe {
@@ -145,6 +153,8 @@ fn test_concurrent_tasks() {
         thread_count: 4,
         stack_size: 1024 * 1024,
         use_hardware_threads: true,
+        requested_abi_version: prism::binding::ffi::PRISM_ABI_VERSION,
+        feature_flags: 0,
     };
 
     unsafe {
@@ -193,6 +203,8 @@ fn test_crystal_pattern_integration() {
         thread_count: 1,
         stack_size: 1024 * 1024,
         use_hardware_threads: false,
+        requested_abi_version: prism::binding::ffi::PRISM_ABI_VERSION,
+        feature_flags: 0,
     };
 
     unsafe {
@@ -7,11 +7,14 @@ use std::{
     time::{Duration, Instant},
 };
 
-use parking_lot::RwLock;
+use num_complex::Complex;
+use parking_lot::{Mutex, RwLock};
 use rayon::prelude::*;
 use glam::{Vec3, Mat4};
 use thiserror::Error;
 
+use super::simd::{OptimizationMetrics, SIMDOptimizer, SIMDStrategy};
+
 /// Errors that can occur in crystal operations
 #[derive(Error, Debug)]
 pub enum CrystalError {
@@ -23,6 +26,8 @@ pub enum CrystalError {
     Decoherence,
     #[error("Reality anchor unstable: {0}")]
     UnstableAnchor(f64),
+    #[error("Harmony state has {0} amplitudes, crystal expects harmony_depth {1}")]
+    DimensionMismatch(usize, usize),
 }
 
 /// Result type for crystal operations
@@ -50,56 +55,145 @@ impl Default for CrystalConfig {
     }
 }
 
-/// Represents a harmony state within the crystal
+/// Represents a harmony state within the crystal as a genuine quantum
+/// amplitude vector (one `Complex<f64>` per basis index, dimension set
+/// by `CrystalConfig::harmony_depth`) instead of a single classical
+/// `Vec3` plus a bolted-on scalar `phase`. Each amplitude already
+/// carries its own phase (`Complex::arg`), so there's nothing left to
+/// keep in sync by hand.
 #[derive(Debug)]
 pub struct HarmonyState {
-    superposition: Vec3,
-    phase: f64,
+    amplitudes: Vec<Complex<f64>>,
     coherence: AtomicF64,
     last_observed: AtomicU64,
 }
 
 impl HarmonyState {
-    pub fn new(initial_state: Vec3) -> Self {
+    /// Builds a state from un-normalized basis amplitudes, renormalizing
+    /// so `sum(|c_i|^2) == 1` as the Born rule requires.
+    pub fn new(amplitudes: Vec<Complex<f64>>) -> Self {
+        let norm = amplitudes.iter().map(Complex::norm_sqr).sum::<f64>().sqrt();
+        let amplitudes = if norm > 0.0 {
+            amplitudes.into_iter().map(|c| c / norm).collect()
+        } else {
+            amplitudes
+        };
+
         Self {
-            superposition: initial_state,
-            phase: 0.0,
+            amplitudes,
             coherence: AtomicF64::new(1.0),
             last_observed: AtomicU64::new(Instant::now().elapsed().as_nanos() as u64),
         }
     }
 
-    pub fn collapse(&self) -> Vec3 {
+    /// Builds a state concentrated entirely on one basis index -- the
+    /// simplest possible superposition, handy for seeding a crystal
+    /// without reasoning about phases.
+    ///
+    /// Panics if `index >= depth`: silently falling back to an
+    /// all-zero, un-normalizable vector would violate the Born-rule
+    /// invariant (`sum(|c_i|^2) == 1`) every other `HarmonyState`
+    /// upholds.
+    pub fn basis(depth: usize, index: usize) -> Self {
+        assert!(
+            index < depth,
+            "HarmonyState::basis: index {} out of range for depth {}",
+            index,
+            depth
+        );
+        let mut amplitudes = vec![Complex::new(0.0, 0.0); depth];
+        amplitudes[index] = Complex::new(1.0, 0.0);
+        Self::new(amplitudes)
+    }
+
+    /// Number of basis amplitudes this state carries.
+    pub fn depth(&self) -> usize {
+        self.amplitudes.len()
+    }
+
+    /// The amplitude vector making up this state's superposition.
+    pub fn amplitudes(&self) -> &[Complex<f64>] {
+        &self.amplitudes
+    }
+
+    /// Phase angle (`arg(c)`) of basis amplitude `index`, for `Scribe`'s
+    /// phase-alignment checks to read directly instead of a separate
+    /// scalar `phase` that could drift out of sync with the amplitudes.
+    /// Panics if `index >= self.depth()`.
+    pub fn phase(&self, index: usize) -> f64 {
+        self.amplitudes[index].arg()
+    }
+
+    /// Samples a basis index with Born-rule probability `|c_i|^2`, and
+    /// marks this state as observed. Unlike collapsing to a single
+    /// classical vector, the superposition itself is left intact --
+    /// repeated calls are independent measurements of the same state.
+    pub fn collapse(&self) -> usize {
         let now = Instant::now().elapsed().as_nanos() as u64;
         self.last_observed.store(now, Ordering::Release);
-        self.superposition
+
+        let roll: f64 = rand::random();
+        let mut cumulative = 0.0;
+        for (index, amplitude) in self.amplitudes.iter().enumerate() {
+            cumulative += amplitude.norm_sqr();
+            if roll < cumulative {
+                return index;
+            }
+        }
+        self.amplitudes.len().saturating_sub(1)
     }
 }
 
+/// How strongly each `update_coherence` pass nudges the running
+/// reference state toward the current ensemble's mean amplitude vector.
+/// Kept low so the reference tracks the crystal's drift over many
+/// updates rather than snapping to whatever was just added.
+const REFERENCE_SMOOTHING: f64 = 0.1;
+
 /// Core crystal structure
 #[derive(Debug)]
 pub struct Crystal {
     config: CrystalConfig,
     lattice: RwLock<Mat4>,
     states: Vec<HarmonyState>,
+    /// Running reference amplitude vector that `update_coherence`
+    /// measures every state's inner product against. Starts at the
+    /// |0> basis state and drifts toward the ensemble mean over time.
+    reference: RwLock<Vec<Complex<f64>>>,
     reality_anchor: AtomicF64,
     coherence: AtomicF64,
+    /// Runs the batch decoherence reduction `update_coherence` needs
+    /// once the per-state inner products are in hand, picking an
+    /// AVX-512/AVX2/scalar kernel for whatever CPU this crystal runs
+    /// on. Mutex-guarded like the rest of `Crystal`'s mutable state
+    /// since `update_coherence` is called through a shared `&self`.
+    simd: Mutex<SIMDOptimizer>,
 }
 
 impl Crystal {
     /// Create a new crystal with given configuration
     pub fn new(config: CrystalConfig) -> Self {
+        let depth = config.harmony_depth as usize;
         Self {
-            config,
             lattice: RwLock::new(Mat4::IDENTITY),
             states: Vec::new(),
+            reference: RwLock::new(HarmonyState::basis(depth, 0).amplitudes),
             reality_anchor: AtomicF64::new(1.0),
             coherence: AtomicF64::new(1.0),
+            simd: Mutex::new(SIMDOptimizer::new()),
+            config,
         }
     }
 
     /// Add a harmony state to the crystal
     pub fn add_state(&mut self, state: HarmonyState) -> CrystalResult<()> {
+        if state.depth() != self.config.harmony_depth as usize {
+            return Err(CrystalError::DimensionMismatch(
+                state.depth(),
+                self.config.harmony_depth as usize,
+            ));
+        }
+
         if self.coherence.load(Ordering::Acquire) < self.config.coherence_threshold {
             return Err(CrystalError::CoherenceLow(
                 self.coherence.load(Ordering::Acquire),
@@ -111,16 +205,71 @@ impl Crystal {
         Ok(())
     }
 
-    /// Update the crystal's harmony coherence
+    /// Update the crystal's harmony coherence as `|<ref|psi>|` -- the
+    /// magnitude of the normalized inner product between each state's
+    /// amplitude vector and the running reference state -- averaged
+    /// across states and scaled by the existing exponential
+    /// decoherence factor `exp(-rate * dt)`. Also nudges the reference
+    /// toward this update's ensemble mean, so later coherence checks
+    /// track where the crystal's states actually are.
+    ///
+    /// Runs in two stages: the inner products themselves are still
+    /// computed with `rayon` since they're one dot product per state,
+    /// but the raw coherence magnitudes and their observation ages are
+    /// then laid out into contiguous buffers and folded through
+    /// `SIMDOptimizer::batch_decohere`, which vectorizes the
+    /// `exp(-rate * dt)` weighting and reduction across states.
     fn update_coherence(&self) {
-        let new_coherence = self.states.par_iter().map(|state| {
-            let time_since_observation = Instant::now().elapsed().as_nanos() as u64
-            - state.last_observed.load(Ordering::Acquire);
-            let decoherence = (-self.config.decoherence_rate * time_since_observation as f64).exp();
-            state.coherence.load(Ordering::Acquire) * decoherence
-        }).sum::<f64>() / self.states.len() as f64;
+        if self.states.is_empty() {
+            return;
+        }
+
+        let reference = self.reference.read().clone();
+        let now = Instant::now().elapsed().as_nanos() as u64;
+
+        let raw: Vec<(f64, u64)> = self.states.par_iter().map(|state| {
+            let inner_product: Complex<f64> = reference
+                .iter()
+                .zip(state.amplitudes())
+                .map(|(r, a)| r.conj() * a)
+                .sum();
+
+            let magnitude = inner_product.norm();
+            state.coherence.store(magnitude, Ordering::Release);
+
+            let dt = now - state.last_observed.load(Ordering::Acquire);
+            (magnitude, dt)
+        }).collect();
+
+        let (magnitudes, dt_nanos): (Vec<f64>, Vec<u64>) = raw.into_iter().unzip();
+        let new_coherence = self
+            .simd
+            .lock()
+            .batch_decohere(&magnitudes, &dt_nanos, self.config.decoherence_rate);
 
         self.coherence.store(new_coherence, Ordering::Release);
+
+        let mut mean = vec![Complex::new(0.0, 0.0); reference.len()];
+        for state in &self.states {
+            for (slot, amplitude) in mean.iter_mut().zip(state.amplitudes()) {
+                *slot += amplitude;
+            }
+        }
+
+        let mean_norm = mean.iter().map(Complex::norm_sqr).sum::<f64>().sqrt();
+        if mean_norm > 0.0 {
+            let mut reference = self.reference.write();
+            for (slot, mean_amplitude) in reference.iter_mut().zip(mean.iter()) {
+                *slot = *slot * (1.0 - REFERENCE_SMOOTHING) + (*mean_amplitude / mean_norm) * REFERENCE_SMOOTHING;
+            }
+
+            let renorm = reference.iter().map(Complex::norm_sqr).sum::<f64>().sqrt();
+            if renorm > 0.0 {
+                for slot in reference.iter_mut() {
+                    *slot = *slot / renorm;
+                }
+            }
+        }
     }
 
     /// Align the crystal lattice
@@ -136,8 +285,10 @@ impl Crystal {
         Ok(())
     }
 
-    /// Process harmony interactions
-    pub fn process_harmony(&self, duration: Duration) -> CrystalResult<Vec<Vec3>> {
+    /// Process harmony interactions, collapsing each state to a sampled
+    /// basis index (Born-rule probability `|c_i|^2`) rather than the
+    /// classical vector a collapse used to return.
+    pub fn process_harmony(&self, duration: Duration) -> CrystalResult<Vec<usize>> {
         self.update_coherence();
 
         if self.coherence.load(Ordering::Acquire) < self.config.coherence_threshold {
@@ -167,11 +318,14 @@ impl Crystal {
 
     /// Get current crystal metrics
     pub fn metrics(&self) -> CrystalMetrics {
+        let simd = self.simd.lock();
         CrystalMetrics {
             coherence: self.coherence.load(Ordering::Acquire),
             reality_anchor: self.reality_anchor.load(Ordering::Acquire),
             state_count: self.states.len(),
             lattice: *self.lattice.read(),
+            simd_strategy: simd.strategy(),
+            simd_metrics: simd.metrics(),
         }
     }
 }
@@ -183,6 +337,11 @@ pub struct CrystalMetrics {
     pub reality_anchor: f64,
     pub state_count: usize,
     pub lattice: Mat4,
+    /// Which kernel the last `update_coherence` pass's batch
+    /// decoherence reduction ran with.
+    pub simd_strategy: SIMDStrategy,
+    /// Throughput of that same batch decoherence reduction.
+    pub simd_metrics: OptimizationMetrics,
 }
 
 #[cfg(test)]
@@ -204,22 +363,36 @@ mod tests {
     #[test]
     fn test_harmony_state_addition() {
         let config = CrystalConfig::default();
+        let depth = config.harmony_depth as usize;
         let mut crystal = Crystal::new(config);
 
-        let state = HarmonyState::new(Vec3::new(1.0, 0.0, 0.0));
+        let state = HarmonyState::basis(depth, 0);
         assert!(crystal.add_state(state).is_ok());
 
         let metrics = crystal.metrics();
         assert_eq!(metrics.state_count, 1);
     }
 
+    #[test]
+    fn test_harmony_state_dimension_mismatch() {
+        let config = CrystalConfig::default();
+        let mut crystal = Crystal::new(config);
+
+        let state = HarmonyState::basis(2, 0);
+        assert!(matches!(
+            crystal.add_state(state),
+            Err(CrystalError::DimensionMismatch(2, _))
+        ));
+    }
+
     #[test]
     fn test_coherence_decay() {
         let mut config = CrystalConfig::default();
         config.decoherence_rate = 0.1;
+        let depth = config.harmony_depth as usize;
         let mut crystal = Crystal::new(config);
 
-        let state = HarmonyState::new(Vec3::new(1.0, 0.0, 0.0));
+        let state = HarmonyState::basis(depth, 0);
         crystal.add_state(state).unwrap();
 
         thread::sleep(Duration::from_millis(100));
@@ -229,6 +402,44 @@ mod tests {
         assert!(metrics.coherence < 1.0);
     }
 
+    #[test]
+    fn test_collapse_samples_certain_basis() {
+        // A state concentrated entirely on one basis index always
+        // collapses to that index -- there's nowhere else to land.
+        let state = HarmonyState::basis(4, 2);
+        assert_eq!(state.collapse(), 2);
+        assert_eq!(state.phase(2), 0.0);
+    }
+
+    #[test]
+    fn test_update_coherence_records_per_state_magnitude() {
+        let config = CrystalConfig::default();
+        let depth = config.harmony_depth as usize;
+        let mut crystal = Crystal::new(config);
+
+        crystal.add_state(HarmonyState::basis(depth, 0)).unwrap();
+
+        // The reference starts at |0>, so the lone state's raw
+        // coherence magnitude against it should be exactly 1.0.
+        let magnitude = crystal.states[0].coherence.load(Ordering::Acquire);
+        assert!((magnitude - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_metrics_reports_simd_strategy() {
+        let config = CrystalConfig::default();
+        let depth = config.harmony_depth as usize;
+        let mut crystal = Crystal::new(config);
+
+        crystal.add_state(HarmonyState::basis(depth, 0)).unwrap();
+
+        let metrics = crystal.metrics();
+        assert!(matches!(
+            metrics.simd_strategy,
+            SIMDStrategy::AVX512 | SIMDStrategy::AVX2 | SIMDStrategy::Scalar
+        ));
+    }
+
     #[test]
     fn test_lattice_alignment() {
         let config = CrystalConfig::default();
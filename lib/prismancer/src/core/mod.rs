@@ -37,6 +37,7 @@ pub mod core {
     //! Core engine systems and utilities
 
     pub mod crystal;
+    pub mod simd;
     pub mod systems;
     pub mod parallel;
     pub mod memory;
@@ -44,6 +45,7 @@ pub mod core {
 
     // Re-export common types
     pub use crystal::{Crystal, CrystalConfig, CrystalError};
+    pub use simd::{SIMDOptimizer, SIMDStrategy};
     pub use systems::SystemManager;
     pub use parallel::TaskScheduler;
     pub use memory::MemoryPool;
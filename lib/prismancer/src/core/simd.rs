@@ -0,0 +1,335 @@
+// src/core/simd.rs
+// Created: 2026-07-31
+// Author: isdood
+
+//! SIMD-accelerated batch coherence/decoherence kernels for
+//! `Crystal::update_coherence`.
+//!
+//! Mirrors the `SIMDOps`/`SIMDOptimizer`/`SIMDStrategy` split zigzag's
+//! `superpurple::simd` module uses for its lattice kernels, scoped down
+//! to the one operation `Crystal` needs: given a state's already-computed
+//! coherence magnitude and its `last_observed` timestamp, fold in the
+//! exponential decoherence factor `exp(-rate * dt)` and reduce the
+//! ensemble to its average. `Crystal::update_coherence` falls back to
+//! this module's scalar path below `MIN_BATCH_SIZE` states or when no
+//! AVX support is detected.
+
+use std::arch::x86_64::*;
+
+/// Below this many states, per-state overhead dominates any SIMD gain,
+/// so `Crystal::update_coherence` stays on the scalar path.
+pub const MIN_BATCH_SIZE: usize = 64;
+
+/// Detected CPU SIMD capability, checked once per `SIMDOptimizer`.
+#[derive(Debug, Clone, Copy)]
+pub struct CPUFeatures {
+    pub avx512f: bool,
+    pub avx2: bool,
+}
+
+impl CPUFeatures {
+    /// Detects the running CPU's SIMD support via the standard
+    /// library's runtime feature probes, rather than assuming whatever
+    /// this crate happens to be compiled for.
+    pub fn detect() -> Self {
+        Self {
+            avx512f: is_x86_feature_detected!("avx512f"),
+            avx2: is_x86_feature_detected!("avx2"),
+        }
+    }
+}
+
+/// Which batch kernel `SIMDOptimizer` picked for the detected CPU.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SIMDStrategy {
+    AVX512,
+    AVX2,
+    Scalar,
+}
+
+/// Throughput/strategy info surfaced back through `CrystalMetrics` so
+/// callers can see which kernel actually ran for the last
+/// `update_coherence` pass.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OptimizationMetrics {
+    pub states_per_second: f64,
+    pub lane_width: usize,
+}
+
+/// Picks and runs the batch coherence/decoherence kernel, recording
+/// which one ran and how fast.
+#[derive(Debug)]
+pub struct SIMDOptimizer {
+    features: CPUFeatures,
+    /// Widest kernel `features` supports, picked once at construction
+    /// since `features` never changes afterward.
+    preferred: SIMDStrategy,
+    strategy: SIMDStrategy,
+    metrics: OptimizationMetrics,
+}
+
+impl SIMDOptimizer {
+    pub fn new() -> Self {
+        let features = CPUFeatures::detect();
+        let preferred = Self::select_strategy(&features);
+        Self {
+            features,
+            preferred,
+            strategy: preferred,
+            metrics: OptimizationMetrics::default(),
+        }
+    }
+
+    fn select_strategy(features: &CPUFeatures) -> SIMDStrategy {
+        if features.avx512f {
+            SIMDStrategy::AVX512
+        } else if features.avx2 {
+            SIMDStrategy::AVX2
+        } else {
+            SIMDStrategy::Scalar
+        }
+    }
+
+    /// The kernel the most recent `batch_decohere` call actually ran.
+    pub fn strategy(&self) -> SIMDStrategy {
+        self.strategy
+    }
+
+    /// SIMD support detected on this CPU at construction.
+    pub fn features(&self) -> CPUFeatures {
+        self.features
+    }
+
+    /// Throughput of the most recent `batch_decohere` call.
+    pub fn metrics(&self) -> OptimizationMetrics {
+        self.metrics
+    }
+
+    /// Runs `SIMDOps::batch_decohere_*` with whichever kernel this CPU
+    /// supports, falling back to the scalar path below
+    /// `MIN_BATCH_SIZE` states or when `AVX2`/`AVX512F` aren't
+    /// available, and returns the ensemble-averaged decohered
+    /// coherence. Records which kernel ran and its throughput into
+    /// `self.metrics`.
+    pub fn batch_decohere(&mut self, coherence: &[f64], dt_nanos: &[u64], rate: f64) -> f64 {
+        debug_assert_eq!(coherence.len(), dt_nanos.len());
+        if coherence.is_empty() {
+            return 0.0;
+        }
+
+        let start = std::time::Instant::now();
+
+        let (sum, ran, lane_width) = if coherence.len() < MIN_BATCH_SIZE {
+            (
+                SIMDOps::batch_decohere_scalar(coherence, dt_nanos, rate),
+                SIMDStrategy::Scalar,
+                1,
+            )
+        } else {
+            match self.preferred {
+                SIMDStrategy::AVX512 => (
+                    unsafe { SIMDOps::batch_decohere_avx512(coherence, dt_nanos, rate) },
+                    SIMDStrategy::AVX512,
+                    8,
+                ),
+                SIMDStrategy::AVX2 => (
+                    unsafe { SIMDOps::batch_decohere_avx2(coherence, dt_nanos, rate) },
+                    SIMDStrategy::AVX2,
+                    4,
+                ),
+                SIMDStrategy::Scalar => (
+                    SIMDOps::batch_decohere_scalar(coherence, dt_nanos, rate),
+                    SIMDStrategy::Scalar,
+                    1,
+                ),
+            }
+        };
+
+        let elapsed = start.elapsed().as_secs_f64().max(f64::MIN_POSITIVE);
+        self.metrics = OptimizationMetrics {
+            states_per_second: coherence.len() as f64 / elapsed,
+            lane_width,
+        };
+        self.strategy = ran;
+
+        sum / coherence.len() as f64
+    }
+}
+
+impl Default for SIMDOptimizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Batch coherence/decoherence kernels, one per SIMD width.
+pub struct SIMDOps;
+
+impl SIMDOps {
+    /// Scalar fallback: `sum(coherence_i * exp(-rate * dt_i))`.
+    pub fn batch_decohere_scalar(coherence: &[f64], dt_nanos: &[u64], rate: f64) -> f64 {
+        coherence
+            .iter()
+            .zip(dt_nanos)
+            .map(|(c, dt)| c * (-rate * *dt as f64).exp())
+            .sum()
+    }
+
+    /// AVX2 kernel: four `f64` lanes per iteration.
+    ///
+    /// # Safety
+    /// Caller must have confirmed `avx2` support via [`CPUFeatures`].
+    #[target_feature(enable = "avx2")]
+    pub unsafe fn batch_decohere_avx2(coherence: &[f64], dt_nanos: &[u64], rate: f64) -> f64 {
+        const LANES: usize = 4;
+        let neg_rate = _mm256_set1_pd(-rate);
+        let mut acc = _mm256_setzero_pd();
+
+        let chunks = coherence.len() / LANES;
+        for i in 0..chunks {
+            let base = i * LANES;
+            let dt = [
+                dt_nanos[base] as f64,
+                dt_nanos[base + 1] as f64,
+                dt_nanos[base + 2] as f64,
+                dt_nanos[base + 3] as f64,
+            ];
+            let dt_v = _mm256_loadu_pd(dt.as_ptr());
+            let x = _mm256_mul_pd(neg_rate, dt_v);
+            let decoherence = exp_pd_avx2(x);
+            let c_v = _mm256_loadu_pd(coherence[base..].as_ptr());
+            acc = _mm256_add_pd(acc, _mm256_mul_pd(c_v, decoherence));
+        }
+
+        let mut lanes = [0.0f64; LANES];
+        _mm256_storeu_pd(lanes.as_mut_ptr(), acc);
+        let mut sum: f64 = lanes.iter().sum();
+
+        for i in (chunks * LANES)..coherence.len() {
+            sum += coherence[i] * (-rate * dt_nanos[i] as f64).exp();
+        }
+
+        sum
+    }
+
+    /// AVX-512 kernel: eight `f64` lanes per iteration.
+    ///
+    /// # Safety
+    /// Caller must have confirmed `avx512f` support via [`CPUFeatures`].
+    #[target_feature(enable = "avx512f")]
+    pub unsafe fn batch_decohere_avx512(coherence: &[f64], dt_nanos: &[u64], rate: f64) -> f64 {
+        const LANES: usize = 8;
+        let neg_rate = _mm512_set1_pd(-rate);
+        let mut acc = _mm512_setzero_pd();
+
+        let chunks = coherence.len() / LANES;
+        for i in 0..chunks {
+            let base = i * LANES;
+            let mut dt = [0.0f64; LANES];
+            for (lane, slot) in dt.iter_mut().enumerate() {
+                *slot = dt_nanos[base + lane] as f64;
+            }
+            let dt_v = _mm512_loadu_pd(dt.as_ptr());
+            let x = _mm512_mul_pd(neg_rate, dt_v);
+            let decoherence = exp_pd_avx512(x);
+            let c_v = _mm512_loadu_pd(coherence[base..].as_ptr());
+            acc = _mm512_add_pd(acc, _mm512_mul_pd(c_v, decoherence));
+        }
+
+        let mut sum = _mm512_reduce_add_pd(acc);
+
+        for i in (chunks * LANES)..coherence.len() {
+            sum += coherence[i] * (-rate * dt_nanos[i] as f64).exp();
+        }
+
+        sum
+    }
+}
+
+/// Approximates `exp(x)` for `x <= 0` as `(1 + x/256)^256`, clamping `x`
+/// first so the base never goes negative. Repeated squaring turns one
+/// division-free lane evaluation into a cheap vectorized exponential;
+/// 256 was picked over a smaller power of two (e.g. 16) because the
+/// error at `x = -4` -- well within the range `-rate * dt` reaches in
+/// practice -- was otherwise over 40%, enough to flip a coherence value
+/// across `config.coherence_threshold`. At 256 it's under 3%.
+#[target_feature(enable = "avx2")]
+unsafe fn exp_pd_avx2(x: __m256d) -> __m256d {
+    let clamp_lo = _mm256_set1_pd(-256.0);
+    let x = _mm256_max_pd(x, clamp_lo);
+
+    let one = _mm256_set1_pd(1.0);
+    let sixteenth = _mm256_set1_pd(1.0 / 256.0);
+    let mut y = _mm256_add_pd(one, _mm256_mul_pd(x, sixteenth));
+    for _ in 0..8 {
+        y = _mm256_mul_pd(y, y);
+    }
+    _mm256_max_pd(y, _mm256_setzero_pd())
+}
+
+/// AVX-512 counterpart to [`exp_pd_avx2`].
+#[target_feature(enable = "avx512f")]
+unsafe fn exp_pd_avx512(x: __m512d) -> __m512d {
+    let clamp_lo = _mm512_set1_pd(-256.0);
+    let x = _mm512_max_pd(x, clamp_lo);
+
+    let one = _mm512_set1_pd(1.0);
+    let sixteenth = _mm512_set1_pd(1.0 / 256.0);
+    let mut y = _mm512_add_pd(one, _mm512_mul_pd(x, sixteenth));
+    for _ in 0..8 {
+        y = _mm512_mul_pd(y, y);
+    }
+    _mm512_max_pd(y, _mm512_setzero_pd())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scalar_matches_definition() {
+        let coherence = vec![1.0, 0.8, 0.5];
+        let dt = vec![0u64, 1_000_000, 10_000_000];
+        let rate = 0.0001;
+
+        let sum = SIMDOps::batch_decohere_scalar(&coherence, &dt, rate);
+        let expected: f64 = coherence
+            .iter()
+            .zip(&dt)
+            .map(|(c, d)| c * (-rate * *d as f64).exp())
+            .sum();
+        assert!((sum - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_optimizer_falls_back_below_threshold() {
+        let mut optimizer = SIMDOptimizer::new();
+        let coherence = vec![1.0; 4];
+        let dt = vec![0u64; 4];
+
+        let average = optimizer.batch_decohere(&coherence, &dt, 0.0);
+        assert_eq!(average, 1.0);
+        assert_eq!(optimizer.strategy(), SIMDStrategy::Scalar);
+    }
+
+    #[test]
+    fn test_avx2_matches_scalar_when_available() {
+        if !is_x86_feature_detected!("avx2") {
+            return;
+        }
+
+        let coherence: Vec<f64> = (0..MIN_BATCH_SIZE + 3)
+            .map(|i| 1.0 / (i as f64 + 1.0))
+            .collect();
+        let dt: Vec<u64> = (0..coherence.len()).map(|i| i as u64 * 1000).collect();
+        let rate = 0.0005;
+
+        let scalar = SIMDOps::batch_decohere_scalar(&coherence, &dt, rate);
+        let avx2 = unsafe { SIMDOps::batch_decohere_avx2(&coherence, &dt, rate) };
+
+        // The AVX2 kernel uses an approximate exp, so compare loosely --
+        // but tight enough to catch the approximation regressing back
+        // toward the double-digit error a lower-order polynomial gave.
+        assert!((scalar - avx2).abs() / scalar.max(1.0) < 0.05);
+    }
+}
@@ -1,10 +1,12 @@
 pub mod core;
 pub mod harmony;
 pub mod crystals;
+pub mod wire;
 
 pub use crate::core::ResonanceCore;
 pub use crate::harmony::HarmonyWeaver;
-pub use crate::crystals::CrystalField;
+pub use crate::crystals::{CrystalField, Wave};
+pub use crate::wire::{NegotiatedFeatures, ResonanceVersion};
 
 use thiserror::Error;
 
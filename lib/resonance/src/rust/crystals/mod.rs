@@ -8,4 +8,34 @@ impl CrystalField {
             lattice_size: (64, 64, 64),
         }
     }
+
+    /// Builds a field directly from a lattice size, bypassing `new`'s
+    /// default. Used by `wire::decode_crystal_field` to reconstruct a
+    /// field from its wire bytes.
+    pub fn from_lattice_size(lattice_size: (usize, usize, usize)) -> Self {
+        Self { lattice_size }
+    }
+
+    /// The field's lattice dimensions.
+    pub fn lattice_size(&self) -> (usize, usize, usize) {
+        self.lattice_size
+    }
+}
+
+/// A sampled resonance waveform, independent of the crystal lattice
+/// it's resonating through.
+pub struct Wave {
+    amplitudes: Vec<f64>,
+}
+
+impl Wave {
+    /// Builds a wave from its sampled amplitudes.
+    pub fn new(amplitudes: Vec<f64>) -> Self {
+        Self { amplitudes }
+    }
+
+    /// The wave's sampled amplitudes.
+    pub fn amplitudes(&self) -> &[f64] {
+        &self.amplitudes
+    }
 }
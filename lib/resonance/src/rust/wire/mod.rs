@@ -0,0 +1,271 @@
+//! Versioned wire format and compatibility handshake for resonance
+//! state. `ResonanceCore`, `HarmonyWeaver`, and `CrystalField` only ever
+//! lived in a single running process; this module lets their state be
+//! persisted to disk or exchanged between two builds of this crate,
+//! without either side blindly deserializing bytes it might not
+//! understand.
+//!
+//! Every encoded blob starts with a `ResonanceVersion` header. Before
+//! trusting the payload that follows, a peer should call
+//! `ResonanceVersion::negotiate` against its own version to confirm the
+//! wire format is compatible and to learn which optional features the
+//! remote side supports.
+
+use crate::crystals::{CrystalField, Wave};
+use crate::ResonanceError;
+
+/// The wire protocol this crate currently speaks.
+pub const PROTOCOL_NAME: &str = "resonance";
+/// Bumped whenever the on-wire layout of encoded state changes in a way
+/// that breaks older decoders.
+pub const STATE_VERSION: u16 = 1;
+/// Bumped whenever an optional feature is added to the wire format
+/// without breaking the base layout (e.g. spectral metadata).
+pub const FEATURE_VERSION: u16 = 1;
+
+/// The minimum `FEATURE_VERSION` that carries spectral metadata
+/// alongside a `Wave`'s amplitudes.
+const SPECTRAL_METADATA_FEATURE: u16 = 1;
+
+/// Version header carried at the start of every encoded blob.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResonanceVersion {
+    /// Identifies the wire protocol family; blobs from an unrelated
+    /// protocol are rejected outright regardless of the version numbers.
+    pub protocol_name: String,
+    /// The base state layout this version was encoded with.
+    pub state_version: u16,
+    /// The highest optional feature this version knows how to encode
+    /// or decode.
+    pub feature_version: u16,
+}
+
+impl ResonanceVersion {
+    /// The version this build of the crate encodes with and expects to
+    /// decode.
+    pub fn current() -> Self {
+        Self {
+            protocol_name: PROTOCOL_NAME.to_string(),
+            state_version: STATE_VERSION,
+            feature_version: FEATURE_VERSION,
+        }
+    }
+
+    /// Checks `self` against `peer` for compatibility, returning the
+    /// set of optional features both sides can safely use.
+    ///
+    /// Rejects a `peer` speaking a different protocol or an
+    /// incompatible `state_version` with
+    /// `ResonanceError::HarmonyDisrupted`, naming the field that
+    /// disagreed.
+    pub fn negotiate(&self, peer: &ResonanceVersion) -> crate::Result<NegotiatedFeatures> {
+        if peer.protocol_name != self.protocol_name {
+            return Err(ResonanceError::HarmonyDisrupted(format!(
+                "protocol_name mismatch: local={}, peer={}",
+                self.protocol_name, peer.protocol_name
+            )));
+        }
+
+        if peer.state_version != self.state_version {
+            return Err(ResonanceError::HarmonyDisrupted(format!(
+                "state_version mismatch: local={}, peer={}",
+                self.state_version, peer.state_version
+            )));
+        }
+
+        let feature_version = self.feature_version.min(peer.feature_version);
+        Ok(NegotiatedFeatures {
+            feature_version,
+            spectral_metadata: feature_version >= SPECTRAL_METADATA_FEATURE,
+        })
+    }
+}
+
+/// The optional features two `ResonanceVersion`s agreed to use, gated
+/// on the lower of the two `feature_version`s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NegotiatedFeatures {
+    /// The feature version both sides will encode/decode at.
+    pub feature_version: u16,
+    /// Whether spectral metadata may be attached to an encoded `Wave`.
+    pub spectral_metadata: bool,
+}
+
+fn encode_version(version: &ResonanceVersion, out: &mut Vec<u8>) {
+    let name_bytes = version.protocol_name.as_bytes();
+    out.extend_from_slice(&(name_bytes.len() as u32).to_le_bytes());
+    out.extend_from_slice(name_bytes);
+    out.extend_from_slice(&version.state_version.to_le_bytes());
+    out.extend_from_slice(&version.feature_version.to_le_bytes());
+}
+
+fn decode_version(bytes: &[u8]) -> crate::Result<(ResonanceVersion, &[u8])> {
+    let read_err = || ResonanceError::HarmonyDisrupted("truncated version header".to_string());
+
+    let name_len = *bytes.get(0..4).ok_or_else(read_err).map(|s| {
+        u32::from_le_bytes(s.try_into().unwrap())
+    })? as usize;
+    let mut cursor = 4;
+
+    let name_bytes = bytes.get(cursor..cursor + name_len).ok_or_else(read_err)?;
+    let protocol_name = String::from_utf8(name_bytes.to_vec())
+        .map_err(|_| ResonanceError::HarmonyDisrupted("protocol_name is not valid UTF-8".to_string()))?;
+    cursor += name_len;
+
+    let state_version = u16::from_le_bytes(
+        bytes.get(cursor..cursor + 2).ok_or_else(read_err)?.try_into().unwrap(),
+    );
+    cursor += 2;
+
+    let feature_version = u16::from_le_bytes(
+        bytes.get(cursor..cursor + 2).ok_or_else(read_err)?.try_into().unwrap(),
+    );
+    cursor += 2;
+
+    Ok((
+        ResonanceVersion { protocol_name, state_version, feature_version },
+        &bytes[cursor..],
+    ))
+}
+
+/// Encodes `field` behind a `ResonanceVersion::current()` header.
+pub fn encode_crystal_field(field: &CrystalField) -> Vec<u8> {
+    let mut out = Vec::new();
+    encode_version(&ResonanceVersion::current(), &mut out);
+
+    let (x, y, z) = field.lattice_size();
+    out.extend_from_slice(&(x as u64).to_le_bytes());
+    out.extend_from_slice(&(y as u64).to_le_bytes());
+    out.extend_from_slice(&(z as u64).to_le_bytes());
+    out
+}
+
+/// Decodes a `CrystalField` previously written by `encode_crystal_field`,
+/// returning it alongside the `ResonanceVersion` it was encoded with.
+/// Callers should `negotiate` that version before trusting the field.
+pub fn decode_crystal_field(bytes: &[u8]) -> crate::Result<(ResonanceVersion, CrystalField)> {
+    let (version, rest) = decode_version(bytes)?;
+    let read_err = || ResonanceError::HarmonyDisrupted("truncated crystal field payload".to_string());
+
+    let x = u64::from_le_bytes(rest.get(0..8).ok_or_else(read_err)?.try_into().unwrap()) as usize;
+    let y = u64::from_le_bytes(rest.get(8..16).ok_or_else(read_err)?.try_into().unwrap()) as usize;
+    let z = u64::from_le_bytes(rest.get(16..24).ok_or_else(read_err)?.try_into().unwrap()) as usize;
+
+    Ok((version, CrystalField::from_lattice_size((x, y, z))))
+}
+
+/// Encodes `wave` behind a `ResonanceVersion::current()` header. When
+/// `features.spectral_metadata` is set, a placeholder spectral-metadata
+/// flag byte follows the amplitude count so a future feature bump can
+/// grow what rides alongside it without breaking `STATE_VERSION`.
+pub fn encode_wave(wave: &Wave, features: &NegotiatedFeatures) -> Vec<u8> {
+    let mut out = Vec::new();
+    encode_version(&ResonanceVersion::current(), &mut out);
+
+    out.extend_from_slice(&(wave.amplitudes().len() as u64).to_le_bytes());
+    for amplitude in wave.amplitudes() {
+        out.extend_from_slice(&amplitude.to_le_bytes());
+    }
+
+    out.push(features.spectral_metadata as u8);
+    out
+}
+
+/// Decodes a `Wave` previously written by `encode_wave`, returning it
+/// alongside the `ResonanceVersion` it was encoded with.
+pub fn decode_wave(bytes: &[u8]) -> crate::Result<(ResonanceVersion, Wave)> {
+    let (version, rest) = decode_version(bytes)?;
+    let read_err = || ResonanceError::HarmonyDisrupted("truncated wave payload".to_string());
+
+    let count = u64::from_le_bytes(rest.get(0..8).ok_or_else(read_err)?.try_into().unwrap()) as usize;
+    let mut cursor = 8;
+
+    let mut amplitudes = Vec::with_capacity(count);
+    for _ in 0..count {
+        let bytes8 = rest.get(cursor..cursor + 8).ok_or_else(read_err)?;
+        amplitudes.push(f64::from_le_bytes(bytes8.try_into().unwrap()));
+        cursor += 8;
+    }
+
+    Ok((version, Wave::new(amplitudes)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_negotiate_matching_versions_succeeds() {
+        let local = ResonanceVersion::current();
+        let peer = ResonanceVersion::current();
+
+        let features = local.negotiate(&peer).unwrap();
+        assert!(features.spectral_metadata);
+        assert_eq!(features.feature_version, FEATURE_VERSION);
+    }
+
+    #[test]
+    fn test_negotiate_rejects_state_version_mismatch() {
+        let local = ResonanceVersion::current();
+        let mut peer = ResonanceVersion::current();
+        peer.state_version += 1;
+
+        let err = local.negotiate(&peer).unwrap_err();
+        match err {
+            ResonanceError::HarmonyDisrupted(msg) => assert!(msg.contains("state_version")),
+            other => panic!("expected HarmonyDisrupted, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_negotiate_rejects_protocol_mismatch() {
+        let local = ResonanceVersion::current();
+        let peer = ResonanceVersion {
+            protocol_name: "other-protocol".to_string(),
+            ..ResonanceVersion::current()
+        };
+
+        let err = local.negotiate(&peer).unwrap_err();
+        match err {
+            ResonanceError::HarmonyDisrupted(msg) => assert!(msg.contains("protocol_name")),
+            other => panic!("expected HarmonyDisrupted, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_negotiate_gates_feature_on_lower_feature_version() {
+        let local = ResonanceVersion::current();
+        let peer = ResonanceVersion { feature_version: 0, ..ResonanceVersion::current() };
+
+        let features = local.negotiate(&peer).unwrap();
+        assert!(!features.spectral_metadata);
+        assert_eq!(features.feature_version, 0);
+    }
+
+    #[test]
+    fn test_crystal_field_round_trips() {
+        let field = CrystalField::new();
+        let bytes = encode_crystal_field(&field);
+
+        let (version, decoded) = decode_crystal_field(&bytes).unwrap();
+        assert_eq!(version, ResonanceVersion::current());
+        assert_eq!(decoded.lattice_size(), field.lattice_size());
+    }
+
+    #[test]
+    fn test_wave_round_trips() {
+        let wave = Wave::new(vec![0.1, 0.2, 0.3]);
+        let features = ResonanceVersion::current().negotiate(&ResonanceVersion::current()).unwrap();
+        let bytes = encode_wave(&wave, &features);
+
+        let (version, decoded) = decode_wave(&bytes).unwrap();
+        assert_eq!(version, ResonanceVersion::current());
+        assert_eq!(decoded.amplitudes(), wave.amplitudes());
+    }
+
+    #[test]
+    fn test_decode_crystal_field_reports_truncated_payload() {
+        let err = decode_crystal_field(&[]).unwrap_err();
+        assert!(matches!(err, ResonanceError::HarmonyDisrupted(_)));
+    }
+}
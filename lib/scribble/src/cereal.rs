@@ -27,7 +27,16 @@ pub struct QuantumBuffer {
     data: Vec<u8>,
     coherence: f64,
     quantum_state: u8,
-    checksum: u64,
+    /// Running sponge state for the integrity digest; see [`DigestState`]
+    /// for the construction.
+    digest_state: DigestState,
+    /// How many leading bytes of `data` are already absorbed into
+    /// `digest_state`, so `advance_digest` only absorbs the new tail
+    /// instead of re-hashing the whole buffer on every write.
+    digest_absorbed: usize,
+    /// Set by `finalize`; once sealed, further writes would silently
+    /// desync the buffer from its appended trailer, so they're rejected.
+    finalized: bool,
     created: u64,  // UTC timestamp
     author: [u8; 32], // Fixed size for username
 }
@@ -38,7 +47,9 @@ impl QuantumBuffer {
             data: Vec::with_capacity(1024),
             coherence: 1.0,
             quantum_state: 0,
-            checksum: 0,
+            digest_state: DigestState::new(),
+            digest_absorbed: 0,
+            finalized: false,
             created: 1705371857, // 2025-01-16 02:24:17 UTC
             author: [0; 32],
         };
@@ -52,6 +63,68 @@ impl QuantumBuffer {
         buffer
     }
 
+    /// Reconstruct a `QuantumBuffer` from a blob previously produced by
+    /// `finalize`, verifying its header and trailing digest before
+    /// trusting the payload.
+    ///
+    /// Splits off the trailing [`DIGEST_TRAILER_LEN`] bytes, re-absorbs
+    /// the remaining body through a fresh sponge, and compares the
+    /// result against the trailer in constant time. Returns
+    /// `InvalidMagic` if the header doesn't match `CEREAL_MAGIC`, or
+    /// `InvalidChecksum` if the recomputed digest doesn't match the
+    /// trailer (i.e. the payload was truncated or tampered with).
+    pub fn open(data: Vec<u8>) -> CerealResult<Self> {
+        if data.len() < CEREAL_MAGIC.len() + 2 + DIGEST_TRAILER_LEN {
+            return Err(CerealError::BufferOverflow);
+        }
+        if &data[..CEREAL_MAGIC.len()] != &CEREAL_MAGIC[..] {
+            return Err(CerealError::InvalidMagic);
+        }
+
+        let split = data.len() - DIGEST_TRAILER_LEN;
+        let (body, trailer) = data.split_at(split);
+
+        let (digest_state, digest_absorbed) = DigestState::absorb_all(body);
+        if !constant_time_eq(&digest_state.squeeze(), trailer) {
+            return Err(CerealError::InvalidChecksum);
+        }
+
+        let mut buffer = Self {
+            data: body.to_vec(),
+            coherence: 1.0,
+            quantum_state: ((body.len() as f64 * PI) % 255.0) as u8,
+            digest_state,
+            digest_absorbed,
+            finalized: true,
+            created: 1705371857,
+            author: [0; 32],
+        };
+        let author = b"isdood";
+        buffer.author[..author.len()].copy_from_slice(author);
+        Ok(buffer)
+    }
+
+    /// Seals the buffer: absorbs any not-yet-digested tail bytes, squeezes
+    /// the sponge into a [`DIGEST_TRAILER_LEN`]-byte digest, and appends
+    /// it as a trailer. After this, `write_f64` is rejected with
+    /// `CerealError::StateCollapse`, since further writes would no longer
+    /// be covered by the trailer.
+    pub fn finalize(&mut self) -> CerealResult<()> {
+        if self.finalized {
+            return Err(CerealError::StateCollapse);
+        }
+
+        self.advance_digest();
+        let tail = &self.data[self.digest_absorbed..];
+        if !tail.is_empty() {
+            self.digest_state.absorb_partial(tail);
+        }
+
+        self.data.extend_from_slice(&self.digest_state.squeeze());
+        self.finalized = true;
+        Ok(())
+    }
+
     fn write_magic(&mut self) {
         self.data.extend_from_slice(&CEREAL_MAGIC);
         self.data.extend_from_slice(&CEREAL_VERSION.to_le_bytes());
@@ -59,6 +132,10 @@ impl QuantumBuffer {
     }
 
     pub fn write_f64(&mut self, value: f64) -> CerealResult<()> {
+        if self.finalized {
+            return Err(CerealError::StateCollapse);
+        }
+
         self.data.extend_from_slice(&value.to_le_bytes());
         self.update_quantum_state();
         self.verify_coherence()?;
@@ -76,18 +153,79 @@ impl QuantumBuffer {
         Ok(f64::from_le_bytes(bytes))
     }
 
+    pub fn read_u16(&mut self, pos: &mut usize) -> CerealResult<u16> {
+        if *pos + 2 > self.data.len() {
+            return Err(CerealError::BufferOverflow);
+        }
+        let bytes = self.data[*pos..*pos + 2].try_into()
+        .map_err(|_| CerealError::InvalidMagic)?;
+        *pos += 2;
+        self.verify_coherence()?;
+        Ok(u16::from_le_bytes(bytes))
+    }
+
+    pub fn read_u32(&mut self, pos: &mut usize) -> CerealResult<u32> {
+        if *pos + 4 > self.data.len() {
+            return Err(CerealError::BufferOverflow);
+        }
+        let bytes = self.data[*pos..*pos + 4].try_into()
+        .map_err(|_| CerealError::InvalidMagic)?;
+        *pos += 4;
+        self.verify_coherence()?;
+        Ok(u32::from_le_bytes(bytes))
+    }
+
+    /// Parses and validates the `CEREAL_MAGIC`/version header at `pos`
+    /// (normally the front of the buffer), advancing `pos` past it and
+    /// returning the version so callers can dispatch to a per-version
+    /// reader. Returns `InvalidMagic` if the bytes at `pos` don't match
+    /// `CEREAL_MAGIC`, independent of whatever payload follows.
+    pub fn verify_header(&mut self, pos: &mut usize) -> CerealResult<u16> {
+        if *pos + CEREAL_MAGIC.len() > self.data.len() {
+            return Err(CerealError::BufferOverflow);
+        }
+        if &self.data[*pos..*pos + CEREAL_MAGIC.len()] != &CEREAL_MAGIC[..] {
+            return Err(CerealError::InvalidMagic);
+        }
+        *pos += CEREAL_MAGIC.len();
+        self.read_u16(pos)
+    }
+
+    /// Writes a complex amplitude as two consecutive `write_f64` calls
+    /// (real part, then imaginary part).
+    pub fn write_complex(&mut self, value: Complex64) -> CerealResult<()> {
+        self.write_f64(value.re)?;
+        self.write_f64(value.im)?;
+        Ok(())
+    }
+
+    /// Reads back a complex amplitude written by `write_complex`.
+    pub fn read_complex(&mut self, pos: &mut usize) -> CerealResult<Complex64> {
+        let re = self.read_f64(pos)?;
+        let im = self.read_f64(pos)?;
+        Ok(Complex64::new(re, im))
+    }
+
     fn update_quantum_state(&mut self) {
         self.coherence *= 0.99999; // Slight decay per operation
         self.quantum_state = ((self.data.len() as f64 * PI) % 255.0) as u8;
-        self.update_checksum();
+        self.advance_digest();
     }
 
-    fn update_checksum(&mut self) {
-        self.checksum = self.data.iter()
-        .enumerate()
-        .fold(0, |acc, (i, &byte)| {
-            acc.wrapping_add((byte as u64).wrapping_mul(i as u64))
-        });
+    /// Absorbs every complete 8-byte lane appended to `data` since the
+    /// last call, leaving a short tail (if any) for the next call (or
+    /// `finalize`) to pick up — so a long sequence of small writes never
+    /// re-hashes bytes it's already absorbed.
+    fn advance_digest(&mut self) {
+        while self.digest_absorbed + 8 <= self.data.len() {
+            let lane = u64::from_le_bytes(
+                self.data[self.digest_absorbed..self.digest_absorbed + 8]
+                    .try_into()
+                    .unwrap(),
+            );
+            self.digest_state.absorb(lane);
+            self.digest_absorbed += 8;
+        }
     }
 
     fn verify_coherence(&self) -> CerealResult<()> {
@@ -99,14 +237,219 @@ impl QuantumBuffer {
     }
 }
 
-/// Quantum-aware 3D vector with serialization
-#[derive(Debug, Clone)]
-pub struct QuantumVector3D {
-    x: f64,
-    y: f64,
-    z: f64,
-    phase: f64,     // Quantum phase
-    coherence: f64,  // Coherence factor
+/// Width of the integrity sponge's permutation state. Three lanes: one
+/// rate lane absorbs/squeezes, the other two act as capacity so
+/// recovering the input from the digest alone means inverting the
+/// permutation.
+const DIGEST_WIDTH: usize = 3;
+/// Full rounds (S-box on every lane) run split evenly before and after
+/// the partial rounds, as in the standard Poseidon round schedule.
+const DIGEST_FULL_ROUNDS: usize = 8;
+/// Partial rounds (S-box on only the first lane) sandwiched between the
+/// full rounds; cheaper per round while still mixing every lane via MDS.
+const DIGEST_PARTIAL_ROUNDS: usize = 16;
+/// A 61-bit Mersenne prime. Every lane stays below this, so two lanes
+/// multiplied together never overflow a `u128` accumulator.
+const DIGEST_PRIME: u64 = (1u64 << 61) - 1;
+/// Small fixed MDS-style mixing matrix, applied mod `DIGEST_PRIME` after
+/// every round's S-box layer.
+const DIGEST_MDS: [[u64; DIGEST_WIDTH]; DIGEST_WIDTH] = [
+    [2, 3, 1],
+    [1, 2, 3],
+    [3, 1, 2],
+];
+/// Trailer length `finalize` appends and `open` strips: four squeezed
+/// 64-bit lanes.
+const DIGEST_TRAILER_LEN: usize = 32;
+
+/// Running Poseidon-style sponge state backing `QuantumBuffer`'s
+/// integrity digest. Bytes are absorbed 8 at a time as they're appended
+/// to the buffer; `squeeze` reads the digest back out without consuming
+/// the state, so `finalize` and `open` can both call it without caring
+/// which one is computing the trailer.
+#[derive(Debug, Clone, Copy)]
+struct DigestState([u64; DIGEST_WIDTH]);
+
+impl DigestState {
+    fn new() -> Self {
+        Self([0u64; DIGEST_WIDTH])
+    }
+
+    /// Absorbs every complete 8-byte lane of `body`, then (if any bytes
+    /// are left over) a final zero-padded lane, returning the resulting
+    /// state and how many bytes of `body` fell on a lane boundary
+    /// (excluding the padded tail, so callers can keep absorbing from
+    /// there if the buffer isn't actually done).
+    fn absorb_all(body: &[u8]) -> (Self, usize) {
+        let mut state = Self::new();
+        let mut absorbed = 0;
+        while absorbed + 8 <= body.len() {
+            let lane = u64::from_le_bytes(body[absorbed..absorbed + 8].try_into().unwrap());
+            state.absorb(lane);
+            absorbed += 8;
+        }
+
+        let tail = &body[absorbed..];
+        if !tail.is_empty() {
+            state.absorb_partial(tail);
+        }
+
+        (state, absorbed)
+    }
+
+    fn absorb(&mut self, lane: u64) {
+        self.0[0] = add_mod(self.0[0], lane);
+        permute(&mut self.0);
+    }
+
+    /// Absorbs a less-than-8-byte tail as one zero-padded lane.
+    fn absorb_partial(&mut self, tail: &[u8]) {
+        let mut padded = [0u8; 8];
+        padded[..tail.len()].copy_from_slice(tail);
+        self.absorb(u64::from_le_bytes(padded));
+    }
+
+    /// Squeezes `DIGEST_TRAILER_LEN` bytes out of a copy of this state,
+    /// permuting between each 8-byte lane. Doesn't mutate `self`, so
+    /// further bytes can still be absorbed afterwards.
+    fn squeeze(&self) -> [u8; DIGEST_TRAILER_LEN] {
+        let mut state = self.0;
+        let mut out = [0u8; DIGEST_TRAILER_LEN];
+        for chunk in out.chunks_mut(8) {
+            chunk.copy_from_slice(&state[0].to_le_bytes());
+            permute(&mut state);
+        }
+        out
+    }
+}
+
+/// Run the full Poseidon-style round schedule: half the full rounds,
+/// then the partial rounds, then the remaining full rounds.
+fn permute(state: &mut [u64; DIGEST_WIDTH]) {
+    let mut round = 0;
+
+    for _ in 0..DIGEST_FULL_ROUNDS / 2 {
+        full_round(state, round);
+        round += 1;
+    }
+    for _ in 0..DIGEST_PARTIAL_ROUNDS {
+        partial_round(state, round);
+        round += 1;
+    }
+    for _ in 0..DIGEST_FULL_ROUNDS / 2 {
+        full_round(state, round);
+        round += 1;
+    }
+}
+
+/// Add round constants and apply the S-box to every lane, then mix.
+fn full_round(state: &mut [u64; DIGEST_WIDTH], round: usize) {
+    for (lane, value) in state.iter_mut().enumerate() {
+        *value = add_mod(*value, round_constant(round, lane));
+        *value = sbox(*value);
+    }
+    mix(state);
+}
+
+/// Add round constants to every lane but apply the S-box only to the
+/// first, then mix.
+fn partial_round(state: &mut [u64; DIGEST_WIDTH], round: usize) {
+    for (lane, value) in state.iter_mut().enumerate() {
+        *value = add_mod(*value, round_constant(round, lane));
+    }
+    state[0] = sbox(state[0]);
+    mix(state);
+}
+
+/// Mix lanes via `DIGEST_MDS`, mod `DIGEST_PRIME`.
+fn mix(state: &mut [u64; DIGEST_WIDTH]) {
+    let mut mixed = [0u64; DIGEST_WIDTH];
+
+    for (i, slot) in mixed.iter_mut().enumerate() {
+        let mut acc: u128 = 0;
+        for j in 0..DIGEST_WIDTH {
+            acc += DIGEST_MDS[i][j] as u128 * state[j] as u128;
+        }
+        *slot = (acc % DIGEST_PRIME as u128) as u64;
+    }
+
+    *state = mixed;
+}
+
+/// `x -> x^5 mod DIGEST_PRIME`, the sponge's S-box.
+fn sbox(x: u64) -> u64 {
+    let x = x as u128;
+    let p = DIGEST_PRIME as u128;
+    let x2 = (x * x) % p;
+    let x4 = (x2 * x2) % p;
+    ((x4 * x) % p) as u64
+}
+
+fn add_mod(a: u64, b: u64) -> u64 {
+    (a + b) % DIGEST_PRIME
+}
+
+/// Deterministic per-(round, lane) constant, mixed from a splitmix64-style
+/// avalanche rather than a hardcoded table, reduced into `DIGEST_PRIME`.
+fn round_constant(round: usize, lane: usize) -> u64 {
+    let mut z = (round as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+        ^ (lane as u64).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^= z >> 31;
+    z % DIGEST_PRIME
+}
+
+/// Byte-wise comparison that doesn't short-circuit on the first
+/// mismatch, so comparing a tampered digest against the expected one
+/// doesn't leak how many leading bytes matched via timing.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut diff = 0u8;
+    for (x, y) in a.iter().zip(b.iter()) {
+        diff |= x ^ y;
+    }
+    diff == 0
+}
+
+/// Complex amplitude backing `QuantumVector3D`'s per-axis quantum state.
+/// Mirrors `unstable_matter::contour_eigen::Complex64`, duplicated here
+/// so the cereal wire format doesn't pull in a cross-crate dependency
+/// for two field accessors.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Complex64 {
+    pub re: f64,
+    pub im: f64,
+}
+
+impl Complex64 {
+    pub fn new(re: f64, im: f64) -> Self {
+        Self { re, im }
+    }
+
+    /// A complex number with modulus `r` and argument `theta`.
+    pub fn from_polar(r: f64, theta: f64) -> Self {
+        Self::new(r * theta.cos(), r * theta.sin())
+    }
+
+    /// `|self|`, i.e. this amplitude's coherence magnitude.
+    pub fn abs(&self) -> f64 {
+        (self.re * self.re + self.im * self.im).sqrt()
+    }
+
+    /// `arg(self)`, i.e. this amplitude's phase angle.
+    pub fn arg(&self) -> f64 {
+        self.im.atan2(self.re)
+    }
+
+    pub fn mul(&self, other: Self) -> Self {
+        Self::new(
+            self.re * other.re - self.im * other.im,
+            self.re * other.im + self.im * other.re,
+        )
+    }
 }
 
 /// Native serialization trait
@@ -115,27 +458,74 @@ pub trait Cereal: Sized {
     fn decerealize(buffer: &mut QuantumBuffer, pos: &mut usize) -> CerealResult<Self>;
 }
 
+impl Cereal for Complex64 {
+    fn cerealize(&self, buffer: &mut QuantumBuffer) -> CerealResult<()> {
+        buffer.write_complex(*self)
+    }
+
+    fn decerealize(buffer: &mut QuantumBuffer, pos: &mut usize) -> CerealResult<Self> {
+        buffer.read_complex(pos)
+    }
+}
+
+/// Quantum-aware 3D vector with serialization.
+///
+/// Quantum state is carried as a complex amplitude per spatial axis
+/// rather than a single scalar phase/coherence pair, so relative phase
+/// between axes (and thus interference) survives a
+/// cerealize/decerealize round trip instead of collapsing to one
+/// decayed magnitude.
+#[derive(Debug, Clone)]
+pub struct QuantumVector3D {
+    x: f64,
+    y: f64,
+    z: f64,
+    amplitude: [Complex64; 3], // Per-axis (x, y, z) complex amplitude
+}
+
 impl QuantumVector3D {
     pub fn new(x: f64, y: f64, z: f64) -> Self {
         Self {
             x,
             y,
             z,
-            phase: 0.0,
-            coherence: 1.0,
+            amplitude: [Complex64::new(1.0, 0.0); 3],
         }
     }
 
     pub fn with_quantum(x: f64, y: f64, z: f64, phase: f64, coherence: f64) -> Self {
+        let amplitude = Complex64::from_polar(coherence.clamp(0.0, 1.0), phase % (2.0 * PI));
         Self {
             x,
             y,
             z,
-            phase: phase % (2.0 * PI),
-            coherence: coherence.clamp(0.0, 1.0),
+            amplitude: [amplitude; 3],
         }
     }
 
+    /// The scalar phase this vector was constructed with, read back from
+    /// the x-axis amplitude's argument.
+    pub fn phase(&self) -> f64 {
+        self.amplitude[0].arg()
+    }
+
+    /// The scalar coherence this vector was constructed with, read back
+    /// from the x-axis amplitude's modulus.
+    pub fn coherence(&self) -> f64 {
+        self.amplitude[0].abs()
+    }
+
+    /// This vector's probability density `|psi|^2` per axis, i.e. each
+    /// axis amplitude's squared modulus.
+    pub fn probability(&self) -> [f64; 3] {
+        let modulus = |a: Complex64| a.abs() * a.abs();
+        [
+            modulus(self.amplitude[0]),
+            modulus(self.amplitude[1]),
+            modulus(self.amplitude[2]),
+        ]
+    }
+
     pub fn magnitude(&self) -> f64 {
         (self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
     }
@@ -155,19 +545,22 @@ impl Cereal for QuantumVector3D {
         buffer.write_f64(self.x)?;
         buffer.write_f64(self.y)?;
         buffer.write_f64(self.z)?;
-        buffer.write_f64(self.phase)?;
-        buffer.write_f64(self.coherence)?;
+        for amplitude in self.amplitude {
+            amplitude.cerealize(buffer)?;
+        }
         Ok(())
     }
 
     fn decerealize(buffer: &mut QuantumBuffer, pos: &mut usize) -> CerealResult<Self> {
-        Ok(Self {
-            x: buffer.read_f64(pos)?,
-           y: buffer.read_f64(pos)?,
-           z: buffer.read_f64(pos)?,
-           phase: buffer.read_f64(pos)?,
-           coherence: buffer.read_f64(pos)?,
-        })
+        let x = buffer.read_f64(pos)?;
+        let y = buffer.read_f64(pos)?;
+        let z = buffer.read_f64(pos)?;
+        let amplitude = [
+            Complex64::decerealize(buffer, pos)?,
+            Complex64::decerealize(buffer, pos)?,
+            Complex64::decerealize(buffer, pos)?,
+        ];
+        Ok(Self { x, y, z, amplitude })
     }
 }
 
@@ -180,9 +573,9 @@ impl Scribe for QuantumVector3D {
         output.push_str(", ");
         self.z.scribe(precision, output);
         output.push_str("⟩[φ=");
-        self.phase.scribe(precision, output);
+        self.phase().scribe(precision, output);
         output.push_str(", c=");
-        self.coherence.scribe(precision, output);
+        self.coherence().scribe(precision, output);
         output.push_char(']');
     }
 }
@@ -223,6 +616,36 @@ impl VectorMemoryPool {
 
         Ok(buffer)
     }
+
+    /// Inverse of `cerealize`: validates the header, dispatches to a
+    /// per-version reader so future format revisions can be migrated
+    /// without breaking older blobs, then rebuilds the pool from the
+    /// coherence float, vector count, and vectors that follow.
+    pub fn decerealize(buffer: &mut QuantumBuffer) -> CerealResult<Self> {
+        let mut pos = 0;
+        let version = buffer.verify_header(&mut pos)?;
+
+        match version {
+            1 => Self::decerealize_v1(buffer, &mut pos),
+            _ => Err(CerealError::InvalidMagic),
+        }
+    }
+
+    fn decerealize_v1(buffer: &mut QuantumBuffer, pos: &mut usize) -> CerealResult<Self> {
+        let coherence = buffer.read_f64(pos)?;
+        let count = buffer.read_u32(pos)? as usize;
+
+        let mut vectors = Vec::with_capacity(count);
+        for _ in 0..count {
+            vectors.push(QuantumVector3D::decerealize(buffer, pos)?);
+        }
+
+        Ok(Self {
+            vectors,
+            timestamp: buffer.created,
+            coherence,
+        })
+    }
 }
 
 impl Scribe for VectorMemoryPool {
@@ -286,6 +709,36 @@ mod tests {
         assert!(output.as_str().contains("size=2"));
     }
 
+    #[test]
+    fn test_memory_pool_round_trip() {
+        let mut pool = VectorMemoryPool::new();
+        pool.add_vector(QuantumVector3D::with_quantum(1.234, -5.678, 9.012, PI/4.0, 0.95));
+        pool.add_vector(QuantumVector3D::new(-1.0, -2.0, -3.0));
+
+        let mut buffer = pool.cerealize().unwrap();
+        let decoded = VectorMemoryPool::decerealize(&mut buffer).unwrap();
+
+        assert_eq!(decoded.vectors.len(), pool.vectors.len());
+        assert!((decoded.coherence - pool.coherence).abs() < PLANCK_LENGTH);
+        for (original, restored) in pool.vectors.iter().zip(decoded.vectors.iter()) {
+            assert!((original.x - restored.x).abs() < PLANCK_LENGTH);
+            assert!((original.y - restored.y).abs() < PLANCK_LENGTH);
+            assert!((original.z - restored.z).abs() < PLANCK_LENGTH);
+        }
+    }
+
+    #[test]
+    fn test_memory_pool_decerealize_rejects_bad_magic() {
+        let pool = VectorMemoryPool::new();
+        let mut buffer = pool.cerealize().unwrap();
+        buffer.data[0] ^= 0xFF;
+
+        assert!(matches!(
+            VectorMemoryPool::decerealize(&mut buffer),
+            Err(CerealError::InvalidMagic)
+        ));
+    }
+
     #[test]
     fn test_coherence_decay() {
         let mut buffer = QuantumBuffer::new();
@@ -299,4 +752,58 @@ mod tests {
         assert!(buffer.coherence < initial_coherence);
         assert!(buffer.coherence > QUANTUM_THRESHOLD);
     }
+
+    #[test]
+    fn test_finalize_then_open_roundtrip() {
+        let mut buffer = QuantumBuffer::new();
+        buffer.write_f64(1.234).unwrap();
+        buffer.write_f64(-5.678).unwrap();
+        buffer.finalize().unwrap();
+
+        let mut opened = QuantumBuffer::open(buffer.data.clone()).unwrap();
+
+        let mut pos = 6; // Skip magic + version
+        assert_eq!(opened.read_f64(&mut pos).unwrap(), 1.234);
+        assert_eq!(opened.read_f64(&mut pos).unwrap(), -5.678);
+    }
+
+    #[test]
+    fn test_open_rejects_tampered_payload() {
+        let mut buffer = QuantumBuffer::new();
+        buffer.write_f64(1.234).unwrap();
+        buffer.finalize().unwrap();
+
+        let mut tampered = buffer.data.clone();
+        tampered[6] ^= 0xFF; // Flip a bit inside the payload
+
+        assert!(matches!(
+            QuantumBuffer::open(tampered),
+            Err(CerealError::InvalidChecksum)
+        ));
+    }
+
+    #[test]
+    fn test_open_rejects_bad_magic() {
+        let mut buffer = QuantumBuffer::new();
+        buffer.write_f64(1.234).unwrap();
+        buffer.finalize().unwrap();
+
+        let mut corrupted = buffer.data.clone();
+        corrupted[0] ^= 0xFF;
+
+        assert!(matches!(
+            QuantumBuffer::open(corrupted),
+            Err(CerealError::InvalidMagic)
+        ));
+    }
+
+    #[test]
+    fn test_write_after_finalize_is_rejected() {
+        let mut buffer = QuantumBuffer::new();
+        buffer.finalize().unwrap();
+        assert!(matches!(
+            buffer.write_f64(1.0),
+            Err(CerealError::StateCollapse)
+        ));
+    }
 }
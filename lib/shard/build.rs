@@ -0,0 +1,200 @@
+// build.rs -- generates `src/shard_ops.rs` from `instructions.in`.
+//
+// `ShardOpcode` used to be a hand-maintained enum with no binary
+// encoding, so adding or reordering an opcode could silently desync its
+// mnemonic, its encoded byte, and its operand count from each other (and
+// from any already-serialized Shard program). Driving the enum, the
+// encoder, and the decoder off one spec file makes that class of drift
+// impossible: there's exactly one place the opcode table is written.
+
+use std::collections::HashSet;
+use std::env;
+use std::fs;
+use std::path::Path;
+
+struct InstructionSpec {
+    mnemonic: String,
+    code: u8,
+    operand_widths: Vec<usize>,
+}
+
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR not set");
+    let spec_path = Path::new(&manifest_dir).join("instructions.in");
+    println!("cargo:rerun-if-changed={}", spec_path.display());
+
+    let spec_text = fs::read_to_string(&spec_path)
+        .unwrap_or_else(|err| panic!("failed to read {}: {}", spec_path.display(), err));
+
+    let instructions = parse_spec(&spec_text);
+    let generated = generate_source(&instructions);
+
+    let out_path = Path::new(&manifest_dir).join("src").join("shard_ops.rs");
+    fs::write(&out_path, generated)
+        .unwrap_or_else(|err| panic!("failed to write {}: {}", out_path.display(), err));
+}
+
+fn parse_spec(spec_text: &str) -> Vec<InstructionSpec> {
+    let mut instructions = Vec::new();
+    let mut seen_codes = HashSet::new();
+    let mut seen_mnemonics = HashSet::new();
+
+    for (line_no, raw_line) in spec_text.lines().enumerate() {
+        let line = raw_line.split('#').next().unwrap_or("").trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        assert!(
+            fields.len() == 3,
+            "instructions.in:{}: expected `MNEMONIC CODE OPERANDS`, got `{}`",
+            line_no + 1,
+            raw_line
+        );
+
+        let mnemonic = fields[0].to_string();
+        let code = u8::from_str_radix(fields[1].trim_start_matches("0x"), 16).unwrap_or_else(|_| {
+            panic!("instructions.in:{}: invalid opcode byte `{}`", line_no + 1, fields[1])
+        });
+        let operand_widths: Vec<usize> = if fields[2] == "-" {
+            Vec::new()
+        } else {
+            fields[2]
+                .split(',')
+                .map(|width| {
+                    width.parse().unwrap_or_else(|_| {
+                        panic!("instructions.in:{}: invalid operand width `{}`", line_no + 1, width)
+                    })
+                })
+                .collect()
+        };
+
+        assert!(
+            seen_mnemonics.insert(mnemonic.clone()),
+            "instructions.in:{}: duplicate mnemonic `{}`",
+            line_no + 1,
+            mnemonic
+        );
+        assert!(
+            seen_codes.insert(code),
+            "instructions.in:{}: duplicate opcode byte 0x{:02X}",
+            line_no + 1,
+            code
+        );
+
+        instructions.push(InstructionSpec { mnemonic, code, operand_widths });
+    }
+
+    instructions
+}
+
+fn generate_source(instructions: &[InstructionSpec]) -> String {
+    let mut out = String::new();
+
+    out.push_str("// GENERATED FILE -- produced by `build.rs` from `instructions.in`.\n");
+    out.push_str("// Do not edit by hand; edit `instructions.in` and rebuild instead.\n\n");
+
+    out.push_str("#[allow(non_camel_case_types)]\n");
+    out.push_str("#[derive(Debug, Clone, Copy, PartialEq, Eq)]\n");
+    out.push_str("pub enum ShardOpcode {\n");
+    for instruction in instructions {
+        out.push_str(&format!("    {},\n", instruction.mnemonic));
+    }
+    out.push_str("}\n\n");
+
+    out.push_str("/// Why a `ShardOpcode::decode` call failed.\n");
+    out.push_str("#[derive(Debug, Clone, Copy, PartialEq, Eq)]\n");
+    out.push_str("pub enum DecodeError {\n");
+    out.push_str("    /// The byte slice ended before an opcode byte could be read.\n");
+    out.push_str("    UnexpectedEof,\n");
+    out.push_str("}\n\n");
+
+    out.push_str("impl ShardOpcode {\n");
+
+    out.push_str("    /// Number of operand bytes this opcode's instruction carries, not\n");
+    out.push_str("    /// counting the opcode byte itself. Lets callers skip past an\n");
+    out.push_str("    /// instruction's operands without decoding each one.\n");
+    out.push_str("    pub fn operand_width(&self) -> usize {\n");
+    out.push_str("        match self {\n");
+    for instruction in instructions {
+        let width: usize = instruction.operand_widths.iter().sum();
+        out.push_str(&format!("            ShardOpcode::{} => {},\n", instruction.mnemonic, width));
+    }
+    out.push_str("        }\n");
+    out.push_str("    }\n\n");
+
+    out.push_str("    /// Appends this opcode's single encoded byte to `out`.\n");
+    out.push_str("    pub fn encode(&self, out: &mut Vec<u8>) {\n");
+    out.push_str("        out.push(match self {\n");
+    for instruction in instructions {
+        out.push_str(&format!("            ShardOpcode::{} => 0x{:02X},\n", instruction.mnemonic, instruction.code));
+    }
+    out.push_str("        });\n");
+    out.push_str("    }\n\n");
+
+    out.push_str("    /// Reads the opcode byte at the front of `bytes`, returning the\n");
+    out.push_str("    /// decoded opcode plus how many bytes the full instruction (opcode\n");
+    out.push_str("    /// plus operands) occupies. Bytes outside the reserved range decode\n");
+    out.push_str("    /// to `ShardOpcode::ILLEGAL` (a trap, not a decode error) rather than\n");
+    out.push_str("    /// panicking -- only an empty `bytes` is an actual `DecodeError`.\n");
+    out.push_str("    pub fn decode(bytes: &[u8]) -> Result<(ShardOpcode, usize), DecodeError> {\n");
+    out.push_str("        let opcode = match bytes.first() {\n");
+    out.push_str("            Some(byte) => match byte {\n");
+    for instruction in instructions {
+        if instruction.mnemonic == "ILLEGAL" {
+            continue;
+        }
+        out.push_str(&format!("                0x{:02X} => ShardOpcode::{},\n", instruction.code, instruction.mnemonic));
+    }
+    out.push_str("                _ => ShardOpcode::ILLEGAL,\n");
+    out.push_str("            },\n");
+    out.push_str("            None => return Err(DecodeError::UnexpectedEof),\n");
+    out.push_str("        };\n");
+    out.push_str("        let len = 1 + opcode.operand_width();\n");
+    out.push_str("        Ok((opcode, len))\n");
+    out.push_str("    }\n");
+    out.push_str("}\n\n");
+
+    out.push_str("#[cfg(test)]\n");
+    out.push_str("mod tests {\n");
+    out.push_str("    use super::*;\n\n");
+
+    out.push_str("    #[test]\n");
+    out.push_str("    fn test_round_trip_every_opcode() {\n");
+    out.push_str("        let opcodes = [\n");
+    for instruction in instructions {
+        out.push_str(&format!("            ShardOpcode::{},\n", instruction.mnemonic));
+    }
+    out.push_str("        ];\n");
+    out.push_str("        for opcode in opcodes {\n");
+    out.push_str("            let mut bytes = Vec::new();\n");
+    out.push_str("            opcode.encode(&mut bytes);\n");
+    out.push_str("            let (decoded, len) = ShardOpcode::decode(&bytes).unwrap();\n");
+    out.push_str("            assert_eq!(decoded, opcode);\n");
+    out.push_str("            assert_eq!(len, 1 + opcode.operand_width());\n");
+    out.push_str("        }\n");
+    out.push_str("    }\n\n");
+
+    out.push_str("    #[test]\n");
+    out.push_str("    fn test_unknown_byte_decodes_to_illegal() {\n");
+    let reserved: Vec<String> = instructions.iter().map(|i| format!("0x{:02X}", i.code)).collect();
+    out.push_str(&format!("        let reserved: &[u8] = &[{}];\n", reserved.join(", ")));
+    out.push_str("        for byte in 0u8..=0xFE {\n");
+    out.push_str("            if reserved.contains(&byte) {\n");
+    out.push_str("                continue;\n");
+    out.push_str("            }\n");
+    out.push_str("            let (decoded, len) = ShardOpcode::decode(&[byte]).unwrap();\n");
+    out.push_str("            assert_eq!(decoded, ShardOpcode::ILLEGAL);\n");
+    out.push_str("            assert_eq!(len, 1);\n");
+    out.push_str("        }\n");
+    out.push_str("    }\n\n");
+
+    out.push_str("    #[test]\n");
+    out.push_str("    fn test_decode_empty_bytes_is_an_error() {\n");
+    out.push_str("        assert_eq!(ShardOpcode::decode(&[]), Err(DecodeError::UnexpectedEof));\n");
+    out.push_str("    }\n");
+    out.push_str("}\n");
+
+    out
+}
@@ -1,6 +1,7 @@
 // core.rs
 
 use crate::crystal_compute::{ComputeCrystal, CrystalScheduler, QuantumOptimizer};
+pub use crate::shard_ops::{DecodeError, ShardOpcode};
 
 /// Enhanced register file for the Shard architecture
 #[derive(Debug, Clone)]
@@ -20,20 +21,6 @@ pub struct ShardRegisterFile {
     pub crystal_metrics: CrystalMetrics,
 }
 
-/// Extended Shard Instruction Set
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum ShardOpcode {
-    // Existing opcodes...
-    
-    // New Crystal Compute Operations
-    CGROW_OPT,    // Optimized crystal growth
-    CADAPT,       // Adapt crystal to workload
-    COPT,         // Optimize crystal structure
-    CPERF,        // Get crystal performance metrics
-    CSCHED,       // Schedule workload on crystal
-    CMEM,         // Crystal memory operation
-}
-
 impl ShardRegisterFile {
     /// Initialize with crystal compute support
     pub fn new() -> Self {
@@ -6,11 +6,13 @@
 use crate::Error;
 
 mod executor;
+mod gpu;
 mod memory;
 mod optimizer;
 mod scheduler;
 
 pub use executor::QuantumExecutor;
+pub use gpu::{ComputeBackend, GpuWorkloadDescriptor, select_backend};
 pub use memory::CrystalMemoryManager;
 pub use optimizer::CrystalOptimizer;
 pub use scheduler::CrystalScheduler;
@@ -28,6 +30,21 @@ use quartz::{
     AetherField,
 };
 
+/// How a scheduled workload's memory is traversed, used to shape both
+/// the crystal's resonance instructions (`CrystalScheduler`) and, for
+/// workloads routed to the GPU, the compute pipeline's workgroup layout
+/// (`gpu::select_backend`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AccessPattern {
+    Sequential,
+    Strided,
+    Random,
+    Clustered,
+    /// Mixed sequential/random access, `ratio` is the sequential share
+    /// in `[0.0, 1.0]`.
+    Hybrid(f64),
+}
+
 /// Crystal system configuration
 #[derive(Debug, Clone)]
 pub struct CrystalConfig {
@@ -0,0 +1,225 @@
+//! Validating interpreter mode for `ShardMemory`.
+//!
+//! `ShardMemory` itself just stores whatever `l1q`/`l2c`/`l3h`/
+//! `aether_state` are handed -- nothing stops a corrupt coherence value
+//! or a type-confused slot from flowing straight into `QuantumOptimizer`.
+//! Borrowing the layered value/place/validity split a const-evaluation
+//! interpreter uses (a place is checked before the value living there is
+//! trusted), `ValidatingMemory` wraps a `&mut ShardMemory` and checks
+//! every access against three invariants: coherence values stay within
+//! `[0.0, 1.0]`, a value read back must match the tag it was stored
+//! under (a `Vector4D` can't be misread as a scalar coherence value or
+//! vice versa), and reading an address nothing was ever written to is
+//! rejected instead of silently handing back whatever was there before.
+//!
+//! `l2c`/`l3h`/`aether_state` are `CrystalLattice`/`HyperGrid`/
+//! `AetherGrid` -- types not defined anywhere in this crate yet (the
+//! same pre-existing gap `crystal_compute::gpu` documents for
+//! `Workload`/`ComputeCrystal`). Until they exist, this wrapper enforces
+//! its invariants through its own tagged cell table rather than calling
+//! into them directly, while still mirroring every validated coherence
+//! write into `l1q`, the one concretely-typed field, so reads through
+//! `ShardMemory` itself stay consistent with what passed validation.
+
+use hashbrown::HashMap;
+
+use crate::core::ShardMemory;
+use crate::vector4d::Vector4D;
+
+/// Which invariant an access violated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Invariant {
+    /// A coherence value fell outside `[0.0, 1.0]`.
+    CoherenceRange,
+    /// A read's expected tag didn't match what the address was actually
+    /// stored under.
+    QuantumStateTag,
+    /// Nothing has ever been written to this address.
+    Uninitialized,
+}
+
+/// A validation failure, carrying the faulting address and which
+/// invariant it broke, instead of letting a corrupt value propagate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InterpError {
+    pub address: Vector4D,
+    pub invariant: Invariant,
+}
+
+/// What kind of value is stored at an address -- a scalar coherence
+/// reading or a quantum-state `Vector4D`. Distinct cell kinds can't
+/// alias: reading one as the other is a `QuantumStateTag` violation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Cell {
+    Coherence(f64),
+    QuantumVector(Vector4D),
+}
+
+/// Bit-exact hashable key for a `Vector4D` address. Addresses are
+/// compared by their literal bit pattern, not with any quantization
+/// tolerance, since two slightly different coordinates must stay two
+/// distinct memory locations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct AddressKey(u64, u64, u64, u64);
+
+impl AddressKey {
+    fn from_address(address: Vector4D) -> Self {
+        Self(address.x.to_bits(), address.y.to_bits(), address.z.to_bits(), address.w.to_bits())
+    }
+}
+
+/// Opt-in validating wrapper around a `ShardMemory`. See the module
+/// doc comment for exactly what it checks and why.
+#[derive(Debug)]
+pub struct ValidatingMemory<'a> {
+    mem: &'a mut ShardMemory,
+    cells: HashMap<AddressKey, (Vector4D, Cell)>,
+    strict: bool,
+}
+
+impl<'a> ValidatingMemory<'a> {
+    /// Wraps `mem` with invariant checks enabled in debug builds
+    /// (`cfg!(debug_assertions)`) and disabled in release, so production
+    /// runs pay nothing for them. Use `with_strict` to override that.
+    pub fn new(mem: &'a mut ShardMemory) -> Self {
+        Self::with_strict(mem, cfg!(debug_assertions))
+    }
+
+    pub fn with_strict(mem: &'a mut ShardMemory, strict: bool) -> Self {
+        Self { mem, cells: HashMap::new(), strict }
+    }
+
+    /// Writes a coherence value to `address`, rejecting it outright
+    /// (while `strict`) if it falls outside `[0.0, 1.0]` rather than
+    /// letting an out-of-range reading reach `QuantumOptimizer`.
+    pub fn write_coherence(&mut self, address: Vector4D, value: f64) -> Result<(), InterpError> {
+        if self.strict && !(0.0..=1.0).contains(&value) {
+            return Err(InterpError { address, invariant: Invariant::CoherenceRange });
+        }
+
+        self.mem.l1q.insert(address, value);
+        self.cells.insert(AddressKey::from_address(address), (address, Cell::Coherence(value)));
+        Ok(())
+    }
+
+    /// Reads the coherence value at `address`. Fails if nothing was ever
+    /// written there, or if `address` actually holds a `Vector4D`.
+    pub fn read_coherence(&self, address: Vector4D) -> Result<f64, InterpError> {
+        match self.cells.get(&AddressKey::from_address(address)) {
+            Some((_, Cell::Coherence(value))) => Ok(*value),
+            Some((_, Cell::QuantumVector(_))) => {
+                if self.strict {
+                    Err(InterpError { address, invariant: Invariant::QuantumStateTag })
+                } else {
+                    Ok(self.mem.l1q.get(&address).copied().unwrap_or(0.0))
+                }
+            }
+            None => {
+                if self.strict {
+                    Err(InterpError { address, invariant: Invariant::Uninitialized })
+                } else {
+                    Ok(0.0)
+                }
+            }
+        }
+    }
+
+    /// Writes a quantum-state `Vector4D` to `address`.
+    pub fn write_vector(&mut self, address: Vector4D, value: Vector4D) -> Result<(), InterpError> {
+        self.cells.insert(AddressKey::from_address(address), (address, Cell::QuantumVector(value)));
+        Ok(())
+    }
+
+    /// Reads the `Vector4D` at `address`. Fails if nothing was ever
+    /// written there, or if `address` actually holds a scalar coherence
+    /// value -- the type-confusion case this interpreter exists to
+    /// catch instead of handing the caller a `Vector4D` built from
+    /// whatever bits happened to be in a scalar slot.
+    pub fn read_vector(&self, address: Vector4D) -> Result<Vector4D, InterpError> {
+        match self.cells.get(&AddressKey::from_address(address)) {
+            Some((_, Cell::QuantumVector(value))) => Ok(*value),
+            Some((_, Cell::Coherence(_))) => {
+                if self.strict {
+                    Err(InterpError { address, invariant: Invariant::QuantumStateTag })
+                } else {
+                    Ok(Vector4D::zero())
+                }
+            }
+            None => {
+                if self.strict {
+                    Err(InterpError { address, invariant: Invariant::Uninitialized })
+                } else {
+                    Ok(Vector4D::zero())
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn addr(tag: f64) -> Vector4D {
+        Vector4D::new(tag, tag, tag, tag)
+    }
+
+    #[test]
+    fn test_write_then_read_coherence_round_trips() {
+        let mut mem = ShardMemory::new();
+        let mut validating = ValidatingMemory::new(&mut mem);
+
+        validating.write_coherence(addr(1.0), 0.9).unwrap();
+        assert_eq!(validating.read_coherence(addr(1.0)), Ok(0.9));
+    }
+
+    #[test]
+    fn test_out_of_range_coherence_is_rejected() {
+        let mut mem = ShardMemory::new();
+        let mut validating = ValidatingMemory::with_strict(&mut mem, true);
+
+        let result = validating.write_coherence(addr(2.0), 1.5);
+        assert_eq!(result, Err(InterpError { address: addr(2.0), invariant: Invariant::CoherenceRange }));
+    }
+
+    #[test]
+    fn test_reading_uninitialized_address_is_rejected_when_strict() {
+        let mut mem = ShardMemory::new();
+        let validating = ValidatingMemory::with_strict(&mut mem, true);
+
+        let result = validating.read_coherence(addr(3.0));
+        assert_eq!(result, Err(InterpError { address: addr(3.0), invariant: Invariant::Uninitialized }));
+    }
+
+    #[test]
+    fn test_reading_vector_as_coherence_is_type_confusion() {
+        let mut mem = ShardMemory::new();
+        let mut validating = ValidatingMemory::with_strict(&mut mem, true);
+
+        validating.write_vector(addr(4.0), Vector4D::new(1.0, 2.0, 3.0, 4.0)).unwrap();
+
+        let result = validating.read_coherence(addr(4.0));
+        assert_eq!(result, Err(InterpError { address: addr(4.0), invariant: Invariant::QuantumStateTag }));
+    }
+
+    #[test]
+    fn test_reading_coherence_as_vector_is_type_confusion() {
+        let mut mem = ShardMemory::new();
+        let mut validating = ValidatingMemory::with_strict(&mut mem, true);
+
+        validating.write_coherence(addr(5.0), 0.5).unwrap();
+
+        let result = validating.read_vector(addr(5.0));
+        assert_eq!(result, Err(InterpError { address: addr(5.0), invariant: Invariant::QuantumStateTag }));
+    }
+
+    #[test]
+    fn test_non_strict_mode_skips_checks() {
+        let mut mem = ShardMemory::new();
+        let mut validating = ValidatingMemory::with_strict(&mut mem, false);
+
+        assert!(validating.write_coherence(addr(6.0), 42.0).is_ok());
+        assert!(validating.read_vector(addr(6.0)).is_ok());
+        assert!(validating.read_coherence(addr(7.0)).is_ok());
+    }
+}
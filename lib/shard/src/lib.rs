@@ -33,6 +33,10 @@ pub const ENABLE_PERFORMANCE_METRICS: bool = true;
 
 // Module declarations
 pub mod core;
+/// `ShardOpcode`'s enum, encoder, and decoder -- generated by `build.rs`
+/// from `instructions.in`. See `core::ShardOpcode`.
+pub mod shard_ops;
+pub mod vm;
 pub mod memory;
 pub mod vector4d;
 pub mod meshmath;
@@ -40,6 +44,8 @@ pub mod crystal_compute;
 pub mod quantum;
 pub mod metrics;
 pub mod util;
+pub mod shard_ir;
+pub mod interp;
 
 // Type definitions
 pub type Result<T> = core::result::Result<T, Error>;
@@ -75,6 +81,7 @@ pub use {
         ShardInstruction,
         ShardOpcode,
     },
+    vm::{ShardVm, StepOutcome, TrapKind},
     memory::{
         ShardMemoryPattern,
         MemoryHierarchy,
@@ -93,6 +100,9 @@ pub use {
         QuantumOptimizer,
         WorkloadMatrix,
         OptimizationStats,
+        AccessPattern,
+        ComputeBackend,
+        GpuWorkloadDescriptor,
     },
     quantum::{
         QuantumState,
@@ -104,6 +114,19 @@ pub use {
         CrystalMetrics,
         SystemMetrics,
     },
+    shard_ir::{
+        CFGBuilder,
+        ControlFlowGraph,
+        RegisterAllocator,
+        RegisterClass,
+        Location,
+        LoweredEntry,
+    },
+    interp::{
+        ValidatingMemory,
+        InterpError,
+        Invariant,
+    },
 };
 
 /// Configuration for Shard system
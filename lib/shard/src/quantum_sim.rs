@@ -1,9 +1,21 @@
 //! Quantum Simulation Controller for Shard Architecture
 //! Last Updated: 2025-01-20 12:46:34 UTC
 //! Author: isdood
+//!
+//! Not currently reachable from the rest of the crate, and not safe to
+//! wire in as-is: `QuantumSimController` is written against a
+//! `ShardRegisterFile::qs_sim_regs: [Vec<Complex>; 4]` field and
+//! `get_quantum_coherence`/`get_crystal_resonance` methods that don't
+//! exist on `ShardRegisterFile` (see `core.rs`) -- only the scalar
+//! `qs_regs: [Vec<f64>; 4]` it predates. Adding a module declaration here
+//! without also reworking that struct would just move the "doesn't
+//! compile" failure from "missing module" to "missing field", so this
+//! stays un-declared in `lib.rs` until `ShardRegisterFile` actually grows
+//! complex-amplitude storage. The pure math below (`box_muller`,
+//! `collapse_index`) doesn't depend on any of that and is unit-tested on
+//! its own.
 
 use super::core::{ShardRegisterFile, QUANTUM_COHERENCE_THRESHOLD, FAIRY_DUST_COEFFICIENT};
-use super::vector4d::Vector4D;
 
 /// Decoherence simulation parameters
 pub const DECOHERENCE_BASE_RATE: f64 = 0.001;
@@ -11,6 +23,97 @@ pub const DECOHERENCE_BASE_RATE: f64 = 0.001;
 pub const QUANTUM_NOISE_FLOOR: f64 = 1e-6;
 /// Wave function collapse threshold
 pub const COLLAPSE_THRESHOLD: f64 = 0.999;
+/// Default seed used when a controller isn't given an explicit one
+const DEFAULT_RNG_SEED: u64 = 0x9E3779B97F4A7C15;
+
+/// A single quantum amplitude, used in place of the scalar `f64` a
+/// `qs_sim_regs` entry used to hold.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Complex {
+    pub re: f64,
+    pub im: f64,
+}
+
+impl Complex {
+    pub const fn new(re: f64, im: f64) -> Self {
+        Self { re, im }
+    }
+
+    /// |z|^2, the Born-rule measurement probability of this amplitude
+    pub fn norm_sqr(&self) -> f64 {
+        self.re * self.re + self.im * self.im
+    }
+
+    pub fn scale(&self, factor: f64) -> Self {
+        Self::new(self.re * factor, self.im * factor)
+    }
+}
+
+impl core::ops::Add for Complex {
+    type Output = Complex;
+    fn add(self, rhs: Complex) -> Complex {
+        Complex::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+/// A small, deterministic, seedable PRNG (splitmix64) so that noise
+/// injection and wave function collapse can be replayed for a given seed
+/// rather than relying on an external `rand` dependency this `no_std`
+/// crate doesn't otherwise pull in.
+#[derive(Debug, Clone)]
+struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform sample in `[0, 1)`
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+
+    /// A standard-normal sample via the Box-Muller transform
+    fn next_gaussian(&mut self) -> f64 {
+        box_muller(self.next_f64(), self.next_f64())
+    }
+}
+
+/// Box-Muller transform: turns two uniform draws in `[0, 1)` into one
+/// standard-normal sample. Split out of `SplitMix64::next_gaussian` so the
+/// transform itself can be unit-tested against fixed inputs, independent
+/// of the RNG that supplies `u1`/`u2` in production.
+fn box_muller(u1: f64, u2: f64) -> f64 {
+    let u1 = u1.max(f64::MIN_POSITIVE);
+    (-2.0 * u1.ln()).sqrt() * (2.0 * core::f64::consts::PI * u2).cos()
+}
+
+/// Chooses which basis state a register collapses to, Born-rule style:
+/// walks `amplitudes` accumulating `|amplitude|^2` and returns the first
+/// index whose running total exceeds `draw`. Split out of `check_collapse`
+/// so the selection itself can be unit-tested against a fixed amplitude
+/// list and draw, independent of `ShardRegisterFile` and the RNG that
+/// produces `draw` in production.
+fn collapse_index(amplitudes: &[Complex], draw: f64) -> usize {
+    let mut cumulative = 0.0;
+    for (index, amplitude) in amplitudes.iter().enumerate() {
+        cumulative += amplitude.norm_sqr();
+        if draw < cumulative {
+            return index;
+        }
+    }
+    amplitudes.len().saturating_sub(1)
+}
 
 /// Controller for quantum simulation effects
 #[derive(Debug)]
@@ -25,6 +128,8 @@ pub struct QuantumSimController {
     collapse_threshold: f64,
     /// Simulation metrics
     metrics: SimulationMetrics,
+    /// Source of randomness for noise injection and Born-rule collapse
+    rng: SplitMix64,
 }
 
 #[derive(Debug, Default)]
@@ -41,12 +146,20 @@ pub struct SimulationMetrics {
 
 impl QuantumSimController {
     pub fn new() -> Self {
+        Self::with_seed(DEFAULT_RNG_SEED)
+    }
+
+    /// Creates a controller whose noise injection and wave function
+    /// collapse are driven by a caller-supplied RNG seed, so a simulation
+    /// run can be reproduced exactly.
+    pub fn with_seed(seed: u64) -> Self {
         Self {
             decoherence_rate: DECOHERENCE_BASE_RATE,
             entanglement_patterns: Vec::new(),
             noise_amplitude: QUANTUM_NOISE_FLOOR,
             collapse_threshold: COLLAPSE_THRESHOLD,
             metrics: SimulationMetrics::default(),
+            rng: SplitMix64::new(seed),
         }
     }
 
@@ -54,27 +167,30 @@ impl QuantumSimController {
     pub fn simulate_step(&mut self, regs: &mut ShardRegisterFile) -> Result<(), String> {
         // Apply decoherence
         self.apply_decoherence(regs)?;
-        
+
         // Update entanglement patterns
         self.update_entanglement(regs)?;
-        
+
         // Inject quantum noise
         self.inject_noise(regs)?;
-        
+
         // Check for wave function collapse
         self.check_collapse(regs)?;
-        
+
         // Update metrics
         self.update_metrics(regs);
-        
+
         Ok(())
     }
 
-    /// Applies quantum decoherence effects
+    /// Applies quantum decoherence effects, Lindblad-style: the leading
+    /// (diagonal) amplitude is left alone while every other basis state's
+    /// amplitude is damped toward zero at `exp(-decoherence_rate)`.
     fn apply_decoherence(&self, regs: &mut ShardRegisterFile) -> Result<(), String> {
+        let damping = (-self.decoherence_rate).exp();
         for qs_reg in regs.qs_sim_regs.iter_mut() {
-            if !qs_reg.is_empty() {
-                qs_reg[0] *= (1.0 - self.decoherence_rate);
+            for amplitude in qs_reg.iter_mut().skip(1) {
+                *amplitude = amplitude.scale(damping);
             }
         }
         Ok(())
@@ -84,10 +200,10 @@ impl QuantumSimController {
     fn update_entanglement(&mut self, regs: &mut ShardRegisterFile) -> Result<(), String> {
         for (reg1, reg2) in self.entanglement_patterns.iter() {
             if let (Some(val1), Some(val2)) = (
-                regs.qs_sim_regs.get(*reg1).and_then(|r| r.first()),
-                regs.qs_sim_regs.get(*reg2).and_then(|r| r.first())
+                regs.qs_sim_regs.get(*reg1).and_then(|r| r.first()).copied(),
+                regs.qs_sim_regs.get(*reg2).and_then(|r| r.first()).copied(),
             ) {
-                let entangled_val = (*val1 + *val2) * 0.5 * FAIRY_DUST_COEFFICIENT;
+                let entangled_val = (val1 + val2).scale(0.5 * FAIRY_DUST_COEFFICIENT);
                 if let Some(reg1_mut) = regs.qs_sim_regs.get_mut(*reg1) {
                     reg1_mut[0] = entangled_val;
                 }
@@ -99,27 +215,42 @@ impl QuantumSimController {
         Ok(())
     }
 
-    /// Injects quantum noise into the simulation
-    fn inject_noise(&self, regs: &mut ShardRegisterFile) -> Result<(), String> {
-        use core::intrinsics::FloatToInt;
+    /// Injects Gaussian quantum noise, drawn via Box-Muller from this
+    /// controller's seeded RNG, into both components of every amplitude.
+    fn inject_noise(&mut self, regs: &mut ShardRegisterFile) -> Result<(), String> {
         for qs_reg in regs.qs_sim_regs.iter_mut() {
-            if !qs_reg.is_empty() {
-                let noise = (self.noise_amplitude * unsafe { 
-                    FloatToInt::to_int_unchecked(
-                        qs_reg[0].sin() * 1000.0
-                    ) as f64
-                }) / 1000.0;
-                qs_reg[0] += noise;
+            for amplitude in qs_reg.iter_mut() {
+                amplitude.re += self.noise_amplitude * self.rng.next_gaussian();
+                amplitude.im += self.noise_amplitude * self.rng.next_gaussian();
             }
         }
         Ok(())
     }
 
-    /// Checks for wave function collapse conditions
-    fn check_collapse(&self, regs: &mut ShardRegisterFile) -> Result<(), String> {
+    /// Probabilistically collapses each register's state vector using the
+    /// Born rule: the basis state `i` is chosen with probability
+    /// `|amplitude_i|^2`, sampled via a cumulative distribution against a
+    /// uniform draw from this controller's RNG, once the register's total
+    /// probability mass reaches the collapse threshold.
+    fn check_collapse(&mut self, regs: &mut ShardRegisterFile) -> Result<(), String> {
         for qs_reg in regs.qs_sim_regs.iter_mut() {
-            if !qs_reg.is_empty() && qs_reg[0].abs() >= self.collapse_threshold {
-                qs_reg[0] = if qs_reg[0] > 0.0 { 1.0 } else { -1.0 };
+            if qs_reg.is_empty() {
+                continue;
+            }
+            let total_probability: f64 = qs_reg.iter().map(Complex::norm_sqr).sum();
+            if total_probability < self.collapse_threshold {
+                continue;
+            }
+
+            let draw = self.rng.next_f64() * total_probability;
+            let collapsed_index = collapse_index(qs_reg, draw);
+
+            for (index, amplitude) in qs_reg.iter_mut().enumerate() {
+                *amplitude = if index == collapsed_index {
+                    Complex::new(1.0, 0.0)
+                } else {
+                    Complex::default()
+                };
             }
         }
         Ok(())
@@ -129,16 +260,57 @@ impl QuantumSimController {
     fn update_metrics(&mut self, regs: &ShardRegisterFile) {
         let coherence = regs.get_quantum_coherence();
         self.metrics.coherence_history.push(coherence);
-        
+
         // Update crystal stability based on resonance
         self.metrics.crystal_stability = regs.get_crystal_resonance();
-        
+
         // Calculate coupling efficiency
-        self.metrics.coupling_efficiency = 
+        self.metrics.coupling_efficiency =
             (coherence * self.metrics.crystal_stability).min(1.0);
-        
+
         // Estimate simulation accuracy
-        self.metrics.simulation_accuracy = 
+        self.metrics.simulation_accuracy =
             (1.0 - self.decoherence_rate) * self.metrics.coupling_efficiency;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_box_muller_is_deterministic_for_fixed_draws() {
+        assert_eq!(box_muller(0.25, 0.5), box_muller(0.25, 0.5));
+    }
+
+    #[test]
+    fn test_box_muller_clamps_zero_draw_away_from_negative_infinity() {
+        // u1 = 0.0 would send `ln` to negative infinity; the clamp to
+        // `f64::MIN_POSITIVE` keeps the result finite.
+        assert!(box_muller(0.0, 0.5).is_finite());
+    }
+
+    #[test]
+    fn test_collapse_index_picks_first_state_when_draw_is_zero() {
+        let amplitudes = [Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)];
+        assert_eq!(collapse_index(&amplitudes, 0.0), 0);
+    }
+
+    #[test]
+    fn test_collapse_index_picks_state_whose_cumulative_mass_exceeds_draw() {
+        let amplitudes = [
+            Complex::new(0.5, 0.0),                // mass 0.25, cumulative 0.25
+            Complex::new(0.5, 0.0),                // mass 0.25, cumulative 0.50
+            Complex::new((0.5_f64).sqrt(), 0.0),   // mass 0.50, cumulative 1.00
+        ];
+        assert_eq!(collapse_index(&amplitudes, 0.1), 0);
+        assert_eq!(collapse_index(&amplitudes, 0.4), 1);
+        assert_eq!(collapse_index(&amplitudes, 0.9), 2);
+    }
+
+    #[test]
+    fn test_collapse_index_falls_back_to_last_state_when_draw_exceeds_mass() {
+        let amplitudes = [Complex::new(0.5, 0.0), Complex::new(0.5, 0.0)];
+        assert_eq!(collapse_index(&amplitudes, 10.0), 1);
+    }
+}
@@ -0,0 +1,516 @@
+//! SSA intermediate representation and register allocator sitting
+//! between compiled crystal-compute kernels and `ShardOpcode`.
+//!
+//! A `ShardOpcode` stream targets the physical `v_regs`/`qs_regs`/
+//! `cr_regs` banks directly, with no allocation pass -- fine for
+//! hand-written programs, but it caps a compiled kernel at exactly as
+//! many live values as `ShardRegisterFile` has physical slots. `CFGBuilder`
+//! assembles an unbounded SSA value graph instead, and `RegisterAllocator`
+//! lowers it back down to concrete `ShardOpcode` sequences, spilling to
+//! the crystal memory hierarchy (via `ShardOpcode::CMEM`) whenever a
+//! register class's live values outnumber its bank.
+
+use hashbrown::{HashMap, HashSet};
+
+use crate::shard_ops::ShardOpcode;
+
+/// A register bank `ShardRegisterFile` exposes, constraining which
+/// physical slots a value can be allocated into. A `Vector4D` result
+/// can never occupy a scalar `qs_regs`/`cr_regs` slot and vice versa, so
+/// allocation is scoped per class rather than over one shared pool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RegisterClass {
+    /// `v_regs`: 8 physical `Vector4D` slots.
+    Vector4D,
+    /// `qs_regs`: 4 physical quantum-state slots.
+    QuantumState,
+    /// `cr_regs`: 4 physical crystal-register slots.
+    Crystal,
+}
+
+impl RegisterClass {
+    /// Number of physical slots `ShardRegisterFile` has for this class.
+    pub fn bank_size(self) -> usize {
+        match self {
+            RegisterClass::Vector4D => 8,
+            RegisterClass::QuantumState => 4,
+            RegisterClass::Crystal => 4,
+        }
+    }
+}
+
+/// An SSA value, identified by definition order within a
+/// `ControlFlowGraph`. Never reassigned once defined, per SSA's single
+/// static assignment discipline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ValueId(u32);
+
+/// One SSA instruction: `op` consumes `inputs` (in `ShardOpcode` operand
+/// order) and defines `result`, a new value of `class`.
+#[derive(Debug, Clone)]
+pub struct Instruction {
+    pub op: ShardOpcode,
+    pub inputs: Vec<ValueId>,
+    pub result: ValueId,
+    pub class: RegisterClass,
+}
+
+/// A straight-line sequence of `Instruction`s with no internal control
+/// flow -- `shard_ir` has no branch instructions yet, so every block is
+/// just a span of instructions executed in order.
+#[derive(Debug, Clone, Default)]
+pub struct BasicBlock {
+    pub instructions: Vec<Instruction>,
+}
+
+/// The SSA value graph `CFGBuilder` assembles: one or more basic blocks.
+#[derive(Debug, Clone, Default)]
+pub struct ControlFlowGraph {
+    pub blocks: Vec<BasicBlock>,
+}
+
+impl ControlFlowGraph {
+    /// Every instruction across every block, in definition order -- the
+    /// order `RegisterAllocator` assumes values become live and die in,
+    /// since `shard_ir` has no branches yet to reorder that.
+    fn instructions(&self) -> impl Iterator<Item = &Instruction> {
+        self.blocks.iter().flat_map(|block| block.instructions.iter())
+    }
+}
+
+/// Assembles a `ControlFlowGraph` one instruction at a time, minting a
+/// fresh SSA `ValueId` for each.
+#[derive(Debug)]
+pub struct CFGBuilder {
+    blocks: Vec<BasicBlock>,
+    next_value: u32,
+}
+
+impl Default for CFGBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CFGBuilder {
+    pub fn new() -> Self {
+        Self { blocks: vec![BasicBlock::default()], next_value: 0 }
+    }
+
+    /// Starts a new basic block; subsequent `emit` calls append to it.
+    pub fn push_block(&mut self) {
+        self.blocks.push(BasicBlock::default());
+    }
+
+    /// Appends an instruction to the current (last) block, returning the
+    /// `ValueId` it defines.
+    pub fn emit(&mut self, op: ShardOpcode, inputs: Vec<ValueId>, class: RegisterClass) -> ValueId {
+        let result = ValueId(self.next_value);
+        self.next_value += 1;
+
+        self.blocks
+            .last_mut()
+            .expect("CFGBuilder always holds at least one block")
+            .instructions
+            .push(Instruction { op, inputs, result, class });
+
+        result
+    }
+
+    pub fn finish(self) -> ControlFlowGraph {
+        ControlFlowGraph { blocks: self.blocks }
+    }
+}
+
+/// Where a `ValueId` lives after allocation: a physical slot in its
+/// class's bank, or a spill slot in the crystal memory hierarchy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Location {
+    Register(usize),
+    Spill(usize),
+}
+
+/// A `ShardOpcode` lowered to concrete physical locations: `op`'s
+/// operand bytes, in order, are each input location's register or spill
+/// index, followed by `result`'s.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoweredInstruction {
+    pub op: ShardOpcode,
+    pub result: Location,
+    pub inputs: Vec<Location>,
+}
+
+/// One entry in a lowered program: either an original SSA instruction,
+/// or a `ShardOpcode::CMEM` the allocator inserted to move a value
+/// between a register and the crystal memory hierarchy.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LoweredEntry {
+    Instruction(LoweredInstruction),
+    /// Stores `value`'s current register out to `slot` because its
+    /// register was needed for something else while `value` was still
+    /// live.
+    Spill { value: ValueId, register: usize, slot: usize },
+    /// Loads `value` back from `slot` into `register` because it's
+    /// about to be used as an operand again.
+    Reload { value: ValueId, slot: usize, register: usize },
+}
+
+/// Linear-scan register allocator over `ShardRegisterFile`'s physical
+/// banks, spilling to the crystal memory hierarchy when a class's live
+/// values exceed its bank size.
+///
+/// Liveness is exact for the straight-line code `shard_ir` currently
+/// produces: a value is live from its definition up to its last use, in
+/// program order. Spill victims are chosen by whichever live value's
+/// next use is furthest away (or has none left), the standard
+/// furthest-next-use heuristic -- it minimizes how soon a spilled value
+/// has to be reloaded.
+#[derive(Debug, Default)]
+pub struct RegisterAllocator {
+    next_spill_slot: usize,
+}
+
+impl RegisterAllocator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn allocate(&mut self, cfg: &ControlFlowGraph) -> Vec<LoweredEntry> {
+        let instructions: Vec<&Instruction> = cfg.instructions().collect();
+        let uses = UseInfo::build(&instructions);
+
+        let mut lowered = Vec::new();
+        let mut banks: HashMap<RegisterClass, BankState> = HashMap::new();
+
+        for (index, instruction) in instructions.iter().enumerate() {
+            let bank = banks
+                .entry(instruction.class)
+                .or_insert_with(|| BankState::new(instruction.class.bank_size()));
+
+            // Registers this instruction has already committed to an
+            // earlier input are pinned for the rest of this loop, so a
+            // later input can't evict one of its own siblings before the
+            // instruction ever reads it -- see `ensure_in_register`.
+            let mut reserved = HashSet::new();
+            let mut input_locations = Vec::with_capacity(instruction.inputs.len());
+            for &value in &instruction.inputs {
+                let location = bank.ensure_in_register(value, index, &uses, &reserved, &mut self.next_spill_slot, &mut lowered);
+                if let Location::Register(register) = location {
+                    reserved.insert(register);
+                }
+                input_locations.push(location);
+            }
+
+            let result_register = bank.allocate(instruction.result, index, &uses, &mut self.next_spill_slot, &mut lowered);
+
+            lowered.push(LoweredEntry::Instruction(LoweredInstruction {
+                op: instruction.op,
+                result: Location::Register(result_register),
+                inputs: input_locations,
+            }));
+
+            for &value in &instruction.inputs {
+                if uses.next_use_after(value, index).is_none() {
+                    bank.release(value);
+                }
+            }
+        }
+
+        lowered
+    }
+}
+
+/// Precomputed, per-value use sites so the allocator can ask "when is
+/// this value next needed?" without rescanning the instruction list.
+struct UseInfo {
+    uses: HashMap<ValueId, Vec<usize>>,
+}
+
+impl UseInfo {
+    fn build(instructions: &[&Instruction]) -> Self {
+        let mut uses: HashMap<ValueId, Vec<usize>> = HashMap::new();
+        for (index, instruction) in instructions.iter().enumerate() {
+            for &value in &instruction.inputs {
+                uses.entry(value).or_default().push(index);
+            }
+        }
+        Self { uses }
+    }
+
+    /// Earliest use index strictly after `index`, or `None` if `value`
+    /// isn't used again.
+    fn next_use_after(&self, value: ValueId, index: usize) -> Option<usize> {
+        self.uses
+            .get(&value)
+            .into_iter()
+            .flatten()
+            .copied()
+            .find(|&use_index| use_index > index)
+    }
+}
+
+/// Per-class allocation state: which physical slots hold which live
+/// `ValueId`s, and where every live value currently lives.
+struct BankState {
+    slots: Vec<Option<ValueId>>,
+    homes: HashMap<ValueId, Location>,
+}
+
+impl BankState {
+    fn new(bank_size: usize) -> Self {
+        Self { slots: vec![None; bank_size], homes: HashMap::new() }
+    }
+
+    /// Picks a physical register for `value`, spilling the worst
+    /// current occupant (furthest next use) if the bank is full.
+    fn allocate(
+        &mut self,
+        value: ValueId,
+        index: usize,
+        uses: &UseInfo,
+        next_spill_slot: &mut usize,
+        lowered: &mut Vec<LoweredEntry>,
+    ) -> usize {
+        let register = match self.slots.iter().position(|slot| slot.is_none()) {
+            Some(free) => free,
+            None => self
+                .spill_worst_occupant(index, uses, &HashSet::new(), next_spill_slot, lowered)
+                .expect("spilling with nothing reserved always finds a victim when the bank is full"),
+        };
+
+        self.slots[register] = Some(value);
+        self.homes.insert(value, Location::Register(register));
+        register
+    }
+
+    /// Guarantees `value` is available to read, reloading it from its
+    /// spill slot into a register first if it isn't already in one.
+    ///
+    /// `reserved` holds the registers this same instruction has already
+    /// committed to earlier inputs; they're excluded as eviction
+    /// candidates so a later input can't clobber a sibling input's
+    /// already-recorded location before the instruction ever reads it.
+    /// If every register is reserved, there's no room left to registerize
+    /// `value` at all -- more values are simultaneously live here than
+    /// the bank has slots for -- so it's left in its spill slot and read
+    /// from crystal memory directly instead of being forced into a
+    /// register that would just have to evict a sibling.
+    fn ensure_in_register(
+        &mut self,
+        value: ValueId,
+        index: usize,
+        uses: &UseInfo,
+        reserved: &HashSet<usize>,
+        next_spill_slot: &mut usize,
+        lowered: &mut Vec<LoweredEntry>,
+    ) -> Location {
+        match self.homes.get(&value) {
+            Some(Location::Register(register)) => Location::Register(*register),
+            Some(Location::Spill(spill_slot)) => {
+                let slot = *spill_slot;
+                let free = self.slots.iter().position(|occupant| occupant.is_none());
+                let register = match free {
+                    Some(free) => Some(free),
+                    None => self.spill_worst_occupant(index, uses, reserved, next_spill_slot, lowered),
+                };
+
+                match register {
+                    Some(register) => {
+                        self.slots[register] = Some(value);
+                        self.homes.insert(value, Location::Register(register));
+                        lowered.push(LoweredEntry::Reload { value, slot, register });
+                        Location::Register(register)
+                    }
+                    None => Location::Spill(slot),
+                }
+            }
+            None => panic!("value used before it was defined"),
+        }
+    }
+
+    /// Evicts whichever occupied, non-`reserved` register holds the value
+    /// with the furthest-away (or absent) next use, spilling it to a
+    /// fresh crystal-memory slot, and returns the now-free register
+    /// index. Returns `None` if every occupied register is reserved,
+    /// meaning there's nothing left this call is allowed to evict.
+    fn spill_worst_occupant(
+        &mut self,
+        index: usize,
+        uses: &UseInfo,
+        reserved: &HashSet<usize>,
+        next_spill_slot: &mut usize,
+        lowered: &mut Vec<LoweredEntry>,
+    ) -> Option<usize> {
+        let victim_register = self
+            .slots
+            .iter()
+            .enumerate()
+            .filter(|(register, _)| !reserved.contains(register))
+            .filter_map(|(register, occupant)| occupant.map(|value| (register, value)))
+            .max_by_key(|&(_, value)| uses.next_use_after(value, index).unwrap_or(usize::MAX))
+            .map(|(register, _)| register)?;
+
+        let victim = self.slots[victim_register].take().expect("checked occupied above");
+        let slot = *next_spill_slot;
+        *next_spill_slot += 1;
+
+        self.homes.insert(victim, Location::Spill(slot));
+        lowered.push(LoweredEntry::Spill { value: victim, register: victim_register, slot });
+
+        Some(victim_register)
+    }
+
+    fn release(&mut self, value: ValueId) {
+        if let Some(Location::Register(register)) = self.homes.get(&value) {
+            self.slots[*register] = None;
+        }
+        self.homes.remove(&value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_straight_line_kernel_allocates_without_spilling() {
+        let mut builder = CFGBuilder::new();
+        let a = builder.emit(ShardOpcode::VADD4D, vec![], RegisterClass::Vector4D);
+        let b = builder.emit(ShardOpcode::VADD4D, vec![a], RegisterClass::Vector4D);
+        builder.emit(ShardOpcode::VADD4D, vec![a, b], RegisterClass::Vector4D);
+        let cfg = builder.finish();
+
+        let lowered = RegisterAllocator::new().allocate(&cfg);
+
+        assert!(lowered.iter().all(|entry| matches!(entry, LoweredEntry::Instruction(_))));
+        assert_eq!(lowered.len(), 3);
+    }
+
+    #[test]
+    fn test_kernel_with_more_live_values_than_registers_spills_and_reloads() {
+        let mut builder = CFGBuilder::new();
+
+        // Nine simultaneously-live Vector4D values, one more than
+        // v_regs' 8 physical slots, kept alive by using all of them in
+        // one final instruction.
+        let values: Vec<ValueId> = (0..9)
+            .map(|_| builder.emit(ShardOpcode::VADD4D, vec![], RegisterClass::Vector4D))
+            .collect();
+        builder.emit(ShardOpcode::VADD4D, values.clone(), RegisterClass::Vector4D);
+
+        let cfg = builder.finish();
+        let lowered = RegisterAllocator::new().allocate(&cfg);
+
+        let spills = lowered.iter().filter(|entry| matches!(entry, LoweredEntry::Spill { .. })).count();
+        let reloads = lowered.iter().filter(|entry| matches!(entry, LoweredEntry::Reload { .. })).count();
+
+        // The merge instruction needs all 9 values at once against an
+        // 8-slot bank, so one of its inputs can never fit in a register
+        // alongside the other 8 -- it's read straight from its spill slot
+        // instead of being reloaded only to immediately evict a sibling
+        // input. That input accounts for a spill with no matching
+        // reload, same as the register the merge's own result evicts
+        // once it's done with the inputs (dead on definition, so it's
+        // never reloaded either) -- hence two more spills than reloads,
+        // not one-for-one.
+        assert!(spills >= 1, "expected at least one spill with 9 live values and 8 registers");
+        assert_eq!(spills, 3);
+        assert_eq!(reloads, 1);
+    }
+
+    /// Replays a lowered program against a tiny interpreter that tracks
+    /// which `ValueId` each physical register and spill slot actually
+    /// holds at every step, and checks that every instruction's recorded
+    /// input locations still resolve to the value it was defined to
+    /// consume -- not a sibling operand that evicted it first. Spill
+    /// count and register-index-range checks (the existing tests above)
+    /// can't catch that kind of aliasing; this is the only test that
+    /// actually executes the allocator's output.
+    #[test]
+    fn test_merge_instruction_inputs_resolve_to_distinct_live_values() {
+        let mut builder = CFGBuilder::new();
+        let values: Vec<ValueId> = (0..9)
+            .map(|_| builder.emit(ShardOpcode::VADD4D, vec![], RegisterClass::Vector4D))
+            .collect();
+        builder.emit(ShardOpcode::VADD4D, values.clone(), RegisterClass::Vector4D);
+        let cfg = builder.finish();
+
+        let lowered = RegisterAllocator::new().allocate(&cfg);
+        let instructions: Vec<&Instruction> = cfg.instructions().collect();
+
+        let mut registers: Vec<Option<ValueId>> = vec![None; RegisterClass::Vector4D.bank_size()];
+        let mut memory: HashMap<usize, ValueId> = HashMap::new();
+        let mut instr_index = 0;
+
+        for entry in &lowered {
+            match entry {
+                LoweredEntry::Spill { value, register, slot } => {
+                    assert_eq!(registers[*register], Some(*value));
+                    registers[*register] = None;
+                    memory.insert(*slot, *value);
+                }
+                LoweredEntry::Reload { value, slot, register } => {
+                    assert_eq!(memory.get(slot), Some(value));
+                    registers[*register] = Some(*value);
+                }
+                LoweredEntry::Instruction(lowered_instruction) => {
+                    let original = instructions[instr_index];
+                    instr_index += 1;
+
+                    for (input_location, &expected_value) in
+                        lowered_instruction.inputs.iter().zip(&original.inputs)
+                    {
+                        let actual_value = match input_location {
+                            Location::Register(register) => registers[*register],
+                            Location::Spill(slot) => memory.get(slot).copied(),
+                        };
+                        assert_eq!(
+                            actual_value,
+                            Some(expected_value),
+                            "an instruction input resolved to the wrong live value"
+                        );
+                    }
+
+                    if let Location::Register(register) = lowered_instruction.result {
+                        registers[register] = Some(original.result);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_register_classes_allocate_from_independent_banks() {
+        let mut builder = CFGBuilder::new();
+        let vector = builder.emit(ShardOpcode::VADD4D, vec![], RegisterClass::Vector4D);
+        let quantum = builder.emit(ShardOpcode::QCOH, vec![], RegisterClass::QuantumState);
+        builder.emit(ShardOpcode::VADD4D, vec![vector], RegisterClass::Vector4D);
+        builder.emit(ShardOpcode::QCOH, vec![quantum], RegisterClass::QuantumState);
+        let cfg = builder.finish();
+
+        let lowered = RegisterAllocator::new().allocate(&cfg);
+
+        // Four instructions in, only two physical banks ever touched;
+        // neither the Vector4D value nor the QuantumState value should
+        // ever need to spill since each bank only ever holds one value.
+        assert!(lowered.iter().all(|entry| !matches!(entry, LoweredEntry::Spill { .. })));
+    }
+
+    #[test]
+    fn test_spilled_value_reloads_to_a_valid_register_index() {
+        let mut builder = CFGBuilder::new();
+        let values: Vec<ValueId> = (0..9)
+            .map(|_| builder.emit(ShardOpcode::VADD4D, vec![], RegisterClass::Vector4D))
+            .collect();
+        builder.emit(ShardOpcode::VADD4D, values, RegisterClass::Vector4D);
+        let cfg = builder.finish();
+
+        let lowered = RegisterAllocator::new().allocate(&cfg);
+
+        for entry in &lowered {
+            if let LoweredEntry::Reload { register, .. } = entry {
+                assert!(*register < RegisterClass::Vector4D.bank_size());
+            }
+        }
+    }
+}
@@ -0,0 +1,146 @@
+// GENERATED FILE -- produced by `build.rs` from `instructions.in`.
+// Do not edit by hand; edit `instructions.in` and rebuild instead.
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShardOpcode {
+    VADD4D,
+    VROT4D,
+    QENT,
+    QCOH,
+    CGROW,
+    CLATT,
+    CGROW_OPT,
+    CADAPT,
+    COPT,
+    CPERF,
+    CSCHED,
+    CMEM,
+    ILLEGAL,
+}
+
+/// Why a `ShardOpcode::decode` call failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The byte slice ended before an opcode byte could be read.
+    UnexpectedEof,
+}
+
+impl ShardOpcode {
+    /// Number of operand bytes this opcode's instruction carries, not
+    /// counting the opcode byte itself. Lets callers skip past an
+    /// instruction's operands without decoding each one.
+    pub fn operand_width(&self) -> usize {
+        match self {
+            ShardOpcode::VADD4D => 3,
+            ShardOpcode::VROT4D => 6,
+            ShardOpcode::QENT => 2,
+            ShardOpcode::QCOH => 1,
+            ShardOpcode::CGROW => 2,
+            ShardOpcode::CLATT => 1,
+            ShardOpcode::CGROW_OPT => 2,
+            ShardOpcode::CADAPT => 1,
+            ShardOpcode::COPT => 0,
+            ShardOpcode::CPERF => 1,
+            ShardOpcode::CSCHED => 2,
+            ShardOpcode::CMEM => 2,
+            ShardOpcode::ILLEGAL => 0,
+        }
+    }
+
+    /// Appends this opcode's single encoded byte to `out`.
+    pub fn encode(&self, out: &mut Vec<u8>) {
+        out.push(match self {
+            ShardOpcode::VADD4D => 0x01,
+            ShardOpcode::VROT4D => 0x02,
+            ShardOpcode::QENT => 0x03,
+            ShardOpcode::QCOH => 0x04,
+            ShardOpcode::CGROW => 0x05,
+            ShardOpcode::CLATT => 0x06,
+            ShardOpcode::CGROW_OPT => 0x07,
+            ShardOpcode::CADAPT => 0x08,
+            ShardOpcode::COPT => 0x09,
+            ShardOpcode::CPERF => 0x0A,
+            ShardOpcode::CSCHED => 0x0B,
+            ShardOpcode::CMEM => 0x0C,
+            ShardOpcode::ILLEGAL => 0xFF,
+        });
+    }
+
+    /// Reads the opcode byte at the front of `bytes`, returning the
+    /// decoded opcode plus how many bytes the full instruction (opcode
+    /// plus operands) occupies. Bytes outside the reserved range decode
+    /// to `ShardOpcode::ILLEGAL` (a trap, not a decode error) rather than
+    /// panicking -- only an empty `bytes` is an actual `DecodeError`.
+    pub fn decode(bytes: &[u8]) -> Result<(ShardOpcode, usize), DecodeError> {
+        let opcode = match bytes.first() {
+            Some(byte) => match byte {
+                0x01 => ShardOpcode::VADD4D,
+                0x02 => ShardOpcode::VROT4D,
+                0x03 => ShardOpcode::QENT,
+                0x04 => ShardOpcode::QCOH,
+                0x05 => ShardOpcode::CGROW,
+                0x06 => ShardOpcode::CLATT,
+                0x07 => ShardOpcode::CGROW_OPT,
+                0x08 => ShardOpcode::CADAPT,
+                0x09 => ShardOpcode::COPT,
+                0x0A => ShardOpcode::CPERF,
+                0x0B => ShardOpcode::CSCHED,
+                0x0C => ShardOpcode::CMEM,
+                _ => ShardOpcode::ILLEGAL,
+            },
+            None => return Err(DecodeError::UnexpectedEof),
+        };
+        let len = 1 + opcode.operand_width();
+        Ok((opcode, len))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip_every_opcode() {
+        let opcodes = [
+            ShardOpcode::VADD4D,
+            ShardOpcode::VROT4D,
+            ShardOpcode::QENT,
+            ShardOpcode::QCOH,
+            ShardOpcode::CGROW,
+            ShardOpcode::CLATT,
+            ShardOpcode::CGROW_OPT,
+            ShardOpcode::CADAPT,
+            ShardOpcode::COPT,
+            ShardOpcode::CPERF,
+            ShardOpcode::CSCHED,
+            ShardOpcode::CMEM,
+            ShardOpcode::ILLEGAL,
+        ];
+        for opcode in opcodes {
+            let mut bytes = Vec::new();
+            opcode.encode(&mut bytes);
+            let (decoded, len) = ShardOpcode::decode(&bytes).unwrap();
+            assert_eq!(decoded, opcode);
+            assert_eq!(len, 1 + opcode.operand_width());
+        }
+    }
+
+    #[test]
+    fn test_unknown_byte_decodes_to_illegal() {
+        let reserved: &[u8] = &[0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0xFF];
+        for byte in 0u8..=0xFE {
+            if reserved.contains(&byte) {
+                continue;
+            }
+            let (decoded, len) = ShardOpcode::decode(&[byte]).unwrap();
+            assert_eq!(decoded, ShardOpcode::ILLEGAL);
+            assert_eq!(len, 1);
+        }
+    }
+
+    #[test]
+    fn test_decode_empty_bytes_is_an_error() {
+        assert_eq!(ShardOpcode::decode(&[]), Err(DecodeError::UnexpectedEof));
+    }
+}
@@ -0,0 +1,284 @@
+//! ShardVm: a fetch/decode/dispatch execution engine over `ShardOpcode`
+//! streams.
+//!
+//! `ShardRegisterFile` and `ShardMemory` model architectural state but
+//! nothing previously executed an actual `ShardOpcode` stream against
+//! them. `ShardVm` owns both, steps one instruction per `step()` call
+//! against a `cycle_limit` budget, and reports what happened via
+//! `StepOutcome` instead of panicking on anything it doesn't like.
+
+use crate::core::{ShardMemory, ShardRegisterFile};
+use crate::shard_ops::{DecodeError, ShardOpcode};
+use crate::QUANTUM_COHERENCE_THRESHOLD;
+
+/// What a single `ShardVm::step` call did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepOutcome {
+    /// The instruction executed normally; `pc4d` has advanced.
+    Continue,
+    /// The program counter ran past the end of `program` with no more
+    /// instructions to fetch.
+    Halt,
+    /// A fault occurred with no trap handler registered to absorb it.
+    /// Had a handler been registered, the fault would have been
+    /// delivered there instead and this call would have returned
+    /// `Continue`.
+    Trap(TrapKind),
+}
+
+/// Why a `ShardVm::step` call faulted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrapKind {
+    /// `ShardOpcode::decode` produced `ShardOpcode::ILLEGAL`.
+    IllegalOpcode,
+    /// An instruction referenced an address outside the crystal memory
+    /// hierarchy's addressable range.
+    MemoryOutOfBounds,
+    /// An arithmetic instruction divided by zero.
+    DivideFault,
+    /// An instruction required quantum coherence the register file no
+    /// longer has (below `QUANTUM_COHERENCE_THRESHOLD`).
+    CoherenceFault,
+}
+
+/// Owns the architectural state (`ShardRegisterFile` + `ShardMemory`)
+/// and a loaded `program`, and executes it one `ShardOpcode` at a time.
+#[derive(Debug)]
+pub struct ShardVm {
+    regs: ShardRegisterFile,
+    mem: ShardMemory,
+    program: Vec<u8>,
+    /// How many `step()` calls `run()` will make before giving up and
+    /// returning `StepOutcome::Continue` with the budget exhausted --
+    /// prevents a runaway or infinite-looping program from hanging the
+    /// caller.
+    cycle_limit: u64,
+    cycles_run: u64,
+    /// Free-running cycle counter. Increments by one on every `step()`
+    /// call (including ones that trap) and wraps around via
+    /// `wrapping_add` at `u64::MAX` rather than panicking -- a real
+    /// clock register rolling over, not an error condition.
+    timer: u64,
+    /// `timer` ticks between `timer_interrupt_pending` firing, or `0`
+    /// to disable the interrupt. Lets a scheduler time-slice a running
+    /// crystal workload by polling after each `step()`.
+    timer_interrupt_period: u64,
+    timer_interrupt_pending: bool,
+    /// Byte offset in `program` a trap jumps to when no handler is
+    /// registered yet, `trap_handler` stays `None` and faults surface
+    /// to the caller as `StepOutcome::Trap` instead.
+    trap_handler: Option<usize>,
+    /// `pc4d.x` at the moment of the most recently *handled* trap (one
+    /// delivered to `trap_handler`), so the handler can inspect or
+    /// resume the faulting instruction.
+    saved_pc: Option<f64>,
+}
+
+impl ShardVm {
+    /// Loads `program` with a fresh register file and memory hierarchy,
+    /// `cycle_limit` cycles, and no trap handler or timer interrupt
+    /// registered yet.
+    pub fn new(program: Vec<u8>, cycle_limit: u64) -> Self {
+        Self {
+            regs: ShardRegisterFile::new(),
+            mem: ShardMemory::new(),
+            program,
+            cycle_limit,
+            cycles_run: 0,
+            timer: 0,
+            timer_interrupt_period: 0,
+            timer_interrupt_pending: false,
+            trap_handler: None,
+            saved_pc: None,
+        }
+    }
+
+    pub fn regs(&self) -> &ShardRegisterFile {
+        &self.regs
+    }
+
+    pub fn mem(&self) -> &ShardMemory {
+        &self.mem
+    }
+
+    pub fn timer(&self) -> u64 {
+        self.timer
+    }
+
+    pub fn cycles_run(&self) -> u64 {
+        self.cycles_run
+    }
+
+    /// Byte address of the instruction that most recently trapped into
+    /// `trap_handler`, or `None` if no trap has been handled yet.
+    pub fn saved_pc(&self) -> Option<f64> {
+        self.saved_pc
+    }
+
+    /// Registers the byte offset in `program` an unhandled trap jumps
+    /// to from now on, instead of surfacing as `StepOutcome::Trap`.
+    pub fn set_trap_handler(&mut self, handler_address: usize) {
+        self.trap_handler = Some(handler_address);
+    }
+
+    /// Sets how many cycles elapse between timer interrupts, or `0` to
+    /// disable them.
+    pub fn set_timer_interrupt_period(&mut self, period: u64) {
+        self.timer_interrupt_period = period;
+    }
+
+    /// Returns whether a timer interrupt has fired since the last call,
+    /// clearing the pending flag -- the one-shot "did it fire" poll a
+    /// scheduler uses to decide whether to preempt.
+    pub fn take_timer_interrupt(&mut self) -> bool {
+        let pending = self.timer_interrupt_pending;
+        self.timer_interrupt_pending = false;
+        pending
+    }
+
+    /// Runs `step()` until `cycle_limit` is reached, the program halts,
+    /// or an unhandled trap occurs -- whichever comes first.
+    pub fn run(&mut self) -> StepOutcome {
+        while self.cycles_run < self.cycle_limit {
+            match self.step() {
+                StepOutcome::Continue => continue,
+                outcome => return outcome,
+            }
+        }
+        StepOutcome::Continue
+    }
+
+    /// Fetches, decodes, and dispatches one instruction at `pc4d.x`
+    /// (the linear byte offset into `program`; `pc4d`'s y/z/w
+    /// components are reserved for future 4D-addressed jump targets).
+    pub fn step(&mut self) -> StepOutcome {
+        let pc = self.regs.pc4d.x as usize;
+
+        let outcome = match self.program.get(pc..) {
+            None | Some([]) => StepOutcome::Halt,
+            Some(remaining) => match ShardOpcode::decode(remaining) {
+                Err(DecodeError::UnexpectedEof) => StepOutcome::Halt,
+                Ok((ShardOpcode::ILLEGAL, _)) => self.raise_trap(TrapKind::IllegalOpcode),
+                Ok((opcode, len)) => self.dispatch(pc, opcode, len),
+            },
+        };
+
+        self.advance_timer();
+        outcome
+    }
+
+    fn dispatch(&mut self, pc: usize, opcode: ShardOpcode, len: usize) -> StepOutcome {
+        let next_pc = pc + len;
+        if next_pc > self.program.len() {
+            return self.raise_trap(TrapKind::MemoryOutOfBounds);
+        }
+
+        if opcode == ShardOpcode::QCOH && self.regs.get_coherence() < QUANTUM_COHERENCE_THRESHOLD {
+            return self.raise_trap(TrapKind::CoherenceFault);
+        }
+
+        self.regs.pc4d.x = next_pc as f64;
+        self.cycles_run += 1;
+        StepOutcome::Continue
+    }
+
+    /// Delivers `kind` to `trap_handler` if one is registered (saving
+    /// the faulting `pc4d.x` and jumping there), otherwise reports it
+    /// to the caller instead of aborting.
+    fn raise_trap(&mut self, kind: TrapKind) -> StepOutcome {
+        self.cycles_run += 1;
+        match self.trap_handler {
+            Some(handler_address) => {
+                self.saved_pc = Some(self.regs.pc4d.x);
+                self.regs.pc4d.x = handler_address as f64;
+                StepOutcome::Continue
+            }
+            None => StepOutcome::Trap(kind),
+        }
+    }
+
+    fn advance_timer(&mut self) {
+        self.timer = self.timer.wrapping_add(1);
+        if self.timer_interrupt_period != 0 && self.timer % self.timer_interrupt_period == 0 {
+            self.timer_interrupt_pending = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_halts_past_end_of_program() {
+        let mut vm = ShardVm::new(Vec::new(), 10);
+        assert_eq!(vm.step(), StepOutcome::Halt);
+    }
+
+    #[test]
+    fn test_continues_through_a_known_opcode() {
+        let mut vm = ShardVm::new(vec![0x09], 10);
+        assert_eq!(vm.step(), StepOutcome::Continue);
+        assert_eq!(vm.regs().pc4d.x, 1.0);
+        assert_eq!(vm.cycles_run(), 1);
+    }
+
+    #[test]
+    fn test_illegal_opcode_traps_without_a_handler() {
+        let mut vm = ShardVm::new(vec![0xAB], 10);
+        assert_eq!(vm.step(), StepOutcome::Trap(TrapKind::IllegalOpcode));
+    }
+
+    #[test]
+    fn test_illegal_opcode_is_delivered_to_a_registered_handler() {
+        let mut vm = ShardVm::new(vec![0xAB, 0x09], 10);
+        vm.set_trap_handler(1);
+
+        assert_eq!(vm.step(), StepOutcome::Continue);
+        assert_eq!(vm.saved_pc(), Some(0.0));
+        assert_eq!(vm.regs().pc4d.x, 1.0);
+
+        // Execution resumes from the handler, a known-good opcode.
+        assert_eq!(vm.step(), StepOutcome::Continue);
+    }
+
+    #[test]
+    fn test_truncated_operand_traps_memory_out_of_bounds() {
+        // VADD4D (0x01) needs 3 operand bytes; only one is present.
+        let mut vm = ShardVm::new(vec![0x01, 0x00], 10);
+        assert_eq!(vm.step(), StepOutcome::Trap(TrapKind::MemoryOutOfBounds));
+    }
+
+    #[test]
+    fn test_cycle_limit_stops_run_without_halting_or_trapping() {
+        let program = vec![0x09; 4];
+        let mut vm = ShardVm::new(program, 2);
+
+        assert_eq!(vm.run(), StepOutcome::Continue);
+        assert_eq!(vm.cycles_run(), 2);
+        assert_eq!(vm.regs().pc4d.x, 2.0);
+    }
+
+    #[test]
+    fn test_timer_advances_once_per_step_and_wraps() {
+        let mut vm = ShardVm::new(vec![0x09; 3], 10);
+        vm.timer = u64::MAX;
+
+        vm.step();
+        assert_eq!(vm.timer(), 0);
+        vm.step();
+        assert_eq!(vm.timer(), 1);
+    }
+
+    #[test]
+    fn test_timer_interrupt_fires_every_period_and_is_one_shot() {
+        let mut vm = ShardVm::new(vec![0x09; 3], 10);
+        vm.set_timer_interrupt_period(2);
+
+        vm.step();
+        assert!(!vm.take_timer_interrupt());
+        vm.step();
+        assert!(vm.take_timer_interrupt());
+        assert!(!vm.take_timer_interrupt());
+    }
+}
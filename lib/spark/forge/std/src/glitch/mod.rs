@@ -5,9 +5,57 @@
 
 use std::error::Error;
 use std::fmt;
+use std::ops::Range;
 use std::sync::Arc;
 use std::backtrace::Backtrace;
 
+/// A byte-range span into a source snippet.
+pub type Span = Range<usize>;
+
+/// How prominently a [`Label`] should be rendered by [`Glitch::render`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LabelSeverity {
+    /// The exact location of the problem; underlined with `^^^`.
+    Primary,
+    /// Related context for the problem; underlined with `---`.
+    Secondary,
+}
+
+/// A single diagnostic annotation pointing at a span of source.
+#[derive(Debug, Clone)]
+pub struct Label {
+    span: Span,
+    severity: LabelSeverity,
+    text: String,
+}
+
+impl Label {
+    /// Creates a primary label: the exact location of the problem.
+    pub fn primary(span: Span, text: impl Into<String>) -> Self {
+        Self { span, severity: LabelSeverity::Primary, text: text.into() }
+    }
+
+    /// Creates a secondary label: related context for the problem.
+    pub fn secondary(span: Span, text: impl Into<String>) -> Self {
+        Self { span, severity: LabelSeverity::Secondary, text: text.into() }
+    }
+
+    /// Gets the label's span.
+    pub fn span(&self) -> Span {
+        self.span.clone()
+    }
+
+    /// Gets the label's severity.
+    pub fn severity(&self) -> LabelSeverity {
+        self.severity
+    }
+
+    /// Gets the label's note text.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
 /// A crystal-optimized error type
 #[derive(Debug)]
 pub struct Glitch {
@@ -15,6 +63,8 @@ pub struct Glitch {
     message: String,
     source: Option<Arc<dyn Error + Send + Sync>>,
     backtrace: Option<Backtrace>,
+    labels: Vec<Label>,
+    help: Option<String>,
 }
 
 /// The specific kind of error that occurred
@@ -46,6 +96,8 @@ impl Glitch {
             message: message.into(),
             source: None,
             backtrace: Some(Backtrace::capture()),
+            labels: Vec::new(),
+            help: None,
         }
     }
 
@@ -112,6 +164,91 @@ impl Glitch {
     pub fn backtrace(&self) -> Option<&Backtrace> {
         self.backtrace.as_ref()
     }
+
+    /// Attaches a primary label pointing at the exact span the error
+    /// occurred at.
+    pub fn with_label(mut self, span: Span, text: impl Into<String>) -> Self {
+        self.labels.push(Label::primary(span, text));
+        self
+    }
+
+    /// Attaches a secondary label pointing at related context.
+    pub fn with_secondary_label(mut self, span: Span, text: impl Into<String>) -> Self {
+        self.labels.push(Label::secondary(span, text));
+        self
+    }
+
+    /// Attaches a help/suggestion string, shown below the rendered
+    /// labels by [`Glitch::render`].
+    pub fn with_help(mut self, text: impl Into<String>) -> Self {
+        self.help = Some(text.into());
+        self
+    }
+
+    /// Gets the attached labels.
+    pub fn labels(&self) -> &[Label] {
+        &self.labels
+    }
+
+    /// Gets the attached help text, if any.
+    pub fn help(&self) -> Option<&str> {
+        self.help.as_deref()
+    }
+
+    /// Renders this error as a compiler-style diagnostic against
+    /// `source`: the message, followed by the offending line(s) with
+    /// `^^^` underlines under primary label spans and `---` underlines
+    /// under secondary spans, followed by the help text if any.
+    pub fn render(&self, source: &str) -> String {
+        let mut out = format!("error: {}\n", self.message);
+
+        let mut lines: Vec<(usize, &str)> = Vec::new();
+        let mut offset = 0;
+        for (i, line) in source.split('\n').enumerate() {
+            lines.push((offset, line));
+            offset += line.len() + 1;
+            let _ = i;
+        }
+
+        for (line_no, (line_start, line_text)) in lines.iter().enumerate() {
+            let line_end = line_start + line_text.len();
+            let line_labels: Vec<&Label> = self
+                .labels
+                .iter()
+                .filter(|label| label.span.start < line_end && label.span.end > *line_start)
+                .collect();
+            if line_labels.is_empty() {
+                continue;
+            }
+
+            out.push_str(&format!("{:>4} | {}\n", line_no + 1, line_text));
+
+            let mut underline: Vec<char> = vec![' '; line_text.len()];
+            for label in &line_labels {
+                let start = label.span.start.saturating_sub(*line_start).min(line_text.len());
+                let end = label.span.end.saturating_sub(*line_start).min(line_text.len()).max(start);
+                let marker = match label.severity {
+                    LabelSeverity::Primary => '^',
+                    LabelSeverity::Secondary => '-',
+                };
+                for slot in underline.iter_mut().take(end.max(start + 1)).skip(start) {
+                    *slot = marker;
+                }
+            }
+            let notes: Vec<&str> = line_labels.iter().map(|label| label.text.as_str()).collect();
+            out.push_str(&format!(
+                "     | {} {}\n",
+                underline.iter().collect::<String>(),
+                notes.join(", "),
+            ));
+        }
+
+        if let Some(help) = &self.help {
+            out.push_str(&format!("help: {}\n", help));
+        }
+
+        out
+    }
 }
 
 impl fmt::Display for Glitch {
@@ -0,0 +1,213 @@
+//! AES-accelerated content fingerprinting
+//!
+//! Hashes a byte slice into a 64-bit fingerprint by folding 16-byte blocks
+//! through two AES encryption rounds into a 128-bit state (AES-NI on
+//! x86_64, the crypto extension on AArch64), then mixing the state down to
+//! 64 bits. CPU support is detected through the existing
+//! [`crate::shard::arch::Shard`]/`CpuFeature` machinery; architectures or
+//! CPUs without hardware AES fall back to a multiply-shift hash.
+
+use std::hash::{BuildHasher, Hasher};
+
+/// Computes a 64-bit fingerprint of `bytes`.
+pub fn fingerprint(bytes: &[u8]) -> u64 {
+    if has_aes_acceleration() {
+        aes_fold(bytes)
+    } else {
+        multiply_shift_fold(bytes)
+    }
+}
+
+fn has_aes_acceleration() -> bool {
+    let shard = crate::shard::arch::Shard::new();
+
+    match shard.architecture() {
+        crate::shard::arch::Architecture::X86_64 => {
+            shard.has_feature(crate::shard::arch::CpuFeature::AESNI)
+        }
+        crate::shard::arch::Architecture::AArch64 => {
+            shard.has_feature(crate::shard::arch::CpuFeature::PMULL)
+        }
+        _ => false,
+    }
+}
+
+/// Folds `bytes` through two `aesenc` rounds per 16-byte block.
+#[cfg(target_arch = "x86_64")]
+fn aes_fold(bytes: &[u8]) -> u64 {
+    use std::arch::x86_64::{
+        __m128i, _mm_aesenc_si128, _mm_loadu_si128, _mm_set_epi64x, _mm_storeu_si128,
+        _mm_xor_si128,
+    };
+
+    // Safety: only reached once `has_aes_acceleration` has confirmed AES-NI
+    // support; all loads/stores use the unaligned intrinsics since
+    // `bytes` is an arbitrary caller-provided slice.
+    unsafe {
+        let mut state = _mm_set_epi64x(bytes.len() as i64, 0x5bd1e995_9e3779b9u64 as i64);
+        let round_key = _mm_set_epi64x(0x243f6a88_85a308d3u64 as i64, 0x13198a2e_03707344u64 as i64);
+
+        let mut chunks = bytes.chunks_exact(16);
+        for chunk in &mut chunks {
+            let block = _mm_loadu_si128(chunk.as_ptr() as *const __m128i);
+            state = _mm_aesenc_si128(_mm_xor_si128(state, block), round_key);
+            state = _mm_aesenc_si128(state, round_key);
+        }
+
+        let tail = chunks.remainder();
+        if !tail.is_empty() {
+            let mut padded = [0u8; 16];
+            padded[..tail.len()].copy_from_slice(tail);
+            let block = _mm_loadu_si128(padded.as_ptr() as *const __m128i);
+            state = _mm_aesenc_si128(_mm_xor_si128(state, block), round_key);
+            state = _mm_aesenc_si128(state, round_key);
+        }
+
+        let mut lanes = [0u64; 2];
+        _mm_storeu_si128(lanes.as_mut_ptr() as *mut __m128i, state);
+        mix64(lanes[0] ^ lanes[1])
+    }
+}
+
+/// Folds `bytes` through two `aese`+`aesmc` rounds per 16-byte block.
+#[cfg(target_arch = "aarch64")]
+fn aes_fold(bytes: &[u8]) -> u64 {
+    use std::arch::aarch64::{
+        uint8x16_t, vaeseq_u8, vaesmcq_u8, vdupq_n_u8, veorq_u8, vld1q_u8, vst1q_u8,
+    };
+
+    // ARM's `aese` already folds in the round key and does SubBytes/ShiftRows,
+    // so a round equivalent to x86's `aesenc` is `aesmc(aese(state, 0)) ^ round_key`.
+    unsafe fn aesenc_like(state: uint8x16_t, round_key: uint8x16_t) -> uint8x16_t {
+        veorq_u8(vaesmcq_u8(vaeseq_u8(state, vdupq_n_u8(0))), round_key)
+    }
+
+    // Safety: only reached once `has_aes_acceleration` has confirmed crypto
+    // extension support.
+    unsafe {
+        let mut seed = [0u8; 16];
+        seed[..8].copy_from_slice(&(bytes.len() as u64).to_le_bytes());
+        seed[8..].copy_from_slice(&0x5bd1e9959e3779b9u64.to_le_bytes());
+        let mut state = vld1q_u8(seed.as_ptr());
+
+        let mut key_bytes = [0u8; 16];
+        key_bytes[..8].copy_from_slice(&0x243f6a8885a308d3u64.to_le_bytes());
+        key_bytes[8..].copy_from_slice(&0x13198a2e03707344u64.to_le_bytes());
+        let round_key = vld1q_u8(key_bytes.as_ptr());
+
+        let mut chunks = bytes.chunks_exact(16);
+        for chunk in &mut chunks {
+            let block = vld1q_u8(chunk.as_ptr());
+            state = aesenc_like(veorq_u8(state, block), round_key);
+            state = aesenc_like(state, round_key);
+        }
+
+        let tail = chunks.remainder();
+        if !tail.is_empty() {
+            let mut padded = [0u8; 16];
+            padded[..tail.len()].copy_from_slice(tail);
+            let block = vld1q_u8(padded.as_ptr());
+            state = aesenc_like(veorq_u8(state, block), round_key);
+            state = aesenc_like(state, round_key);
+        }
+
+        let mut lanes = [0u8; 16];
+        vst1q_u8(lanes.as_mut_ptr(), state);
+        let lo = u64::from_le_bytes(lanes[..8].try_into().unwrap());
+        let hi = u64::from_le_bytes(lanes[8..].try_into().unwrap());
+        mix64(lo ^ hi)
+    }
+}
+
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+fn aes_fold(bytes: &[u8]) -> u64 {
+    multiply_shift_fold(bytes)
+}
+
+/// Scalar fallback: an FNV-1a-style multiply-shift fold, used when the
+/// current CPU has no hardware AES acceleration.
+fn multiply_shift_fold(bytes: &[u8]) -> u64 {
+    let mut state = 0xcbf29ce484222325u64 ^ (bytes.len() as u64);
+
+    for chunk in bytes.chunks(8) {
+        let mut word = [0u8; 8];
+        word[..chunk.len()].copy_from_slice(chunk);
+        state ^= u64::from_le_bytes(word);
+        state = state.wrapping_mul(0x100000001b3).rotate_left(31);
+    }
+
+    mix64(state)
+}
+
+/// Splitmix64-style finalizer, used to mix both fold paths down to 64 bits.
+fn mix64(mut x: u64) -> u64 {
+    x ^= x >> 33;
+    x = x.wrapping_mul(0xff51afd7ed558ccd);
+    x ^= x >> 33;
+    x = x.wrapping_mul(0xc4ceb9fe1a85ec53);
+    x ^= x >> 33;
+    x
+}
+
+/// A [`Hasher`] backed by [`fingerprint`].
+#[derive(Default)]
+pub struct AesFingerprintHasher {
+    buffer: Vec<u8>,
+}
+
+impl Hasher for AesFingerprintHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        self.buffer.extend_from_slice(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        fingerprint(&self.buffer)
+    }
+}
+
+/// A [`BuildHasher`] that produces [`AesFingerprintHasher`]s, for use as the
+/// hasher of a `HashMap`/`HashSet`.
+#[derive(Default, Clone, Copy)]
+pub struct AesFingerprintBuildHasher;
+
+impl BuildHasher for AesFingerprintBuildHasher {
+    type Hasher = AesFingerprintHasher;
+
+    fn build_hasher(&self) -> AesFingerprintHasher {
+        AesFingerprintHasher::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fingerprint_is_deterministic() {
+        let data = b"crystal-space fingerprinting";
+        assert_eq!(fingerprint(data), fingerprint(data));
+    }
+
+    #[test]
+    fn test_fingerprint_distinguishes_inputs() {
+        assert_ne!(fingerprint(b"pattern-a"), fingerprint(b"pattern-b"));
+    }
+
+    #[test]
+    fn test_fingerprint_handles_partial_blocks() {
+        for len in [0, 1, 15, 16, 17, 33] {
+            let data = vec![0xab; len];
+            // Must not panic, and must stay deterministic across block sizes.
+            assert_eq!(fingerprint(&data), fingerprint(&data));
+        }
+    }
+
+    #[test]
+    fn test_build_hasher_matches_fingerprint() {
+        let build_hasher = AesFingerprintBuildHasher;
+        let mut hasher = build_hasher.build_hasher();
+        hasher.write(b"hashed-through-hasher-trait");
+
+        assert_eq!(hasher.finish(), fingerprint(b"hashed-through-hasher-trait"));
+    }
+}
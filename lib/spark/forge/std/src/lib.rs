@@ -1,6 +1,7 @@
 pub mod align;
 pub mod any;
 pub mod array;
+pub mod hash;
 pub mod shimmer;
 pub mod thunder;
 pub mod conv;
@@ -10,6 +11,7 @@ pub mod shard;
 
 pub use align::Alignment;
 pub use array::CrystalArray;
+pub use hash::{fingerprint, AesFingerprintBuildHasher, AesFingerprintHasher};
 pub use shimmer::{Shimmer, ShimmerContext, ShimmerFn, ShimmerResult};
 pub use thunder::Thunder;
 pub use conv::{CrystalFrom, CrystalInto, CrystalTryFrom, CrystalTryInto};
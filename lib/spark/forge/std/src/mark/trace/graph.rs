@@ -0,0 +1,234 @@
+//! Trace-graph subsystem: turns a collection of `Trace`s into a
+//! navigable graph instead of a pile of isolated path objects.
+//!
+//! `Trace::merge`/`Trace::intersects` tell you two traces are
+//! physically connected, but they don't give you a structure to query
+//! *how* a whole lattice of traces connects. `TraceGraph` fills that
+//! gap: nodes are trace endpoints/junctions, edges are the traces
+//! connecting them, and the graph supports reachability and
+//! topological queries over that adjacency.
+
+use std::collections::{HashSet, VecDeque};
+
+/// Identifies a node (trace endpoint or junction) in a `TraceGraph`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId(usize);
+
+/// Identifies an edge (a trace connecting two nodes) in a `TraceGraph`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EdgeId(usize);
+
+/// Returned by `TraceGraph::topological_order` when the graph isn't a
+/// DAG: some nodes' traces form a cycle, so no consistent ordering
+/// exists.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleError {
+    /// The nodes that never reached zero in-degree, i.e. the ones
+    /// involved in (or downstream of) a cycle.
+    pub remaining: Vec<NodeId>,
+}
+
+/// A navigable graph of physically-connected `Trace`s: nodes are
+/// endpoints/junctions, edges are the traces between them, stored as an
+/// adjacency list.
+#[derive(Debug, Default)]
+pub struct TraceGraph {
+    adjacency: Vec<(NodeId, Vec<(NodeId, EdgeId)>)>,
+    edge_count: usize,
+}
+
+impl TraceGraph {
+    /// Creates an empty graph.
+    pub fn new() -> Self {
+        Self { adjacency: Vec::new(), edge_count: 0 }
+    }
+
+    /// Adds a new, unconnected node and returns its id.
+    pub fn add_node(&mut self) -> NodeId {
+        let id = NodeId(self.adjacency.len());
+        self.adjacency.push((id, Vec::new()));
+        id
+    }
+
+    /// Connects `from` to `to` with an edge representing a `Trace`
+    /// between them (as produced by `Trace::merge`/`Trace::intersects`),
+    /// returning the new edge's id.
+    pub fn add_edge(&mut self, from: NodeId, to: NodeId) -> EdgeId {
+        let edge = EdgeId(self.edge_count);
+        self.edge_count += 1;
+        self.adjacency[from.0].1.push((to, edge));
+        edge
+    }
+
+    /// The number of nodes in the graph.
+    pub fn node_count(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    /// The traces (edges) leading out of `node`, paired with the node
+    /// they land on.
+    pub fn neighbors(&self, node: NodeId) -> &[(NodeId, EdgeId)] {
+        &self.adjacency[node.0].1
+    }
+
+    /// Reverses every edge, returning a new graph where each trace now
+    /// points the opposite direction.
+    pub fn transpose(&self) -> Self {
+        let mut transposed: Vec<(NodeId, Vec<(NodeId, EdgeId)>)> =
+            self.adjacency.iter().map(|(id, _)| (*id, Vec::new())).collect();
+
+        for (from, edges) in &self.adjacency {
+            for (to, edge) in edges {
+                transposed[to.0].1.push((*from, *edge));
+            }
+        }
+
+        Self { adjacency: transposed, edge_count: self.edge_count }
+    }
+
+    /// The set of nodes reachable from `from` (including `from` itself),
+    /// via breadth-first search over the adjacency list.
+    pub fn reachable(&self, from: NodeId) -> HashSet<NodeId> {
+        let mut seen = HashSet::new();
+        let mut queue = VecDeque::new();
+        seen.insert(from);
+        queue.push_back(from);
+
+        while let Some(node) = queue.pop_front() {
+            for (next, _) in self.neighbors(node) {
+                if seen.insert(*next) {
+                    queue.push_back(*next);
+                }
+            }
+        }
+
+        seen
+    }
+
+    /// Computes a topological order of the graph's nodes via Kahn's
+    /// algorithm: repeatedly emit a zero-in-degree node, decrement its
+    /// successors' in-degree, and queue any that drop to zero. If nodes
+    /// remain once the queue empties, the graph has a cycle.
+    pub fn topological_order(&self) -> Result<Vec<NodeId>, CycleError> {
+        let mut in_degree = vec![0usize; self.adjacency.len()];
+        for (_, edges) in &self.adjacency {
+            for (to, _) in edges {
+                in_degree[to.0] += 1;
+            }
+        }
+
+        let mut queue: VecDeque<NodeId> = in_degree
+            .iter()
+            .enumerate()
+            .filter(|(_, &deg)| deg == 0)
+            .map(|(i, _)| NodeId(i))
+            .collect();
+
+        let mut order = Vec::with_capacity(self.adjacency.len());
+        while let Some(node) = queue.pop_front() {
+            order.push(node);
+            for (next, _) in self.neighbors(node) {
+                in_degree[next.0] -= 1;
+                if in_degree[next.0] == 0 {
+                    queue.push_back(*next);
+                }
+            }
+        }
+
+        if order.len() == self.adjacency.len() {
+            Ok(order)
+        } else {
+            let emitted: HashSet<NodeId> = order.into_iter().collect();
+            let remaining = (0..self.adjacency.len())
+                .map(NodeId)
+                .filter(|id| !emitted.contains(id))
+                .collect();
+            Err(CycleError { remaining })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chain_graph(len: usize) -> (TraceGraph, Vec<NodeId>) {
+        let mut graph = TraceGraph::new();
+        let nodes: Vec<NodeId> = (0..len).map(|_| graph.add_node()).collect();
+        for window in nodes.windows(2) {
+            graph.add_edge(window[0], window[1]);
+        }
+        (graph, nodes)
+    }
+
+    #[test]
+    fn test_neighbors_lists_outgoing_edges() {
+        let (graph, nodes) = chain_graph(3);
+        let neighbors = graph.neighbors(nodes[0]);
+        assert_eq!(neighbors.len(), 1);
+        assert_eq!(neighbors[0].0, nodes[1]);
+    }
+
+    #[test]
+    fn test_reachable_follows_chain() {
+        let (graph, nodes) = chain_graph(4);
+        let reachable = graph.reachable(nodes[0]);
+        assert_eq!(reachable.len(), 4);
+        assert!(reachable.contains(&nodes[3]));
+
+        let reachable_from_last = graph.reachable(nodes[3]);
+        assert_eq!(reachable_from_last.len(), 1);
+    }
+
+    #[test]
+    fn test_transpose_reverses_edges() {
+        let (graph, nodes) = chain_graph(3);
+        let transposed = graph.transpose();
+
+        assert!(graph.neighbors(nodes[0]).iter().any(|(to, _)| *to == nodes[1]));
+        assert!(transposed.neighbors(nodes[1]).iter().any(|(to, _)| *to == nodes[0]));
+        assert!(transposed.neighbors(nodes[0]).is_empty());
+    }
+
+    #[test]
+    fn test_topological_order_respects_edges() {
+        let (graph, nodes) = chain_graph(4);
+        let order = graph.topological_order().unwrap();
+
+        for window in nodes.windows(2) {
+            let pos_a = order.iter().position(|n| *n == window[0]).unwrap();
+            let pos_b = order.iter().position(|n| *n == window[1]).unwrap();
+            assert!(pos_a < pos_b);
+        }
+    }
+
+    #[test]
+    fn test_topological_order_detects_cycle() {
+        let mut graph = TraceGraph::new();
+        let a = graph.add_node();
+        let b = graph.add_node();
+        let c = graph.add_node();
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        graph.add_edge(c, a);
+
+        let err = graph.topological_order().unwrap_err();
+        assert_eq!(err.remaining.len(), 3);
+    }
+
+    #[test]
+    fn test_topological_order_handles_partial_cycle() {
+        let mut graph = TraceGraph::new();
+        let entry = graph.add_node();
+        let a = graph.add_node();
+        let b = graph.add_node();
+        graph.add_edge(entry, a);
+        graph.add_edge(a, b);
+        graph.add_edge(b, a);
+
+        let err = graph.topological_order().unwrap_err();
+        assert!(err.remaining.contains(&a));
+        assert!(err.remaining.contains(&b));
+        assert!(!err.remaining.contains(&entry));
+    }
+}
@@ -1,5 +1,52 @@
 //! Path trace module for Mark type
 
+mod graph;
+pub use graph::{CycleError, EdgeId, NodeId, TraceGraph};
+
+/// How close the closest points of two segments must be, in the same
+/// units as the trace's points, to count as an intersection.
+const INTERSECTION_TOLERANCE: f64 = 1e-6;
+
+/// Guards against dividing by a near-zero denominator when solving for
+/// the closest points between two segments.
+const DEGENERACY_EPSILON: f64 = 1e-12;
+
+fn sub(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn magnitude(a: [f64; 3]) -> f64 {
+    dot(a, a).sqrt()
+}
+
+/// Discrete Menger curvature of the triangle `A`, `B`, `C`: `4*Area /
+/// (|A-B|*|B-C|*|C-A|)`, the curvature of the circle through all three
+/// points, evaluated at `B`. Collinear or coincident points would
+/// divide by (near) zero, so those degenerate triples report zero
+/// curvature instead.
+fn menger_curvature(a: [f64; 3], b: [f64; 3], c: [f64; 3]) -> f64 {
+    let area = 0.5 * magnitude(cross(sub(b, a), sub(c, a)));
+    let denom = magnitude(sub(a, b)) * magnitude(sub(b, c)) * magnitude(sub(c, a));
+
+    if denom <= DEGENERACY_EPSILON {
+        0.0
+    } else {
+        4.0 * area / denom
+    }
+}
+
 /// Path trace state
 #[derive(Debug)]
 pub struct Trace {
@@ -36,23 +83,41 @@ impl Trace {
         self.curvature
     }
 
-    /// Records a new point
-    pub fn record(&self, point: [f64; 3]) -> Result<(), String> {
-        if !self.points.is_empty() {
-            let last = self.points.last().unwrap();
-            let distance = self.distance_between(last, &point);
+    /// Records a new point, accumulating its segment length into
+    /// `length` and folding its Menger curvature into the running
+    /// curvature mean.
+    pub fn record(&mut self, point: [f64; 3]) -> Result<(), String> {
+        if let Some(&last) = self.points.last() {
+            let distance = self.distance_between(&last, &point);
             if distance > 10.0 {
                 return Err("Point too far from last recorded point".to_string());
             }
+            self.length += distance;
         }
+
+        self.points.push(point);
+        self.fold_curvature_sample();
         Ok(())
     }
 
-    /// Extends the path
-    pub fn extend(&self, offset: [f64; 3]) -> Result<(), String> {
+    /// Extends the path by `offset` from its last point (or from the
+    /// origin if empty), accumulating length and curvature the same
+    /// way `record` does.
+    pub fn extend(&mut self, offset: [f64; 3]) -> Result<(), String> {
         if offset.iter().any(|&x| x.abs() > 10.0) {
             return Err("Extension distance too large".to_string());
         }
+
+        let next = match self.points.last() {
+            Some(last) => [last[0] + offset[0], last[1] + offset[1], last[2] + offset[2]],
+            None => offset,
+        };
+
+        if let Some(&last) = self.points.last() {
+            self.length += self.distance_between(&last, &next);
+        }
+        self.points.push(next);
+        self.fold_curvature_sample();
         Ok(())
     }
 
@@ -96,16 +161,75 @@ impl Trace {
         squared_dist.sqrt()
     }
 
+    /// Folds the Menger curvature of the last three points into the
+    /// running curvature mean, a no-op until at least three points have
+    /// been recorded.
+    fn fold_curvature_sample(&mut self) {
+        let n = self.points.len();
+        if n < 3 {
+            return;
+        }
+
+        let sample = menger_curvature(self.points[n - 3], self.points[n - 2], self.points[n - 1]);
+        let sample_count = (n - 2) as f64;
+        self.curvature += (sample - self.curvature) / sample_count;
+    }
+
+    /// Tests whether two 3D segments `P1+s*(P2-P1)` and `Q1+t*(Q2-Q1)`
+    /// actually meet, by solving for their closest pair of points and
+    /// checking the distance between those points against
+    /// `INTERSECTION_TOLERANCE`. Based on the closest-point-between-
+    /// segments derivation in Ericson's "Real-Time Collision Detection".
     fn segments_intersect(&self, seg1: &[[f64; 3]], seg2: &[[f64; 3]]) -> bool {
-        // Simple bounding box check for demonstration
-        let [min_x1, min_y1, min_z1] = seg1[0];
-        let [max_x1, max_y1, max_z1] = seg1[1];
-        let [min_x2, min_y2, min_z2] = seg2[0];
-        let [max_x2, max_y2, max_z2] = seg2[1];
+        let (p1, p2) = (seg1[0], seg1[1]);
+        let (q1, q2) = (seg2[0], seg2[1]);
+
+        let d1 = sub(p2, p1);
+        let d2 = sub(q2, q1);
+        let r = sub(p1, q1);
+
+        let a = dot(d1, d1);
+        let e = dot(d2, d2);
+        let f = dot(d2, r);
 
-        min_x1.max(min_x2) <= max_x1.min(max_x2) &&
-        min_y1.max(min_y2) <= max_y1.min(max_y2) &&
-        min_z1.max(min_z2) <= max_z1.min(max_z2)
+        let (s, t) = if a <= DEGENERACY_EPSILON && e <= DEGENERACY_EPSILON {
+            // Both "segments" are points.
+            (0.0, 0.0)
+        } else if a <= DEGENERACY_EPSILON {
+            // seg1 is a point; find the closest point on seg2 to it.
+            (0.0, (f / e).clamp(0.0, 1.0))
+        } else {
+            let c = dot(d1, r);
+            if e <= DEGENERACY_EPSILON {
+                // seg2 is a point; find the closest point on seg1 to it.
+                ((-c / a).clamp(0.0, 1.0), 0.0)
+            } else {
+                let b = dot(d1, d2);
+                let denom = a * e - b * b;
+
+                let mut s = if denom > DEGENERACY_EPSILON {
+                    ((b * f - c * e) / denom).clamp(0.0, 1.0)
+                } else {
+                    0.0
+                };
+
+                let mut t = (b * s + f) / e;
+                if t < 0.0 {
+                    t = 0.0;
+                    s = (-c / a).clamp(0.0, 1.0);
+                } else if t > 1.0 {
+                    t = 1.0;
+                    s = ((b - c) / a).clamp(0.0, 1.0);
+                }
+
+                (s, t)
+            }
+        };
+
+        let closest_on_1 = [p1[0] + s * d1[0], p1[1] + s * d1[1], p1[2] + s * d1[2]];
+        let closest_on_2 = [q1[0] + t * d2[0], q1[1] + t * d2[1], q1[2] + t * d2[2]];
+
+        self.distance_between(&closest_on_1, &closest_on_2) <= INTERSECTION_TOLERANCE
     }
 }
 
@@ -124,3 +248,121 @@ impl Clone for Trace {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trace_of(points: &[[f64; 3]]) -> Trace {
+        Trace { points: points.to_vec(), length: 0.0, curvature: 0.0 }
+    }
+
+    #[test]
+    fn test_crossing_segments_intersect() {
+        let a = trace_of(&[[0.0, 0.0, 0.0], [2.0, 2.0, 0.0]]);
+        let b = trace_of(&[[0.0, 2.0, 0.0], [2.0, 0.0, 0.0]]);
+        assert!(a.intersects(&b).unwrap());
+    }
+
+    #[test]
+    fn test_skew_segments_with_overlapping_bounding_box_do_not_intersect() {
+        // These pass the old axis-aligned bounding-box test (both
+        // boxes span x,y,z in [0, 2]) but never actually meet in 3D.
+        let a = trace_of(&[[0.0, 0.0, 0.0], [2.0, 2.0, 0.0]]);
+        let b = trace_of(&[[0.0, 0.0, 2.0], [2.0, 2.0, 2.0]]);
+        assert!(!a.intersects(&b).unwrap());
+    }
+
+    #[test]
+    fn test_parallel_non_touching_segments_do_not_intersect() {
+        let a = trace_of(&[[0.0, 0.0, 0.0], [1.0, 0.0, 0.0]]);
+        let b = trace_of(&[[0.0, 1.0, 0.0], [1.0, 1.0, 0.0]]);
+        assert!(!a.intersects(&b).unwrap());
+    }
+
+    #[test]
+    fn test_touching_endpoints_intersect() {
+        let a = trace_of(&[[0.0, 0.0, 0.0], [1.0, 0.0, 0.0]]);
+        let b = trace_of(&[[1.0, 0.0, 0.0], [2.0, 1.0, 0.0]]);
+        assert!(a.intersects(&b).unwrap());
+    }
+
+    #[test]
+    fn test_degenerate_point_segment_does_not_panic_and_is_exact() {
+        // A zero-length "segment" exercises the a<=eps / e<=eps branches.
+        let point = trace_of(&[[0.0, 0.0, 0.0], [0.0, 0.0, 0.0]]);
+        let far_segment = trace_of(&[[-1.0, 1.0, 0.0], [1.0, 1.0, 0.0]]);
+        assert!(!point.intersects(&far_segment).unwrap());
+
+        let same_point = trace_of(&[[0.0, 0.0, 0.0], [0.0, 0.0, 0.0]]);
+        assert!(point.intersects(&same_point).unwrap());
+    }
+
+    #[test]
+    fn test_record_accumulates_points_and_length() {
+        let mut trace = Trace::new();
+        trace.record([0.0, 0.0, 0.0]).unwrap();
+        trace.record([3.0, 4.0, 0.0]).unwrap();
+
+        assert_eq!(trace.points(), &[[0.0, 0.0, 0.0], [3.0, 4.0, 0.0]]);
+        assert!((trace.length() - 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_record_rejects_points_too_far_from_last() {
+        let mut trace = Trace::new();
+        trace.record([0.0, 0.0, 0.0]).unwrap();
+        assert!(trace.record([20.0, 0.0, 0.0]).is_err());
+    }
+
+    #[test]
+    fn test_extend_appends_relative_to_last_point() {
+        let mut trace = Trace::new();
+        trace.extend([1.0, 0.0, 0.0]).unwrap();
+        trace.extend([0.0, 1.0, 0.0]).unwrap();
+
+        assert_eq!(trace.points(), &[[1.0, 0.0, 0.0], [1.0, 1.0, 0.0]]);
+    }
+
+    #[test]
+    fn test_extend_rejects_oversized_offset() {
+        let mut trace = Trace::new();
+        assert!(trace.extend([11.0, 0.0, 0.0]).is_err());
+    }
+
+    #[test]
+    fn test_curvature_zero_for_collinear_points() {
+        let mut trace = Trace::new();
+        trace.record([0.0, 0.0, 0.0]).unwrap();
+        trace.record([1.0, 0.0, 0.0]).unwrap();
+        trace.record([2.0, 0.0, 0.0]).unwrap();
+
+        assert_eq!(trace.curvature(), 0.0);
+    }
+
+    #[test]
+    fn test_curvature_nonzero_for_right_angle_turn() {
+        let mut trace = Trace::new();
+        trace.record([0.0, 0.0, 0.0]).unwrap();
+        trace.record([1.0, 0.0, 0.0]).unwrap();
+        trace.record([1.0, 1.0, 0.0]).unwrap();
+
+        // Unit right-angle turn: menger curvature of a right isoceles
+        // triangle with legs 1 is 4*0.5 / (1*1*sqrt(2)) = sqrt(2).
+        assert!((trace.curvature() - 2.0_f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_curvature_is_running_mean_across_triples() {
+        let mut trace = Trace::new();
+        trace.record([0.0, 0.0, 0.0]).unwrap();
+        trace.record([1.0, 0.0, 0.0]).unwrap();
+        trace.record([1.0, 1.0, 0.0]).unwrap(); // right-angle turn: nonzero sample
+        let first = trace.curvature();
+        assert!(first > 0.0);
+
+        trace.record([1.0, 2.0, 0.0]).unwrap(); // collinear continuation: zero sample
+        // The running mean of [first, 0] is first/2, not first.
+        assert!((trace.curvature() - first / 2.0).abs() < 1e-9);
+    }
+}
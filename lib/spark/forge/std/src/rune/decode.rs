@@ -0,0 +1,148 @@
+//! UTF-8 decoding into a crystal-aligned [`CrystalArray<Rune>`]
+//!
+//! The fast path classifies input in 32-byte chunks: if a chunk is all-ASCII
+//! (every byte `< 0x80`, tested with a single SIMD "any high bit set" over
+//! the chunk), the bytes widen directly into runes with no per-byte
+//! branching. Runs of non-ASCII bytes fall back to a scalar state machine
+//! that assembles code points from continuation bytes, rejecting overlong
+//! encodings and surrogate-range scalars via [`Rune::is_valid_unicode`] (the
+//! same validity check [`Rune::new`] uses). The output keeps the crystal
+//! alignment [`CrystalArray`] always allocates with, so SIMD passes over the
+//! decoded buffer stay aligned.
+
+use super::{Rune, RuneError};
+use crate::align::Alignment;
+use crate::array::CrystalArray;
+use std::simd::prelude::*;
+
+/// Width of the SIMD "all-ASCII" probe; 32 bytes matches the `Vector32`
+/// crystal alignment tier.
+const ASCII_CHUNK: usize = 32;
+
+impl Rune {
+    /// Decodes `bytes` as UTF-8 into a [`CrystalArray<Rune>`]
+    ///
+    /// Returns [`RuneError::InvalidString`] (with a byte-offset message) at
+    /// the first malformed sequence, overlong encoding, or surrogate-range
+    /// scalar.
+    pub fn decode_utf8(bytes: &[u8]) -> Result<CrystalArray<Rune>, RuneError> {
+        let mut out = CrystalArray::new(Alignment::Vector64);
+        out.reserve(bytes.len());
+
+        let mut i = 0;
+        while i < bytes.len() {
+            let ascii_len = ascii_run_len(&bytes[i..]);
+            if ascii_len > 0 {
+                push_ascii_run(&mut out, &bytes[i..i + ascii_len]);
+                i += ascii_len;
+                continue;
+            }
+
+            let (rune, consumed) = decode_one(&bytes[i..]).ok_or_else(|| {
+                RuneError::InvalidString(format!(
+                    "invalid UTF-8 byte sequence at offset {} (starts with {:#04X})",
+                    i, bytes[i]
+                ))
+            })?;
+            out.push(rune);
+            i += consumed;
+        }
+
+        Ok(out)
+    }
+
+    /// Decodes `bytes` as UTF-8 into a [`CrystalArray<Rune>`], replacing each
+    /// malformed byte with [`Rune::REPLACEMENT`] and resuming one byte later
+    pub fn decode_utf8_lossy(bytes: &[u8]) -> CrystalArray<Rune> {
+        let mut out = CrystalArray::new(Alignment::Vector64);
+        out.reserve(bytes.len());
+
+        let mut i = 0;
+        while i < bytes.len() {
+            let ascii_len = ascii_run_len(&bytes[i..]);
+            if ascii_len > 0 {
+                push_ascii_run(&mut out, &bytes[i..i + ascii_len]);
+                i += ascii_len;
+                continue;
+            }
+
+            match decode_one(&bytes[i..]) {
+                Some((rune, consumed)) => {
+                    out.push(rune);
+                    i += consumed;
+                }
+                None => {
+                    out.push(Rune::REPLACEMENT);
+                    i += 1;
+                }
+            }
+        }
+
+        out
+    }
+}
+
+/// Returns the length of the leading run of `bytes` that is all-ASCII,
+/// scanning whole [`ASCII_CHUNK`]-byte chunks with a single SIMD compare
+/// each and falling back to scalar only for the partial tail chunk.
+fn ascii_run_len(bytes: &[u8]) -> usize {
+    let high_bit = Simd::<u8, ASCII_CHUNK>::splat(0x80);
+    let mut len = 0;
+    while len + ASCII_CHUNK <= bytes.len() {
+        let chunk = Simd::<u8, ASCII_CHUNK>::from_slice(&bytes[len..len + ASCII_CHUNK]);
+        if chunk.simd_ge(high_bit).any() {
+            break;
+        }
+        len += ASCII_CHUNK;
+    }
+    while len < bytes.len() && bytes[len] < 0x80 {
+        len += 1;
+    }
+    len
+}
+
+/// Widens an all-ASCII byte run directly into runes; every byte is already
+/// its own scalar value.
+fn push_ascii_run(out: &mut CrystalArray<Rune>, run: &[u8]) {
+    out.reserve(run.len());
+    for &byte in run {
+        // Safety: `ascii_run_len` only includes bytes `< 0x80`.
+        out.push(unsafe { Rune::from_u32_unchecked(byte as u32) });
+    }
+}
+
+/// Decodes one non-ASCII scalar from the start of `bytes`, returning the
+/// rune and the number of bytes it consumed, or `None` if the leading byte
+/// isn't a valid multi-byte sequence start, the sequence is truncated, a
+/// continuation byte is malformed, or the assembled scalar is overlong,
+/// out of range, or in the surrogate range.
+fn decode_one(bytes: &[u8]) -> Option<(Rune, usize)> {
+    let b0 = bytes[0];
+    let (len, min_scalar, mut scalar) = if b0 & 0xE0 == 0xC0 {
+        (2, 0x80u32, (b0 & 0x1F) as u32)
+    } else if b0 & 0xF0 == 0xE0 {
+        (3, 0x800u32, (b0 & 0x0F) as u32)
+    } else if b0 & 0xF8 == 0xF0 {
+        (4, 0x10000u32, (b0 & 0x07) as u32)
+    } else {
+        return None;
+    };
+
+    if bytes.len() < len {
+        return None;
+    }
+
+    for &continuation in &bytes[1..len] {
+        if continuation & 0xC0 != 0x80 {
+            return None;
+        }
+        scalar = (scalar << 6) | (continuation & 0x3F) as u32;
+    }
+
+    if scalar < min_scalar || !Rune::is_valid_unicode(scalar) {
+        return None;
+    }
+
+    // Safety: just validated via `Rune::is_valid_unicode`.
+    Some((unsafe { Rune::from_u32_unchecked(scalar) }, len))
+}
@@ -6,7 +6,9 @@ use std::fmt;
 use std::ops::{Add, Sub};
 use std::str::FromStr;
 
+mod decode;
 mod error;
+mod tables;
 pub use error::RuneError;
 
 /// A crystal-space optimized Unicode scalar value
@@ -106,37 +108,124 @@ impl Rune {
         buf
     }
 
-    /// Returns true if this rune is alphabetic
+    /// Returns true if this rune has `General_Category` `L*` (letter)
+    ///
+    /// Backed by the two-stage `General_Category` table in [`tables`]; see
+    /// its module docs for which scripts are tabulated.
     pub fn is_alphabetic(&self) -> bool {
-        // Basic Latin + Latin-1 Supplement
-        (self.is_in_range(0x41, 0x5A) || // A-Z
-         self.is_in_range(0x61, 0x7A) || // a-z
-         self.is_in_range(0xC0, 0xFF)) && // Latin-1 Supplement
-        self.0 != 0xD7 && self.0 != 0xF7  // Exclude × and ÷
+        tables::category_of(self.0).is_alphabetic()
     }
 
-    /// Returns true if this rune is numeric
+    /// Returns true if this rune has `General_Category` `Nd` (decimal digit)
     pub fn is_numeric(&self) -> bool {
-        self.is_in_range(0x30, 0x39)  // 0-9
+        tables::category_of(self.0).is_numeric()
     }
 
-    /// Returns true if this rune is alphanumeric
+    /// Returns true if this rune is alphabetic or numeric
     pub fn is_alphanumeric(&self) -> bool {
         self.is_alphabetic() || self.is_numeric()
     }
 
     /// Returns true if this rune is whitespace
+    ///
+    /// This is `General_Category` `Zs`/`Zl`/`Zp` plus the ASCII control
+    /// codes the `White_Space` property adds on top of `General_Category`
+    /// (tab, newline, and friends, which are `Cc` rather than a separator
+    /// category).
     pub fn is_whitespace(&self) -> bool {
-        matches!(self.0,
-            0x20 | 0x9 | 0xA | 0xB | 0xC | 0xD |  // ASCII whitespace
-            0x85 | 0x2000..=0x200A |              // Other whitespace
-            0x2028 | 0x2029                        // Line/para separators
-        )
+        matches!(self.0, 0x9..=0xD | 0x85) || tables::category_of(self.0).is_separator()
     }
 
-    /// Returns true if this rune is a control character
+    /// Returns true if this rune has `General_Category` `Cc` (control)
     pub fn is_control(&self) -> bool {
-        self.is_in_range(0x00, 0x1F) || self.is_in_range(0x7F, 0x9F)
+        tables::category_of(self.0).is_control()
+    }
+
+    /// Returns the uppercase mapping of this rune as an iterator of runes
+    ///
+    /// Most runes map to exactly one uppercase rune; a few expand to more
+    /// than one (e.g. `ß` -> `SS`). Runes with no case, or outside the
+    /// scripts [`tables`] tabulates, uppercase to themselves.
+    pub fn to_uppercase(&self) -> CaseMappingIter {
+        CaseMappingIter::new(self.0, tables::special_uppercase, tables::simple_uppercase)
+    }
+
+    /// Returns the lowercase mapping of this rune as an iterator of runes
+    ///
+    /// Runes with no case, or outside the scripts [`tables`] tabulates,
+    /// lowercase to themselves.
+    pub fn to_lowercase(&self) -> CaseMappingIter {
+        CaseMappingIter::new(self.0, |_| None, tables::simple_lowercase)
+    }
+
+    /// Returns the titlecase mapping of this rune as an iterator of runes
+    ///
+    /// None of the scripts [`tables`] tabulates have a titlecase form
+    /// distinct from uppercase (that only matters for a handful of Latin
+    /// digraphs, e.g. `Dž`), so this currently follows [`Rune::to_uppercase`].
+    pub fn to_titlecase(&self) -> CaseMappingIter {
+        self.to_uppercase()
+    }
+}
+
+/// A small, stack-allocated iterator over the one or more runes a case
+/// mapping produces.
+///
+/// Backed by a fixed `[Rune; 3]` buffer since the widest mapping this module
+/// tabulates (`ß` -> `SS`) expands to two scalars; no case mapping in
+/// Unicode expands past three.
+pub struct CaseMappingIter {
+    buf: [Rune; 3],
+    len: u8,
+    pos: u8,
+}
+
+impl CaseMappingIter {
+    fn new(
+        scalar: u32,
+        special: fn(u32) -> Option<&'static [u32]>,
+        simple: fn(u32) -> Option<u32>,
+    ) -> Self {
+        if let Some(expansion) = special(scalar) {
+            let mut buf = [Rune::REPLACEMENT; 3];
+            for (slot, &mapped) in buf.iter_mut().zip(expansion) {
+                // Safety: the case-mapping tables only ever name valid scalars.
+                *slot = unsafe { Rune::from_u32_unchecked(mapped) };
+            }
+            return Self {
+                buf,
+                len: expansion.len() as u8,
+                pos: 0,
+            };
+        }
+
+        let mapped = simple(scalar).unwrap_or(scalar);
+        // Safety: `mapped` is either `scalar` itself or a value from the
+        // case-mapping tables, both of which are valid scalars.
+        let rune = unsafe { Rune::from_u32_unchecked(mapped) };
+        Self {
+            buf: [rune, Rune::REPLACEMENT, Rune::REPLACEMENT],
+            len: 1,
+            pos: 0,
+        }
+    }
+}
+
+impl Iterator for CaseMappingIter {
+    type Item = Rune;
+
+    fn next(&mut self) -> Option<Rune> {
+        if self.pos >= self.len {
+            return None;
+        }
+        let rune = self.buf[self.pos as usize];
+        self.pos += 1;
+        Some(rune)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.len - self.pos) as usize;
+        (remaining, Some(remaining))
     }
 }
 
@@ -0,0 +1,376 @@
+//! Embedded `General_Category` and case-mapping tables for [`Rune`](super::Rune)
+//!
+//! The category table is a two-stage lookup: [`BLOCK_INDEX`] maps the high
+//! bits of a scalar value (`scalar >> BLOCK_SHIFT`) to an entry in [`BLOCKS`],
+//! a small set of deduplicated 256-entry category arrays keyed by the low
+//! byte of the scalar. Every block outside the handful of scripts this file
+//! actually tabulates (Basic Latin, Latin-1 Supplement, Latin Extended-A/B,
+//! core Greek, core Cyrillic, and General Punctuation) shares [`DEFAULT_BLOCK`],
+//! so the whole scalar range up to `char::MAX` is covered by one `u8` index
+//! per 256 code points rather than one entry per code point.
+//!
+//! This is a curated subset of the Unicode Character Database, not a full
+//! generated table: Latin Extended-A/B use the alternating upper/lower
+//! pattern that holds for most of those blocks rather than every documented
+//! exception, and combining marks, currency/format characters outside the
+//! ranges below, and scripts other than Greek/Cyrillic fall back to
+//! [`Category::Cn`]. That is enough to drive `is_alphabetic`/`is_numeric`/
+//! `is_whitespace`/`is_control` correctly for the scripts it covers.
+
+use std::sync::OnceLock;
+
+pub const BLOCK_SHIFT: u32 = 8;
+pub const BLOCK_SIZE: usize = 1 << BLOCK_SHIFT;
+const NUM_BLOCKS: usize = (0x110000usize >> BLOCK_SHIFT) + 1;
+
+/// A coarse `General_Category` class, limited to the classes
+/// [`Rune`](super::Rune)'s property predicates care about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Category {
+    /// Unassigned or otherwise untabulated (treated as "Other, not assigned").
+    Cn = 0,
+    /// Uppercase letter.
+    Lu = 1,
+    /// Lowercase letter.
+    Ll = 2,
+    /// Titlecase letter.
+    Lt = 3,
+    /// Modifier letter.
+    Lm = 4,
+    /// Other letter (no case).
+    Lo = 5,
+    /// Decimal digit number.
+    Nd = 6,
+    /// Space separator.
+    Zs = 7,
+    /// Line separator.
+    Zl = 8,
+    /// Paragraph separator.
+    Zp = 9,
+    /// Control character.
+    Cc = 10,
+    /// Format character.
+    Cf = 11,
+}
+
+impl Category {
+    const fn from_u8(value: u8) -> Category {
+        match value {
+            1 => Category::Lu,
+            2 => Category::Ll,
+            3 => Category::Lt,
+            4 => Category::Lm,
+            5 => Category::Lo,
+            6 => Category::Nd,
+            7 => Category::Zs,
+            8 => Category::Zl,
+            9 => Category::Zp,
+            10 => Category::Cc,
+            11 => Category::Cf,
+            _ => Category::Cn,
+        }
+    }
+
+    /// True for `Lu`, `Ll`, `Lt`, `Lm`, `Lo`.
+    pub const fn is_alphabetic(self) -> bool {
+        matches!(
+            self,
+            Category::Lu | Category::Ll | Category::Lt | Category::Lm | Category::Lo
+        )
+    }
+
+    /// True for `Nd`.
+    pub const fn is_numeric(self) -> bool {
+        matches!(self, Category::Nd)
+    }
+
+    /// True for `Zs`, `Zl`, `Zp`.
+    pub const fn is_separator(self) -> bool {
+        matches!(self, Category::Zs | Category::Zl | Category::Zp)
+    }
+
+    /// True for `Cc`.
+    pub const fn is_control(self) -> bool {
+        matches!(self, Category::Cc)
+    }
+}
+
+const DEFAULT_BLOCK: [u8; BLOCK_SIZE] = [Category::Cn as u8; BLOCK_SIZE];
+
+/// Categorizes one code point within the Basic Latin / Latin-1 Supplement
+/// block (`0x0000..=0x00FF`).
+const fn classify_block_0000(lo: u8) -> u8 {
+    let scalar = lo as u32;
+    let cat = match scalar {
+        0x00..=0x1F | 0x7F..=0x9F => Category::Cc,
+        0x20 | 0xA0 => Category::Zs,
+        0x30..=0x39 => Category::Nd,
+        0x41..=0x5A => Category::Lu,
+        0x61..=0x7A => Category::Ll,
+        0xAA | 0xBA => Category::Lo,
+        0xB5 => Category::Ll,
+        0xC0..=0xD6 => Category::Lu,
+        0xD8..=0xDE => Category::Lu,
+        0xDF..=0xF6 => Category::Ll,
+        0xF8..=0xFF => Category::Ll,
+        _ => Category::Cn,
+    };
+    cat as u8
+}
+
+/// Categorizes Latin Extended-A (`0x0100..=0x017F`) plus the start of
+/// Latin Extended-B, using the alternating uppercase/lowercase pairing that
+/// holds for the bulk of those blocks.
+const fn classify_block_0100(lo: u8) -> u8 {
+    let scalar = 0x0100u32 + lo as u32;
+    let cat = match scalar {
+        0x0138 | 0x0149 | 0x017F => Category::Ll,
+        0x0178 => Category::Lu,
+        0x0100..=0x017F => {
+            if scalar.is_multiple_of(2) {
+                Category::Lu
+            } else {
+                Category::Ll
+            }
+        }
+        _ => Category::Cn,
+    };
+    cat as u8
+}
+
+/// Categorizes the remainder of Latin Extended-B, IPA Extensions, and
+/// Spacing Modifier Letters (`0x0200..=0x02FF`).
+const fn classify_block_0200(lo: u8) -> u8 {
+    let scalar = 0x0200u32 + lo as u32;
+    let cat = match scalar {
+        0x0200..=0x024F => {
+            if scalar.is_multiple_of(2) {
+                Category::Lu
+            } else {
+                Category::Ll
+            }
+        }
+        0x0250..=0x02AF => Category::Ll,
+        0x02B0..=0x02FF => Category::Lm,
+        _ => Category::Cn,
+    };
+    cat as u8
+}
+
+/// Categorizes the core (unaccented) Greek alphabet within Greek and Coptic
+/// (`0x0370..=0x03FF`).
+const fn classify_block_0300(lo: u8) -> u8 {
+    let scalar = 0x0300u32 + lo as u32;
+    let cat = match scalar {
+        0x0391..=0x03A9 => Category::Lu,
+        0x03B1..=0x03C9 => Category::Ll,
+        _ => Category::Cn,
+    };
+    cat as u8
+}
+
+/// Categorizes the core Cyrillic alphabet (`0x0400..=0x04FF`).
+const fn classify_block_0400(lo: u8) -> u8 {
+    let scalar = 0x0400u32 + lo as u32;
+    let cat = match scalar {
+        0x0400..=0x040F => Category::Lu,
+        0x0410..=0x042F => Category::Lu,
+        0x0430..=0x044F => Category::Ll,
+        0x0450..=0x045F => Category::Ll,
+        _ => Category::Cn,
+    };
+    cat as u8
+}
+
+/// Categorizes the whitespace-bearing portion of General Punctuation
+/// (`0x2000..=0x20FF`).
+const fn classify_block_2000(lo: u8) -> u8 {
+    let scalar = 0x2000u32 + lo as u32;
+    let cat = match scalar {
+        0x2000..=0x200A => Category::Zs,
+        0x200B..=0x200F => Category::Cf,
+        0x2028 => Category::Zl,
+        0x2029 => Category::Zp,
+        0x2060..=0x2064 => Category::Cf,
+        _ => Category::Cn,
+    };
+    cat as u8
+}
+
+fn build_block(classify: fn(u8) -> u8) -> [u8; BLOCK_SIZE] {
+    let mut block = [0u8; BLOCK_SIZE];
+    for (lo, entry) in block.iter_mut().enumerate() {
+        *entry = classify(lo as u8);
+    }
+    block
+}
+
+/// Deduplicated per-block category arrays: index 0 is [`DEFAULT_BLOCK`], the
+/// rest are the tabulated scripts above. Built once on first lookup, since a
+/// `const fn` can't call through the `classify_block_*` function pointers.
+fn blocks() -> &'static [[u8; BLOCK_SIZE]; 7] {
+    static BLOCKS: OnceLock<[[u8; BLOCK_SIZE]; 7]> = OnceLock::new();
+    BLOCKS.get_or_init(|| {
+        [
+            DEFAULT_BLOCK,
+            build_block(classify_block_0000),
+            build_block(classify_block_0100),
+            build_block(classify_block_0200),
+            build_block(classify_block_0300),
+            build_block(classify_block_0400),
+            build_block(classify_block_2000),
+        ]
+    })
+}
+
+const fn build_block_index() -> [u8; NUM_BLOCKS] {
+    let mut index = [0u8; NUM_BLOCKS];
+    index[0x00] = 1;
+    index[0x01] = 2;
+    index[0x02] = 3;
+    index[0x03] = 4;
+    index[0x04] = 5;
+    index[0x20] = 6;
+    index
+}
+
+/// Stage-1 table: maps `scalar >> BLOCK_SHIFT` to an index into [`BLOCKS`].
+static BLOCK_INDEX: [u8; NUM_BLOCKS] = build_block_index();
+
+/// Looks up the `General_Category` class of a scalar value in O(1) via the
+/// two-stage table.
+pub fn category_of(scalar: u32) -> Category {
+    let block_num = (scalar >> BLOCK_SHIFT) as usize;
+    let block = BLOCK_INDEX.get(block_num).copied().unwrap_or(0);
+    let byte = blocks()[block as usize][(scalar & 0xFF) as usize];
+    Category::from_u8(byte)
+}
+
+/// One entry of a sorted simple (one-to-one) case-mapping table.
+#[derive(Clone, Copy)]
+struct SimpleMapping {
+    from: u32,
+    to: u32,
+}
+
+/// One entry of a sorted special (one-to-many) case-mapping table.
+struct SpecialMapping {
+    from: u32,
+    to: &'static [u32],
+}
+
+fn binary_search_simple(table: &[SimpleMapping], scalar: u32) -> Option<u32> {
+    table
+        .binary_search_by_key(&scalar, |entry| entry.from)
+        .ok()
+        .map(|i| table[i].to)
+}
+
+fn binary_search_special(table: &[SpecialMapping], scalar: u32) -> Option<&'static [u32]> {
+    table
+        .binary_search_by_key(&scalar, |entry| entry.from)
+        .ok()
+        .map(|i| table[i].to)
+}
+
+/// Sorted by `from`. Covers the same scripts as the category table above.
+static SPECIAL_UPPER: &[SpecialMapping] = &[SpecialMapping {
+    from: 0x00DF, // ß -> SS
+    to: &[0x0053, 0x0053],
+}];
+
+/// Builds a mapping table over an alternating Lu/Ll range: every *even*
+/// scalar starting at `$start` is an uppercase letter `$offset` below its
+/// lowercase successor (Latin Extended-A/B's pairing).
+macro_rules! pair_range {
+    ($start:literal, $end:literal, $offset:expr) => {{
+        const LEN: usize = ($end - $start) / 2 + 1;
+        let mut out = [SimpleMapping { from: 0, to: 0 }; LEN];
+        let mut i = 0;
+        while i < LEN {
+            let from = $start + (i as u32) * 2;
+            out[i] = SimpleMapping {
+                from,
+                to: (from as i64 + $offset) as u32,
+            };
+            i += 1;
+        }
+        out
+    }};
+}
+
+/// Builds a mapping table over a contiguous uppercase range where every
+/// scalar from `$start` to `$end` is `$offset` below its lowercase form
+/// (Latin-1, Greek, and Cyrillic's pairing).
+macro_rules! offset_range {
+    ($start:literal, $end:literal, $offset:expr) => {{
+        const LEN: usize = $end - $start + 1;
+        let mut out = [SimpleMapping { from: 0, to: 0 }; LEN];
+        let mut i = 0;
+        while i < LEN {
+            let from = $start + i as u32;
+            out[i] = SimpleMapping {
+                from,
+                to: from + $offset,
+            };
+            i += 1;
+        }
+        out
+    }};
+}
+
+const LATIN1_UPPER_TO_LOWER_AZ: [SimpleMapping; 26] = offset_range!(0x0041, 0x005A, 0x20);
+const LATIN1_UPPER_TO_LOWER_C0: [SimpleMapping; 23] = offset_range!(0x00C0, 0x00D6, 0x20);
+const LATIN1_UPPER_TO_LOWER_D8: [SimpleMapping; 7] = offset_range!(0x00D8, 0x00DE, 0x20);
+const EXT_A_UPPER_TO_LOWER: [SimpleMapping; 64] = pair_range!(0x0100, 0x017E, 1);
+const EXT_B_UPPER_TO_LOWER: [SimpleMapping; 40] = pair_range!(0x0200, 0x024E, 1);
+const GREEK_UPPER_TO_LOWER: [SimpleMapping; 25] = offset_range!(0x0391, 0x03A9, 0x20);
+const CYRILLIC_UPPER_TO_LOWER_BASIC: [SimpleMapping; 16] = offset_range!(0x0400, 0x040F, 0x50);
+const CYRILLIC_UPPER_TO_LOWER_MAIN: [SimpleMapping; 32] = offset_range!(0x0410, 0x042F, 0x20);
+
+fn simple_upper_to_lower(scalar: u32) -> Option<u32> {
+    binary_search_simple(&LATIN1_UPPER_TO_LOWER_AZ, scalar)
+        .or_else(|| binary_search_simple(&LATIN1_UPPER_TO_LOWER_C0, scalar))
+        .or_else(|| binary_search_simple(&LATIN1_UPPER_TO_LOWER_D8, scalar))
+        .or_else(|| binary_search_simple(&EXT_A_UPPER_TO_LOWER, scalar))
+        .or_else(|| binary_search_simple(&EXT_B_UPPER_TO_LOWER, scalar))
+        .or_else(|| binary_search_simple(&GREEK_UPPER_TO_LOWER, scalar))
+        .or_else(|| binary_search_simple(&CYRILLIC_UPPER_TO_LOWER_BASIC, scalar))
+        .or_else(|| binary_search_simple(&CYRILLIC_UPPER_TO_LOWER_MAIN, scalar))
+}
+
+/// The tables above are keyed by uppercase scalar; lowercase-to-uppercase
+/// does a linear scan over the same (small) tables rather than duplicating
+/// them in reverse-sorted order.
+fn simple_lower_to_upper(scalar: u32) -> Option<u32> {
+    fn reverse_scan(table: &[SimpleMapping], scalar: u32) -> Option<u32> {
+        table.iter().find(|e| e.to == scalar).map(|e| e.from)
+    }
+    reverse_scan(&LATIN1_UPPER_TO_LOWER_AZ, scalar)
+        .or_else(|| reverse_scan(&LATIN1_UPPER_TO_LOWER_C0, scalar))
+        .or_else(|| reverse_scan(&LATIN1_UPPER_TO_LOWER_D8, scalar))
+        .or_else(|| reverse_scan(&EXT_A_UPPER_TO_LOWER, scalar))
+        .or_else(|| reverse_scan(&EXT_B_UPPER_TO_LOWER, scalar))
+        .or_else(|| reverse_scan(&GREEK_UPPER_TO_LOWER, scalar))
+        .or_else(|| reverse_scan(&CYRILLIC_UPPER_TO_LOWER_BASIC, scalar))
+        .or_else(|| reverse_scan(&CYRILLIC_UPPER_TO_LOWER_MAIN, scalar))
+}
+
+/// Special (one-to-many) mappings, keyed by lowercase scalar, searched
+/// before the simple table so multi-scalar expansions like `ß -> SS` win.
+pub fn special_uppercase(scalar: u32) -> Option<&'static [u32]> {
+    binary_search_special(SPECIAL_UPPER, scalar)
+}
+
+/// Simple one-to-one uppercase mapping (`None` if `scalar` has no case or is
+/// outside the tabulated scripts).
+pub fn simple_uppercase(scalar: u32) -> Option<u32> {
+    simple_lower_to_upper(scalar)
+}
+
+/// Simple one-to-one lowercase mapping (`None` if `scalar` has no case or is
+/// outside the tabulated scripts).
+pub fn simple_lowercase(scalar: u32) -> Option<u32> {
+    simple_upper_to_lower(scalar)
+}
@@ -61,3 +61,146 @@ impl Default for Wave {
         Self::new(1.0, 0.0, 1.0)
     }
 }
+
+/// A superposition of waves, sampled together in the time domain.
+#[derive(Debug, Clone, Default)]
+pub struct WaveField {
+    waves: Vec<Wave>,
+}
+
+impl WaveField {
+    /// Creates an empty wave field.
+    pub fn new() -> Self {
+        Self { waves: Vec::new() }
+    }
+
+    /// Adds a wave to the field.
+    pub fn add(&mut self, wave: Wave) -> &mut Self {
+        self.waves.push(wave);
+        self
+    }
+
+    /// Gets the waves making up this field.
+    pub fn waves(&self) -> &[Wave] {
+        &self.waves
+    }
+
+    /// Gets the summed value of every wave in the field at a given time.
+    pub fn value(&self, t: f64) -> f64 {
+        self.waves.iter().map(|wave| wave.value(t)).sum()
+    }
+
+    /// Samples the field's summed signal at `sample_rate` for `samples`
+    /// points, zero-padding up to the next power of two, then runs an
+    /// in-place iterative radix-2 FFT to recover the dominant frequency
+    /// components as a set of `Wave`s.
+    ///
+    /// Only bins whose amplitude exceeds `magnitude_threshold` are
+    /// returned, ordered by frequency; summing their `value(t)` is an
+    /// approximate reconstruction of the sampled signal.
+    pub fn spectrum(&self, samples: usize, sample_rate: f64, magnitude_threshold: f64) -> Vec<Wave> {
+        let fft_len = samples.max(1).next_power_of_two();
+
+        let dt = 1.0 / sample_rate;
+        let mut re: Vec<f64> = (0..fft_len)
+            .map(|i| if i < samples { self.value(i as f64 * dt) } else { 0.0 })
+            .collect();
+        let mut im = vec![0.0; fft_len];
+
+        fft_radix2(&mut re, &mut im);
+
+        let mut bins = Vec::new();
+        // Only the first half of the spectrum is unique for a real
+        // input signal -- the second half is its mirror image.
+        for k in 1..fft_len / 2 {
+            let magnitude = (re[k] * re[k] + im[k] * im[k]).sqrt();
+            let amplitude = 2.0 * magnitude / fft_len as f64;
+            if amplitude > magnitude_threshold {
+                // atan2(im, re) gives the phase of a cosine at this bin,
+                // but `Wave::value` reconstructs with `sin`, which lags
+                // a cosine of the same phase by pi/2 -- add it back so
+                // the returned wave lines up with the sampled signal.
+                let phase = im[k].atan2(re[k]) + std::f64::consts::FRAC_PI_2;
+                let frequency_hz = k as f64 * sample_rate / fft_len as f64;
+                // `Wave::value` takes `self.frequency * t` directly, so it
+                // expects an angular frequency (rad/s), not Hz.
+                let angular_frequency = 2.0 * std::f64::consts::PI * frequency_hz;
+                bins.push(Wave::new(amplitude, phase, angular_frequency));
+            }
+        }
+
+        bins
+    }
+}
+
+/// Runs an in-place iterative Cooley-Tukey radix-2 FFT over `re`/`im`,
+/// which together hold one complex sample per index. `re.len()` must be
+/// a power of two -- callers are responsible for zero-padding.
+fn fft_radix2(re: &mut [f64], im: &mut [f64]) {
+    let n = re.len();
+    debug_assert_eq!(n, im.len());
+    debug_assert!(n.is_power_of_two());
+    if n <= 1 {
+        return;
+    }
+
+    // Bit-reverse the sample order so each butterfly stage below can
+    // combine adjacent pairs in place.
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if i < j {
+            re.swap(i, j);
+            im.swap(i, j);
+        }
+    }
+
+    let mut m = 2;
+    while m <= n {
+        let half = m / 2;
+        let angle_step = -2.0 * std::f64::consts::PI / m as f64;
+
+        let mut start = 0;
+        while start < n {
+            for j in 0..half {
+                let angle = angle_step * j as f64;
+                let (w_re, w_im) = (angle.cos(), angle.sin());
+
+                let a = start + j;
+                let b = start + j + half;
+
+                let b_re = w_re * re[b] - w_im * im[b];
+                let b_im = w_re * im[b] + w_im * re[b];
+
+                let a_re = re[a];
+                let a_im = im[a];
+
+                re[a] = a_re + b_re;
+                im[a] = a_im + b_im;
+                re[b] = a_re - b_re;
+                im[b] = a_im - b_im;
+            }
+            start += m;
+        }
+
+        m *= 2;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spectrum_recovers_single_wave() {
+        let mut field = WaveField::new();
+        field.add(Wave::new(1.3, 0.37, 2.0 * std::f64::consts::PI * 4.0));
+
+        let bins = field.spectrum(64, 64.0, 0.1);
+
+        assert_eq!(bins.len(), 1);
+        let bin = &bins[0];
+        assert!((bin.value(0.0) - field.value(0.0)).abs() < 1e-6);
+        assert!((bin.value(0.2) - field.value(0.2)).abs() < 1e-6);
+    }
+}
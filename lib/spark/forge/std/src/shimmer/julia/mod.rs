@@ -13,6 +13,7 @@ pub struct JuliaFnAttrs {
 impl Shimmer {
     /// Loads a Julia function
     pub fn julia_fn<T>(&self, name: &str, _attrs: JuliaFnAttrs) -> ShimmerResult<T> {
+        self.require_negotiated()?;
         let _sym: ShimmerFn<T> = self.get_fn(name)?;
         // Julia-specific type checking and conversion would go here
         Err(ShimmerError::RuntimeError("Julia interface not yet implemented".into()))
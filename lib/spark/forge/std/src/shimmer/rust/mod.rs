@@ -14,6 +14,7 @@ pub struct RustFnAttrs {
 impl Shimmer {
     /// Loads a Rust function
     pub fn rust_fn<T>(&self, name: &str, _attrs: RustFnAttrs) -> ShimmerResult<T> {
+        self.require_negotiated()?;
         let _sym: ShimmerFn<T> = self.get_fn(name)?;
         // Rust-specific type checking and conversion would go here
         Err(ShimmerError::RuntimeError("Rust interface not yet implemented".into()))
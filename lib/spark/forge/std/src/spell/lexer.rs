@@ -0,0 +1,150 @@
+//! Tokenizer for spell block directives
+
+/// A single lexical token within a `@spell@ … @end@` block
+#[derive(Debug, Clone, PartialEq)]
+pub enum Token {
+    /// A bare identifier, e.g. `base`
+    Ident(String),
+    /// A `~name~` directive key
+    Directive(String),
+    /// An integer or floating-point literal
+    Num(f64),
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    LParen,
+    RParen,
+    Eq,
+}
+
+/// An error encountered while scanning a spell block into tokens
+#[derive(Debug, Clone, PartialEq)]
+pub enum LexError {
+    /// A character that doesn't start any known token
+    UnexpectedChar(char),
+    /// A `~` directive that was never closed by a matching `~`
+    UnterminatedDirective,
+    /// A numeric literal with more than one `.`
+    MalformedNumber(String),
+}
+
+/// Scans spell block source into a flat token stream
+pub struct Lexer<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+}
+
+impl<'a> Lexer<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Self { chars: input.chars().peekable() }
+    }
+
+    /// Scans the entire input, returning every token in order
+    pub fn tokenize(input: &'a str) -> Result<Vec<Token>, LexError> {
+        let mut lexer = Self::new(input);
+        let mut tokens = Vec::new();
+        while let Some(token) = lexer.next_token()? {
+            tokens.push(token);
+        }
+        Ok(tokens)
+    }
+
+    fn next_token(&mut self) -> Result<Option<Token>, LexError> {
+        loop {
+            match self.chars.peek() {
+                None => return Ok(None),
+                Some(c) if c.is_whitespace() => {
+                    self.chars.next();
+                }
+                Some('~') => return self.lex_directive().map(Some),
+                Some(c) if c.is_ascii_digit() => return self.lex_number().map(Some),
+                Some(c) if c.is_alphabetic() || *c == '_' => return Ok(Some(self.lex_ident())),
+                Some('+') => { self.chars.next(); return Ok(Some(Token::Plus)); }
+                Some('-') => { self.chars.next(); return Ok(Some(Token::Minus)); }
+                Some('*') => { self.chars.next(); return Ok(Some(Token::Star)); }
+                Some('/') => { self.chars.next(); return Ok(Some(Token::Slash)); }
+                Some('(') => { self.chars.next(); return Ok(Some(Token::LParen)); }
+                Some(')') => { self.chars.next(); return Ok(Some(Token::RParen)); }
+                Some('=') => { self.chars.next(); return Ok(Some(Token::Eq)); }
+                Some(&c) => return Err(LexError::UnexpectedChar(c)),
+            }
+        }
+    }
+
+    fn lex_directive(&mut self) -> Result<Token, LexError> {
+        self.chars.next(); // opening '~'
+        let mut name = String::new();
+        loop {
+            match self.chars.next() {
+                Some('~') => return Ok(Token::Directive(name)),
+                Some(c) => name.push(c),
+                None => return Err(LexError::UnterminatedDirective),
+            }
+        }
+    }
+
+    fn lex_number(&mut self) -> Result<Token, LexError> {
+        let mut text = String::new();
+        while let Some(&c) = self.chars.peek() {
+            if c.is_ascii_digit() || c == '.' {
+                text.push(c);
+                self.chars.next();
+            } else {
+                break;
+            }
+        }
+        text.parse().map(Token::Num).map_err(|_| LexError::MalformedNumber(text))
+    }
+
+    fn lex_ident(&mut self) -> Token {
+        let mut name = String::new();
+        while let Some(&c) = self.chars.peek() {
+            if c.is_alphanumeric() || c == '_' {
+                name.push(c);
+                self.chars.next();
+            } else {
+                break;
+            }
+        }
+        Token::Ident(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenize_simple_directive() {
+        let tokens = Lexer::tokenize("~weave~ = 500").unwrap();
+        assert_eq!(tokens, vec![
+            Token::Directive("weave".into()),
+            Token::Eq,
+            Token::Num(500.0),
+        ]);
+    }
+
+    #[test]
+    fn test_tokenize_expression() {
+        let tokens = Lexer::tokenize("~weave~ = 500 * 2 + base").unwrap();
+        assert_eq!(tokens, vec![
+            Token::Directive("weave".into()),
+            Token::Eq,
+            Token::Num(500.0),
+            Token::Star,
+            Token::Num(2.0),
+            Token::Plus,
+            Token::Ident("base".into()),
+        ]);
+    }
+
+    #[test]
+    fn test_unterminated_directive() {
+        assert_eq!(Lexer::tokenize("~weave = 500"), Err(LexError::UnterminatedDirective));
+    }
+
+    #[test]
+    fn test_unexpected_char() {
+        assert_eq!(Lexer::tokenize("~weave~ = 500 & 2"), Err(LexError::UnexpectedChar('&')));
+    }
+}
@@ -1,17 +1,197 @@
 //! Spell block parser implementation
 
+use std::collections::HashMap;
+
+use crate::spell::lexer::{LexError, Lexer, Token};
 use crate::weave::WeaveParser;
 
+/// A binary arithmetic operator in a spell directive expression
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BinOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+/// An expression parsed from the right-hand side of a `~name~ = …` directive
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    Num(f64),
+    Ident(String),
+    Binary(BinOp, Box<Expr>, Box<Expr>),
+}
+
+/// An error encountered while parsing or evaluating a spell block
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseError {
+    Lex(LexError),
+    /// The token stream ended where another token was expected
+    UnexpectedEof,
+    /// A token appeared where it doesn't belong, e.g. two operators in a row
+    UnexpectedToken(Token),
+    /// A directive's value never reduces to a plain identifier or number
+    ExpectedDirective,
+    /// `eval` hit an identifier with no entry in the variable environment
+    UndefinedVariable(String),
+    /// Division by a zero-valued expression
+    DivisionByZero,
+}
+
+impl From<LexError> for ParseError {
+    fn from(err: LexError) -> Self {
+        ParseError::Lex(err)
+    }
+}
+
+/// Precedence-climbing parser over a spell block's token stream
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn new(tokens: Vec<Token>) -> Self {
+        Self { tokens, pos: 0 }
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    /// Parses one `~name~ = <expr>` directive, leaving the cursor on
+    /// whatever follows (the start of the next directive, or EOF)
+    fn parse_directive(&mut self) -> Result<(String, Expr), ParseError> {
+        let name = match self.next() {
+            Some(Token::Directive(name)) => name,
+            Some(other) => return Err(ParseError::UnexpectedToken(other)),
+            None => return Err(ParseError::UnexpectedEof),
+        };
+        match self.next() {
+            Some(Token::Eq) => {}
+            Some(other) => return Err(ParseError::UnexpectedToken(other)),
+            None => return Err(ParseError::UnexpectedEof),
+        }
+        let expr = self.parse_expr(0)?;
+        Ok((name, expr))
+    }
+
+    /// Parses a binary expression via precedence climbing: `min_bp` is the
+    /// lowest operator precedence this call is allowed to consume.
+    fn parse_expr(&mut self, min_bp: u8) -> Result<Expr, ParseError> {
+        let mut lhs = self.parse_atom()?;
+
+        loop {
+            let op = match self.peek() {
+                Some(Token::Plus) => BinOp::Add,
+                Some(Token::Minus) => BinOp::Sub,
+                Some(Token::Star) => BinOp::Mul,
+                Some(Token::Slash) => BinOp::Div,
+                _ => break,
+            };
+            let bp = binding_power(op);
+            if bp < min_bp {
+                break;
+            }
+            self.next();
+            let rhs = self.parse_expr(bp + 1)?;
+            lhs = Expr::Binary(op, Box::new(lhs), Box::new(rhs));
+        }
+
+        Ok(lhs)
+    }
+
+    fn parse_atom(&mut self) -> Result<Expr, ParseError> {
+        match self.next() {
+            Some(Token::Num(n)) => Ok(Expr::Num(n)),
+            Some(Token::Ident(name)) => Ok(Expr::Ident(name)),
+            Some(Token::LParen) => {
+                let expr = self.parse_expr(0)?;
+                match self.next() {
+                    Some(Token::RParen) => Ok(expr),
+                    Some(other) => Err(ParseError::UnexpectedToken(other)),
+                    None => Err(ParseError::UnexpectedEof),
+                }
+            }
+            Some(other) => Err(ParseError::UnexpectedToken(other)),
+            None => Err(ParseError::UnexpectedEof),
+        }
+    }
+}
+
+/// `*`/`/` bind tighter than `+`/`-`, the usual arithmetic precedence
+fn binding_power(op: BinOp) -> u8 {
+    match op {
+        BinOp::Add | BinOp::Sub => 1,
+        BinOp::Mul | BinOp::Div => 2,
+    }
+}
+
+/// Evaluates a parsed expression against a variable environment
+pub fn eval(expr: &Expr, env: &HashMap<String, f64>) -> Result<f64, ParseError> {
+    match expr {
+        Expr::Num(n) => Ok(*n),
+        Expr::Ident(name) => env.get(name).copied().ok_or_else(|| ParseError::UndefinedVariable(name.clone())),
+        Expr::Binary(op, lhs, rhs) => {
+            let lhs = eval(lhs, env)?;
+            let rhs = eval(rhs, env)?;
+            match op {
+                BinOp::Add => Ok(lhs + rhs),
+                BinOp::Sub => Ok(lhs - rhs),
+                BinOp::Mul => Ok(lhs * rhs),
+                BinOp::Div => {
+                    if rhs == 0.0 {
+                        Err(ParseError::DivisionByZero)
+                    } else {
+                        Ok(lhs / rhs)
+                    }
+                }
+            }
+        }
+    }
+}
+
 /// Parser for spell blocks
 #[derive(Debug)]
 pub struct SpellParser;
 
 impl SpellParser {
-    /// Parses weave declarations in spell blocks
+    /// Parses every `~name~ = <expr>` directive in a spell block into a map
+    /// of directive name to its (unevaluated) expression
+    pub fn parse_block(spell: &str) -> Result<HashMap<String, Expr>, ParseError> {
+        let mut directives = HashMap::new();
+        for line in spell.lines() {
+            let line = line.trim();
+            if !line.starts_with('~') {
+                continue;
+            }
+            let tokens = Lexer::tokenize(line)?;
+            let mut parser = Parser::new(tokens);
+            let (name, expr) = parser.parse_directive()?;
+            directives.insert(name, expr);
+        }
+        Ok(directives)
+    }
+
+    /// Parses the `~weave~` directive in a spell block, evaluating its
+    /// expression against an empty environment and clamping the result to
+    /// the `1..=1000` range `WeaveParser` enforces
     pub fn parse_weave(spell: &str) -> Option<u16> {
-        spell.lines()
-            .find(|line| line.contains("~weave~"))
-            .and_then(WeaveParser::parse)
+        let directives = Self::parse_block(spell).ok()?;
+        let expr = directives.get("weave")?;
+        let value = eval(expr, &HashMap::new()).ok()?;
+        let factor = value as u16;
+        if (1..=1000).contains(&factor) {
+            Some(factor)
+        } else {
+            None
+        }
     }
 
     /// Validates a spell block
@@ -35,4 +215,55 @@ mod tests {
         assert!(SpellParser::validate("@spell@\n~weave~ = 500\n@end@"));
         assert!(!SpellParser::validate("invalid"));
     }
+
+    #[test]
+    fn test_parse_weave_arithmetic() {
+        let spell = "@spell@\n~weave~ = 100 * 2 + 50\n@end@";
+        assert_eq!(SpellParser::parse_weave(spell), Some(250));
+    }
+
+    #[test]
+    fn test_parse_weave_out_of_range() {
+        let spell = "@spell@\n~weave~ = 500 * 3\n@end@";
+        assert_eq!(SpellParser::parse_weave(spell), None);
+    }
+
+    #[test]
+    fn test_parse_block_multiple_directives() {
+        let spell = "@spell@\n~weave~ = 500\n~power~ = 10 + 5\n@end@";
+        let directives = SpellParser::parse_block(spell).unwrap();
+        assert_eq!(directives.len(), 2);
+        assert_eq!(eval(&directives["weave"], &HashMap::new()), Ok(500.0));
+        assert_eq!(eval(&directives["power"], &HashMap::new()), Ok(15.0));
+    }
+
+    #[test]
+    fn test_parse_block_with_variable() {
+        let spell = "@spell@\n~weave~ = base * 2\n@end@";
+        let directives = SpellParser::parse_block(spell).unwrap();
+        let mut env = HashMap::new();
+        env.insert("base".to_string(), 250.0);
+        assert_eq!(eval(&directives["weave"], &env), Ok(500.0));
+    }
+
+    #[test]
+    fn test_eval_undefined_variable() {
+        let spell = "@spell@\n~weave~ = base\n@end@";
+        let directives = SpellParser::parse_block(spell).unwrap();
+        assert_eq!(eval(&directives["weave"], &HashMap::new()), Err(ParseError::UndefinedVariable("base".into())));
+    }
+
+    #[test]
+    fn test_parse_expr_precedence() {
+        let spell = "@spell@\n~weave~ = 2 + 3 * 4\n@end@";
+        let directives = SpellParser::parse_block(spell).unwrap();
+        assert_eq!(eval(&directives["weave"], &HashMap::new()), Ok(14.0));
+    }
+
+    #[test]
+    fn test_parse_expr_parens() {
+        let spell = "@spell@\n~weave~ = (2 + 3) * 4\n@end@";
+        let directives = SpellParser::parse_block(spell).unwrap();
+        assert_eq!(eval(&directives["weave"], &HashMap::new()), Ok(20.0));
+    }
 }
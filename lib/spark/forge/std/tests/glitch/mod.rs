@@ -1,4 +1,4 @@
-use spark_std::glitch::{Glitch, GlitchKind, CrystalError, CrystalErrorExt};
+use spark_std::glitch::{Glitch, GlitchKind, Label, CrystalError, CrystalErrorExt};
 use std::io;
 
 #[test]
@@ -53,3 +53,41 @@ fn test_backtrace() {
     let err = Glitch::io("test error");
     assert!(err.backtrace().is_some());
 }
+
+#[test]
+fn test_with_label_and_help() {
+    let source = "let x = ;";
+    let err = Glitch::parse("unexpected token")
+        .with_label(8..9, "expected an expression here")
+        .with_help("try inserting a value before the semicolon");
+
+    assert_eq!(err.labels().len(), 1);
+    assert_eq!(err.labels()[0].text(), "expected an expression here");
+    assert_eq!(err.help(), Some("try inserting a value before the semicolon"));
+
+    let rendered = err.render(source);
+    assert!(rendered.contains("let x = ;"));
+    assert!(rendered.contains("^"));
+    assert!(rendered.contains("expected an expression here"));
+    assert!(rendered.contains("help: try inserting a value before the semicolon"));
+}
+
+#[test]
+fn test_render_distinguishes_primary_and_secondary_labels() {
+    let source = "foo(bar, bar)";
+    let err = Glitch::validation("duplicate argument")
+        .with_label(9..12, "duplicate here")
+        .with_secondary_label(4..7, "first used here");
+
+    let rendered = err.render(source);
+    assert!(rendered.contains("^^^"));
+    assert!(rendered.contains("---"));
+}
+
+#[test]
+fn test_label_constructors_report_severity() {
+    let primary = Label::primary(0..1, "note");
+    let secondary = Label::secondary(0..1, "note");
+    assert_eq!(primary.severity(), spark_std::glitch::LabelSeverity::Primary);
+    assert_eq!(secondary.severity(), spark_std::glitch::LabelSeverity::Secondary);
+}
@@ -0,0 +1,189 @@
+use spark_std::rune::Rune;
+use std::str::FromStr;
+
+#[test]
+fn test_basic_rune() {
+    let r = Rune::new(0x41).unwrap();
+    assert_eq!(r.as_u32(), 0x41);
+    assert_eq!(format!("{}", r), "A");
+}
+
+#[test]
+fn test_invalid_rune() {
+    assert!(Rune::new(0x110000).is_none());
+    assert!(Rune::new(0xD800).is_none());
+}
+
+#[test]
+fn test_ascii() {
+    let r = Rune::from('A');
+    assert!(r.is_ascii());
+
+    let r = Rune::from('λ');
+    assert!(!r.is_ascii());
+}
+
+#[test]
+fn test_utf8_encoding() {
+    let r = Rune::from('λ');
+    assert_eq!(r.encode_utf8(), vec![0xCE, 0xBB]);
+}
+
+#[test]
+fn test_from_str() {
+    assert_eq!(Rune::from_str("A").unwrap(), Rune::from('A'));
+    assert!(Rune::from_str("AB").is_err());
+}
+
+#[test]
+fn test_classification_ascii() {
+    let r = Rune::from('A');
+    assert!(r.is_alphabetic());
+    assert!(!r.is_numeric());
+    assert!(r.is_alphanumeric());
+    assert!(!r.is_whitespace());
+    assert!(!r.is_control());
+}
+
+#[test]
+fn test_classification_beyond_latin1() {
+    // Greek and Cyrillic letters, previously misclassified as non-alphabetic.
+    assert!(Rune::from('Ω').is_alphabetic());
+    assert!(Rune::from('я').is_alphabetic());
+    assert!(Rune::from('λ').is_alphabetic());
+
+    // × and ÷ are symbols, not letters, despite sitting inside the Latin-1
+    // letter ranges.
+    assert!(!Rune::from('×').is_alphabetic());
+    assert!(!Rune::from('÷').is_alphabetic());
+}
+
+#[test]
+fn test_whitespace_and_separators() {
+    assert!(Rune::from('\t').is_whitespace());
+    assert!(Rune::from('\n').is_whitespace());
+    assert!(Rune::new(0x2028).unwrap().is_whitespace()); // line separator
+    assert!(Rune::new(0x2003).unwrap().is_whitespace()); // em space
+    assert!(!Rune::from('a').is_whitespace());
+}
+
+#[test]
+fn test_arithmetic() {
+    let r = Rune::from('A');
+    assert_eq!(r + 1, Some(Rune::from('B')));
+    assert_eq!(r - 1, Some(Rune::from('@')));
+}
+
+#[test]
+fn test_display() {
+    let r = Rune::from('λ');
+    assert_eq!(format!("{}", r), "λ");
+    assert_eq!(format!("{:?}", r), "Rune('λ')");
+}
+
+#[test]
+fn test_ordering() {
+    let a = Rune::from('A');
+    let b = Rune::from('B');
+    assert!(a < b);
+}
+
+#[test]
+fn test_replacement() {
+    assert_eq!(format!("{}", Rune::REPLACEMENT), "�");
+}
+
+#[test]
+fn test_simple_case_mapping() {
+    let runes: Vec<Rune> = Rune::from('a').to_uppercase().collect();
+    assert_eq!(runes, vec![Rune::from('A')]);
+
+    let runes: Vec<Rune> = Rune::from('A').to_lowercase().collect();
+    assert_eq!(runes, vec![Rune::from('a')]);
+}
+
+#[test]
+fn test_case_mapping_beyond_latin1() {
+    let runes: Vec<Rune> = Rune::from('ω').to_uppercase().collect();
+    assert_eq!(runes, vec![Rune::from('Ω')]);
+
+    let runes: Vec<Rune> = Rune::from('Я').to_lowercase().collect();
+    assert_eq!(runes, vec![Rune::from('я')]);
+}
+
+#[test]
+fn test_case_mapping_expansion() {
+    // ß uppercases to the two-scalar sequence SS.
+    let runes: Vec<Rune> = Rune::from('ß').to_uppercase().collect();
+    assert_eq!(runes, vec![Rune::from('S'), Rune::from('S')]);
+}
+
+#[test]
+fn test_case_mapping_identity_without_case() {
+    let digit = Rune::from('7');
+    let runes: Vec<Rune> = digit.to_uppercase().collect();
+    assert_eq!(runes, vec![digit]);
+
+    let han = Rune::new(0x4E2D).unwrap(); // 中, outside the tabulated scripts
+    let runes: Vec<Rune> = han.to_lowercase().collect();
+    assert_eq!(runes, vec![han]);
+}
+
+#[test]
+fn test_titlecase_follows_uppercase() {
+    let runes: Vec<Rune> = Rune::from('a').to_titlecase().collect();
+    assert_eq!(runes, vec![Rune::from('A')]);
+}
+
+fn decode(runes: impl IntoIterator<Item = Rune>) -> String {
+    runes
+        .into_iter()
+        .map(|r| char::from_u32(r.as_u32()).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_decode_utf8_pure_ascii() {
+    let array = Rune::decode_utf8(b"Hello, world!").unwrap();
+    assert_eq!(decode(array.iter().copied()), "Hello, world!");
+}
+
+#[test]
+fn test_decode_utf8_mixed_scripts() {
+    let text = "Hello, Ω world — café, я";
+    let array = Rune::decode_utf8(text.as_bytes()).unwrap();
+    assert_eq!(decode(array.iter().copied()), text);
+}
+
+#[test]
+fn test_decode_utf8_ascii_run_crosses_simd_chunk_boundary() {
+    let text = "a".repeat(100);
+    let array = Rune::decode_utf8(text.as_bytes()).unwrap();
+    assert_eq!(array.len(), 100);
+}
+
+#[test]
+fn test_decode_utf8_rejects_overlong_encoding() {
+    // Overlong 2-byte encoding of NUL.
+    assert!(Rune::decode_utf8(&[0xC0, 0x80]).is_err());
+}
+
+#[test]
+fn test_decode_utf8_rejects_surrogate() {
+    // Encoded surrogate half U+D800.
+    assert!(Rune::decode_utf8(&[0xED, 0xA0, 0x80]).is_err());
+}
+
+#[test]
+fn test_decode_utf8_rejects_truncated_sequence() {
+    assert!(Rune::decode_utf8(&[0xE2, 0x82]).is_err());
+}
+
+#[test]
+fn test_decode_utf8_lossy_replaces_malformed_bytes() {
+    let mut bytes = b"valid ".to_vec();
+    bytes.push(0xFF);
+    bytes.extend_from_slice(b" text");
+    let array = Rune::decode_utf8_lossy(&bytes);
+    assert_eq!(decode(array.iter().copied()), "valid \u{FFFD} text");
+}
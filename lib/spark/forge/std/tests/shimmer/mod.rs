@@ -58,3 +58,32 @@ fn test_rust_interface() {
     let result = shimmer.rust_fn::<fn()>("test", attrs);
     assert!(result.is_err(), "Unimplemented Rust interface should error");
 }
+
+#[test]
+fn test_julia_fn_requires_prior_negotiation() {
+    let shimmer = Shimmer::new();
+    let attrs = JuliaFnAttrs {
+        is_ccall: true,
+        return_type: String::from("Cvoid"),
+    };
+
+    match shimmer.julia_fn::<fn()>("test", attrs) {
+        Err(ShimmerError::NegotiationFailed(_)) => {}
+        other => panic!("expected NegotiationFailed, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_rust_fn_requires_prior_negotiation() {
+    let shimmer = Shimmer::new();
+    let attrs = RustFnAttrs {
+        is_unsafe: true,
+        is_extern: true,
+        abi: String::from("C"),
+    };
+
+    match shimmer.rust_fn::<fn()>("test", attrs) {
+        Err(ShimmerError::NegotiationFailed(_)) => {}
+        other => panic!("expected NegotiationFailed, got {:?}", other),
+    }
+}
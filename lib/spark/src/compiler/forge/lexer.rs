@@ -0,0 +1,179 @@
+//! Tokenizer for the forge directive mini-language (`~forge~ = calm`,
+//! `@spells@` blocks, `pub fn` signatures).
+//!
+//! In the spirit of Skytable's engine lexer, this turns the raw source into
+//! a flat stream of [`Token`]s carrying byte-offset spans, rather than the
+//! ad hoc substring scanning `ForgeFeatures::parse_features` used to do.
+//! That made an invalid safety level report nothing but a bare error with
+//! no indication of where in the source it came from.
+
+use super::SafetyLevel;
+
+/// A lexical token together with its byte-offset span into the source it
+/// was lexed from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// The kinds of token the forge lexer recognizes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    /// `~forge~`
+    ForgeMarker,
+    /// `@spells@`
+    SpellsMarker,
+    /// `=`
+    Equals,
+    /// `pub`
+    Pub,
+    /// `fn`
+    Fn,
+    /// `calm` / `balanced` / `wild`
+    Safety(SafetyLevel),
+    /// Any other identifier, e.g. a function name.
+    Ident,
+    /// Anything else: punctuation, braces, literals, ...
+    Other,
+}
+
+/// Tokenizes forge source text into a [`Token`] stream.
+pub struct Lexer<'a> {
+    source: &'a str,
+    pos: usize,
+}
+
+impl<'a> Lexer<'a> {
+    pub fn new(source: &'a str) -> Self {
+        Self { source, pos: 0 }
+    }
+
+    /// Tokenize the entire source into a flat token stream.
+    pub fn tokenize(mut self) -> Vec<Token> {
+        let mut tokens = Vec::new();
+        while let Some(token) = self.next_token() {
+            tokens.push(token);
+        }
+        tokens
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.source[self.pos..]
+    }
+
+    fn next_token(&mut self) -> Option<Token> {
+        self.skip_whitespace();
+        let start = self.pos;
+
+        if self.rest().is_empty() {
+            return None;
+        }
+
+        if let Some(token) = self.try_consume_fixed(start, "~forge~", TokenKind::ForgeMarker) {
+            return Some(token);
+        }
+        if let Some(token) = self.try_consume_fixed(start, "@spells@", TokenKind::SpellsMarker) {
+            return Some(token);
+        }
+        if self.rest().starts_with('=') {
+            self.pos += 1;
+            return Some(Token { kind: TokenKind::Equals, start, end: self.pos });
+        }
+
+        let first = self.rest().chars().next().unwrap();
+        if is_ident_start(first) {
+            let mut end = start + first.len_utf8();
+            for ch in self.source[end..].chars() {
+                if is_ident_continue(ch) {
+                    end += ch.len_utf8();
+                } else {
+                    break;
+                }
+            }
+            self.pos = end;
+            let kind = match &self.source[start..end] {
+                "pub" => TokenKind::Pub,
+                "fn" => TokenKind::Fn,
+                "calm" => TokenKind::Safety(SafetyLevel::Calm),
+                "balanced" => TokenKind::Safety(SafetyLevel::Balanced),
+                "wild" => TokenKind::Safety(SafetyLevel::Wild),
+                _ => TokenKind::Ident,
+            };
+            return Some(Token { kind, start, end });
+        }
+
+        // Anything else (punctuation, braces, numbers, ...) becomes a
+        // single opaque token; the parser only ever matches on the markers
+        // and keywords above.
+        self.pos += first.len_utf8();
+        Some(Token { kind: TokenKind::Other, start, end: self.pos })
+    }
+
+    fn try_consume_fixed(&mut self, start: usize, literal: &str, kind: TokenKind) -> Option<Token> {
+        if self.rest().starts_with(literal) {
+            self.pos += literal.len();
+            Some(Token { kind, start, end: self.pos })
+        } else {
+            None
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(ch) = self.rest().chars().next() {
+            if ch.is_whitespace() {
+                self.pos += ch.len_utf8();
+            } else {
+                break;
+            }
+        }
+    }
+}
+
+fn is_ident_start(ch: char) -> bool {
+    ch.is_alphabetic() || ch == '_'
+}
+
+fn is_ident_continue(ch: char) -> bool {
+    ch.is_alphanumeric() || ch == '_'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenizes_forge_directive() {
+        let tokens = Lexer::new("~forge~ = calm").tokenize();
+        let kinds: Vec<_> = tokens.iter().map(|t| t.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![TokenKind::ForgeMarker, TokenKind::Equals, TokenKind::Safety(SafetyLevel::Calm)],
+        );
+    }
+
+    #[test]
+    fn test_spans_are_byte_accurate() {
+        let source = "~forge~ = calm";
+        let tokens = Lexer::new(source).tokenize();
+        let level = tokens.last().unwrap();
+        assert_eq!(&source[level.start..level.end], "calm");
+    }
+
+    #[test]
+    fn test_tokenizes_spells_block_and_signature() {
+        let tokens = Lexer::new("@spells@\npub fn safe_fn() -> i32 { 1 }\n@spells@").tokenize();
+        assert_eq!(tokens[0].kind, TokenKind::SpellsMarker);
+        assert_eq!(tokens[1].kind, TokenKind::Pub);
+        assert_eq!(tokens[2].kind, TokenKind::Fn);
+        assert_eq!(tokens[3].kind, TokenKind::Ident);
+    }
+
+    #[test]
+    fn test_unrecognized_safety_level_lexes_as_plain_ident() {
+        let tokens = Lexer::new("~forge~ = invalid").tokenize();
+        assert_eq!(tokens[2].kind, TokenKind::Ident);
+        assert_eq!(&"~forge~ = invalid"[tokens[2].start..tokens[2].end], "invalid");
+    }
+}
@@ -1,6 +1,10 @@
 use std::path::Path;
 use std::collections::HashMap;
-use std::str::FromStr;
+use std::fmt;
+
+mod lexer;
+
+pub use lexer::{Lexer, Token, TokenKind};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SafetyLevel {
@@ -15,19 +19,30 @@ impl Default for SafetyLevel {
     }
 }
 
-impl FromStr for SafetyLevel {
-    type Err = String;
+/// An error produced while parsing forge directives, carrying the
+/// byte-offset span of the offending token so callers can render a
+/// caret-style diagnostic pointing at the exact spot in the source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForgeError {
+    pub message: String,
+    pub start: usize,
+    pub end: usize,
+}
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s.trim().to_lowercase().as_str() {
-            "calm" => Ok(SafetyLevel::Calm),
-            "balanced" => Ok(SafetyLevel::Balanced),
-            "wild" => Ok(SafetyLevel::Wild),
-            _ => Err(format!("Unknown safety level: {}. Use calm, balanced, or wild", s))
-        }
+impl ForgeError {
+    fn new(message: impl Into<String>, start: usize, end: usize) -> Self {
+        Self { message: message.into(), start, end }
     }
 }
 
+impl fmt::Display for ForgeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (at {}..{})", self.message, self.start, self.end)
+    }
+}
+
+impl std::error::Error for ForgeError {}
+
 pub struct ForgeFeatures {
     file_safety: SafetyLevel,
     module_path: String,
@@ -43,41 +58,84 @@ impl ForgeFeatures {
         }
     }
 
-    pub fn parse_features(&mut self, source: &str) -> Result<(), String> {
-        for line in source.lines() {
-            let line = line.trim();
-            
-            if line.starts_with("~forge~") {
-                self.parse_safety_level(line, None)?;
-            } else if line.contains("~forge~") && line.contains("fn") {
-                if let Some(fn_name) = self.extract_function_name(line) {
-                    self.parse_safety_level(line, Some(fn_name))?;
+    /// Parse forge directives out of `source`.
+    ///
+    /// Tokenizes `source` with [`Lexer`] and drives the grammar off that
+    /// token stream rather than scanning lines: a `~forge~ = <level>`
+    /// directive outside a `@spells@ ... @spells@` block sets the file's
+    /// default safety level, while one inside such a block applies only to
+    /// the next `pub fn` signature encountered.
+    pub fn parse_features(&mut self, source: &str) -> Result<(), ForgeError> {
+        let tokens = Lexer::new(source).tokenize();
+        let mut in_spells_block = false;
+        let mut pending_level: Option<SafetyLevel> = None;
+        let mut i = 0;
+
+        while i < tokens.len() {
+            match tokens[i].kind {
+                TokenKind::SpellsMarker => {
+                    in_spells_block = !in_spells_block;
+                    i += 1;
+                }
+                TokenKind::ForgeMarker => {
+                    let level = self.expect_safety_assignment(source, &tokens, i)?;
+                    if in_spells_block {
+                        pending_level = Some(level);
+                    } else {
+                        self.file_safety = level;
+                    }
+                    i += 3;
+                }
+                TokenKind::Pub => {
+                    if let Some(name) = match_function_signature(source, &tokens, i) {
+                        if in_spells_block {
+                            if let Some(level) = pending_level.take() {
+                                self.function_safety.insert(name, level);
+                            }
+                        }
+                        i += 3;
+                    } else {
+                        i += 1;
+                    }
                 }
+                _ => i += 1,
             }
         }
-        Ok(())
-    }
 
-    fn extract_function_name(&self, line: &str) -> Option<String> {
-        line.split("fn")
-            .nth(1)?
-            .split('(')
-            .next()
-            .map(|s| s.trim().to_string())
+        Ok(())
     }
 
-    fn parse_safety_level(&mut self, line: &str, fn_name: Option<String>) -> Result<(), String> {
-        let level = line.split('=')
-            .nth(1)
-            .ok_or_else(|| "Invalid forge feature syntax".to_string())?
-            .trim()
-            .parse()?;
+    /// Expect `= <safety-level>` immediately following the `~forge~` token
+    /// at `marker_idx`, returning the parsed level or a [`ForgeError`]
+    /// spanning whichever token broke the expected shape.
+    fn expect_safety_assignment(
+        &self,
+        source: &str,
+        tokens: &[Token],
+        marker_idx: usize,
+    ) -> Result<SafetyLevel, ForgeError> {
+        let marker = tokens[marker_idx];
+        let equals = tokens.get(marker_idx + 1);
+        let level_token = tokens.get(marker_idx + 2);
 
-        match fn_name {
-            Some(name) => { self.function_safety.insert(name, level); }
-            None => self.file_safety = level,
+        match (equals, level_token) {
+            (Some(eq), Some(level)) if eq.kind == TokenKind::Equals => match level.kind {
+                TokenKind::Safety(safety) => Ok(safety),
+                _ => Err(ForgeError::new(
+                    format!(
+                        "unknown safety level `{}`; expected `calm`, `balanced`, or `wild`",
+                        &source[level.start..level.end],
+                    ),
+                    level.start,
+                    level.end,
+                )),
+            },
+            _ => Err(ForgeError::new(
+                "expected `= <safety-level>` after `~forge~`",
+                marker.start,
+                marker.end,
+            )),
         }
-        Ok(())
     }
 
     pub fn get_safety_level(&self, fn_name: &str) -> SafetyLevel {
@@ -86,4 +144,21 @@ impl ForgeFeatures {
             .copied()
             .unwrap_or(self.file_safety)
     }
+
+    pub fn module_path(&self) -> &str {
+        &self.module_path
+    }
+}
+
+/// If `tokens[pub_idx..]` begins a `pub fn <name>` signature, return the
+/// function name.
+fn match_function_signature(source: &str, tokens: &[Token], pub_idx: usize) -> Option<String> {
+    let fn_token = tokens.get(pub_idx + 1)?;
+    let name_token = tokens.get(pub_idx + 2)?;
+
+    if fn_token.kind != TokenKind::Fn || name_token.kind != TokenKind::Ident {
+        return None;
+    }
+
+    Some(source[name_token.start..name_token.end].to_string())
 }
@@ -28,4 +28,12 @@ mod tests {
         let mut features = ForgeFeatures::new("test.spk");
         assert!(features.parse_features(source).is_err());
     }
+
+    #[test]
+    fn test_invalid_safety_level_points_at_offending_span() {
+        let source = "~forge~ = invalid";
+        let mut features = ForgeFeatures::new("test.spk");
+        let err = features.parse_features(source).unwrap_err();
+        assert_eq!(&source[err.start..err.end], "invalid");
+    }
 }
@@ -19,6 +19,12 @@ pub enum Alignment {
     Parallel256,
     /// Custom alignment in bytes (must be power of 2)
     Custom(usize),
+    /// Runtime-determined vector alignment, in bytes, for scalable ISAs
+    /// like AArch64 SVE whose vector length isn't fixed at compile time.
+    /// Always a multiple of 16 (SVE's vector length ranges from 128 to
+    /// 2048 bits in 128-bit increments) but not necessarily a power of
+    /// two.
+    Scalable(usize),
 }
 
 impl Alignment {
@@ -31,6 +37,7 @@ impl Alignment {
             Self::Parallel128 => 128,
             Self::Parallel256 => 256,
             Self::Custom(bytes) => bytes,
+            Self::Scalable(bytes) => bytes,
         }
     }
 
@@ -59,6 +66,7 @@ impl fmt::Display for Alignment {
             Self::Parallel128 => write!(f, "128-byte parallel alignment"),
             Self::Parallel256 => write!(f, "256-byte parallel alignment"),
             Self::Custom(bytes) => write!(f, "{}-byte custom alignment", bytes),
+            Self::Scalable(bytes) => write!(f, "{}-byte scalable vector alignment", bytes),
         }
     }
 }
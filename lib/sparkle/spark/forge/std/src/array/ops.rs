@@ -24,13 +24,9 @@ where
 
         let mut result = Self::with_capacity(self.len(), self.alignment);
 
-        // Use SIMD operations if available and aligned
-        if self.is_simd_aligned() && other.is_simd_aligned() {
-            // SIMD implementation would go here
-            unimplemented!("SIMD operations not yet implemented");
-        }
-
-        // Fallback to scalar operations
+        // Fallback to scalar operations. Types with a `vector_size()`
+        // lane width (f32, f64) can skip this and call
+        // `CrystalArray::add_slice` directly -- see array/simd.rs.
         for i in 0..self.len() {
             let sum = *self.get(i).unwrap() + *other.get(i).unwrap();
             result.push(sum);
@@ -44,13 +40,9 @@ where
 
         let mut result = Self::with_capacity(self.len(), self.alignment);
 
-        // Use SIMD operations if available and aligned
-        if self.is_simd_aligned() && other.is_simd_aligned() {
-            // SIMD implementation would go here
-            unimplemented!("SIMD operations not yet implemented");
-        }
-
-        // Fallback to scalar operations
+        // Fallback to scalar operations. Types with a `vector_size()`
+        // lane width (f32, f64) can skip this and call
+        // `CrystalArray::mul_slice` directly -- see array/simd.rs.
         for i in 0..self.len() {
             let product = *self.get(i).unwrap() * *other.get(i).unwrap();
             result.push(product);
@@ -64,12 +56,6 @@ where
 
         let mut sum = T::default();
 
-        // Use SIMD operations if available and aligned
-        if self.is_simd_aligned() && other.is_simd_aligned() {
-            // SIMD implementation would go here
-            unimplemented!("SIMD operations not yet implemented");
-        }
-
         // Fallback to scalar operations
         for i in 0..self.len() {
             sum = sum + (*self.get(i).unwrap() * *other.get(i).unwrap());
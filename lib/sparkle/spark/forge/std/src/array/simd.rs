@@ -0,0 +1,185 @@
+//! Slice-level SIMD batch arithmetic for `CrystalArray`.
+//!
+//! `ArrayOps::add`/`mul`/`dot` (see `ops.rs`) checked `is_simd_aligned()`
+//! and then gave up with `unimplemented!()` whenever it was actually
+//! true, so `optimal_alignment()` and `vector_size()` (see `layout.rs`)
+//! were metadata nobody consumed. `add_slice`/`sub_slice`/`mul_slice`
+//! turn that into real throughput: they process a whole slice through
+//! `vector_size()`-wide chunks instead of one scalar at a time, with a
+//! scalar remainder loop for whatever doesn't divide evenly.
+//!
+//! This crate has no root module on disk to add
+//! `#![feature(portable_simd)]` to, so these dispatch through plain
+//! per-lane arithmetic over fixed-width chunks rather than
+//! `std::simd`'s explicit vector types -- the same data-parallel access
+//! pattern a real SIMD backend would use, structured so the optimizer
+//! can autovectorize it, without requiring a feature flag this source
+//! tree has nowhere to declare.
+//!
+//! Lane width and alignment both come from `shard::arch::kernel`'s
+//! cached `KernelTier` rather than calling `CrystalArray::vector_size()`
+//! directly, so repeated `add_slice`/`sub_slice`/`mul_slice` calls reuse
+//! one `Shard` probe instead of re-running feature detection each time.
+
+use super::CrystalArray;
+use crate::shard::arch::kernel;
+
+/// How many lanes of `Self` fit in a `vector_size()`-byte SIMD register,
+/// so batch arithmetic dispatches to the right width instead of
+/// hardcoding one -- 16 lanes of `f32` under AVX-512's 64-byte vectors,
+/// 8 lanes of `f64` under the same, scaling down with `vector_size()`
+/// on narrower targets.
+pub trait SimdLanes: Sized {
+    fn lanes_for(vector_bytes: usize) -> usize {
+        (vector_bytes / std::mem::size_of::<Self>()).max(1)
+    }
+}
+
+impl SimdLanes for f32 {}
+impl SimdLanes for f64 {}
+
+/// Reads a `lanes`-wide chunk starting at `start`, via the aligned-load
+/// path. Identical to `read_chunk_unaligned` today -- safe Rust slice
+/// indexing has no separate aligned-load instruction to choose between
+/// -- but kept as its own call site so a real intrinsics backend has
+/// somewhere to plug in `_mm256_load_ps`-style aligned loads later
+/// without touching call sites.
+#[inline]
+fn read_chunk_aligned<T: Copy>(slice: &[T], start: usize, lanes: usize) -> &[T] {
+    &slice[start..start + lanes]
+}
+
+/// Reads a `lanes`-wide chunk starting at `start`, via the
+/// unaligned-load path. See `read_chunk_aligned`.
+#[inline]
+fn read_chunk_unaligned<T: Copy>(slice: &[T], start: usize, lanes: usize) -> &[T] {
+    &slice[start..start + lanes]
+}
+
+/// True when both `a` and `b` are aligned to `vector_bytes`, mirroring
+/// `CrystalArray::is_simd_aligned`'s own pointer check on a plain slice.
+fn slices_simd_aligned<T>(a: &[T], b: &[T], vector_bytes: usize) -> bool {
+    (a.as_ptr() as usize) % vector_bytes == 0 && (b.as_ptr() as usize) % vector_bytes == 0
+}
+
+/// Applies `op` elementwise to `a`/`b`, processing `lanes`-wide chunks
+/// in one pass and handling the tail with a plain scalar loop.
+fn batch<T: Copy>(a: &[T], b: &[T], lanes: usize, aligned: bool, op: impl Fn(T, T) -> T) -> Vec<T> {
+    assert_eq!(a.len(), b.len(), "slices must have equal length");
+
+    let len = a.len();
+    let mut out = Vec::with_capacity(len);
+    let full_chunks = len / lanes;
+
+    for chunk in 0..full_chunks {
+        let start = chunk * lanes;
+        let (a_chunk, b_chunk) = if aligned {
+            (read_chunk_aligned(a, start, lanes), read_chunk_aligned(b, start, lanes))
+        } else {
+            (read_chunk_unaligned(a, start, lanes), read_chunk_unaligned(b, start, lanes))
+        };
+
+        for lane in 0..lanes {
+            out.push(op(a_chunk[lane], b_chunk[lane]));
+        }
+    }
+
+    for i in (full_chunks * lanes)..len {
+        out.push(op(a[i], b[i]));
+    }
+
+    out
+}
+
+/// Runs `op` elementwise over `a`/`b` at the process-wide cached
+/// `KernelTier` (see `shard::arch::kernel::current_tier`), rather than
+/// reconstructing a `Shard` and re-detecting features per call.
+fn dispatch_batch<T: Copy + SimdLanes>(a: &[T], b: &[T], op: impl Fn(T, T) -> T) -> Vec<T> {
+    let tier = kernel::current_tier();
+    let lanes = tier.lanes_for::<T>();
+    let vector_bytes = tier.vector_bytes();
+    let aligned = vector_bytes > 0 && slices_simd_aligned(a, b, vector_bytes);
+    batch(a, b, lanes, aligned, op)
+}
+
+macro_rules! impl_simd_batch {
+    ($t:ty) => {
+        impl CrystalArray<$t> {
+            /// Elementwise `a[i] + b[i]`, dispatched through the cached
+            /// `KernelTier`'s lane width.
+            pub fn add_slice(a: &[$t], b: &[$t]) -> Vec<$t> {
+                dispatch_batch(a, b, |x, y| x + y)
+            }
+
+            /// Elementwise `a[i] - b[i]`, dispatched through the cached
+            /// `KernelTier`'s lane width.
+            pub fn sub_slice(a: &[$t], b: &[$t]) -> Vec<$t> {
+                dispatch_batch(a, b, |x, y| x - y)
+            }
+
+            /// Elementwise `a[i] * b[i]`, dispatched through the cached
+            /// `KernelTier`'s lane width.
+            pub fn mul_slice(a: &[$t], b: &[$t]) -> Vec<$t> {
+                dispatch_batch(a, b, |x, y| x * y)
+            }
+        }
+    };
+}
+
+impl_simd_batch!(f32);
+impl_simd_batch!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_slice_matches_scalar_addition() {
+        let a: Vec<f32> = (0..37).map(|i| i as f32).collect();
+        let b: Vec<f32> = (0..37).map(|i| (i * 2) as f32).collect();
+
+        let result = CrystalArray::<f32>::add_slice(&a, &b);
+
+        for i in 0..a.len() {
+            assert_eq!(result[i], a[i] + b[i]);
+        }
+    }
+
+    #[test]
+    fn test_sub_slice_handles_non_multiple_of_lane_width() {
+        let a: Vec<f64> = (0..13).map(|i| i as f64).collect();
+        let b: Vec<f64> = (0..13).map(|i| i as f64 * 0.5).collect();
+
+        let result = CrystalArray::<f64>::sub_slice(&a, &b);
+
+        assert_eq!(result.len(), 13);
+        for i in 0..a.len() {
+            assert_eq!(result[i], a[i] - b[i]);
+        }
+    }
+
+    #[test]
+    fn test_mul_slice_matches_scalar_multiplication() {
+        let a: Vec<f32> = (0..64).map(|i| i as f32).collect();
+        let b: Vec<f32> = (0..64).map(|i| (i % 5) as f32).collect();
+
+        let result = CrystalArray::<f32>::mul_slice(&a, &b);
+
+        for i in 0..a.len() {
+            assert_eq!(result[i], a[i] * b[i]);
+        }
+    }
+
+    #[test]
+    fn test_lanes_for_scales_with_element_size() {
+        assert_eq!(f32::lanes_for(64), 16);
+        assert_eq!(f64::lanes_for(64), 8);
+        assert_eq!(f32::lanes_for(16), 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "equal length")]
+    fn test_mismatched_lengths_panics() {
+        CrystalArray::<f32>::add_slice(&[1.0, 2.0], &[1.0]);
+    }
+}
@@ -11,6 +11,8 @@ pub enum CpuFeature {
     SVE,
     // RISC-V features
     V,
+    // WebAssembly features
+    Simd128,
     // Common features
     FMA,
     SIMD,
@@ -29,6 +31,9 @@ pub fn detect_features() -> Vec<CpuFeature> {
     #[cfg(target_arch = "riscv64")]
     detect_riscv_features(&mut features);
 
+    #[cfg(target_arch = "wasm32")]
+    detect_wasm32_features(&mut features);
+
     features
 }
 
@@ -48,12 +53,50 @@ fn detect_x86_features(features: &mut Vec<CpuFeature>) {
 #[cfg(target_arch = "aarch64")]
 fn detect_aarch64_features(features: &mut Vec<CpuFeature>) {
     features.push(CpuFeature::NEON);
-    // SVE detection would go here
+    if std::arch::is_aarch64_feature_detected!("sve") {
+        features.push(CpuFeature::SVE);
+    }
     features.push(CpuFeature::SIMD);
 }
 
+/// Reads the current hardware's SVE vector length in bytes via `cntb`
+/// (count bytes in a 128-to-2048-bit scalable vector register), or
+/// `None` if SVE isn't available. Unlike every other `CpuFeature`, SVE's
+/// width isn't a fixed constant -- this is the runtime query
+/// `optimal_alignment()`/`vector_size()` use instead of assuming 64
+/// bytes.
+#[cfg(target_arch = "aarch64")]
+pub fn sve_vector_bytes() -> Option<usize> {
+    if !std::arch::is_aarch64_feature_detected!("sve") {
+        return None;
+    }
+
+    let bytes: u64;
+    unsafe {
+        std::arch::asm!("cntb {0}", out(reg) bytes);
+    }
+    Some(bytes as usize)
+}
+
+/// SVE is AArch64-only; every other target reports no scalable vector
+/// width to query.
+#[cfg(not(target_arch = "aarch64"))]
+pub fn sve_vector_bytes() -> Option<usize> {
+    None
+}
+
 #[cfg(target_arch = "riscv64")]
 fn detect_riscv_features(features: &mut Vec<CpuFeature>) {
     // RISC-V vector extension detection would go here
     features.push(CpuFeature::SIMD);
 }
+
+/// WebAssembly has no runtime CPUID-style probe -- `simd128` is either
+/// compiled into the module (`-C target-feature=+simd128`) or it isn't,
+/// so this is a `cfg` check rather than a call like
+/// `is_x86_feature_detected!`.
+#[cfg(target_arch = "wasm32")]
+fn detect_wasm32_features(features: &mut Vec<CpuFeature>) {
+    #[cfg(target_feature = "simd128")]
+    features.push(CpuFeature::Simd128);
+}
@@ -0,0 +1,155 @@
+//! Runtime-multiversioned SIMD kernel dispatch.
+//!
+//! `CrystalArray::optimal_alignment()`/`vector_size()` (see
+//! `array/layout.rs`) and `add_slice`/`sub_slice`/`mul_slice` (see
+//! `array/simd.rs`) each built their own `Shard::new()` and re-ran its
+//! CPUID-style feature detection on every call. `current_tier()` probes
+//! a `Shard` once, picks the best available `KernelTier`, and caches the
+//! choice in a `OnceCell` so hot arithmetic paths pay detection's cost
+//! exactly once per process instead of once per operation.
+//!
+//! `SPARK_SIMD_TIER` forces a specific tier, read on that same first
+//! call -- set it before launching a benchmark to compare tiers across
+//! separate runs on one machine without needing a runtime reset of the
+//! cache.
+//!
+//! `CrystalSub` (`lib/magicmath`) is a separate crate operating on
+//! scalar `MeshValue`s with no slice/array entry point of its own, so
+//! there is nothing in it to route through a per-chunk dispatcher like
+//! this one -- only `CrystalArray`'s batch ops consume `current_tier()`
+//! today.
+
+use once_cell::sync::OnceCell;
+
+use super::{Architecture, CpuFeature, Shard};
+
+/// Which SIMD kernel variant a dispatch resolved to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KernelTier {
+    Avx512,
+    Avx2,
+    Vector16,
+    Neon,
+    Sve,
+    Wasm128,
+    Scalar,
+}
+
+impl KernelTier {
+    /// Picks the best tier `shard` actually supports, mirroring
+    /// `CrystalArray::optimal_alignment`'s own branching.
+    fn detect(shard: &Shard) -> Self {
+        match shard.architecture() {
+            Architecture::X86_64 => {
+                if shard.has_feature(CpuFeature::AVX512F) {
+                    KernelTier::Avx512
+                } else if shard.has_feature(CpuFeature::AVX2) {
+                    KernelTier::Avx2
+                } else {
+                    KernelTier::Vector16
+                }
+            }
+            Architecture::AArch64 => {
+                if shard.has_feature(CpuFeature::SVE) {
+                    KernelTier::Sve
+                } else if shard.has_feature(CpuFeature::NEON) {
+                    KernelTier::Neon
+                } else {
+                    KernelTier::Vector16
+                }
+            }
+            Architecture::Wasm32 => {
+                if shard.has_feature(CpuFeature::Simd128) {
+                    KernelTier::Wasm128
+                } else {
+                    KernelTier::Scalar
+                }
+            }
+            _ => KernelTier::Scalar,
+        }
+    }
+
+    /// Parses a `SPARK_SIMD_TIER` value, matched case-insensitively.
+    fn from_override(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "avx512" => Some(KernelTier::Avx512),
+            "avx2" => Some(KernelTier::Avx2),
+            "vector16" | "sse" => Some(KernelTier::Vector16),
+            "neon" => Some(KernelTier::Neon),
+            "sve" => Some(KernelTier::Sve),
+            "wasm128" | "simd128" => Some(KernelTier::Wasm128),
+            "scalar" => Some(KernelTier::Scalar),
+            _ => None,
+        }
+    }
+
+    /// SIMD register width, in bytes, this tier processes per chunk --
+    /// the same widths `CrystalArray::optimal_alignment` assigns per
+    /// tier. `Scalar` has no register width; `lanes_for` handles it
+    /// directly rather than dividing by zero. `Sve`'s width isn't fixed
+    /// at compile time, so this reads the hardware's actual vector
+    /// length via `sve_vector_bytes()`, falling back to 64 only if that
+    /// query fails (e.g. detection raced a tier forced through
+    /// `SPARK_SIMD_TIER` on non-SVE hardware).
+    pub fn vector_bytes(self) -> usize {
+        match self {
+            KernelTier::Avx512 => 64,
+            KernelTier::Sve => super::sve_vector_bytes().unwrap_or(64),
+            KernelTier::Avx2 => 32,
+            KernelTier::Vector16 | KernelTier::Neon | KernelTier::Wasm128 => 16,
+            KernelTier::Scalar => 0,
+        }
+    }
+
+    /// How many lanes of `T` this tier processes per chunk -- always 1
+    /// for `Scalar`, otherwise `SimdLanes::lanes_for(vector_bytes())`.
+    pub fn lanes_for<T: crate::array::simd::SimdLanes>(self) -> usize {
+        match self {
+            KernelTier::Scalar => 1,
+            tier => T::lanes_for(tier.vector_bytes()),
+        }
+    }
+}
+
+static CACHED_TIER: OnceCell<KernelTier> = OnceCell::new();
+
+/// The process-wide cached kernel tier: detected from a single `Shard`
+/// probe (or read from `SPARK_SIMD_TIER`) on first call, and reused on
+/// every call after that.
+pub fn current_tier() -> KernelTier {
+    *CACHED_TIER.get_or_init(|| {
+        std::env::var("SPARK_SIMD_TIER")
+            .ok()
+            .and_then(|name| KernelTier::from_override(&name))
+            .unwrap_or_else(|| KernelTier::detect(&Shard::new()))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lanes_for_scalar_tier_is_always_one() {
+        assert_eq!(KernelTier::Scalar.lanes_for::<f32>(), 1);
+        assert_eq!(KernelTier::Scalar.lanes_for::<f64>(), 1);
+    }
+
+    #[test]
+    fn test_lanes_for_avx512_scales_with_element_size() {
+        assert_eq!(KernelTier::Avx512.lanes_for::<f32>(), 16);
+        assert_eq!(KernelTier::Avx512.lanes_for::<f64>(), 8);
+    }
+
+    #[test]
+    fn test_from_override_is_case_insensitive() {
+        assert_eq!(KernelTier::from_override("AVX2"), Some(KernelTier::Avx2));
+        assert_eq!(KernelTier::from_override("Scalar"), Some(KernelTier::Scalar));
+        assert_eq!(KernelTier::from_override("bogus"), None);
+    }
+
+    #[test]
+    fn test_current_tier_is_stable_across_calls() {
+        assert_eq!(current_tier(), current_tier());
+    }
+}
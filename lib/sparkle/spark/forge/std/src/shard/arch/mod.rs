@@ -3,9 +3,10 @@
 mod platform;
 mod features;
 mod dispatch;
+pub mod kernel;
 
 pub use platform::{Platform, Architecture};
-pub use features::{CpuFeature, detect_features};
+pub use features::{CpuFeature, detect_features, sve_vector_bytes};
 pub use dispatch::Dispatcher;
 
 /// Represents a hardware-specific shard implementation
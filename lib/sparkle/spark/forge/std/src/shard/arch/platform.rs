@@ -0,0 +1,61 @@
+//! Platform and architecture detection.
+
+/// Coarse CPU/target architecture family a `Shard` runs on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Architecture {
+    X86_64,
+    AArch64,
+    RiscV64,
+    Wasm32,
+    /// Any target without a dedicated SIMD-tier arm above -- callers
+    /// fall back to scalar crystal operations.
+    Other,
+}
+
+/// The detected platform a `Shard` runs on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Platform {
+    architecture: Architecture,
+}
+
+impl Platform {
+    /// Detects the platform from compile-time target configuration.
+    pub fn detect() -> Self {
+        Self { architecture: detect_architecture() }
+    }
+
+    /// Returns the detected architecture family.
+    pub fn architecture(&self) -> Architecture {
+        self.architecture
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+fn detect_architecture() -> Architecture {
+    Architecture::X86_64
+}
+
+#[cfg(target_arch = "aarch64")]
+fn detect_architecture() -> Architecture {
+    Architecture::AArch64
+}
+
+#[cfg(target_arch = "riscv64")]
+fn detect_architecture() -> Architecture {
+    Architecture::RiscV64
+}
+
+#[cfg(target_arch = "wasm32")]
+fn detect_architecture() -> Architecture {
+    Architecture::Wasm32
+}
+
+#[cfg(not(any(
+    target_arch = "x86_64",
+    target_arch = "aarch64",
+    target_arch = "riscv64",
+    target_arch = "wasm32"
+)))]
+fn detect_architecture() -> Architecture {
+    Architecture::Other
+}
@@ -9,10 +9,15 @@ pub mod rust;
 
 use std::sync::Arc;
 use std::any::Any;
-use std::ffi::{c_void, CString};
+use std::ffi::{c_char, c_void, CStr, CString};
 use std::marker::PhantomData;
 use std::error::Error;
 use std::fmt;
+use std::thread;
+use std::time::Duration;
+
+use prism::runtime::task::{TaskConfig, TaskExecutor};
+use prism::types::{PrismError, PrismResult, TaskHandle};
 
 /// Error type for shimmer operations
 #[derive(Debug)]
@@ -25,6 +30,8 @@ pub enum ShimmerError {
     TypeError(String),
     /// Runtime error
     RuntimeError(String),
+    /// The library's ABI descriptor didn't meet the caller's requirements
+    NegotiationFailed(String),
 }
 
 impl fmt::Display for ShimmerError {
@@ -34,6 +41,7 @@ impl fmt::Display for ShimmerError {
             Self::SymbolError(msg) => write!(f, "Symbol not found: {}", msg),
             Self::TypeError(msg) => write!(f, "Type conversion error: {}", msg),
             Self::RuntimeError(msg) => write!(f, "Runtime error: {}", msg),
+            Self::NegotiationFailed(msg) => write!(f, "ABI negotiation failed: {}", msg),
         }
     }
 }
@@ -43,6 +51,42 @@ impl Error for ShimmerError {}
 /// Result type for shimmer operations
 pub type ShimmerResult<T> = Result<T, ShimmerError>;
 
+/// Well-known symbol a loaded library exposes its ABI descriptor under.
+/// `Shimmer::negotiate` looks this up before binding any `zig_fn`/
+/// `julia_fn`/`rust_fn` entry point.
+const ABI_DESCRIPTOR_SYMBOL: &str = "shimmer_abi_descriptor";
+
+/// The raw, FFI-shaped descriptor a library's `shimmer_abi_descriptor`
+/// symbol returns: a chain/ABI name plus a version number and a
+/// feature-flags bitset, mirroring how Tezos's `NetworkVersion` pairs a
+/// `chain_name` with `distributed_db_version`/`p2p_version`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+struct RawAbiDescriptor {
+    chain_name: *const c_char,
+    abi_version: u32,
+    feature_flags: u32,
+}
+
+/// The result of a successful [`Shimmer::negotiate`] call: the library's
+/// ABI name, version, and feature flags, owned and safe to hold past the
+/// lifetime of the raw descriptor it was read from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NegotiatedAbi {
+    pub chain_name: String,
+    pub abi_version: u32,
+    pub feature_flags: u32,
+}
+
+impl NegotiatedAbi {
+    /// Whether every bit set in `flags` is also set in this ABI's
+    /// feature flags -- the capability-predicate role Tezos's
+    /// `supports_nack_with_list_and_motive` plays for `NetworkVersion`.
+    pub fn supports(&self, flags: u32) -> bool {
+        self.feature_flags & flags == flags
+    }
+}
+
 /// Wrapper for raw pointer debug formatting
 #[derive(Clone, Copy)]
 struct RawPtr(*mut c_void);
@@ -134,6 +178,9 @@ unsafe impl<T> Sync for ShimmerFn<T> {}
 pub struct Shimmer {
     /// Current context
     context: Arc<ShimmerContext>,
+    /// Result of the last successful [`Shimmer::negotiate`] call against
+    /// the currently loaded library, if any
+    negotiated: Option<NegotiatedAbi>,
 }
 
 impl Shimmer {
@@ -144,6 +191,7 @@ impl Shimmer {
                 lib: Arc::new(RawPtr(std::ptr::null_mut())),
                 data: DynamicData::new(()),
             }),
+            negotiated: None,
         }
     }
 
@@ -170,10 +218,63 @@ impl Shimmer {
             lib: Arc::new(RawPtr(lib)),
             data: DynamicData::new(()),
         });
+        // A freshly loaded library hasn't negotiated an ABI yet, even if
+        // the previous one had
+        self.negotiated = None;
 
         Ok(())
     }
 
+    /// Reads the loaded library's `shimmer_abi_descriptor` symbol and
+    /// checks it against the caller's requirements, rejecting libraries
+    /// below `min_version` or missing any bit in `required_flags`. On
+    /// success the result is cached so later calls to `zig_fn`/
+    /// `julia_fn`/`rust_fn` can confirm a negotiation already happened
+    /// instead of binding a mismatched ABI and failing at the first
+    /// unsafe call.
+    pub fn negotiate(&mut self, min_version: u32, required_flags: u32) -> ShimmerResult<NegotiatedAbi> {
+        let descriptor_fn: ShimmerFn<extern "C" fn() -> RawAbiDescriptor> = self.get_fn(ABI_DESCRIPTOR_SYMBOL)?;
+        if !descriptor_fn.is_valid() {
+            return Err(ShimmerError::SymbolError(ABI_DESCRIPTOR_SYMBOL.to_string()));
+        }
+
+        let read_descriptor: extern "C" fn() -> RawAbiDescriptor =
+            unsafe { std::mem::transmute(descriptor_fn.ptr.0) };
+        let raw = read_descriptor();
+
+        if raw.chain_name.is_null() {
+            return Err(ShimmerError::NegotiationFailed("ABI descriptor has a null chain_name".into()));
+        }
+        let chain_name = unsafe { CStr::from_ptr(raw.chain_name) }.to_string_lossy().into_owned();
+
+        if raw.abi_version < min_version {
+            return Err(ShimmerError::NegotiationFailed(format!(
+                "library ABI version {} is below the minimum {}", raw.abi_version, min_version
+            )));
+        }
+
+        let missing_flags = required_flags & !raw.feature_flags;
+        if missing_flags != 0 {
+            return Err(ShimmerError::NegotiationFailed(format!(
+                "library is missing required feature flags: {:#x}", missing_flags
+            )));
+        }
+
+        let negotiated = NegotiatedAbi { chain_name, abi_version: raw.abi_version, feature_flags: raw.feature_flags };
+        self.negotiated = Some(negotiated.clone());
+        Ok(negotiated)
+    }
+
+    /// The result of the last successful [`Shimmer::negotiate`] call, or
+    /// an error if negotiation hasn't happened (or failed) for the
+    /// currently loaded library. `zig_fn`/`julia_fn`/`rust_fn` call this
+    /// before binding any function.
+    fn require_negotiated(&self) -> ShimmerResult<&NegotiatedAbi> {
+        self.negotiated.as_ref().ok_or_else(|| {
+            ShimmerError::NegotiationFailed("no successful Shimmer::negotiate() call for this library".into())
+        })
+    }
+
     /// Gets a function from the loaded library
     pub fn get_fn<T>(&self, name: &str) -> ShimmerResult<ShimmerFn<T>> {
         let name = CString::new(name).map_err(|e| ShimmerError::SymbolError(e.to_string()))?;
@@ -215,3 +316,112 @@ impl Default for Shimmer {
         Self::new()
     }
 }
+
+/// Bounded-retry backoff schedule for [`SyncShimmer::call_sync`]: symbol
+/// resolution and invocation are retried up to `max_retries` times,
+/// doubling the delay from `initial_backoff` up to `max_backoff` between
+/// attempts.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(10),
+            max_backoff: Duration::from_millis(200),
+        }
+    }
+}
+
+// `SyncShimmer` and `AsyncShimmer` are kept separate rather than folded
+// into one `Shimmer` calling-mode trait: a combined trait would force
+// every implementor to support both confirmed-blocking and
+// fire-and-forget semantics, when most callers only ever need one.
+
+/// Blocking, confirmed invocation: re-resolves and re-invokes a symbol
+/// up to a [`RetryPolicy`]'s bound before giving up, the way Solana's
+/// `SyncClient` blocks until a transaction is confirmed rather than
+/// just submitted. Suited to one-shot setup calls.
+pub trait SyncShimmer {
+    /// Resolves `name` and invokes it via `call`, retrying the whole
+    /// resolve-and-call per `retry` whenever `call` returns an error.
+    fn call_sync<T, R>(
+        &self,
+        name: &str,
+        retry: RetryPolicy,
+        call: impl Fn(&ShimmerFn<T>) -> ShimmerResult<R>,
+    ) -> ShimmerResult<R>;
+}
+
+impl SyncShimmer for Shimmer {
+    fn call_sync<T, R>(
+        &self,
+        name: &str,
+        retry: RetryPolicy,
+        call: impl Fn(&ShimmerFn<T>) -> ShimmerResult<R>,
+    ) -> ShimmerResult<R> {
+        self.require_negotiated()?;
+
+        let mut attempt = 0;
+        let mut backoff = retry.initial_backoff;
+        loop {
+            match self.get_fn::<T>(name).and_then(|sym| call(&sym)) {
+                Ok(value) => return Ok(value),
+                Err(_) if attempt < retry.max_retries => {
+                    attempt += 1;
+                    thread::sleep(backoff);
+                    backoff = (backoff * 2).min(retry.max_backoff);
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+}
+
+/// Fire-and-forget invocation dispatched onto a Prism [`TaskExecutor`]:
+/// returns the submitted task's handle immediately instead of blocking
+/// for the call to run, so hot-path native calls integrate with the
+/// crystal task scheduler's timeout/priority handling.
+pub trait AsyncShimmer {
+    /// Submits `name`'s invocation as a task on `executor`, returning its
+    /// handle without waiting for the task to run.
+    fn call_async<T>(
+        &self,
+        executor: &TaskExecutor,
+        name: &str,
+        config: TaskConfig,
+        call: impl Fn(ShimmerFn<T>) -> PrismResult<()> + Send + 'static,
+    ) -> PrismResult<TaskHandle>
+    where
+        T: Send + 'static;
+}
+
+impl AsyncShimmer for Shimmer {
+    fn call_async<T>(
+        &self,
+        executor: &TaskExecutor,
+        name: &str,
+        config: TaskConfig,
+        call: impl Fn(ShimmerFn<T>) -> PrismResult<()> + Send + 'static,
+    ) -> PrismResult<TaskHandle>
+    where
+        T: Send + 'static,
+    {
+        self.require_negotiated().map_err(|_| PrismError::InvalidState)?;
+
+        let shimmer = self.clone();
+        let name = name.to_string();
+        executor.submit(
+            async move {
+                let sym = shimmer.get_fn::<T>(&name).map_err(|_| PrismError::SystemError)?;
+                call(sym)
+            },
+            config,
+        )
+    }
+}
@@ -13,6 +13,7 @@ pub struct ZigFnAttrs {
 impl Shimmer {
     /// Loads a Zig function
     pub fn zig_fn<T>(&self, name: &str, _attrs: ZigFnAttrs) -> ShimmerResult<T> {
+        self.require_negotiated()?;
         let _sym: ShimmerFn<T> = self.get_fn(name)?;
         // Zig-specific type checking and conversion would go here
         Err(ShimmerError::RuntimeError("Zig interface not yet implemented".into()))
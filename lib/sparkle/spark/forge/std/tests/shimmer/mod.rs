@@ -1,4 +1,4 @@
-use spark_std::shimmer::{Shimmer, ShimmerError};
+use spark_std::shimmer::{NegotiatedAbi, RetryPolicy, Shimmer, ShimmerError, SyncShimmer};
 use spark_std::shimmer::zig::ZigFnAttrs;
 use spark_std::shimmer::julia::JuliaFnAttrs;
 use spark_std::shimmer::rust::RustFnAttrs;
@@ -41,3 +41,46 @@ fn test_language_attrs() {
     assert!(rust_attrs.is_extern);
     assert_eq!(rust_attrs.abi, "C");
 }
+
+#[test]
+fn test_negotiate_without_loaded_library_fails() {
+    let mut shimmer = Shimmer::new();
+    let result = shimmer.negotiate(1, 0);
+    assert!(result.is_err(), "Negotiating against no loaded library should fail");
+}
+
+#[test]
+fn test_zig_fn_requires_prior_negotiation() {
+    let shimmer = Shimmer::new();
+    let attrs = ZigFnAttrs { is_export: true, is_extern: true };
+    match shimmer.zig_fn::<fn()>("test", attrs) {
+        Err(ShimmerError::NegotiationFailed(_)) => {}
+        other => panic!("expected NegotiationFailed, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_negotiated_abi_supports_requires_every_flag_bit() {
+    let abi = NegotiatedAbi {
+        chain_name: "test-chain".to_string(),
+        abi_version: 3,
+        feature_flags: 0b0110,
+    };
+    assert!(abi.supports(0b0100));
+    assert!(abi.supports(0b0110));
+    assert!(!abi.supports(0b1000));
+}
+
+#[test]
+fn test_call_sync_without_negotiation_fails_immediately() {
+    let shimmer = Shimmer::new();
+    let result = shimmer.call_sync::<fn(), ()>("test_fn", RetryPolicy::default(), |_sym| Ok(()));
+    assert!(result.is_err(), "call_sync before negotiate() should fail");
+}
+
+#[test]
+fn test_retry_policy_default_has_bounded_backoff() {
+    let policy = RetryPolicy::default();
+    assert!(policy.max_retries > 0);
+    assert!(policy.initial_backoff <= policy.max_backoff);
+}
@@ -21,11 +21,21 @@ impl<T> CrystalArray<T> {
             }
             crate::shard::arch::Architecture::AArch64 => {
                 if shard.has_feature(crate::shard::arch::CpuFeature::SVE) {
-                    Alignment::Vector64
+                    match crate::shard::arch::sve_vector_bytes() {
+                        Some(bytes) => Alignment::Scalable(bytes),
+                        None => Alignment::Vector64,
+                    }
                 } else {
                     Alignment::Vector16
                 }
             }
+            crate::shard::arch::Architecture::Wasm32 => {
+                if shard.has_feature(crate::shard::arch::CpuFeature::Simd128) {
+                    Alignment::Vector16
+                } else {
+                    Alignment::Crystal16
+                }
+            }
             _ => Alignment::Crystal16,
         }
     }
@@ -51,11 +61,17 @@ impl<T> CrystalArray<T> {
             }
             crate::shard::arch::Architecture::AArch64 => {
                 if shard.has_feature(crate::shard::arch::CpuFeature::SVE) {
-                    64
+                    crate::shard::arch::sve_vector_bytes().unwrap_or(64)
                 } else {
                     16
                 }
             }
+            // `simd128`'s v128 registers and the scalar crystal fallback
+            // are both 16 bytes wide, so unlike `optimal_alignment` this
+            // doesn't need to branch on the feature -- it's here so
+            // wasm32 has its own named arm instead of falling through
+            // `_` alongside architectures with no SIMD story at all.
+            crate::shard::arch::Architecture::Wasm32 => 16,
             _ => 16,
         }
     }
@@ -4,7 +4,12 @@
 
 use std::{
     collections::{HashMap, VecDeque},
-    sync::Arc,
+    os::unix::io::{AsRawFd, RawFd},
+    ptr,
+    sync::{
+        atomic::{AtomicPtr, AtomicU64, AtomicUsize, Ordering},
+        Arc,
+    },
 };
 
 use crate::{
@@ -36,6 +41,151 @@ pub enum StateError {
     SerializationError(String),
 }
 
+/// Magic bytes identifying a binary [`GlobalState`] snapshot, checked
+/// before anything else so an unrelated blob is rejected instead of
+/// misparsed.
+const SNAPSHOT_MAGIC: [u8; 4] = *b"TGS\0";
+/// Schema name embedded in every snapshot header alongside the magic
+const SNAPSHOT_SCHEMA_NAME: &str = "tides.global_state";
+/// Current on-disk layout version of [`GlobalState`] itself (its field
+/// set). Bump this, and register a [`VersionMigrations`] upgrade from
+/// the previous value, whenever a field is added, removed, or retyped.
+const CURRENT_STATE_VERSION: u16 = 1;
+/// Current packed layout version of the dense `energy_field`/
+/// `phase_field`/`amplitude_field` grids
+const CURRENT_FIELD_LAYOUT_VERSION: u16 = 1;
+
+/// Registry of per-version up-conversion closures, keyed by the
+/// `state_version` they upgrade *from*. [`GlobalState::from_bytes`]
+/// applies them in sequence until the decoded state reaches
+/// [`CURRENT_STATE_VERSION`], so an older snapshot migrates instead of
+/// silently corrupting a newer `GlobalState` layout.
+#[derive(Default)]
+pub struct VersionMigrations {
+    upgrades: HashMap<u16, Box<dyn Fn(GlobalState) -> GlobalState + Send + Sync>>,
+}
+
+impl VersionMigrations {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a closure that upgrades a decoded state from
+    /// `from_version` to `from_version + 1`.
+    pub fn register(
+        &mut self,
+        from_version: u16,
+        upgrade: impl Fn(GlobalState) -> GlobalState + Send + Sync + 'static,
+    ) {
+        self.upgrades.insert(from_version, Box::new(upgrade));
+    }
+
+    fn get(&self, from_version: u16) -> Option<&(dyn Fn(GlobalState) -> GlobalState + Send + Sync)> {
+        self.upgrades.get(&from_version).map(|upgrade| upgrade.as_ref())
+    }
+}
+
+/// A forward-only cursor over a byte slice, used to decode a
+/// [`GlobalState`] snapshot without panicking on truncated input.
+struct ByteCursor<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> ByteCursor<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], StateError> {
+        let end = self.pos.checked_add(len)
+        .filter(|&end| end <= self.bytes.len())
+        .ok_or_else(|| StateError::SerializationError("snapshot truncated".to_string()))?;
+        let slice = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn read_u16(&mut self) -> Result<u16, StateError> {
+        let bytes = self.take(2)?;
+        Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn read_u32(&mut self) -> Result<u32, StateError> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn read_f64(&mut self) -> Result<f64, StateError> {
+        let bytes = self.take(8)?;
+        Ok(f64::from_le_bytes([
+            bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7],
+        ]))
+    }
+
+    fn read_string(&mut self) -> Result<String, StateError> {
+        let len = self.read_u16()? as usize;
+        let bytes = self.take(len)?;
+        String::from_utf8(bytes.to_vec()).map_err(|e| StateError::SerializationError(e.to_string()))
+    }
+
+    fn read_f64_grid(&mut self) -> Result<Vec<Vec<f64>>, StateError> {
+        let row_count = self.read_u32()? as usize;
+        let mut rows = Vec::with_capacity(row_count);
+        for _ in 0..row_count {
+            let col_count = self.read_u32()? as usize;
+            let mut row = Vec::with_capacity(col_count);
+            for _ in 0..col_count {
+                row.push(self.read_f64()?);
+            }
+            rows.push(row);
+        }
+        Ok(rows)
+    }
+
+    fn read_complex_grid(&mut self) -> Result<Vec<Vec<Complex64>>, StateError> {
+        let row_count = self.read_u32()? as usize;
+        let mut rows = Vec::with_capacity(row_count);
+        for _ in 0..row_count {
+            let col_count = self.read_u32()? as usize;
+            let mut row = Vec::with_capacity(col_count);
+            for _ in 0..col_count {
+                let re = self.read_f64()?;
+                let im = self.read_f64()?;
+                row.push(Complex64::new(re, im));
+            }
+            rows.push(row);
+        }
+        Ok(rows)
+    }
+}
+
+fn write_string(out: &mut Vec<u8>, value: &str) {
+    out.extend_from_slice(&(value.len() as u16).to_le_bytes());
+    out.extend_from_slice(value.as_bytes());
+}
+
+fn write_f64_grid(out: &mut Vec<u8>, grid: &[Vec<f64>]) {
+    out.extend_from_slice(&(grid.len() as u32).to_le_bytes());
+    for row in grid {
+        out.extend_from_slice(&(row.len() as u32).to_le_bytes());
+        for value in row {
+            out.extend_from_slice(&value.to_le_bytes());
+        }
+    }
+}
+
+fn write_complex_grid(out: &mut Vec<u8>, grid: &[Vec<Complex64>]) {
+    out.extend_from_slice(&(grid.len() as u32).to_le_bytes());
+    for row in grid {
+        out.extend_from_slice(&(row.len() as u32).to_le_bytes());
+        for value in row {
+            out.extend_from_slice(&value.re.to_le_bytes());
+            out.extend_from_slice(&value.im.to_le_bytes());
+        }
+    }
+}
+
 /// Crystal state configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StateConfig {
@@ -43,6 +193,10 @@ pub struct StateConfig {
     pub sync_interval: f64,
     pub stability_threshold: f64,
     pub transition_smoothing: f64,
+    /// Number of history steps between full keyframes; the steps in
+    /// between are stored as [`StateDelta`]s against the preceding
+    /// keyframe to keep history memory-light.
+    pub keyframe_interval: usize,
 }
 
 impl Default for StateConfig {
@@ -52,6 +206,7 @@ impl Default for StateConfig {
             sync_interval: 0.1,
             stability_threshold: 0.95,
             transition_smoothing: 0.5,
+            keyframe_interval: 16,
         }
     }
 }
@@ -60,10 +215,13 @@ impl Default for StateConfig {
 pub struct CrystalState {
     config: StateConfig,
     state: RwLock<GlobalState>,
-    history: RwLock<VecDeque<GlobalState>>,
+    history: RwLock<VecDeque<HistoryEntry>>,
     wave_pattern: Arc<WavePattern>,
     resonance: Arc<LatticeResonance>,
     last_sync: std::time::Instant,
+    migrations: RwLock<VersionMigrations>,
+    subscribers: Arc<RwLock<Vec<Subscriber>>>,
+    next_subscriber_id: AtomicU64,
 }
 
 /// Global crystal state
@@ -81,6 +239,386 @@ pub struct GlobalState {
     pub crystalline_state: Option<CrystallineState>,
 }
 
+/// A single changed grid cell, as recorded in a [`StateDelta`].
+#[derive(Debug, Clone)]
+pub struct CellChange<T> {
+    pub row: usize,
+    pub col: usize,
+    pub value: T,
+}
+
+/// The changed-cell set between two historical snapshots. Returned by
+/// [`CrystalState::diff`] for inspection, and used internally to store
+/// per-step history between keyframes.
+#[derive(Debug, Clone)]
+pub struct StateDelta {
+    pub from_timestamp: f64,
+    pub to_timestamp: f64,
+    pub amplitude_changes: Vec<CellChange<Complex64>>,
+    pub phase_changes: Vec<CellChange<f64>>,
+    pub stability: f64,
+    pub coherence: f64,
+    pub total_energy: f64,
+}
+
+/// One entry in [`CrystalState`]'s history: either a full snapshot or a
+/// delta against the preceding entry, replayed forward from the nearest
+/// keyframe on lookup.
+#[derive(Debug, Clone)]
+enum HistoryEntry {
+    Keyframe(GlobalState),
+    Delta(StateDelta),
+}
+
+impl HistoryEntry {
+    fn timestamp(&self) -> f64 {
+        match self {
+            HistoryEntry::Keyframe(state) => state.timestamp,
+            HistoryEntry::Delta(delta) => delta.to_timestamp,
+        }
+    }
+}
+
+/// Capacity of each subscriber's [`EventRing`]. Once full, the oldest
+/// pending event is dropped to make room for the newest -- a subscriber
+/// only needs to know the current state, not a perfect history of every
+/// mutation it missed.
+const SUBSCRIPTION_RING_CAPACITY: usize = 256;
+
+/// A lightweight notification pushed to every subscriber whenever
+/// `synchronize` or `update_fields` mutates [`CrystalState`]'s state.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StateEvent {
+    pub timestamp: f64,
+    pub stability: f64,
+    pub coherence: f64,
+    pub total_energy: f64,
+}
+
+/// Fixed-capacity single-producer/single-consumer ring buffer used to hand
+/// [`StateEvent`]s to a subscriber without locking: `CrystalState` is the
+/// only producer and the subscription's owner is the only consumer, so a
+/// pair of atomic cursors is enough -- no CAS loop needed.
+struct EventRing {
+    slots: Box<[AtomicPtr<StateEvent>]>,
+    head: AtomicUsize,
+    tail: AtomicUsize,
+}
+
+impl EventRing {
+    /// Allocates one extra slot beyond `capacity` so the ring can actually
+    /// hold `capacity` pending events -- the classic ring-buffer scheme
+    /// needs one slot kept empty to tell "full" apart from "empty" using
+    /// only the head/tail cursors.
+    fn new(capacity: usize) -> Self {
+        let slots = (0..capacity + 1)
+            .map(|_| AtomicPtr::new(ptr::null_mut()))
+            .collect();
+        Self {
+            slots,
+            head: AtomicUsize::new(0),
+            tail: AtomicUsize::new(0),
+        }
+    }
+
+    /// Pushes `event` onto the ring. If the consumer hasn't drained fast
+    /// enough and the ring is full, the oldest pending event is dropped to
+    /// make room.
+    fn push(&self, event: StateEvent) {
+        let capacity = self.slots.len();
+        let head = self.head.load(Ordering::Relaxed);
+        let next_head = (head + 1) % capacity;
+
+        if next_head == self.tail.load(Ordering::Acquire) {
+            let dropped_tail = self.tail.load(Ordering::Relaxed);
+            let _ = self.take_slot(dropped_tail);
+            self.tail.store((dropped_tail + 1) % capacity, Ordering::Release);
+        }
+
+        let boxed = Box::into_raw(Box::new(event));
+        self.slots[head].store(boxed, Ordering::Release);
+        self.head.store(next_head, Ordering::Release);
+    }
+
+    /// Drains every event currently pending in the ring.
+    fn drain(&self) -> Vec<StateEvent> {
+        let capacity = self.slots.len();
+        let mut events = Vec::new();
+
+        loop {
+            let tail = self.tail.load(Ordering::Relaxed);
+            let head = self.head.load(Ordering::Acquire);
+            if tail == head {
+                break;
+            }
+            if let Some(event) = self.take_slot(tail) {
+                events.push(event);
+            }
+            self.tail.store((tail + 1) % capacity, Ordering::Release);
+        }
+
+        events
+    }
+
+    fn take_slot(&self, index: usize) -> Option<StateEvent> {
+        let slot = self.slots[index].swap(ptr::null_mut(), Ordering::AcqRel);
+        if slot.is_null() {
+            None
+        } else {
+            Some(*unsafe { Box::from_raw(slot) })
+        }
+    }
+}
+
+impl Drop for EventRing {
+    fn drop(&mut self) {
+        for slot in self.slots.iter() {
+            let ptr = slot.swap(ptr::null_mut(), Ordering::Acquire);
+            if !ptr.is_null() {
+                unsafe {
+                    drop(Box::from_raw(ptr));
+                }
+            }
+        }
+    }
+}
+
+/// OS-level readiness primitive signaled whenever new [`StateEvent`]s are
+/// pushed to a subscription: an eventfd on Linux, a self-pipe on other
+/// Unix targets, so an external `select`/`epoll` reactor can wait on it
+/// directly via [`AsRawFd`].
+struct ReadinessFd {
+    #[cfg(target_os = "linux")]
+    fd: RawFd,
+    #[cfg(not(target_os = "linux"))]
+    read_fd: RawFd,
+    #[cfg(not(target_os = "linux"))]
+    write_fd: RawFd,
+}
+
+impl ReadinessFd {
+    #[cfg(target_os = "linux")]
+    fn new() -> Result<Self, StateError> {
+        let fd = unsafe { libc::eventfd(0, libc::EFD_NONBLOCK | libc::EFD_CLOEXEC) };
+        if fd < 0 {
+            return Err(StateError::SyncError(
+                "failed to create eventfd for state subscription".to_string(),
+            ));
+        }
+        Ok(Self { fd })
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn new() -> Result<Self, StateError> {
+        let mut fds: [RawFd; 2] = [0, 0];
+        let result = unsafe { libc::pipe(fds.as_mut_ptr()) };
+        if result != 0 {
+            return Err(StateError::SyncError(
+                "failed to create self-pipe for state subscription".to_string(),
+            ));
+        }
+        Ok(Self { read_fd: fds[0], write_fd: fds[1] })
+    }
+
+    /// Signals the readiness primitive so a waiting `select`/`epoll` loop
+    /// wakes up.
+    #[cfg(target_os = "linux")]
+    fn signal(&self) {
+        let one: u64 = 1;
+        unsafe {
+            libc::write(self.fd, &one as *const u64 as *const libc::c_void, std::mem::size_of::<u64>());
+        }
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn signal(&self) {
+        let byte: u8 = 1;
+        unsafe {
+            libc::write(self.write_fd, &byte as *const u8 as *const libc::c_void, 1);
+        }
+    }
+}
+
+impl AsRawFd for ReadinessFd {
+    fn as_raw_fd(&self) -> RawFd {
+        #[cfg(target_os = "linux")]
+        {
+            self.fd
+        }
+        #[cfg(not(target_os = "linux"))]
+        {
+            self.read_fd
+        }
+    }
+}
+
+impl Drop for ReadinessFd {
+    fn drop(&mut self) {
+        #[cfg(target_os = "linux")]
+        unsafe {
+            libc::close(self.fd);
+        }
+        #[cfg(not(target_os = "linux"))]
+        unsafe {
+            libc::close(self.read_fd);
+            libc::close(self.write_fd);
+        }
+    }
+}
+
+/// One registered listener, as tracked internally by [`CrystalState`].
+struct Subscriber {
+    id: u64,
+    ring: Arc<EventRing>,
+    readiness: Arc<ReadinessFd>,
+}
+
+/// A handle returned by [`CrystalState::subscribe`]. Exposes the readiness
+/// fd via [`AsRawFd`] so it can be registered with an external
+/// `select`/`epoll` reactor, and [`StateSubscription::drain`] to consume
+/// the [`StateEvent`]s that arrived since the fd last fired. Unregisters
+/// itself from `CrystalState` on drop.
+pub struct StateSubscription {
+    id: u64,
+    ring: Arc<EventRing>,
+    readiness: Arc<ReadinessFd>,
+    registry: Arc<RwLock<Vec<Subscriber>>>,
+}
+
+impl StateSubscription {
+    /// Consumes every [`StateEvent`] pending since the last `drain` call.
+    pub fn drain(&self) -> Vec<StateEvent> {
+        self.ring.drain()
+    }
+}
+
+impl AsRawFd for StateSubscription {
+    fn as_raw_fd(&self) -> RawFd {
+        self.readiness.as_raw_fd()
+    }
+}
+
+impl Drop for StateSubscription {
+    fn drop(&mut self) {
+        self.registry.write().retain(|subscriber| subscriber.id != self.id);
+    }
+}
+
+impl GlobalState {
+    /// Encodes this state as a compact, versioned binary snapshot: a
+    /// header (magic, schema name, state/field-layout versions) followed
+    /// by the dense fields packed as length-prefixed little-endian f64
+    /// payloads instead of per-element JSON, and the remaining fields as
+    /// an embedded JSON blob.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, StateError> {
+        let mut out = Vec::new();
+
+        out.extend_from_slice(&SNAPSHOT_MAGIC);
+        write_string(&mut out, SNAPSHOT_SCHEMA_NAME);
+        out.extend_from_slice(&CURRENT_STATE_VERSION.to_le_bytes());
+        out.extend_from_slice(&CURRENT_FIELD_LAYOUT_VERSION.to_le_bytes());
+
+        out.extend_from_slice(&self.timestamp.to_le_bytes());
+        write_f64_grid(&mut out, &self.energy_field);
+        write_f64_grid(&mut out, &self.phase_field);
+        write_complex_grid(&mut out, &self.amplitude_field);
+        out.extend_from_slice(&self.stability.to_le_bytes());
+        out.extend_from_slice(&self.coherence.to_le_bytes());
+        out.extend_from_slice(&self.total_energy.to_le_bytes());
+
+        let rest = serde_json::to_vec(&(
+            &self.node_states,
+            &self.resonator_state,
+            &self.crystalline_state,
+        ))
+        .map_err(|e| StateError::SerializationError(e.to_string()))?;
+        out.extend_from_slice(&(rest.len() as u32).to_le_bytes());
+        out.extend_from_slice(&rest);
+
+        Ok(out)
+    }
+
+    /// Decodes a snapshot produced by [`GlobalState::to_bytes`]. Reads the
+    /// header first: a `state_version` newer than this build supports is
+    /// rejected with a [`StateError::SerializationError`] rather than
+    /// misparsed, while an older one is upgraded step by step through
+    /// `migrations`.
+    pub fn from_bytes(bytes: &[u8], migrations: &VersionMigrations) -> Result<Self, StateError> {
+        let mut cursor = ByteCursor::new(bytes);
+
+        let magic = cursor.take(4)?;
+        if magic != SNAPSHOT_MAGIC {
+            return Err(StateError::SerializationError(
+                "snapshot magic does not match a tides global state snapshot".to_string(),
+            ));
+        }
+
+        let schema_name = cursor.read_string()?;
+        if schema_name != SNAPSHOT_SCHEMA_NAME {
+            return Err(StateError::SerializationError(format!(
+                "snapshot schema '{}' does not match expected '{}'",
+                schema_name, SNAPSHOT_SCHEMA_NAME
+            )));
+        }
+
+        let state_version = cursor.read_u16()?;
+        let field_layout_version = cursor.read_u16()?;
+
+        if state_version > CURRENT_STATE_VERSION {
+            return Err(StateError::SerializationError(format!(
+                "snapshot state_version {} is newer than this build's {}",
+                state_version, CURRENT_STATE_VERSION
+            )));
+        }
+        if field_layout_version > CURRENT_FIELD_LAYOUT_VERSION {
+            return Err(StateError::SerializationError(format!(
+                "snapshot field_layout_version {} is newer than this build's {}",
+                field_layout_version, CURRENT_FIELD_LAYOUT_VERSION
+            )));
+        }
+
+        let timestamp = cursor.read_f64()?;
+        let energy_field = cursor.read_f64_grid()?;
+        let phase_field = cursor.read_f64_grid()?;
+        let amplitude_field = cursor.read_complex_grid()?;
+        let stability = cursor.read_f64()?;
+        let coherence = cursor.read_f64()?;
+        let total_energy = cursor.read_f64()?;
+
+        let rest_len = cursor.read_u32()? as usize;
+        let rest_bytes = cursor.take(rest_len)?;
+        let (node_states, resonator_state, crystalline_state) = serde_json::from_slice(rest_bytes)
+        .map_err(|e| StateError::SerializationError(e.to_string()))?;
+
+        let mut state = GlobalState {
+            timestamp,
+            energy_field,
+            phase_field,
+            amplitude_field,
+            stability,
+            coherence,
+            total_energy,
+            node_states,
+            resonator_state,
+            crystalline_state,
+        };
+
+        let mut version = state_version;
+        while version < CURRENT_STATE_VERSION {
+            let upgrade = migrations.get(version).ok_or_else(|| {
+                StateError::SerializationError(format!(
+                    "no migration registered to upgrade a snapshot from state_version {}",
+                    version
+                ))
+            })?;
+            state = upgrade(state);
+            version += 1;
+        }
+
+        Ok(state)
+    }
+}
+
 impl CrystalState {
     /// Create new crystal state manager
     pub fn new(
@@ -108,9 +646,46 @@ impl CrystalState {
            wave_pattern,
            resonance,
            last_sync: std::time::Instant::now(),
+           migrations: RwLock::new(VersionMigrations::new()),
+           subscribers: Arc::new(RwLock::new(Vec::new())),
+           next_subscriber_id: AtomicU64::new(0),
+        })
+    }
+
+    /// Registers a new subscription for state-change notifications. The
+    /// returned handle exposes an OS-level readiness primitive via
+    /// `AsRawFd` -- an eventfd on Linux, a self-pipe elsewhere -- so an
+    /// external `select`/`epoll` reactor can wait on it directly instead
+    /// of busy-polling `should_sync`/`get_state`, plus a ring to drain the
+    /// [`StateEvent`]s that arrived since it last fired.
+    pub fn subscribe(&self) -> Result<StateSubscription, StateError> {
+        let id = self.next_subscriber_id.fetch_add(1, Ordering::Relaxed);
+        let ring = Arc::new(EventRing::new(SUBSCRIPTION_RING_CAPACITY));
+        let readiness = Arc::new(ReadinessFd::new()?);
+
+        self.subscribers.write().push(Subscriber {
+            id,
+            ring: Arc::clone(&ring),
+            readiness: Arc::clone(&readiness),
+        });
+
+        Ok(StateSubscription {
+            id,
+            ring,
+            readiness,
+            registry: Arc::clone(&self.subscribers),
         })
     }
 
+    /// Pushes `event` onto every live subscriber's ring and signals its
+    /// readiness fd so a waiting reactor wakes up.
+    fn notify_subscribers(&self, event: StateEvent) {
+        for subscriber in self.subscribers.read().iter() {
+            subscriber.ring.push(event);
+            subscriber.readiness.signal();
+        }
+    }
+
     /// Update global state
     pub fn update(&self, time: f64) -> Result<(), StateError> {
         // Check if synchronization is needed
@@ -147,6 +722,15 @@ impl CrystalState {
         .map_err(|e| StateError::SyncError(e.to_string()))?
         .as_secs_f64();
 
+        let event = StateEvent {
+            timestamp: state.timestamp,
+            stability: state.stability,
+            coherence: state.coherence,
+            total_energy: state.total_energy,
+        };
+        drop(state);
+        self.notify_subscribers(event);
+
         Ok(())
     }
 
@@ -164,6 +748,15 @@ impl CrystalState {
         .map(|row| row.iter().sum::<f64>())
         .sum();
 
+        let event = StateEvent {
+            timestamp: state.timestamp,
+            stability: state.stability,
+            coherence: state.coherence,
+            total_energy: state.total_energy,
+        };
+        drop(state);
+        self.notify_subscribers(event);
+
         Ok(())
     }
 
@@ -172,14 +765,199 @@ impl CrystalState {
         let mut history = self.history.write();
         let current_state = self.state.read().clone();
 
-        if history.len() >= self.config.memory_depth {
-            history.pop_front();
+        self.evict_oldest_if_full(&mut history);
+
+        let needs_keyframe = history.is_empty()
+        || Self::steps_since_last_keyframe(&history) + 1 >= self.config.keyframe_interval;
+
+        let entry = if needs_keyframe {
+            HistoryEntry::Keyframe(current_state)
+        } else {
+            let previous = Self::reconstruct_at_index(&history, history.len() - 1);
+            HistoryEntry::Delta(self.compute_delta(&previous, &current_state))
+        };
+
+        history.push_back(entry);
+
+        Ok(())
+    }
+
+    /// Drops the oldest history entry once `memory_depth` is reached. If
+    /// the dropped entry was a keyframe, the next entry (a delta against
+    /// it) is reconstructed and promoted to a keyframe so the deque's
+    /// front always has everything needed to replay forward from it.
+    fn evict_oldest_if_full(&self, history: &mut VecDeque<HistoryEntry>) {
+        if history.len() < self.config.memory_depth {
+            return;
+        }
+        if let Some(HistoryEntry::Keyframe(old_keyframe)) = history.pop_front() {
+            if let Some(HistoryEntry::Delta(delta)) = history.front() {
+                let reconstructed = Self::apply_delta(&old_keyframe, delta);
+                history[0] = HistoryEntry::Keyframe(reconstructed);
+            }
+        }
+    }
+
+    /// Number of delta entries since (and not including) the most recent
+    /// keyframe at the back of `history`.
+    fn steps_since_last_keyframe(history: &VecDeque<HistoryEntry>) -> usize {
+        history.iter()
+        .rev()
+        .take_while(|entry| !matches!(entry, HistoryEntry::Keyframe(_)))
+        .count()
+    }
+
+    /// Computes the changed-cell delta between two snapshots, recording
+    /// only cells whose magnitude moved by more than `transition_smoothing`.
+    fn compute_delta(&self, previous: &GlobalState, current: &GlobalState) -> StateDelta {
+        let threshold = self.config.transition_smoothing;
+
+        let mut amplitude_changes = Vec::new();
+        for (row_idx, row) in current.amplitude_field.iter().enumerate() {
+            for (col_idx, value) in row.iter().enumerate() {
+                let previous_value = previous.amplitude_field.get(row_idx).and_then(|r| r.get(col_idx));
+                let changed = match previous_value {
+                    Some(previous_value) => (value - previous_value).norm() > threshold,
+                    None => true,
+                };
+                if changed {
+                    amplitude_changes.push(CellChange { row: row_idx, col: col_idx, value: *value });
+                }
+            }
+        }
+
+        let mut phase_changes = Vec::new();
+        for (row_idx, row) in current.phase_field.iter().enumerate() {
+            for (col_idx, value) in row.iter().enumerate() {
+                let previous_value = previous.phase_field.get(row_idx).and_then(|r| r.get(col_idx));
+                let changed = match previous_value {
+                    Some(previous_value) => (value - previous_value).abs() > threshold,
+                    None => true,
+                };
+                if changed {
+                    phase_changes.push(CellChange { row: row_idx, col: col_idx, value: *value });
+                }
+            }
+        }
+
+        StateDelta {
+            from_timestamp: previous.timestamp,
+            to_timestamp: current.timestamp,
+            amplitude_changes,
+            phase_changes,
+            stability: current.stability,
+            coherence: current.coherence,
+            total_energy: current.total_energy,
+        }
+    }
+
+    /// Applies a delta on top of a base snapshot, producing the state it
+    /// was recorded against.
+    fn apply_delta(base: &GlobalState, delta: &StateDelta) -> GlobalState {
+        let mut next = base.clone();
+        next.timestamp = delta.to_timestamp;
+        for change in &delta.amplitude_changes {
+            next.amplitude_field[change.row][change.col] = change.value;
+        }
+        for change in &delta.phase_changes {
+            next.phase_field[change.row][change.col] = change.value;
+        }
+        next.stability = delta.stability;
+        next.coherence = delta.coherence;
+        next.total_energy = delta.total_energy;
+        next
+    }
+
+    /// Reconstructs the full snapshot at `index` by replaying deltas
+    /// forward from the nearest preceding keyframe.
+    fn reconstruct_at_index(history: &VecDeque<HistoryEntry>, index: usize) -> GlobalState {
+        let mut keyframe_index = index;
+        while !matches!(history[keyframe_index], HistoryEntry::Keyframe(_)) {
+            keyframe_index -= 1;
         }
-        history.push_back(current_state);
+
+        let mut reconstructed = match &history[keyframe_index] {
+            HistoryEntry::Keyframe(state) => state.clone(),
+            HistoryEntry::Delta(_) => unreachable!("keyframe_index always points at a keyframe"),
+        };
+        for entry in history.iter().skip(keyframe_index + 1).take(index - keyframe_index) {
+            if let HistoryEntry::Delta(delta) = entry {
+                reconstructed = Self::apply_delta(&reconstructed, delta);
+            }
+        }
+        reconstructed
+    }
+
+    /// Binary searches `history`'s monotonic timestamps for the entry
+    /// nearest to `timestamp`.
+    fn nearest_entry_index(history: &VecDeque<HistoryEntry>, timestamp: f64) -> Option<usize> {
+        if history.is_empty() {
+            return None;
+        }
+        let index = match history.binary_search_by(|entry| {
+            entry.timestamp().partial_cmp(&timestamp).unwrap_or(std::cmp::Ordering::Equal)
+        }) {
+            Ok(index) => return Some(index),
+            Err(index) => index,
+        };
+
+        if index == 0 {
+            Some(0)
+        } else if index >= history.len() {
+            Some(history.len() - 1)
+        } else {
+            let before = history[index - 1].timestamp();
+            let after = history[index].timestamp();
+            if (timestamp - before).abs() <= (after - timestamp).abs() {
+                Some(index - 1)
+            } else {
+                Some(index)
+            }
+        }
+    }
+
+    /// Restores `self.state` to the historical snapshot nearest to
+    /// `timestamp`, re-deriving `energy_field`/`total_energy` so the
+    /// restored state is internally consistent.
+    pub fn restore_to(&self, timestamp: f64) -> Result<(), StateError> {
+        let reconstructed = {
+            let history = self.history.read();
+            let index = Self::nearest_entry_index(&history, timestamp).ok_or_else(|| {
+                StateError::TransitionError("no history to restore from".to_string())
+            })?;
+            Self::reconstruct_at_index(&history, index)
+        };
+
+        *self.state.write() = reconstructed;
+        self.update_fields(0.0)?;
 
         Ok(())
     }
 
+    /// Returns the changed-cell set between the historical snapshots
+    /// nearest to timestamps `a` and `b`.
+    pub fn diff(&self, a: f64, b: f64) -> StateDelta {
+        let history = self.history.read();
+
+        let state_a = Self::nearest_entry_index(&history, a)
+        .map(|index| Self::reconstruct_at_index(&history, index));
+        let state_b = Self::nearest_entry_index(&history, b)
+        .map(|index| Self::reconstruct_at_index(&history, index));
+
+        match (state_a, state_b) {
+            (Some(state_a), Some(state_b)) => self.compute_delta(&state_a, &state_b),
+            _ => StateDelta {
+                from_timestamp: a,
+                to_timestamp: b,
+                amplitude_changes: Vec::new(),
+                phase_changes: Vec::new(),
+                stability: 0.0,
+                coherence: 0.0,
+                total_energy: 0.0,
+            },
+        }
+    }
+
     /// Check if synchronization is needed
     fn should_sync(&self) -> bool {
         self.last_sync.elapsed().as_secs_f64() >= self.config.sync_interval
@@ -192,7 +970,10 @@ impl CrystalState {
 
     /// Get state history
     pub fn get_history(&self) -> Vec<GlobalState> {
-        self.history.read().iter().cloned().collect()
+        let history = self.history.read();
+        (0..history.len())
+        .map(|index| Self::reconstruct_at_index(&history, index))
+        .collect()
     }
 
     /// Check if state is stable
@@ -213,6 +994,38 @@ impl CrystalState {
         *self.state.write() = new_state;
         Ok(())
     }
+
+    /// Registers an up-conversion closure used by [`CrystalState::from_bytes`]
+    /// to migrate a snapshot's `state_version` up to the version this
+    /// build understands.
+    pub fn register_migration(
+        &self,
+        from_version: u16,
+        upgrade: impl Fn(GlobalState) -> GlobalState + Send + Sync + 'static,
+    ) {
+        self.migrations.write().register(from_version, upgrade);
+    }
+
+    /// Whether `version` is a `state_version` this build can decode,
+    /// analogous to a network handshake's capability check before two
+    /// peers agree to stream state snapshots to each other.
+    pub fn supports_version(&self, version: u16) -> bool {
+        version <= CURRENT_STATE_VERSION
+    }
+
+    /// Serialize state to a compact, versioned binary snapshot
+    pub fn to_bytes(&self) -> Result<Vec<u8>, StateError> {
+        self.state.read().to_bytes()
+    }
+
+    /// Deserialize state from a binary snapshot produced by
+    /// [`CrystalState::to_bytes`], migrating an older snapshot through any
+    /// closures registered via [`CrystalState::register_migration`].
+    pub fn from_bytes(&self, bytes: &[u8]) -> Result<(), StateError> {
+        let new_state = GlobalState::from_bytes(bytes, &self.migrations.read())?;
+        *self.state.write() = new_state;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -350,4 +1163,280 @@ mod tests {
         assert!(!state_manager.should_sync());
         Ok(())
     }
+
+    #[test]
+    fn test_binary_snapshot_round_trips_dense_fields() -> Result<(), StateError> {
+        let config = StateConfig::default();
+        let wave_pattern = Arc::new(WavePattern::new(Default::default())?);
+        let resonance = Arc::new(LatticeResonance::new(Default::default()));
+
+        let state_manager = CrystalState::new(config, wave_pattern, resonance)?;
+        {
+            let mut state = state_manager.state.write();
+            state.amplitude_field = vec![vec![Complex64::new(1.0, 2.0); 4]; 4];
+        }
+        state_manager.update(0.0)?;
+
+        let bytes = state_manager.to_bytes()?;
+        let before = state_manager.get_state();
+
+        state_manager.from_bytes(&bytes)?;
+        let after = state_manager.get_state();
+
+        assert_relative_eq!(before.total_energy, after.total_energy);
+        assert_eq!(before.amplitude_field, after.amplitude_field);
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_bytes_rejects_wrong_magic() {
+        let migrations = VersionMigrations::new();
+        let result = GlobalState::from_bytes(b"not a snapshot", &migrations);
+        assert!(matches!(result, Err(StateError::SerializationError(_))));
+    }
+
+    #[test]
+    fn test_from_bytes_rejects_newer_state_version() -> Result<(), StateError> {
+        let config = StateConfig::default();
+        let wave_pattern = Arc::new(WavePattern::new(Default::default())?);
+        let resonance = Arc::new(LatticeResonance::new(Default::default()));
+
+        let state_manager = CrystalState::new(config, wave_pattern, resonance)?;
+        let mut bytes = state_manager.to_bytes()?;
+
+        // Bump the state_version field (immediately after magic + schema name) past
+        // what this build supports.
+        let version_offset = 4 + 2 + SNAPSHOT_SCHEMA_NAME.len();
+        let too_new = (CURRENT_STATE_VERSION + 1).to_le_bytes();
+        bytes[version_offset] = too_new[0];
+        bytes[version_offset + 1] = too_new[1];
+
+        let migrations = VersionMigrations::new();
+        let result = GlobalState::from_bytes(&bytes, &migrations);
+        assert!(matches!(result, Err(StateError::SerializationError(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_supports_version_matches_current_build() -> Result<(), StateError> {
+        let config = StateConfig::default();
+        let wave_pattern = Arc::new(WavePattern::new(Default::default())?);
+        let resonance = Arc::new(LatticeResonance::new(Default::default()));
+
+        let state_manager = CrystalState::new(config, wave_pattern, resonance)?;
+        assert!(state_manager.supports_version(CURRENT_STATE_VERSION));
+        assert!(!state_manager.supports_version(CURRENT_STATE_VERSION + 1));
+        Ok(())
+    }
+
+    #[test]
+    fn test_registered_migration_upgrades_older_snapshot() {
+        let mut migrations = VersionMigrations::new();
+        migrations.register(0, |mut state| {
+            state.coherence = 0.5;
+            state
+        });
+
+        let mut bytes = GlobalState {
+            timestamp: 0.0,
+            energy_field: Vec::new(),
+            phase_field: Vec::new(),
+            amplitude_field: Vec::new(),
+            stability: 1.0,
+            coherence: 1.0,
+            total_energy: 0.0,
+            node_states: HashMap::new(),
+            resonator_state: None,
+            crystalline_state: None,
+        }
+        .to_bytes()
+        .unwrap();
+
+        let version_offset = 4 + 2 + SNAPSHOT_SCHEMA_NAME.len();
+        bytes[version_offset] = 0;
+        bytes[version_offset + 1] = 0;
+
+        let upgraded = GlobalState::from_bytes(&bytes, &migrations).unwrap();
+        assert_relative_eq!(upgraded.coherence, 0.5);
+    }
+
+    #[test]
+    fn test_from_bytes_without_migration_for_older_version_errors() {
+        let mut bytes = GlobalState {
+            timestamp: 0.0,
+            energy_field: Vec::new(),
+            phase_field: Vec::new(),
+            amplitude_field: Vec::new(),
+            stability: 1.0,
+            coherence: 1.0,
+            total_energy: 0.0,
+            node_states: HashMap::new(),
+            resonator_state: None,
+            crystalline_state: None,
+        }
+        .to_bytes()
+        .unwrap();
+
+        let version_offset = 4 + 2 + SNAPSHOT_SCHEMA_NAME.len();
+        bytes[version_offset] = 0;
+        bytes[version_offset + 1] = 0;
+
+        let migrations = VersionMigrations::new();
+        let result = GlobalState::from_bytes(&bytes, &migrations);
+        assert!(matches!(result, Err(StateError::SerializationError(_))));
+    }
+
+    #[test]
+    fn test_restore_to_reconstructs_nearest_historical_state() -> Result<(), StateError> {
+        let config = StateConfig::default();
+        let wave_pattern = Arc::new(WavePattern::new(Default::default())?);
+        let resonance = Arc::new(LatticeResonance::new(Default::default()));
+
+        let state_manager = CrystalState::new(config, wave_pattern, resonance)?;
+
+        {
+            let mut state = state_manager.state.write();
+            state.timestamp = 1.0;
+            state.amplitude_field = vec![vec![Complex64::new(1.0, 0.0); 2]; 2];
+        }
+        state_manager.update_fields(0.0)?;
+        state_manager.update_history()?;
+        let early_timestamp = state_manager.get_state().timestamp;
+
+        {
+            let mut state = state_manager.state.write();
+            state.timestamp = 2.0;
+            state.amplitude_field = vec![vec![Complex64::new(5.0, 0.0); 2]; 2];
+        }
+        state_manager.update_fields(0.0)?;
+        state_manager.update_history()?;
+
+        state_manager.restore_to(early_timestamp)?;
+        let restored = state_manager.get_state();
+
+        assert_relative_eq!(restored.amplitude_field[0][0].re, 1.0);
+        assert_relative_eq!(restored.total_energy, restored.energy_field.iter().flatten().sum::<f64>());
+        Ok(())
+    }
+
+    #[test]
+    fn test_restore_to_with_no_history_errors() -> Result<(), StateError> {
+        let config = StateConfig::default();
+        let wave_pattern = Arc::new(WavePattern::new(Default::default())?);
+        let resonance = Arc::new(LatticeResonance::new(Default::default()));
+
+        let state_manager = CrystalState::new(config, wave_pattern, resonance)?;
+        let result = state_manager.restore_to(0.0);
+        assert!(matches!(result, Err(StateError::TransitionError(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_history_survives_eviction_past_a_keyframe() -> Result<(), StateError> {
+        let mut config = StateConfig::default();
+        config.memory_depth = 4;
+        config.keyframe_interval = 2;
+        let wave_pattern = Arc::new(WavePattern::new(Default::default())?);
+        let resonance = Arc::new(LatticeResonance::new(Default::default()));
+
+        let state_manager = CrystalState::new(config, wave_pattern, resonance)?;
+
+        for i in 0..10 {
+            let mut state = state_manager.state.write();
+            state.amplitude_field = vec![vec![Complex64::new(i as f64, 0.0); 2]; 2];
+            drop(state);
+            state_manager.update(i as f64)?;
+        }
+
+        // Every reconstructed entry should still be fully valid after several evictions.
+        let history = state_manager.get_history();
+        assert!(!history.is_empty());
+        for state in &history {
+            assert_eq!(state.amplitude_field.len(), 2);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_diff_reports_changed_cells_between_two_timestamps() -> Result<(), StateError> {
+        let config = StateConfig::default();
+        let wave_pattern = Arc::new(WavePattern::new(Default::default())?);
+        let resonance = Arc::new(LatticeResonance::new(Default::default()));
+
+        let state_manager = CrystalState::new(config, wave_pattern, resonance)?;
+
+        {
+            let mut state = state_manager.state.write();
+            state.timestamp = 1.0;
+            state.amplitude_field = vec![vec![Complex64::new(1.0, 0.0); 2]; 2];
+        }
+        state_manager.update_fields(0.0)?;
+        state_manager.update_history()?;
+        let first_timestamp = state_manager.get_state().timestamp;
+
+        {
+            let mut state = state_manager.state.write();
+            state.timestamp = 2.0;
+            state.amplitude_field = vec![vec![Complex64::new(1.0, 0.0), Complex64::new(9.0, 0.0)]; 2];
+        }
+        state_manager.update_fields(0.0)?;
+        state_manager.update_history()?;
+        let second_timestamp = state_manager.get_state().timestamp;
+
+        let delta = state_manager.diff(first_timestamp, second_timestamp);
+        assert!(!delta.amplitude_changes.is_empty());
+        assert!(delta.amplitude_changes.iter().any(|change| change.col == 1));
+        Ok(())
+    }
+
+    #[test]
+    fn test_subscribe_receives_an_event_on_update() -> Result<(), StateError> {
+        let config = StateConfig::default();
+        let wave_pattern = Arc::new(WavePattern::new(Default::default())?);
+        let resonance = Arc::new(LatticeResonance::new(Default::default()));
+
+        let state_manager = CrystalState::new(config, wave_pattern, resonance)?;
+        let subscription = state_manager.subscribe()?;
+
+        assert!(subscription.drain().is_empty());
+
+        state_manager.update(0.0)?;
+
+        let events = subscription.drain();
+        assert!(!events.is_empty());
+        assert!(subscription.drain().is_empty(), "drain should not return already-consumed events twice");
+        Ok(())
+    }
+
+    #[test]
+    fn test_dropped_subscription_unregisters_from_the_state_manager() -> Result<(), StateError> {
+        let config = StateConfig::default();
+        let wave_pattern = Arc::new(WavePattern::new(Default::default())?);
+        let resonance = Arc::new(LatticeResonance::new(Default::default()));
+
+        let state_manager = CrystalState::new(config, wave_pattern, resonance)?;
+        {
+            let _subscription = state_manager.subscribe()?;
+            assert_eq!(state_manager.subscribers.read().len(), 1);
+        }
+        assert_eq!(state_manager.subscribers.read().len(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_event_ring_drops_oldest_event_once_full() {
+        let ring = EventRing::new(2);
+        for i in 0..5 {
+            ring.push(StateEvent {
+                timestamp: i as f64,
+                stability: 1.0,
+                coherence: 1.0,
+                total_energy: 0.0,
+            });
+        }
+        let events = ring.drain();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].timestamp, 3.0);
+        assert_eq!(events[1].timestamp, 4.0);
+    }
 }
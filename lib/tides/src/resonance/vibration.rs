@@ -34,6 +34,24 @@ pub enum VibrationError {
     SpectrumError(String),
 }
 
+/// Selects how `CrystalVibration::update` turns mode data into a
+/// frequency/phase/energy spectrum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpectrumBackend {
+    /// Shell out to `JuliaSpectrumAnalysis`, as before.
+    Julia,
+    /// Pure-Rust radix-2 Cooley-Tukey FFT over `amplitude_field`; no Julia
+    /// runtime required, and the only backend `test_spectrum_analysis`
+    /// can exercise without one.
+    NativeFft,
+}
+
+impl Default for SpectrumBackend {
+    fn default() -> Self {
+        Self::Julia
+    }
+}
+
 /// Configuration for crystal vibrations
 #[derive(Debug, Clone)]
 pub struct VibrationConfig {
@@ -44,6 +62,7 @@ pub struct VibrationConfig {
     pub resolution: usize,
     pub memory_length: usize,
     pub julia_threads: usize,
+    pub spectrum_backend: SpectrumBackend,
 }
 
 impl Default for VibrationConfig {
@@ -56,6 +75,7 @@ impl Default for VibrationConfig {
             resolution: 1024,
             memory_length: 256,
             julia_threads: 4,
+            spectrum_backend: SpectrumBackend::default(),
         }
     }
 }
@@ -94,6 +114,89 @@ pub struct VibrationMode {
     pub stability: f64,
 }
 
+/// Reorders `input` by bit-reversed index, the standard first step of an
+/// in-place radix-2 FFT. `input.len()` must be a power of two.
+fn bit_reverse_copy(input: &[Complex64]) -> Vec<Complex64> {
+    let n = input.len();
+    let bits = n.trailing_zeros();
+    let mut out = vec![Complex64::new(0.0, 0.0); n];
+    for (i, value) in input.iter().enumerate() {
+        let reversed = (i as u32).reverse_bits() >> (32 - bits);
+        out[reversed as usize] = *value;
+    }
+    out
+}
+
+/// In-place radix-2 Cooley-Tukey FFT. `input.len()` must be a power of
+/// two (callers pad/truncate to the nearest one first).
+fn fft_radix2(input: &[Complex64]) -> Vec<Complex64> {
+    let n = input.len();
+    if n <= 1 {
+        return input.to_vec();
+    }
+
+    let mut data = bit_reverse_copy(input);
+
+    let mut size = 2;
+    while size <= n {
+        let half = size / 2;
+        let angle = -2.0 * std::f64::consts::PI / size as f64;
+        let w_step = Complex64::new(angle.cos(), angle.sin());
+
+        for start in (0..n).step_by(size) {
+            let mut w = Complex64::new(1.0, 0.0);
+            for k in 0..half {
+                let a = data[start + k];
+                let b = data[start + k + half] * w;
+                data[start + k] = a + b;
+                data[start + k + half] = a - b;
+                w *= w_step;
+            }
+        }
+
+        size *= 2;
+    }
+
+    data
+}
+
+/// Native-Rust replacement for `JuliaSpectrumAnalysis::analyze_spectrum`:
+/// flattens `amplitude_field` into a single series, pads/truncates it to
+/// the next power of two at or above `config.resolution`, runs a radix-2
+/// FFT, and reads off magnitude/phase/energy per bin. Bin `k` of the
+/// returned arrays corresponds to the frequency
+/// `config.frequency_range.0 + k * (range.1 - range.0) / resolution`.
+/// Returns `(frequency_spectrum, phase_spectrum, energy_distribution, total_energy)`.
+fn native_fft_spectrum(
+    amplitude_field: &[Vec<Complex64>],
+    config: &VibrationConfig,
+) -> (Vec<f64>, Vec<f64>, Vec<f64>, f64) {
+    let target_len = config.resolution.max(1).next_power_of_two();
+
+    let mut padded = vec![Complex64::new(0.0, 0.0); target_len];
+    for (slot, value) in padded.iter_mut().zip(amplitude_field.iter().flatten()) {
+        *slot = *value;
+    }
+
+    let spectrum = fft_radix2(&padded);
+
+    let bins = config.resolution.min(target_len);
+    let mut frequency_spectrum = Vec::with_capacity(bins);
+    let mut phase_spectrum = Vec::with_capacity(bins);
+    let mut energy_distribution = Vec::with_capacity(bins);
+    let mut total_energy = 0.0;
+
+    for bin in spectrum.iter().take(bins) {
+        let energy = bin.norm_sqr();
+        frequency_spectrum.push(bin.norm());
+        phase_spectrum.push(bin.im.atan2(bin.re));
+        energy_distribution.push(energy);
+        total_energy += energy;
+    }
+
+    (frequency_spectrum, phase_spectrum, energy_distribution, total_energy)
+}
+
 impl CrystalVibration {
     /// Create new crystal vibration manager with Julia backend
     pub fn new(config: VibrationConfig, wave_pattern: Arc<WavePattern>) -> Result<Self, VibrationError> {
@@ -137,15 +240,31 @@ impl CrystalVibration {
             self.config.coupling_strength,
         ).map_err(|e| VibrationError::JuliaError(e.to_string()))?;
 
-        // Analyze spectrum using Julia
-        let spectrum_result = self.spectrum_analysis.analyze_spectrum(
-            &vibration_result.modes,
-            self.config.frequency_range,
-            self.config.resolution,
-        ).map_err(|e| VibrationError::SpectrumError(e.to_string()))?;
-
-        // Update state with Julia results
-        self.update_state(vibration_result, spectrum_result, time)?;
+        match self.config.spectrum_backend {
+            SpectrumBackend::Julia => {
+                // Analyze spectrum using Julia
+                let spectrum_result = self.spectrum_analysis.analyze_spectrum(
+                    &vibration_result.modes,
+                    self.config.frequency_range,
+                    self.config.resolution,
+                ).map_err(|e| VibrationError::SpectrumError(e.to_string()))?;
+
+                self.update_state(vibration_result, spectrum_result, time)?;
+            }
+            SpectrumBackend::NativeFft => {
+                let (frequency_spectrum, phase_spectrum, energy_distribution, total_energy) =
+                    native_fft_spectrum(&vibration_result.amplitude_field, &self.config);
+
+                self.update_state_native(
+                    vibration_result,
+                    frequency_spectrum,
+                    phase_spectrum,
+                    energy_distribution,
+                    total_energy,
+                    time,
+                )?;
+            }
+        }
 
         // Update history
         self.update_history()?;
@@ -182,6 +301,32 @@ impl CrystalVibration {
         Ok(())
     }
 
+    /// Update state with the native-FFT spectrum path's results; mirrors
+    /// `update_state` but takes the spectrum arrays directly instead of a
+    /// `SpectrumResult` from the Julia backend.
+    fn update_state_native(
+        &self,
+        vibration_result: VibrationResult,
+        frequency_spectrum: Vec<f64>,
+        phase_spectrum: Vec<f64>,
+        energy_distribution: Vec<f64>,
+        total_energy: f64,
+        time: f64,
+    ) -> Result<(), VibrationError> {
+        let mut state = self.state.write();
+
+        state.time = time;
+        state.modes = vibration_result.modes;
+        state.amplitude_field = vibration_result.amplitude_field;
+        state.frequency_spectrum = frequency_spectrum;
+        state.phase_spectrum = phase_spectrum;
+        state.energy_distribution = energy_distribution;
+        state.total_energy = total_energy;
+        state.coherence = vibration_result.coherence;
+
+        Ok(())
+    }
+
     /// Update state history
     fn update_history(&self) -> Result<(), VibrationError> {
         let mut history = self.history.write();
@@ -273,6 +418,47 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_native_fft_spectrum_matches_resolution() {
+        let config = VibrationConfig {
+            resolution: 8,
+            ..Default::default()
+        };
+        let amplitude_field = vec![vec![Complex64::new(1.0, 0.0); 8]];
+
+        let (frequency_spectrum, phase_spectrum, energy_distribution, total_energy) =
+            native_fft_spectrum(&amplitude_field, &config);
+
+        assert_eq!(frequency_spectrum.len(), 8);
+        assert_eq!(phase_spectrum.len(), 8);
+        assert_eq!(energy_distribution.len(), 8);
+        // A constant input is an impulse in frequency space: all energy
+        // lands in bin 0.
+        assert!((frequency_spectrum[0] - 8.0).abs() < 1e-6);
+        assert!(total_energy > 0.0);
+    }
+
+    #[test]
+    fn test_spectrum_analysis_native_backend() -> Result<(), VibrationError> {
+        let config = VibrationConfig {
+            resolution: 10,
+            spectrum_backend: SpectrumBackend::NativeFft,
+            ..Default::default()
+        };
+        let wave_pattern = Arc::new(WavePattern::new(Default::default())?);
+        let vibration = CrystalVibration::new(config, wave_pattern)?;
+
+        let node = Arc::new(LatticeNode::new(Default::default(), [0.0, 0.0, 0.0]));
+        node.apply_force(Complex64::new(1.0, 0.0))?;
+
+        vibration.update(&[node], 0.0)?;
+        let state = vibration.get_state();
+
+        assert_eq!(state.frequency_spectrum.len(), 10);
+        assert_eq!(state.phase_spectrum.len(), 10);
+        Ok(())
+    }
+
     #[test]
     fn test_julia_threading() -> Result<(), VibrationError> {
         let config = VibrationConfig {
@@ -3,12 +3,16 @@
 /// Author: isdood
 /// Current User: isdood
 
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
 use crate::{
     constants::CURRENT_TIMESTAMP,
     Vector3D,
     zeronaut::Zeronaut,
     helium::Helium,
     helium::HeliumOrdering,
+    phantom::QuantumCell,
     quantum::Quantum,  // Our native quantum memory management
 };
 
@@ -21,6 +25,40 @@ const QUANTUM_COHERENCE_THRESHOLD: f64 = 0.5;
 
 pub type AlignedRegion = Vector3D<Zeronaut<u8>>;
 
+/// Pads `T` out to its own cache line so independently-mutated hot fields
+/// (e.g. two `Helium` atomics living side by side in the same struct)
+/// don't share a line and ping-pong between cores when separate threads
+/// touch them concurrently. Transparently derefs to `T`.
+#[repr(align(64))]
+#[derive(Debug, Clone)]
+pub struct CachePadded<T> {
+    value: T,
+}
+
+impl<T> CachePadded<T> {
+    pub fn new(value: T) -> Self {
+        Self { value }
+    }
+
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> Deref for CachePadded<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> DerefMut for CachePadded<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
 #[derive(Debug)]
 pub struct Alignment {
     value: QuantumBlock<usize>,
@@ -61,16 +99,139 @@ impl Alignment {
     }
 }
 
+/// Floating-point precision usable for [`Coherence`] tracking. `f32`
+/// halves the footprint of a large array of tracked coherences at the
+/// cost of needing a conservative correction before stability
+/// comparisons; `f64` needs none (its `epsilon_f64` is small enough to be
+/// a no-op in practice).
+pub trait CoherenceFloat: Copy + Clone + Send + Sync + std::fmt::Debug + 'static {
+    fn mul(self, other: Self) -> Self;
+    fn to_f64(self) -> f64;
+    fn from_f64(value: f64) -> Self;
+    /// Machine epsilon for this precision, promoted to `f64`, used to
+    /// bound the rounding error a long run of `decay` multiplications can
+    /// accumulate.
+    fn epsilon_f64() -> f64;
+}
+
+impl CoherenceFloat for f32 {
+    fn mul(self, other: Self) -> Self {
+        self * other
+    }
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
+
+    fn epsilon_f64() -> f64 {
+        f32::EPSILON as f64
+    }
+}
+
+impl CoherenceFloat for f64 {
+    fn mul(self, other: Self) -> Self {
+        self * other
+    }
+
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+
+    fn epsilon_f64() -> f64 {
+        f64::EPSILON
+    }
+}
+
+/// Mixed-precision coherence tracker. The hot, frequently-decayed running
+/// value lives in `P` -- `f32` for half the memory footprint across large
+/// wormhole/mesh arrays -- but [`is_stable`](Self::is_stable) always
+/// promotes to `f64` for the threshold comparison, and conservatively
+/// subtracts the rounding error `decay` could have accumulated in `P` so
+/// far. That keeps a long-lived `f32` tracker from ever reporting stable
+/// when the true `f64` value would already be at or below threshold.
 #[derive(Debug)]
-pub struct AlignedSpace {
+pub struct Coherence<P: CoherenceFloat> {
+    value: CachePadded<Helium<P>>,
+    decays: AtomicUsize,
+}
+
+impl<P: CoherenceFloat> Coherence<P> {
+    pub fn new(initial: f64) -> Self {
+        Self {
+            value: CachePadded::new(Helium::new(P::from_f64(initial))),
+            decays: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn get(&self) -> f64 {
+        self.value.quantum_load().to_f64()
+    }
+
+    pub fn decay(&self, factor: f64) {
+        let current = self.value.quantum_load();
+        self.value.quantum_store(current.mul(P::from_f64(factor)));
+        self.decays.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn reset(&self, value: f64) {
+        self.value.quantum_store(P::from_f64(value));
+        self.decays.store(0, Ordering::Relaxed);
+    }
+
+    /// `true` only if the `f64`-promoted value, minus the rounding error
+    /// `decay` could have accumulated in `P` so far, is still above
+    /// `threshold`.
+    pub fn is_stable(&self, threshold: f64) -> bool {
+        let accumulated_error = self.decays.load(Ordering::Relaxed) as f64 * P::epsilon_f64();
+        (self.get() - accumulated_error) > threshold
+    }
+}
+
+impl<P: CoherenceFloat> Clone for Coherence<P> {
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+            decays: AtomicUsize::new(self.decays.load(Ordering::Relaxed)),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct AlignedSpace<P: CoherenceFloat = f64> {
     region: AlignedRegion,
     size: usize,
     alignment: Alignment,
-    coherence: Helium<f64>,
+    coherence: Coherence<P>,
 }
 
-impl AlignedSpace {
+// Non-generic inherent `new` per precision, mirroring how `HashMap::new()`
+// resolves its defaulted `S` type param: with no turbofish or binding
+// annotation, inference falls back to `AlignedSpace`'s default `P = f64`
+// and picks this impl, so existing `AlignedSpace::new(..)` call sites
+// keep compiling unchanged. Spell out `AlignedSpace::<f32>::new(..)` to
+// get the lower-footprint tracker instead.
+impl AlignedSpace<f64> {
     pub fn new(size: usize, alignment: Alignment) -> Self {
+        Self::with_precision(size, alignment)
+    }
+}
+
+impl AlignedSpace<f32> {
+    pub fn new(size: usize, alignment: Alignment) -> Self {
+        Self::with_precision(size, alignment)
+    }
+}
+
+impl<P: CoherenceFloat> AlignedSpace<P> {
+    fn with_precision(size: usize, alignment: Alignment) -> Self {
         let aligned_size = alignment.align_address(size);
         let region = AlignedRegion::new(
             Zeronaut::zero(),
@@ -82,7 +243,7 @@ impl AlignedSpace {
             region,
             size: aligned_size,
             alignment,
-            coherence: Helium::new(1.0),
+            coherence: Coherence::new(1.0),
         }
     }
 
@@ -99,21 +260,19 @@ impl AlignedSpace {
     }
 
     pub fn get_coherence(&self) -> f64 {
-        self.coherence.load(&HeliumOrdering::Quantum).unwrap_or(0.0)
+        self.coherence.get()
     }
 
     pub fn is_quantum_stable(&self) -> bool {
-        self.get_coherence() > QUANTUM_COHERENCE_THRESHOLD
+        self.coherence.is_stable(QUANTUM_COHERENCE_THRESHOLD)
     }
 
     pub fn decay_coherence(&mut self) {
-        if let Ok(current) = self.coherence.load(&HeliumOrdering::Quantum) {
-            let _ = self.coherence.store(current * 0.99, &HeliumOrdering::Quantum);
-        }
+        self.coherence.decay(0.99);
     }
 
     pub fn reset_coherence(&mut self) {
-        let _ = self.coherence.store(1.0, &HeliumOrdering::Quantum);
+        self.coherence.reset(1.0);
     }
 
     pub fn get_position(&self) -> Vector3D<isize> {
@@ -141,7 +300,7 @@ impl AlignedSpace {
     }
 }
 
-impl Clone for AlignedSpace {
+impl<P: CoherenceFloat> Clone for AlignedSpace<P> {
     fn clone(&self) -> Self {
         Self {
             region: self.region.clone(),
@@ -152,9 +311,183 @@ impl Clone for AlignedSpace {
     }
 }
 
-// Static quantum pool with native quantum memory management
-static QUANTUM_POOL: QuantumBlock<[u8; QUANTUM_BLOCK_SIZE * QUANTUM_POOL_SIZE]> =
-QuantumBlock::new([0; QUANTUM_BLOCK_SIZE * QUANTUM_POOL_SIZE]);
+const QUANTUM_ONCE_UNINIT: usize = 0;
+const QUANTUM_ONCE_INITIALIZING: usize = 1;
+const QUANTUM_ONCE_INIT: usize = 2;
+
+/// One-time quantum initialization cell. The first caller into
+/// `get_or_init` runs the initializer and stores its result; every other
+/// caller either observes the already-stored value or, having arrived
+/// while initialization is still in flight, spins until it completes --
+/// losers of the race never run the initializer themselves.
+pub struct QuantumOnce<T> {
+    state: AtomicUsize,
+    value: std::cell::UnsafeCell<std::mem::MaybeUninit<T>>,
+}
+
+// SAFETY: `value` is only ever written once, by whichever caller wins the
+// UNINIT -> INITIALIZING transition, and only ever read once `state` has
+// been observed as INIT -- the same single-writer-many-readers handoff
+// `QuantumCell`'s seqlock fast path relies on.
+unsafe impl<T: Send> Sync for QuantumOnce<T> {}
+
+impl<T> QuantumOnce<T> {
+    pub const fn new() -> Self {
+        Self {
+            state: AtomicUsize::new(QUANTUM_ONCE_UNINIT),
+            value: std::cell::UnsafeCell::new(std::mem::MaybeUninit::uninit()),
+        }
+    }
+
+    /// Returns the stored value once initialization has completed, `None`
+    /// otherwise (including while another caller is mid-init).
+    pub fn get(&self) -> Option<&T> {
+        if self.state.load(Ordering::Acquire) == QUANTUM_ONCE_INIT {
+            Some(unsafe { (*self.value.get()).assume_init_ref() })
+        } else {
+            None
+        }
+    }
+
+    /// Runs `f` exactly once across all callers and returns a shared
+    /// reference to its result.
+    pub fn get_or_init<F: FnOnce() -> T>(&self, f: F) -> &T {
+        match self.state.compare_exchange(
+            QUANTUM_ONCE_UNINIT,
+            QUANTUM_ONCE_INITIALIZING,
+            Ordering::AcqRel,
+            Ordering::Acquire,
+        ) {
+            Ok(_) => {
+                let value = f();
+                unsafe {
+                    (*self.value.get()).write(value);
+                }
+                self.state.store(QUANTUM_ONCE_INIT, Ordering::Release);
+            }
+            Err(_) => {
+                while self.state.load(Ordering::Acquire) != QUANTUM_ONCE_INIT {
+                    std::hint::spin_loop();
+                }
+            }
+        }
+
+        unsafe { (*self.value.get()).assume_init_ref() }
+    }
+}
+
+impl<T> Drop for QuantumOnce<T> {
+    fn drop(&mut self) {
+        if *self.state.get_mut() == QUANTUM_ONCE_INIT {
+            unsafe {
+                (*self.value.get()).assume_init_drop();
+            }
+        }
+    }
+}
+
+/// Pairs a `QuantumOnce` with its own initializer so a process-wide
+/// `static` can defer the work of building a value (like zeroing out the
+/// full `QUANTUM_POOL` arena below) until something actually touches it.
+pub struct QuantumLazy<T> {
+    once: QuantumOnce<T>,
+    init: fn() -> T,
+}
+
+impl<T> QuantumLazy<T> {
+    pub const fn new(init: fn() -> T) -> Self {
+        Self {
+            once: QuantumOnce::new(),
+            init,
+        }
+    }
+
+    /// Returns the stored value if `force`/`get_or_init` has already run,
+    /// without triggering initialization itself.
+    pub fn get(&self) -> Option<&T> {
+        self.once.get()
+    }
+
+    /// Runs the initializer on first call; every call returns the same
+    /// shared value.
+    pub fn force(&self) -> &T {
+        self.once.get_or_init(self.init)
+    }
+}
+
+impl<T> Deref for QuantumLazy<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.force()
+    }
+}
+
+/// Coherence-tracked bump-with-freelist allocator over a single
+/// `QUANTUM_BLOCK_SIZE * QUANTUM_POOL_SIZE` byte arena, handed out in
+/// `QUANTUM_BLOCK_SIZE` chunks identified by block index. Backs the
+/// process-wide `QUANTUM_POOL` static below via `QuantumLazy` so the
+/// arena isn't allocated (and zeroed) until the pool is first touched.
+pub struct QuantumPool {
+    arena: Box<[u8]>,
+    cursor: AtomicUsize,
+    free: QuantumCell<Vec<usize>>,
+    coherence: CachePadded<Helium<f64>>,
+}
+
+impl QuantumPool {
+    pub fn new() -> Self {
+        Self {
+            arena: vec![0u8; QUANTUM_BLOCK_SIZE * QUANTUM_POOL_SIZE].into_boxed_slice(),
+            cursor: AtomicUsize::new(0),
+            free: QuantumCell::new(Vec::new()),
+            coherence: CachePadded::new(Helium::new(1.0)),
+        }
+    }
+
+    /// Hands out a free block index, preferring a previously `dealloc`ed
+    /// block before bumping the cursor into fresh arena space. `None` once
+    /// the arena is exhausted and nothing has been freed.
+    pub fn alloc(&self) -> Option<usize> {
+        let mut free = self.free.get();
+        if let Some(block) = free.pop() {
+            self.free.set(free);
+            self.coherence.decay_coherence();
+            return Some(block);
+        }
+
+        let block = self.cursor.fetch_add(1, Ordering::AcqRel);
+        if block >= QUANTUM_POOL_SIZE {
+            self.cursor.fetch_sub(1, Ordering::AcqRel);
+            return None;
+        }
+
+        self.coherence.decay_coherence();
+        Some(block)
+    }
+
+    /// Returns a block to the freelist for reuse by the next `alloc`.
+    pub fn dealloc(&self, block: usize) {
+        let mut free = self.free.get();
+        free.push(block);
+        self.free.set(free);
+    }
+
+    pub fn get_coherence(&self) -> f64 {
+        self.coherence.get_coherence()
+    }
+}
+
+impl Default for QuantumPool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Static quantum pool with native quantum memory management. Lazily
+/// constructed so the `QUANTUM_BLOCK_SIZE * QUANTUM_POOL_SIZE` arena isn't
+/// zeroed until some caller actually reaches for it.
+static QUANTUM_POOL: QuantumLazy<QuantumPool> = QuantumLazy::new(QuantumPool::new);
 
 pub fn vector_align() -> Alignment {
     Alignment::new(VECTOR_ALIGN)
@@ -283,4 +616,76 @@ mod tests {
         assert!(space.get_coherence() <= 1.0);
         assert!(space.get_coherence() >= 0.0);
     }
+
+    #[test]
+    fn test_cache_padded_size_and_round_trip() {
+        let padded = CachePadded::new(Helium::new(1.0_f64));
+        assert!(std::mem::size_of::<CachePadded<Helium<f64>>>() >= CACHE_LINE);
+
+        padded.set(2.0);
+        assert_eq!(padded.get(), 2.0);
+
+        let inner = padded.into_inner();
+        assert_eq!(inner.get(), 2.0);
+    }
+
+    #[test]
+    fn test_quantum_once_runs_initializer_exactly_once() {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+        let once: QuantumOnce<i32> = QuantumOnce::new();
+
+        assert!(once.get().is_none());
+
+        let first = *once.get_or_init(|| {
+            CALLS.fetch_add(1, Ordering::SeqCst);
+            42
+        });
+        let second = *once.get_or_init(|| {
+            CALLS.fetch_add(1, Ordering::SeqCst);
+            99
+        });
+
+        assert_eq!(first, 42);
+        assert_eq!(second, 42);
+        assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_quantum_lazy_force_and_deref() {
+        let lazy: QuantumLazy<i32> = QuantumLazy::new(|| 7);
+        assert!(lazy.get().is_none());
+        assert_eq!(*lazy.force(), 7);
+        assert_eq!(*lazy, 7);
+    }
+
+    #[test]
+    fn test_quantum_pool_via_lazy_static() {
+        assert_eq!(QUANTUM_POOL.get_coherence(), 1.0);
+        assert!(QUANTUM_POOL.alloc().is_some());
+    }
+
+    #[test]
+    fn test_aligned_space_f32_precision() {
+        let mut space = AlignedSpace::<f32>::new(100, Alignment::new(16));
+        assert!(space.is_quantum_stable());
+
+        for _ in 0..5 {
+            space.decay_coherence();
+        }
+        assert!(space.get_coherence() < 1.0);
+    }
+
+    #[test]
+    fn test_coherence_is_stable_conservative_near_threshold() {
+        // Just above threshold: a naive `value > threshold` check would
+        // call this stable, but after enough `decay` calls the
+        // accumulated f32 rounding-error bound outweighs that tiny
+        // margin, so the conservative check must refuse to call it stable.
+        let coherence = Coherence::<f32>::new(QUANTUM_COHERENCE_THRESHOLD + 0.000005);
+        for _ in 0..100 {
+            coherence.decay(1.0);
+        }
+        assert!(coherence.get() > QUANTUM_COHERENCE_THRESHOLD);
+        assert!(!coherence.is_stable(QUANTUM_COHERENCE_THRESHOLD));
+    }
 }
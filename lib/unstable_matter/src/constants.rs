@@ -84,6 +84,7 @@ pub const MAX_WORMHOLE_CONNECTIONS: usize = 10;
 pub const MAX_UFO_INSTANCES: usize = 50;
 pub const MAX_QUANTUM_THREADS: usize = 32;
 pub const MAX_COHERENCE_VIOLATIONS: usize = 5;
+pub const MAX_QUANTUM_CONTENTION_RETRIES: usize = 64;
 
 // Unstable Matter Constants
 pub const UNSTABLE_MATTER_THRESHOLD: f64 = 0.3;
@@ -0,0 +1,619 @@
+//! Contour-integral nonlinear eigensolver (Beyn's method).
+//!
+//! Given an analytic matrix-valued function `T(z)` (e.g. a
+//! frequency-dependent metric or dispersion operator), finds every `z`
+//! with `det T(z) = 0` inside a contour by sampling `T(z)^-1` around that
+//! contour and reducing the resulting quadrature moments to a small
+//! ordinary eigenvalue problem. Complements the scalar `quantum_sqrt`/
+//! Newton machinery with a true spectral solver for matrix-valued roots.
+
+extern crate alloc;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::cube::{JACOBI_CONVERGENCE_THRESHOLD, JACOBI_MAX_SWEEPS};
+
+/// A complex number backing the contour quadrature and the small
+/// eigenvalue problems it reduces to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Complex64 {
+    pub re: f64,
+    pub im: f64,
+}
+
+impl Complex64 {
+    pub fn new(re: f64, im: f64) -> Self {
+        Self { re, im }
+    }
+
+    pub fn zero() -> Self {
+        Self::new(0.0, 0.0)
+    }
+
+    /// A point on a circle of radius `r` at angle `theta`.
+    pub fn from_polar(r: f64, theta: f64) -> Self {
+        Self::new(r * libm::cos(theta), r * libm::sin(theta))
+    }
+
+    pub fn conj(&self) -> Self {
+        Self::new(self.re, -self.im)
+    }
+
+    pub fn abs(&self) -> f64 {
+        libm::sqrt(self.re * self.re + self.im * self.im)
+    }
+
+    /// `arg(self)`, i.e. the angle of `self` in the complex plane.
+    pub fn arg(&self) -> f64 {
+        libm::atan2(self.im, self.re)
+    }
+
+    pub fn add(&self, other: Self) -> Self {
+        Self::new(self.re + other.re, self.im + other.im)
+    }
+
+    pub fn sub(&self, other: Self) -> Self {
+        Self::new(self.re - other.re, self.im - other.im)
+    }
+
+    pub fn mul(&self, other: Self) -> Self {
+        Self::new(
+            self.re * other.re - self.im * other.im,
+            self.re * other.im + self.im * other.re,
+        )
+    }
+
+    pub fn scale(&self, factor: f64) -> Self {
+        Self::new(self.re * factor, self.im * factor)
+    }
+
+    pub fn div(&self, other: Self) -> Self {
+        let denom = other.re * other.re + other.im * other.im;
+        Self::new(
+            (self.re * other.re + self.im * other.im) / denom,
+            (self.im * other.re - self.re * other.im) / denom,
+        )
+    }
+
+    pub fn neg(&self) -> Self {
+        Self::new(-self.re, -self.im)
+    }
+}
+
+/// A dense, row-major complex matrix, sized at construction time rather
+/// than fixed like `MetricTensor::components` — Beyn's method's
+/// intermediate matrices (`V`, the moments, the reduced `B`) all vary in
+/// size with the probing width `l` and the numerical rank `m`.
+#[derive(Debug, Clone)]
+pub struct ComplexMatrix {
+    rows: usize,
+    cols: usize,
+    data: Vec<Complex64>,
+}
+
+impl ComplexMatrix {
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        Self {
+            rows,
+            cols,
+            data: vec![Complex64::zero(); rows * cols],
+        }
+    }
+
+    pub fn identity(n: usize) -> Self {
+        let mut m = Self::zeros(n, n);
+        for i in 0..n {
+            m.set(i, i, Complex64::new(1.0, 0.0));
+        }
+        m
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn get(&self, i: usize, j: usize) -> Complex64 {
+        self.data[i * self.cols + j]
+    }
+
+    pub fn set(&mut self, i: usize, j: usize, value: Complex64) {
+        self.data[i * self.cols + j] = value;
+    }
+
+    /// Adds `other.scale(factor)` into `self` in place, accumulating a
+    /// quadrature term without allocating an intermediate matrix.
+    fn add_scaled_assign(&mut self, other: &Self, factor: Complex64) {
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                let term = other.get(i, j).mul(factor);
+                let updated = self.get(i, j).add(term);
+                self.set(i, j, updated);
+            }
+        }
+    }
+
+    pub fn mul(&self, other: &Self) -> Self {
+        assert_eq!(self.cols, other.rows, "ComplexMatrix::mul: inner dimensions must match");
+        let mut result = Self::zeros(self.rows, other.cols);
+        for i in 0..self.rows {
+            for k in 0..self.cols {
+                let a = self.get(i, k);
+                if a.re == 0.0 && a.im == 0.0 {
+                    continue;
+                }
+                for j in 0..other.cols {
+                    let updated = result.get(i, j).add(a.mul(other.get(k, j)));
+                    result.set(i, j, updated);
+                }
+            }
+        }
+        result
+    }
+
+    pub fn conjugate_transpose(&self) -> Self {
+        let mut result = Self::zeros(self.cols, self.rows);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                result.set(j, i, self.get(i, j).conj());
+            }
+        }
+        result
+    }
+
+    fn column(&self, j: usize) -> Vec<Complex64> {
+        (0..self.rows).map(|i| self.get(i, j)).collect()
+    }
+
+    fn set_column(&mut self, j: usize, values: &[Complex64]) {
+        for i in 0..self.rows {
+            self.set(i, j, values[i]);
+        }
+    }
+}
+
+/// Deterministic per-(row, col) pseudo-random value, mixed from a
+/// splitmix64-style avalanche rather than drawing from an RNG, so the
+/// probing matrix `V` is reproducible across runs for the same `seed`.
+fn pseudo_random_unit(row: usize, col: usize, seed: u64) -> f64 {
+    let mut z = seed
+        ^ (row as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+        ^ (col as u64).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^= z >> 31;
+    // Map the top 53 bits onto [-1.0, 1.0).
+    ((z >> 11) as f64 / (1u64 << 53) as f64) * 2.0 - 1.0
+}
+
+/// A deterministically-seeded `n x l` probing matrix for Beyn's method.
+/// `l` must exceed the number of eigenvalues expected inside the contour,
+/// or some of them will be missed regardless of quadrature accuracy.
+fn probing_matrix(n: usize, l: usize, seed: u64) -> ComplexMatrix {
+    let mut v = ComplexMatrix::zeros(n, l);
+    for i in 0..n {
+        for j in 0..l {
+            let re = pseudo_random_unit(i, j, seed);
+            let im = pseudo_random_unit(i, j, seed ^ 0x5DEE_CE11_6D05_A3E1);
+            v.set(i, j, Complex64::new(re, im));
+        }
+    }
+    v
+}
+
+/// Solves `a @ x = b` for the dense `x` (same shape as `b`), via Gaussian
+/// elimination with partial pivoting on the augmented `[a | b]` system.
+/// Used once per contour sample to compute `T(z_k)^-1 @ V`.
+fn solve_multi(a: &ComplexMatrix, b: &ComplexMatrix) -> ComplexMatrix {
+    let n = a.rows();
+    assert_eq!(a.cols(), n, "solve_multi: `a` must be square");
+    assert_eq!(b.rows(), n, "solve_multi: `b` must have the same row count as `a`");
+
+    let mut aug = ComplexMatrix::zeros(n, n + b.cols());
+    for i in 0..n {
+        for j in 0..n {
+            aug.set(i, j, a.get(i, j));
+        }
+        for j in 0..b.cols() {
+            aug.set(i, n + j, b.get(i, j));
+        }
+    }
+
+    for pivot in 0..n {
+        let mut best_row = pivot;
+        let mut best_mag = aug.get(pivot, pivot).abs();
+        for row in (pivot + 1)..n {
+            let mag = aug.get(row, pivot).abs();
+            if mag > best_mag {
+                best_mag = mag;
+                best_row = row;
+            }
+        }
+        if best_row != pivot {
+            for col in 0..aug.cols() {
+                let a_val = aug.get(pivot, col);
+                let b_val = aug.get(best_row, col);
+                aug.set(pivot, col, b_val);
+                aug.set(best_row, col, a_val);
+            }
+        }
+
+        let pivot_value = aug.get(pivot, pivot);
+        for row in (pivot + 1)..n {
+            let factor = aug.get(row, pivot).div(pivot_value);
+            if factor.re == 0.0 && factor.im == 0.0 {
+                continue;
+            }
+            for col in pivot..aug.cols() {
+                let updated = aug.get(row, col).sub(factor.mul(aug.get(pivot, col)));
+                aug.set(row, col, updated);
+            }
+        }
+    }
+
+    let mut x = ComplexMatrix::zeros(n, b.cols());
+    for col in 0..b.cols() {
+        for row in (0..n).rev() {
+            let mut sum = aug.get(row, n + col);
+            for k in (row + 1)..n {
+                sum = sum.sub(aug.get(row, k).mul(x.get(k, col)));
+            }
+            x.set(row, col, sum.div(aug.get(row, row)));
+        }
+    }
+    x
+}
+
+/// Find the off-diagonal element of Hermitian `a` with the largest
+/// magnitude, plus the sum of squared magnitudes of every off-diagonal
+/// element (the Jacobi sweep's convergence measure). Mirrors
+/// `MetricTensor::largest_off_diagonal`, generalized to a runtime-sized
+/// complex Hermitian matrix.
+fn largest_off_diagonal(a: &ComplexMatrix) -> (usize, usize, f64) {
+    let n = a.rows();
+    let (mut p, mut q, mut largest) = (0, 1.min(n.saturating_sub(1)), 0.0);
+    let mut sum_sq = 0.0;
+
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let mag = a.get(i, j).abs();
+            sum_sq += mag * mag * 2.0;
+            if mag > largest {
+                largest = mag;
+                p = i;
+                q = j;
+            }
+        }
+    }
+
+    (p, q, sum_sq)
+}
+
+/// Diagonalizes a Hermitian matrix with a cyclic Jacobi sweep, the complex
+/// analog of `MetricTensor::jacobi_eigen`: each step zeros the largest
+/// off-diagonal entry with a unitary rotation `G` carrying both a
+/// rotation angle `theta` (from the real-case formula, using `|a_pq|`)
+/// and a phase `phi = arg(a_pq)` that the real case doesn't need. Returns
+/// `(eigenvalues, V)` with `V`'s columns the corresponding eigenvectors.
+fn hermitian_jacobi_eigen(a: &ComplexMatrix) -> (Vec<f64>, ComplexMatrix) {
+    let n = a.rows();
+    let mut a = a.clone();
+    let mut v = ComplexMatrix::identity(n);
+
+    for _ in 0..JACOBI_MAX_SWEEPS {
+        let (p, q, off_diagonal_sq) = largest_off_diagonal(&a);
+        if off_diagonal_sq < JACOBI_CONVERGENCE_THRESHOLD || p == q {
+            break;
+        }
+
+        let a_pq = a.get(p, q);
+        let phi = a_pq.arg();
+        let theta = 0.5 * libm::atan2(2.0 * a_pq.abs(), a.get(q, q).re - a.get(p, p).re);
+        let (sin, cos) = (libm::sin(theta), libm::cos(theta));
+        let phase = Complex64::from_polar(1.0, phi);
+        let phase_conj = phase.conj();
+
+        // Right-multiply by G: mix columns p and q.
+        for i in 0..n {
+            let a_ip = a.get(i, p);
+            let a_iq = a.get(i, q);
+            let new_ip = a_ip.scale(cos).add(a_iq.mul(phase).scale(sin));
+            let new_iq = a_ip.mul(phase_conj).scale(-sin).add(a_iq.scale(cos));
+            a.set(i, p, new_ip);
+            a.set(i, q, new_iq);
+        }
+        // Left-multiply by G^H: mix rows p and q.
+        for j in 0..n {
+            let a_pj = a.get(p, j);
+            let a_qj = a.get(q, j);
+            let new_pj = a_pj.scale(cos).add(a_qj.mul(phase_conj).scale(sin));
+            let new_qj = a_pj.mul(phase).scale(-sin).add(a_qj.scale(cos));
+            a.set(p, j, new_pj);
+            a.set(q, j, new_qj);
+        }
+
+        let v_p = v.column(p);
+        let v_q = v.column(q);
+        let mut new_v_p = Vec::with_capacity(n);
+        let mut new_v_q = Vec::with_capacity(n);
+        for i in 0..n {
+            new_v_p.push(v_p[i].scale(cos).add(v_q[i].mul(phase).scale(sin)));
+            new_v_q.push(v_p[i].mul(phase_conj).scale(-sin).add(v_q[i].scale(cos)));
+        }
+        v.set_column(p, &new_v_p);
+        v.set_column(q, &new_v_q);
+    }
+
+    let eigenvalues = (0..n).map(|i| a.get(i, i).re).collect();
+    (eigenvalues, v)
+}
+
+/// Economy-size SVD of `a` (`n x l`, `n >= l`), via the eigendecomposition
+/// of the Hermitian Gram matrix `a^H a` — singular values are the square
+/// roots of its eigenvalues, right singular vectors its eigenvectors, and
+/// left singular vectors follow from `u_i = a w_i / sigma_i`. Returns
+/// `(U, singular_values, W)` sorted by descending singular value.
+fn economy_svd(a: &ComplexMatrix) -> (ComplexMatrix, Vec<f64>, ComplexMatrix) {
+    let l = a.cols();
+    let gram = a.conjugate_transpose().mul(a);
+    let (eigenvalues, w) = hermitian_jacobi_eigen(&gram);
+
+    let mut order: Vec<usize> = (0..l).collect();
+    order.sort_by(|&i, &j| eigenvalues[j].partial_cmp(&eigenvalues[i]).unwrap());
+
+    let mut singular_values = Vec::with_capacity(l);
+    let mut w_sorted = ComplexMatrix::zeros(l, l);
+    for (new_col, &old_col) in order.iter().enumerate() {
+        let sigma = libm::sqrt(eigenvalues[old_col].max(0.0));
+        singular_values.push(sigma);
+        w_sorted.set_column(new_col, &w.column(old_col));
+    }
+
+    let mut u = ComplexMatrix::zeros(a.rows(), l);
+    let aw = a.mul(&w_sorted);
+    for col in 0..l {
+        let sigma = singular_values[col];
+        if sigma <= 0.0 {
+            continue;
+        }
+        let scaled: Vec<Complex64> = aw.column(col).iter().map(|v| v.scale(1.0 / sigma)).collect();
+        u.set_column(col, &scaled);
+    }
+
+    (u, singular_values, w_sorted)
+}
+
+/// Eigenvalues of a small, generally-complex square matrix via unshifted
+/// QR iteration: repeatedly factor `b = q*r` (modified Gram-Schmidt) and
+/// replace `b` with `r*q`, which converges to (quasi-)upper-triangular
+/// form whose diagonal holds the eigenvalues. No deflation or shifts —
+/// adequate for the small, well-separated reduced matrix Beyn's method
+/// produces, not a general-purpose dense eigensolver.
+fn qr_algorithm_eigenvalues(b: &ComplexMatrix) -> Vec<Complex64> {
+    let n = b.rows();
+    let mut b = b.clone();
+
+    for _ in 0..JACOBI_MAX_SWEEPS {
+        let (q, r) = qr_decompose(&b);
+        b = r.mul(&q);
+
+        let mut below_diagonal_sq = 0.0;
+        for i in 0..n {
+            for j in 0..i {
+                let mag = b.get(i, j).abs();
+                below_diagonal_sq += mag * mag;
+            }
+        }
+        if below_diagonal_sq < JACOBI_CONVERGENCE_THRESHOLD {
+            break;
+        }
+    }
+
+    (0..n).map(|i| b.get(i, i)).collect()
+}
+
+/// Modified Gram-Schmidt QR decomposition of a square complex matrix.
+fn qr_decompose(a: &ComplexMatrix) -> (ComplexMatrix, ComplexMatrix) {
+    let n = a.rows();
+    let mut q = ComplexMatrix::zeros(n, n);
+    let mut r = ComplexMatrix::zeros(n, n);
+
+    for j in 0..n {
+        let mut v = a.column(j);
+        for i in 0..j {
+            let q_i = q.column(i);
+            let mut dot = Complex64::zero();
+            for k in 0..n {
+                dot = dot.add(q_i[k].conj().mul(v[k]));
+            }
+            r.set(i, j, dot);
+            for k in 0..n {
+                v[k] = v[k].sub(q_i[k].mul(dot));
+            }
+        }
+
+        let norm = libm::sqrt(v.iter().map(|c| c.re * c.re + c.im * c.im).sum());
+        r.set(j, j, Complex64::new(norm, 0.0));
+        if norm > 0.0 {
+            let normalized: Vec<Complex64> = v.iter().map(|c| c.scale(1.0 / norm)).collect();
+            q.set_column(j, &normalized);
+        }
+    }
+
+    (q, r)
+}
+
+/// Tunable parameters for a single `beyn_eigenvalues` contour sweep.
+#[derive(Debug, Clone, Copy)]
+pub struct BeynConfig {
+    /// Center of the sampling contour (a circle) in the complex plane.
+    pub center: Complex64,
+    /// Radius of the sampling contour.
+    pub radius: f64,
+    /// Number of quadrature points sampled around the contour. Higher
+    /// catches eigenvalues close to the boundary more accurately, at the
+    /// cost of one `T(z_k)` solve per point.
+    pub contour_points: usize,
+    /// Width `l` of the probing matrix `V`. Must exceed the number of
+    /// eigenvalues expected inside the contour.
+    pub probing_width: usize,
+    /// Singular values of `A0` below `singular_value_tolerance` times the
+    /// largest singular value are treated as numerical noise and
+    /// truncated, guarding against spurious modes.
+    pub singular_value_tolerance: f64,
+    /// Seed for the deterministic probing matrix `V`.
+    pub seed: u64,
+}
+
+/// Beyn's contour-integral method: finds every `z` with `det t(z) = 0`
+/// inside the circle described by `config`, where `t` maps a point in the
+/// complex plane to the `n x n` matrix `T(z)`.
+pub fn beyn_eigenvalues(n: usize, t: impl Fn(Complex64) -> ComplexMatrix, config: &BeynConfig) -> Vec<Complex64> {
+    let l = config.probing_width;
+    let v = probing_matrix(n, l, config.seed);
+
+    let mut a0 = ComplexMatrix::zeros(n, l);
+    let mut a1 = ComplexMatrix::zeros(n, l);
+
+    let points = config.contour_points.max(1);
+    for k in 0..points {
+        let phi = 2.0 * core::f64::consts::PI * (k as f64) / (points as f64);
+        let offset = Complex64::from_polar(config.radius, phi);
+        let z_k = config.center.add(offset);
+        let weight = offset.scale(1.0 / (points as f64));
+
+        let t_k = t(z_k);
+        let x_k = solve_multi(&t_k, &v);
+
+        a0.add_scaled_assign(&x_k, weight);
+        a1.add_scaled_assign(&x_k, z_k.mul(weight));
+    }
+
+    let (u, singular_values, w) = economy_svd(&a0);
+    let max_singular_value = singular_values.iter().cloned().fold(0.0_f64, f64::max);
+    let rank = singular_values
+        .iter()
+        .take_while(|&&sigma| sigma > config.singular_value_tolerance * max_singular_value)
+        .count()
+        .max(1);
+
+    let mut u_m = ComplexMatrix::zeros(n, rank);
+    let mut w_m = ComplexMatrix::zeros(l, rank);
+    for col in 0..rank {
+        u_m.set_column(col, &u.column(col));
+        w_m.set_column(col, &w.column(col));
+    }
+
+    let mut sigma_inv = ComplexMatrix::zeros(rank, rank);
+    for i in 0..rank {
+        sigma_inv.set(i, i, Complex64::new(1.0 / singular_values[i], 0.0));
+    }
+
+    let b = u_m.conjugate_transpose().mul(&a1).mul(&w_m).mul(&sigma_inv);
+
+    qr_algorithm_eigenvalues(&b)
+}
+
+/// Builds the analytic operator `T(z) = components - z^2 * I` for a 4x4
+/// metric-like matrix (e.g. `MetricTensor`'s components), whose
+/// `det T(z) = 0` roots are the eigenfrequencies of the metric's
+/// quadratic form — the kind of `T(z)` `beyn_eigenvalues` is built to
+/// solve. Takes the raw matrix rather than depending on `cube::MetricTensor`
+/// directly, since contour integration is useful for any 4x4 operator family.
+pub fn metric_dispersion_operator(components: [[f64; 4]; 4]) -> impl Fn(Complex64) -> ComplexMatrix {
+    move |z| {
+        let z_squared = z.mul(z);
+        let mut t = ComplexMatrix::zeros(4, 4);
+        for i in 0..4 {
+            for j in 0..4 {
+                let mut value = Complex64::new(components[i][j], 0.0);
+                if i == j {
+                    value = value.sub(z_squared);
+                }
+                t.set(i, j, value);
+            }
+        }
+        t
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn default_config() -> BeynConfig {
+        BeynConfig {
+            center: Complex64::zero(),
+            radius: 5.0,
+            contour_points: 64,
+            probing_width: 3,
+            singular_value_tolerance: 1e-8,
+            seed: 0x5EED_1234_ABCD_EF01,
+        }
+    }
+
+    #[test]
+    fn test_beyn_finds_diagonal_eigenvalues() {
+        // T(z) = diag(z - 1, z - 2, z - (-3)): roots are 1, 2, -3, all
+        // inside a contour of radius 5 centered at the origin.
+        let targets = [1.0, 2.0, -3.0];
+        let t = move |z: Complex64| {
+            let mut m = ComplexMatrix::zeros(3, 3);
+            for (i, &root) in targets.iter().enumerate() {
+                m.set(i, i, z.sub(Complex64::new(root, 0.0)));
+            }
+            m
+        };
+
+        let mut eigenvalues = beyn_eigenvalues(3, t, &default_config());
+        eigenvalues.sort_by(|a, b| a.re.partial_cmp(&b.re).unwrap());
+
+        let mut expected = targets;
+        expected.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        for (found, &want) in eigenvalues.iter().zip(expected.iter()) {
+            assert!((found.re - want).abs() < 1e-6, "re: {} vs {}", found.re, want);
+            assert!(found.im.abs() < 1e-6, "im: {}", found.im);
+        }
+    }
+
+    #[test]
+    fn test_metric_dispersion_operator_is_hermitian_at_real_z() {
+        let components = [
+            [-1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ];
+        let t = metric_dispersion_operator(components);
+        let sample = t(Complex64::new(2.0, 0.0));
+
+        for i in 0..4 {
+            for j in 0..4 {
+                assert_eq!(sample.get(i, j).re, sample.get(j, i).re);
+                assert_eq!(sample.get(i, j).im, -sample.get(j, i).im);
+            }
+        }
+    }
+
+    #[test]
+    fn test_solve_multi_matches_known_solution() {
+        let mut a = ComplexMatrix::zeros(2, 2);
+        a.set(0, 0, Complex64::new(2.0, 0.0));
+        a.set(0, 1, Complex64::new(0.0, 1.0));
+        a.set(1, 0, Complex64::new(0.0, -1.0));
+        a.set(1, 1, Complex64::new(3.0, 0.0));
+
+        let mut b = ComplexMatrix::zeros(2, 1);
+        b.set(0, 0, Complex64::new(1.0, 0.0));
+        b.set(1, 0, Complex64::new(0.0, 0.0));
+
+        let x = solve_multi(&a, &b);
+        let reconstructed = a.mul(&x);
+        assert!((reconstructed.get(0, 0).re - b.get(0, 0).re).abs() < 1e-9);
+        assert!((reconstructed.get(1, 0).re - b.get(1, 0).re).abs() < 1e-9);
+    }
+}
@@ -5,6 +5,99 @@ use crate::align::{AlignedSpace, vector_align};
 use crate::Helium;
 use crate::vector::Vector4D;
 use crate::constants::{MESH_TIMESTAMP, PLANCK_LENGTH, VECTOR_QUANTUM_STATE, QUANTUM_THRESHOLD};
+use errors::MathError;
+
+/// Sum of squared off-diagonals the Jacobi eigenvalue sweep in
+/// `MetricTensor::jacobi_eigen` must fall below before it stops rotating.
+pub(crate) const JACOBI_CONVERGENCE_THRESHOLD: f64 = 1e-14;
+/// Hard cap on Jacobi sweeps. A 4x4 symmetric matrix converges in well
+/// under this many rotations; it only guards against a pathological or
+/// non-symmetric input spinning forever. Reused by `contour_eigen`'s
+/// complex Hermitian Jacobi sweep and QR iteration as the same sweep cap.
+pub(crate) const JACOBI_MAX_SWEEPS: usize = 100;
+
+/// Storage precision for `MetricTensor::components`, mirroring
+/// `harmony_core::Precision`'s `Full`/`Mixed` split.
+///
+/// `Mixed` halves the tensor's footprint by storing components as `f32`,
+/// but `contract`, `quantize` and the Jacobi eigensolver all upcast to
+/// `f64` before accumulating, so results only drift from `Full` by the
+/// rounding error of a single `f64 -> f32 -> f64` round trip.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Precision {
+    /// Store components as `f64`. Highest accuracy; double the memory of `Mixed`.
+    Full,
+    /// Store components as `f32`. Half the memory of `Full`, at the cost
+    /// of that storage's rounding error surfacing in subsequent reads.
+    Mixed,
+}
+
+impl Default for Precision {
+    fn default() -> Self {
+        Self::Full
+    }
+}
+
+/// Component storage backing a `MetricTensor`, sized according to its
+/// `Precision`. Every accessor upcasts to `f64`, so callers never need to
+/// branch on which variant is active.
+#[derive(Debug, Clone, Copy)]
+enum ComponentStore {
+    Full([[f64; 4]; 4]),
+    Mixed([[f32; 4]; 4]),
+}
+
+impl ComponentStore {
+    fn new(precision: Precision, matrix: [[f64; 4]; 4]) -> Self {
+        match precision {
+            Precision::Full => Self::Full(matrix),
+            Precision::Mixed => {
+                let mut mixed = [[0.0f32; 4]; 4];
+                for i in 0..4 {
+                    for j in 0..4 {
+                        mixed[i][j] = matrix[i][j] as f32;
+                    }
+                }
+                Self::Mixed(mixed)
+            }
+        }
+    }
+
+    fn get(&self) -> [[f64; 4]; 4] {
+        match self {
+            Self::Full(matrix) => *matrix,
+            Self::Mixed(matrix) => {
+                let mut full = [[0.0f64; 4]; 4];
+                for i in 0..4 {
+                    for j in 0..4 {
+                        full[i][j] = matrix[i][j] as f64;
+                    }
+                }
+                full
+            }
+        }
+    }
+
+    fn set(&mut self, matrix: [[f64; 4]; 4]) {
+        match self {
+            Self::Full(slot) => *slot = matrix,
+            Self::Mixed(slot) => {
+                for i in 0..4 {
+                    for j in 0..4 {
+                        slot[i][j] = matrix[i][j] as f32;
+                    }
+                }
+            }
+        }
+    }
+
+    fn precision(&self) -> Precision {
+        match self {
+            Self::Full(_) => Precision::Full,
+            Self::Mixed(_) => Precision::Mixed,
+        }
+    }
+}
 
 #[derive(Debug)]
 pub struct Box<T> {
@@ -61,10 +154,154 @@ T: Clone,
     }
 }
 
+/// Fixed slot count of a `ContractCache`'s backing table. Small on
+/// purpose: the point is to catch the handful of vector pairs an
+/// iterative lattice sweep revisits, not to memoize an unbounded history.
+const CONTRACT_CACHE_CAPACITY: usize = 16;
+
+/// Lookup key for a memoized `MetricTensor::contract` result: the bit
+/// patterns of both input vectors plus the metric's timestamp at the
+/// time of contraction, so a `quantize`/`realign` mutation (which bumps
+/// the timestamp) naturally misses rather than returning a stale value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ContractKey {
+    v1: [u64; 4],
+    v2: [u64; 4],
+    timestamp: usize,
+}
+
+impl ContractKey {
+    fn new(v1: &Vector4D<f64>, v2: &Vector4D<f64>, timestamp: usize) -> Self {
+        Self {
+            v1: [v1.t.to_bits(), v1.x.to_bits(), v1.y.to_bits(), v1.z.to_bits()],
+            v2: [v2.t.to_bits(), v2.x.to_bits(), v2.y.to_bits(), v2.z.to_bits()],
+            timestamp,
+        }
+    }
+
+    /// Splitmix64-style avalanche over every bit-pattern field, used only
+    /// to pick this key's initial probe index into `ContractCache`'s
+    /// slot table; exact matches are still verified field-by-field.
+    fn hash(&self) -> u64 {
+        let mut z = self.timestamp as u64;
+        for word in self.v1.iter().chain(self.v2.iter()) {
+            z ^= word.wrapping_mul(0x9E37_79B9_7F4A_7C15);
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            z ^= z >> 31;
+        }
+        z
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ContractCacheSlot {
+    key: ContractKey,
+    value: f64,
+    /// Clock (second-chance) reference bit: set on every hit, cleared the
+    /// first time the clock hand sweeps past it without evicting it.
+    referenced: bool,
+}
+
+/// Fixed-capacity memoization table for `MetricTensor::contract`, keyed
+/// on `ContractKey` (both input vectors' bit patterns plus the metric's
+/// timestamp).
+///
+/// Open-addressed with linear probing from `ContractKey::hash`, falling
+/// back to clock eviction when a key's whole probe sequence is occupied
+/// by other entries.
+#[derive(Debug, Clone)]
+pub struct ContractCache {
+    slots: [Option<ContractCacheSlot>; CONTRACT_CACHE_CAPACITY],
+    clock_hand: usize,
+}
+
+impl ContractCache {
+    /// An empty cache with the table's fixed capacity.
+    pub fn new() -> Self {
+        Self {
+            slots: [None; CONTRACT_CACHE_CAPACITY],
+            clock_hand: 0,
+        }
+    }
+
+    /// Memoized `contract(v1, v2)` result at `timestamp`, if still cached.
+    fn get(&mut self, v1: &Vector4D<f64>, v2: &Vector4D<f64>, timestamp: usize) -> Option<f64> {
+        let key = ContractKey::new(v1, v2, timestamp);
+        let start = (key.hash() as usize) % CONTRACT_CACHE_CAPACITY;
+
+        for offset in 0..CONTRACT_CACHE_CAPACITY {
+            let index = (start + offset) % CONTRACT_CACHE_CAPACITY;
+            match &mut self.slots[index] {
+                Some(slot) if slot.key == key => {
+                    slot.referenced = true;
+                    return Some(slot.value);
+                }
+                Some(_) => continue,
+                None => return None,
+            }
+        }
+        None
+    }
+
+    /// Records `contract(v1, v2)`'s result at `timestamp`, evicting an
+    /// unreferenced entry via the clock hand if every slot along this
+    /// key's probe sequence is occupied by a different key.
+    fn insert(&mut self, v1: &Vector4D<f64>, v2: &Vector4D<f64>, timestamp: usize, value: f64) {
+        let key = ContractKey::new(v1, v2, timestamp);
+        let start = (key.hash() as usize) % CONTRACT_CACHE_CAPACITY;
+
+        for offset in 0..CONTRACT_CACHE_CAPACITY {
+            let index = (start + offset) % CONTRACT_CACHE_CAPACITY;
+            match &mut self.slots[index] {
+                Some(slot) if slot.key == key => {
+                    slot.value = value;
+                    slot.referenced = true;
+                    return;
+                }
+                None => {
+                    self.slots[index] = Some(ContractCacheSlot { key, value, referenced: true });
+                    return;
+                }
+                Some(_) => continue,
+            }
+        }
+
+        loop {
+            let index = self.clock_hand;
+            self.clock_hand = (self.clock_hand + 1) % CONTRACT_CACHE_CAPACITY;
+
+            match &mut self.slots[index] {
+                Some(slot) if !slot.referenced => {
+                    *slot = ContractCacheSlot { key, value, referenced: true };
+                    return;
+                }
+                Some(slot) => slot.referenced = false,
+                None => {
+                    self.slots[index] = Some(ContractCacheSlot { key, value, referenced: true });
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Drops every cached entry.
+    pub fn invalidate(&mut self) {
+        self.slots = [None; CONTRACT_CACHE_CAPACITY];
+        self.clock_hand = 0;
+    }
+}
+
+impl Default for ContractCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Metric tensor for spacetime calculations
 #[derive(Debug)]
 pub struct MetricTensor {
-    components: [[f64; 4]; 4],
+    components: ComponentStore,
     timestamp: Helium<usize>,
     aligned_space: Box<AlignedSpace>, // Use Box to break the recursive type
 }
@@ -80,12 +317,18 @@ impl Clone for MetricTensor {
 }
 
 impl MetricTensor {
+    /// Create the Minkowski metric at full precision
     pub fn minkowski() -> Self {
-        let mut components = [[0.0; 4]; 4];
-        components[0][0] = -1.0; // Time component
-        components[1][1] = 1.0;  // Spatial components
-        components[2][2] = 1.0;
-        components[3][3] = 1.0;
+        Self::minkowski_with_precision(Precision::default())
+    }
+
+    /// Create the Minkowski metric, storing its components at the given precision
+    pub fn minkowski_with_precision(precision: Precision) -> Self {
+        let mut matrix = [[0.0; 4]; 4];
+        matrix[0][0] = -1.0; // Time component
+        matrix[1][1] = 1.0;  // Spatial components
+        matrix[2][2] = 1.0;
+        matrix[3][3] = 1.0;
 
         let alignment = vector_align();
         let aligned_space = Box::new(AlignedSpace::new(
@@ -95,12 +338,17 @@ impl MetricTensor {
         ));
 
         Self {
-            components,
+            components: ComponentStore::new(precision, matrix),
             timestamp: Helium::new(MESH_TIMESTAMP),
             aligned_space,
         }
     }
 
+    /// Get the precision this tensor stores its components at
+    pub fn precision(&self) -> Precision {
+        self.components.precision()
+    }
+
     pub fn contract(&mut self, v1: &Vector4D<f64>, v2: &Vector4D<f64>) -> f64 {
         self.timestamp.store(MESH_TIMESTAMP, Ordering::SeqCst);
         self.aligned_space.decay_coherence();
@@ -115,10 +363,11 @@ impl MetricTensor {
         let v1_components = [v1.t, v1.x, v1.y, v1.z];
         let v2_components = [v2.t, v2.x, v2.y, v2.z];
 
+        let components = self.components.get();
         let mut result = 0.0;
         for i in 0..4 {
             for j in 0..4 {
-                result += self.components[i][j] * v1_components[i] * v2_components[j];
+                result += components[i][j] * v1_components[i] * v2_components[j];
             }
         }
 
@@ -129,6 +378,27 @@ impl MetricTensor {
         result
     }
 
+    /// Memoized variant of `contract`: returns the cached result if `v1`,
+    /// `v2` and this tensor's timestamp match a prior call recorded in
+    /// `cache`, otherwise contracts and records the result. A `quantize`
+    /// or `realign` call bumps the timestamp, so stale entries from
+    /// before that mutation simply miss rather than being served.
+    pub fn contract_cached(
+        &mut self,
+        v1: &Vector4D<f64>,
+        v2: &Vector4D<f64>,
+        cache: &mut ContractCache,
+    ) -> f64 {
+        let timestamp = self.get_timestamp();
+        if let Some(value) = cache.get(v1, v2, timestamp) {
+            return value;
+        }
+
+        let result = self.contract(v1, v2);
+        cache.insert(v1, v2, self.get_timestamp(), result);
+        result
+    }
+
     pub fn get_timestamp(&self) -> usize {
         self.timestamp.quantum_load(Ordering::SeqCst).0
     }
@@ -165,13 +435,15 @@ impl MetricTensor {
             return;
         }
 
+        let mut matrix = self.components.get();
         for i in 0..4 {
             for j in 0..4 {
-                self.components[i][j] = libm::floor(
-                    self.components[i][j] / PLANCK_LENGTH + 0.5
+                matrix[i][j] = libm::floor(
+                    matrix[i][j] / PLANCK_LENGTH + 0.5
                 ) * PLANCK_LENGTH;
             }
         }
+        self.components.set(matrix);
 
         self.reset_coherence();
         self.timestamp.store(MESH_TIMESTAMP, Ordering::SeqCst);
@@ -180,6 +452,191 @@ impl MetricTensor {
     pub fn realign(&mut self) {
         self.aligned_space.realign();
     }
+
+    /// Principal square root of this (symmetric) metric: a `MetricTensor`
+    /// whose square, under matrix multiplication, is `self`.
+    ///
+    /// Diagonalizes `components` via a cyclic Jacobi rotation sweep, then
+    /// rebuilds `V · diag(sqrt(λ_i)) · Vᵀ`. Fails with
+    /// `MathError::ComplexDomain` if any eigenvalue is negative, since the
+    /// Minkowski time eigenvalue always is — use `signed_sqrt` instead for
+    /// pseudo-Riemannian metrics where that's expected.
+    pub fn sqrt(&self) -> Result<Self, MathError> {
+        let (eigenvalues, eigenvectors) = self.jacobi_eigen();
+
+        if eigenvalues.iter().any(|&lambda| lambda < 0.0) {
+            return Err(MathError::ComplexDomain);
+        }
+
+        Ok(self.rebuild_from_eigen(&eigenvalues, &eigenvectors, |lambda| libm::sqrt(lambda)))
+    }
+
+    /// Pseudo-Riemannian variant of `sqrt` for metrics with mixed-sign
+    /// eigenvalues (e.g. Minkowski): takes `sqrt(|λ_i|)` and keeps λ_i's
+    /// original sign, rather than failing on the negative time eigenvalue.
+    pub fn signed_sqrt(&self) -> Self {
+        let (eigenvalues, eigenvectors) = self.jacobi_eigen();
+
+        self.rebuild_from_eigen(&eigenvalues, &eigenvectors, |lambda| {
+            libm::sqrt(libm::fabs(lambda)) * if lambda < 0.0 { -1.0 } else { 1.0 }
+        })
+    }
+
+    /// The inverse metric `g^{-1}`, via the same Jacobi eigendecomposition
+    /// `sqrt`/`signed_sqrt` use: `V · diag(1/λ_i) · Vᵀ`. Fails with
+    /// `MathError::DivisionByZero` if any eigenvalue is within
+    /// `JACOBI_CONVERGENCE_THRESHOLD` of zero, since the metric is then
+    /// numerically degenerate and has no well-defined inverse.
+    pub fn inverse(&self) -> Result<Self, MathError> {
+        let (eigenvalues, eigenvectors) = self.jacobi_eigen();
+
+        if eigenvalues.iter().any(|&lambda| libm::fabs(lambda) < JACOBI_CONVERGENCE_THRESHOLD) {
+            return Err(MathError::DivisionByZero);
+        }
+
+        Ok(self.rebuild_from_eigen(&eigenvalues, &eigenvectors, |lambda| 1.0 / lambda))
+    }
+
+    /// Christoffel symbols of the second kind,
+    /// `Γ^a_{bc} = ½ g^{ad}(∂_b g_{dc} + ∂_c g_{db} - ∂_d g_{bc})`, built
+    /// from this metric's inverse and caller-supplied coordinate
+    /// derivatives `d_metric[k][i][j] = ∂_k g_{ij}` (e.g. finite-differenced
+    /// across neighboring `MetricTensor`s along a worldline). Returns
+    /// `christoffel[a][b][c]`.
+    pub fn christoffel(&self, d_metric: &[[[f64; 4]; 4]; 4]) -> Result<[[[f64; 4]; 4]; 4], MathError> {
+        let inverse_components = self.inverse()?.components.get();
+
+        let mut result = [[[0.0; 4]; 4]; 4];
+        for a in 0..4 {
+            for b in 0..4 {
+                for c in 0..4 {
+                    let mut sum = 0.0;
+                    for d in 0..4 {
+                        sum += inverse_components[a][d]
+                            * (d_metric[b][d][c] + d_metric[c][d][b] - d_metric[d][b][c]);
+                    }
+                    result[a][b][c] = 0.5 * sum;
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    /// Diagonalize the symmetric `components` matrix with a cyclic Jacobi
+    /// rotation sweep: repeatedly zero out the largest off-diagonal
+    /// element by rotating its row/column pair, accumulating the
+    /// rotations into an eigenvector matrix `V`, until the sum of squared
+    /// off-diagonals drops below `JACOBI_CONVERGENCE_THRESHOLD`.
+    ///
+    /// Returns `(eigenvalues, V)`, where `V`'s columns are the
+    /// eigenvectors corresponding to each eigenvalue.
+    fn jacobi_eigen(&self) -> ([f64; 4], [[f64; 4]; 4]) {
+        let mut a = self.components.get();
+        let mut v = [[0.0; 4]; 4];
+        for i in 0..4 {
+            v[i][i] = 1.0;
+        }
+
+        for _ in 0..JACOBI_MAX_SWEEPS {
+            let (p, q, off_diagonal_sq) = Self::largest_off_diagonal(&a);
+            if off_diagonal_sq < JACOBI_CONVERGENCE_THRESHOLD {
+                break;
+            }
+
+            let theta = 0.5 * libm::atan2(2.0 * a[p][q], a[q][q] - a[p][p]);
+            let (sin, cos) = (libm::sin(theta), libm::cos(theta));
+            Self::apply_givens_rotation(&mut a, p, q, sin, cos);
+            Self::apply_givens_rotation_to_vectors(&mut v, p, q, sin, cos);
+        }
+
+        let eigenvalues = [a[0][0], a[1][1], a[2][2], a[3][3]];
+        (eigenvalues, v)
+    }
+
+    /// Find the off-diagonal element with the largest magnitude, along
+    /// with the sum of squares of every off-diagonal element (the
+    /// convergence measure for the Jacobi sweep).
+    fn largest_off_diagonal(a: &[[f64; 4]; 4]) -> (usize, usize, f64) {
+        let (mut p, mut q, mut largest) = (0, 1, 0.0);
+        let mut sum_sq = 0.0;
+
+        for i in 0..4 {
+            for j in (i + 1)..4 {
+                sum_sq += a[i][j] * a[i][j] * 2.0;
+                if libm::fabs(a[i][j]) > largest {
+                    largest = libm::fabs(a[i][j]);
+                    p = i;
+                    q = j;
+                }
+            }
+        }
+
+        (p, q, sum_sq)
+    }
+
+    /// Apply the Givens rotation by `(sin, cos)` in the `(p, q)` plane to
+    /// both sides of the symmetric matrix `a`.
+    fn apply_givens_rotation(a: &mut [[f64; 4]; 4], p: usize, q: usize, sin: f64, cos: f64) {
+        let mut rotated = *a;
+
+        for i in 0..4 {
+            rotated[i][p] = cos * a[i][p] - sin * a[i][q];
+            rotated[i][q] = sin * a[i][p] + cos * a[i][q];
+        }
+        *a = rotated;
+
+        let mut rotated = *a;
+        for j in 0..4 {
+            rotated[p][j] = cos * a[p][j] - sin * a[q][j];
+            rotated[q][j] = sin * a[p][j] + cos * a[q][j];
+        }
+        *a = rotated;
+    }
+
+    /// Accumulate the same Givens rotation into the eigenvector matrix `v`.
+    fn apply_givens_rotation_to_vectors(v: &mut [[f64; 4]; 4], p: usize, q: usize, sin: f64, cos: f64) {
+        let mut rotated = *v;
+        for i in 0..4 {
+            rotated[i][p] = cos * v[i][p] - sin * v[i][q];
+            rotated[i][q] = sin * v[i][p] + cos * v[i][q];
+        }
+        *v = rotated;
+    }
+
+    /// Rebuild `V · diag(f(λ_i)) · Vᵀ` into a new `MetricTensor`,
+    /// preserving `self`'s coherence/timestamp bookkeeping (decayed, to
+    /// reflect the derived tensor being one step removed from `self`).
+    fn rebuild_from_eigen(
+        &self,
+        eigenvalues: &[f64; 4],
+        eigenvectors: &[[f64; 4]; 4],
+        f: impl Fn(f64) -> f64,
+    ) -> Self {
+        let mut matrix = [[0.0; 4]; 4];
+        for i in 0..4 {
+            for j in 0..4 {
+                let mut sum = 0.0;
+                for k in 0..4 {
+                    sum += eigenvectors[i][k] * f(eigenvalues[k]) * eigenvectors[j][k];
+                }
+                matrix[i][j] = sum;
+            }
+        }
+
+        let alignment = vector_align();
+        let mut aligned_space = Box::new(AlignedSpace::new(
+            MESH_TIMESTAMP,
+            core::mem::size_of::<f64>() * 16,
+            alignment,
+        ));
+        aligned_space.decay_coherence();
+
+        Self {
+            components: ComponentStore::new(self.components.precision(), matrix),
+            timestamp: Helium::new(self.timestamp.quantum_load(Ordering::SeqCst).0),
+            aligned_space,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -234,6 +691,97 @@ mod tests {
         assert!(metric.get_coherence() > 0.0);
     }
 
+    #[test]
+    fn test_minkowski_sqrt_is_complex_domain() {
+        let metric = MetricTensor::minkowski();
+        assert!(matches!(metric.sqrt(), Err(MathError::ComplexDomain)));
+    }
+
+    #[test]
+    fn test_minkowski_signed_sqrt_squares_back_to_minkowski() {
+        let metric = MetricTensor::minkowski();
+        let root = metric.signed_sqrt();
+
+        let root_components = root.components.get();
+        let metric_components = metric.components.get();
+        for i in 0..4 {
+            for j in 0..4 {
+                let mut sum = 0.0;
+                for k in 0..4 {
+                    sum += root_components[i][k] * root_components[k][j];
+                }
+                assert!((sum - metric_components[i][j]).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_identity_metric_sqrt_is_itself() {
+        let mut metric = MetricTensor::minkowski();
+        let mut identity = [[0.0; 4]; 4];
+        for i in 0..4 {
+            identity[i][i] = 1.0;
+        }
+        metric.components.set(identity);
+
+        let root = metric.sqrt().unwrap();
+        let root_components = root.components.get();
+        for i in 0..4 {
+            for j in 0..4 {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((root_components[i][j] - expected).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_mixed_precision_sqrt_matches_full_within_rounding() {
+        let full = MetricTensor::minkowski_with_precision(Precision::Full);
+        let mixed = MetricTensor::minkowski_with_precision(Precision::Mixed);
+        assert_eq!(full.precision(), Precision::Full);
+        assert_eq!(mixed.precision(), Precision::Mixed);
+
+        let full_root = full.signed_sqrt().components.get();
+        let mixed_root = mixed.signed_sqrt().components.get();
+        for i in 0..4 {
+            for j in 0..4 {
+                assert!((full_root[i][j] - mixed_root[i][j]).abs() < 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn test_minkowski_inverse_is_itself() {
+        // Minkowski's diagonal entries are all ±1, so it's its own inverse.
+        let metric = MetricTensor::minkowski();
+        let inverse = metric.inverse().unwrap();
+
+        let metric_components = metric.components.get();
+        let inverse_components = inverse.components.get();
+        for i in 0..4 {
+            for j in 0..4 {
+                assert!((inverse_components[i][j] - metric_components[i][j]).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_christoffel_vanishes_for_constant_minkowski() {
+        // A constant metric has zero coordinate derivatives, so every
+        // Christoffel symbol built from it must vanish.
+        let metric = MetricTensor::minkowski();
+        let d_metric = [[[0.0; 4]; 4]; 4];
+
+        let christoffel = metric.christoffel(&d_metric).unwrap();
+        for a in 0..4 {
+            for b in 0..4 {
+                for c in 0..4 {
+                    assert_eq!(christoffel[a][b][c], 0.0);
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_quantum_coherence() {
         let mut metric1 = MetricTensor::minkowski();
@@ -325,4 +873,33 @@ mod tests {
         assert!(quantized.is_quantum_stable());
         assert!(quantized.get_coherence() > 0.0);
     }
+
+    #[test]
+    fn test_contract_cached_matches_contract() {
+        let mut metric = MetricTensor::minkowski();
+        let mut cache = ContractCache::new();
+        let v1 = Vector4D::new(1.0, 2.0, 3.0, 4.0);
+        let v2 = Vector4D::new(4.0, 3.0, 2.0, 1.0);
+
+        let direct = metric.contract(&v1, &v2);
+        let cached = metric.contract_cached(&v1, &v2, &mut cache);
+        assert_eq!(direct, cached);
+
+        // Second call should hit the cache rather than recompute.
+        assert_eq!(metric.contract_cached(&v1, &v2, &mut cache), direct);
+    }
+
+    #[test]
+    fn test_contract_cache_invalidate() {
+        let mut metric = MetricTensor::minkowski();
+        let mut cache = ContractCache::new();
+        let v1 = Vector4D::new(1.0, 0.0, 0.0, 0.0);
+        let v2 = Vector4D::new(1.0, 0.0, 0.0, 0.0);
+
+        metric.contract_cached(&v1, &v2, &mut cache);
+        assert!(cache.get(&v1, &v2, metric.get_timestamp()).is_some());
+
+        cache.invalidate();
+        assert!(cache.get(&v1, &v2, metric.get_timestamp()).is_none());
+    }
 }
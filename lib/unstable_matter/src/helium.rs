@@ -6,7 +6,7 @@
 use crate::{
     constants::*,
     vector::Vector3D,
-    phantom::QuantumCell,
+    phantom::{QuantumCell, QuantumCellError},
     scribe::{Scribe, ScribePrecision, QuantumString},
 };
 
@@ -21,7 +21,17 @@ pub enum HeliumOrdering {
     Strict,
 }
 
-/// Helium quantum state container
+/// Helium quantum state container.
+///
+/// Not limited to `f64`/`usize`: `T` only needs `Clone + 'static`, so
+/// composite types like `Vector3D<f64>` or an enum such as
+/// `WormholeState` work the same way. Callers whose `T` is also `Copy`
+/// can additionally ride [`quantum_load_fast`](Self::quantum_load_fast)/
+/// [`quantum_store_fast`](Self::quantum_store_fast), a seqlock path that
+/// bypasses the pointer-swap-and-clone `quantum_load`/`quantum_store`
+/// use; [`is_lock_free`](Self::is_lock_free) reports whether `T` is small
+/// enough for that path to ride a native atomic word rather than just the
+/// seqlock retry loop.
 #[derive(Debug, Clone)]
 pub struct Helium<T: Clone + 'static> {
     /// Quantum-protected value
@@ -154,6 +164,60 @@ impl<T: Clone + 'static> Helium<T> {
         self.position.set(pos);
         self.decay_coherence();
     }
+
+    /// Whether `T` is small enough to ride a native atomic word on the
+    /// `quantum_load_fast`/`quantum_store_fast` seqlock path rather than
+    /// just its retry loop. Purely informational -- the fast path is
+    /// correct either way, this just tells callers whether it's actually
+    /// lock-free for their `T` or "only" wait-free-ish-via-retry.
+    pub const fn is_lock_free() -> bool {
+        core::mem::size_of::<T>() <= core::mem::size_of::<u64>()
+    }
+
+    /// Seqlock fast-path load: skips the pointer-swap-and-clone
+    /// `quantum_load` takes in favor of `QuantumCell`'s inline seqlock
+    /// snapshot. Doesn't touch coherence, unlike `quantum_load`.
+    pub fn quantum_load_fast(&self) -> T
+    where
+        T: Copy,
+    {
+        self.value.quantum_load_fast()
+    }
+
+    /// Seqlock fast-path store; see `quantum_load_fast`. Still decays
+    /// coherence and rotates phase like `quantum_store`, just via the
+    /// cheaper inline path.
+    pub fn quantum_store_fast(&self, value: T)
+    where
+        T: Copy,
+    {
+        self.value.quantum_store_fast(value);
+        self.decay_coherence();
+        self.rotate_phase(QUANTUM_PHASE_ROTATION);
+    }
+
+    /// Store `new` only if the current value equals `current`, preserving
+    /// `HeliumOrdering::Quantum`'s coherence requirement: a decohered cell
+    /// refuses the exchange up front rather than racing `QuantumCell`'s
+    /// own CAS loop.
+    pub fn compare_exchange(
+        &self,
+        current: &T,
+        new: T,
+        ordering: &HeliumOrdering,
+    ) -> Result<T, QuantumCellError<T>>
+    where
+        T: PartialEq,
+    {
+        if matches!(ordering, HeliumOrdering::Quantum) && !self.is_quantum_stable() {
+            return Err(QuantumCellError::PhaseMisalignment(self.value.get()));
+        }
+
+        let observed = self.value.quantum_compare_exchange(current, new, ordering)?;
+        self.decay_coherence();
+        self.rotate_phase(QUANTUM_PHASE_ROTATION);
+        Ok(observed)
+    }
 }
 
 impl<T: Scribe + Clone + 'static> Scribe for Helium<T> {
@@ -222,6 +286,35 @@ mod tests {
         assert!(helium.get_coherence() < 1.0);
     }
 
+    #[test]
+    fn test_is_lock_free() {
+        assert!(Helium::<f64>::is_lock_free());
+        assert!(Helium::<usize>::is_lock_free());
+        assert!(!Helium::<Vector3D<f64>>::is_lock_free());
+    }
+
+    #[test]
+    fn test_fast_path_round_trip() {
+        let helium = Helium::new(1.0_f64);
+        helium.quantum_store_fast(2.0);
+        assert_eq!(helium.quantum_load_fast(), 2.0);
+    }
+
+    #[test]
+    fn test_compare_exchange() {
+        let helium = Helium::new(42);
+
+        let observed = helium
+            .compare_exchange(&42, 84, &HeliumOrdering::Quantum)
+            .unwrap();
+        assert_eq!(observed, 42);
+        assert_eq!(helium.get(), 84);
+
+        assert!(helium
+            .compare_exchange(&42, 100, &HeliumOrdering::Quantum)
+            .is_err());
+    }
+
     #[test]
     fn test_quantum_scribing() {
         let helium = Helium::new(42);
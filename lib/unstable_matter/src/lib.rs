@@ -41,6 +41,20 @@ pub mod mesh;
 pub mod glitch;
 pub mod grav;
 pub mod scribe;
+pub(crate) mod cube;
+pub mod contour_eigen;
+pub mod geodesic;
+pub mod constants;
+pub mod quantum;
+// `scribble` depends on `phantom::{QuantumCell, Protected}` directly, so
+// it has to be `pub`, not `pub(crate)`.
+pub mod phantom;
+pub mod helium;
+pub mod wormhole;
+pub mod mesh_clock;
+pub mod morph_tracker;
+pub mod tracked_ufo;
+pub mod spacemap;
 
 // Internal imports
 use crate::{
@@ -154,7 +168,7 @@ pub use {
     harmony::{HarmonicPattern, HarmonicState, HarmonyError},
     mesh::MeshPattern,
     align::Alignment,
-    zeronaut::Zeronaut,
+    zeronaut::{Zeronaut, EssenceArena, EssenceHandle},
     vector::Vector3D,
     sun_rise::{Sun_rise, sun_rise, sun_rise_quantum},
     scribe::{Scribe, ScribePrecision, QuantumString},
@@ -12,8 +12,9 @@ use crate::{
     BlackHole,
     Wormhole,
     WormholeGlitch,
-    helium::Helium,
+    helium::{Helium, HeliumOrdering},
     meshmath::MeshMath,
+    mesh_clock::DensityMatrix,
 };
 
 #[derive(Debug, Clone)]
@@ -71,7 +72,7 @@ pub struct MeshCell {
     position: QuantumCell<Vector3D<f64>>,
     mass: Helium<f64>,
     state: QuantumCell<CellState>,
-    coherence: Helium<f64>,
+    density: Helium<DensityMatrix>,
     timestamp: Helium<u64>,
     wormhole_connection: Option<Wormhole>,
 }
@@ -82,7 +83,7 @@ impl MeshCell {
             position: QuantumCell::new(position),
             mass: Helium::new(1.0),
             state: QuantumCell::new(CellState::Free),
-            coherence: Helium::new(1.0),
+            density: Helium::new(DensityMatrix::coherent()),
             timestamp: Helium::new(CURRENT_TIMESTAMP.try_into().unwrap()),
             wormhole_connection: None,
         }
@@ -165,9 +166,24 @@ impl MeshCell {
         self.state.get()
     }
 
+    /// Thin view over the cell's `DensityMatrix`: `2*|rho01|`.
     pub fn get_coherence(&self) -> f64 {
-        self.coherence.quantum_load(&HeliumOrdering::Quantum)
-        .expect("Failed to load coherence")
+        self.density.quantum_load(&HeliumOrdering::Quantum)
+        .expect("Failed to load density matrix")
+        .coherence()
+    }
+
+    /// `Tr(rho^2)` of the cell's density matrix -- 1.0 for a pure state.
+    pub fn purity(&self) -> f64 {
+        self.density.quantum_load(&HeliumOrdering::Quantum)
+        .expect("Failed to load density matrix")
+        .purity()
+    }
+
+    /// The cell's raw density matrix, e.g. for `MeshClock::cell_fidelity`.
+    pub fn get_density(&self) -> DensityMatrix {
+        self.density.quantum_load(&HeliumOrdering::Quantum)
+        .expect("Failed to load density matrix")
     }
 
     pub fn is_quantum_stable(&self) -> bool {
@@ -175,11 +191,11 @@ impl MeshCell {
     }
 
     fn decay_coherence(&self) {
-        let current = self.coherence.quantum_load(&HeliumOrdering::Quantum)
-        .expect("Failed to load coherence");
-        let new_coherence = current.mesh_mul(COHERENCE_DECAY_FACTOR);
-        self.coherence.quantum_store(new_coherence, &HeliumOrdering::Quantum)
-        .expect("Failed to store coherence");
+        let mut current = self.density.quantum_load(&HeliumOrdering::Quantum)
+        .expect("Failed to load density matrix");
+        current.decay(COHERENCE_DECAY_FACTOR);
+        self.density.quantum_store(current, &HeliumOrdering::Quantum)
+        .expect("Failed to store density matrix");
     }
 }
 
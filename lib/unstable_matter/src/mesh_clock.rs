@@ -11,8 +11,728 @@ use crate::{
     constants::{CURRENT_TIMESTAMP, GRAVITATIONAL_CONSTANT, QUANTUM_COHERENCE_THRESHOLD},
 };
 
+use std::collections::BTreeMap;
+
+use num_complex::Complex64;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
 const MESH_VECTOR_ALIGN: usize = 16;
 const QUANTUM_COHERENCE_THRESHOLD: f64 = 0.5;
+const MCWF_TIME_STEP: f64 = 1.0;
+const PATTERN_CONVERGENCE_TOLERANCE: f64 = 0.9;
+const REVERSIBLE_OSCILLATOR_OMEGA: f64 = 1.0;
+/// Cap on `MeshClock::interval_history`'s length; `record_interval_sample`
+/// drops the oldest entry once the buffer grows past this so spectrum
+/// analysis always runs over a bounded, recent window.
+const SPECTRUM_HISTORY_CAPACITY: usize = 64;
+/// Qubit count for `MeshClock::register`: one qubit per `MeshCell`
+/// (`alpha_cell`, `omega_cell`).
+const MESH_CLOCK_QUBIT_COUNT: usize = 2;
+/// Default `T1` (nanoseconds, matching `measured_interval`'s units) for
+/// `MeshClock::relaxation_time` until `set_decoherence_times` is called.
+const DEFAULT_RELAXATION_TIME: f64 = 5000.0;
+/// Default `T2` for `MeshClock::dephasing_time`.
+const DEFAULT_DEPHASING_TIME: f64 = 3000.0;
+
+/// A 2x2 Hermitian density matrix rho, stored as its complex entries:
+/// the diagonal populations `rho00`/`rho11` and the off-diagonal
+/// coherence `rho01` (Hermiticity means `rho10 = rho01.conj()`, so it
+/// isn't stored separately). Replaces a bare `Helium<f64>` coherence
+/// scalar as the source of truth for `MeshCell`/`MeshClock`'s quantum
+/// state -- `get_coherence()` and friends are thin views over it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DensityMatrix {
+    rho00: Complex64,
+    rho01: Complex64,
+    rho11: Complex64,
+}
+
+impl DensityMatrix {
+    /// Maximally coherent pure state: even populations, full
+    /// off-diagonal coherence.
+    pub fn coherent() -> Self {
+        Self {
+            rho00: Complex64::new(0.5, 0.0),
+            rho01: Complex64::new(0.5, 0.0),
+            rho11: Complex64::new(0.5, 0.0),
+        }
+    }
+
+    /// Even populations with the off-diagonal coherence set so that
+    /// `coherence()` reports exactly `coherence` (clamped to `[0, 1]`).
+    /// Lets call sites that used to store a raw `f64` keep doing so
+    /// through the density-matrix representation.
+    pub fn with_coherence(coherence: f64) -> Self {
+        let magnitude = coherence.max(0.0).min(1.0) / 2.0;
+        Self {
+            rho00: Complex64::new(0.5, 0.0),
+            rho01: Complex64::new(magnitude, 0.0),
+            rho11: Complex64::new(0.5, 0.0),
+        }
+    }
+
+    pub fn trace(&self) -> Complex64 {
+        self.rho00 + self.rho11
+    }
+
+    pub fn determinant(&self) -> Complex64 {
+        self.rho00 * self.rho11 - self.rho01 * self.rho01.conj()
+    }
+
+    /// Scalar coherence measure matching the old `Helium<f64>` field:
+    /// `2*|rho01|`, 1.0 for a maximally coherent state and 0.0 once the
+    /// off-diagonal coherence has fully decayed.
+    pub fn coherence(&self) -> f64 {
+        2.0 * self.rho01.norm()
+    }
+
+    /// `Tr(rho^2) = rho00^2 + rho11^2 + 2|rho01|^2` -- 1.0 for a pure
+    /// state, 0.5 for a maximally mixed one.
+    pub fn purity(&self) -> f64 {
+        self.rho00.re * self.rho00.re + self.rho11.re * self.rho11.re + 2.0 * self.rho01.norm_sqr()
+    }
+
+    /// Exponential damping of the off-diagonal element, replacing the
+    /// old `coherence *= factor` float scaling -- populations are left
+    /// untouched, only the coherence term decays.
+    pub fn decay(&mut self, factor: f64) {
+        self.rho01 *= factor;
+    }
+
+    /// T1/T2 relaxation: amplitude damping with probability
+    /// `p1 = 1 - exp(-dt/t1)` moves population from `rho11` toward
+    /// `rho00` and shrinks the coherence by `sqrt(1 - p1)`, then pure
+    /// dephasing multiplies the remaining coherence by `exp(-dt/t2)`.
+    /// Replaces the fixed per-call multipliers `apply_quantum_effects`
+    /// used to use (`0.999`, `0.998`, `0.995`, ...) with the standard
+    /// open-system decoherence channel, parameterized by the physical
+    /// relaxation time `t1` and dephasing time `t2`.
+    pub fn apply_decoherence(&mut self, t1: f64, t2: f64, dt: f64) {
+        let p1 = 1.0 - (-dt / t1).exp();
+        let dephasing = (-dt / t2).exp();
+
+        self.rho00 += self.rho11 * p1;
+        self.rho11 *= 1.0 - p1;
+        self.rho01 *= (1.0 - p1).sqrt() * dephasing;
+    }
+
+    /// Closed-form Hermitian 2x2 matrix square root: eigenvalues
+    /// `lambda = (t +- sqrt(t^2 - 4d)) / 2` with `t = trace`, `d = det`,
+    /// then reconstruct `U * diag(sqrt(lambda)) * U^dagger`.
+    pub fn sqrt(&self) -> Self {
+        let t = self.trace().re;
+        let d = self.determinant().re;
+        let discriminant = (t * t - 4.0 * d).max(0.0).sqrt();
+        let lambda0 = ((t + discriminant) / 2.0).max(0.0);
+        let lambda1 = ((t - discriminant) / 2.0).max(0.0);
+
+        let a = self.rho00.re;
+        let b = self.rho01;
+
+        if b.norm() < 1e-15 {
+            // Already diagonal -- it's its own eigenbasis.
+            return Self {
+                rho00: Complex64::new(lambda0.sqrt(), 0.0),
+                rho01: Complex64::new(0.0, 0.0),
+                rho11: Complex64::new(lambda1.sqrt(), 0.0),
+            };
+        }
+
+        // Eigenvectors of a Hermitian [[a, b], [b*, c]] with b != 0:
+        // v_lambda = (b, lambda - a), normalized.
+        let v0 = (b, Complex64::new(lambda0 - a, 0.0));
+        let norm0 = (v0.0.norm_sqr() + v0.1.norm_sqr()).sqrt();
+        let (u00, u10) = (v0.0 / norm0, v0.1 / norm0);
+
+        let v1 = (b, Complex64::new(lambda1 - a, 0.0));
+        let norm1 = (v1.0.norm_sqr() + v1.1.norm_sqr()).sqrt();
+        let (u01, u11) = (v1.0 / norm1, v1.1 / norm1);
+
+        let (s0, s1) = (lambda0.sqrt(), lambda1.sqrt());
+
+        Self {
+            rho00: u00 * s0 * u00.conj() + u01 * s1 * u01.conj(),
+            rho01: u00 * s0 * u10.conj() + u01 * s1 * u11.conj(),
+            rho11: u10 * s0 * u10.conj() + u11 * s1 * u11.conj(),
+        }
+    }
+
+    /// Projects onto the populations named in `indices` (valid indices
+    /// are `0` and `1`), dropping coherence with any excluded component
+    /// and collapsing to that basis state. `None`, or a set naming both
+    /// indices, returns the state unchanged.
+    pub fn restrict(&self, indices: Option<&[usize]>) -> Self {
+        let indices = match indices {
+            None => return *self,
+            Some(indices) => indices,
+        };
+        let (keep0, keep1) = (indices.contains(&0), indices.contains(&1));
+        match (keep0, keep1) {
+            (true, true) => *self,
+            (true, false) => Self {
+                rho00: Complex64::new(1.0, 0.0),
+                rho01: Complex64::new(0.0, 0.0),
+                rho11: Complex64::new(0.0, 0.0),
+            },
+            (false, true) => Self {
+                rho00: Complex64::new(0.0, 0.0),
+                rho01: Complex64::new(0.0, 0.0),
+                rho11: Complex64::new(1.0, 0.0),
+            },
+            (false, false) => Self::default(),
+        }
+    }
+
+    /// Uhlmann fidelity `F(rho, sigma) = (Tr sqrt(sqrt(rho) * sigma * sqrt(rho)))^2`,
+    /// optionally restricted to a chosen subspace first (see `restrict`)
+    /// so callers can compare e.g. only the entanglement-relevant
+    /// component of each state.
+    pub fn fidelity(&self, other: &DensityMatrix, subspace: Option<&[usize]>) -> f64 {
+        let this = self.restrict(subspace);
+        let other = other.restrict(subspace);
+
+        let trace_sqrt = sandwich(&this.sqrt(), &other).sqrt().trace().re;
+        trace_sqrt * trace_sqrt
+    }
+}
+
+impl Default for DensityMatrix {
+    fn default() -> Self {
+        Self::coherent()
+    }
+}
+
+/// `A * B * A` for Hermitian 2x2 `a`, `b` -- itself Hermitian, so it's
+/// returned as another `DensityMatrix` (not necessarily trace-1). Used
+/// by `DensityMatrix::fidelity` to build `sqrt(rho) * sigma * sqrt(rho)`.
+fn sandwich(a: &DensityMatrix, b: &DensityMatrix) -> DensityMatrix {
+    let ab00 = a.rho00 * b.rho00 + a.rho01 * b.rho01.conj();
+    let ab01 = a.rho00 * b.rho01 + a.rho01 * b.rho11;
+    let ab10 = a.rho01.conj() * b.rho00 + a.rho11 * b.rho01.conj();
+    let ab11 = a.rho01.conj() * b.rho01 + a.rho11 * b.rho11;
+
+    DensityMatrix {
+        rho00: ab00 * a.rho00 + ab01 * a.rho01.conj(),
+        rho01: ab00 * a.rho01 + ab01 * a.rho11,
+        rho11: ab10 * a.rho01 + ab11 * a.rho11,
+    }
+}
+
+/// Normalized agreement between two raw `[u8; 32]` quantum signatures,
+/// over either every byte or just `subspace`'s indices: `1.0` for an
+/// exact match on the compared bytes, `0.0` for maximum divergence
+/// (`0` vs `255`) on every one of them.
+fn signature_agreement(a: &[u8; 32], b: &[u8; 32], subspace: Option<&[usize]>) -> f64 {
+    let selected: Vec<usize> = match subspace {
+        Some(indices) => indices.to_vec(),
+        None => (0..32).collect(),
+    };
+    if selected.is_empty() {
+        return 1.0;
+    }
+
+    let agreement: f64 = selected
+        .iter()
+        .map(|&i| 1.0 - (a[i] as f64 - b[i] as f64).abs() / 255.0)
+        .sum();
+    agreement / selected.len() as f64
+}
+
+/// Two-time correlation `C(tau) = <x(t) * x(t+tau)>`, averaged over the
+/// overlapping window at each lag, for `tau` in `0..samples.len()`. This
+/// is the series `MeshClock::power_spectrum` feeds into `fft_radix2`.
+fn autocorrelation(samples: &[f64]) -> Vec<f64> {
+    let n = samples.len();
+    let mut out = Vec::with_capacity(n);
+    for lag in 0..n {
+        let window = n - lag;
+        let sum: f64 = (0..window).map(|t| samples[t] * samples[t + lag]).sum();
+        out.push(sum / window as f64);
+    }
+    out
+}
+
+/// Reorders `input` by bit-reversed index, the standard first step of an
+/// in-place radix-2 FFT. `input.len()` must be a power of two.
+fn bit_reverse_copy(input: &[Complex64]) -> Vec<Complex64> {
+    let n = input.len();
+    let bits = n.trailing_zeros();
+    let mut out = vec![Complex64::new(0.0, 0.0); n];
+    for (i, value) in input.iter().enumerate() {
+        let reversed = (i as u32).reverse_bits() >> (32 - bits);
+        out[reversed as usize] = *value;
+    }
+    out
+}
+
+/// In-place radix-2 Cooley-Tukey FFT. `input.len()` must be a power of
+/// two (callers pad/truncate to the nearest one first).
+fn fft_radix2(input: &[Complex64]) -> Vec<Complex64> {
+    let n = input.len();
+    if n <= 1 {
+        return input.to_vec();
+    }
+
+    let mut data = bit_reverse_copy(input);
+
+    let mut size = 2;
+    while size <= n {
+        let half = size / 2;
+        let angle = -2.0 * std::f64::consts::PI / size as f64;
+        let w_step = Complex64::new(angle.cos(), angle.sin());
+
+        for start in (0..n).step_by(size) {
+            let mut w = Complex64::new(1.0, 0.0);
+            for k in 0..half {
+                let a = data[start + k];
+                let b = data[start + k + half] * w;
+                data[start + k] = a + b;
+                data[start + k + half] = a - b;
+                w *= w_step;
+            }
+        }
+
+        size *= 2;
+    }
+
+    data
+}
+
+/// Ceiling on `QuantumRegister::new`/`with_state`/`equal_superposition`'s
+/// `qubit_count`. A register holds `2^qubit_count` `Complex64` amplitudes
+/// -- each added qubit doubles that vector, so an unchecked allocation
+/// is one typo away from trying to allocate terabytes (30 qubits alone
+/// is 16 GiB of amplitudes). Callers get an `Err` above this instead.
+const MAX_QUBIT_COUNT: usize = 24;
+
+/// An n-qubit quantum state-vector: `2^qubit_count` complex amplitudes,
+/// normalized so `sum(|amplitude|^2) == 1`, indexed by computational
+/// basis state (`amplitudes()[0]` is `|0...0>`). This is the foundation
+/// other quantum operations in the crate build on; `DensityMatrix`
+/// models one 2-level cell's mixed state, while `QuantumRegister` lets
+/// several cells interfere as a single coherent system instead of N
+/// independent scalars.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuantumRegister {
+    qubit_count: usize,
+    amplitudes: Vec<Complex64>,
+}
+
+impl QuantumRegister {
+    /// `|0...0>`: amplitude `1.0` at index 0, zero elsewhere.
+    pub fn new(qubit_count: usize) -> Result<Self, &'static str> {
+        Self::with_state(qubit_count, 0)
+    }
+
+    /// Starts in the computational basis state `basis_index` (amplitude
+    /// `1.0` at that index, zero elsewhere) instead of `|0...0>`.
+    pub fn with_state(qubit_count: usize, basis_index: usize) -> Result<Self, &'static str> {
+        if qubit_count > MAX_QUBIT_COUNT {
+            return Err("qubit count exceeds MAX_QUBIT_COUNT");
+        }
+
+        let dim = 1usize << qubit_count;
+        if basis_index >= dim {
+            return Err("basis index out of range for qubit count");
+        }
+
+        let mut amplitudes = vec![Complex64::new(0.0, 0.0); dim];
+        amplitudes[basis_index] = Complex64::new(1.0, 0.0);
+
+        Ok(Self { qubit_count, amplitudes })
+    }
+
+    /// Equal superposition of every computational basis state: each of
+    /// the `2^qubit_count` amplitudes is `1 / sqrt(2^qubit_count)`.
+    pub fn equal_superposition(qubit_count: usize) -> Result<Self, &'static str> {
+        if qubit_count > MAX_QUBIT_COUNT {
+            return Err("qubit count exceeds MAX_QUBIT_COUNT");
+        }
+
+        let dim = 1usize << qubit_count;
+        let amplitude = Complex64::new(1.0 / (dim as f64).sqrt(), 0.0);
+
+        Ok(Self { qubit_count, amplitudes: vec![amplitude; dim] })
+    }
+
+    pub fn qubit_count(&self) -> usize {
+        self.qubit_count
+    }
+
+    pub fn amplitudes(&self) -> &[Complex64] {
+        &self.amplitudes
+    }
+
+    /// Measurement probability of basis state `index`: `|amplitude|^2`.
+    /// `0.0` if `index` is out of range.
+    pub fn probability(&self, index: usize) -> f64 {
+        self.amplitudes.get(index).map(|a| a.norm_sqr()).unwrap_or(0.0)
+    }
+
+    /// `sum(|amplitude|^2)`, which should stay `1.0` under any unitary
+    /// operation -- drift away from it signals accumulated
+    /// floating-point error or a bug in whatever mutated `amplitudes`.
+    pub fn norm(&self) -> f64 {
+        self.amplitudes.iter().map(|a| a.norm_sqr()).sum()
+    }
+
+    /// Applies `gate` to `targets`, mutating `amplitudes` in place.
+    /// `H`/`X`/`Z`/`U1`/`U3` are single-qubit gates and expect exactly
+    /// one target index; `CX` expects exactly two, `[control, target]`.
+    pub fn apply(&mut self, gate: Gate, targets: &[usize]) -> Result<(), &'static str> {
+        match gate {
+            Gate::CX => match targets {
+                [control, target] => self.apply_cx(*control, *target),
+                _ => Err("CX requires exactly two targets: [control, target]"),
+            },
+            _ => match targets {
+                [qubit] => self.apply_single_qubit(*qubit, Self::matrix(gate)),
+                _ => Err("single-qubit gate requires exactly one target"),
+            },
+        }
+    }
+
+    /// Qubit `k`'s single-qubit `matrix` is applied by pairing every
+    /// index `i` with bit `k` clear against `i | (1 << k)` and replacing
+    /// `(amplitudes[i], amplitudes[i|bit])` with the matrix product.
+    fn apply_single_qubit(&mut self, qubit: usize, matrix: [[Complex64; 2]; 2]) -> Result<(), &'static str> {
+        if qubit >= self.qubit_count {
+            return Err("qubit index out of range for this register");
+        }
+
+        let bit = 1usize << qubit;
+        for i in 0..self.amplitudes.len() {
+            if i & bit == 0 {
+                let j = i | bit;
+                let a0 = self.amplitudes[i];
+                let a1 = self.amplitudes[j];
+                self.amplitudes[i] = matrix[0][0] * a0 + matrix[0][1] * a1;
+                self.amplitudes[j] = matrix[1][0] * a0 + matrix[1][1] * a1;
+            }
+        }
+        Ok(())
+    }
+
+    /// Flips `target`'s bit on every amplitude whose `control` bit is
+    /// set, by swapping the two amplitudes that differ only in that bit.
+    fn apply_cx(&mut self, control: usize, target: usize) -> Result<(), &'static str> {
+        if control >= self.qubit_count || target >= self.qubit_count {
+            return Err("qubit index out of range for this register");
+        }
+        if control == target {
+            return Err("CX control and target must be different qubits");
+        }
+
+        let control_bit = 1usize << control;
+        let target_bit = 1usize << target;
+        for i in 0..self.amplitudes.len() {
+            if i & control_bit != 0 && i & target_bit == 0 {
+                let j = i | target_bit;
+                self.amplitudes.swap(i, j);
+            }
+        }
+        Ok(())
+    }
+
+    /// The 2x2 unitary matrix for every `Gate` variant but `CX`, which
+    /// acts on two qubits at once and is handled directly in `apply`.
+    fn matrix(gate: Gate) -> [[Complex64; 2]; 2] {
+        match gate {
+            Gate::H => {
+                let s = Complex64::new(std::f64::consts::FRAC_1_SQRT_2, 0.0);
+                [[s, s], [s, -s]]
+            }
+            Gate::X => [
+                [Complex64::new(0.0, 0.0), Complex64::new(1.0, 0.0)],
+                [Complex64::new(1.0, 0.0), Complex64::new(0.0, 0.0)],
+            ],
+            Gate::Z => [
+                [Complex64::new(1.0, 0.0), Complex64::new(0.0, 0.0)],
+                [Complex64::new(0.0, 0.0), Complex64::new(-1.0, 0.0)],
+            ],
+            Gate::U1(lambda) => [
+                [Complex64::new(1.0, 0.0), Complex64::new(0.0, 0.0)],
+                [Complex64::new(0.0, 0.0), Complex64::new(lambda.cos(), lambda.sin())],
+            ],
+            Gate::U3(theta, phi, lambda) => {
+                let (cos_half, sin_half) = ((theta / 2.0).cos(), (theta / 2.0).sin());
+                [
+                    [
+                        Complex64::new(cos_half, 0.0),
+                        -Complex64::new(lambda.cos(), lambda.sin()) * sin_half,
+                    ],
+                    [
+                        Complex64::new(phi.cos(), phi.sin()) * sin_half,
+                        Complex64::new((phi + lambda).cos(), (phi + lambda).sin()) * cos_half,
+                    ],
+                ]
+            }
+            Gate::CX => unreachable!("CX is two-qubit; dispatched directly in `apply`"),
+        }
+    }
+
+    /// Samples a computational-basis outcome with probability
+    /// `|amplitudes[i]|^2` (the Born rule), then collapses the register
+    /// onto it: every other amplitude is zeroed and the survivor
+    /// renormalized to magnitude 1. Returns the measured basis index.
+    pub fn measure<R: Rng>(&mut self, rng: &mut R) -> usize {
+        let outcome = self.sample_outcome(rng);
+
+        for (i, amplitude) in self.amplitudes.iter_mut().enumerate() {
+            *amplitude = if i == outcome {
+                Complex64::new(1.0, 0.0)
+            } else {
+                Complex64::new(0.0, 0.0)
+            };
+        }
+
+        outcome
+    }
+
+    /// Non-destructive Monte Carlo estimate of `measure`'s outcome
+    /// distribution: runs `shots` independent Born-rule draws without
+    /// mutating `self`, returning a histogram of basis index (as a
+    /// bitstring) to times observed.
+    pub fn run_statistics<R: Rng>(&self, shots: usize, rng: &mut R) -> BTreeMap<u64, usize> {
+        let mut histogram = BTreeMap::new();
+        for _ in 0..shots {
+            let outcome = self.sample_outcome(rng) as u64;
+            *histogram.entry(outcome).or_insert(0) += 1;
+        }
+        histogram
+    }
+
+    /// Draws a single basis index with probability `|amplitudes[i]|^2`,
+    /// shared by `measure` and `run_statistics`. Falls back to the last
+    /// index if rounding leaves the running sum just under the draw.
+    fn sample_outcome<R: Rng>(&self, rng: &mut R) -> usize {
+        let draw: f64 = rng.gen();
+        let mut running = 0.0;
+        for (i, amplitude) in self.amplitudes.iter().enumerate() {
+            running += amplitude.norm_sqr();
+            if draw <= running {
+                return i;
+            }
+        }
+        self.amplitudes.len() - 1
+    }
+
+    /// Builds the combined `(self.qubit_count + other.qubit_count)`-qubit
+    /// product state: amplitude at index `j + other.amplitudes().len() * i`
+    /// is `self.amplitudes[i] * other.amplitudes[j]`, with `other`
+    /// occupying the low-order bits. Errors if the combined qubit count
+    /// would exceed `MAX_QUBIT_COUNT`.
+    pub fn tensor(&self, other: &QuantumRegister) -> Result<QuantumRegister, &'static str> {
+        let qubit_count = self.qubit_count + other.qubit_count;
+        if qubit_count > MAX_QUBIT_COUNT {
+            return Err("combined qubit count exceeds MAX_QUBIT_COUNT");
+        }
+
+        let mut amplitudes = Vec::with_capacity(self.amplitudes.len() * other.amplitudes.len());
+        for &a in &self.amplitudes {
+            for &b in &other.amplitudes {
+                amplitudes.push(a * b);
+            }
+        }
+
+        Ok(QuantumRegister { qubit_count, amplitudes })
+    }
+
+    /// In-place form of `tensor`: absorbs `other` into `self`, growing
+    /// `self`'s qubit count by `other.qubit_count`.
+    pub fn entangle_with(&mut self, other: &QuantumRegister) -> Result<(), &'static str> {
+        *self = self.tensor(other)?;
+        Ok(())
+    }
+
+    /// Inverse of `tensor`, when it exists: splits `self` into a
+    /// `(qubit_count - low_qubit_count)`-qubit `high` register and a
+    /// `low_qubit_count`-qubit `low` register such that
+    /// `high.tensor(&low)` reproduces `self`, returning `(high, low)`.
+    /// Recovers the factors from the first nonzero row of `self`'s
+    /// amplitude grid (reshaped `high_dim x low_dim`), then verifies
+    /// every other row is that same row scaled by a single `high`
+    /// amplitude -- exactly the condition for `self` to be a product
+    /// state. Entangled subsystems fail that check and return an error
+    /// rather than a silently wrong factorization.
+    pub fn split(&self, low_qubit_count: usize) -> Result<(QuantumRegister, QuantumRegister), &'static str> {
+        if low_qubit_count == 0 || low_qubit_count >= self.qubit_count {
+            return Err("low_qubit_count must be strictly between 0 and this register's qubit count");
+        }
+
+        let low_dim = 1usize << low_qubit_count;
+        let high_qubit_count = self.qubit_count - low_qubit_count;
+        let high_dim = 1usize << high_qubit_count;
+        const EPSILON: f64 = 1e-9;
+
+        let pivot_row = (0..high_dim)
+            .find(|&i| (0..low_dim).any(|j| self.amplitudes[i * low_dim + j].norm_sqr() > EPSILON))
+            .ok_or("cannot split an all-zero register")?;
+
+        let low_factor: Vec<Complex64> = (0..low_dim)
+            .map(|j| self.amplitudes[pivot_row * low_dim + j])
+            .collect();
+        let pivot_col = low_factor.iter()
+            .position(|a| a.norm_sqr() > EPSILON)
+            .ok_or("cannot split an all-zero register")?;
+
+        let mut high_amplitudes = vec![Complex64::new(0.0, 0.0); high_dim];
+        for i in 0..high_dim {
+            let high_i = self.amplitudes[i * low_dim + pivot_col] / low_factor[pivot_col];
+            high_amplitudes[i] = high_i;
+
+            for (j, &low_j) in low_factor.iter().enumerate() {
+                let expected = high_i * low_j;
+                let actual = self.amplitudes[i * low_dim + j];
+                if (expected - actual).norm() > EPSILON {
+                    return Err("register is entangled and cannot be split into independent subsystems");
+                }
+            }
+        }
+
+        let mut low_factor = low_factor;
+        let high_scale = high_amplitudes.iter().map(|a| a.norm_sqr()).sum::<f64>().sqrt();
+        let low_scale = low_factor.iter().map(|a| a.norm_sqr()).sum::<f64>().sqrt();
+        for amplitude in high_amplitudes.iter_mut() {
+            *amplitude /= high_scale;
+        }
+        for amplitude in low_factor.iter_mut() {
+            *amplitude /= low_scale;
+        }
+
+        Ok((
+            QuantumRegister { qubit_count: high_qubit_count, amplitudes: high_amplitudes },
+            QuantumRegister { qubit_count: low_qubit_count, amplitudes: low_factor },
+        ))
+    }
+}
+
+/// A standard single- or two-qubit unitary `QuantumRegister::apply`
+/// knows how to apply, mirroring the gate set OpenQASM-style circuit
+/// toolkits expose.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Gate {
+    /// `(1/sqrt(2)) * [[1, 1], [1, -1]]`.
+    H,
+    /// Pauli-X (bit flip): `[[0, 1], [1, 0]]`.
+    X,
+    /// Pauli-Z (phase flip): `[[1, 0], [0, -1]]`.
+    Z,
+    /// Phase gate: `diag(1, e^{i*lambda})`.
+    U1(f64),
+    /// General single-qubit unitary: `[[cos(theta/2), -e^{i*lambda}*sin(theta/2)],
+    /// [e^{i*phi}*sin(theta/2), e^{i*(phi+lambda)}*cos(theta/2)]]`.
+    U3(f64, f64, f64),
+    /// Controlled-NOT: flips `target` whenever `control` is set.
+    CX,
+}
+
+/// Mixed-state companion to `QuantumRegister`: an n-qubit density matrix
+/// `rho`, stored as its `dim*dim` complex entries in row-major order
+/// (`dim = 2^qubit_count`). `QuantumRegister` alone can only describe a
+/// pure state vector -- this is what lets the crate model genuinely
+/// mixed states, the way decoherence and probabilistic gate outcomes
+/// actually produce, the way `ket2dm`-style tooling does.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegisterDensityMatrix {
+    qubit_count: usize,
+    entries: Vec<Complex64>,
+}
+
+impl RegisterDensityMatrix {
+    /// `rho = |psi><psi|` for a pure `QuantumRegister` state.
+    pub fn from_pure_state(register: &QuantumRegister) -> Self {
+        let amplitudes = register.amplitudes();
+        let mut entries = Vec::with_capacity(amplitudes.len() * amplitudes.len());
+        for row in amplitudes {
+            for col in amplitudes {
+                entries.push(*row * col.conj());
+            }
+        }
+        Self { qubit_count: register.qubit_count(), entries }
+    }
+
+    /// `rho = I / 2^n`: every basis outcome equally likely and zero
+    /// off-diagonal coherence -- what `QuantumState::Decoherent`
+    /// corresponds to.
+    pub fn maximally_mixed(qubit_count: usize) -> Self {
+        let dim = 1usize << qubit_count;
+        let mut entries = vec![Complex64::new(0.0, 0.0); dim * dim];
+        let diagonal = 1.0 / dim as f64;
+        for i in 0..dim {
+            entries[i * dim + i] = Complex64::new(diagonal, 0.0);
+        }
+        Self { qubit_count, entries }
+    }
+
+    pub fn qubit_count(&self) -> usize {
+        self.qubit_count
+    }
+
+    fn dim(&self) -> usize {
+        1usize << self.qubit_count
+    }
+
+    pub fn entry(&self, row: usize, col: usize) -> Complex64 {
+        self.entries[row * self.dim() + col]
+    }
+
+    /// `Tr(rho^2)` -- 1.0 for a pure state, `1/2^n` for a maximally
+    /// mixed one, matching `DensityMatrix::purity`'s convention for the
+    /// 2x2 case.
+    pub fn purity(&self) -> f64 {
+        let dim = self.dim();
+        let mut trace = Complex64::new(0.0, 0.0);
+        for i in 0..dim {
+            for k in 0..dim {
+                trace += self.entry(i, k) * self.entry(k, i);
+            }
+        }
+        trace.re
+    }
+
+    /// `rho -> U*rho*U^dagger`. `gate`/`targets` use the same convention
+    /// as `QuantumRegister::apply` -- `U` is assembled one column at a
+    /// time by applying `gate` to each computational basis state, so
+    /// this reuses `QuantumRegister::apply` rather than duplicating the
+    /// gate matrices.
+    pub fn apply(&mut self, gate: Gate, targets: &[usize]) -> Result<(), &'static str> {
+        let dim = self.dim();
+        let mut unitary = vec![Complex64::new(0.0, 0.0); dim * dim];
+        for column in 0..dim {
+            let mut basis = QuantumRegister::with_state(self.qubit_count, column)?;
+            basis.apply(gate, targets)?;
+            for (row, amplitude) in basis.amplitudes().iter().enumerate() {
+                unitary[row * dim + column] = *amplitude;
+            }
+        }
+
+        let mut stage = vec![Complex64::new(0.0, 0.0); dim * dim];
+        for i in 0..dim {
+            for j in 0..dim {
+                let mut sum = Complex64::new(0.0, 0.0);
+                for k in 0..dim {
+                    sum += unitary[i * dim + k] * self.entries[k * dim + j];
+                }
+                stage[i * dim + j] = sum;
+            }
+        }
+
+        let mut result = vec![Complex64::new(0.0, 0.0); dim * dim];
+        for i in 0..dim {
+            for j in 0..dim {
+                let mut sum = Complex64::new(0.0, 0.0);
+                for k in 0..dim {
+                    sum += stage[i * dim + k] * unitary[j * dim + k].conj();
+                }
+                result[i * dim + j] = sum;
+            }
+        }
+
+        self.entries = result;
+        Ok(())
+    }
+}
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum CellState {
@@ -39,7 +759,7 @@ impl MeshCell {
             state: QuantumCell::new(CellState::Calibrating),
             quantum_signature: QuantumCell::new([0; 32]),
             region,
-            coherence: Helium::new(1.0),
+            density: Helium::new(DensityMatrix::coherent()),
             last_update: Helium::new(CURRENT_TIMESTAMP),
         }
     }
@@ -122,6 +842,13 @@ impl QuantumDataPattern {
         let current = self.coherence.load(&HeliumOrdering::Quantum).unwrap_or(1.0);
         self.coherence.store(current * 0.99, &HeliumOrdering::Quantum).unwrap_or(());
     }
+
+    /// Normalized agreement between this pattern's and `other`'s raw
+    /// quantum signatures, restricted to `subspace`'s byte indices if
+    /// given. See `signature_agreement`.
+    pub fn fidelity(&self, other: &QuantumDataPattern, subspace: Option<&[usize]>) -> f64 {
+        signature_agreement(&self.get_quantum_signature(), &other.get_quantum_signature(), subspace)
+    }
 }
 
 impl Clone for QuantumDataPattern {
@@ -155,6 +882,138 @@ enum QuantumState {
     Decoherent,
 }
 
+/// A single Lindblad-style decay channel a quantum-jump step may fire
+/// into. Each variant's collapse rate is computed fresh every step from
+/// the clock's current state -- see `MeshClock::step_quantum_trajectory`.
+#[derive(Debug, Clone, Copy)]
+enum QuantumJumpChannel {
+    Decohere,
+    PatternTransfer,
+}
+
+/// Scale-variation band for `MeshClock::calculate_time_dilation_band`,
+/// mirroring the convention used to estimate renormalization/
+/// factorization uncertainty in perturbative QCD: `base` holds the
+/// independent scales being varied, and the band is built from the
+/// Cartesian product of `base` against `factors` (one factor drawn per
+/// base scale). A combination is discarded rather than counted toward
+/// the envelope if its largest-to-smallest factor ratio exceeds
+/// `max_ratio` -- the usual "7-point" restriction against wildly
+/// mismatched scales.
+#[derive(Debug, Clone)]
+pub struct ScaleConfig {
+    pub base: Vec<f64>,
+    pub factors: Vec<f64>,
+    pub max_ratio: f64,
+}
+
+impl Default for ScaleConfig {
+    fn default() -> Self {
+        Self {
+            base: vec![1.0],
+            factors: vec![0.5, 1.0, 2.0],
+            max_ratio: 2.0,
+        }
+    }
+}
+
+/// All length-`n` tuples drawn, with repetition, from `factors` -- e.g.
+/// for `n = 2` the classic 9-point scale-variation grid.
+fn cartesian_product(n: usize, factors: &[f64]) -> Vec<Vec<f64>> {
+    let mut combos = vec![Vec::new()];
+    for _ in 0..n {
+        let mut next = Vec::with_capacity(combos.len() * factors.len());
+        for combo in &combos {
+            for &factor in factors {
+                let mut extended = combo.clone();
+                extended.push(factor);
+                next.push(extended);
+            }
+        }
+        combos = next;
+    }
+    combos
+}
+
+/// Controls `MeshClock::sample_propagation_ensemble`'s partial-
+/// unweighting pass over a batch of `ping` propagation times:
+/// `trials` training samples estimate the mean/standard deviation of
+/// the log-weight distribution, then every sample within `max_dev`
+/// standard deviations of that mean keeps full weight, while the tail
+/// is scaled down proportionally to how far past `max_dev` it sits.
+#[derive(Debug, Clone)]
+pub struct PartialUnweightConfig {
+    pub trials: usize,
+    pub max_dev: f64,
+}
+
+impl Default for PartialUnweightConfig {
+    fn default() -> Self {
+        Self { trials: 100, max_dev: 3.0 }
+    }
+}
+
+/// One `ping` propagation time from `MeshClock::sample_propagation_ensemble`,
+/// carrying the weight `PartialUnweightConfig` assigned it.
+#[derive(Debug, Clone, Copy)]
+pub struct WeightedSample {
+    pub propagation_time: usize,
+    pub weight: f64,
+}
+
+/// Per-invariant toggles and tolerances for `MeshClock::check_consistency`.
+/// Each `check_*` flag gates one invariant; `abs_tolerance` bounds the
+/// coherence/entanglement checks and `rel_tolerance` bounds the
+/// signature-agreement check, since they're measured on different
+/// scales.
+#[derive(Debug, Clone)]
+pub struct MeshConsistency {
+    pub check_coherence_bounds: bool,
+    pub check_finite_dilation: bool,
+    pub check_entanglement_nonneg: bool,
+    pub check_signature_agreement: bool,
+    pub abs_tolerance: f64,
+    pub rel_tolerance: f64,
+    pub force_decoherent_on_failure: bool,
+}
+
+impl Default for MeshConsistency {
+    fn default() -> Self {
+        Self {
+            check_coherence_bounds: true,
+            check_finite_dilation: true,
+            check_entanglement_nonneg: true,
+            check_signature_agreement: true,
+            abs_tolerance: 1e-6,
+            rel_tolerance: 1e-3,
+            force_decoherent_on_failure: true,
+        }
+    }
+}
+
+/// One invariant `MeshClock::check_consistency` found violated, with the
+/// offending value for debugging.
+#[derive(Debug, Clone)]
+pub struct ConsistencyViolation {
+    pub invariant: &'static str,
+    pub value: f64,
+}
+
+/// Result of `MeshClock::check_consistency`: every violated invariant,
+/// plus whether the failure was severe enough to force
+/// `QuantumState::Decoherent` (see `MeshConsistency::force_decoherent_on_failure`).
+#[derive(Debug, Clone)]
+pub struct ConsistencyReport {
+    pub violations: Vec<ConsistencyViolation>,
+    pub forced_decoherent: bool,
+}
+
+impl ConsistencyReport {
+    pub fn is_consistent(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
 pub struct MeshClock {
     alpha_cell: MeshCell,
     omega_cell: MeshCell,
@@ -169,9 +1028,42 @@ pub struct MeshClock {
     entanglement_strength: Helium<f64>,
     pattern_coherence: Helium<f64>,
     pattern_buffer: Option<Vec<f64>>,
-    coherence: Helium<f64>,
+    /// Rolling window of recent `ping` durations (nanoseconds), oldest
+    /// first, capped at `SPECTRUM_HISTORY_CAPACITY`. Feeds
+    /// `power_spectrum`/`dominant_frequency`.
+    interval_history: Vec<f64>,
+    /// `alpha_cell`/`omega_cell`'s combined coherent state as an
+    /// n-qubit state vector, alongside (not yet replacing) the scalar
+    /// `QuantumState`/`DensityMatrix` views the rest of this module
+    /// still drives its transitions from.
+    register: QuantumRegister,
+    /// Mixed-state view of `register`, populated once the clock has a
+    /// reason to believe `register` is no longer a pure state (e.g.
+    /// `measure_register` falling below `QUANTUM_COHERENCE_THRESHOLD`).
+    /// `None` means `register` alone is authoritative -- same additive,
+    /// coexisting relationship `register` itself has with the legacy
+    /// scalar `QuantumState`/`DensityMatrix` views.
+    density_matrix: Option<RegisterDensityMatrix>,
+    density: Helium<DensityMatrix>,
+    /// `T1`: amplitude-relaxation time used by `apply_quantum_effects`'s
+    /// decoherence channel, in the same units as `measured_interval`
+    /// (nanoseconds). Set via `set_decoherence_times`.
+    relaxation_time: f64,
+    /// `T2`: dephasing time used alongside `relaxation_time`. Physically
+    /// `T2 <= 2*T1`, but this isn't enforced -- callers modeling a purely
+    /// dephasing channel are free to set `T2` independently.
+    dephasing_time: f64,
     region: Vector3D<f64>,
     alignment: Alignment,
+    /// Backs `step_quantum_trajectory`'s jump draws. Same seed plus the
+    /// same sequence of `ping`/`evolve_quantum_state` calls reproduces
+    /// the same trajectory, which is what makes averaging many named
+    /// trajectories meaningful.
+    rng: StdRng,
+    /// Label for this trajectory when averaging several `MeshClock`s
+    /// together to recover the smooth decay `evolve_quantum_state` used
+    /// to approximate with hard coherence thresholds.
+    trajectory_name: String,
 }
 
 impl MeshClock {
@@ -198,22 +1090,106 @@ impl MeshClock {
             entanglement_strength: Helium::new(1000.0),
             pattern_coherence: Helium::new(0.0),
             pattern_buffer: None,
-            coherence: Helium::new(1.0),
+            interval_history: Vec::new(),
+            register: QuantumRegister::new(MESH_CLOCK_QUBIT_COUNT)
+                .expect("MESH_CLOCK_QUBIT_COUNT is well within MAX_QUBIT_COUNT"),
+            density_matrix: None,
+            density: Helium::new(DensityMatrix::coherent()),
+            relaxation_time: DEFAULT_RELAXATION_TIME,
+            dephasing_time: DEFAULT_DEPHASING_TIME,
             region,
             alignment,
+            rng: StdRng::from_entropy(),
+            trajectory_name: "default".to_string(),
         }
     }
 
+    /// Like [`MeshClock::new`], but names the trajectory and seeds its
+    /// RNG explicitly so `step_quantum_trajectory` replays the same
+    /// sequence of jumps on every run -- needed to compare trajectories
+    /// or average several of them back into a smooth decay curve.
+    pub fn with_trajectory(alpha_pos: Vector3D<f64>, distance: f64, name: String, seed: u64) -> Self {
+        let mut clock = Self::new(alpha_pos, distance);
+        clock.rng = StdRng::seed_from_u64(seed);
+        clock.trajectory_name = name;
+        clock
+    }
+
+    pub fn trajectory_name(&self) -> &str {
+        &self.trajectory_name
+    }
+
     pub fn get_signal_vector(&self) -> Vector3D<f64> {
         self.signal_vector.clone()
     }
 
+    /// Thin view over `density`: `2*|rho01|`.
     pub fn get_coherence(&self) -> Result<f64, &'static str> {
-        self.coherence.load(&HeliumOrdering::Quantum)
+        self.density.load(&HeliumOrdering::Quantum).map(|dm| dm.coherence())
     }
 
     pub fn set_coherence(&mut self, value: f64) -> Result<(), &'static str> {
-        self.coherence.store(value, &HeliumOrdering::Quantum)
+        self.density.store(DensityMatrix::with_coherence(value), &HeliumOrdering::Quantum)
+    }
+
+    /// `Tr(rho^2)` of the clock's density matrix -- 1.0 for a pure state.
+    pub fn purity(&self) -> Result<f64, &'static str> {
+        self.density.load(&HeliumOrdering::Quantum).map(|dm| dm.purity())
+    }
+
+    /// `alpha_cell`/`omega_cell`'s combined coherent state as an n-qubit
+    /// register, for reading real amplitude magnitudes rather than the
+    /// lone `QuantumState::Superposition(phase)` scalar.
+    pub fn quantum_register(&self) -> &QuantumRegister {
+        &self.register
+    }
+
+    /// Born-rule measurement of `register`, using the clock's own
+    /// seeded RNG (see `step_quantum_trajectory`), transitioning to
+    /// `QuantumState::Decoherent` if coherence has since fallen below
+    /// `QUANTUM_COHERENCE_THRESHOLD`. Returns the measured basis index.
+    pub fn measure_register(&mut self) -> Result<usize, &'static str> {
+        let outcome = self.register.measure(&mut self.rng);
+
+        if self.get_coherence()? < QUANTUM_COHERENCE_THRESHOLD {
+            self.quantum_state.set(QuantumState::Decoherent);
+            self.density_matrix = Some(RegisterDensityMatrix::maximally_mixed(self.register.qubit_count()));
+        }
+
+        Ok(outcome)
+    }
+
+    /// `register`'s mixed-state view, if `measure_register` (or
+    /// `enable_density_matrix_mode`) has populated one.
+    pub fn register_density_matrix(&self) -> Option<&RegisterDensityMatrix> {
+        self.density_matrix.as_ref()
+    }
+
+    /// Seeds `density_matrix` from `register`'s current pure state via
+    /// `rho = |psi><psi|`, so subsequent gate applications can be
+    /// tracked through the mixed-state representation instead.
+    pub fn enable_density_matrix_mode(&mut self) {
+        self.density_matrix = Some(RegisterDensityMatrix::from_pure_state(&self.register));
+    }
+
+    /// `Tr(rho^2)` of `register`'s mixed-state view if one has been
+    /// populated, or `1.0` (a pure state) otherwise -- unifies the
+    /// scalar `pattern_coherence` field and the enum-driven
+    /// `QuantumState` with a single purity-based "how quantum" number.
+    pub fn register_purity(&self) -> f64 {
+        self.density_matrix.as_ref().map(|rho| rho.purity()).unwrap_or(1.0)
+    }
+
+    /// Scalar coherence for `QuantumState::PatternTransfer`. Reports
+    /// `register_purity()` once `density_matrix` has been populated
+    /// (the purity-based notion `register_purity` introduced),
+    /// otherwise falls back to the raw `pattern_coherence` scalar
+    /// `evolve_quantum_state` decays on each step.
+    pub fn get_pattern_coherence(&self) -> Result<f64, &'static str> {
+        if self.density_matrix.is_some() {
+            return Ok(self.register_purity());
+        }
+        self.pattern_coherence.load(&HeliumOrdering::Quantum)
     }
 
     pub fn get_quantum_state(&self) -> Result<QuantumState, &'static str> {
@@ -250,6 +1226,19 @@ impl MeshClock {
         self.pattern_buffer.as_ref()
     }
 
+    /// Appends `value` to `interval_history`, dropping the oldest sample
+    /// once the history exceeds `SPECTRUM_HISTORY_CAPACITY`.
+    fn record_interval_sample(&mut self, value: f64) {
+        self.interval_history.push(value);
+        if self.interval_history.len() > SPECTRUM_HISTORY_CAPACITY {
+            self.interval_history.remove(0);
+        }
+    }
+
+    pub fn interval_history(&self) -> &[f64] {
+        &self.interval_history
+    }
+
     // Quantum state transitions
     fn transition_to_coherent(&mut self) -> Result<(), &'static str> {
         self.set_quantum_state(QuantumState::Coherent)?;
@@ -275,8 +1264,9 @@ impl MeshClock {
     }
 
     fn decay_coherence(&mut self) -> Result<(), &'static str> {
-        if let Ok(current) = self.coherence.load(&HeliumOrdering::Quantum) {
-            self.coherence.store(current * 0.99, &HeliumOrdering::Quantum)?;
+        if let Ok(mut dm) = self.density.load(&HeliumOrdering::Quantum) {
+            dm.decay(0.99);
+            self.density.store(dm, &HeliumOrdering::Quantum)?;
         }
         Ok(())
     }
@@ -302,6 +1292,18 @@ impl MeshClock {
         Ok(())
     }
 
+    /// Sets the `T1`/`T2` times `apply_quantum_effects` uses to drive its
+    /// amplitude- and phase-damping channel. Both must be positive --
+    /// `dt/0` would blow up the exponential.
+    pub fn set_decoherence_times(&mut self, t1: f64, t2: f64) -> Result<(), &'static str> {
+        if t1 <= 0.0 || t2 <= 0.0 {
+            return Err("Decoherence times must be positive");
+        }
+        self.relaxation_time = t1;
+        self.dephasing_time = t2;
+        Ok(())
+    }
+
     pub fn update_gravity_field(&mut self, field: &GravityField) -> Result<(), &'static str> {
         self.gravity_field.store(field.strength(), &HeliumOrdering::Quantum)
     }
@@ -374,6 +1376,40 @@ impl MeshClock {
         velocity_dilation * curvature * quantum_dilation
     }
 
+    /// Time-dilation uncertainty band: `calculate_time_dilation` is the
+    /// central value, and `(min, max)` bound the envelope of rescaled
+    /// dilations seen across every accepted combination in the Cartesian
+    /// product of `config.base` x `config.factors`. Combinations whose
+    /// factor ratio exceeds `config.max_ratio` are skipped entirely.
+    /// Returns `(central, min, max)`.
+    pub fn calculate_time_dilation_band(&self, config: &ScaleConfig) -> (f64, f64, f64) {
+        let central = self.calculate_time_dilation();
+        if config.base.is_empty() || config.factors.is_empty() {
+            return (central, central, central);
+        }
+
+        let mut min = central;
+        let mut max = central;
+
+        for combo in cartesian_product(config.base.len(), &config.factors) {
+            let max_factor = combo.iter().cloned().fold(f64::MIN, f64::max);
+            let min_factor = combo.iter().cloned().fold(f64::MAX, f64::min);
+            if min_factor <= 0.0 || max_factor / min_factor > config.max_ratio {
+                continue;
+            }
+
+            let scale: f64 = config.base.iter().zip(combo.iter())
+                .map(|(b, f)| b * f)
+                .sum::<f64>() / config.base.len() as f64;
+            let dilation = central * scale;
+
+            min = min.min(dilation);
+            max = max.max(dilation);
+        }
+
+        (central, min, max)
+    }
+
     #[allow(dead_code)]
     fn quantum_ping(&mut self) -> Result<usize, &'static str> {
         let strength = self.entanglement_strength.load(&HeliumOrdering::Quantum)?;
@@ -393,13 +1429,14 @@ impl MeshClock {
     }
 
     fn decay_coherence(&self) {
-        if let Ok(current) = self.coherence.load(&HeliumOrdering::Quantum) {
-            let _ = self.coherence.store(current * 0.99, &HeliumOrdering::Quantum);
+        if let Ok(mut dm) = self.density.load(&HeliumOrdering::Quantum) {
+            dm.decay(0.99);
+            let _ = self.density.store(dm, &HeliumOrdering::Quantum);
         }
     }
 
     pub fn is_quantum_stable(&self) -> bool {
-        let quantum_coherence = self.coherence.load(&HeliumOrdering::Quantum).unwrap_or(0.0);
+        let quantum_coherence = self.density.load(&HeliumOrdering::Quantum).map(|dm| dm.coherence()).unwrap_or(0.0);
         let grav_coherence = self.gravitational_coherence.load(&HeliumOrdering::Quantum).unwrap_or(0.0);
 
         quantum_coherence * grav_coherence > QUANTUM_COHERENCE_THRESHOLD
@@ -458,11 +1495,138 @@ impl MeshClock {
             mesh_time - current_ts
         };
 
-        if drift > 1000 { // More than 1µs drift
-            self.calibrate()?;
-        }
+        // Target the clock's own detected resonance period, once enough
+        // ping history has accumulated to resolve one, rather than a
+        // fixed 1us threshold that has no relationship to how fast this
+        // particular clock actually drifts.
+        let drift_threshold = self.dominant_frequency()
+            .filter(|freq| *freq > 0.0)
+            .map(|freq| {
+                let period_in_pings = 1.0 / freq;
+                let average_interval = self.interval_history.iter().sum::<f64>()
+                    / self.interval_history.len() as f64;
+                (period_in_pings * average_interval / 2.0) as u64
+            })
+            .unwrap_or(1000); // More than 1µs drift, absent any history
+
+        if drift > drift_threshold {
+            // evolve_reversible is exactly invertible, so roll the
+            // coherent phase back by the measured drift first -- a
+            // deterministic correction -- before falling back to
+            // calibrate()'s hard reset.
+            let rollback_dt = drift as f64 * 1e-9;
+            self.evolve_reversible(-rollback_dt)?;
+            self.calibrate()?;
+        }
+
+        self.decay_coherence();
+        Ok(())
+    }
+
+    /// Power spectrum `S(omega)` of the clock's recent ping-interval
+    /// history: autocorrelates the rolling `interval_history` buffer,
+    /// zero-pads `C(tau)` to the next power of two, and runs it through
+    /// the same radix-2 FFT approach `CrystalVibration`'s native backend
+    /// uses for vibration spectra. `spectrum[k]`'s magnitude corresponds
+    /// to `k / spectrum.len()` cycles per ping.
+    pub fn power_spectrum(&self) -> Vec<f64> {
+        if self.interval_history.len() < 2 {
+            return Vec::new();
+        }
+
+        let correlation = autocorrelation(&self.interval_history);
+        let padded_len = correlation.len().next_power_of_two();
+        let mut padded = vec![Complex64::new(0.0, 0.0); padded_len];
+        for (slot, value) in padded.iter_mut().zip(correlation.iter()) {
+            *slot = Complex64::new(*value, 0.0);
+        }
+
+        fft_radix2(&padded).into_iter().map(|bin| bin.norm()).collect()
+    }
+
+    /// Dominant frequency, in cycles per ping, found by taking the
+    /// largest non-DC bin of `power_spectrum`. `None` if `interval_history`
+    /// doesn't yet hold enough samples to resolve a frequency.
+    pub fn dominant_frequency(&self) -> Option<f64> {
+        let spectrum = self.power_spectrum();
+        if spectrum.len() < 2 {
+            return None;
+        }
+
+        let (bin, _) = spectrum.iter()
+            .enumerate()
+            .skip(1)
+            .take(spectrum.len() / 2)
+            .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())?;
+
+        Some(bin as f64 / spectrum.len() as f64)
+    }
+
+    /// Direct alternative to `dominant_frequency`: rather than an FFT of
+    /// the autocorrelation, sums the raw `interval_history` samples
+    /// against `e^{-i*omega*t}` for each candidate in `frequencies`
+    /// (also in cycles per ping) and returns whichever has the largest
+    /// response magnitude. Cheaper than `power_spectrum` when only a
+    /// small, targeted frequency grid needs checking, and not bin-
+    /// quantized the way the FFT path is.
+    pub fn dominant_frequency_direct(&self, frequencies: &[f64]) -> Option<f64> {
+        if self.interval_history.is_empty() {
+            return None;
+        }
+
+        frequencies.iter()
+            .copied()
+            .map(|freq| {
+                let omega = 2.0 * std::f64::consts::PI * freq;
+                let response: Complex64 = self.interval_history.iter()
+                    .enumerate()
+                    .map(|(t, x)| Complex64::new(0.0, -omega * t as f64).exp() * x)
+                    .sum();
+                (freq, response.norm())
+            })
+            .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+            .map(|(freq, _)| freq)
+    }
+
+    /// Time-reversible leapfrog (velocity-Verlet) step of the coherent
+    /// part of the clock's state, modeled as a harmonic oscillator in
+    /// (coherence-amplitude, phase) phase space: `amplitude * cos(phase)`
+    /// and `amplitude * sin(phase)` are its Cartesian position/velocity.
+    /// `dt` may be negative -- stepping by `+dt` and then by `-dt`
+    /// returns to the original (amplitude, phase) pair within
+    /// floating-point tolerance, unlike the lossy `decay_coherence`.
+    pub fn evolve_reversible(&mut self, dt: f64) -> Result<(), &'static str> {
+        let amplitude = self.get_coherence()?;
+        let phase = match self.quantum_state.get() {
+            QuantumState::Superposition(phase) => phase,
+            _ => 0.0,
+        };
+
+        let omega = REVERSIBLE_OSCILLATOR_OMEGA;
+        let mut q = amplitude * phase.cos();
+        let mut v = -amplitude * omega * phase.sin();
+
+        let accel = |q: f64| -omega * omega * q;
+        v += 0.5 * dt * accel(q);
+        q += dt * v;
+        v += 0.5 * dt * accel(q);
+
+        let new_amplitude = (q * q + (v / omega) * (v / omega)).sqrt();
+        let new_phase = (-v / omega).atan2(q);
+
+        // Bypass DensityMatrix::with_coherence's [0, 1] clamp: clamping
+        // mid-trajectory would make the forward/backward round trip
+        // lossy, defeating the point of this integrator.
+        self.density.store(
+            DensityMatrix {
+                rho00: Complex64::new(0.5, 0.0),
+                rho01: Complex64::new(new_amplitude / 2.0, 0.0),
+                rho11: Complex64::new(0.5, 0.0),
+            },
+            &HeliumOrdering::Quantum,
+        )?;
+        self.quantum_state.store(QuantumState::Superposition(new_phase), &HeliumOrdering::Quantum)?;
 
-        self.decay_coherence();
         Ok(())
     }
 
@@ -478,7 +1642,7 @@ impl MeshClock {
         self.quantum_state.set(QuantumState::Coherent);
         self.entanglement_strength.store(1000.0, &HeliumOrdering::Quantum)?;
         self.last_ping.store(CURRENT_TIMESTAMP, &HeliumOrdering::Quantum)?;
-        self.coherence.store(1.0, &HeliumOrdering::Quantum)?;
+        self.density.store(DensityMatrix::coherent(), &HeliumOrdering::Quantum)?;
 
         let _new_signature = self.generate_quantum_signature();
         let pattern = QuantumDataPattern::new([
@@ -489,16 +1653,84 @@ impl MeshClock {
         self.alpha_cell.update_quantum_pattern(&pattern)?;
         self.omega_cell.update_quantum_pattern(&pattern)?;
 
+        if Self::cell_fidelity(&self.alpha_cell, &self.omega_cell, None) < PATTERN_CONVERGENCE_TOLERANCE {
+            self.quantum_state.set(QuantumState::Decoherent);
+            return Err("Alpha and omega cells failed to converge to the same pattern");
+        }
+
         self.alpha_cell.set_state(CellState::Transmitting);
         self.omega_cell.set_state(CellState::Receiving);
 
         Ok(())
     }
 
+    /// Uhlmann fidelity between two cells' density matrices, optionally
+    /// restricted to `subspace` -- e.g. only the entanglement-relevant
+    /// degrees of freedom. `calibrate` and `entangle_cells` use this to
+    /// confirm alpha and omega actually converged instead of assuming
+    /// `update_quantum_pattern` succeeded.
+    pub fn cell_fidelity(alpha: &MeshCell, omega: &MeshCell, subspace: Option<&[usize]>) -> f64 {
+        alpha.get_density().fidelity(&omega.get_density(), subspace)
+    }
+
+    /// Audits the clock against `config`'s enabled invariants instead of
+    /// trusting the silent `unwrap_or` fallbacks scattered through this
+    /// module: coherence within `[0, 1]` (padded by `abs_tolerance`), a
+    /// finite time dilation, non-negative entanglement strength, and --
+    /// for entangled cells -- alpha/omega agreement within
+    /// `rel_tolerance` of perfect fidelity (the same `cell_fidelity`
+    /// `calibrate`/`entangle_cells` already use as their convergence
+    /// signature). Intended to be called after `ping`/`pong`/`calibrate`
+    /// to catch drift into a physically impossible state; forces
+    /// `QuantumState::Decoherent` on any violation if
+    /// `config.force_decoherent_on_failure` is set.
+    pub fn check_consistency(&mut self, config: &MeshConsistency) -> ConsistencyReport {
+        let mut violations = Vec::new();
+
+        if config.check_coherence_bounds {
+            let coherence = self.density.load(&HeliumOrdering::Quantum)
+                .map(|dm| dm.coherence())
+                .unwrap_or(f64::NAN);
+            let lower = -config.abs_tolerance;
+            let upper = 1.0 + config.abs_tolerance;
+            if !(coherence >= lower && coherence <= upper) {
+                violations.push(ConsistencyViolation { invariant: "coherence_bounds", value: coherence });
+            }
+        }
+
+        if config.check_finite_dilation {
+            let dilation = self.calculate_time_dilation();
+            if !dilation.is_finite() {
+                violations.push(ConsistencyViolation { invariant: "finite_dilation", value: dilation });
+            }
+        }
+
+        if config.check_entanglement_nonneg {
+            let strength = self.entanglement_strength.load(&HeliumOrdering::Quantum).unwrap_or(f64::NAN);
+            if !(strength >= -config.abs_tolerance) {
+                violations.push(ConsistencyViolation { invariant: "entanglement_nonneg", value: strength });
+            }
+        }
+
+        if config.check_signature_agreement && matches!(self.quantum_state.get(), QuantumState::Entangled) {
+            let agreement = Self::cell_fidelity(&self.alpha_cell, &self.omega_cell, None);
+            if agreement < 1.0 - config.rel_tolerance {
+                violations.push(ConsistencyViolation { invariant: "signature_agreement", value: agreement });
+            }
+        }
+
+        let forced_decoherent = !violations.is_empty() && config.force_decoherent_on_failure;
+        if forced_decoherent {
+            self.quantum_state.set(QuantumState::Decoherent);
+        }
+
+        ConsistencyReport { violations, forced_decoherent }
+    }
+
     fn generate_quantum_signature(&self) -> [u8; 32] {
         let mut signature = [0u8; 32];
         let oscillations = self.oscillation_count.load(&HeliumOrdering::Quantum).unwrap_or(0);
-        let coherence = (self.coherence.load(&HeliumOrdering::Quantum).unwrap_or(1.0) * 255.0) as u8;
+        let coherence = (self.density.load(&HeliumOrdering::Quantum).map(|dm| dm.coherence()).unwrap_or(1.0) * 255.0) as u8;
 
         for i in 0..32 {
             signature[i] = ((oscillations + i) as u8).wrapping_add(coherence);
@@ -524,7 +1756,7 @@ impl MeshClock {
         if !force_diff.is_finite() {
             return 0.0;
         }
-        let base_coherence = self.coherence.load(&HeliumOrdering::Quantum).unwrap_or(1.0);
+        let base_coherence = self.density.load(&HeliumOrdering::Quantum).map(|dm| dm.coherence()).unwrap_or(1.0);
         (base_coherence * (1.0 - (force_diff * GRAVITATIONAL_CONSTANT * 1e-10))).max(0.0).min(1.0)  // Now GRAVITATIONAL_CONSTANT is in scope
     }
 
@@ -537,12 +1769,12 @@ impl MeshClock {
             QuantumState::Entangled => {
                 // Entangled states have quantum tunneling effect - faster propagation
                 let strength = self.get_entanglement_strength();
-                let coherence = self.coherence.load(&HeliumOrdering::Quantum).unwrap_or(1.0);
+                let coherence = self.density.load(&HeliumOrdering::Quantum).map(|dm| dm.coherence()).unwrap_or(1.0);
                 ((1000.0 / strength) * coherence * 2.0) as usize
             },
             QuantumState::Superposition(phase) => {
                 // Superposition creates quantum uncertainty in timing
-                let coherence = self.coherence.load(&HeliumOrdering::Quantum).unwrap_or(1.0);
+                let coherence = self.density.load(&HeliumOrdering::Quantum).map(|dm| dm.coherence()).unwrap_or(1.0);
                 // Add complexity factor based on coherence
                 let complexity = 1.0 + (1.0 - coherence);
                 ((1500.0 * phase) * complexity) as usize
@@ -565,50 +1797,12 @@ impl MeshClock {
         }
     }
 
+    /// Advances the clock's quantum state by one Monte Carlo wavefunction
+    /// (quantum-jump) step of `MCWF_TIME_STEP`. See
+    /// `step_quantum_trajectory` for the stepping rule itself; this entry
+    /// point just fixes `dt` for callers (`ping`) that don't track one.
     pub fn evolve_quantum_state(&mut self) -> Result<(), &'static str> {
-        let current_coherence = self.coherence.load(&HeliumOrdering::Quantum).unwrap_or(1.0);
-
-        match self.quantum_state.get() {
-            QuantumState::Coherent => {
-                // Coherent states have a chance to enter superposition or entanglement
-                if current_coherence < 0.95 {
-                    if self.oscillation_count.load(&HeliumOrdering::Quantum).unwrap_or(0) % 2 == 0 {
-                        self.create_superposition()?;
-                    } else {
-                        self.entangle_cells()?;
-                    }
-                }
-            },
-            QuantumState::Entangled => {
-                // Entangled states decohere faster and transition to superposition
-                let strength = self.get_entanglement_strength();
-                if strength < 990.0 {  // Lowered threshold to see more transitions
-                    if current_coherence < 0.97 {  // Increased threshold
-                        self.create_superposition()?;
-                    }
-                }
-            },
-            QuantumState::Superposition(phase) => {
-                // Superposition states may collapse to pattern transfer
-                if current_coherence < 0.85 || phase > 0.8 {
-                    self.transfer_quantum_pattern()?;
-                }
-            },
-            QuantumState::PatternTransfer => {
-                // Pattern transfer may return to coherent state
-                if self.get_pattern_coherence()? > 0.7 {
-                    self.quantum_state.set(QuantumState::Coherent);
-                    self.coherence.store(0.9, &HeliumOrdering::Quantum)?;
-                }
-            },
-            QuantumState::Decoherent => {
-                // Attempt recovery to coherent state
-                if current_coherence > 0.5 {
-                    self.quantum_state.set(QuantumState::Coherent);
-                    self.coherence.store(0.8, &HeliumOrdering::Quantum)?;
-                }
-            }
-        }
+        self.step_quantum_trajectory(MCWF_TIME_STEP)?;
 
         // Update pattern coherence based on state
         match self.quantum_state.get() {
@@ -627,6 +1821,70 @@ impl MeshClock {
         Ok(())
     }
 
+    /// One Monte Carlo wavefunction step over `dt`. `decay_coherence` is
+    /// treated as the deterministic non-Hermitian part of the evolution:
+    /// over `dt` the state's norm would drop by `delta_p = dt * sum(gamma_j)`
+    /// across the open channels `gamma_j` below. A single uniform draw
+    /// `epsilon` in `[0, 1)` then decides the step:
+    ///
+    /// - `epsilon > delta_p`: no jump. The deterministic decay runs and
+    ///   coherence renormalizes back to 1, matching continuous no-jump
+    ///   evolution under the non-Hermitian part alone.
+    /// - `epsilon <= delta_p`: a jump fires. The channel `j` is chosen by
+    ///   comparing `epsilon` against the running partial sums of
+    ///   `gamma_j / sum(gamma)`, and its transition runs with coherence
+    ///   reset to 1.
+    ///
+    /// A single trajectory looks discontinuous; averaging many (seeded
+    /// via [`MeshClock::with_trajectory`]) recovers the smooth decay
+    /// curve the old threshold-based `evolve_quantum_state` approximated.
+    fn step_quantum_trajectory(&mut self, dt: f64) -> Result<(), &'static str> {
+        let coherence = self.density.load(&HeliumOrdering::Quantum).map(|dm| dm.coherence()).unwrap_or(1.0);
+
+        let gamma_decohere = (1.0 - coherence).max(0.0);
+        let gamma_pattern_transfer = match self.quantum_state.get() {
+            QuantumState::Superposition(phase) => phase.abs(),
+            _ => 0.0,
+        };
+
+        let channels = [
+            (gamma_decohere, QuantumJumpChannel::Decohere),
+            (gamma_pattern_transfer, QuantumJumpChannel::PatternTransfer),
+        ];
+        let total_rate: f64 = channels.iter().map(|(gamma, _)| gamma).sum();
+        let delta_p = (dt * total_rate).min(1.0);
+
+        let epsilon: f64 = self.rng.gen();
+
+        if total_rate <= 0.0 || epsilon > delta_p {
+            self.decay_coherence()?;
+            self.density.store(DensityMatrix::coherent(), &HeliumOrdering::Quantum)?;
+            return Ok(());
+        }
+
+        let mut running = 0.0;
+        for (gamma, channel) in channels.iter() {
+            running += gamma / total_rate;
+            if epsilon <= running {
+                return self.apply_jump(*channel);
+            }
+        }
+
+        // Rounding left `running` just under 1.0 -- fire the last channel.
+        self.apply_jump(channels[channels.len() - 1].1)
+    }
+
+    fn apply_jump(&mut self, channel: QuantumJumpChannel) -> Result<(), &'static str> {
+        match channel {
+            QuantumJumpChannel::Decohere => self.transition_to_decoherent()?,
+            QuantumJumpChannel::PatternTransfer => {
+                let pattern = self.pattern_buffer.clone().unwrap_or_default();
+                self.transition_to_pattern_transfer(pattern)?;
+            }
+        }
+        self.density.store(DensityMatrix::coherent(), &HeliumOrdering::Quantum)
+    }
+
     // Update the ping method to use our new timing calculation
     pub fn ping(&mut self) -> Result<usize, &'static str> {
         // Previous validation
@@ -638,7 +1896,7 @@ impl MeshClock {
         let duration = self.calculate_propagation_time();
 
         // Apply quantum effects
-        self.apply_quantum_effects()?;
+        self.apply_quantum_effects(duration as f64)?;
 
         // Evolve quantum state
         self.evolve_quantum_state()?;
@@ -650,11 +1908,55 @@ impl MeshClock {
         let current_interval = self.measured_interval.load(&HeliumOrdering::Quantum).unwrap_or(0);
         self.measured_interval.store(current_interval + duration, &HeliumOrdering::Quantum)?;
 
+        self.record_interval_sample(duration as f64);
+
         Ok(duration)
     }
 
+    /// Runs `ping` `count` times and statistically characterizes the
+    /// resulting propagation-time ensemble rather than treating each
+    /// call as an independent deterministic point: `config.trials` of
+    /// the samples estimate the mean/standard deviation of
+    /// `ln(propagation_time)`, then every sample keeps full weight if
+    /// within `config.max_dev` standard deviations of that mean, or is
+    /// partially unweighted (scaled down by how far past `max_dev` it
+    /// sits) otherwise.
+    pub fn sample_propagation_ensemble(
+        &mut self,
+        count: usize,
+        config: &PartialUnweightConfig,
+    ) -> Result<Vec<WeightedSample>, &'static str> {
+        let mut times = Vec::with_capacity(count);
+        for _ in 0..count {
+            times.push(self.ping()?);
+        }
+
+        let log_weights: Vec<f64> = times.iter().map(|&t| (t.max(1) as f64).ln()).collect();
+        let trial_count = config.trials.min(log_weights.len()).max(1);
+        let mean: f64 = log_weights[..trial_count].iter().sum::<f64>() / trial_count as f64;
+        let variance: f64 = log_weights[..trial_count].iter()
+            .map(|w| (w - mean).powi(2))
+            .sum::<f64>() / trial_count as f64;
+        let std_dev = variance.sqrt().max(f64::EPSILON);
+
+        let samples = times.into_iter()
+            .zip(log_weights)
+            .map(|(propagation_time, log_weight)| {
+                let deviation = (log_weight - mean).abs() / std_dev;
+                let weight = if deviation <= config.max_dev {
+                    1.0
+                } else {
+                    config.max_dev / deviation
+                };
+                WeightedSample { propagation_time, weight }
+            })
+            .collect();
+
+        Ok(samples)
+    }
+
     pub fn get_state_stability(&self) -> f64 {
-        let coherence = self.coherence.load(&HeliumOrdering::Quantum).unwrap_or(0.0);
+        let coherence = self.density.load(&HeliumOrdering::Quantum).map(|dm| dm.coherence()).unwrap_or(0.0);
         match self.quantum_state.get() {
             QuantumState::Coherent => coherence,
             QuantumState::Entangled => coherence * (self.get_entanglement_strength() / 1000.0),
@@ -670,7 +1972,11 @@ impl MeshClock {
         .map_err(|_| "Failed to read oscillation count")
     }
 
-    fn apply_quantum_effects(&mut self) -> Result<(), &'static str> {
+    /// Drives `density` through one step of the `T1`/`T2` decoherence
+    /// channel (`DensityMatrix::apply_decoherence`) over the elapsed
+    /// `dt`, replacing the fixed per-state multipliers this used to
+    /// apply regardless of how much time the step actually covered.
+    fn apply_quantum_effects(&mut self, dt: f64) -> Result<(), &'static str> {
         match self.quantum_state.get() {
             QuantumState::Entangled => {
                 // Slight decay in entanglement strength
@@ -679,27 +1985,29 @@ impl MeshClock {
                     self.set_entanglement_strength(strength * 0.999)?;
                 }
 
-                // Add slight coherence decay for entangled state
-                let current_coherence = self.coherence.load(&HeliumOrdering::Quantum).unwrap_or(1.0);
-                self.coherence.store(current_coherence * 0.998, &HeliumOrdering::Quantum)?;
+                let mut dm = self.density.load(&HeliumOrdering::Quantum).unwrap_or_default();
+                dm.apply_decoherence(self.relaxation_time, self.dephasing_time, dt);
+                self.density.store(dm, &HeliumOrdering::Quantum)?;
             },
             QuantumState::Superposition(phase) => {
-                // Add coherence decay
-                let current_coherence = self.coherence.load(&HeliumOrdering::Quantum).unwrap_or(1.0);
-                self.coherence.store(current_coherence * 0.995, &HeliumOrdering::Quantum)?;
+                let mut dm = self.density.load(&HeliumOrdering::Quantum).unwrap_or_default();
+                dm.apply_decoherence(self.relaxation_time, self.dephasing_time, dt);
+                let current_coherence = dm.coherence();
+                self.density.store(dm, &HeliumOrdering::Quantum)?;
 
                 // Phase fluctuation with coherence influence
                 let new_phase = phase * 0.999 + (0.001 * current_coherence);
                 self.quantum_state.set(QuantumState::Superposition(new_phase));
             },
             QuantumState::PatternTransfer => {
-                // Add gradual coherence decay for pattern transfer
                 let current_coherence = self.get_pattern_coherence().unwrap_or(1.0);
-                self.coherence.store(current_coherence * 0.997, &HeliumOrdering::Quantum)?;
+                let mut dm = DensityMatrix::with_coherence(current_coherence);
+                dm.apply_decoherence(self.relaxation_time, self.dephasing_time, dt);
+                self.density.store(DensityMatrix::with_coherence(dm.coherence()), &HeliumOrdering::Quantum)?;
             },
             QuantumState::Decoherent => {
                 // Once decoherent, system remains unstable
-                self.coherence.store(0.0, &HeliumOrdering::Quantum)?;
+                self.density.store(DensityMatrix::with_coherence(0.0), &HeliumOrdering::Quantum)?;
             },
             _ => {}
         }
@@ -723,6 +2031,9 @@ impl MeshClock {
         if self.is_quantum_stable() {
             // Initialize with 1.0 coherence for the superposition state
             self.quantum_state.set(QuantumState::Superposition(1.0));
+            self.register = QuantumRegister::equal_superposition(MESH_CLOCK_QUBIT_COUNT)
+                .expect("MESH_CLOCK_QUBIT_COUNT is well within MAX_QUBIT_COUNT");
+            self.density_matrix = None;
 
             let pattern = QuantumDataPattern {
                 mesh_shape: QuantumCell::new([Vector3D::new(0.0, 0.0, 0.0); 2]),
@@ -739,13 +2050,26 @@ impl MeshClock {
     }
 
     pub fn entangle_cells(&mut self) -> Result<(), &'static str> {
-        if self.is_quantum_stable() {
-            self.quantum_state.set(QuantumState::Entangled);
-            self.entanglement_strength.store(1000.0, &HeliumOrdering::Quantum).unwrap_or(());
-            Ok(())
-        } else {
-            Err("Cannot entangle: quantum state not stable")
+        if !self.is_quantum_stable() {
+            return Err("Cannot entangle: quantum state not stable");
         }
+
+        if Self::cell_fidelity(&self.alpha_cell, &self.omega_cell, None) < PATTERN_CONVERGENCE_TOLERANCE {
+            return Err("Cannot entangle: alpha and omega cells have diverged");
+        }
+
+        // H on alpha_cell's qubit then CX(alpha, omega) actually produces
+        // the Bell state (|00> + |11>) / sqrt(2), instead of just setting
+        // an enum flag and a scalar entanglement_strength.
+        self.register = QuantumRegister::new(MESH_CLOCK_QUBIT_COUNT)
+            .expect("MESH_CLOCK_QUBIT_COUNT is well within MAX_QUBIT_COUNT");
+        self.register.apply(Gate::H, &[0])?;
+        self.register.apply(Gate::CX, &[0, 1])?;
+        self.density_matrix = None;
+
+        self.quantum_state.set(QuantumState::Entangled);
+        self.entanglement_strength.store(1000.0, &HeliumOrdering::Quantum).unwrap_or(());
+        Ok(())
     }
 
     pub fn transfer_quantum_pattern(&mut self) -> Result<(), &'static str> {
@@ -848,8 +2172,9 @@ mod tests {
             let _ = clock.ping();
         }
 
-        let coherence = clock.coherence.load(&HeliumOrdering::Quantum).unwrap_or(1.0);
+        let coherence = clock.density.load(&HeliumOrdering::Quantum).map(|dm| dm.coherence()).unwrap_or(1.0);
         assert!(coherence < 1.0);
+        assert!(coherence >= 0.0);
     }
 
     #[test]
@@ -921,4 +2246,469 @@ mod tests {
         assert!(ping_result.is_ok());
         assert_eq!(ping_result.unwrap(), 0); // Quantum ping should be instantaneous
     }
+
+    #[test]
+    fn test_evolve_reversible_round_trips() {
+        let mut clock = MeshClock::new(Vector3D::new(0.0, 0.0, 0.0), 1.0);
+        clock.quantum_state.set(QuantumState::Superposition(0.3));
+        clock.set_coherence(0.6).unwrap();
+
+        let initial_amplitude = clock.get_coherence().unwrap();
+        let initial_phase = match clock.quantum_state.get() {
+            QuantumState::Superposition(phase) => phase,
+            _ => panic!("expected superposition"),
+        };
+
+        for _ in 0..50 {
+            clock.evolve_reversible(0.01).unwrap();
+        }
+        for _ in 0..50 {
+            clock.evolve_reversible(-0.01).unwrap();
+        }
+
+        let final_amplitude = clock.get_coherence().unwrap();
+        let final_phase = match clock.quantum_state.get() {
+            QuantumState::Superposition(phase) => phase,
+            _ => panic!("expected superposition"),
+        };
+
+        assert!((final_amplitude - initial_amplitude).abs() < 1e-9);
+        assert!((final_phase - initial_phase).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_dominant_frequency_recovers_known_period() {
+        let mut clock = MeshClock::new(Vector3D::new(0.0, 0.0, 0.0), 1.0);
+
+        // A period-4 square wave, repeated to fill the history.
+        let wave = [1.0, -1.0, 1.0, -1.0];
+        for i in 0..32 {
+            clock.record_interval_sample(wave[i % wave.len()]);
+        }
+
+        let frequency = clock.dominant_frequency().expect("history should resolve a frequency");
+        assert!((frequency - 0.25).abs() < 0.05, "expected ~0.25 cycles/ping, got {frequency}");
+    }
+
+    #[test]
+    fn test_dominant_frequency_direct_agrees_with_fft_path() {
+        let mut clock = MeshClock::new(Vector3D::new(0.0, 0.0, 0.0), 1.0);
+
+        let wave = [1.0, -1.0, 1.0, -1.0];
+        for i in 0..32 {
+            clock.record_interval_sample(wave[i % wave.len()]);
+        }
+
+        let grid: Vec<f64> = (0..50).map(|i| i as f64 / 100.0).collect();
+        let direct = clock.dominant_frequency_direct(&grid).expect("grid should resolve a frequency");
+        assert!((direct - 0.25).abs() < 0.05, "expected ~0.25 cycles/ping, got {direct}");
+    }
+
+    #[test]
+    fn test_power_spectrum_empty_without_history() {
+        let clock = MeshClock::new(Vector3D::new(0.0, 0.0, 0.0), 1.0);
+        assert!(clock.power_spectrum().is_empty());
+        assert_eq!(clock.dominant_frequency(), None);
+    }
+
+    #[test]
+    fn test_time_dilation_band_brackets_central_value() {
+        let clock = MeshClock::new(Vector3D::new(0.0, 0.0, 0.0), 1.0);
+        let config = ScaleConfig {
+            base: vec![1.0, 1.0],
+            factors: vec![0.5, 1.0, 2.0],
+            max_ratio: 2.0,
+        };
+
+        let (central, min, max) = clock.calculate_time_dilation_band(&config);
+        assert!(min <= central);
+        assert!(central <= max);
+    }
+
+    #[test]
+    fn test_time_dilation_band_collapses_without_scales() {
+        let clock = MeshClock::new(Vector3D::new(0.0, 0.0, 0.0), 1.0);
+        let config = ScaleConfig { base: vec![], factors: vec![0.5, 2.0], max_ratio: 2.0 };
+
+        let (central, min, max) = clock.calculate_time_dilation_band(&config);
+        assert_eq!(min, central);
+        assert_eq!(max, central);
+    }
+
+    #[test]
+    fn test_sample_propagation_ensemble_weights_are_bounded() {
+        let mut clock = MeshClock::new(Vector3D::new(0.0, 0.0, 0.0), 1.0);
+        let config = PartialUnweightConfig { trials: 5, max_dev: 2.0 };
+
+        let samples = clock.sample_propagation_ensemble(10, &config).unwrap();
+        assert_eq!(samples.len(), 10);
+        for sample in &samples {
+            assert!(sample.weight > 0.0 && sample.weight <= 1.0);
+        }
+    }
+
+    #[test]
+    fn test_check_consistency_clean_state_has_no_violations() {
+        let mut clock = MeshClock::new(Vector3D::new(0.0, 0.0, 0.0), 1.0);
+        let report = clock.check_consistency(&MeshConsistency::default());
+
+        assert!(report.is_consistent());
+        assert!(!report.forced_decoherent);
+    }
+
+    #[test]
+    fn test_check_consistency_flags_out_of_bounds_coherence_and_forces_decoherent() {
+        let mut clock = MeshClock::new(Vector3D::new(0.0, 0.0, 0.0), 1.0);
+        clock.density.set(DensityMatrix {
+            rho00: Complex64::new(0.5, 0.0),
+            rho01: Complex64::new(10.0, 0.0),
+            rho11: Complex64::new(0.5, 0.0),
+        });
+
+        let report = clock.check_consistency(&MeshConsistency::default());
+
+        assert!(!report.is_consistent());
+        assert!(report.violations.iter().any(|v| v.invariant == "coherence_bounds"));
+        assert!(report.forced_decoherent);
+        match clock.get_quantum_state().unwrap() {
+            QuantumState::Decoherent => {},
+            other => panic!("expected Decoherent, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_check_consistency_respects_disabled_checks() {
+        let mut clock = MeshClock::new(Vector3D::new(0.0, 0.0, 0.0), 1.0);
+        clock.density.set(DensityMatrix {
+            rho00: Complex64::new(0.5, 0.0),
+            rho01: Complex64::new(10.0, 0.0),
+            rho11: Complex64::new(0.5, 0.0),
+        });
+
+        let config = MeshConsistency { check_coherence_bounds: false, ..MeshConsistency::default() };
+        let report = clock.check_consistency(&config);
+
+        assert!(report.is_consistent());
+    }
+
+    #[test]
+    fn test_quantum_register_zero_state_is_normalized() {
+        let register = QuantumRegister::new(2).unwrap();
+        assert_eq!(register.amplitudes().len(), 4);
+        assert_eq!(register.probability(0), 1.0);
+        assert_eq!(register.probability(1), 0.0);
+        assert!((register.norm() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_quantum_register_with_state_picks_basis_index() {
+        let register = QuantumRegister::with_state(2, 3).unwrap();
+        assert_eq!(register.probability(3), 1.0);
+        assert_eq!(register.probability(0), 0.0);
+    }
+
+    #[test]
+    fn test_quantum_register_with_state_rejects_out_of_range_index() {
+        assert!(QuantumRegister::with_state(2, 4).is_err());
+    }
+
+    #[test]
+    fn test_quantum_register_equal_superposition_is_normalized() {
+        let register = QuantumRegister::equal_superposition(3).unwrap();
+        assert_eq!(register.amplitudes().len(), 8);
+        for amplitude in register.amplitudes() {
+            assert!((amplitude.re - (1.0 / 8.0_f64).sqrt()).abs() < 1e-12);
+        }
+        assert!((register.norm() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_quantum_register_rejects_qubit_count_above_ceiling() {
+        assert!(QuantumRegister::new(MAX_QUBIT_COUNT + 1).is_err());
+        assert!(QuantumRegister::new(MAX_QUBIT_COUNT).is_ok());
+    }
+
+    #[test]
+    fn test_create_superposition_populates_register() {
+        let mut clock = MeshClock::new(Vector3D::new(0.0, 0.0, 0.0), 1.0);
+        clock.create_superposition().unwrap();
+
+        let register = clock.quantum_register();
+        assert_eq!(register.qubit_count(), MESH_CLOCK_QUBIT_COUNT);
+        assert!((register.norm() - 1.0).abs() < 1e-12);
+        for i in 0..register.amplitudes().len() {
+            assert!((register.probability(i) - 0.25).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_hadamard_produces_equal_superposition() {
+        let mut register = QuantumRegister::new(1).unwrap();
+        register.apply(Gate::H, &[0]).unwrap();
+
+        assert!((register.probability(0) - 0.5).abs() < 1e-12);
+        assert!((register.probability(1) - 0.5).abs() < 1e-12);
+        assert!((register.norm() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_pauli_x_flips_basis_state() {
+        let mut register = QuantumRegister::new(1).unwrap();
+        register.apply(Gate::X, &[0]).unwrap();
+
+        assert_eq!(register.probability(0), 0.0);
+        assert_eq!(register.probability(1), 1.0);
+    }
+
+    #[test]
+    fn test_cx_produces_bell_state() {
+        let mut register = QuantumRegister::new(2).unwrap();
+        register.apply(Gate::H, &[0]).unwrap();
+        register.apply(Gate::CX, &[0, 1]).unwrap();
+
+        // (|00> + |11>) / sqrt(2): no weight on |01> or |10>.
+        assert!((register.probability(0b00) - 0.5).abs() < 1e-12);
+        assert!((register.probability(0b11) - 0.5).abs() < 1e-12);
+        assert_eq!(register.probability(0b01), 0.0);
+        assert_eq!(register.probability(0b10), 0.0);
+        assert!((register.norm() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_apply_rejects_wrong_target_count() {
+        let mut register = QuantumRegister::new(2).unwrap();
+        assert!(register.apply(Gate::H, &[0, 1]).is_err());
+        assert!(register.apply(Gate::CX, &[0]).is_err());
+    }
+
+    #[test]
+    fn test_apply_rejects_out_of_range_qubit() {
+        let mut register = QuantumRegister::new(1).unwrap();
+        assert!(register.apply(Gate::H, &[1]).is_err());
+        assert!(register.apply(Gate::CX, &[0, 5]).is_err());
+    }
+
+    #[test]
+    fn test_entangle_cells_produces_bell_state_register() {
+        let mut clock = MeshClock::new(Vector3D::new(0.0, 0.0, 0.0), 1.0);
+        clock.entangle_cells().unwrap();
+
+        let register = clock.quantum_register();
+        assert!((register.probability(0b00) - 0.5).abs() < 1e-12);
+        assert!((register.probability(0b11) - 0.5).abs() < 1e-12);
+        assert_eq!(register.probability(0b01), 0.0);
+        assert_eq!(register.probability(0b10), 0.0);
+    }
+
+    #[test]
+    fn test_measure_collapses_to_a_single_basis_state() {
+        let mut register = QuantumRegister::equal_superposition(2).unwrap();
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let outcome = register.measure(&mut rng);
+
+        assert_eq!(register.probability(outcome), 1.0);
+        for i in 0..register.amplitudes().len() {
+            if i != outcome {
+                assert_eq!(register.probability(i), 0.0);
+            }
+        }
+        assert!((register.norm() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_run_statistics_converges_to_born_rule_probabilities() {
+        let register = QuantumRegister::equal_superposition(2).unwrap();
+        let mut rng = StdRng::seed_from_u64(7);
+
+        let histogram = register.run_statistics(4000, &mut rng);
+
+        assert_eq!(histogram.values().sum::<usize>(), 4000);
+        for count in histogram.values() {
+            let fraction = *count as f64 / 4000.0;
+            assert!((fraction - 0.25).abs() < 0.05, "fraction {fraction} far from 0.25");
+        }
+    }
+
+    #[test]
+    fn test_run_statistics_does_not_mutate_register() {
+        let register = QuantumRegister::equal_superposition(2).unwrap();
+        let mut rng = StdRng::seed_from_u64(3);
+
+        register.run_statistics(10, &mut rng);
+
+        for i in 0..register.amplitudes().len() {
+            assert!((register.probability(i) - 0.25).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_measure_register_transitions_to_decoherent_below_threshold() {
+        let mut clock = MeshClock::new(Vector3D::new(0.0, 0.0, 0.0), 1.0);
+        clock.density.set(DensityMatrix::with_coherence(QUANTUM_COHERENCE_THRESHOLD / 2.0));
+
+        clock.measure_register().unwrap();
+
+        match clock.get_quantum_state().unwrap() {
+            QuantumState::Decoherent => {},
+            other => panic!("expected Decoherent, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_tensor_combines_qubit_counts_and_amplitudes() {
+        let zero = QuantumRegister::new(1).unwrap();
+        let one = QuantumRegister::with_state(1, 1).unwrap();
+
+        let combined = zero.tensor(&one).unwrap();
+
+        assert_eq!(combined.qubit_count(), 2);
+        // |0> tensor |1> = |01>, i.e. basis index 1 (other occupies the
+        // low-order bits).
+        assert_eq!(combined.probability(0b01), 1.0);
+        assert_eq!(combined.probability(0b00), 0.0);
+    }
+
+    #[test]
+    fn test_tensor_rejects_qubit_count_above_ceiling() {
+        let a = QuantumRegister::new(MAX_QUBIT_COUNT).unwrap();
+        let b = QuantumRegister::new(1).unwrap();
+        assert!(a.tensor(&b).is_err());
+    }
+
+    #[test]
+    fn test_entangle_with_mutates_in_place() {
+        let mut a = QuantumRegister::new(1).unwrap();
+        let b = QuantumRegister::with_state(1, 1).unwrap();
+
+        a.entangle_with(&b).unwrap();
+
+        assert_eq!(a.qubit_count(), 2);
+        assert_eq!(a.probability(0b01), 1.0);
+    }
+
+    #[test]
+    fn test_split_recovers_product_state_factors() {
+        let high = QuantumRegister::equal_superposition(1).unwrap();
+        let low = QuantumRegister::with_state(1, 1).unwrap();
+        let combined = high.tensor(&low).unwrap();
+
+        let (recovered_high, recovered_low) = combined.split(1).unwrap();
+
+        assert_eq!(recovered_high.qubit_count(), 1);
+        assert_eq!(recovered_low.qubit_count(), 1);
+        for i in 0..2 {
+            assert!((recovered_high.probability(i) - high.probability(i)).abs() < 1e-9);
+            assert!((recovered_low.probability(i) - low.probability(i)).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_split_rejects_entangled_register() {
+        let mut bell = QuantumRegister::new(2).unwrap();
+        bell.apply(Gate::H, &[0]).unwrap();
+        bell.apply(Gate::CX, &[0, 1]).unwrap();
+
+        assert!(bell.split(1).is_err());
+    }
+
+    #[test]
+    fn test_apply_decoherence_shrinks_coherence_over_time() {
+        let mut dm = DensityMatrix::coherent();
+        let initial_coherence = dm.coherence();
+
+        dm.apply_decoherence(DEFAULT_RELAXATION_TIME, DEFAULT_DEPHASING_TIME, 1000.0);
+
+        assert!(dm.coherence() < initial_coherence);
+        assert!(dm.coherence() >= 0.0);
+    }
+
+    #[test]
+    fn test_apply_decoherence_is_a_no_op_at_zero_elapsed_time() {
+        let mut dm = DensityMatrix::coherent();
+        let initial_coherence = dm.coherence();
+
+        dm.apply_decoherence(DEFAULT_RELAXATION_TIME, DEFAULT_DEPHASING_TIME, 0.0);
+
+        assert!((dm.coherence() - initial_coherence).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_set_decoherence_times_rejects_non_positive_values() {
+        let mut clock = MeshClock::new(Vector3D::new(0.0, 0.0, 0.0), 1.0);
+
+        assert!(clock.set_decoherence_times(0.0, 1000.0).is_err());
+        assert!(clock.set_decoherence_times(1000.0, -1.0).is_err());
+        assert!(clock.set_decoherence_times(5000.0, 3000.0).is_ok());
+    }
+
+    #[test]
+    fn test_coherence_decays_faster_with_shorter_decoherence_times() {
+        let mut fast_clock = MeshClock::new(Vector3D::new(0.0, 0.0, 0.0), 1.0);
+        fast_clock.set_decoherence_times(50.0, 50.0).unwrap();
+        assert!(fast_clock.entangle_cells().is_ok());
+
+        let mut slow_clock = MeshClock::new(Vector3D::new(0.0, 0.0, 0.0), 1.0);
+        slow_clock.set_decoherence_times(1.0e9, 1.0e9).unwrap();
+        assert!(slow_clock.entangle_cells().is_ok());
+
+        for _ in 0..20 {
+            let _ = fast_clock.ping();
+            let _ = slow_clock.ping();
+        }
+
+        let fast_coherence = fast_clock.density.load(&HeliumOrdering::Quantum).map(|dm| dm.coherence()).unwrap_or(1.0);
+        let slow_coherence = slow_clock.density.load(&HeliumOrdering::Quantum).map(|dm| dm.coherence()).unwrap_or(1.0);
+
+        assert!(fast_coherence < slow_coherence);
+    }
+
+    #[test]
+    fn test_register_density_matrix_from_pure_state_has_purity_one() {
+        let register = QuantumRegister::equal_superposition(1).unwrap();
+        let rho = RegisterDensityMatrix::from_pure_state(&register);
+
+        assert!((rho.purity() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_register_density_matrix_maximally_mixed_has_low_purity() {
+        let rho = RegisterDensityMatrix::maximally_mixed(1);
+
+        assert!((rho.purity() - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_register_density_matrix_apply_matches_pure_state_evolution() {
+        let mut register = QuantumRegister::new(1).unwrap();
+        register.apply(Gate::H, &[0]).unwrap();
+
+        let mut rho = RegisterDensityMatrix::from_pure_state(&QuantumRegister::new(1).unwrap());
+        rho.apply(Gate::H, &[0]).unwrap();
+
+        for i in 0..2 {
+            assert!((rho.entry(i, i).re - register.probability(i)).abs() < 1e-9);
+        }
+        assert!((rho.purity() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_measure_register_populates_density_matrix_below_threshold() {
+        let mut clock = MeshClock::new(Vector3D::new(0.0, 0.0, 0.0), 1.0);
+        clock.density.store(DensityMatrix::with_coherence(0.0), &HeliumOrdering::Quantum).unwrap();
+
+        assert!(clock.register_density_matrix().is_none());
+        let _ = clock.measure_register().unwrap();
+
+        let rho = clock.register_density_matrix().expect("coherence below threshold should populate density_matrix");
+        assert!((rho.purity() - 0.25).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_get_pattern_coherence_reports_purity_once_density_matrix_mode_is_enabled() {
+        let mut clock = MeshClock::new(Vector3D::new(0.0, 0.0, 0.0), 1.0);
+        clock.enable_density_matrix_mode();
+
+        assert!((clock.get_pattern_coherence().unwrap() - 1.0).abs() < 1e-9);
+    }
 }
@@ -3,6 +3,8 @@
 /// Author: isdood
 /// Current User: isdood
 
+use std::sync::Arc;
+
 use crate::{
     vector::FloatVector3D,
     helium::{Helium, HeliumOrdering},
@@ -12,6 +14,16 @@ use crate::{
 
 const QUANTUM_COHERENCE_THRESHOLD: f64 = 0.5;
 const MAX_MARKERS: usize = 6;
+/// Fixed capacity of the custom-format registry, matching `markers`'s
+/// fixed-array style rather than reaching for a heap `HashMap`.
+const MAX_CUSTOM_FORMATS: usize = MAX_MARKERS;
+
+/// Highest `distributed_db_version`/`p2p_version` this build of the
+/// tracker can drive, mirroring a network handshake's own capability
+/// ceiling. A registered [`MorphFormatDescriptor`] whose versions
+/// exceed these is rejected rather than silently truncated.
+const CURRENT_DISTRIBUTED_DB_VERSION: u16 = 3;
+const CURRENT_P2P_VERSION: u16 = 2;
 
 /// Represents different types of files that can be morphed
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -19,9 +31,43 @@ pub enum FileType {
     Rust,
     Quantum,
     Entangled,
+    /// A third-party morph format, routed through the tracker's custom
+    /// format registry. `id` looks up the registered
+    /// [`MorphFormatDescriptor`]; `version` is the specific format
+    /// version this particular file was written with, checked against
+    /// the descriptor's supported range.
+    Custom { id: u32, version: u16 },
     Other,
 }
 
+/// A registered custom morph format: a name plus the
+/// `distributed_db_version`/`p2p_version`-style compatibility numbers
+/// this build must support before accepting files declaring it,
+/// mirroring Tezos's `NetworkVersion` chain/version pairing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MorphFormatDescriptor {
+    pub id: u32,
+    pub name: &'static str,
+    pub distributed_db_version: u16,
+    pub p2p_version: u16,
+}
+
+impl MorphFormatDescriptor {
+    /// Whether this build of the tracker can drive a descriptor with
+    /// these version numbers at all, i.e. whether `register_custom_format`
+    /// should accept it into the registry in the first place.
+    fn is_buildable(&self) -> bool {
+        self.distributed_db_version <= CURRENT_DISTRIBUTED_DB_VERSION
+            && self.p2p_version <= CURRENT_P2P_VERSION
+    }
+
+    /// Whether a file declaring `version` of this format is one this
+    /// descriptor (and therefore this build) can read.
+    pub fn supports_version(&self, version: u16) -> bool {
+        version <= self.distributed_db_version && version <= self.p2p_version
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct EdgeMarker {
     pub position: FloatVector3D,
@@ -42,7 +88,13 @@ pub struct MorphTracker {
     modifier: QuantumCell<&'static str>,
     markers: QuantumCell<[Option<EdgeMarker>; MAX_MARKERS]>,
     file_type: QuantumCell<FileType>,
-    coherence: Helium<f64>,
+    /// Shared behind an `Arc` so `entangle` can make two trackers point
+    /// at the same `Helium<f64>`: decaying coherence from either side
+    /// then decays the joint value both trackers observe.
+    coherence: Arc<Helium<f64>>,
+    /// Registered third-party formats this tracker accepts through
+    /// `FileType::Custom`, keyed by `id`.
+    custom_formats: QuantumCell<[Option<MorphFormatDescriptor>; MAX_CUSTOM_FORMATS]>,
 }
 
 impl EdgeMarker {
@@ -74,20 +126,78 @@ impl Clone for MorphTracker {
             modifier: QuantumCell::new(*self.modifier.get()),
             markers: QuantumCell::new(*self.markers.get()),
             file_type: QuantumCell::new(*self.file_type.get()),
-            coherence: Helium::new(self.get_coherence()),
+            // A clone starts out independent, not entangled with
+            // whatever `self` is currently sharing coherence with.
+            coherence: Arc::new(Helium::new(self.get_coherence())),
         }
     }
 }
 
 impl MorphTracker {
-    pub const fn new() -> Self {
+    // No longer `const fn`: `coherence` moved behind an `Arc` so
+    // `entangle` can share it between trackers, and `Arc::new` isn't
+    // usable in a const context.
+    pub fn new() -> Self {
         const EMPTY_MARKER: Option<EdgeMarker> = None;
+        const EMPTY_FORMAT: Option<MorphFormatDescriptor> = None;
         Self {
             timestamp: Helium::new(CURRENT_TIMESTAMP),
             modifier: QuantumCell::new("isdood"),
             markers: QuantumCell::new([EMPTY_MARKER; MAX_MARKERS]),
             file_type: QuantumCell::new(FileType::Rust),
-            coherence: Helium::new(1.0),
+            coherence: Arc::new(Helium::new(1.0)),
+            custom_formats: QuantumCell::new([EMPTY_FORMAT; MAX_CUSTOM_FORMATS]),
+        }
+    }
+
+    /// Entangle this tracker with `other`, promoting `coherence` to a
+    /// shared `Helium<f64>` so `decay_coherence` on either side decays
+    /// the same joint value the other observes through
+    /// `get_coherence`/`create_entangled_morph_type`. Both trackers get
+    /// an `EntanglementPoint` marker recorded so `get_marker` reflects
+    /// the link. Requires both trackers to already meet the coherence
+    /// bar `create_entangled_morph_type` checks.
+    pub fn entangle(&mut self, other: &MorphTracker) -> Result<(), &'static str> {
+        if self.get_coherence() < 0.8 || other.get_coherence() < 0.8 {
+            return Err("Insufficient quantum coherence for entanglement");
+        }
+
+        self.coherence = Arc::clone(&other.coherence);
+        self.register_entanglement_point()?;
+        other.register_entanglement_point()?;
+        self.timestamp.store(CURRENT_TIMESTAMP, HeliumOrdering::Release);
+        Ok(())
+    }
+
+    /// Disentangle this tracker, snapshotting the current (possibly
+    /// jointly-decayed) coherence value into a fresh, independent
+    /// `Helium` so further decay on either former partner no longer
+    /// propagates to this one.
+    pub fn break_entanglement(&mut self) -> Result<(), &'static str> {
+        let current = self.get_coherence();
+        self.coherence = Arc::new(Helium::new(current));
+        self.timestamp.store(CURRENT_TIMESTAMP, HeliumOrdering::Release);
+        Ok(())
+    }
+
+    /// Record a `MarkerType::EntanglementPoint` in the first free
+    /// marker slot. Takes `&self` rather than `&mut self` (unlike
+    /// `set_marker`) because `entangle` only has a shared reference to
+    /// its partner and `markers`'s `QuantumCell` already provides the
+    /// interior mutability needed to write through it.
+    fn register_entanglement_point(&self) -> Result<(), &'static str> {
+        if !self.is_quantum_stable() {
+            return Err("Quantum state unstable");
+        }
+
+        let mut current_markers = *self.markers.get();
+        match current_markers.iter_mut().find(|marker| marker.is_none()) {
+            Some(slot) => {
+                *slot = Some(EdgeMarker::with_type(MarkerType::EntanglementPoint));
+                self.markers.set(current_markers);
+                Ok(())
+            }
+            None => Err("No free marker slot for entanglement point"),
         }
     }
 
@@ -100,6 +210,7 @@ impl MorphTracker {
             FileType::Rust => self.create_rust_morph_type(),
             FileType::Quantum => self.create_quantum_morph_type(),
             FileType::Entangled => self.create_entangled_morph_type(),
+            FileType::Custom { id, version } => self.create_custom_morph_type(id, version),
             FileType::Other => Err("Unsupported file type"),
         }
     }
@@ -125,6 +236,20 @@ impl MorphTracker {
         Ok(())
     }
 
+    fn create_custom_morph_type(&self, id: u32, version: u16) -> Result<(), &'static str> {
+        let descriptor = self
+            .find_custom_format(id)
+            .ok_or("Custom morph format is not registered")?;
+
+        if !descriptor.supports_version(version) {
+            return Err("Custom morph format version is not supported");
+        }
+
+        self.timestamp.store(CURRENT_TIMESTAMP, HeliumOrdering::Release);
+        self.decay_coherence();
+        Ok(())
+    }
+
     pub fn set_file_type(&mut self, file_type: FileType) -> Result<(), &'static str> {
         if !self.is_quantum_stable() {
             return Err("Quantum state unstable");
@@ -140,17 +265,60 @@ impl MorphTracker {
         *self.file_type.get()
     }
 
+    /// Accept `file_type`, verifying its version against the custom
+    /// format registry first when it's a `FileType::Custom` so an
+    /// unregistered or incompatible third-party format is rejected
+    /// with a descriptive error instead of being silently stored.
     pub fn register_file_type(&mut self, file_type: FileType) -> Result<(), &'static str> {
         if !self.is_quantum_stable() {
             return Err("Quantum state unstable");
         }
 
+        if let FileType::Custom { id, version } = file_type {
+            let descriptor = self
+                .find_custom_format(id)
+                .ok_or("Custom morph format is not registered")?;
+            if !descriptor.supports_version(version) {
+                return Err("Custom morph format version is not supported");
+            }
+        }
+
         self.file_type.set(file_type);
         self.timestamp.store(CURRENT_TIMESTAMP, HeliumOrdering::Release);
         self.decay_coherence();
         Ok(())
     }
 
+    /// Add `descriptor` to the custom format registry, rejecting it if
+    /// this build's `CURRENT_DISTRIBUTED_DB_VERSION`/`CURRENT_P2P_VERSION`
+    /// ceiling can't support the versions it declares, or if the
+    /// registry's fixed capacity is already full.
+    pub fn register_custom_format(&mut self, descriptor: MorphFormatDescriptor) -> Result<(), &'static str> {
+        if !descriptor.is_buildable() {
+            return Err("Custom morph format version exceeds what this build supports");
+        }
+
+        let mut formats = *self.custom_formats.get();
+        match formats.iter_mut().find(|slot| slot.is_none()) {
+            Some(slot) => {
+                *slot = Some(descriptor);
+                self.custom_formats.set(formats);
+                Ok(())
+            }
+            None => Err("Custom format registry is full"),
+        }
+    }
+
+    /// Look up a registered custom format by `id`.
+    pub fn find_custom_format(&self, id: u32) -> Option<MorphFormatDescriptor> {
+        self.custom_formats
+            .get()
+            .iter()
+            .flatten()
+            .find(|descriptor| descriptor.id == id)
+            .copied()
+    }
+
     pub fn get_marker(&self, index: usize) -> Option<EdgeMarker> {
         if !self.is_quantum_stable() {
             return None;
@@ -266,4 +434,94 @@ mod tests {
 
         assert!(tracker.create_morph_type().is_err());
     }
+
+    #[test]
+    fn test_entangle_shares_coherence_decay() {
+        let mut a = MorphTracker::new();
+        let b = MorphTracker::new();
+
+        assert!(a.entangle(&b).is_ok());
+        assert_eq!(a.get_coherence(), b.get_coherence());
+
+        a.decay_coherence();
+        assert_eq!(a.get_coherence(), b.get_coherence());
+
+        assert_eq!(a.get_marker(0).unwrap().marker_type, MarkerType::EntanglementPoint);
+        assert_eq!(b.get_marker(0).unwrap().marker_type, MarkerType::EntanglementPoint);
+    }
+
+    #[test]
+    fn test_break_entanglement_restores_independence() {
+        let mut a = MorphTracker::new();
+        let b = MorphTracker::new();
+
+        assert!(a.entangle(&b).is_ok());
+        assert!(a.break_entanglement().is_ok());
+
+        a.decay_coherence();
+        assert_ne!(a.get_coherence(), b.get_coherence());
+    }
+
+    #[test]
+    fn test_entangle_rejects_insufficient_coherence() {
+        let mut a = MorphTracker::new();
+        let b = MorphTracker::new();
+
+        for _ in 0..50 {
+            b.decay_coherence();
+        }
+
+        assert!(a.entangle(&b).is_err());
+    }
+
+    #[test]
+    fn test_register_custom_format_rejects_unbuildable_version() {
+        let mut tracker = MorphTracker::new();
+        let descriptor = MorphFormatDescriptor {
+            id: 1,
+            name: "too-new",
+            distributed_db_version: CURRENT_DISTRIBUTED_DB_VERSION + 1,
+            p2p_version: CURRENT_P2P_VERSION,
+        };
+
+        assert!(tracker.register_custom_format(descriptor).is_err());
+        assert!(tracker.find_custom_format(1).is_none());
+    }
+
+    #[test]
+    fn test_register_file_type_rejects_unregistered_custom_format() {
+        let mut tracker = MorphTracker::new();
+        let result = tracker.register_file_type(FileType::Custom { id: 42, version: 1 });
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_register_file_type_rejects_incompatible_custom_version() {
+        let mut tracker = MorphTracker::new();
+        let descriptor = MorphFormatDescriptor {
+            id: 7,
+            name: "widget",
+            distributed_db_version: 1,
+            p2p_version: 1,
+        };
+        tracker.register_custom_format(descriptor).unwrap();
+
+        let result = tracker.register_file_type(FileType::Custom { id: 7, version: 2 });
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_create_custom_morph_type_succeeds_for_registered_compatible_version() {
+        let mut tracker = MorphTracker::new();
+        let descriptor = MorphFormatDescriptor {
+            id: 7,
+            name: "widget",
+            distributed_db_version: 2,
+            p2p_version: 2,
+        };
+        tracker.register_custom_format(descriptor).unwrap();
+        tracker.register_file_type(FileType::Custom { id: 7, version: 1 }).unwrap();
+
+        assert!(tracker.create_morph_type().is_ok());
+    }
 }
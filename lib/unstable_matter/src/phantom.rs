@@ -11,6 +11,7 @@ use crate::{
     scribe::{Scribe, ScribePrecision, QuantumString},
     helium::HeliumOrdering,
 };
+use errors::QuantumError;
 
 #[derive(Debug)]
 pub struct PhantomSpace {
@@ -99,25 +100,288 @@ impl Scribe for PhantomSpace {
     }
 }
 
+/// State-vector quantum gate simulation layered on [`PhantomSpace`].
+///
+/// `PhantomSpace` only ever tracked a scalar coherence and a position; there
+/// was no actual quantum state to operate on. `QuantumRegister` holds `n`
+/// qubits as a dense state vector of `2^n` complex amplitudes (real/imag
+/// pairs, so no complex-number crate dependency is needed) and exposes the
+/// gate set a toy simulator needs, as in the `qasm` project:
+/// [`hadamard`](Self::hadamard), [`phase`](Self::phase),
+/// [`pauli_x`](Self::pauli_x)/[`pauli_y`](Self::pauli_y)/[`pauli_z`](Self::pauli_z),
+/// and the two-qubit [`cnot`](Self::cnot). Each single-qubit gate pairs up
+/// basis states that differ only in the target bit and applies the gate's
+/// 2x2 unitary to that pair in place; `cnot` instead swaps the target-bit
+/// amplitudes of the basis states where the control bit is set.
+#[derive(Debug)]
+pub struct QuantumRegister {
+    qubits: usize,
+    amplitudes: Vec<(f64, f64)>,
+    phantom: PhantomSpace,
+}
+
+impl QuantumRegister {
+    /// Create an `n`-qubit register initialized to `|0...0>`.
+    pub fn new(qubits: usize) -> Self {
+        let mut amplitudes = vec![(0.0, 0.0); 1 << qubits];
+        amplitudes[0] = (1.0, 0.0);
+        Self {
+            qubits,
+            amplitudes,
+            phantom: PhantomSpace::new(),
+        }
+    }
+
+    /// Number of qubits held by this register.
+    pub fn qubits(&self) -> usize {
+        self.qubits
+    }
+
+    /// The raw `2^n` amplitude vector, one `(real, imag)` pair per basis state.
+    pub fn amplitudes(&self) -> &[(f64, f64)] {
+        &self.amplitudes
+    }
+
+    /// Current phantom coherence backing this register's measurement history.
+    pub fn coherence(&self) -> f64 {
+        self.phantom.get_coherence()
+    }
+
+    /// Apply a single-qubit 2x2 unitary `gate` to qubit `q`, pairing every
+    /// basis state that has bit `q` clear with the one that has it set.
+    fn apply_single_qubit_gate<F>(&mut self, q: usize, gate: F)
+    where
+        F: Fn((f64, f64), (f64, f64)) -> ((f64, f64), (f64, f64)),
+    {
+        let mask = 1usize << q;
+        for i in 0..self.amplitudes.len() {
+            if i & mask == 0 {
+                let j = i | mask;
+                let (a0, a1) = gate(self.amplitudes[i], self.amplitudes[j]);
+                self.amplitudes[i] = a0;
+                self.amplitudes[j] = a1;
+            }
+        }
+    }
+
+    /// Hadamard gate: puts qubit `q` into an equal superposition.
+    pub fn hadamard(&mut self, q: usize) {
+        let f = core::f64::consts::FRAC_1_SQRT_2;
+        self.apply_single_qubit_gate(q, |(a0_re, a0_im), (a1_re, a1_im)| {
+            (
+                (f * (a0_re + a1_re), f * (a0_im + a1_im)),
+                (f * (a0_re - a1_re), f * (a0_im - a1_im)),
+            )
+        });
+    }
+
+    /// Phase gate: rotates the `|1>` component of qubit `q` by `theta` radians.
+    pub fn phase(&mut self, q: usize, theta: f64) {
+        let (cos_t, sin_t) = (theta.cos(), theta.sin());
+        self.apply_single_qubit_gate(q, |a0, (a1_re, a1_im)| {
+            (
+                a0,
+                (cos_t * a1_re - sin_t * a1_im, cos_t * a1_im + sin_t * a1_re),
+            )
+        });
+    }
+
+    /// Pauli-X (bit flip) gate on qubit `q`.
+    pub fn pauli_x(&mut self, q: usize) {
+        self.apply_single_qubit_gate(q, |a0, a1| (a1, a0));
+    }
+
+    /// Pauli-Y gate on qubit `q`.
+    pub fn pauli_y(&mut self, q: usize) {
+        self.apply_single_qubit_gate(q, |(a0_re, a0_im), (a1_re, a1_im)| {
+            ((a1_im, -a1_re), (-a0_im, a0_re))
+        });
+    }
+
+    /// Pauli-Z (phase flip) gate on qubit `q`.
+    pub fn pauli_z(&mut self, q: usize) {
+        self.apply_single_qubit_gate(q, |a0, (a1_re, a1_im)| (a0, (-a1_re, -a1_im)));
+    }
+
+    /// Controlled-NOT: flips `target` whenever `control` is set, by swapping
+    /// the target-bit amplitudes of every basis state with the control bit
+    /// set.
+    pub fn cnot(&mut self, control: usize, target: usize) {
+        let control_mask = 1usize << control;
+        let target_mask = 1usize << target;
+        for i in 0..self.amplitudes.len() {
+            if i & control_mask != 0 && i & target_mask == 0 {
+                let j = i | target_mask;
+                self.amplitudes.swap(i, j);
+            }
+        }
+    }
+
+    /// Probability that measuring qubit `q` right now would yield `1`.
+    pub fn probability_of_one(&self, q: usize) -> f64 {
+        let mask = 1usize << q;
+        self.amplitudes
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| i & mask != 0)
+            .map(|(_, (re, im))| re * re + im * im)
+            .sum()
+    }
+
+    /// Measure qubit `q`, collapsing the state vector to the outcome branch
+    /// and renormalizing it.
+    ///
+    /// Collapse always decays this register's backing `PhantomSpace`
+    /// coherence. If the surviving branch carried less probability mass than
+    /// `QUANTUM_STABILITY_THRESHOLD`, the collapse is too lossy to trust and
+    /// this returns `Err(QuantumError::CoherenceLoss)` instead of
+    /// renormalizing.
+    pub fn measure(&mut self, q: usize) -> Result<u8, QuantumError> {
+        let p_one = self.probability_of_one(q);
+        let outcome = if rand::random::<f64>() < p_one { 1u8 } else { 0u8 };
+        let norm_sq = if outcome == 1 { p_one } else { 1.0 - p_one };
+
+        self.phantom.decay_coherence();
+        if norm_sq < QUANTUM_STABILITY_THRESHOLD {
+            return Err(QuantumError::CoherenceLoss);
+        }
+
+        let mask = 1usize << q;
+        let norm = norm_sq.sqrt();
+        for (i, amp) in self.amplitudes.iter_mut().enumerate() {
+            if (i & mask != 0) == (outcome == 1) {
+                amp.0 /= norm;
+                amp.1 /= norm;
+            } else {
+                *amp = (0.0, 0.0);
+            }
+        }
+
+        Ok(outcome)
+    }
+}
+
+/// Epoch-based reclamation for `QuantumCell`.
+///
+/// The old implementation swapped in a new value and immediately dropped the
+/// displaced box. That is unsound under concurrent readers: a thread that
+/// loaded the old pointer in `get`/`quantum_load` can still be dereferencing
+/// it on another core while `set`/`quantum_store` frees it out from under
+/// them. This mirrors the scheme crossbeam-epoch uses internally, scaled
+/// down to what this crate needs: a single global epoch clock plus, per
+/// cell, three epoch-indexed garbage bags so retirement stays lock-free.
+mod epoch {
+    use core::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+
+    /// Monotonically increasing global epoch, advanced only while no reader
+    /// anywhere is pinned.
+    static GLOBAL_EPOCH: AtomicU64 = AtomicU64::new(0);
+    /// Count of readers currently pinned against `GLOBAL_EPOCH`.
+    static PINNED: AtomicUsize = AtomicUsize::new(0);
+
+    /// RAII guard marking a reader as active in the current epoch. While any
+    /// guard is alive the epoch cannot advance, so garbage stamped with an
+    /// epoch two generations older than the current one is guaranteed to be
+    /// unreachable by any live reader.
+    #[must_use]
+    pub struct Guard;
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            PINNED.fetch_sub(1, Ordering::AcqRel);
+        }
+    }
+
+    /// Pin the current reader to the global epoch for the duration of the
+    /// returned guard.
+    pub fn pin() -> Guard {
+        PINNED.fetch_add(1, Ordering::AcqRel);
+        Guard
+    }
+
+    /// Current global epoch.
+    pub fn current() -> u64 {
+        GLOBAL_EPOCH.load(Ordering::Acquire)
+    }
+
+    /// Advance the epoch by one if no reader is currently pinned, returning
+    /// the (possibly unchanged) epoch afterward.
+    pub fn try_advance() -> u64 {
+        if PINNED.load(Ordering::Acquire) == 0 {
+            GLOBAL_EPOCH.fetch_add(1, Ordering::AcqRel) + 1
+        } else {
+            GLOBAL_EPOCH.load(Ordering::Acquire)
+        }
+    }
+}
+
+/// Number of epoch-indexed garbage bags kept per `QuantumCell`. Bag
+/// `e % RECLAIM_BUCKETS` holds everything retired while the global epoch was
+/// `e`; once the epoch has advanced two generations past it, every reader
+/// that could have observed the retired pointer has necessarily unpinned, so
+/// the whole bag can be freed at once.
+const RECLAIM_BUCKETS: usize = 3;
+
+/// A single retired allocation awaiting reclamation, linked into its bag's
+/// Treiber stack so retirement never blocks.
+struct Retired<T> {
+    ptr: *mut T,
+    next: *mut Retired<T>,
+}
+
+/// Failure modes for `QuantumCell`'s compare-and-swap style operations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QuantumCellError<T> {
+    /// `quantum_compare_exchange` observed a value other than `current`, or
+    /// the `f` passed to `quantum_fetch_update` declined to produce a next
+    /// value; carries the value actually observed.
+    PhaseMisalignment(T),
+    /// `MAX_QUANTUM_CONTENTION_RETRIES` consecutive CAS attempts all lost
+    /// the race to another writer.
+    ContentionExhausted,
+}
+
 #[derive(Debug)]
 pub struct QuantumCell<T: Clone + 'static> {
     value: AtomicPtr<T>,
     coherence: AtomicU64,
     timestamp: AtomicPtr<usize>,
+    garbage: [AtomicPtr<Retired<T>>; RECLAIM_BUCKETS],
+    /// Sequence counter guarding `fast`: even while quiescent, odd while a
+    /// writer is in the middle of `quantum_store_fast`.
+    seq: AtomicU64,
+    /// Inline storage backing the seqlock fast-path. Kept in addition to
+    /// `value` so `Copy` callers can bypass the heap indirection and epoch
+    /// reclamation entirely when they only need the latest snapshot.
+    fast: std::cell::UnsafeCell<std::mem::MaybeUninit<T>>,
 }
 
+// SAFETY: access to `fast` is mediated entirely by `seq` using the seqlock
+// protocol below (an odd sequence means a write is in flight and readers
+// must retry), exactly as `value`'s access is mediated by the atomic
+// pointer swap it already performs.
+unsafe impl<T: Clone + Send + 'static> Sync for QuantumCell<T> {}
+
 impl<T: Clone + 'static> QuantumCell<T> {
     pub fn new(value: T) -> Self {
-        let ptr = Box::into_raw(Box::new(value));
+        let ptr = Box::into_raw(Box::new(value.clone()));
         let ts = Box::into_raw(Box::new(CURRENT_TIMESTAMP));
         Self {
             value: AtomicPtr::new(ptr),
             coherence: AtomicU64::new(f64::to_bits(1.0)),
             timestamp: AtomicPtr::new(ts),
+            garbage: [
+                AtomicPtr::new(core::ptr::null_mut()),
+                AtomicPtr::new(core::ptr::null_mut()),
+                AtomicPtr::new(core::ptr::null_mut()),
+            ],
+            seq: AtomicU64::new(0),
+            fast: std::cell::UnsafeCell::new(std::mem::MaybeUninit::new(value)),
         }
     }
 
     pub fn get(&self) -> T {
+        let _guard = epoch::pin();
         unsafe {
             (*self.value.load(Ordering::Acquire)).clone()
         }
@@ -126,8 +390,138 @@ impl<T: Clone + 'static> QuantumCell<T> {
     pub fn set(&self, value: T) {
         let new_ptr = Box::into_raw(Box::new(value));
         let old_ptr = self.value.swap(new_ptr, Ordering::AcqRel);
+        self.retire(old_ptr);
+    }
+
+    /// Store `new` only if the current value equals `current`, returning the
+    /// value observed immediately before the swap. Retries internally
+    /// against contention from other writers, but gives up with
+    /// `QuantumCellError::ContentionExhausted` after
+    /// `MAX_QUANTUM_CONTENTION_RETRIES` attempts rather than spinning
+    /// forever.
+    pub fn quantum_compare_exchange(
+        &self,
+        current: &T,
+        new: T,
+        ordering: &HeliumOrdering,
+    ) -> Result<T, QuantumCellError<T>>
+    where
+        T: PartialEq,
+    {
+        let _guard = epoch::pin();
+        let load_order = match ordering {
+            HeliumOrdering::Quantum => Ordering::SeqCst,
+            HeliumOrdering::Relaxed => Ordering::Relaxed,
+        };
+        for _ in 0..MAX_QUANTUM_CONTENTION_RETRIES {
+            let old_ptr = self.value.load(load_order);
+            let observed = unsafe { (*old_ptr).clone() };
+            if observed != *current {
+                return Err(QuantumCellError::PhaseMisalignment(observed));
+            }
+            let new_ptr = Box::into_raw(Box::new(new.clone()));
+            match self.value.compare_exchange(
+                old_ptr,
+                new_ptr,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => {
+                    self.retire(old_ptr);
+                    return Ok(observed);
+                }
+                Err(_) => {
+                    // Lost the race; undo the speculative allocation and retry.
+                    unsafe {
+                        drop(Box::from_raw(new_ptr));
+                    }
+                }
+            }
+        }
+        Err(QuantumCellError::ContentionExhausted)
+    }
+
+    /// Mirrors `AtomicU64::fetch_update`: repeatedly applies `f` to the
+    /// current value, swapping it in as soon as `f` returns `Some`, and
+    /// bailing out with `QuantumCellError::PhaseMisalignment` carrying the
+    /// last-observed value as soon as `f` returns `None`. Gives up with
+    /// `QuantumCellError::ContentionExhausted` if
+    /// `MAX_QUANTUM_CONTENTION_RETRIES` attempts all lose the race.
+    pub fn quantum_fetch_update<F>(
+        &self,
+        ordering: &HeliumOrdering,
+        mut f: F,
+    ) -> Result<T, QuantumCellError<T>>
+    where
+        F: FnMut(T) -> Option<T>,
+    {
+        let _guard = epoch::pin();
+        let load_order = match ordering {
+            HeliumOrdering::Quantum => Ordering::SeqCst,
+            HeliumOrdering::Relaxed => Ordering::Relaxed,
+        };
+        for _ in 0..MAX_QUANTUM_CONTENTION_RETRIES {
+            let old_ptr = self.value.load(load_order);
+            let observed = unsafe { (*old_ptr).clone() };
+            let next = match f(observed.clone()) {
+                Some(next) => next,
+                None => return Err(QuantumCellError::PhaseMisalignment(observed)),
+            };
+            let new_ptr = Box::into_raw(Box::new(next));
+            match self.value.compare_exchange(
+                old_ptr,
+                new_ptr,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => {
+                    self.retire(old_ptr);
+                    return Ok(observed);
+                }
+                Err(_) => unsafe {
+                    drop(Box::from_raw(new_ptr));
+                },
+            }
+        }
+        Err(QuantumCellError::ContentionExhausted)
+    }
+
+    /// Seqlock fast-path write: bypasses the heap-pointer indirection (and
+    /// the epoch-GC machinery that guards it) entirely. Intended for a
+    /// single writer racing many readers of `quantum_load_fast`; concurrent
+    /// writers must serialize externally, same as this crate's other
+    /// single-writer-assumed atomics.
+    pub fn quantum_store_fast(&self, value: T)
+    where
+        T: Copy,
+    {
+        let start = self.seq.fetch_add(1, Ordering::AcqRel);
+        debug_assert!(start % 2 == 0, "quantum_store_fast called concurrently with itself");
         unsafe {
-            drop(Box::from_raw(old_ptr));
+            (*self.fast.get()).write(value);
+        }
+        self.seq.store(start.wrapping_add(2), Ordering::Release);
+    }
+
+    /// Seqlock fast-path read: optimistically reads the inline snapshot and
+    /// retries if a writer was or is mid-update, never blocking and never
+    /// touching the epoch-reclaimed pointer path.
+    pub fn quantum_load_fast(&self) -> T
+    where
+        T: Copy,
+    {
+        loop {
+            let before = self.seq.load(Ordering::Acquire);
+            if before % 2 != 0 {
+                std::hint::spin_loop();
+                continue;
+            }
+            let value = unsafe { (*self.fast.get()).assume_init() };
+            let after = self.seq.load(Ordering::Acquire);
+            if before == after {
+                return value;
+            }
+            std::hint::spin_loop();
         }
     }
 
@@ -136,6 +530,7 @@ impl<T: Clone + 'static> QuantumCell<T> {
     }
 
     pub fn quantum_load(&self, ordering: &HeliumOrdering) -> Result<T, &'static str> {
+        let _guard = epoch::pin();
         let ptr = match ordering {
             HeliumOrdering::Quantum => self.value.load(Ordering::SeqCst),
             HeliumOrdering::Relaxed => self.value.load(Ordering::Relaxed),
@@ -158,11 +553,54 @@ impl<T: Clone + 'static> QuantumCell<T> {
         };
 
         if !old_ptr.is_null() {
+            self.retire(old_ptr);
+        }
+        Ok(())
+    }
+
+    /// Hand a displaced pointer off to the garbage bag for the current
+    /// epoch instead of dropping it immediately, then opportunistically
+    /// collect whichever bag is now safe to free.
+    fn retire(&self, ptr: *mut T) {
+        let guard = epoch::pin();
+        let bucket = epoch::current() as usize % RECLAIM_BUCKETS;
+        let node = Box::into_raw(Box::new(Retired { ptr, next: core::ptr::null_mut() }));
+        loop {
+            let head = self.garbage[bucket].load(Ordering::Acquire);
             unsafe {
-                drop(Box::from_raw(old_ptr));
+                (*node).next = head;
+            }
+            if self.garbage[bucket]
+                .compare_exchange_weak(head, node, Ordering::AcqRel, Ordering::Acquire)
+                .is_ok()
+            {
+                break;
+            }
+        }
+        drop(guard);
+        self.collect();
+    }
+
+    /// Advance the global epoch when no reader is pinned and, if it moved,
+    /// drain the bag that is now two epochs stale.
+    fn collect(&self) {
+        let epoch = epoch::try_advance();
+        if epoch == 0 {
+            return;
+        }
+        let stale = (epoch as usize + 1) % RECLAIM_BUCKETS;
+        self.drain_bucket(stale);
+    }
+
+    fn drain_bucket(&self, bucket: usize) {
+        let mut cur = self.garbage[bucket].swap(core::ptr::null_mut(), Ordering::AcqRel);
+        while !cur.is_null() {
+            unsafe {
+                let node = Box::from_raw(cur);
+                drop(Box::from_raw(node.ptr));
+                cur = node.next;
             }
         }
-        Ok(())
     }
 }
 
@@ -178,6 +616,9 @@ impl<T: Clone + 'static> Drop for QuantumCell<T> {
             drop(Box::from_raw(self.value.load(Ordering::Acquire)));
             drop(Box::from_raw(self.timestamp.load(Ordering::Acquire)));
         }
+        for bucket in 0..RECLAIM_BUCKETS {
+            self.drain_bucket(bucket);
+        }
     }
 }
 
@@ -215,4 +656,173 @@ mod tests {
         assert!(cell.quantum_store(84, &HeliumOrdering::Quantum).is_ok());
         assert_eq!(cell.quantum_load(&HeliumOrdering::Quantum).unwrap(), 84);
     }
+
+    #[test]
+    fn test_quantum_cell_reclaims_across_many_epochs() {
+        // Cycle well past RECLAIM_BUCKETS worth of retirements; every
+        // displaced value should end up freed rather than leaked or
+        // dropped while still reachable, and the live value must always
+        // reflect the most recent store.
+        let cell = QuantumCell::new(0);
+        for i in 1..=64 {
+            cell.set(i);
+            assert_eq!(cell.get(), i);
+        }
+    }
+
+    /// A value whose `Clone` takes long enough to land squarely inside a
+    /// concurrent writer's retirement window, used to pin a reader
+    /// mid-clone against the pointer `set` is racing to reclaim below.
+    #[derive(Debug)]
+    struct SlowClone(Vec<u64>);
+
+    impl SlowClone {
+        fn new(tag: u64) -> Self {
+            SlowClone(vec![tag; 4096])
+        }
+    }
+
+    impl Clone for SlowClone {
+        fn clone(&self) -> Self {
+            std::thread::sleep(std::time::Duration::from_millis(50));
+            SlowClone(self.0.clone())
+        }
+    }
+
+    #[test]
+    fn test_quantum_cell_reclaim_waits_for_pinned_reader() {
+        // A reader pinned mid-`get` (mid-clone, specifically) must never
+        // see the pointer it's reading freed out from under it, no matter
+        // how many times another thread calls `set` while it's pinned.
+        use std::sync::{Arc, Barrier};
+        use std::thread;
+
+        let cell = Arc::new(QuantumCell::new(SlowClone::new(0)));
+        let barrier = Arc::new(Barrier::new(2));
+
+        let reader = {
+            let cell = Arc::clone(&cell);
+            let barrier = Arc::clone(&barrier);
+            thread::spawn(move || {
+                barrier.wait();
+                // `get` pins the epoch for the duration of the clone below,
+                // so every retirement the writer queues while we sleep
+                // inside `SlowClone::clone` must stay un-reclaimed until we
+                // unpin.
+                cell.get()
+            })
+        };
+
+        barrier.wait();
+        for i in 1..=(RECLAIM_BUCKETS as u64 * 8) {
+            cell.set(SlowClone::new(i));
+        }
+
+        let observed = reader.join().expect("reader thread panicked");
+        assert!(
+            observed.0.iter().all(|&v| v == observed.0[0]),
+            "reader observed a torn value, meaning it read memory that was freed mid-clone",
+        );
+    }
+
+    #[test]
+    fn test_quantum_compare_exchange() {
+        let cell = QuantumCell::new(42);
+        assert_eq!(
+            cell.quantum_compare_exchange(&42, 84, &HeliumOrdering::Quantum),
+            Ok(42),
+        );
+        assert_eq!(cell.get(), 84);
+
+        match cell.quantum_compare_exchange(&42, 100, &HeliumOrdering::Quantum) {
+            Err(QuantumCellError::PhaseMisalignment(observed)) => assert_eq!(observed, 84),
+            other => panic!("expected PhaseMisalignment, got {:?}", other),
+        }
+        assert_eq!(cell.get(), 84);
+    }
+
+    #[test]
+    fn test_quantum_fetch_update() {
+        let cell = QuantumCell::new(1);
+        let result = cell.quantum_fetch_update(&HeliumOrdering::Quantum, |v| Some(v + 1));
+        assert_eq!(result, Ok(1));
+        assert_eq!(cell.get(), 2);
+
+        match cell.quantum_fetch_update(&HeliumOrdering::Quantum, |_| None) {
+            Err(QuantumCellError::PhaseMisalignment(observed)) => assert_eq!(observed, 2),
+            other => panic!("expected PhaseMisalignment, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_quantum_cell_fast_path() {
+        let cell = QuantumCell::new(1.0_f64);
+        cell.quantum_store_fast(2.0);
+        assert_eq!(cell.quantum_load_fast(), 2.0);
+        cell.quantum_store_fast(3.0);
+        assert_eq!(cell.quantum_load_fast(), 3.0);
+    }
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{} !~= {}", a, b);
+    }
+
+    #[test]
+    fn test_register_starts_in_ground_state() {
+        let reg = QuantumRegister::new(2);
+        assert_eq!(reg.amplitudes()[0], (1.0, 0.0));
+        assert!(reg.amplitudes()[1..].iter().all(|&a| a == (0.0, 0.0)));
+    }
+
+    #[test]
+    fn test_hadamard_creates_equal_superposition() {
+        let mut reg = QuantumRegister::new(1);
+        reg.hadamard(0);
+        assert_close(reg.probability_of_one(0), 0.5);
+    }
+
+    #[test]
+    fn test_pauli_x_flips_qubit() {
+        let mut reg = QuantumRegister::new(1);
+        reg.pauli_x(0);
+        assert_close(reg.probability_of_one(0), 1.0);
+    }
+
+    #[test]
+    fn test_pauli_z_leaves_probabilities_unchanged() {
+        let mut reg = QuantumRegister::new(1);
+        reg.hadamard(0);
+        reg.pauli_z(0);
+        assert_close(reg.probability_of_one(0), 0.5);
+    }
+
+    #[test]
+    fn test_cnot_entangles_control_and_target() {
+        let mut reg = QuantumRegister::new(2);
+        reg.hadamard(0);
+        reg.cnot(0, 1);
+        // |00> and |11> should each carry half the probability mass, with
+        // |01> and |10> left empty.
+        assert_close(reg.amplitudes()[0b00].0.powi(2), 0.5);
+        assert_close(reg.amplitudes()[0b11].0.powi(2), 0.5);
+        assert_eq!(reg.amplitudes()[0b01], (0.0, 0.0));
+        assert_eq!(reg.amplitudes()[0b10], (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_measure_collapses_to_certain_state() {
+        let mut reg = QuantumRegister::new(1);
+        reg.pauli_x(0);
+        assert_eq!(reg.measure(0), Ok(1));
+        assert_close(reg.amplitudes()[0].0.powi(2) + reg.amplitudes()[0].1.powi(2), 0.0);
+        assert_close(reg.amplitudes()[1].0.powi(2) + reg.amplitudes()[1].1.powi(2), 1.0);
+    }
+
+    #[test]
+    fn test_measure_decays_phantom_coherence() {
+        let mut reg = QuantumRegister::new(1);
+        let before = reg.coherence();
+        reg.measure(0).unwrap();
+        assert!(reg.coherence() < before);
+    }
 }
@@ -10,78 +10,232 @@
 //! - Wave function coherence
 //! - Temporal causality preservation
 
-use core::sync::atomic::{AtomicUsize, AtomicF64, Ordering, fence};
+use core::sync::atomic::{AtomicUsize, AtomicF64, Ordering};
+use crossbeam_utils::atomic::AtomicCell;
+use crossbeam_utils::sync::ShardedLock;
+use crossbeam_utils::CachePadded;
 use crate::vector::Vector3D;
 use crate::mesh_clock::{QuantumTimestamp, MeshClock};
 use crate::sunrise::Sunrise;
 use crate::grav::GravitationalConstants;
+use crate::contour_eigen::Complex64;
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
 
 /// System synchronization timestamp
 pub const SYSTEM_TIMESTAMP: usize = 1705204850; // 2025-01-14 04:40:50 UTC
 
+/// Number of independently-locked shards the bucket array is split
+/// across, keyed by the low bits of each position's hash, so concurrent
+/// inserts/queries into different regions of the 4D grid don't contend
+/// for the same lock. Must be a power of two.
+const SPACE_MAP_SHARDS: usize = 16;
+
+/// Minimum bucket length each shard is given, regardless of the
+/// requested capacity, so two colliding keys always have at least a
+/// few slots to probe through rather than landing in a single-slot
+/// shard with nowhere to go.
+const MIN_SHARD_BUCKET: usize = 4;
+
+/// A single discrete coordinate along one axis of a [`Topology`]'s grid:
+/// how many `space_quantum`-wide cells from the origin a real-space
+/// coordinate falls into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SpaceQuantum(pub u32);
+
+impl SpaceQuantum {
+    /// The inclusive lower/upper real-space bound this quantum covers
+    /// along one axis of `topo`'s grid.
+    pub fn axis_bounds(self, topo: &Topology) -> (f64, f64) {
+        let lower = self.0 as f64 * topo.space_quantum;
+        (lower, lower + topo.space_quantum)
+    }
+}
+
+/// Maps continuous 4D-mesh space onto a discrete grid of
+/// `space_quantum`-sided cells bounded by `dimensions`, so that nearby
+/// positions collapse onto the same [`SpaceMap`] node instead of every
+/// real-valued coordinate hashing to its own bucket.
+#[derive(Debug, Clone, Copy)]
+pub struct Topology {
+    /// Edge length of one grid cell, in the same units as `dimensions`.
+    pub space_quantum: f64,
+    /// Upper bound of the quantized space along each axis (x, y, z).
+    /// Coordinates outside `[0, dimensions[axis]]` clamp to the nearest
+    /// boundary quantum rather than wrapping or erroring.
+    pub dimensions: [f64; 3],
+}
+
+impl Topology {
+    pub fn new(space_quantum: f64, dimensions: [f64; 3]) -> Self {
+        Self { space_quantum, dimensions }
+    }
+
+    /// Quantizes a continuous position into one [`SpaceQuantum`] per
+    /// axis. Monotonic in each axis -- increasing a coordinate never
+    /// decreases its quantum -- so a contiguous real-space range always
+    /// quantizes to a contiguous run of quanta.
+    pub fn quantize(&self, pos: Vector3D<f64>) -> [SpaceQuantum; 3] {
+        let axes = [pos.x(), pos.y(), pos.z()];
+        let mut quanta = [SpaceQuantum(0); 3];
+
+        for axis in 0..3 {
+            let bound = self.dimensions[axis].max(0.0);
+            let clamped = axes[axis].clamp(0.0, bound);
+            let max_cell = (bound / self.space_quantum).floor() as u32;
+            let cell = ((clamped / self.space_quantum).floor() as u32).min(max_cell);
+            quanta[axis] = SpaceQuantum(cell);
+        }
+
+        quanta
+    }
+
+    /// The inclusive lower/upper real-space corner of the 3D cell that
+    /// `quanta` (as returned by [`Topology::quantize`]) identifies.
+    pub fn quantum_bounds(&self, quanta: [SpaceQuantum; 3]) -> (Vector3D<f64>, Vector3D<f64>) {
+        let (x_lo, x_hi) = quanta[0].axis_bounds(self);
+        let (y_lo, y_hi) = quanta[1].axis_bounds(self);
+        let (z_lo, z_hi) = quanta[2].axis_bounds(self);
+
+        (
+            Vector3D::new(x_lo, y_lo, z_lo),
+            Vector3D::new(x_hi, y_hi, z_hi),
+        )
+    }
+}
+
+/// The identity a [`SpaceNode`] was indexed under: the exact position it
+/// was inserted at, or -- under a [`Topology`] -- the quantum cell that
+/// position collapsed onto. Two insertions that hash to the same slot
+/// but carry different keys are a genuine collision and must both
+/// survive via probing; two insertions with the *same* key (e.g. two
+/// positions in one quantum) are intentionally treated as the same
+/// node and the second legitimately overwrites the first.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SlotKey {
+    Position(Vector3D<isize>),
+    Quantum([SpaceQuantum; 3]),
+}
+
 /// SpaceNode represents a point in quantum-aware vector space
 #[derive(Debug, Clone)]
 pub struct SpaceNode<T> {
     data: T,
-    quantum_state: AtomicF64,
-    gravity_influence: AtomicF64,
+    /// The position (or quantum) this node was indexed under, kept so a
+    /// hash collision with an unrelated key can be detected and probed
+    /// past instead of silently overwriting this node.
+    key: SlotKey,
+    quantum_state: AtomicCell<f64>,
+    /// Gravitational influence at this node's position. Purely a scored
+    /// attribute of the node -- unlike the slot address, it plays no
+    /// part in choosing where the node lives, so a later change to the
+    /// gravity field can never relocate (and thus lose) a stored node.
+    gravity_influence: AtomicCell<f64>,
     last_access: AtomicUsize,
-    coherence_factor: AtomicF64,
+    coherence_factor: AtomicCell<f64>,
     wave_function: WaveFunction,
 }
 
-/// WaveFunction tracks quantum state of spatial nodes
+/// One slot in a shard's bucket array. Plain `Option` can't support
+/// linear-probed removal: clearing a slot to `None` on `remove` would
+/// break the probe chain for any node that collided past it and is
+/// still live. `Tombstone` keeps the chain intact while still being
+/// reusable by a future `insert`.
+#[derive(Clone)]
+enum Slot<T> {
+    Empty,
+    Tombstone,
+    Occupied(SpaceNode<T>),
+}
+
+impl<T> Slot<T> {
+    fn is_stop(&self) -> bool {
+        matches!(self, Slot::Empty)
+    }
+}
+
+/// WaveFunction tracks quantum state of spatial nodes as a single
+/// complex amplitude (real/imaginary pair) rather than separate
+/// magnitude/phase scalars, so a state update can advance phase by
+/// multiplying by a complex phase factor instead of juggling the two
+/// independently.
 #[derive(Debug, Clone)]
 pub struct WaveFunction {
-    amplitude: AtomicF64,
-    phase: AtomicF64,
+    amplitude_re: AtomicF64,
+    amplitude_im: AtomicF64,
     coherence: AtomicF64,
 }
 
+/// One independently-locked region of the bucket array. `ShardedLock`
+/// keeps reads (the common case -- `get`) nearly contention-free across
+/// shards, and `CachePadded` keeps neighboring shards' lock metadata off
+/// the same cache line so hot regions of the grid don't false-share.
+type Shard<T> = CachePadded<ShardedLock<Vec<Slot<T>>>>;
+
 /// Main SpaceMap implementation
+///
+/// The bucket array is split into [`SPACE_MAP_SHARDS`] independently
+/// locked shards rather than one `Vec` behind a single `&mut self`
+/// borrow, so `insert`/`get`/`remove` all take `&self` and can run
+/// concurrently from multiple threads as long as they land in different
+/// shards.
 pub struct SpaceMap<T: Clone + 'static> {
-    nodes: Vec<Option<SpaceNode<T>>>,
+    shards: Vec<Shard<T>>,
     capacity: AtomicUsize,
     quantum_clock: MeshClock,
     gravity_field: GravitationalConstants,
     occupation_count: AtomicUsize,
     resize_threshold: f64,
+    /// When set, positions are quantized through this grid before
+    /// hashing, so every position within one `space_quantum` cube shares
+    /// a node instead of hashing to its own bucket. `None` keeps the
+    /// original exact-point hashing.
+    topology: Option<Topology>,
 }
 
 impl<T: Clone + 'static> SpaceMap<T> {
     /// Creates a new SpaceMap with quantum awareness
     pub fn new(initial_capacity: usize) -> Self {
-        fence(Ordering::SeqCst);
+        Self::new_inner(initial_capacity, None)
+    }
+
+    /// Creates a new SpaceMap whose positions are first quantized onto
+    /// `topology`'s grid, so nearby positions collapse onto the same
+    /// node instead of each exact coordinate hashing to its own bucket.
+    pub fn new_with_topology(topology: Topology, initial_capacity: usize) -> Self {
+        Self::new_inner(initial_capacity, Some(topology))
+    }
 
-        let mut nodes = Vec::with_capacity(initial_capacity);
-        nodes.resize_with(initial_capacity, || None);
+    fn new_inner(initial_capacity: usize, topology: Option<Topology>) -> Self {
+        let total_capacity = Self::round_capacity(initial_capacity);
+        let shards = (0..SPACE_MAP_SHARDS)
+            .map(|_| CachePadded::new(ShardedLock::new(Self::empty_bucket(total_capacity))))
+            .collect();
 
         Self {
-            nodes,
-            capacity: AtomicUsize::new(initial_capacity),
+            shards,
+            capacity: AtomicUsize::new(total_capacity),
             quantum_clock: MeshClock::new(),
             gravity_field: GravitationalConstants::new(),
             occupation_count: AtomicUsize::new(0),
             resize_threshold: 0.75,
+            topology,
         }
     }
 
     /// Inserts a value at the specified spatial coordinates
-    pub fn insert(&mut self, position: Vector3D<isize>, value: T) -> Option<T> {
-        fence(Ordering::SeqCst);
-
-        let index = self.calculate_quantum_index(&position);
+    pub fn insert(&self, position: Vector3D<isize>, value: T) -> Option<T> {
         let now = self.quantum_clock.quantum_now();
+        let key = self.key_for(&position);
 
         // Create new node with quantum state
         let node = SpaceNode {
             data: value,
-            quantum_state: AtomicF64::new(1.0),
-            gravity_influence: AtomicF64::new(self.calculate_gravity_influence(&position)),
+            key,
+            quantum_state: AtomicCell::new(1.0),
+            gravity_influence: AtomicCell::new(self.calculate_gravity_influence(&position)),
             last_access: AtomicUsize::new(now.as_raw()),
-            coherence_factor: AtomicF64::new(1.0),
+            coherence_factor: AtomicCell::new(1.0),
             wave_function: WaveFunction::new(),
         };
 
@@ -90,83 +244,160 @@ impl<T: Clone + 'static> SpaceMap<T> {
             self.quantum_resize();
         }
 
-        // Perform quantum-safe insertion
-        let result = match self.nodes.get_mut(index) {
-            Some(slot) => {
-                let old_value = slot.replace(node).map(|old_node| old_node.data);
-                if old_value.is_none() {
+        let (shard_id, start) = self.shard_and_start_index(key);
+        let mut bucket = self.shards[shard_id].write().unwrap();
+        let per_shard_capacity = bucket.len();
+
+        // Probe for either the matching key (overwrite in place) or the
+        // first empty/tombstone slot (new entry), never the first
+        // occupied-but-different-key slot (that would silently drop a
+        // distinct, merely-colliding node).
+        let mut first_reusable = None;
+        for step in 0..per_shard_capacity {
+            let index = (start + step) % per_shard_capacity;
+            match &bucket[index] {
+                Slot::Occupied(existing) if existing.key == key => {
+                    let old = std::mem::replace(&mut bucket[index], Slot::Occupied(node));
+                    return match old {
+                        Slot::Occupied(old_node) => Some(old_node.data),
+                        _ => unreachable!("matched slot was just observed as Occupied"),
+                    };
+                }
+                Slot::Empty => {
+                    let index = first_reusable.unwrap_or(index);
+                    bucket[index] = Slot::Occupied(node);
                     self.occupation_count.fetch_add(1, Ordering::SeqCst);
+                    return None;
+                }
+                Slot::Tombstone if first_reusable.is_none() => {
+                    first_reusable = Some(index);
                 }
-                old_value
+                _ => {}
             }
-            None => None,
-        };
+        }
 
-        fence(Ordering::SeqCst);
-        result
+        // Every slot was occupied by a different key or already claimed
+        // as a tombstone candidate; reuse the first tombstone we saw, if
+        // any. `should_resize` keeps this from running on a truly full
+        // shard in practice.
+        if let Some(index) = first_reusable {
+            bucket[index] = Slot::Occupied(node);
+            self.occupation_count.fetch_add(1, Ordering::SeqCst);
+        }
+        None
     }
 
     /// Retrieves a value from the specified spatial coordinates
     pub fn get(&self, position: &Vector3D<isize>) -> Option<T> {
-        fence(Ordering::SeqCst);
-
-        let index = self.calculate_quantum_index(position);
         let now = self.quantum_clock.quantum_now();
-
-        let result = self.nodes.get(index).and_then(|slot| {
-            slot.as_ref().map(|node| {
-                // Update quantum state and last access
-                node.last_access.store(now.as_raw(), Ordering::SeqCst);
-                node.update_quantum_state();
-                node.data.clone()
-            })
-        });
-
-        fence(Ordering::SeqCst);
-        result
+        let key = self.key_for(position);
+        let (shard_id, start) = self.shard_and_start_index(key);
+
+        let bucket = self.shards[shard_id].read().unwrap();
+        let per_shard_capacity = bucket.len();
+
+        for step in 0..per_shard_capacity {
+            let index = (start + step) % per_shard_capacity;
+            match &bucket[index] {
+                Slot::Occupied(node) if node.key == key => {
+                    // Update quantum state and last access
+                    node.last_access.store(now.as_raw(), Ordering::SeqCst);
+                    node.update_quantum_state();
+                    return Some(node.data.clone());
+                }
+                slot if slot.is_stop() => return None,
+                _ => {}
+            }
+        }
+        None
     }
 
     /// Removes a value from the specified spatial coordinates
-    pub fn remove(&mut self, position: &Vector3D<isize>) -> Option<T> {
-        fence(Ordering::SeqCst);
-
-        let index = self.calculate_quantum_index(position);
-
-        let result = if let Some(slot) = self.nodes.get_mut(index) {
-            if slot.is_some() {
-                self.occupation_count.fetch_sub(1, Ordering::SeqCst);
+    pub fn remove(&self, position: &Vector3D<isize>) -> Option<T> {
+        let key = self.key_for(position);
+        let (shard_id, start) = self.shard_and_start_index(key);
+        let mut bucket = self.shards[shard_id].write().unwrap();
+        let per_shard_capacity = bucket.len();
+
+        for step in 0..per_shard_capacity {
+            let index = (start + step) % per_shard_capacity;
+            match &bucket[index] {
+                Slot::Occupied(node) if node.key == key => {
+                    let old = std::mem::replace(&mut bucket[index], Slot::Tombstone);
+                    self.occupation_count.fetch_sub(1, Ordering::SeqCst);
+                    return match old {
+                        Slot::Occupied(old_node) => Some(old_node.data),
+                        _ => unreachable!("matched slot was just observed as Occupied"),
+                    };
+                }
+                slot if slot.is_stop() => return None,
+                _ => {}
             }
-            slot.take().map(|node| node.data)
-        } else {
-            None
-        };
-
-        fence(Ordering::SeqCst);
-        result
+        }
+        None
     }
 
-    /// Calculates quantum-aware spatial index
-    fn calculate_quantum_index(&self, position: &Vector3D<isize>) -> usize {
-        let mut hasher = DefaultHasher::new();
-        position.hash(&mut hasher);
+    /// Rounds `requested` up to the nearest multiple of
+    /// `SPACE_MAP_SHARDS`, with at least [`MIN_SHARD_BUCKET`] buckets
+    /// per shard, so every shard's bucket vector comes out the same
+    /// length *and* has enough room for linear probing to resolve a
+    /// collision -- a single-slot shard has nowhere to probe to.
+    fn round_capacity(requested: usize) -> usize {
+        let floor = requested.max(SPACE_MAP_SHARDS * MIN_SHARD_BUCKET);
+        ((floor + SPACE_MAP_SHARDS - 1) / SPACE_MAP_SHARDS) * SPACE_MAP_SHARDS
+    }
 
-        // Apply quantum corrections to hash
-        let base_hash = hasher.finish() as usize;
-        let quantum_factor = self.calculate_quantum_factor(position);
+    fn empty_bucket(total_capacity: usize) -> Vec<Slot<T>> {
+        let per_shard = total_capacity / SPACE_MAP_SHARDS;
+        let mut bucket = Vec::with_capacity(per_shard);
+        bucket.resize_with(per_shard, || Slot::Empty);
+        bucket
+    }
 
-        ((base_hash as f64 * quantum_factor) as usize) % self.capacity.load(Ordering::Relaxed)
+    /// The identity a position is indexed under: itself, or -- under a
+    /// `Topology` -- the quantum cell it collapses onto.
+    fn key_for(&self, position: &Vector3D<isize>) -> SlotKey {
+        match &self.topology {
+            Some(topology) => {
+                let continuous = Vector3D::new(
+                    position.x() as f64,
+                    position.y() as f64,
+                    position.z() as f64,
+                );
+                SlotKey::Quantum(topology.quantize(continuous))
+            }
+            None => SlotKey::Position(*position),
+        }
     }
 
-    /// Calculates quantum influence factor for position
-    fn calculate_quantum_factor(&self, position: &Vector3D<isize>) -> f64 {
-        let gravity = self.gravity_field.g.load(Ordering::Relaxed);
-        let distance = position.magnitude() as f64;
+    /// Splits a key into the shard it belongs to and the first slot to
+    /// probe in that shard's bucket. The shard is keyed on the low bits
+    /// of the key's stable hash -- independent of `capacity` -- so a
+    /// node never has to move shards across a resize, only slots within
+    /// one. Unlike the old indexing, this hash depends on nothing but
+    /// the key itself, so `get` after `insert` always lands on the same
+    /// shard and probe start regardless of what the gravity field does
+    /// in between.
+    fn shard_and_start_index(&self, key: SlotKey) -> (usize, usize) {
+        let hash = Self::stable_hash(key);
+        let shard_id = hash & (SPACE_MAP_SHARDS - 1);
+
+        let per_shard_capacity = self.capacity.load(Ordering::Relaxed) / SPACE_MAP_SHARDS;
+        (shard_id, hash % per_shard_capacity)
+    }
 
-        // Quantum correction based on gravitational field
-        1.0 + (gravity / (distance + 1.0)).sqrt()
+    fn stable_hash(key: SlotKey) -> usize {
+        let mut hasher = DefaultHasher::new();
+        match key {
+            SlotKey::Position(position) => position.hash(&mut hasher),
+            SlotKey::Quantum(quanta) => quanta.hash(&mut hasher),
+        }
+        hasher.finish() as usize
     }
 
-    /// Calculates gravitational influence at position
+    /// Calculates gravitational influence at position. A scored
+    /// attribute carried on the node for callers to read -- it has no
+    /// bearing on where the node is stored.
     fn calculate_gravity_influence(&self, position: &Vector3D<isize>) -> f64 {
         let g = self.gravity_field.g.load(Ordering::Relaxed);
         let r = position.magnitude() as f64;
@@ -186,83 +417,92 @@ impl<T: Clone + 'static> SpaceMap<T> {
         (occupation as f64 / capacity as f64) > self.resize_threshold
     }
 
-    /// Performs quantum-aware resize operation
-    fn quantum_resize(&mut self) {
-        fence(Ordering::SeqCst);
-
+    /// Performs quantum-aware resize operation. Every shard is resized
+    /// independently -- a node's shard never changes (see
+    /// `shard_and_start_index`), so this only needs one shard's write
+    /// lock at a time rather than a single global lock over the whole
+    /// map.
+    fn quantum_resize(&self) {
         let old_capacity = self.capacity.load(Ordering::Relaxed);
         let new_capacity = old_capacity * 2;
-
-        // Create new nodes vector with quantum initialization
-        let mut new_nodes = Vec::with_capacity(new_capacity);
-        new_nodes.resize_with(new_capacity, || None);
-
-        // Quantum-safe transfer of nodes
-        for old_node in self.nodes.drain(..) {
-            if let Some(node) = old_node {
-                // Recalculate quantum state during transfer
-                node.update_quantum_state();
-                // Insert into new location
-                let new_index = self.calculate_quantum_index(&node.position()) % new_capacity;
-                new_nodes[new_index] = Some(node);
+        let new_per_shard = new_capacity / SPACE_MAP_SHARDS;
+
+        for shard in &self.shards {
+            let mut bucket = shard.write().unwrap();
+            let mut new_bucket = Vec::with_capacity(new_per_shard);
+            new_bucket.resize_with(new_per_shard, || Slot::Empty);
+
+            for old_slot in bucket.drain(..) {
+                if let Slot::Occupied(node) = old_slot {
+                    // Recalculate quantum state during transfer
+                    node.update_quantum_state();
+
+                    let hash = Self::stable_hash(node.key);
+                    let start = hash % new_per_shard;
+                    let index = (0..new_per_shard)
+                        .map(|step| (start + step) % new_per_shard)
+                        .find(|&index| matches!(new_bucket[index], Slot::Empty))
+                        .expect("resized bucket has room for every surviving node");
+                    new_bucket[index] = Slot::Occupied(node);
+                }
             }
+
+            *bucket = new_bucket;
         }
 
-        self.nodes = new_nodes;
         self.capacity.store(new_capacity, Ordering::SeqCst);
-
-        fence(Ordering::SeqCst);
     }
 }
 
 impl<T: Clone> SpaceNode<T> {
     /// Updates quantum state of the node
     fn update_quantum_state(&self) {
-        let current_state = self.quantum_state.load(Ordering::Relaxed);
-        let coherence = self.coherence_factor.load(Ordering::Relaxed);
+        let current_state = self.quantum_state.load();
+        let coherence = self.coherence_factor.load();
 
         // Apply quantum decoherence effects
         let new_state = current_state * coherence;
 
-        self.quantum_state.store(new_state, Ordering::Relaxed);
+        self.quantum_state.store(new_state);
         self.wave_function.update(new_state);
     }
-
-    /// Gets the position of the node
-    fn position(&self) -> Vector3D<isize> {
-        // Calculate position from quantum state
-        let state = self.quantum_state.load(Ordering::Relaxed);
-        let gravity = self.gravity_influence.load(Ordering::Relaxed);
-
-        Vector3D::new(
-            (state * 1000.0) as isize,
-                      (gravity * 1000.0) as isize,
-                      0
-        )
-    }
 }
 
 impl WaveFunction {
     /// Creates a new wave function
     fn new() -> Self {
         Self {
-            amplitude: AtomicF64::new(1.0),
-            phase: AtomicF64::new(0.0),
+            amplitude_re: AtomicF64::new(1.0),
+            amplitude_im: AtomicF64::new(0.0),
             coherence: AtomicF64::new(1.0),
         }
     }
 
-    /// Updates wave function based on quantum state
-    fn update(&self, quantum_state: f64) {
-        let current_amplitude = self.amplitude.load(Ordering::Relaxed);
-        let current_phase = self.phase.load(Ordering::Relaxed);
+    /// This wave function's current complex amplitude.
+    pub fn amplitude(&self) -> Complex64 {
+        Complex64::new(
+            self.amplitude_re.load(Ordering::Relaxed),
+            self.amplitude_im.load(Ordering::Relaxed),
+        )
+    }
+
+    /// `|amplitude|^2`, this wave function's probability density.
+    pub fn probability(&self) -> f64 {
+        let amplitude = self.amplitude();
+        amplitude.re * amplitude.re + amplitude.im * amplitude.im
+    }
 
-        // Update amplitude and phase
-        let new_amplitude = current_amplitude * quantum_state;
-        let new_phase = (current_phase + std::f64::consts::PI / 4.0) % (2.0 * std::f64::consts::PI);
+    /// Updates wave function based on quantum state: scales the
+    /// amplitude by `quantum_state` and advances its phase by a fixed
+    /// `pi/4` step, via a single complex multiplication by `e^{i*pi/4}`
+    /// rather than separately updating a magnitude and a phase scalar.
+    fn update(&self, quantum_state: f64) {
+        let current = self.amplitude();
+        let phase_step = Complex64::from_polar(1.0, std::f64::consts::PI / 4.0);
+        let new_amplitude = current.scale(quantum_state).mul(phase_step);
 
-        self.amplitude.store(new_amplitude, Ordering::Relaxed);
-        self.phase.store(new_phase, Ordering::Relaxed);
+        self.amplitude_re.store(new_amplitude.re, Ordering::Relaxed);
+        self.amplitude_im.store(new_amplitude.im, Ordering::Relaxed);
         self.coherence.store(quantum_state, Ordering::Relaxed);
     }
 }
@@ -276,7 +516,7 @@ mod tests {
 
     #[test]
     fn test_spacemap_basic_operations() {
-        let mut map = SpaceMap::new(16);
+        let map = SpaceMap::new(16);
         let pos = Vector3D::new(1, 2, 3);
         let data = "test_data";
 
@@ -296,7 +536,7 @@ mod tests {
 
     #[test]
     fn test_gravitational_coherence() {
-        let mut map = SpaceMap::new(16);
+        let map = SpaceMap::new(16);
         let pos1 = Vector3D::new(0, 0, 0);
         let pos2 = Vector3D::new(1, 1, 1);
 
@@ -313,7 +553,7 @@ mod tests {
 
     #[test]
     fn test_quantum_entanglement() {
-        let mut map = SpaceMap::new(16);
+        let map = SpaceMap::new(16);
         let pos1 = Vector3D::new(1, 1, 1);
         let pos2 = Vector3D::new(-1, -1, -1);
 
@@ -330,7 +570,7 @@ mod tests {
 
     #[test]
     fn test_temporal_consistency() {
-        let mut map = SpaceMap::new(16);
+        let map = SpaceMap::new(16);
         let pos = Vector3D::new(1, 1, 1);
         let timestamp = QuantumTimestamp::now();
 
@@ -344,7 +584,7 @@ mod tests {
 
     #[test]
     fn test_wave_function_collapse() {
-        let mut map = SpaceMap::new(16);
+        let map = SpaceMap::new(16);
         let pos = Vector3D::new(0, 0, 0);
         let data = "wave_data";
 
@@ -359,7 +599,7 @@ mod tests {
 
     #[test]
     fn test_4d_coordinate_mapping() {
-        let mut map = SpaceMap::new(16);
+        let map = SpaceMap::new(16);
         let space_pos = Vector3D::new(1, 1, 1);
         let time_coord = 1705204961.0; // 2025-01-14 04:42:41 UTC
 
@@ -371,7 +611,7 @@ mod tests {
 
     #[test]
     fn test_compression_boundaries() {
-        let mut map = SpaceMap::new(16);
+        let map = SpaceMap::new(16);
         let center = Vector3D::new(0, 0, 0);
         let boundary = Vector3D::new(10, 10, 10);
 
@@ -392,7 +632,7 @@ mod tests {
 
     #[test]
     fn test_quantum_tunneling() {
-        let mut map = SpaceMap::new(16);
+        let map = SpaceMap::new(16);
         let start = Vector3D::new(0, 0, 0);
         let end = Vector3D::new(5, 5, 5);
         let data = "tunnel_data";
@@ -408,8 +648,11 @@ mod tests {
 
     #[test]
     fn test_memory_efficiency() {
-        let mut map = SpaceMap::new(16);
-        let initial_memory = map.nodes.len();
+        let map = SpaceMap::new(16);
+        let total_buckets = |map: &SpaceMap<String>| -> usize {
+            map.shards.iter().map(|shard| shard.read().unwrap().len()).sum()
+        };
+        let initial_memory = total_buckets(&map);
 
         // Add 1000 data points
         for i in 0..10 {
@@ -421,10 +664,103 @@ mod tests {
             }
         }
 
-        let final_memory = map.nodes.len();
+        let final_memory = total_buckets(&map);
         let bytes_per_point = (final_memory - initial_memory) / 1000;
 
         assert!(bytes_per_point < 64,
                 "Memory usage per point should be optimized");
     }
+
+    #[test]
+    fn test_wave_function_update_advances_phase() {
+        let wave = WaveFunction::new();
+        let initial = wave.amplitude();
+        assert_eq!(initial.arg(), 0.0);
+
+        wave.update(1.0);
+        let updated = wave.amplitude();
+
+        // A pi/4 phase step at unchanged modulus leaves |amplitude|
+        // roughly where it started, but rotates its argument.
+        assert!((updated.abs() - initial.abs()).abs() < 1e-9);
+        assert!((updated.arg() - std::f64::consts::PI / 4.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_topology_quantize_collapses_nearby_points() {
+        let topo = Topology::new(1.0, [10.0, 10.0, 10.0]);
+
+        let a = topo.quantize(Vector3D::new(1.1, 1.2, 1.3));
+        let b = topo.quantize(Vector3D::new(1.9, 1.9, 1.9));
+
+        assert_eq!(a, b, "positions within the same quantum should collapse together");
+    }
+
+    #[test]
+    fn test_topology_quantize_clamps_out_of_bounds() {
+        let topo = Topology::new(2.0, [10.0, 10.0, 10.0]);
+
+        let inside = topo.quantize(Vector3D::new(10.0, 10.0, 10.0));
+        let outside = topo.quantize(Vector3D::new(1000.0, 1000.0, 1000.0));
+
+        assert_eq!(inside, outside, "out-of-bounds coordinates should clamp to the boundary quantum");
+    }
+
+    #[test]
+    fn test_topology_quantum_bounds_round_trip() {
+        let topo = Topology::new(2.5, [10.0, 10.0, 10.0]);
+        let quanta = topo.quantize(Vector3D::new(3.0, 3.0, 3.0));
+
+        let (lower, upper) = topo.quantum_bounds(quanta);
+
+        assert!(lower.x() <= 3.0 && 3.0 < upper.x());
+        assert!(lower.y() <= 3.0 && 3.0 < upper.y());
+        assert!(lower.z() <= 3.0 && 3.0 < upper.z());
+    }
+
+    #[test]
+    fn test_spacemap_with_topology_collapses_nearby_inserts() {
+        let topo = Topology::new(4.0, [100.0, 100.0, 100.0]);
+        let map: SpaceMap<String> = SpaceMap::new_with_topology(topo, 16);
+
+        map.insert(Vector3D::new(1, 1, 1), "first".to_string());
+        let replaced = map.insert(Vector3D::new(2, 2, 2), "second".to_string());
+
+        assert_eq!(replaced, Some("first".to_string()),
+                   "positions in the same quantum should land in the same node");
+    }
+
+    #[test]
+    fn test_distinct_positions_survive_hash_collisions() {
+        let map = SpaceMap::new(4);
+        let positions: Vec<_> = (0..50)
+            .map(|i| Vector3D::new(i, i * 2, i * 3))
+            .collect();
+
+        for (i, pos) in positions.iter().enumerate() {
+            map.insert(pos.clone(), i);
+        }
+
+        for (i, pos) in positions.iter().enumerate() {
+            assert_eq!(
+                map.get(pos),
+                Some(i),
+                "every distinct position must remain retrievable even once two of them hash into the same slot"
+            );
+        }
+    }
+
+    #[test]
+    fn test_get_after_insert_is_stable_regardless_of_node_mutation() {
+        let map = SpaceMap::new(16);
+        let pos = Vector3D::new(3, 3, 3);
+        map.insert(pos.clone(), "stable".to_string());
+
+        // Each `get` mutates the node's quantum_state/gravity_influence,
+        // but indexing no longer factors those in, so repeated reads
+        // keep resolving to the same slot instead of drifting off it.
+        for _ in 0..10 {
+            assert_eq!(map.get(&pos), Some("stable".to_string()));
+        }
+    }
 }
@@ -18,14 +18,23 @@ use crate::{
 
 use core::{
     cell::UnsafeCell,
+    convert::Infallible,
     ops::{Deref, DerefMut},
-    sync::atomic::{AtomicBool, AtomicU64, Ordering},
+    sync::atomic::{AtomicU8, AtomicU64, Ordering},
 };
 
+/// `state` has not yet been claimed by an initializer.
+const UNINIT: u8 = 0;
+/// A thread won the CAS out of `UNINIT` and is running the initializer.
+const INITIALIZING: u8 = 1;
+/// The value is published and safe to read.
+const INIT: u8 = 2;
+
 /// Quantum state for static initialization
 pub struct Sun_rise<T> {
-    /// Initialization state with quantum protection
-    initialized: AtomicBool,
+    /// `UNINIT` -> `INITIALIZING` -> `INIT`, driven by `compare_exchange`
+    /// so only one racing thread ever writes into `value`.
+    state: AtomicU8,
     /// Protected value storage
     value: UnsafeCell<Option<T>>,
     /// Quantum coherence tracking
@@ -41,7 +50,7 @@ impl<T> Sun_rise<T> {
     /// Creates a new quantum-safe static initializer
     pub const fn new() -> Self {
         Self {
-            initialized: AtomicBool::new(false),
+            state: AtomicU8::new(UNINIT),
             value: UnsafeCell::new(None),
             coherence: AtomicU64::new(f64_to_bits(1.0)),
             crystal: ShardMemoryPattern::new(MemoryBlock::new(64)),
@@ -50,28 +59,69 @@ impl<T> Sun_rise<T> {
 
     /// Initialize the value with quantum protection
     pub fn init(&self, value: T) -> Result<bool, &'static str> {
-        // Check quantum stability
         if !self.is_quantum_stable() {
             return Err("Quantum state unstable");
         }
 
-        // Check if already initialized using quantum-safe compare
-        if self.initialized.load(Ordering::SeqCst) {
-            return Ok(false);
+        match self.try_initialize(|| Ok::<T, Infallible>(value)) {
+            Ok(did_init) => Ok(did_init),
+            Err(never) => match never {},
         }
+    }
 
-        // Initialize value with crystal structure protection
-        unsafe {
-            *self.value.get() = Some(value);
+    /// Returns the value, running `f` to produce and publish it if no
+    /// caller has done so yet. Concurrent callers race on who runs `f`;
+    /// everyone else spins until the winner publishes and then reads the
+    /// same value -- no caller observes a half-initialized cell.
+    pub fn get_or_init(&self, f: impl FnOnce() -> T) -> &T {
+        match self.get_or_try_init(|| Ok::<T, Infallible>(f())) {
+            Ok(value) => value,
+            Err(never) => match never {},
         }
+    }
 
-        // Grow crystal structure for stability
-        self.crystal.grow_crystal(FAIRY_DUST_COEFFICIENT);
+    /// Like [`get_or_init`](Self::get_or_init), but `f` may fail. If it
+    /// does, the slot is rolled back to `UNINIT` so a later caller gets a
+    /// chance to retry instead of being stuck behind a failed attempt.
+    pub fn get_or_try_init<E>(&self, f: impl FnOnce() -> Result<T, E>) -> Result<&T, E> {
+        self.try_initialize(f)?;
+
+        Ok(unsafe {
+            (*self.value.get())
+                .as_ref()
+                .expect("Sun_rise reached INIT without a published value")
+        })
+    }
 
-        // Mark as initialized with quantum barrier
-        self.initialized.store(true, Ordering::SeqCst);
-        self.decay_coherence();
-        Ok(true)
+    /// Drives the `UNINIT -> INITIALIZING -> INIT` state machine. Returns
+    /// `Ok(true)` if this call won the race and ran `f`, `Ok(false)` if the
+    /// value was already (or concurrently became) initialized, or
+    /// propagates `f`'s error after rolling the state back to `UNINIT`.
+    fn try_initialize<E>(&self, f: impl FnOnce() -> Result<T, E>) -> Result<bool, E> {
+        loop {
+            match self.state.compare_exchange(UNINIT, INITIALIZING, Ordering::Acquire, Ordering::Acquire) {
+                Ok(_) => {
+                    return match f() {
+                        Ok(value) => {
+                            unsafe {
+                                *self.value.get() = Some(value);
+                            }
+                            self.crystal.grow_crystal(FAIRY_DUST_COEFFICIENT);
+                            self.state.store(INIT, Ordering::Release);
+                            self.decay_coherence();
+                            Ok(true)
+                        }
+                        Err(e) => {
+                            self.state.store(UNINIT, Ordering::Release);
+                            Err(e)
+                        }
+                    };
+                }
+                Err(INIT) => return Ok(false),
+                Err(INITIALIZING) => core::hint::spin_loop(),
+                Err(_) => unreachable!("Sun_rise state is only ever UNINIT/INITIALIZING/INIT"),
+            }
+        }
     }
 
     /// Get immutable reference with quantum checks
@@ -80,7 +130,7 @@ impl<T> Sun_rise<T> {
             return None;
         }
 
-        if !self.initialized.load(Ordering::SeqCst) {
+        if self.state.load(Ordering::Acquire) != INIT {
             return None;
         }
 
@@ -97,7 +147,7 @@ impl<T> Sun_rise<T> {
             return None;
         }
 
-        if !self.initialized.load(Ordering::SeqCst) {
+        if self.state.load(Ordering::Acquire) != INIT {
             return None;
         }
 
@@ -130,7 +180,7 @@ impl<T> Sun_rise<T> {
 
     /// Reset quantum coherence with crystal realignment
     pub fn reset_coherence(&self) -> Result<(), &'static str> {
-        if self.initialized.load(Ordering::SeqCst) {
+        if self.state.load(Ordering::Acquire) == INIT {
             self.coherence.store(f64_to_bits(1.0), Ordering::SeqCst);
 
             // Realign crystal structure
@@ -163,12 +213,7 @@ fn bits_to_f64(v: u64) -> f64 {
 macro_rules! sun_rise {
     ($init:expr) => {{
         static SUN_RISE: $crate::sun_rise::Sun_rise<_> = $crate::sun_rise::Sun_rise::new();
-        if SUN_RISE.get().is_none() {
-            if let Err(e) = SUN_RISE.init($init) {
-                panic!("Sun_rise initialization failed: {}", e);
-            }
-        }
-        SUN_RISE.get().expect("Sun_rise value unavailable")
+        SUN_RISE.get_or_init(|| $init)
     }};
 }
 
@@ -185,18 +230,14 @@ macro_rules! sun_rise_quantum {
         }
 
         // Initialize with crystal structure protection
-        if SUN_RISE.get().is_none() {
-            if let Err(e) = SUN_RISE.init($init) {
-                panic!("Quantum Sun_rise initialization failed: {}", e);
-            }
-        }
+        let value = SUN_RISE.get_or_init(|| $init);
 
         // Verify crystal resonance
         if SUN_RISE.crystal_resonance() < FAIRY_DUST_COEFFICIENT {
             panic!("Crystal structure unstable");
         }
 
-        SUN_RISE.get().expect("Quantum Sun_rise value unavailable")
+        value
     }};
 }
 
@@ -259,4 +300,25 @@ mod tests {
         let value = sun_rise_quantum!(42);
         assert_eq!(*value, 42);
     }
+
+    #[test]
+    fn test_get_or_init_runs_once() {
+        let sun_rise = Sun_rise::<i32>::new();
+        let mut calls = 0;
+
+        assert_eq!(*sun_rise.get_or_init(|| { calls += 1; 7 }), 7);
+        assert_eq!(*sun_rise.get_or_init(|| { calls += 1; 9 }), 7);
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn test_get_or_try_init_rolls_back_on_error() {
+        let sun_rise = Sun_rise::<i32>::new();
+
+        assert_eq!(sun_rise.get_or_try_init(|| Err::<i32, &'static str>("boom")), Err("boom"));
+        assert_eq!(sun_rise.get(), None);
+
+        assert_eq!(sun_rise.get_or_try_init(|| Ok::<i32, &'static str>(5)), Ok(&5));
+        assert_eq!(sun_rise.get(), Some(&5));
+    }
 }
@@ -3,6 +3,9 @@
 /// Author: isdood
 /// Current User: isdood
 
+use core::ptr;
+use core::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+
 use crate::{
     helium::{Helium, HeliumOrdering},
     phantom::QuantumCell,
@@ -11,6 +14,17 @@ use crate::{
 
 const QUANTUM_COHERENCE_THRESHOLD: f64 = 0.5;
 
+/// Maximum number of distinct `TrackedUFO`s an `EntanglementRegistry` can
+/// track at once. Fixed at compile time: this crate has no allocator to
+/// grow a dynamic collection into.
+pub const MAX_ENTANGLED_UFOS: usize = 64;
+
+/// Fraction of a coherence drop that [`EntanglementRegistry::decay`]
+/// propagates from a decaying UFO to the rest of its entangled group.
+/// Kept small and sub-unity so repeated decays converge toward a shared
+/// coherence floor rather than oscillating around it.
+const PROPAGATION_FRACTION: f64 = 0.1;
+
 #[derive(Debug)]
 pub struct TrackedUFO {
     origin: Helium<usize>,
@@ -105,10 +119,15 @@ impl TrackedUFO {
 
     fn decay_coherence(&self) {
         let current = self.coherence.load(HeliumOrdering::Acquire);
-        let new_coherence = current * 0.99;
+        self.set_coherence(current * 0.99);
+    }
+
+    /// Store `new_coherence` and recompute `quantum_state` from it.
+    /// Shared by `decay_coherence` and [`EntanglementRegistry`]'s
+    /// propagation so both update state the same way.
+    pub(crate) fn set_coherence(&self, new_coherence: f64) {
         self.coherence.store(new_coherence, HeliumOrdering::Release);
 
-        // Update quantum state based on coherence
         let new_state = if new_coherence > 0.9 {
             UFOState::Stable
         } else if new_coherence > 0.7 {
@@ -122,6 +141,14 @@ impl TrackedUFO {
         self.quantum_state.set(new_state);
     }
 
+    /// Force this UFO straight to `UFOState::Decoherent`, used by
+    /// [`EntanglementRegistry::collapse`] when any member of its group
+    /// decoheres.
+    pub(crate) fn force_decoherent(&self) {
+        self.coherence.store(0.0, HeliumOrdering::Release);
+        self.quantum_state.set(UFOState::Decoherent);
+    }
+
     pub fn entangle_with(&self, other: &TrackedUFO) -> Result<(), &'static str> {
         if !self.is_quantum_stable() || !other.is_quantum_stable() {
             return Err("One or both UFOs are quantum unstable");
@@ -134,6 +161,188 @@ impl TrackedUFO {
     }
 }
 
+/// Tracks which `TrackedUFO`s are entangled together as a union-find over
+/// raw pointers, so that a coherence drop on one member can propagate to
+/// its whole group the way entanglement physically implies -- unlike
+/// `TrackedUFO::entangle_with`, which only averages two peers once and
+/// keeps no lasting relationship.
+///
+/// Backed by a fixed-size table since this crate has no allocator to
+/// grow a dynamic collection into; entangling more than
+/// `MAX_ENTANGLED_UFOS` distinct UFOs returns `Err`. Every `TrackedUFO`
+/// passed in must outlive the registry and never move in memory -- the
+/// same invariant `Helium`/`QuantumCell` already rely on for their own
+/// pointer-based state.
+pub struct EntanglementRegistry {
+    members: [AtomicPtr<TrackedUFO>; MAX_ENTANGLED_UFOS],
+    parent: [AtomicUsize; MAX_ENTANGLED_UFOS],
+    len: AtomicUsize,
+}
+
+impl EntanglementRegistry {
+    pub fn new() -> Self {
+        Self {
+            members: core::array::from_fn(|_| AtomicPtr::new(ptr::null_mut())),
+            parent: core::array::from_fn(|i| AtomicUsize::new(i)),
+            len: AtomicUsize::new(0),
+        }
+    }
+
+    fn index_of(&self, ufo: &TrackedUFO) -> Option<usize> {
+        let target = ufo as *const TrackedUFO as *mut TrackedUFO;
+        let len = self.len.load(Ordering::Acquire);
+        (0..len).find(|&i| self.members[i].load(Ordering::Acquire) == target)
+    }
+
+    fn register(&self, ufo: &TrackedUFO) -> Result<usize, &'static str> {
+        if let Some(idx) = self.index_of(ufo) {
+            return Ok(idx);
+        }
+
+        let idx = self.len.fetch_add(1, Ordering::AcqRel);
+        if idx >= MAX_ENTANGLED_UFOS {
+            self.len.fetch_sub(1, Ordering::AcqRel);
+            return Err("EntanglementRegistry is full");
+        }
+
+        self.members[idx].store(ufo as *const TrackedUFO as *mut TrackedUFO, Ordering::Release);
+        self.parent[idx].store(idx, Ordering::Release);
+        Ok(idx)
+    }
+
+    /// Union-find root of `idx`, with path compression.
+    fn find(&self, idx: usize) -> usize {
+        let mut root = idx;
+        while self.parent[root].load(Ordering::Acquire) != root {
+            root = self.parent[root].load(Ordering::Acquire);
+        }
+
+        let mut current = idx;
+        while current != root {
+            let next = self.parent[current].load(Ordering::Acquire);
+            self.parent[current].store(root, Ordering::Release);
+            current = next;
+        }
+
+        root
+    }
+
+    /// Calls `f` for every UFO entangled with `ufo`, including `ufo`
+    /// itself if it has never been registered.
+    fn for_each_in_group<F: FnMut(&TrackedUFO)>(&self, ufo: &TrackedUFO, mut f: F) {
+        let Some(idx) = self.index_of(ufo) else {
+            f(ufo);
+            return;
+        };
+
+        let root = self.find(idx);
+        let len = self.len.load(Ordering::Acquire);
+        for i in 0..len {
+            if self.find(i) != root {
+                continue;
+            }
+            let ptr = self.members[i].load(Ordering::Acquire);
+            if let Some(member) = unsafe { ptr.as_ref() } {
+                f(member);
+            }
+        }
+    }
+
+    /// Register `a` and `b` as entangled, merging their groups if either
+    /// is already entangled with others. Fails if either UFO has already
+    /// decohered -- a decohered UFO cannot be re-entangled -- or if the
+    /// registry is full.
+    pub fn entangle(&self, a: &TrackedUFO, b: &TrackedUFO) -> Result<(), &'static str> {
+        if !a.is_quantum_stable() || !b.is_quantum_stable() {
+            return Err("One or both UFOs are quantum unstable");
+        }
+
+        let ia = self.register(a)?;
+        let ib = self.register(b)?;
+        let ra = self.find(ia);
+        let rb = self.find(ib);
+        if ra != rb {
+            self.parent[ra].store(rb, Ordering::Release);
+        }
+
+        let shared = self.group_coherence(a);
+        self.for_each_in_group(a, |member| member.set_coherence(shared));
+        Ok(())
+    }
+
+    /// Mean coherence of every UFO entangled with `ufo` (including
+    /// `ufo`); just `ufo`'s own coherence if it isn't entangled with
+    /// anything.
+    pub fn group_coherence(&self, ufo: &TrackedUFO) -> f64 {
+        let mut total = 0.0;
+        let mut count = 0usize;
+        self.for_each_in_group(ufo, |member| {
+            total += member.get_coherence();
+            count += 1;
+        });
+
+        if count == 0 {
+            ufo.get_coherence()
+        } else {
+            total / count as f64
+        }
+    }
+
+    /// Decay `ufo`'s coherence by `factor` (typically just under 1.0),
+    /// the same way `TrackedUFO::decay_coherence` would on its own, then
+    /// propagate a `PROPAGATION_FRACTION` share of the drop to every
+    /// other member of its entangled group. The propagated share only
+    /// ever subtracts and floors at 0.0, so repeated decays converge
+    /// toward a shared coherence rather than oscillating around it.
+    /// Collapses the group if any member ends up decoherent.
+    pub fn decay(&self, ufo: &TrackedUFO, factor: f64) {
+        let before = ufo.get_coherence();
+        let after = before * factor;
+        ufo.set_coherence(after);
+
+        let drop = (before - after).max(0.0);
+        let shared_drop = drop * PROPAGATION_FRACTION;
+        if shared_drop > 0.0 {
+            self.for_each_in_group(ufo, |member| {
+                if ptr::eq(member, ufo) {
+                    return;
+                }
+                let decayed = (member.get_coherence() - shared_drop).max(0.0);
+                member.set_coherence(decayed);
+            });
+        }
+
+        if !ufo.is_quantum_stable() {
+            self.collapse(ufo);
+        }
+    }
+
+    /// Force every member of `ufo`'s entangled group to
+    /// `UFOState::Decoherent` and sever the group, so a decohered UFO
+    /// stops spreading further drops and can't be re-entangled. Safe to
+    /// call whenever any member reaches `UFOState::Decoherent`;
+    /// idempotent.
+    pub fn collapse(&self, ufo: &TrackedUFO) {
+        self.for_each_in_group(ufo, |member| member.force_decoherent());
+
+        if let Some(idx) = self.index_of(ufo) {
+            let root = self.find(idx);
+            let len = self.len.load(Ordering::Acquire);
+            for i in 0..len {
+                if self.find(i) == root {
+                    self.parent[i].store(i, Ordering::Release);
+                }
+            }
+        }
+    }
+}
+
+impl Default for EntanglementRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -185,4 +394,87 @@ mod tests {
         assert!(ufo1.entangle_with(&ufo2).is_ok());
         assert_eq!(ufo1.get_quantum_state(), UFOState::Entangled);
     }
+
+    #[test]
+    fn test_registry_merges_already_entangled_groups() {
+        let a = TrackedUFO::new(0x1000, 0x1000);
+        let b = TrackedUFO::new(0x2000, 0x1000);
+        let c = TrackedUFO::new(0x3000, 0x1000);
+        let d = TrackedUFO::new(0x4000, 0x1000);
+
+        let registry = EntanglementRegistry::new();
+        registry.entangle(&a, &b).unwrap();
+        registry.entangle(&c, &d).unwrap();
+        registry.entangle(&b, &c).unwrap();
+
+        // All four should now report the same group coherence.
+        let expected = registry.group_coherence(&a);
+        assert_eq!(registry.group_coherence(&d), expected);
+    }
+
+    #[test]
+    fn test_registry_rejects_reentangling_decoherent_ufo() {
+        let a = TrackedUFO::new(0x1000, 0x1000);
+        let b = TrackedUFO::new(0x2000, 0x1000);
+
+        let registry = EntanglementRegistry::new();
+        registry.entangle(&a, &b).unwrap();
+
+        for _ in 0..50 {
+            registry.decay(&a, 0.9);
+        }
+        assert_eq!(a.get_quantum_state(), UFOState::Decoherent);
+
+        let c = TrackedUFO::new(0x3000, 0x1000);
+        assert!(registry.entangle(&a, &c).is_err());
+    }
+
+    #[test]
+    fn test_registry_propagates_decay_to_peers() {
+        let a = TrackedUFO::new(0x1000, 0x1000);
+        let b = TrackedUFO::new(0x2000, 0x1000);
+
+        let registry = EntanglementRegistry::new();
+        registry.entangle(&a, &b).unwrap();
+        let before = b.get_coherence();
+
+        registry.decay(&a, 0.5);
+
+        assert!(b.get_coherence() < before);
+    }
+
+    #[test]
+    fn test_registry_decay_converges_instead_of_oscillating() {
+        let a = TrackedUFO::new(0x1000, 0x1000);
+        let b = TrackedUFO::new(0x2000, 0x1000);
+
+        let registry = EntanglementRegistry::new();
+        registry.entangle(&a, &b).unwrap();
+
+        let mut previous = b.get_coherence();
+        for _ in 0..20 {
+            registry.decay(&a, 0.95);
+            let current = b.get_coherence();
+            assert!(current <= previous);
+            previous = current;
+        }
+    }
+
+    #[test]
+    fn test_registry_collapse_severs_group() {
+        let a = TrackedUFO::new(0x1000, 0x1000);
+        let b = TrackedUFO::new(0x2000, 0x1000);
+
+        let registry = EntanglementRegistry::new();
+        registry.entangle(&a, &b).unwrap();
+        registry.collapse(&a);
+
+        assert_eq!(a.get_quantum_state(), UFOState::Decoherent);
+        assert_eq!(b.get_quantum_state(), UFOState::Decoherent);
+
+        // Severed: decaying a afterward no longer touches a fresh peer
+        // that gets entangled with b alone.
+        let c = TrackedUFO::new(0x3000, 0x1000);
+        assert!(registry.entangle(&b, &c).is_err());
+    }
 }
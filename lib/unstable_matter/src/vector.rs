@@ -512,6 +512,54 @@ impl Vector4D<f64> {
          MeshMath::isize_to_f64(self.w as isize)
         )
     }
+
+    /// L_p norm of the vector's components, via the scaled accumulation
+    /// BLAS's `nrm2`/`nrmp` use: tracking a running `scale` and `ssq`
+    /// rather than summing `|x|^p` directly keeps this overflow/underflow
+    /// safe for lattice vectors whose components span many orders of
+    /// magnitude near `PLANCK_LENGTH`, where `magnitude`'s plain
+    /// sum-of-squares would otherwise lose precision.
+    pub fn norm_p(&self, p: f64) -> f64 {
+        scaled_norm_p(&[self.x, self.y, self.z, self.w], p)
+    }
+}
+
+/// Overflow/underflow-safe L_p norm of `values`, via scaled accumulation.
+///
+/// Keeps a running `scale` (the largest `|x|` seen so far) and `ssq` (the
+/// sum of powers relative to that scale): whenever a larger-magnitude
+/// element arrives, `ssq` is rescaled down before folding the new element
+/// in, rather than letting `|x|^p` overflow or underflow directly.
+/// Zero elements are skipped. Special-cases `p == 1.0` (plain sum of
+/// absolute values) and `p == 2.0` (classic sum-of-squares), since those
+/// dominate real usage and don't need the general `powf` path.
+fn scaled_norm_p(values: &[f64], p: f64) -> f64 {
+    if p == 1.0 {
+        return values.iter().map(|x| libm::fabs(*x)).sum();
+    }
+    if p == 2.0 {
+        let sum_sq: f64 = values.iter().map(|x| x * x).sum();
+        return libm::sqrt(sum_sq);
+    }
+
+    let mut scale = 0.0_f64;
+    let mut ssq = 1.0_f64;
+
+    for &x in values {
+        let ax = libm::fabs(x);
+        if ax == 0.0 {
+            continue;
+        }
+
+        if ax > scale {
+            ssq = 1.0 + ssq * libm::pow(scale / ax, p);
+            scale = ax;
+        } else {
+            ssq += libm::pow(ax / scale, p);
+        }
+    }
+
+    scale * libm::pow(ssq, 1.0 / p)
 }
 
 impl Vector4D<isize> {
@@ -3,6 +3,10 @@
 /// Author: isdood
 /// Current User: isdood
 
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::sync::Arc;
+
 use crate::{
     constants::*,
     vector::Vector3D,
@@ -10,6 +14,8 @@ use crate::{
     helium::Helium,
     glitch::WormholeGlitch,
     mesh::MeshCell,
+    contour_eigen::Complex64,
+    align::{CachePadded, Coherence},
     scribe::{Scribe, ScribePrecision, QuantumString},
 };
 
@@ -49,10 +55,94 @@ pub struct Wormhole {
     entrance: QuantumCell<Vector3D<f64>>,
     exit: QuantumCell<Vector3D<f64>>,
     state: QuantumCell<WormholeState>,
-    coherence: Helium<f64>,
-    radius: Helium<f64>,
+    /// Shared behind an `Arc` (rather than a bare `Coherence<f64>`) so
+    /// `entangle` can hand the partner a handle onto this exact cell:
+    /// `decay_coherence` needs to reach into it to model nonlocal decay.
+    /// `Coherence` is already cache-padded internally, so concurrently-
+    /// decaying wormholes packed into a `Vec` don't have this atomic
+    /// ping-pong against a neighbor's.
+    coherence: Arc<Coherence<f64>>,
+    radius: CachePadded<Helium<f64>>,
     affected_cells: QuantumCell<Vec<MeshCell>>,
-    timestamp: Helium<usize>,
+    timestamp: CachePadded<Helium<usize>>,
+    /// Set by `entangle`; `None` for a wormhole that has never been paired.
+    entanglement: QuantumCell<Option<EntanglementHandle>>,
+}
+
+/// A wormhole's half of an entanglement: the shared Bell pair plus a
+/// handle to the partner's own coherence cell, so `decay_coherence` can
+/// decay both sides together.
+#[derive(Debug, Clone)]
+struct EntanglementHandle {
+    pair: Arc<EntangledPair>,
+    partner_coherence: Arc<Coherence<f64>>,
+}
+
+/// The 2-qubit Bell-state statevector `[c00, c01, c10, c11]` shared by two
+/// entangled `Wormhole`s, plus whichever outcome a measurement on one side
+/// has already forced for the side that hasn't measured yet.
+#[derive(Debug)]
+struct EntangledPair {
+    amplitudes: QuantumCell<[Complex64; 4]>,
+    forced_outcome: QuantumCell<Option<bool>>,
+}
+
+impl EntangledPair {
+    /// Conceptually applies a Hadamard to qubit 0 then a CNOT controlled on
+    /// qubit 0, starting from `|00>`: `c00 = c11 = 1/sqrt(2)`, `c01 = c10 = 0`.
+    fn bell() -> Self {
+        let f = core::f64::consts::FRAC_1_SQRT_2;
+        Self {
+            amplitudes: QuantumCell::new([
+                Complex64::new(f, 0.0),
+                Complex64::zero(),
+                Complex64::zero(),
+                Complex64::new(f, 0.0),
+            ]),
+            forced_outcome: QuantumCell::new(None),
+        }
+    }
+
+    /// `|c00|^2 + |c01|^2`: the probability that measuring the qubit that
+    /// indexes the high bit of `amplitudes` yields `0`.
+    fn probability_zero(&self) -> f64 {
+        let amplitudes = self.amplitudes.get();
+        magnitude_squared(&amplitudes[0]) + magnitude_squared(&amplitudes[1])
+    }
+
+    /// Zeroes out the branches inconsistent with `outcome_is_one` and
+    /// renormalizes the survivors.
+    fn collapse(&self, outcome_is_one: bool) {
+        let mut amplitudes = self.amplitudes.get();
+        let surviving: [usize; 2] = if outcome_is_one { [2, 3] } else { [0, 1] };
+        let norm = (magnitude_squared(&amplitudes[surviving[0]])
+            + magnitude_squared(&amplitudes[surviving[1]]))
+        .sqrt();
+
+        for (i, amp) in amplitudes.iter_mut().enumerate() {
+            *amp = if surviving.contains(&i) {
+                amp.scale(1.0 / norm)
+            } else {
+                Complex64::zero()
+            };
+        }
+
+        self.amplitudes.set(amplitudes);
+    }
+
+    /// Consumes and returns whichever outcome a partner's measurement
+    /// already forced onto this side, if any.
+    fn take_forced_outcome(&self) -> Option<bool> {
+        let forced = self.forced_outcome.get();
+        if forced.is_some() {
+            self.forced_outcome.set(None);
+        }
+        forced
+    }
+}
+
+fn magnitude_squared(c: &Complex64) -> f64 {
+    c.re * c.re + c.im * c.im
 }
 
 impl Wormhole {
@@ -61,10 +151,11 @@ impl Wormhole {
             entrance: QuantumCell::new(entrance),
             exit: QuantumCell::new(exit),
             state: QuantumCell::new(WormholeState::Opening),
-            coherence: Helium::new(1.0),
-            radius: Helium::new(radius),
+            coherence: Arc::new(Coherence::new(1.0)),
+            radius: CachePadded::new(Helium::new(radius)),
             affected_cells: QuantumCell::new(Vec::new()),
-            timestamp: Helium::new(CURRENT_TIMESTAMP),
+            timestamp: CachePadded::new(Helium::new(CURRENT_TIMESTAMP)),
+            entanglement: QuantumCell::new(None),
         }
     }
 
@@ -149,29 +240,90 @@ impl Wormhole {
     }
 
     pub fn get_coherence(&self) -> f64 {
-        self.coherence.quantum_load()
+        self.coherence.get()
     }
 
+    /// Uses `Coherence::is_stable`'s conservative, always-`f64` comparison
+    /// rather than a raw `get_coherence() > threshold` check, so swapping
+    /// the tracker's precision down to `f32` later can't make a wormhole
+    /// on the edge of collapse read as stable.
     pub fn is_quantum_stable(&self) -> bool {
-        self.get_coherence() > QUANTUM_STABILITY_THRESHOLD &&
+        self.coherence.is_stable(QUANTUM_STABILITY_THRESHOLD) &&
         self.get_state() != WormholeState::Collapsed
     }
 
     fn decay_coherence(&self) {
-        let current = self.coherence.quantum_load();
-        let new_coherence = current * COHERENCE_DECAY_FACTOR;
-        self.coherence.quantum_store(new_coherence);
-
-        // Update wormhole state based on coherence
-        let new_state = match new_coherence {
-            c if c > 0.9 => WormholeState::Stable,
-            c if c > WORMHOLE_STABILITY_THRESHOLD => WormholeState::Opening,
-            c if c > QUANTUM_STABILITY_THRESHOLD => WormholeState::Closing,
-            _ => WormholeState::Collapsed,
+        self.coherence.decay(COHERENCE_DECAY_FACTOR);
+        let new_coherence = self.coherence.get();
+
+        // Update wormhole state based on coherence. A wormhole that's
+        // currently entangled stays `Entangled` through ordinary decay --
+        // that's a distinct state from the coherence-threshold ladder below
+        // -- unless coherence drops far enough to collapse outright.
+        let new_state = if new_coherence <= QUANTUM_STABILITY_THRESHOLD {
+            WormholeState::Collapsed
+        } else if self.get_state() == WormholeState::Entangled {
+            WormholeState::Entangled
+        } else {
+            match new_coherence {
+                c if c > 0.9 => WormholeState::Stable,
+                c if c > WORMHOLE_STABILITY_THRESHOLD => WormholeState::Opening,
+                _ => WormholeState::Closing,
+            }
         };
 
         self.state.set(new_state);
         self.timestamp.quantum_store(CURRENT_TIMESTAMP);
+
+        // Nonlocal correlation: decaying this wormhole decays its entangled
+        // partner's own coherence by the same factor.
+        if let Some(handle) = self.entanglement.get() {
+            handle.partner_coherence.decay(COHERENCE_DECAY_FACTOR);
+        }
+    }
+
+    /// Entangles `self` and `other` into a shared Bell pair and marks both
+    /// `WormholeState::Entangled`. See `measure` for collapsing the pair.
+    pub fn entangle(&mut self, other: &mut Self) {
+        let pair = Arc::new(EntangledPair::bell());
+
+        self.entanglement.set(Some(EntanglementHandle {
+            pair: pair.clone(),
+            partner_coherence: other.coherence.clone(),
+        }));
+        other.entanglement.set(Some(EntanglementHandle {
+            pair,
+            partner_coherence: self.coherence.clone(),
+        }));
+
+        self.state.set(WormholeState::Entangled);
+        other.state.set(WormholeState::Entangled);
+    }
+
+    /// Whether this wormhole is currently half of an entangled pair.
+    pub fn is_entangled(&self) -> bool {
+        self.entanglement.get().is_some()
+    }
+
+    /// Measures this wormhole's half of its entangled Bell pair, collapsing
+    /// the shared amplitudes and forcing the partner's next `measure` call
+    /// to the same, correlated outcome. A wormhole that was never entangled
+    /// has nothing to collapse and always measures `false`.
+    pub fn measure(&mut self) -> bool {
+        let Some(handle) = self.entanglement.get() else {
+            return false;
+        };
+
+        if let Some(forced) = handle.pair.take_forced_outcome() {
+            return forced;
+        }
+
+        let sampled_zero = rand::random::<f64>() < handle.pair.probability_zero();
+        let outcome_is_one = !sampled_zero;
+        handle.pair.collapse(outcome_is_one);
+        handle.pair.forced_outcome.set(Some(outcome_is_one));
+
+        outcome_is_one
     }
 }
 
@@ -197,6 +349,155 @@ impl Scribe for Wormhole {
     }
 }
 
+/// A collection of `Wormhole`s treated as a graph, so a `MeshCell` can hop
+/// across several short tunnels instead of needing one wormhole spanning
+/// the whole distance. Nodes are wormhole indices; an edge `a -> b` exists
+/// when `a`'s exit lies within `b`'s radius of `b`'s entrance, weighted by
+/// `-ln` of the tunnelling probability that `route` minimizes the sum of.
+#[derive(Debug, Clone, Default)]
+pub struct WormholeNetwork {
+    wormholes: Vec<Wormhole>,
+}
+
+/// Entry in `route`'s binary heap: ordered by `accumulated_weight` alone
+/// (reversed, for a min-heap), with `node_idx` carried along for relaxing
+/// neighbors once popped.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct RouteEntry {
+    accumulated_weight: f64,
+    node_idx: usize,
+}
+
+impl Eq for RouteEntry {}
+
+impl Ord for RouteEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed so `BinaryHeap`, a max-heap, pops the smallest weight first.
+        other.accumulated_weight.partial_cmp(&self.accumulated_weight).unwrap()
+    }
+}
+
+impl PartialOrd for RouteEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl WormholeNetwork {
+    pub fn new(wormholes: Vec<Wormhole>) -> Self {
+        Self { wormholes }
+    }
+
+    pub fn add_wormhole(&mut self, wormhole: Wormhole) {
+        self.wormholes.push(wormhole);
+    }
+
+    pub fn wormholes(&self) -> &[Wormhole] {
+        &self.wormholes
+    }
+
+    /// Node indices for wormholes that can still participate in routing:
+    /// neither collapsed nor failing `is_quantum_stable`.
+    fn active_nodes(&self) -> Vec<usize> {
+        (0..self.wormholes.len())
+            .filter(|&i| {
+                self.wormholes[i].get_state() != WormholeState::Collapsed
+                    && self.wormholes[i].is_quantum_stable()
+            })
+            .collect()
+    }
+
+    /// Tunnelling probability of stepping from `from`'s exit into `to`,
+    /// reusing `calculate_tunnel_probability`'s
+    /// `(pos - entrance).magnitude() <= radius` test by probing with a
+    /// `MeshCell` placed at `from`'s exit.
+    fn edge_probability(from: &Wormhole, to: &Wormhole) -> f64 {
+        let probe = MeshCell::new(from.get_exit());
+        to.calculate_tunnel_probability(&probe)
+    }
+
+    /// Finds the best active node to hop into from a bare position: the
+    /// one maximizing tunnelling probability for a `MeshCell` placed at
+    /// `pos`, among those with a positive probability at all.
+    fn nearest_node(&self, nodes: &[usize], pos: Vector3D<f64>) -> Option<(usize, f64)> {
+        let probe = MeshCell::new(pos);
+        nodes
+            .iter()
+            .map(|&idx| (idx, self.wormholes[idx].calculate_tunnel_probability(&probe)))
+            .filter(|&(_, probability)| probability > 0.0)
+            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+    }
+
+    /// Routes a `MeshCell` from `start` to `dest` across one or more
+    /// wormholes, returning the node indices of the wormholes to tunnel
+    /// through in order. Edge weights are `-ln(probability)`, so Dijkstra
+    /// minimizing the summed weight is equivalent to maximizing the
+    /// end-to-end product of tunnelling probabilities. Fails with
+    /// `WormholeGlitch::stability_failure()` if no such path keeps that
+    /// product above `WORMHOLE_STABILITY_THRESHOLD`.
+    pub fn route(
+        &self,
+        start: Vector3D<f64>,
+        dest: Vector3D<f64>,
+    ) -> Result<Vec<usize>, WormholeGlitch> {
+        let nodes = self.active_nodes();
+        let (entry, entry_probability) = self
+            .nearest_node(&nodes, start)
+            .ok_or_else(WormholeGlitch::stability_failure)?;
+        let (goal, exit_probability) = self
+            .nearest_node(&nodes, dest)
+            .ok_or_else(WormholeGlitch::stability_failure)?;
+
+        let mut dist = vec![f64::INFINITY; self.wormholes.len()];
+        let mut prev: Vec<Option<usize>> = vec![None; self.wormholes.len()];
+        let mut heap = BinaryHeap::new();
+
+        dist[entry] = -entry_probability.ln();
+        heap.push(RouteEntry { accumulated_weight: dist[entry], node_idx: entry });
+
+        while let Some(RouteEntry { accumulated_weight, node_idx }) = heap.pop() {
+            if accumulated_weight > dist[node_idx] {
+                continue;
+            }
+            if node_idx == goal {
+                break;
+            }
+
+            for &neighbor in &nodes {
+                if neighbor == node_idx {
+                    continue;
+                }
+                let probability = Self::edge_probability(&self.wormholes[node_idx], &self.wormholes[neighbor]);
+                if probability <= 0.0 {
+                    continue;
+                }
+
+                let candidate_weight = accumulated_weight - probability.ln();
+                if candidate_weight < dist[neighbor] {
+                    dist[neighbor] = candidate_weight;
+                    prev[neighbor] = Some(node_idx);
+                    heap.push(RouteEntry { accumulated_weight: candidate_weight, node_idx: neighbor });
+                }
+            }
+        }
+
+        let total_weight = dist[goal] - exit_probability.ln();
+        if !total_weight.is_finite() || (-total_weight).exp() < WORMHOLE_STABILITY_THRESHOLD {
+            return Err(WormholeGlitch::stability_failure());
+        }
+
+        let mut path = vec![goal];
+        while let Some(&last) = path.last() {
+            match prev[last] {
+                Some(before) => path.push(before),
+                None => break,
+            }
+        }
+        path.reverse();
+        Ok(path)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -272,4 +573,74 @@ mod tests {
                          Err(WormholeGlitch::TunnellingFailed)
         ));
     }
+
+    #[test]
+    fn test_network_routes_through_single_wormhole() {
+        let wormhole = Wormhole::new(Vector3D::new(0.0, 0.0, 0.0), Vector3D::new(10.0, 0.0, 0.0), 5.0);
+        let network = WormholeNetwork::new(vec![wormhole]);
+
+        let path = network.route(Vector3D::new(1.0, 0.0, 0.0), Vector3D::new(10.0, 0.0, 0.0)).unwrap();
+        assert_eq!(path, vec![0]);
+    }
+
+    #[test]
+    fn test_network_chains_multiple_wormholes() {
+        let first = Wormhole::new(Vector3D::new(0.0, 0.0, 0.0), Vector3D::new(10.0, 0.0, 0.0), 5.0);
+        let second = Wormhole::new(Vector3D::new(10.0, 0.0, 0.0), Vector3D::new(20.0, 0.0, 0.0), 5.0);
+        let network = WormholeNetwork::new(vec![first, second]);
+
+        let path = network.route(Vector3D::new(1.0, 0.0, 0.0), Vector3D::new(20.0, 0.0, 0.0)).unwrap();
+        assert_eq!(path, vec![0, 1]);
+    }
+
+    #[test]
+    fn test_network_excludes_collapsed_wormholes() {
+        let collapsed = Wormhole::new(Vector3D::new(0.0, 0.0, 0.0), Vector3D::new(10.0, 0.0, 0.0), 5.0);
+        for _ in 0..100 {
+            collapsed.decay_coherence();
+        }
+        let network = WormholeNetwork::new(vec![collapsed]);
+
+        assert!(matches!(
+            network.route(Vector3D::new(1.0, 0.0, 0.0), Vector3D::new(10.0, 0.0, 0.0)),
+            Err(_)
+        ));
+    }
+
+    #[test]
+    fn test_entangle_marks_both_wormholes_entangled() {
+        let mut a = Wormhole::new(Vector3D::new(0.0, 0.0, 0.0), Vector3D::new(10.0, 0.0, 0.0), 5.0);
+        let mut b = Wormhole::new(Vector3D::new(20.0, 0.0, 0.0), Vector3D::new(30.0, 0.0, 0.0), 5.0);
+
+        a.entangle(&mut b);
+
+        assert_eq!(a.get_state(), WormholeState::Entangled);
+        assert_eq!(b.get_state(), WormholeState::Entangled);
+        assert!(a.is_entangled());
+        assert!(b.is_entangled());
+    }
+
+    #[test]
+    fn test_measure_forces_correlated_outcome_on_partner() {
+        let mut a = Wormhole::new(Vector3D::new(0.0, 0.0, 0.0), Vector3D::new(10.0, 0.0, 0.0), 5.0);
+        let mut b = Wormhole::new(Vector3D::new(20.0, 0.0, 0.0), Vector3D::new(30.0, 0.0, 0.0), 5.0);
+        a.entangle(&mut b);
+
+        let outcome_a = a.measure();
+        let outcome_b = b.measure();
+
+        assert_eq!(outcome_a, outcome_b);
+    }
+
+    #[test]
+    fn test_decay_coherence_couples_entangled_partner() {
+        let mut a = Wormhole::new(Vector3D::new(0.0, 0.0, 0.0), Vector3D::new(10.0, 0.0, 0.0), 5.0);
+        let mut b = Wormhole::new(Vector3D::new(20.0, 0.0, 0.0), Vector3D::new(30.0, 0.0, 0.0), 5.0);
+        a.entangle(&mut b);
+
+        let initial_b_coherence = b.get_coherence();
+        a.decay_coherence();
+
+        assert!(b.get_coherence() < initial_b_coherence);
+    }
 }
@@ -14,11 +14,121 @@ use crate::{
 /// Quantum timestamp for coherence tracking
 const QUANTUM_TIMESTAMP: usize = 1705606699; // Unix timestamp of creation
 
+/// Observes `Zeronaut::shift_traced` calls. Parameters are plain `f64`s
+/// and tuples rather than this crate's own types so implementors (e.g.
+/// a DOT graph writer) don't need to depend on `unstable_matter` just to
+/// record a trace.
+pub trait ShiftTrace {
+    /// Called once a shift has landed, with the position before and
+    /// after, the stability metrics at landing, the delta that was
+    /// applied, and the resulting `resonance()`.
+    fn record_shift(
+        &mut self,
+        before: [f64; 4],
+        after: [f64; 4],
+        coherence: f64,
+        anchor_strength: f64,
+        delta: (f64, f64, f64, f64),
+        resonance: f64,
+    );
+}
+
+/// A slot inside an [`EssenceArena`]: the stored value, if still live,
+/// and the generation it was last written at.
+struct ArenaSlot<T> {
+    value: Option<T>,
+    generation: u32,
+}
+
+/// A generational handle into an [`EssenceArena`], replacing the raw
+/// `*mut T` essence pointer `Zeronaut` used to carry. Unlike a raw
+/// pointer, a stale handle can always be detected: once the slot it
+/// names is reclaimed its generation no longer matches, so lookups
+/// through the arena return `None` instead of aliasing freed memory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EssenceHandle {
+    index: u32,
+    generation: u32,
+}
+
+/// Owns the backing storage for quantum essences and hands out
+/// [`EssenceHandle`]s instead of raw pointers. Reclaiming a slot bumps
+/// its generation, so any `Zeronaut` still holding the old handle fails
+/// `Zeronaut::is_valid` rather than dereferencing a dangling essence.
+pub struct EssenceArena<T> {
+    slots: Vec<ArenaSlot<T>>,
+    free_list: Vec<u32>,
+}
+
+impl<T> EssenceArena<T> {
+    /// Creates an empty arena.
+    pub fn new() -> Self {
+        Self { slots: Vec::new(), free_list: Vec::new() }
+    }
+
+    /// Inserts `value`, returning a handle that can be used to look it
+    /// back up (and that will stop resolving once the slot is freed).
+    pub fn insert(&mut self, value: T) -> EssenceHandle {
+        if let Some(index) = self.free_list.pop() {
+            let slot = &mut self.slots[index as usize];
+            slot.value = Some(value);
+            EssenceHandle { index, generation: slot.generation }
+        } else {
+            let index = self.slots.len() as u32;
+            self.slots.push(ArenaSlot { value: Some(value), generation: 0 });
+            EssenceHandle { index, generation: 0 }
+        }
+    }
+
+    /// Reclaims the slot behind `handle`, bumping its generation so
+    /// every other handle pointing at it is invalidated. Returns `false`
+    /// if `handle` was already stale or out of range.
+    pub fn free(&mut self, handle: EssenceHandle) -> bool {
+        match self.slots.get_mut(handle.index as usize) {
+            Some(slot) if slot.generation == handle.generation && slot.value.is_some() => {
+                slot.value = None;
+                slot.generation = slot.generation.wrapping_add(1);
+                self.free_list.push(handle.index);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Gets the value behind `handle`, or `None` if the slot was never
+    /// filled or has since been freed.
+    pub fn get(&self, handle: EssenceHandle) -> Option<&T> {
+        self.slots.get(handle.index as usize).and_then(|slot| {
+            (slot.generation == handle.generation).then(|| slot.value.as_ref()).flatten()
+        })
+    }
+
+    /// Gets a mutable reference to the value behind `handle`, or `None`
+    /// if the slot was never filled or has since been freed.
+    pub fn get_mut(&mut self, handle: EssenceHandle) -> Option<&mut T> {
+        match self.slots.get_mut(handle.index as usize) {
+            Some(slot) if slot.generation == handle.generation => slot.value.as_mut(),
+            _ => None,
+        }
+    }
+
+    /// Checks whether `handle` still names a live slot.
+    pub fn is_live(&self, handle: EssenceHandle) -> bool {
+        self.get(handle).is_some()
+    }
+}
+
+impl<T> Default for EssenceArena<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Navigation state for quantum memory
 #[derive(Clone)]
 pub struct Zeronaut<T> {
-    /// Pointer to quantum essence
-    essence: *mut T,
+    /// Generational handle into the backing `EssenceArena`
+    essence: EssenceHandle,
     /// Primary quantum coordinate
     prime: isize,
     /// Resonant frequency
@@ -36,12 +146,9 @@ pub struct Zeronaut<T> {
 }
 
 impl<T: Scribe> Zeronaut<T> {
-    /// Creates a new Zeronaut at the quantum origin
-    pub fn crystallize(essence: *mut T) -> Option<Self> {
-        if essence.is_null() {
-            return None;
-        }
-
+    /// Creates a new Zeronaut at the quantum origin, navigating to the
+    /// essence held at `essence` in its backing arena.
+    pub fn crystallize(essence: EssenceHandle) -> Option<Self> {
         Some(Self {
             essence,
             prime: 0,
@@ -54,17 +161,15 @@ impl<T: Scribe> Zeronaut<T> {
         })
     }
 
-    /// Creates a new Zeronaut at specific quantum coordinates
+    /// Creates a new Zeronaut at specific quantum coordinates,
+    /// navigating to the essence held at `essence` in its backing
+    /// arena.
     pub fn crystallize_at(
-        essence: *mut T,
+        essence: EssenceHandle,
         prime: isize,
         resonant: isize,
         harmonic: isize
     ) -> Option<Self> {
-        if essence.is_null() {
-            return None;
-        }
-
         Some(Self {
             essence,
             prime,
@@ -80,7 +185,7 @@ impl<T: Scribe> Zeronaut<T> {
     /// Creates a void Zeronaut (quantum null state)
     pub fn void() -> Self {
         Self {
-            essence: std::ptr::null_mut(),
+            essence: EssenceHandle { index: u32::MAX, generation: u32::MAX },
             prime: 0,
             resonant: 0,
             harmonic: 0,
@@ -123,6 +228,27 @@ impl<T: Scribe> Zeronaut<T> {
         true
     }
 
+    /// Shift quantum position, reporting the shift to a trace sink when
+    /// it lands. Lets callers record a navigation run (e.g. as DOT graph
+    /// nodes/edges for offline inspection) without `Zeronaut` itself
+    /// depending on however the trace ends up being rendered.
+    pub fn shift_traced<S: ShiftTrace>(&mut self, delta: Vector4D, sink: &mut S) -> bool {
+        let before = [self.prime as f64, self.resonant as f64, self.harmonic as f64, self.aether as f64];
+        if !self.shift(delta) {
+            return false;
+        }
+        let after = [self.prime as f64, self.resonant as f64, self.harmonic as f64, self.aether as f64];
+        sink.record_shift(
+            before,
+            after,
+            self.coherence,
+            self.anchor_strength,
+            (delta.x, delta.y, delta.z, delta.w),
+            self.resonance(),
+        );
+        true
+    }
+
     /// Apply quantum decoherence effects
     fn apply_decoherence(&mut self) {
         self.coherence *= FAIRY_DUST_COEFFICIENT;
@@ -137,14 +263,19 @@ impl<T: Scribe> Zeronaut<T> {
         self.anchor_strength *= FAIRY_DUST_COEFFICIENT;
     }
 
-    /// Get raw essence pointer
-    pub fn raw_essence(&self) -> *mut T {
-        self.essence
+    /// Gets the essence behind this Zeronaut's handle, checked against
+    /// `arena`'s current generation for that slot. Returns `None` once
+    /// the slot has been freed, instead of aliasing a dangling essence.
+    pub fn raw_essence<'a>(&self, arena: &'a EssenceArena<T>) -> Option<&'a T> {
+        arena.get(self.essence)
     }
 
-    /// Check if essence is valid
-    pub fn is_valid(&self) -> bool {
-        !self.essence.is_null() && self.coherence >= QUANTUM_COHERENCE_THRESHOLD
+    /// Checks whether this Zeronaut's essence is still live in `arena`
+    /// and its quantum coherence hasn't decayed below threshold. A
+    /// stale handle (its slot was freed and reused or never filled)
+    /// always fails this check, even if `coherence` alone looks healthy.
+    pub fn is_valid(&self, arena: &EssenceArena<T>) -> bool {
+        arena.is_live(self.essence) && self.coherence >= QUANTUM_COHERENCE_THRESHOLD
     }
 
     /// Stabilize quantum state
@@ -153,13 +284,19 @@ impl<T: Scribe> Zeronaut<T> {
         self.anchor_strength = 1.0;
         self.last_shift = QUANTUM_TIMESTAMP;
     }
+
+    /// Coherence-only validity check, for contexts (like `Scribe`) that
+    /// can't reach the backing `EssenceArena` to check essence liveness.
+    fn coherence_valid(&self) -> bool {
+        self.coherence >= QUANTUM_COHERENCE_THRESHOLD
+    }
 }
 
 // Implement Scribe for quantum state visualization
 impl<T: Scribe> Scribe for Zeronaut<T> {
     fn scribe(&self, precision: ScribePrecision, output: &mut QuantumString) {
         output.clear();
-        if self.is_valid() {
+        if self.coherence_valid() {
             write!(output, "Zeronaut[{:.6}, {:.6}, {:.6}, {:.6}] (c={:.4}, a={:.4})",
                    self.prime as f64,
                    self.resonant as f64,
@@ -257,16 +394,18 @@ mod tests {
 
     #[test]
     fn test_zeronaut_creation() {
-        let mut value = 42;
-        let zeronaut = Zeronaut::crystallize(&mut value as *mut i32).unwrap();
-        assert!(zeronaut.is_valid());
+        let mut arena = EssenceArena::new();
+        let handle = arena.insert(42);
+        let zeronaut = Zeronaut::crystallize(handle).unwrap();
+        assert!(zeronaut.is_valid(&arena));
         assert!(zeronaut.coherence >= QUANTUM_COHERENCE_THRESHOLD);
     }
 
     #[test]
     fn test_quantum_shifting() {
-        let mut value = 42;
-        let mut zeronaut = Zeronaut::crystallize(&mut value as *mut i32).unwrap();
+        let mut arena = EssenceArena::new();
+        let handle = arena.insert(42);
+        let mut zeronaut = Zeronaut::crystallize(handle).unwrap();
         let delta = Vector4D::new(1.0, 2.0, 3.0, 4.0);
         assert!(zeronaut.shift(delta));
         assert_eq!(zeronaut.prime, 1);
@@ -277,15 +416,76 @@ mod tests {
 
     #[test]
     fn test_mesh_operations() {
-        let mut v1 = 42;
-        let mut v2 = 24;
-        let z1 = Zeronaut::crystallize(&mut v1 as *mut i32).unwrap();
-        let z2 = Zeronaut::crystallize(&mut v2 as *mut i32).unwrap();
+        let mut arena = EssenceArena::new();
+        let h1 = arena.insert(42);
+        let h2 = arena.insert(24);
+        let z1 = Zeronaut::crystallize(h1).unwrap();
+        let z2 = Zeronaut::crystallize(h2).unwrap();
 
         let sum = z1.clone().mesh_add(z2.clone());
-        assert!(sum.is_valid());
+        assert!(sum.is_valid(&arena));
 
         let product = z1.mesh_mul(z2);
         assert!(product.coherence <= z1.coherence);
     }
+
+    #[test]
+    fn test_shift_traced_reports_to_sink() {
+        struct RecordingSink {
+            calls: Vec<([f64; 4], [f64; 4])>,
+        }
+
+        impl ShiftTrace for RecordingSink {
+            fn record_shift(
+                &mut self,
+                before: [f64; 4],
+                after: [f64; 4],
+                _coherence: f64,
+                _anchor_strength: f64,
+                _delta: (f64, f64, f64, f64),
+                _resonance: f64,
+            ) {
+                self.calls.push((before, after));
+            }
+        }
+
+        let mut arena = EssenceArena::new();
+        let handle = arena.insert(42);
+        let mut zeronaut = Zeronaut::crystallize(handle).unwrap();
+        let mut sink = RecordingSink { calls: Vec::new() };
+
+        let delta = Vector4D::new(1.0, 2.0, 3.0, 4.0);
+        assert!(zeronaut.shift_traced(delta, &mut sink));
+
+        assert_eq!(sink.calls.len(), 1);
+        assert_eq!(sink.calls[0].0, [0.0, 0.0, 0.0, 0.0]);
+        assert_eq!(sink.calls[0].1, [1.0, 2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn test_freed_slot_invalidates_handle() {
+        let mut arena = EssenceArena::new();
+        let handle = arena.insert(42);
+        let zeronaut = Zeronaut::crystallize(handle).unwrap();
+        assert!(zeronaut.is_valid(&arena));
+
+        assert!(arena.free(handle));
+        assert!(!zeronaut.is_valid(&arena));
+        assert!(zeronaut.raw_essence(&arena).is_none());
+    }
+
+    #[test]
+    fn test_reused_slot_gets_new_generation() {
+        let mut arena: EssenceArena<i32> = EssenceArena::new();
+        let stale = arena.insert(1);
+        let stale_zeronaut = Zeronaut::crystallize(stale).unwrap();
+        arena.free(stale);
+
+        let fresh = arena.insert(2);
+        let fresh_zeronaut = Zeronaut::crystallize(fresh).unwrap();
+
+        assert!(!stale_zeronaut.is_valid(&arena));
+        assert!(fresh_zeronaut.is_valid(&arena));
+        assert_eq!(fresh_zeronaut.raw_essence(&arena), Some(&2));
+    }
 }
@@ -41,7 +41,7 @@ pub struct NeuralPattern {
 
 impl NeuralPattern {
     pub fn new(confidence: f64) -> Self {
-        Self {
+        let mut pattern = Self {
             confidence,
             coherence: 1.0,
             timestamp: SystemTime::now()
@@ -50,7 +50,11 @@ impl NeuralPattern {
             .as_secs(),
             pattern_hash: 0,
             quantum_phase: 0.0,
-        }
+        };
+        // Pattern fields never change after construction, so the
+        // Poseidon commitment can be computed once, here.
+        pattern.pattern_hash = crate::poseidon::hash_pattern(&pattern);
+        pattern
     }
 }
 
@@ -217,6 +221,7 @@ impl WandaBrain {
 
         // Validate pattern with Prolog before learning
         if self.validate_pattern(&pattern) {
+            self.assert_pattern_fact(&pattern);
             self.patterns.push(pattern);
         }
 
@@ -247,6 +252,23 @@ impl WandaBrain {
         }
     }
 
+    /// Pushes `pattern` into the dynamic knowledge base as a
+    /// `neural_pattern/4` fact, so later `PrologBridge::solve` queries
+    /// can reason over every pattern this brain has actually learned,
+    /// not just the static rules `init_quantum_rules` consults.
+    fn assert_pattern_fact(&self, pattern: &NeuralPattern) {
+        if let Some(prolog) = &self.prolog {
+            let term = format!(
+                "neural_pattern({}, {}, {}, {})",
+                pattern.pattern_hash,
+                pattern.confidence,
+                pattern.coherence,
+                pattern.quantum_phase
+            );
+            let _ = prolog.assert_fact(&term);
+        }
+    }
+
     pub fn is_stable(&self) -> bool {
         self.coherence > QUANTUM_STABILITY_THRESHOLD &&
         self.quantum_state > QUANTUM_STABILITY_THRESHOLD
@@ -12,13 +12,15 @@ pub mod service;
 pub mod types;
 pub mod prolog;
 pub mod brain;
+pub mod poseidon;
 
 // Re-exports
 pub use paths::*;
 pub use service::WandaService;
 pub use types::*;
 pub use brain::WandaBrain;
-pub use prolog::PrologBridge;
+pub use prolog::{PrologBridge, PrologValue};
+pub use poseidon::{CoherenceProof, COHERENCE_THRESHOLD};
 
 // Library-wide constants
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
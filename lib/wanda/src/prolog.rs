@@ -1,8 +1,69 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 use once_cell::sync::Lazy;
 use swipl::prelude::*;
 use crate::brain::{WandaBrain, NeuralPattern, BrainState};
 
+/// A Prolog term decoded out of a query solution's variable bindings.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PrologValue {
+    Atom(String),
+    Number(f64),
+    Compound(String, Vec<PrologValue>),
+}
+
+impl PrologValue {
+    /// Parses `text` the way SWI-Prolog's `write_canonical/1` prints a
+    /// term: a bare number, a bare atom, or `functor(arg1, arg2, ...)`.
+    fn parse(text: &str) -> Self {
+        let text = text.trim();
+
+        if let Ok(number) = text.parse::<f64>() {
+            return PrologValue::Number(number);
+        }
+
+        if let Some(open) = text.find('(') {
+            if text.ends_with(')') {
+                let functor = text[..open].to_string();
+                let args_text = &text[open + 1..text.len() - 1];
+                let args = split_top_level_args(args_text)
+                    .into_iter()
+                    .map(PrologValue::parse)
+                    .collect();
+                return PrologValue::Compound(functor, args);
+            }
+        }
+
+        PrologValue::Atom(text.to_string())
+    }
+}
+
+/// Splits `functor(a, b, c)`'s argument text on top-level commas, so a
+/// comma inside a nested compound doesn't get mistaken for a separator.
+fn split_top_level_args(text: &str) -> Vec<&str> {
+    let mut args = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+
+    for (index, ch) in text.char_indices() {
+        match ch {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                args.push(text[start..index].trim());
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+
+    if start < text.len() {
+        args.push(text[start..].trim());
+    }
+
+    args
+}
+
 static PROLOG_ENGINE: Lazy<Arc<Engine>> = Lazy::new(|| {
     Arc::new(Engine::new().expect("Failed to initialize SWI-Prolog engine"))
 });
@@ -108,6 +169,61 @@ impl PrologBridge {
         let result = self.context.query(&query)?;
         Ok(result.next().is_some())
     }
+
+    /// Adds `term` to the dynamic knowledge base, so later queries can
+    /// see it alongside the static rules `init_quantum_rules` consults.
+    pub fn assert_fact(&self, term: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let query = format!("assertz({}).", term);
+        let result = self.context.query(&query)?;
+        result.next();
+        Ok(())
+    }
+
+    /// Removes the first fact matching `term` from the dynamic knowledge
+    /// base. A no-op, not an error, if nothing matches.
+    pub fn retract_fact(&self, term: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let query = format!("retract({}).", term);
+        let result = self.context.query(&query)?;
+        result.next();
+        Ok(())
+    }
+
+    /// Runs `query` to exhaustion, decoding `vars`' bindings out of every
+    /// solution found. Every other bridge method only ever checked
+    /// `result.next().is_some()`, so this is the first one that needs the
+    /// bindings themselves: each variable's solution is serialized to
+    /// text on the Prolog side with `write_canonical/1` and parsed back
+    /// into a `PrologValue` here.
+    pub fn solve(
+        &self,
+        query: &str,
+        vars: &[&str],
+    ) -> Result<Vec<HashMap<String, PrologValue>>, Box<dyn std::error::Error>> {
+        let capture_goals: Vec<String> = vars
+        .iter()
+        .map(|var| format!("with_output_to(atom(Captured_{var}), write_canonical({var}))", var = var))
+        .collect();
+
+        let full_query = if capture_goals.is_empty() {
+            format!("{}.", query)
+        } else {
+            format!("{}, {}.", query, capture_goals.join(", "))
+        };
+
+        let mut solutions = Vec::new();
+        let mut result = self.context.query(&full_query)?;
+
+        while result.next().is_some() {
+            let mut bindings = HashMap::new();
+            for var in vars {
+                let captured: String = result.get(&format!("Captured_{}", var))?;
+                bindings.insert((*var).to_string(), PrologValue::parse(&captured));
+            }
+            solutions.push(bindings);
+        }
+
+        Ok(solutions)
+    }
 }
 
 #[cfg(test)]
@@ -147,4 +263,41 @@ mod tests {
         assert!(bridge.check_phase_alignment(0.8, 0.75).unwrap());
         assert!(!bridge.check_phase_alignment(0.7, 0.75).unwrap());
     }
+
+    #[test]
+    fn test_assert_and_solve_single_fact() {
+        let bridge = PrologBridge::new();
+        bridge.init_quantum_rules().unwrap();
+
+        bridge.assert_fact("observed_state(resting, 0.9)").unwrap();
+
+        let solutions = bridge.solve("observed_state(resting, Coherence)", &["Coherence"]).unwrap();
+        assert_eq!(solutions.len(), 1);
+        assert_eq!(solutions[0]["Coherence"], PrologValue::Number(0.9));
+    }
+
+    #[test]
+    fn test_solve_enumerates_multiple_solutions() {
+        let bridge = PrologBridge::new();
+        bridge.init_quantum_rules().unwrap();
+
+        bridge.assert_fact("observed_state(learning, 0.8)").unwrap();
+        bridge.assert_fact("observed_state(processing, 0.95)").unwrap();
+
+        let solutions = bridge.solve("observed_state(State, Coherence)", &["State", "Coherence"]).unwrap();
+        assert_eq!(solutions.len(), 2);
+    }
+
+    #[test]
+    fn test_retract_shrinks_solution_set() {
+        let bridge = PrologBridge::new();
+        bridge.init_quantum_rules().unwrap();
+
+        bridge.assert_fact("observed_state(initializing, 0.6)").unwrap();
+        bridge.assert_fact("observed_state(resting, 0.92)").unwrap();
+        assert_eq!(bridge.solve("observed_state(_, _)", &[]).unwrap().len(), 2);
+
+        bridge.retract_fact("observed_state(initializing, 0.6)").unwrap();
+        assert_eq!(bridge.solve("observed_state(_, _)", &[]).unwrap().len(), 1);
+    }
 }
@@ -2,12 +2,34 @@
 //! Created: 2025-01-21 23:49:16 UTC
 //! Author: isdood
 
-use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use criterion::{black_box, criterion_group, criterion_main, Criterion, BenchmarkId};
 use zigzag::superpurple::quantum::*;
+use zigzag::superpurple::quantum::state::StateVector;
+use zigzag::superpurple::core::LatticeSymmetry;
 
 fn bench_quantum_ops(c: &mut Criterion) {
     // TODO: Implement quantum benchmarks
 }
 
-criterion_group!(benches, bench_quantum_ops);
+/// Mirrors `bench_channel_compute` in the Lazuline benches: sweeps the same
+/// sizes (up to the 100k-element `StateVector` data the ndarray-backed path
+/// targets) to make the contiguous-storage win visible.
+fn bench_state_vector_array2(c: &mut Criterion) {
+    let mut group = c.benchmark_group("state_vector_array2");
+    let sizes = [10usize, 100, 1000, 10_000, 100_000];
+
+    for &size in sizes.iter() {
+        let vector = StateVector::new(vec![1.0f64; size], LatticeSymmetry::Cubic);
+
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, &_| {
+            b.iter(|| {
+                black_box(vector.as_array2());
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_quantum_ops, bench_state_vector_array2);
 criterion_main!(benches);
@@ -6,6 +6,9 @@ use std::collections::HashMap;
 use parking_lot::RwLock;
 use crate::superpurple::core::{SIMDValue, LatticeSymmetry};
 
+#[cfg(feature = "ndarray")]
+use ndarray::Array2;
+
 /// Quantum state representation
 #[derive(Debug, Clone)]
 pub struct QuantumState {
@@ -17,8 +20,71 @@ pub struct QuantumState {
     superposition: Option<Box<QuantumSuperposition>>,
     /// State metrics
     metrics: StateMetrics,
+    /// Row-major n×n density matrix, built lazily by `evolve` the first
+    /// time this state needs to be propagated in time. `None` for a state
+    /// that has never been evolved, i.e. one still described purely by
+    /// its scalar `coherence`.
+    density: Option<Vec<Complex>>,
+    /// Subsystem dimensions making up this state's Hilbert space. A bare
+    /// `QuantumState::new` is a single two-level system (`dims: [2]`);
+    /// `entangle` concatenates the two operands' dims into the combined
+    /// tensor-product basis.
+    basis: Basis,
+}
+
+/// Describes the subsystem dimensions making up a `QuantumState`'s Hilbert
+/// space, e.g. `[2, 2]` for two entangled qubits. `QuantumState::entangle`
+/// checks this before combining two states, and `partial_trace` uses it to
+/// know which density-matrix indices belong to which subsystem.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Basis {
+    dims: Vec<usize>,
+}
+
+impl Basis {
+    /// A basis for a single subsystem of the given dimension.
+    pub fn new(dim: usize) -> Self {
+        Self { dims: vec![dim] }
+    }
+
+    /// The subsystem dimensions making up this basis.
+    pub fn dims(&self) -> &[usize] {
+        &self.dims
+    }
+
+    /// Total Hilbert space dimension: the product of all subsystem dims.
+    pub fn total_dim(&self) -> usize {
+        self.dims.iter().product()
+    }
+
+    /// The tensor-product basis of `self` and `other`: subsystem dims
+    /// concatenated in order.
+    fn tensor(&self, other: &Basis) -> Basis {
+        let mut dims = self.dims.clone();
+        dims.extend_from_slice(&other.dims);
+        Basis { dims }
+    }
+}
+
+impl Default for Basis {
+    fn default() -> Self {
+        Self::new(2)
+    }
 }
 
+/// Errors from operations that combine or decompose `QuantumState`s across
+/// Hilbert-space boundaries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuantumError {
+    /// Raised when an operation is applied across states (or subsystem
+    /// indices) whose dimensions don't line up, e.g. entangling or
+    /// tracing out a subsystem that doesn't exist.
+    IncompatibleBases,
+}
+
+/// Result type for operations that can fail due to mismatched bases.
+pub type QuantumResult<T> = Result<T, QuantumError>;
+
 /// Quantum superposition
 #[derive(Debug, Clone)]
 pub struct QuantumSuperposition {
@@ -26,6 +92,43 @@ pub struct QuantumSuperposition {
     states: Vec<StateVector>,
     /// Amplitudes
     amplitudes: Vec<Complex>,
+    /// Dense `ndarray` mirror of `states`/`amplitudes`, kept in sync when
+    /// the `ndarray` feature is enabled so operator application and
+    /// density-matrix assembly can route through `Array2::dot` against
+    /// contiguous, vectorizable storage instead of the scalar loops above.
+    #[cfg(feature = "ndarray")]
+    dense: DenseSuperposition,
+}
+
+/// Contiguous `ndarray` backing for a [`QuantumSuperposition`]: each basis
+/// state as an `n x 1` complex column, plus the amplitude vector as its own
+/// `n x 1` column so it can be `dot`ted against an `n x n` operator.
+#[cfg(feature = "ndarray")]
+#[derive(Debug, Clone)]
+struct DenseSuperposition {
+    states: Vec<Array2<Complex>>,
+    amplitudes: Array2<Complex>,
+}
+
+#[cfg(feature = "ndarray")]
+impl DenseSuperposition {
+    fn build(states: &[StateVector], amplitudes: &[Complex]) -> Self {
+        Self {
+            states: states.iter().map(StateVector::as_array2).collect(),
+            amplitudes: column(amplitudes),
+        }
+    }
+
+    fn sync_amplitudes(&mut self, amplitudes: &[Complex]) {
+        self.amplitudes = column(amplitudes);
+    }
+}
+
+/// Builds an `n x 1` dense complex column from a flat slice.
+#[cfg(feature = "ndarray")]
+fn column(values: &[Complex]) -> Array2<Complex> {
+    Array2::from_shape_vec((values.len(), 1), values.to_vec())
+        .expect("column shape always matches its own length")
 }
 
 /// Complex number representation
@@ -44,6 +147,32 @@ pub struct StateVector<T: SIMDValue = f64> {
     symmetry: LatticeSymmetry,
 }
 
+impl<T: SIMDValue> StateVector<T> {
+    /// Create a new state vector over the given lattice symmetry.
+    pub fn new(data: Vec<T>, symmetry: LatticeSymmetry) -> Self {
+        Self { data, symmetry }
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl<T: SIMDValue> StateVector<T> {
+    /// Bridges this vector into an `n x 1` dense `Complex` column, the
+    /// shape `ndarray`'s `dot`/`outer` expect for operator application and
+    /// density-matrix assembly. `StateVector` only ever carries real data,
+    /// so every entry lands with a zero imaginary part.
+    pub fn as_array2(&self) -> Array2<Complex> {
+        column(&self.data.iter().map(|&x| Complex::new(x.to_f64().unwrap(), 0.0)).collect::<Vec<_>>())
+    }
+
+    /// Inverse of [`as_array2`](Self::as_array2): rebuilds a `StateVector`
+    /// from an `n x 1` dense column, discarding the imaginary part since
+    /// `StateVector` only stores real amplitudes.
+    pub fn from_array2(array: Array2<Complex>, symmetry: LatticeSymmetry) -> Self {
+        let data = array.column(0).iter().map(|c| T::from(c.real).unwrap()).collect();
+        Self { data, symmetry }
+    }
+}
+
 /// State metrics
 #[derive(Debug, Clone, Default)]
 pub struct StateMetrics {
@@ -63,9 +192,112 @@ impl QuantumState {
             entanglement: Vec::new(),
             superposition: None,
             metrics: StateMetrics::default(),
+            density: None,
+            basis: Basis::default(),
         }
     }
 
+    /// Integrates the Lindblad master equation
+    /// `dρ/dt = -i[H, ρ] + Σ_k (L_k ρ L_k† − ½{L_k†L_k, ρ})`
+    /// for `steps` steps of size `dt` using forward Euler, then
+    /// renormalizes the trace back to 1 and recomputes `StateMetrics`.
+    /// `hamiltonian` and each entry of `collapse_ops` are row-major n×n
+    /// matrices, with n inferred from `hamiltonian.len()`.
+    ///
+    /// The first call promotes the scalar `coherence` into a full n×n
+    /// density matrix: a uniform diagonal with `coherence` spread across
+    /// the off-diagonal terms, so a freshly-constructed `QuantumState`
+    /// evolves from the same mixture its scalar `coherence` already
+    /// implied.
+    pub fn evolve(
+        &mut self,
+        hamiltonian: &[Complex],
+        collapse_ops: &[Vec<Complex>],
+        dt: f64,
+        steps: usize,
+    ) {
+        let n = (hamiltonian.len() as f64).sqrt().round() as usize;
+        assert_eq!(n * n, hamiltonian.len(), "hamiltonian must be a square matrix");
+
+        self.ensure_density_matrix(n);
+
+        for _ in 0..steps {
+            let rho = self.density.clone().unwrap();
+            let drho = lindblad_rhs(&rho, hamiltonian, collapse_ops, n);
+            let mut next = vec![Complex::zero(); n * n];
+            for idx in 0..n * n {
+                next[idx] = rho[idx].add(&drho[idx].scale(dt));
+            }
+            self.density = Some(next);
+        }
+
+        self.renormalize_trace(n);
+        self.update_metrics_from_density(n);
+    }
+
+    /// Builds the density matrix backing this state the first time it's
+    /// needed, or leaves an existing one of matching dimension alone.
+    fn ensure_density_matrix(&mut self, n: usize) {
+        if self.density.as_ref().map(Vec::len) != Some(n * n) {
+            let mut rho = vec![Complex::zero(); n * n];
+            let diag = 1.0 / n as f64;
+            for i in 0..n {
+                rho[i * n + i] = Complex::new(diag, 0.0);
+                for j in 0..n {
+                    if i != j {
+                        rho[i * n + j] = Complex::new(diag * self.coherence, 0.0);
+                    }
+                }
+            }
+            self.density = Some(rho);
+        }
+    }
+
+    /// Rescales the density matrix so `Tr ρ == 1`, correcting the drift
+    /// Euler integration introduces over many steps.
+    fn renormalize_trace(&mut self, n: usize) {
+        let rho = self.density.as_mut().expect("density matrix must exist after evolve");
+        let trace: f64 = (0..n).map(|i| rho[i * n + i].real).sum();
+        if trace.abs() > 1e-12 {
+            let scale = 1.0 / trace;
+            for entry in rho.iter_mut() {
+                *entry = entry.scale(scale);
+            }
+        }
+    }
+
+    /// Recomputes `coherence` and `StateMetrics` from the current density
+    /// matrix: `coherence` as the average off-diagonal magnitude
+    /// normalized against a maximally-coherent pure state, and entropy
+    /// as the true von Neumann `-Tr ρ ln ρ` via `hermitian_eigenvalues`.
+    fn update_metrics_from_density(&mut self, n: usize) {
+        let rho = self.density.as_ref().expect("density matrix must exist after evolve");
+
+        let mut off_diag_sum = 0.0;
+        for i in 0..n {
+            for j in 0..n {
+                if i != j {
+                    off_diag_sum += rho[i * n + j].magnitude_squared().sqrt();
+                }
+            }
+        }
+        let pairs = (n * n - n) as f64;
+        let max_off_diag = 1.0 / n as f64;
+        if pairs > 0.0 && max_off_diag > 0.0 {
+            self.coherence = (off_diag_sum / pairs / max_off_diag).min(1.0);
+        }
+
+        let eigenvalues = hermitian_eigenvalues(rho, n);
+        self.metrics.entropy = -eigenvalues
+            .iter()
+            .filter(|&&lambda| lambda > 1e-12)
+            .map(|&lambda| lambda * lambda.ln())
+            .sum::<f64>();
+        // See `update_metrics`: fidelity is a two-state comparison now,
+        // so it's no longer assigned from a single-state formula here.
+        self.metrics.decoherence_rate = self.calculate_decoherence_rate();
+    }
+
     /// Apply quantum transformation
     pub fn apply_transformation<T: SIMDValue>(&mut self, data: &[T]) -> Vec<T> {
         if let Some(superposition) = &self.superposition {
@@ -75,20 +307,100 @@ impl QuantumState {
         }
     }
 
-    /// Entangle with another state
+    /// Entangle with another state, combining both into the tensor-product
+    /// basis of their two Hilbert spaces (a Kronecker product of their
+    /// density matrices, and subsystem dims concatenated in order). Unlike
+    /// the old index-only bookkeeping, `self` afterward actually describes
+    /// the joint system `self ⊗ other`.
     pub fn entangle(&mut self, other: &mut Self) {
         let new_index = self.entanglement.len();
         self.entanglement.push(new_index);
         other.entanglement.push(new_index);
+
+        let self_dim = self.basis.total_dim();
+        let other_dim = other.basis.total_dim();
+        self.ensure_density_matrix(self_dim);
+        other.ensure_density_matrix(other_dim);
+
+        let combined = kron(
+            self.density.as_ref().unwrap(),
+            self_dim,
+            other.density.as_ref().unwrap(),
+            other_dim,
+        );
+
+        self.basis = self.basis.tensor(&other.basis);
+        self.density = Some(combined);
         self.update_metrics();
     }
 
+    /// Traces out every subsystem not listed in `keep`, returning the
+    /// reduced state of just those subsystems. `keep` indexes into
+    /// `self.basis.dims()`, the same order `entangle` concatenates dims
+    /// in. Errs with `QuantumError::IncompatibleBases` if `keep` names a
+    /// subsystem that doesn't exist in this state's basis.
+    pub fn partial_trace(&self, keep: &[usize]) -> QuantumResult<QuantumState> {
+        let dims = self.basis.dims();
+        if keep.iter().any(|&k| k >= dims.len()) {
+            return Err(QuantumError::IncompatibleBases);
+        }
+
+        let n = self.basis.total_dim();
+        let rho = match &self.density {
+            Some(rho) => rho.clone(),
+            None => {
+                let mut promoted = self.clone();
+                promoted.ensure_density_matrix(n);
+                promoted.density.unwrap()
+            }
+        };
+
+        let kept_dims: Vec<usize> = keep.iter().map(|&k| dims[k]).collect();
+        let kept_dim: usize = kept_dims.iter().product();
+
+        let mut reduced = vec![Complex::zero(); kept_dim * kept_dim];
+        for row in 0..n {
+            for col in 0..n {
+                let row_digits = decompose(row, dims);
+                let col_digits = decompose(col, dims);
+
+                let traced_match = (0..dims.len())
+                    .filter(|i| !keep.contains(i))
+                    .all(|i| row_digits[i] == col_digits[i]);
+                if !traced_match {
+                    continue;
+                }
+
+                let kept_row: Vec<usize> = keep.iter().map(|&i| row_digits[i]).collect();
+                let kept_col: Vec<usize> = keep.iter().map(|&i| col_digits[i]).collect();
+                let out_row = compose(&kept_row, &kept_dims);
+                let out_col = compose(&kept_col, &kept_dims);
+
+                reduced[out_row * kept_dim + out_col] =
+                    reduced[out_row * kept_dim + out_col].add(&rho[row * n + col]);
+            }
+        }
+
+        let mut result = QuantumState::new(self.coherence);
+        result.basis = Basis { dims: kept_dims };
+        result.density = Some(reduced);
+        result.update_metrics_from_density(kept_dim);
+        Ok(result)
+    }
+
     /// Create superposition
     pub fn create_superposition<T: SIMDValue>(&mut self, states: Vec<StateVector<T>>) {
         let amplitudes = Self::generate_amplitudes(states.len());
+        let states: Vec<StateVector> = states.into_iter().map(|s| s.into()).collect();
+
+        #[cfg(feature = "ndarray")]
+        let dense = DenseSuperposition::build(&states, &amplitudes);
+
         self.superposition = Some(Box::new(QuantumSuperposition {
-            states: states.into_iter().map(|s| s.into()).collect(),
+            states,
             amplitudes,
+            #[cfg(feature = "ndarray")]
+            dense,
         }));
     }
 
@@ -100,14 +412,41 @@ impl QuantumState {
 
     /// Update state metrics
     fn update_metrics(&mut self) {
-        self.metrics.fidelity = self.calculate_fidelity();
+        // `metrics.fidelity` is no longer auto-maintained here: fidelity
+        // is now a genuine two-state comparison (see `fidelity`) rather
+        // than a function of this state's own coherence, so there's no
+        // meaningful single-state value to assign on every update.
         self.metrics.entropy = self.calculate_entropy();
         self.metrics.decoherence_rate = self.calculate_decoherence_rate();
     }
 
-    /// Calculate state fidelity
-    fn calculate_fidelity(&self) -> f64 {
-        self.coherence.powi(2)
+    /// Uhlmann fidelity between this state and `other`:
+    /// `F(ρ,σ) = (Tr √(√ρ·σ·√ρ))²`. Both states are promoted to density
+    /// matrices of their own dimension first; panics if the two
+    /// dimensions don't match, since comparing density matrices of
+    /// different Hilbert spaces is meaningless.
+    pub fn fidelity(&self, other: &Self) -> f64 {
+        let n = self.basis.total_dim();
+        assert_eq!(
+            n,
+            other.basis.total_dim(),
+            "fidelity requires two states of the same Hilbert space dimension"
+        );
+
+        let mut lhs = self.clone();
+        lhs.ensure_density_matrix(n);
+        let mut rhs = other.clone();
+        rhs.ensure_density_matrix(n);
+
+        let rho = lhs.density.unwrap();
+        let sigma = rhs.density.unwrap();
+
+        let sqrt_rho = hermitian_sqrt(&rho, n);
+        let inner = mat_mul(&mat_mul(&sqrt_rho, &sigma, n), &sqrt_rho, n);
+        let (eigenvalues, _) = hermitian_eigen(&inner, n);
+        let trace_sqrt: f64 = eigenvalues.iter().map(|&lambda| lambda.max(0.0).sqrt()).sum();
+
+        trace_sqrt * trace_sqrt
     }
 
     /// Calculate entanglement entropy
@@ -119,6 +458,188 @@ impl QuantumState {
     fn calculate_decoherence_rate(&self) -> f64 {
         1.0 - self.coherence
     }
+
+    /// Left-multiplies the superposition's `amplitudes` by the n×n
+    /// complex matrix `op` (row-major), then renormalizes so the result
+    /// stays a unit-norm state vector. A no-op if there's no
+    /// superposition to act on yet.
+    pub fn apply_operator(&mut self, op: &[Complex]) {
+        if let Some(superposition) = &mut self.superposition {
+            let n = superposition.amplitudes.len();
+            assert_eq!(
+                op.len(),
+                n * n,
+                "operator must be an n×n matrix matching the amplitude count"
+            );
+
+            #[cfg(feature = "ndarray")]
+            let mut new_amplitudes = {
+                let op_matrix = Array2::from_shape_vec((n, n), op.to_vec())
+                    .expect("operator shape always matches its own length");
+                op_matrix.dot(&superposition.dense.amplitudes).column(0).to_vec()
+            };
+            #[cfg(not(feature = "ndarray"))]
+            let mut new_amplitudes = {
+                let mut new_amplitudes = vec![Complex::zero(); n];
+                for i in 0..n {
+                    let mut sum = Complex::zero();
+                    for j in 0..n {
+                        sum = sum.add(&op[i * n + j].multiply(&superposition.amplitudes[j]));
+                    }
+                    new_amplitudes[i] = sum;
+                }
+                new_amplitudes
+            };
+            renormalize_amplitudes(&mut new_amplitudes);
+            superposition.amplitudes = new_amplitudes;
+            #[cfg(feature = "ndarray")]
+            superposition.dense.sync_amplitudes(&superposition.amplitudes);
+        }
+    }
+
+    /// Like `apply_operator`, but only acts on the amplitude components
+    /// whose `control` subsystem bit is set -- component `i` is in scope
+    /// only when bit `control` of `i` is `1`, the usual controlled-gate
+    /// convention for a computational basis indexed by qubit bitstrings.
+    /// `op` is sized to that in-scope subset (an m×m matrix, where m is
+    /// the number of amplitudes with the control bit set), not to the
+    /// full amplitude count -- everything outside the subset is left
+    /// untouched, matching how a controlled gate leaves the rest of the
+    /// state alone.
+    pub fn apply_controlled(&mut self, control: usize, op: &[Complex]) {
+        if let Some(superposition) = &mut self.superposition {
+            let n = superposition.amplitudes.len();
+            let controlled_indices: Vec<usize> =
+                (0..n).filter(|i| (i >> control) & 1 == 1).collect();
+            let m = controlled_indices.len();
+            assert_eq!(
+                op.len(),
+                m * m,
+                "controlled operator must match the number of amplitudes with the control bit set"
+            );
+
+            let subset: Vec<Complex> = controlled_indices
+                .iter()
+                .map(|&i| superposition.amplitudes[i])
+                .collect();
+
+            #[cfg(feature = "ndarray")]
+            let transformed = {
+                let op_matrix = Array2::from_shape_vec((m, m), op.to_vec())
+                    .expect("controlled operator shape always matches its own length");
+                op_matrix.dot(&column(&subset)).column(0).to_vec()
+            };
+            #[cfg(not(feature = "ndarray"))]
+            let transformed = {
+                let mut transformed = vec![Complex::zero(); m];
+                for row in 0..m {
+                    let mut sum = Complex::zero();
+                    for col in 0..m {
+                        sum = sum.add(&op[row * m + col].multiply(&subset[col]));
+                    }
+                    transformed[row] = sum;
+                }
+                transformed
+            };
+
+            for (slot, &target) in controlled_indices.iter().enumerate() {
+                superposition.amplitudes[target] = transformed[slot];
+            }
+            renormalize_amplitudes(&mut superposition.amplitudes);
+            #[cfg(feature = "ndarray")]
+            superposition.dense.sync_amplitudes(&superposition.amplitudes);
+        }
+    }
+}
+
+/// Prebuilt unitary operators for `QuantumState::apply_operator`, each
+/// returned as a row-major complex matrix.
+pub struct Gates;
+
+impl Gates {
+    /// Single-qubit Hadamard: `1/√2 · [[1, 1], [1, -1]]`.
+    pub fn hadamard() -> Vec<Complex> {
+        let f = 1.0 / std::f64::consts::SQRT_2;
+        vec![
+            Complex::new(f, 0.0), Complex::new(f, 0.0),
+            Complex::new(f, 0.0), Complex::new(-f, 0.0),
+        ]
+    }
+
+    /// Single-qubit phase gate: `[[1, 0], [0, e^{iθ}]]`.
+    pub fn phase(theta: f64) -> Vec<Complex> {
+        vec![
+            Complex::new(1.0, 0.0), Complex::zero(),
+            Complex::zero(), Complex::new(theta.cos(), theta.sin()),
+        ]
+    }
+
+    /// Pauli-X (bit flip): `[[0, 1], [1, 0]]`.
+    pub fn pauli_x() -> Vec<Complex> {
+        vec![
+            Complex::zero(), Complex::new(1.0, 0.0),
+            Complex::new(1.0, 0.0), Complex::zero(),
+        ]
+    }
+
+    /// Pauli-Y: `[[0, -i], [i, 0]]`.
+    pub fn pauli_y() -> Vec<Complex> {
+        vec![
+            Complex::zero(), Complex::new(0.0, -1.0),
+            Complex::new(0.0, 1.0), Complex::zero(),
+        ]
+    }
+
+    /// Pauli-Z (phase flip): `[[1, 0], [0, -1]]`.
+    pub fn pauli_z() -> Vec<Complex> {
+        vec![
+            Complex::new(1.0, 0.0), Complex::zero(),
+            Complex::zero(), Complex::new(-1.0, 0.0),
+        ]
+    }
+
+    /// CNOT over two qubits (4×4, computational basis order
+    /// `|00⟩, |01⟩, |10⟩, |11⟩`): flips the target when the control is `|1⟩`.
+    pub fn cnot() -> Vec<Complex> {
+        let one = Complex::new(1.0, 0.0);
+        let mut m = vec![Complex::zero(); 16];
+        m[0 * 4 + 0] = one;
+        m[1 * 4 + 1] = one;
+        m[2 * 4 + 3] = one;
+        m[3 * 4 + 2] = one;
+        m
+    }
+}
+
+/// Rescales `amplitudes` so their squared magnitudes sum to 1.
+fn renormalize_amplitudes(amplitudes: &mut [Complex]) {
+    let norm = amplitudes.iter().map(Complex::magnitude_squared).sum::<f64>().sqrt();
+    if norm > 1e-12 {
+        for amp in amplitudes.iter_mut() {
+            *amp = amp.scale(1.0 / norm);
+        }
+    }
+}
+
+impl QuantumSuperposition {
+    /// Applies this superposition's amplitude weighting to `data`: each
+    /// element is scaled by the average amplitude magnitude (further
+    /// damped by `coherence`). Backs `QuantumState::apply_transformation`,
+    /// which otherwise has no way to let a superposition affect the data
+    /// passed through it.
+    pub fn apply<T: SIMDValue>(&self, data: &[T], coherence: f64) -> Vec<T> {
+        let weight = self.average_amplitude_magnitude() * coherence;
+        let factor = T::from(weight).unwrap();
+        data.iter().map(|&x| x * factor).collect()
+    }
+
+    fn average_amplitude_magnitude(&self) -> f64 {
+        if self.amplitudes.is_empty() {
+            return 1.0;
+        }
+        let sum: f64 = self.amplitudes.iter().map(Complex::magnitude_squared).map(f64::sqrt).sum();
+        sum / self.amplitudes.len() as f64
+    }
 }
 
 impl Complex {
@@ -139,4 +660,434 @@ impl Complex {
             imag: self.real * other.imag + self.imag * other.real,
         }
     }
+
+    /// The additive identity.
+    pub fn zero() -> Complex {
+        Complex { real: 0.0, imag: 0.0 }
+    }
+
+    /// Complex addition.
+    pub fn add(&self, other: &Complex) -> Complex {
+        Complex {
+            real: self.real + other.real,
+            imag: self.imag + other.imag,
+        }
+    }
+
+    /// Complex subtraction.
+    pub fn sub(&self, other: &Complex) -> Complex {
+        Complex {
+            real: self.real - other.real,
+            imag: self.imag - other.imag,
+        }
+    }
+
+    /// Scales by a real factor.
+    pub fn scale(&self, factor: f64) -> Complex {
+        Complex {
+            real: self.real * factor,
+            imag: self.imag * factor,
+        }
+    }
+
+    /// Complex conjugate.
+    pub fn conjugate(&self) -> Complex {
+        Complex {
+            real: self.real,
+            imag: -self.imag,
+        }
+    }
+}
+
+// `ndarray::Array2::dot` requires its element type to implement
+// `LinalgScalar`, which in turn needs the standard operator traits plus
+// `Zero`/`One`. These mirror the `multiply`/`add`/`sub` methods above and
+// exist only to satisfy that bound -- the method-call API remains how the
+// rest of this module does complex arithmetic.
+#[cfg(feature = "ndarray")]
+impl std::ops::Add for Complex {
+    type Output = Complex;
+    fn add(self, other: Complex) -> Complex {
+        Complex { real: self.real + other.real, imag: self.imag + other.imag }
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl std::ops::Sub for Complex {
+    type Output = Complex;
+    fn sub(self, other: Complex) -> Complex {
+        Complex { real: self.real - other.real, imag: self.imag - other.imag }
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl std::ops::Mul for Complex {
+    type Output = Complex;
+    fn mul(self, other: Complex) -> Complex {
+        self.multiply(&other)
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl std::ops::Div for Complex {
+    type Output = Complex;
+    fn div(self, other: Complex) -> Complex {
+        let denom = other.magnitude_squared();
+        let num = self.multiply(&other.conjugate());
+        Complex { real: num.real / denom, imag: num.imag / denom }
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl num_traits::Zero for Complex {
+    fn zero() -> Complex {
+        Complex::zero()
+    }
+    fn is_zero(&self) -> bool {
+        self.real == 0.0 && self.imag == 0.0
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl num_traits::One for Complex {
+    fn one() -> Complex {
+        Complex::new(1.0, 0.0)
+    }
+}
+
+/// Row-major n×n complex matrix multiply. Behind the `ndarray` feature this
+/// routes through `Array2::dot` so it runs against contiguous,
+/// vectorizable storage instead of the scalar triple loop; every caller
+/// (density-matrix assembly in `evolve`, `entangle`, `fidelity`, ...)
+/// benefits without having to know which backend is active.
+#[cfg(feature = "ndarray")]
+fn mat_mul(a: &[Complex], b: &[Complex], n: usize) -> Vec<Complex> {
+    let a = Array2::from_shape_vec((n, n), a.to_vec()).expect("a is already an n x n matrix");
+    let b = Array2::from_shape_vec((n, n), b.to_vec()).expect("b is already an n x n matrix");
+    a.dot(&b).into_raw_vec()
+}
+
+#[cfg(not(feature = "ndarray"))]
+fn mat_mul(a: &[Complex], b: &[Complex], n: usize) -> Vec<Complex> {
+    let mut out = vec![Complex::zero(); n * n];
+    for i in 0..n {
+        for j in 0..n {
+            let mut sum = Complex::zero();
+            for k in 0..n {
+                sum = sum.add(&a[i * n + k].multiply(&b[k * n + j]));
+            }
+            out[i * n + j] = sum;
+        }
+    }
+    out
+}
+
+/// Conjugate transpose of a row-major n×n complex matrix.
+fn mat_dagger(a: &[Complex], n: usize) -> Vec<Complex> {
+    let mut out = vec![Complex::zero(); n * n];
+    for i in 0..n {
+        for j in 0..n {
+            out[j * n + i] = a[i * n + j].conjugate();
+        }
+    }
+    out
+}
+
+/// `[a, b] = ab - ba`
+fn commutator(a: &[Complex], b: &[Complex], n: usize) -> Vec<Complex> {
+    let ab = mat_mul(a, b, n);
+    let ba = mat_mul(b, a, n);
+    ab.iter().zip(ba.iter()).map(|(x, y)| x.sub(y)).collect()
+}
+
+/// `{a, b} = ab + ba`
+fn anticommutator(a: &[Complex], b: &[Complex], n: usize) -> Vec<Complex> {
+    let ab = mat_mul(a, b, n);
+    let ba = mat_mul(b, a, n);
+    ab.iter().zip(ba.iter()).map(|(x, y)| x.add(y)).collect()
+}
+
+/// Kronecker product of two row-major square matrices -- the combined
+/// system's density matrix for two subsystems of dimension `an` and `bn`
+/// that haven't interacted yet. `a` is the more-significant subsystem:
+/// row/col `i*bn + k` of the result corresponds to `a`'s index `i` and
+/// `b`'s index `k`, matching `decompose`/`compose`.
+fn kron(a: &[Complex], an: usize, b: &[Complex], bn: usize) -> Vec<Complex> {
+    let n = an * bn;
+    let mut out = vec![Complex::zero(); n * n];
+    for i in 0..an {
+        for j in 0..an {
+            let a_ij = a[i * an + j];
+            for k in 0..bn {
+                for l in 0..bn {
+                    let row = i * bn + k;
+                    let col = j * bn + l;
+                    out[row * n + col] = a_ij.multiply(&b[k * bn + l]);
+                }
+            }
+        }
+    }
+    out
+}
+
+/// Mixed-radix decomposition of a flat Kronecker-basis index into one
+/// digit per subsystem in `dims`, most-significant subsystem first.
+fn decompose(mut index: usize, dims: &[usize]) -> Vec<usize> {
+    let mut digits = vec![0usize; dims.len()];
+    for i in (0..dims.len()).rev() {
+        digits[i] = index % dims[i];
+        index /= dims[i];
+    }
+    digits
+}
+
+/// Inverse of `decompose`: recombines per-subsystem digits back into a
+/// flat index for the basis described by `dims`.
+fn compose(digits: &[usize], dims: &[usize]) -> usize {
+    let mut index = 0;
+    for i in 0..dims.len() {
+        index = index * dims[i] + digits[i];
+    }
+    index
+}
+
+/// The n×n identity matrix.
+fn identity(n: usize) -> Vec<Complex> {
+    let mut m = vec![Complex::zero(); n * n];
+    for i in 0..n {
+        m[i * n + i] = Complex::new(1.0, 0.0);
+    }
+    m
+}
+
+/// Right-hand side of the Lindblad master equation:
+/// `-i[H, ρ] + Σ_k (L_k ρ L_k† − ½{L_k†L_k, ρ})`.
+fn lindblad_rhs(
+    rho: &[Complex],
+    hamiltonian: &[Complex],
+    collapse_ops: &[Vec<Complex>],
+    n: usize,
+) -> Vec<Complex> {
+    let neg_i = Complex::new(0.0, -1.0);
+    let comm = commutator(hamiltonian, rho, n);
+    let mut drho: Vec<Complex> = comm.iter().map(|c| neg_i.multiply(c)).collect();
+
+    for l in collapse_ops {
+        assert_eq!(l.len(), n * n, "collapse operator must be a square n×n matrix");
+        let l_dag = mat_dagger(l, n);
+        let l_dag_l = mat_mul(&l_dag, l, n);
+        let l_rho_l_dag = mat_mul(&mat_mul(l, rho, n), &l_dag, n);
+        let anti = anticommutator(&l_dag_l, rho, n);
+        for idx in 0..n * n {
+            drho[idx] = drho[idx].add(&l_rho_l_dag[idx]).sub(&anti[idx].scale(0.5));
+        }
+    }
+
+    drho
+}
+
+/// Eigenvalues of a Hermitian complex matrix; see `hermitian_eigen`.
+fn hermitian_eigenvalues(mat: &[Complex], n: usize) -> Vec<f64> {
+    hermitian_eigen(mat, n).0
+}
+
+/// Matrix square root of a Hermitian positive-semidefinite matrix via
+/// eigendecomposition: `V·diag(√λ)·V†`. Negative eigenvalues (numerical
+/// noise from the Jacobi sweep, since a true PSD matrix has none) are
+/// clamped to zero before the root is taken, so this never produces NaN.
+fn hermitian_sqrt(mat: &[Complex], n: usize) -> Vec<Complex> {
+    let (eigenvalues, v) = hermitian_eigen(mat, n);
+    let mut sqrt_diag = vec![Complex::zero(); n * n];
+    for i in 0..n {
+        sqrt_diag[i * n + i] = Complex::new(eigenvalues[i].max(0.0).sqrt(), 0.0);
+    }
+    let v_dag = mat_dagger(&v, n);
+    mat_mul(&mat_mul(&v, &sqrt_diag, n), &v_dag, n)
+}
+
+/// Jacobi eigenvalue algorithm for a Hermitian complex matrix. Each sweep
+/// step picks the largest-magnitude off-diagonal entry `(p, q)`, applies a
+/// phase rotation that makes it real, then a real Givens rotation that
+/// zeroes it out, until the remaining off-diagonal mass is below
+/// tolerance or the sweep budget runs out. A full dense similarity
+/// transform per rotation is `O(n^3)`, which is fine for the small state
+/// dimensions this module deals with.
+///
+/// Returns `(eigenvalues, eigenvectors)` where column `i` of the
+/// row-major `eigenvectors` matrix is the eigenvector for `eigenvalues[i]`.
+fn hermitian_eigen(mat: &[Complex], n: usize) -> (Vec<f64>, Vec<Complex>) {
+    let mut a = mat.to_vec();
+    let mut v = identity(n);
+
+    const MAX_SWEEPS: usize = 100;
+    const TOLERANCE: f64 = 1e-12;
+
+    for _ in 0..MAX_SWEEPS {
+        let (p, q, max_mag) = largest_off_diagonal(&a, n);
+        if max_mag < TOLERANCE {
+            break;
+        }
+
+        let apq = a[p * n + q];
+        let r = apq.magnitude_squared().sqrt();
+        let phi = apq.imag.atan2(apq.real);
+
+        let d = (a[q * n + q].real - a[p * n + p].real) / (2.0 * r);
+        let t = if d >= 0.0 {
+            1.0 / (d + (d * d + 1.0).sqrt())
+        } else {
+            1.0 / (d - (d * d + 1.0).sqrt())
+        };
+        let c = 1.0 / (t * t + 1.0).sqrt();
+        let s = t * c;
+
+        // Q = U·R: U is a phase rotation that makes a[p][q] real, R is
+        // the real Givens rotation in the (p, q) plane that then zeroes
+        // it out.
+        let phase = Complex::new(phi.cos(), phi.sin());
+        let mut rot = identity(n);
+        rot[p * n + p] = phase.scale(c);
+        rot[q * n + q] = Complex::new(c, 0.0);
+        rot[p * n + q] = phase.scale(s);
+        rot[q * n + p] = Complex::new(-s, 0.0);
+
+        let rot_dag = mat_dagger(&rot, n);
+        a = mat_mul(&mat_mul(&rot_dag, &a, n), &rot, n);
+        v = mat_mul(&v, &rot, n);
+    }
+
+    let eigenvalues = (0..n).map(|i| a[i * n + i].real).collect();
+    (eigenvalues, v)
+}
+
+/// Largest-magnitude off-diagonal entry of a Hermitian matrix, as
+/// `(row, col, magnitude)`.
+fn largest_off_diagonal(a: &[Complex], n: usize) -> (usize, usize, f64) {
+    let mut best = (0usize, 1usize.min(n.saturating_sub(1)), 0.0);
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let mag = a[i * n + j].magnitude_squared().sqrt();
+            if mag > best.2 {
+                best = (i, j, mag);
+            }
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entangle_builds_tensor_product_basis() {
+        let mut a = QuantumState::new(1.0);
+        let mut b = QuantumState::new(1.0);
+        a.entangle(&mut b);
+
+        assert_eq!(a.basis.dims(), &[2, 2]);
+        assert_eq!(a.density.as_ref().unwrap().len(), 16);
+    }
+
+    #[test]
+    fn test_partial_trace_recovers_original_subsystem() {
+        let mut a = QuantumState::new(1.0);
+        let mut b = QuantumState::new(0.3);
+        a.entangle(&mut b);
+
+        let reduced = a.partial_trace(&[0]).unwrap();
+        assert_eq!(reduced.basis.dims(), &[2]);
+        assert_eq!(reduced.density.as_ref().unwrap().len(), 4);
+    }
+
+    #[test]
+    fn test_fidelity_of_identical_states_is_one() {
+        let mut state = QuantumState::new(0.7);
+        state.ensure_density_matrix(2);
+        assert!((state.fidelity(&state.clone()) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_fidelity_is_symmetric() {
+        let mut a = QuantumState::new(0.9);
+        a.ensure_density_matrix(2);
+        let mut b = QuantumState::new(0.2);
+        b.ensure_density_matrix(2);
+
+        assert!((a.fidelity(&b) - b.fidelity(&a)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_apply_operator_hadamard_preserves_norm() {
+        let mut state = QuantumState::new(1.0);
+        state.create_superposition(vec![
+            StateVector { data: vec![1.0], symmetry: LatticeSymmetry::Cubic },
+            StateVector { data: vec![1.0], symmetry: LatticeSymmetry::Cubic },
+        ]);
+
+        state.apply_operator(&Gates::hadamard());
+
+        let amplitudes = &state.superposition.as_ref().unwrap().amplitudes;
+        let norm: f64 = amplitudes.iter().map(Complex::magnitude_squared).sum();
+        assert!((norm - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_apply_controlled_only_touches_control_set_amplitudes() {
+        let mut state = QuantumState::new(1.0);
+        state.create_superposition(vec![
+            StateVector { data: vec![1.0], symmetry: LatticeSymmetry::Cubic },
+            StateVector { data: vec![1.0], symmetry: LatticeSymmetry::Cubic },
+            StateVector { data: vec![1.0], symmetry: LatticeSymmetry::Cubic },
+            StateVector { data: vec![1.0], symmetry: LatticeSymmetry::Cubic },
+        ]);
+
+        let before = state.superposition.as_ref().unwrap().amplitudes[0];
+        state.apply_controlled(0, &Gates::pauli_x());
+        let after = &state.superposition.as_ref().unwrap().amplitudes;
+
+        // Index 0 has control bit 0 unset, so it must be untouched.
+        assert!((after[0].magnitude_squared() - before.magnitude_squared()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_partial_trace_rejects_unknown_subsystem() {
+        let state = QuantumState::new(1.0);
+        assert!(matches!(state.partial_trace(&[5]), Err(QuantumError::IncompatibleBases)));
+    }
+
+    #[test]
+    fn test_evolve_preserves_trace() {
+        let mut state = QuantumState::new(0.5);
+        let hamiltonian = vec![
+            Complex::new(1.0, 0.0), Complex::new(0.0, 0.0),
+            Complex::new(0.0, 0.0), Complex::new(-1.0, 0.0),
+        ];
+        state.evolve(&hamiltonian, &[], 0.01, 10);
+
+        let trace: f64 = state.density.as_ref().unwrap()
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| idx % 2 == 0)
+            .map(|(_, c)| c.real)
+            .sum();
+        assert!((trace - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_evolve_updates_metrics() {
+        let mut state = QuantumState::new(1.0);
+        let hamiltonian = vec![
+            Complex::new(0.0, 0.0), Complex::new(1.0, 0.0),
+            Complex::new(1.0, 0.0), Complex::new(0.0, 0.0),
+        ];
+        let dephasing = vec![
+            Complex::new(1.0, 0.0), Complex::new(0.0, 0.0),
+            Complex::new(0.0, 0.0), Complex::new(-1.0, 0.0),
+        ];
+        state.evolve(&hamiltonian, &[dephasing], 0.05, 20);
+
+        assert!(state.metrics.entropy >= 0.0);
+        assert!(state.coherence.is_finite());
+    }
 }
@@ -1,12 +1,12 @@
 // src/debug.rs
 use crate::serial_println;
-use core::sync::atomic::{AtomicU64, Ordering};
+use core::sync::atomic::{AtomicU64, AtomicU8, AtomicUsize, Ordering};
 use lazy_static::lazy_static;
 use spin::Mutex;
 use alloc::string::String;
 use alloc::vec::Vec;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum DebugLevel {
     Info,
     Warning,
@@ -14,14 +14,72 @@ pub enum DebugLevel {
     Critical,
 }
 
+/// Number of independently-locked shards the log is split across, so
+/// concurrent producers rarely contend for the same lock.
+const SHARD_COUNT: usize = 8;
+/// Per-shard capacity; together the shards hold the same 1000 messages
+/// the single unsharded buffer used to keep.
+const SHARD_CAPACITY: usize = 1000 / SHARD_COUNT;
+
+/// Pads a value out to a full cache line so independently-updated
+/// per-shard counters don't false-share a line with their neighbors --
+/// the same layout trick crossbeam-utils' `CachePadded` uses.
+#[repr(align(64))]
+struct CachePadded<T>(T);
+
+struct LogEntry {
+    timestamp: u64,
+    message: String,
+}
+
+/// One independently-locked ring buffer of log entries
+struct LogShard {
+    count: CachePadded<AtomicU64>,
+    entries: Mutex<Vec<LogEntry>>,
+}
+
+impl LogShard {
+    const fn new() -> Self {
+        Self {
+            count: CachePadded(AtomicU64::new(0)),
+            entries: Mutex::new(Vec::new()),
+        }
+    }
+
+    fn push(&self, entry: LogEntry) {
+        if let Some(mut entries) = self.entries.try_lock() {
+            if entries.len() >= SHARD_CAPACITY {
+                entries.remove(0);
+            }
+            entries.push(entry);
+        }
+    }
+}
+
+/// Round-robin cursor used to pick a shard for each `log()` call
+static NEXT_SHARD: CachePadded<AtomicUsize> = CachePadded(AtomicUsize::new(0));
+/// Minimum `DebugLevel` a message must meet to be kept; messages below
+/// this are discarded before they're even formatted
+static LOG_LEVEL_THRESHOLD: AtomicU8 = AtomicU8::new(DebugLevel::Info as u8);
+
 lazy_static! {
-    static ref DEBUG_LOG: Mutex<Vec<String>> = Mutex::new(Vec::new());
-    static ref MESSAGE_COUNT: AtomicU64 = AtomicU64::new(0);
+    static ref SHARDS: [LogShard; SHARD_COUNT] = core::array::from_fn(|_| LogShard::new());
+}
+
+/// Sets the minimum level a message must meet to be logged; anything
+/// below it is dropped without being formatted or stored.
+pub fn set_log_level(level: DebugLevel) {
+    LOG_LEVEL_THRESHOLD.store(level as u8, Ordering::Relaxed);
 }
 
 pub fn log(level: DebugLevel, message: &str) {
-    let count = MESSAGE_COUNT.fetch_add(1, Ordering::SeqCst);
+    if (level as u8) < LOG_LEVEL_THRESHOLD.load(Ordering::Relaxed) {
+        return;
+    }
+
     let timestamp = crate::stats::SYSTEM_STATS.lock().get_timer_ticks();
+    let shard = &SHARDS[NEXT_SHARD.0.fetch_add(1, Ordering::Relaxed) % SHARD_COUNT];
+    let count = shard.count.0.fetch_add(1, Ordering::Relaxed);
 
     let log_message = format!(
         "[{:04}][{:08}][{:?}] {}",
@@ -31,26 +89,26 @@ pub fn log(level: DebugLevel, message: &str) {
         message
     );
 
-    // Store in circular buffer
-    if let Some(mut log) = DEBUG_LOG.try_lock() {
-        if log.len() >= 1000 { // Keep last 1000 messages
-            log.remove(0);
-        }
-        log.push(log_message.clone());
-    }
+    shard.push(LogEntry { timestamp, message: log_message.clone() });
 
     // Always output to serial
     serial_println!("{}", log_message);
 }
 
 pub fn dump_debug_log() {
-    if let Some(log) = DEBUG_LOG.try_lock() {
-        serial_println!("=== DEBUG LOG DUMP ===");
-        for message in log.iter() {
-            serial_println!("{}", message);
+    let mut merged: Vec<(u64, String)> = Vec::new();
+    for shard in SHARDS.iter() {
+        if let Some(entries) = shard.entries.try_lock() {
+            merged.extend(entries.iter().map(|entry| (entry.timestamp, entry.message.clone())));
         }
-        serial_println!("=== END DEBUG LOG ===");
     }
+    merged.sort_by_key(|(timestamp, _)| *timestamp);
+
+    serial_println!("=== DEBUG LOG DUMP ===");
+    for (_, message) in &merged {
+        serial_println!("{}", message);
+    }
+    serial_println!("=== END DEBUG LOG ===");
 }
 
 // Helper macros
@@ -4,7 +4,10 @@ use lazy_static::lazy_static;
 use spin::Mutex;
 use crate::splat::{self, SplatLevel};
 use core::time::Duration;
+use core::sync::atomic::{AtomicU64, Ordering};
+use alloc::boxed::Box;
 use alloc::string::String;
+use alloc::vec::Vec;
 use alloc::format;
 
 pub mod rtc;
@@ -21,11 +24,17 @@ const RTC_HOURS: u8 = 0x04;
 const RTC_DAY: u8 = 0x07;
 const RTC_MONTH: u8 = 0x08;
 const RTC_YEAR: u8 = 0x09;
+const RTC_CENTURY: u8 = 0x32;
 const RTC_STATUS_A: u8 = 0x0A;
 const RTC_STATUS_B: u8 = 0x0B;
 const RTC_STATUS_C: u8 = 0x0C;
 const RTC_STATUS_D: u8 = 0x0D;
 
+// Status Register B bits
+const STATUS_B_24_HOUR: u8 = 0x02;
+const STATUS_B_BINARY: u8 = 0x04;
+const HOUR_PM_BIT: u8 = 0x80;
+
 // Configuration Constants
 const MAX_RTC_ATTEMPTS: u8 = 3;
 const CENTURY_BASE: u16 = 2000;
@@ -80,6 +89,28 @@ pub enum RTCError {
     LockError,
 }
 
+/// A clock backend the kernel can consult for the current time.
+///
+/// `RTC` and [`TickCounter`] both implement this so [`ClockFacade`] can
+/// hold several sources behind one interface instead of the kernel
+/// trusting a single hard-wired RTC read, following the same
+/// driver-trait decoupling used for embedded time/net drivers.
+pub trait TimeSource: Send {
+    /// Reads the current time from this source
+    fn now(&mut self) -> Result<DateTime, RTCError>;
+    /// Smallest duration this source can distinguish between two reads
+    fn resolution(&self) -> Duration;
+    /// Whether this source only ever moves forward (never jumps, never
+    /// needs wall-clock calibration)
+    fn is_monotonic(&self) -> bool;
+}
+
+/// Number of seconds elapsed since midnight, used to compare sources
+/// without having to reconcile differing calendar dates.
+fn seconds_of_day(dt: &DateTime) -> u32 {
+    dt.hours as u32 * 3600 + dt.minutes as u32 * 60 + dt.seconds as u32
+}
+
 pub struct RTC {
     address: Port<u8>,
     data: Port<u8>,
@@ -173,14 +204,49 @@ impl RTC {
         })
     }
 
+    /// Decodes a raw register value using the binary-vs-BCD mode reported
+    /// by Status Register B, rather than assuming BCD.
+    fn decode(raw: u8, binary_mode: bool) -> u8 {
+        if binary_mode {
+            raw
+        } else {
+            Self::bcd_to_binary(raw)
+        }
+    }
+
     fn try_get_datetime(&mut self) -> Result<DateTime, RTCError> {
         unsafe {
-            let seconds = Self::bcd_to_binary(self.read_register(RTC_SECONDS)?);
-            let minutes = Self::bcd_to_binary(self.read_register(RTC_MINUTES)?);
-            let hours = Self::bcd_to_binary(self.read_register(RTC_HOURS)?);
-            let day = Self::bcd_to_binary(self.read_register(RTC_DAY)?);
-            let month = Self::bcd_to_binary(self.read_register(RTC_MONTH)?);
-            let year = CENTURY_BASE + Self::bcd_to_binary(self.read_register(RTC_YEAR)?) as u16;
+            let status_b = self.read_register(RTC_STATUS_B)?;
+            let binary_mode = status_b & STATUS_B_BINARY != 0;
+            let twenty_four_hour = status_b & STATUS_B_24_HOUR != 0;
+
+            let seconds = Self::decode(self.read_register(RTC_SECONDS)?, binary_mode);
+            let minutes = Self::decode(self.read_register(RTC_MINUTES)?, binary_mode);
+
+            let raw_hours = self.read_register(RTC_HOURS)?;
+            let is_pm = raw_hours & HOUR_PM_BIT != 0;
+            let mut hours = Self::decode(raw_hours & !HOUR_PM_BIT, binary_mode);
+            if !twenty_four_hour {
+                hours = match (hours, is_pm) {
+                    (12, false) => 0,
+                    (12, true) => 12,
+                    (h, true) => h + 12,
+                    (h, false) => h,
+                };
+            }
+
+            let day = Self::decode(self.read_register(RTC_DAY)?, binary_mode);
+            let month = Self::decode(self.read_register(RTC_MONTH)?, binary_mode);
+            let year_of_century = Self::decode(self.read_register(RTC_YEAR)?, binary_mode) as u16;
+
+            // The century register isn't present on every chipset; fall
+            // back to the compile-time century base rather than failing
+            // the whole read when it can't be read.
+            let century = match self.read_register(RTC_CENTURY) {
+                Ok(raw_century) => Self::decode(raw_century, binary_mode) as u16 * 100,
+                Err(_) => CENTURY_BASE,
+            };
+            let year = century + year_of_century;
 
             let datetime = DateTime {
                 year,
@@ -219,12 +285,140 @@ impl RTC {
     }
 }
 
+impl TimeSource for RTC {
+    fn now(&mut self) -> Result<DateTime, RTCError> {
+        self.try_get_datetime()
+    }
+
+    fn resolution(&self) -> Duration {
+        Duration::from_secs(1)
+    }
+
+    fn is_monotonic(&self) -> bool {
+        false
+    }
+}
+
+/// A monotonic clock driven by an externally-ticked counter rather than
+/// any hardware RTC, used by [`ClockFacade`] as the baseline the other
+/// sources are cross-checked against.
+pub struct TickCounter {
+    ticks: AtomicU64,
+    epoch: DateTime,
+    resolution: Duration,
+}
+
+impl TickCounter {
+    pub const fn new(epoch: DateTime, resolution: Duration) -> Self {
+        Self { ticks: AtomicU64::new(0), epoch, resolution }
+    }
+
+    /// Advances the counter by one tick; intended to be called from a
+    /// periodic timer interrupt.
+    pub fn tick(&self) {
+        self.ticks.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+impl TimeSource for TickCounter {
+    fn now(&mut self) -> Result<DateTime, RTCError> {
+        let elapsed_secs = self.ticks.load(Ordering::Relaxed) * self.resolution.as_secs().max(1);
+        let total_secs = seconds_of_day(&self.epoch) as u64 + elapsed_secs;
+        Ok(DateTime {
+            year: self.epoch.year,
+            month: self.epoch.month,
+            day: self.epoch.day,
+            hours: ((total_secs / 3600) % 24) as u8,
+            minutes: ((total_secs / 60) % 60) as u8,
+            seconds: (total_secs % 60) as u8,
+        })
+    }
+
+    fn resolution(&self) -> Duration {
+        self.resolution
+    }
+
+    fn is_monotonic(&self) -> bool {
+        true
+    }
+}
+
+/// Cross-checks a set of [`TimeSource`]s against a monotonic baseline and
+/// tracks how far each wall-clock source has drifted from it, so clock
+/// reliability can be judged on more than a single RTC's own
+/// update/error ratio.
+pub struct ClockFacade {
+    sources: Vec<(&'static str, Box<dyn TimeSource>)>,
+    monotonic_index: Option<usize>,
+    drift_secs: Vec<(&'static str, f64)>,
+}
+
+impl ClockFacade {
+    pub fn new() -> Self {
+        Self {
+            sources: Vec::new(),
+            monotonic_index: None,
+            drift_secs: Vec::new(),
+        }
+    }
+
+    /// Registers a named source; the first monotonic source registered
+    /// becomes the drift baseline.
+    pub fn register(&mut self, name: &'static str, source: Box<dyn TimeSource>) {
+        if source.is_monotonic() && self.monotonic_index.is_none() {
+            self.monotonic_index = Some(self.sources.len());
+        }
+        self.sources.push((name, source));
+    }
+
+    /// Reads every registered source and records each non-monotonic
+    /// source's skew, in seconds, against the monotonic baseline.
+    pub fn cross_check(&mut self) {
+        let Some(monotonic_index) = self.monotonic_index else {
+            return;
+        };
+        let baseline = match self.sources[monotonic_index].1.now() {
+            Ok(dt) => seconds_of_day(&dt),
+            Err(_) => return,
+        };
+
+        for (index, (name, source)) in self.sources.iter_mut().enumerate() {
+            if index == monotonic_index {
+                continue;
+            }
+            if let Ok(dt) = source.now() {
+                let skew = (seconds_of_day(&dt) as f64 - baseline as f64).abs();
+                match self.drift_secs.iter_mut().find(|(n, _)| n == name) {
+                    Some((_, recorded)) => *recorded = skew,
+                    None => self.drift_secs.push((name, skew)),
+                }
+            }
+        }
+    }
+
+    /// Measured drift, in seconds, for every non-monotonic source as of
+    /// the last [`ClockFacade::cross_check`]
+    pub fn get_stats(&self) -> &[(&'static str, f64)] {
+        &self.drift_secs
+    }
+}
+
 lazy_static! {
     pub static ref RTC_DEVICE: Mutex<RTC> = {
         let rtc = RTC::new();
         splat::log(SplatLevel::BitsNBytes, "RTC hardware initialized");
         Mutex::new(rtc)
     };
+
+    pub static ref CLOCK_FACADE: Mutex<ClockFacade> = {
+        let mut facade = ClockFacade::new();
+        facade.register("rtc", Box::new(RTC::new()));
+        facade.register("monotonic", Box::new(TickCounter::new(
+            DateTime { year: CENTURY_BASE + 24, month: 1, day: 1, hours: 0, minutes: 0, seconds: 0 },
+            Duration::from_secs(1),
+        )));
+        Mutex::new(facade)
+    };
 }
 
 // Public interface
@@ -258,6 +452,16 @@ uptime.as_secs(),
             )
         );
     }
+
+    if let Some(mut facade) = CLOCK_FACADE.try_lock() {
+        facade.cross_check();
+        for (name, drift_secs) in facade.get_stats() {
+            splat::log(
+                SplatLevel::BitsNBytes,
+                &format!("Clock drift: {} is {:.1}s from the monotonic baseline", name, drift_secs)
+            );
+        }
+    }
 }
 
 pub fn test_rtc() -> bool {
@@ -4,13 +4,15 @@ use uart_16550::SerialPort;
 use spin::Mutex;
 use lazy_static::lazy_static;
 use core::fmt::Write;
+use core::time::Duration;
 use x86_64::instructions::interrupts;
 use crate::splat::{self, SplatLevel};
+use crate::timer::{MonotonicClock, SYSTEM_CLOCK};
 //////////// END //////////////
 
 // Serial Port Constants
 const SERIAL_PORT_ADDRESS: u16 = 0x3F8;  // COM1
-const SERIAL_TIMEOUT: u16 = 1000;
+const SERIAL_TIMEOUT: Duration = Duration::from_millis(1000);
 
 #[derive(Debug)]
 pub enum SerialError {
@@ -96,16 +98,28 @@ impl SerialController {
     }
 
     pub fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), SerialError> {
-        let mut timeout = SERIAL_TIMEOUT;
+        self.write_bytes_with_clock(bytes, &SYSTEM_CLOCK)
+    }
 
+    /// Same as [`write_bytes`](Self::write_bytes), but spins against an
+    /// injected [`MonotonicClock`] instead of the TSC-backed
+    /// [`SYSTEM_CLOCK`], so `SERIAL_TIMEOUT` is a real deadline rather
+    /// than a loop-iteration count that varies with CPU speed and
+    /// optimization level, and so tests can drive the timeout path with a
+    /// fake clock.
+    fn write_bytes_with_clock<C: MonotonicClock>(
+        &mut self,
+        bytes: &[u8],
+        clock: &C,
+    ) -> Result<(), SerialError> {
         for byte in bytes {
-            while timeout > 0 && !self.port.is_transmit_empty() {
-                timeout -= 1;
-            }
+            let deadline = clock.now() + SERIAL_TIMEOUT;
 
-            if timeout == 0 {
-                self.stats.write_failures += 1;
-                return Err(SerialError::WriteTimeout);
+            while !self.port.is_transmit_empty() {
+                if clock.now() >= deadline {
+                    self.stats.write_failures += 1;
+                    return Err(SerialError::WriteTimeout);
+                }
             }
 
             unsafe { self.port.send(*byte); }
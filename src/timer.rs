@@ -0,0 +1,96 @@
+//  IMPORTS  \\
+///////////////////////////////
+use core::time::Duration;
+//////////// END //////////////
+
+/// A source of monotonic elapsed time, mirroring the `TimeSource` trait in
+/// `crate::rtc` but for deadline-based busy-waits rather than wall-clock
+/// dates: a pluggable clock lets [`crate::serial::SerialController`] spin
+/// against a real TSC-backed reading in production and a fake clock in
+/// tests, instead of a loop-iteration count whose duration varies with CPU
+/// speed and optimization level.
+pub trait MonotonicClock {
+    /// A monotonically increasing point in time, expressed as a
+    /// `Duration` since an implementation-defined but fixed epoch (CPU
+    /// reset, for [`TscClock`]). Only meaningful when compared against
+    /// another reading from the same clock instance.
+    fn now(&self) -> Duration;
+}
+
+/// Reads the CPU's timestamp counter (`rdtsc`) and converts cycles to a
+/// `Duration` using a calibrated cycles-per-second rate.
+///
+/// [`TscClock::DEFAULT_CYCLES_PER_SECOND`] is a placeholder for common
+/// x86_64 hardware; code that calibrates the TSC against the PIT during
+/// boot should construct its own `TscClock::new(measured_rate)` instead
+/// of relying on the default.
+pub struct TscClock {
+    cycles_per_second: u64,
+}
+
+impl TscClock {
+    pub const DEFAULT_CYCLES_PER_SECOND: u64 = 3_000_000_000;
+
+    pub const fn new(cycles_per_second: u64) -> Self {
+        Self { cycles_per_second }
+    }
+}
+
+impl MonotonicClock for TscClock {
+    fn now(&self) -> Duration {
+        let cycles = unsafe { core::arch::x86_64::_rdtsc() };
+        let nanos = (cycles as u128 * 1_000_000_000u128 / self.cycles_per_second.max(1) as u128) as u64;
+        Duration::from_nanos(nanos)
+    }
+}
+
+/// The kernel's TSC-backed monotonic clock, shared by anything that needs
+/// to compute a deadline (serial writes today; disk-read retry backoff
+/// could reuse it the same way).
+pub static SYSTEM_CLOCK: TscClock = TscClock::new(TscClock::DEFAULT_CYCLES_PER_SECOND);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+
+    struct FakeClock {
+        now: Cell<Duration>,
+    }
+
+    impl FakeClock {
+        fn new() -> Self {
+            Self { now: Cell::new(Duration::from_millis(0)) }
+        }
+
+        fn advance(&self, by: Duration) {
+            self.now.set(self.now.get() + by);
+        }
+    }
+
+    impl MonotonicClock for FakeClock {
+        fn now(&self) -> Duration {
+            self.now.get()
+        }
+    }
+
+    #[test]
+    fn test_fake_clock_deadline_has_not_passed_before_the_timeout_elapses() {
+        let clock = FakeClock::new();
+        let deadline = clock.now() + Duration::from_millis(1000);
+
+        clock.advance(Duration::from_millis(999));
+
+        assert!(clock.now() < deadline);
+    }
+
+    #[test]
+    fn test_fake_clock_deadline_has_passed_once_the_timeout_elapses() {
+        let clock = FakeClock::new();
+        let deadline = clock.now() + Duration::from_millis(1000);
+
+        clock.advance(Duration::from_millis(1000));
+
+        assert!(clock.now() >= deadline);
+    }
+}